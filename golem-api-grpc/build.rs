@@ -19,6 +19,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/rib/type_name.proto",
                 "proto/golem/rib/expr.proto",
                 "proto/golem/rib/rib_input.proto",
+                "proto/golem/rib/rib_output.proto",
                 "proto/golem/rib/ir.proto",
                 "proto/golem/rib/rib_byte_code.proto",
                 "proto/golem/rib/worker_functions_in_rib.proto",
@@ -54,6 +55,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/worker/log_event.proto",
                 "proto/golem/worker/promise_id.proto",
                 "proto/golem/worker/public_oplog.proto",
+                "proto/golem/worker/revert_worker_target.proto",
+                "proto/golem/worker/scheduled_invocation.proto",
                 "proto/golem/worker/update_mode.proto",
                 "proto/golem/worker/worker_id.proto",
                 "proto/golem/worker/worker_metadata.proto",
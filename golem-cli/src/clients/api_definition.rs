@@ -16,7 +16,9 @@ use crate::model::{
     ApiDefinitionFileFormat, ApiDefinitionId, ApiDefinitionVersion, GolemError, PathBufOrStdin,
 };
 use async_trait::async_trait;
-use golem_client::model::HttpApiDefinitionWithTypeInfo;
+use golem_client::model::{
+    ApiDefinitionTestRequest, ApiDefinitionTestResult, HttpApiDefinitionWithTypeInfo,
+};
 
 #[async_trait]
 pub trait ApiDefinitionClient {
@@ -57,4 +59,17 @@ pub trait ApiDefinitionClient {
         version: ApiDefinitionVersion,
         project: &Self::ProjectContext,
     ) -> Result<String, GolemError>;
+    async fn export(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        project: &Self::ProjectContext,
+    ) -> Result<serde_json::Value, GolemError>;
+    async fn test(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        request: ApiDefinitionTestRequest,
+        project: &Self::ProjectContext,
+    ) -> Result<ApiDefinitionTestResult, GolemError>;
 }
@@ -18,8 +18,13 @@ use crate::model::{
     WorkersMetadataResponse,
 };
 use async_trait::async_trait;
-use golem_client::model::{InvokeParameters, InvokeResult, ScanCursor, WorkerFilter, WorkerId};
+use chrono::{DateTime, Utc};
+use golem_client::model::{
+    InvokeParameters, InvokeResult, ScanCursor, ScheduleInvocationParameters,
+    ScheduledInvocationInfo, WorkerFilter, WorkerId,
+};
 use golem_common::model::public_oplog::PublicOplogEntry;
+use golem_common::model::RevertWorkerTarget;
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
 
 #[async_trait]
@@ -46,6 +51,7 @@ pub trait WorkerClient {
         function: String,
         parameters: InvokeParameters,
         idempotency_key: Option<IdempotencyKey>,
+        schedule_at: Option<DateTime<Utc>>,
     ) -> Result<(), GolemError>;
 
     async fn interrupt(&self, worker_urn: WorkerUrn) -> Result<(), GolemError>;
@@ -95,6 +101,23 @@ pub trait WorkerClient {
         target_version: u64,
     ) -> Result<(), GolemError>;
 
+    async fn fork(
+        &self,
+        worker_urn: WorkerUrn,
+        target_worker_name: WorkerName,
+        oplog_index_cutoff: u64,
+    ) -> Result<(), GolemError>;
+
+    async fn revert(
+        &self,
+        worker_urn: WorkerUrn,
+        target: RevertWorkerTarget,
+    ) -> Result<(), GolemError>;
+
+    async fn export(&self, worker_urn: WorkerUrn) -> Result<Vec<u8>, GolemError>;
+
+    async fn import(&self, worker_urn: WorkerUrn, archive: Vec<u8>) -> Result<(), GolemError>;
+
     async fn get_oplog(
         &self,
         worker_urn: WorkerUrn,
@@ -106,6 +129,29 @@ pub trait WorkerClient {
         worker_urn: WorkerUrn,
         query: String,
     ) -> Result<Vec<(u64, PublicOplogEntry)>, GolemError>;
+
+    async fn create_scheduled_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        parameters: ScheduleInvocationParameters,
+    ) -> Result<ScheduledInvocationInfo, GolemError>;
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_urn: WorkerUrn,
+    ) -> Result<Vec<ScheduledInvocationInfo>, GolemError>;
+
+    async fn update_scheduled_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        parameters: ScheduleInvocationParameters,
+    ) -> Result<ScheduledInvocationInfo, GolemError>;
+
+    async fn delete_scheduled_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        name: String,
+    ) -> Result<(), GolemError>;
 }
 
 pub fn worker_name_required(urn: &WorkerUrn) -> Result<String, GolemError> {
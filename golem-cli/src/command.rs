@@ -20,6 +20,7 @@ pub mod api_definition;
 pub mod api_deployment;
 pub mod component;
 pub mod profile;
+pub mod repl;
 pub mod worker;
 
 pub trait ComponentRefSplit<ProjectRef> {
@@ -14,11 +14,15 @@
 
 use crate::model::{
     ApiDefinitionFileFormat, ApiDefinitionId, ApiDefinitionVersion, GolemError, GolemResult,
-    PathBufOrStdin,
+    JsonValueParser, PathBufOrStdin,
 };
+use crate::parse_key_val;
 use crate::service::api_definition::ApiDefinitionService;
 use crate::service::project::ProjectResolver;
+use clap::builder::ValueParser;
 use clap::Subcommand;
+use golem_client::model::{ApiDefinitionTestRequest, MethodPattern};
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
 #[derive(Subcommand, Debug)]
 #[command()]
@@ -105,6 +109,67 @@ pub enum ApiDefinitionSubcommand<ProjectRef: clap::Args> {
         version: ApiDefinitionVersion,
     },
 
+    /// Exports an existing api definition as an OpenAPI spec
+    ///
+    /// The schemas of the request and response are inferred from the Rib expressions of the routes.
+    /// The spec is printed as json, or as yaml with `--format yaml`, and can be imported back.
+    #[command()]
+    Export {
+        /// The newly created component's owner project
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// Api definition id
+        #[arg(short, long)]
+        id: ApiDefinitionId,
+
+        /// Version of the api definition
+        #[arg(short = 'V', long)]
+        version: ApiDefinitionVersion,
+    },
+
+    /// Tests an existing api definition with a sample request
+    ///
+    /// Prints the matched route, the evaluated worker name and idempotency key, and the result of the response mapping.
+    /// The api definition does not have to be deployed. Workers are invoked, unless a mock worker result is given.
+    #[command()]
+    Test {
+        /// The newly created component's owner project
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// Api definition id
+        #[arg(short, long)]
+        id: ApiDefinitionId,
+
+        /// Version of the api definition
+        #[arg(short = 'V', long)]
+        version: ApiDefinitionVersion,
+
+        /// HTTP method of the sample request
+        #[arg(short, long, value_parser = parse_method_pattern, default_value = "get")]
+        method: MethodPattern,
+
+        /// Path of the sample request, including the query
+        path: String,
+
+        /// Header of the sample request
+        ///
+        /// You can specify this argument multiple times for multiple headers.
+        #[arg(short = 'H', long = "header", value_parser = parse_key_val, value_name = "NAME=VALUE")]
+        headers: Vec<(String, String)>,
+
+        /// JSON body of the sample request
+        #[arg(short, long, value_name = "json", value_parser = ValueParser::new(JsonValueParser))]
+        body: Option<serde_json::value::Value>,
+
+        /// Result returned for every worker call instead of invoking the worker
+        ///
+        /// Expected as a JSON type annotated value, in the same format as the result of a worker invocation.
+        #[arg(long, value_name = "json", value_parser = ValueParser::new(JsonValueParser))]
+        mock_worker_result: Option<serde_json::value::Value>,
+    },
+
     /// Deletes an existing api definition
     #[command()]
     Delete {
@@ -182,6 +247,55 @@ impl<ProjectRef: clap::Args + Send + Sync + 'static> ApiDefinitionSubcommand<Pro
                 let project_id = projects.resolve_id_or_default(project_ref).await?;
                 service.delete(id, version, &project_id).await
             }
+            ApiDefinitionSubcommand::Export {
+                project_ref,
+                id,
+                version,
+            } => {
+                let project_id = projects.resolve_id_or_default(project_ref).await?;
+                service.export(id, version, &project_id).await
+            }
+            ApiDefinitionSubcommand::Test {
+                project_ref,
+                id,
+                version,
+                method,
+                path,
+                headers,
+                body,
+                mock_worker_result,
+            } => {
+                let project_id = projects.resolve_id_or_default(project_ref).await?;
+                let mock_worker_result = mock_worker_result
+                    .map(serde_json::from_value::<TypeAnnotatedValue>)
+                    .transpose()
+                    .map_err(|err| GolemError(format!("Invalid mock worker result: {err}")))?;
+
+                let request = ApiDefinitionTestRequest {
+                    method,
+                    path,
+                    headers: Some(headers.into_iter().collect()),
+                    body,
+                    mock_worker_result,
+                };
+
+                service.test(id, version, request, &project_id).await
+            }
         }
     }
 }
+
+fn parse_method_pattern(s: &str) -> Result<MethodPattern, String> {
+    match s.to_lowercase().as_str() {
+        "get" => Ok(MethodPattern::Get),
+        "connect" => Ok(MethodPattern::Connect),
+        "post" => Ok(MethodPattern::Post),
+        "delete" => Ok(MethodPattern::Delete),
+        "put" => Ok(MethodPattern::Put),
+        "patch" => Ok(MethodPattern::Patch),
+        "options" => Ok(MethodPattern::Options),
+        "trace" => Ok(MethodPattern::Trace),
+        "head" => Ok(MethodPattern::Head),
+        _ => Err(format!("invalid HTTP method: `{s}`")),
+    }
+}
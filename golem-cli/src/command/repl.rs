@@ -0,0 +1,48 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::ComponentRefSplit;
+use crate::model::{GolemError, GolemResult, WorkerName};
+use crate::service::project::ProjectResolver;
+use crate::service::repl::ReplService;
+use clap::Args;
+
+#[derive(Args, Debug)]
+pub struct ReplCommand<ComponentRef: clap::Args> {
+    /// The Golem component whose exported functions can be called
+    #[command(flatten)]
+    component_name_or_uri: ComponentRef,
+
+    /// Name of the worker the function calls go to. Each call goes to a new ephemeral worker if not set
+    #[arg(short, long)]
+    worker_name: Option<WorkerName>,
+}
+
+impl<ComponentRef: clap::Args> ReplCommand<ComponentRef> {
+    pub async fn handle<ProjectRef: Send + Sync + 'static, ProjectContext: Send + Sync>(
+        self,
+        service: &(dyn ReplService<ProjectContext = ProjectContext> + Send + Sync),
+        projects: &(dyn ProjectResolver<ProjectRef, ProjectContext> + Send + Sync),
+    ) -> Result<GolemResult, GolemError>
+    where
+        ComponentRef: ComponentRefSplit<ProjectRef>,
+    {
+        let (component_name_or_uri, project_ref) = self.component_name_or_uri.split();
+        let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+
+        service
+            .start(component_name_or_uri, self.worker_name, project_id)
+            .await
+    }
+}
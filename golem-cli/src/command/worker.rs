@@ -13,13 +13,19 @@
 // limitations under the License.
 
 use crate::command::ComponentRefSplit;
+use chrono::{DateTime, Utc};
 use clap::builder::ValueParser;
 use clap::{ArgMatches, Args, Error, FromArgMatches, Subcommand};
 use golem_client::model::ScanCursor;
-use golem_common::model::TargetWorkerId;
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::scheduled_invocation::{Cron, Interval, Once, Recurrence};
+use golem_common::model::{
+    RevertLastInvocations, RevertToOplogIndex, RevertWorkerTarget, TargetWorkerId,
+};
 use golem_common::uri::oss::uri::{ComponentUri, WorkerUri};
 use golem_common::uri::oss::url::{ComponentUrl, WorkerUrl};
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::join;
 use tokio::task::spawn;
@@ -78,6 +84,101 @@ pub struct InvokeParameterList {
     wave: Vec<String>,
 }
 
+#[derive(clap::Args, Debug, Clone)]
+pub struct ScheduleRecurrence {
+    /// Invoke repeatedly, waiting the given number of milliseconds between the invocations
+    #[arg(long, conflicts_with = "cron")]
+    interval_millis: Option<u64>,
+
+    /// Invoke every time the five-field cron expression matches (in UTC)
+    ///
+    /// If neither an interval nor a cron expression is given, the function is invoked only once.
+    #[arg(long)]
+    cron: Option<String>,
+
+    /// Time of the first invocation in RFC 3339 format. If missing, it is calculated from the recurrence
+    #[arg(long)]
+    start_at: Option<DateTime<Utc>>,
+}
+
+impl ScheduleRecurrence {
+    fn recurrence(&self) -> Recurrence {
+        match (&self.interval_millis, &self.cron) {
+            (Some(interval_millis), _) => Recurrence::Interval(Interval {
+                interval_millis: *interval_millis,
+            }),
+            (None, Some(expression)) => Recurrence::Cron(Cron {
+                expression: expression.clone(),
+            }),
+            (None, None) => Recurrence::Once(Once {}),
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum WorkerScheduleSubcommand<WorkerRef: clap::Args> {
+    /// Schedules a named, optionally recurring invocation of a worker function
+    #[command(alias = "create")]
+    Add {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the scheduled invocation, unique per worker
+        #[arg(short, long)]
+        name: String,
+
+        /// Name of the function to be invoked
+        #[arg(short, long)]
+        function: String,
+
+        #[command(flatten)]
+        parameters: InvokeParameterList,
+
+        #[command(flatten)]
+        recurrence: ScheduleRecurrence,
+    },
+
+    /// Lists the scheduled invocations of a worker
+    #[command()]
+    List {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+    },
+
+    /// Replaces the function, parameters and recurrence of a scheduled invocation
+    #[command()]
+    Update {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the scheduled invocation
+        #[arg(short, long)]
+        name: String,
+
+        /// Name of the function to be invoked
+        #[arg(short, long)]
+        function: String,
+
+        #[command(flatten)]
+        parameters: InvokeParameterList,
+
+        #[command(flatten)]
+        recurrence: ScheduleRecurrence,
+    },
+
+    /// Deletes a scheduled invocation
+    #[command()]
+    Delete {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the scheduled invocation
+        #[arg(short, long)]
+        name: String,
+    },
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct OssWorkerUriArg {
     pub uri: WorkerUri,
@@ -329,6 +430,11 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         #[command(flatten)]
         parameters: InvokeParameterList,
 
+        /// Enqueue the invocation only at the given time, in RFC 3339 format.
+        /// Until then it can be cancelled with its idempotency key.
+        #[arg(long, conflicts_with = "connect")]
+        schedule_at: Option<DateTime<Utc>>,
+
         /// Connect to the worker and show its logs
         #[arg(long)]
         connect: bool,
@@ -447,6 +553,60 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         #[arg(short = 't', long)]
         target_version: u64,
     },
+    /// Creates a new worker by copying an existing worker's oplog up to a given index
+    #[command()]
+    Fork {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the newly created worker
+        #[arg(short, long)]
+        target_worker_name: WorkerName,
+
+        /// Index of the last oplog entry to be copied to the new worker
+        #[arg(short, long)]
+        oplog_index_cutoff: u64,
+    },
+    /// Reverts a worker by undoing its last recorded oplog entries or invocations
+    #[command()]
+    Revert {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Index of the last oplog entry to keep
+        #[arg(short, long, conflicts_with = "number_of_invocations")]
+        last_oplog_index: Option<u64>,
+
+        /// Number of the last invocations to undo
+        #[arg(short, long, conflicts_with = "last_oplog_index")]
+        number_of_invocations: Option<u64>,
+    },
+    /// Exports a worker's oplog and durable state into an archive file
+    #[command()]
+    Export {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Path of the archive file to write
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Creates a new worker from an archive file created by the export command
+    #[command()]
+    Import {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Path of the archive file to import
+        #[arg(short, long)]
+        archive: PathBuf,
+    },
+    /// Manages scheduled, optionally recurring invocations of a worker
+    #[command()]
+    Schedule {
+        #[command(subcommand)]
+        subcommand: WorkerScheduleSubcommand<WorkerRef>,
+    },
     /// Queries and dumps a worker's full oplog
     #[command()]
     Oplog {
@@ -461,6 +621,21 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         #[arg(long, conflicts_with = "from")]
         query: Option<String>,
     },
+    /// Compares the oplogs of two workers of the same component and reports where they first diverge
+    #[command()]
+    OplogDiff {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the other worker of the same component to compare with
+        #[arg(short, long)]
+        other_worker_name: WorkerName,
+
+        /// Imported function to leave out of the comparison, for example `wall-clock::now`.
+        /// Can be used multiple times
+        #[arg(long)]
+        ignore: Vec<String>,
+    },
 }
 
 pub trait WorkerRefSplit<ProjectRef> {
@@ -553,6 +728,7 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                 idempotency_key,
                 function,
                 parameters,
+                schedule_at,
                 connect,
                 connect_options,
             } => {
@@ -566,6 +742,7 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                         function,
                         parameters.parameters,
                         parameters.wave,
+                        schedule_at,
                         project_id.clone(),
                     );
                     let connect_future =
@@ -580,6 +757,7 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                             function,
                             parameters.parameters,
                             parameters.wave,
+                            schedule_at,
                             project_id,
                         )
                         .await
@@ -669,6 +847,118 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                     )
                     .await
             }
+            WorkerSubcommand::Fork {
+                worker_ref,
+                target_worker_name,
+                oplog_index_cutoff,
+            } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service
+                    .fork(
+                        worker_uri,
+                        target_worker_name,
+                        oplog_index_cutoff,
+                        project_id,
+                    )
+                    .await
+            }
+            WorkerSubcommand::Revert {
+                worker_ref,
+                last_oplog_index,
+                number_of_invocations,
+            } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                let target = match (last_oplog_index, number_of_invocations) {
+                    (Some(last_oplog_index), None) => {
+                        RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex {
+                            last_oplog_index: OplogIndex::from_u64(last_oplog_index),
+                        })
+                    }
+                    (None, Some(number_of_invocations)) => {
+                        RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                            number_of_invocations,
+                        })
+                    }
+                    _ => {
+                        return Err(GolemError(
+                            "Exactly one of 'last-oplog-index' and 'number-of-invocations' must be specified"
+                                .to_string(),
+                        ))
+                    }
+                };
+                service.revert(worker_uri, target, project_id).await
+            }
+            WorkerSubcommand::Export { worker_ref, output } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service.export(worker_uri, output, project_id).await
+            }
+            WorkerSubcommand::Import {
+                worker_ref,
+                archive,
+            } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service.import(worker_uri, archive, project_id).await
+            }
+            WorkerSubcommand::Schedule { subcommand } => match subcommand {
+                WorkerScheduleSubcommand::Add {
+                    worker_ref,
+                    name,
+                    function,
+                    parameters,
+                    recurrence,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service
+                        .create_schedule(
+                            worker_uri,
+                            name,
+                            function,
+                            parameters.parameters,
+                            parameters.wave,
+                            recurrence.recurrence(),
+                            recurrence.start_at,
+                            project_id,
+                        )
+                        .await
+                }
+                WorkerScheduleSubcommand::List { worker_ref } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.list_schedules(worker_uri, project_id).await
+                }
+                WorkerScheduleSubcommand::Update {
+                    worker_ref,
+                    name,
+                    function,
+                    parameters,
+                    recurrence,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service
+                        .update_schedule(
+                            worker_uri,
+                            name,
+                            function,
+                            parameters.parameters,
+                            parameters.wave,
+                            recurrence.recurrence(),
+                            recurrence.start_at,
+                            project_id,
+                        )
+                        .await
+                }
+                WorkerScheduleSubcommand::Delete { worker_ref, name } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.delete_schedule(worker_uri, name, project_id).await
+                }
+            },
             WorkerSubcommand::Oplog {
                 worker_ref,
                 from,
@@ -687,6 +977,17 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                     (Some(from), None) => service.get_oplog(worker_uri, from, project_id).await,
                 }
             }
+            WorkerSubcommand::OplogDiff {
+                worker_ref,
+                other_worker_name,
+                ignore,
+            } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service
+                    .oplog_diff(worker_uri, other_worker_name, ignore, project_id)
+                    .await
+            }
         }
     }
 }
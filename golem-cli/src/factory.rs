@@ -22,6 +22,7 @@ use crate::service::api_deployment::{ApiDeploymentService, ApiDeploymentServiceL
 use crate::service::component::{ComponentService, ComponentServiceLive};
 use crate::service::deploy::{DeployService, DeployServiceLive};
 use crate::service::project::ProjectResolver;
+use crate::service::repl::{ReplService, ReplServiceLive};
 use crate::service::version::{VersionService, VersionServiceLive};
 use crate::service::worker::{WorkerService, WorkerServiceLive};
 use std::fmt::Display;
@@ -109,4 +110,16 @@ pub trait ServiceFactory {
             worker_service: self.worker_service(),
         })
     }
+
+    fn repl_service(
+        &self,
+    ) -> Arc<dyn ReplService<ProjectContext = Self::ProjectContext> + Send + Sync>
+    where
+        Self: Send + Sync + Sized + 'static,
+    {
+        Arc::new(ReplServiceLive {
+            client: self.worker_client(),
+            components: self.component_service(),
+        })
+    }
 }
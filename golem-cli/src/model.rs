@@ -16,6 +16,8 @@ pub mod application_manifest;
 pub mod component;
 pub mod deploy;
 pub mod invoke_result_view;
+pub mod oplog_diff;
+pub mod repl;
 pub mod text;
 pub mod wave;
 
@@ -523,6 +525,9 @@ pub struct WorkerMetadataView {
     pub total_linear_memory_size: u64,
     #[serde(rename = "ownedResources")]
     pub owned_resources: HashMap<String, golem_client::model::ResourceMetadata>,
+    #[serde(rename = "scheduledInvocations")]
+    #[serde(default)]
+    pub scheduled_invocations: Vec<golem_client::model::ScheduledInvocationInfo>,
 }
 
 impl TrimDateTime for WorkerMetadataView {
@@ -551,6 +556,7 @@ impl From<WorkerMetadata> for WorkerMetadataView {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            scheduled_invocations,
         } = value;
 
         WorkerMetadataView {
@@ -570,6 +576,7 @@ impl From<WorkerMetadata> for WorkerMetadataView {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            scheduled_invocations,
         }
     }
 }
@@ -590,6 +597,7 @@ pub struct WorkerMetadata {
     pub component_size: u64,
     pub total_linear_memory_size: u64,
     pub owned_resources: HashMap<String, golem_client::model::ResourceMetadata>,
+    pub scheduled_invocations: Vec<golem_client::model::ScheduledInvocationInfo>,
 }
 
 impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
@@ -608,6 +616,7 @@ impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            scheduled_invocations,
         } = value;
 
         WorkerMetadata {
@@ -625,6 +634,7 @@ impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            scheduled_invocations,
         }
     }
 }
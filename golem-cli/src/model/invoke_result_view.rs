@@ -168,6 +168,7 @@ mod tests {
                     results: func_res,
                 })],
                 memories: vec![],
+                limits: None,
             },
             project_id: None,
             created_at: Some(Utc::now()),
@@ -0,0 +1,511 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use golem_common::model::public_oplog::{ImportedFunctionInvokedParameters, PublicOplogEntry};
+use golem_common::model::IdempotencyKey;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use golem_wasm_rpc::{type_annotated_value_to_string, ValueAndType};
+use serde::{Deserialize, Serialize};
+
+/// The part of an invocation where two oplogs first differ
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OplogDivergenceKind {
+    /// The invocation only exists in one of the oplogs
+    MissingInvocation,
+    /// Different exported functions were invoked
+    FunctionName,
+    /// The exported function was invoked with different parameters
+    Parameters,
+    /// An imported (host) function call differs in its name, parameters or result
+    ImportedFunction,
+    /// The invocation completed with a different result, failed or is still pending
+    Outcome,
+}
+
+/// Describes the first point where two oplogs diverge. The `left` and `right` fields contain a
+/// human-readable description of the differing values, if there is one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OplogDivergence {
+    /// Zero-based position of the diverging invocation in the left oplog (or in the right one
+    /// if the invocation is missing from the left oplog)
+    pub invocation: usize,
+    pub kind: OplogDivergenceKind,
+    pub left_idempotency_key: Option<IdempotencyKey>,
+    pub right_idempotency_key: Option<IdempotencyKey>,
+    pub left_oplog_index: Option<u64>,
+    pub right_oplog_index: Option<u64>,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OplogDiff {
+    /// Number of invocations that were found identical before the divergence
+    pub matching_invocations: usize,
+    pub divergence: Option<OplogDivergence>,
+}
+
+impl OplogDiff {
+    /// Compares two oplogs invocation by invocation and returns the first divergence.
+    ///
+    /// Invocations are aligned by their idempotency key when the same key appears in both
+    /// oplogs, and by their position among the remaining invocations otherwise, so both workers
+    /// invoked with the same keys and workers invoked independently can be compared. Timestamps,
+    /// logs and other bookkeeping entries are not compared. Calls to the imported functions
+    /// listed in `ignored_imports` (for example `wall-clock::now`) are left out of the comparison.
+    pub fn between(
+        left: &[(u64, PublicOplogEntry)],
+        right: &[(u64, PublicOplogEntry)],
+        ignored_imports: &[String],
+    ) -> OplogDiff {
+        let left = Invocation::collect(left, ignored_imports);
+        let right = Invocation::collect(right, ignored_imports);
+
+        let left_keys: HashSet<&IdempotencyKey> =
+            left.iter().map(|inv| inv.idempotency_key).collect();
+        let right_by_key: HashMap<&IdempotencyKey, usize> = right
+            .iter()
+            .enumerate()
+            .map(|(idx, inv)| (inv.idempotency_key, idx))
+            .collect();
+        let mut unkeyed_right: VecDeque<usize> = right
+            .iter()
+            .enumerate()
+            .filter(|(_, inv)| !left_keys.contains(inv.idempotency_key))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let mut matching_invocations = 0;
+        for (idx, left_invocation) in left.iter().enumerate() {
+            let right_idx = match right_by_key.get(left_invocation.idempotency_key) {
+                Some(right_idx) => Some(*right_idx),
+                None => unkeyed_right.pop_front(),
+            };
+
+            let divergence = match right_idx {
+                Some(right_idx) => left_invocation.compare(&right[right_idx], idx),
+                None => Some(OplogDivergence {
+                    invocation: idx,
+                    kind: OplogDivergenceKind::MissingInvocation,
+                    left_idempotency_key: Some(left_invocation.idempotency_key.clone()),
+                    right_idempotency_key: None,
+                    left_oplog_index: Some(left_invocation.oplog_index),
+                    right_oplog_index: None,
+                    left: Some(left_invocation.function_name.to_string()),
+                    right: None,
+                }),
+            };
+
+            if divergence.is_some() {
+                return OplogDiff {
+                    matching_invocations,
+                    divergence,
+                };
+            }
+            matching_invocations += 1;
+        }
+
+        let divergence = unkeyed_right.pop_front().map(|right_idx| {
+            let right_invocation = &right[right_idx];
+            OplogDivergence {
+                invocation: right_idx,
+                kind: OplogDivergenceKind::MissingInvocation,
+                left_idempotency_key: None,
+                right_idempotency_key: Some(right_invocation.idempotency_key.clone()),
+                left_oplog_index: None,
+                right_oplog_index: Some(right_invocation.oplog_index),
+                left: None,
+                right: Some(right_invocation.function_name.to_string()),
+            }
+        });
+
+        OplogDiff {
+            matching_invocations,
+            divergence,
+        }
+    }
+}
+
+enum Outcome<'a> {
+    Completed(&'a ValueAndType),
+    Failed(&'a str),
+}
+
+impl Outcome<'_> {
+    fn describe(&self) -> String {
+        match self {
+            Outcome::Completed(response) => describe_value(response),
+            Outcome::Failed(error) => format!("failed: {error}"),
+        }
+    }
+}
+
+struct Invocation<'a> {
+    oplog_index: u64,
+    idempotency_key: &'a IdempotencyKey,
+    function_name: &'a str,
+    request: &'a [ValueAndType],
+    imported_calls: Vec<(u64, &'a ImportedFunctionInvokedParameters)>,
+    outcome: Option<(u64, Outcome<'a>)>,
+}
+
+impl<'a> Invocation<'a> {
+    fn collect(
+        entries: &'a [(u64, PublicOplogEntry)],
+        ignored_imports: &[String],
+    ) -> Vec<Invocation<'a>> {
+        let mut invocations: Vec<Invocation<'a>> = Vec::new();
+
+        for (oplog_index, entry) in entries {
+            match entry {
+                PublicOplogEntry::ExportedFunctionInvoked(params) => {
+                    invocations.push(Invocation {
+                        oplog_index: *oplog_index,
+                        idempotency_key: &params.idempotency_key,
+                        function_name: &params.function_name,
+                        request: &params.request,
+                        imported_calls: Vec::new(),
+                        outcome: None,
+                    });
+                }
+                PublicOplogEntry::ImportedFunctionInvoked(params)
+                    if !ignored_imports.contains(&params.function_name) =>
+                {
+                    if let Some(current) = invocations.last_mut() {
+                        current.imported_calls.push((*oplog_index, params));
+                    }
+                }
+                PublicOplogEntry::ExportedFunctionCompleted(params) => {
+                    if let Some(current) = invocations.last_mut() {
+                        current.outcome =
+                            Some((*oplog_index, Outcome::Completed(&params.response)));
+                    }
+                }
+                PublicOplogEntry::Error(params) => {
+                    // A failed attempt may be retried, so only a completion overrides an error
+                    if let Some(current) = invocations.last_mut() {
+                        if !matches!(current.outcome, Some((_, Outcome::Completed(_)))) {
+                            current.outcome = Some((*oplog_index, Outcome::Failed(&params.error)));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        invocations
+    }
+
+    fn compare(&self, other: &Invocation<'_>, invocation: usize) -> Option<OplogDivergence> {
+        let divergence = |kind: OplogDivergenceKind,
+                          left_oplog_index: Option<u64>,
+                          right_oplog_index: Option<u64>,
+                          left: Option<String>,
+                          right: Option<String>| OplogDivergence {
+            invocation,
+            kind,
+            left_idempotency_key: Some(self.idempotency_key.clone()),
+            right_idempotency_key: Some(other.idempotency_key.clone()),
+            left_oplog_index,
+            right_oplog_index,
+            left,
+            right,
+        };
+
+        if self.function_name != other.function_name {
+            return Some(divergence(
+                OplogDivergenceKind::FunctionName,
+                Some(self.oplog_index),
+                Some(other.oplog_index),
+                Some(self.function_name.to_string()),
+                Some(other.function_name.to_string()),
+            ));
+        }
+
+        if self.request != other.request {
+            return Some(divergence(
+                OplogDivergenceKind::Parameters,
+                Some(self.oplog_index),
+                Some(other.oplog_index),
+                Some(describe_values(self.request)),
+                Some(describe_values(other.request)),
+            ));
+        }
+
+        let call_count = self.imported_calls.len().max(other.imported_calls.len());
+        for idx in 0..call_count {
+            let left_call = self.imported_calls.get(idx);
+            let right_call = other.imported_calls.get(idx);
+            let same = match (left_call, right_call) {
+                (Some((_, left_call)), Some((_, right_call))) => {
+                    left_call.function_name == right_call.function_name
+                        && left_call.request == right_call.request
+                        && left_call.response == right_call.response
+                }
+                _ => false,
+            };
+            if !same {
+                return Some(divergence(
+                    OplogDivergenceKind::ImportedFunction,
+                    left_call.map(|(oplog_index, _)| *oplog_index),
+                    right_call.map(|(oplog_index, _)| *oplog_index),
+                    left_call.map(|(_, call)| describe_call(call)),
+                    right_call.map(|(_, call)| describe_call(call)),
+                ));
+            }
+        }
+
+        let same_outcome = match (&self.outcome, &other.outcome) {
+            (Some((_, Outcome::Completed(left))), Some((_, Outcome::Completed(right)))) => {
+                left == right
+            }
+            (Some((_, Outcome::Failed(left))), Some((_, Outcome::Failed(right)))) => left == right,
+            (None, None) => true,
+            _ => false,
+        };
+        if !same_outcome {
+            return Some(divergence(
+                OplogDivergenceKind::Outcome,
+                self.outcome.as_ref().map(|(oplog_index, _)| *oplog_index),
+                other.outcome.as_ref().map(|(oplog_index, _)| *oplog_index),
+                Some(describe_outcome(&self.outcome)),
+                Some(describe_outcome(&other.outcome)),
+            ));
+        }
+
+        None
+    }
+}
+
+fn describe_outcome(outcome: &Option<(u64, Outcome<'_>)>) -> String {
+    match outcome {
+        Some((_, outcome)) => outcome.describe(),
+        None => "pending".to_string(),
+    }
+}
+
+fn describe_call(call: &ImportedFunctionInvokedParameters) -> String {
+    format!(
+        "{}({}) -> {}",
+        call.function_name,
+        describe_value(&call.request),
+        describe_value(&call.response)
+    )
+}
+
+fn describe_values(values: &[ValueAndType]) -> String {
+    values
+        .iter()
+        .map(describe_value)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn describe_value(value: &ValueAndType) -> String {
+    TypeAnnotatedValue::try_from(value)
+        .ok()
+        .and_then(|tav| type_annotated_value_to_string(&tav).ok())
+        .unwrap_or_else(|| format!("{:?}", value.value))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use golem_common::model::public_oplog::{
+        Empty, ErrorParameters, ExportedFunctionCompletedParameters,
+        ExportedFunctionInvokedParameters, ImportedFunctionInvokedParameters, PublicOplogEntry,
+        PublicWrappedFunctionType, TimestampParameter,
+    };
+    use golem_common::model::{IdempotencyKey, Timestamp};
+    use golem_wasm_ast::analysis::analysed_type::u64;
+    use golem_wasm_rpc::{Value, ValueAndType};
+
+    use crate::model::oplog_diff::{OplogDiff, OplogDivergenceKind};
+
+    fn number(n: u64) -> ValueAndType {
+        ValueAndType {
+            value: Value::U64(n),
+            typ: u64(),
+        }
+    }
+
+    fn invoked(key: &str, function_name: &str, request: Vec<ValueAndType>) -> PublicOplogEntry {
+        PublicOplogEntry::ExportedFunctionInvoked(ExportedFunctionInvokedParameters {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request,
+            idempotency_key: IdempotencyKey::new(key.to_string()),
+        })
+    }
+
+    fn call(function_name: &str, response: u64) -> PublicOplogEntry {
+        PublicOplogEntry::ImportedFunctionInvoked(ImportedFunctionInvokedParameters {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request: number(0),
+            response: number(response),
+            wrapped_function_type: PublicWrappedFunctionType::ReadLocal(Empty),
+        })
+    }
+
+    fn completed(response: u64) -> PublicOplogEntry {
+        PublicOplogEntry::ExportedFunctionCompleted(ExportedFunctionCompletedParameters {
+            timestamp: Timestamp::now_utc(),
+            response: number(response),
+            consumed_fuel: 0,
+        })
+    }
+
+    fn indexed(entries: Vec<PublicOplogEntry>) -> Vec<(u64, PublicOplogEntry)> {
+        entries
+            .into_iter()
+            .enumerate()
+            .map(|(idx, entry)| (idx as u64 + 1, entry))
+            .collect()
+    }
+
+    #[test]
+    fn identical_oplogs_do_not_diverge() {
+        let oplog = indexed(vec![
+            invoked("k1", "api.{get}", vec![]),
+            call("wall-clock::now", 1),
+            completed(10),
+        ]);
+
+        let diff = OplogDiff::between(&oplog, &oplog, &[]);
+
+        assert_eq!(diff.matching_invocations, 1);
+        assert_eq!(diff.divergence, None);
+    }
+
+    #[test]
+    fn first_diverging_imported_call_is_reported() {
+        let left = indexed(vec![
+            invoked("k1", "api.{get}", vec![]),
+            completed(10),
+            invoked("k2", "api.{get}", vec![]),
+            call("random::get-random-u64", 1),
+            completed(11),
+        ]);
+        let right = indexed(vec![
+            invoked("k1", "api.{get}", vec![]),
+            completed(10),
+            invoked("k2", "api.{get}", vec![]),
+            call("random::get-random-u64", 2),
+            completed(12),
+        ]);
+
+        let diff = OplogDiff::between(&left, &right, &[]);
+        let divergence = diff.divergence.unwrap();
+
+        assert_eq!(diff.matching_invocations, 1);
+        assert_eq!(divergence.invocation, 1);
+        assert_eq!(divergence.kind, OplogDivergenceKind::ImportedFunction);
+        assert_eq!(divergence.left_oplog_index, Some(4));
+        assert_eq!(divergence.right_oplog_index, Some(4));
+    }
+
+    #[test]
+    fn ignored_imports_are_not_compared() {
+        let left = indexed(vec![
+            invoked("k1", "api.{get}", vec![]),
+            call("wall-clock::now", 1),
+            completed(10),
+        ]);
+        let right = indexed(vec![
+            invoked("k1", "api.{get}", vec![]),
+            call("wall-clock::now", 2),
+            completed(10),
+        ]);
+
+        let diff = OplogDiff::between(&left, &right, &["wall-clock::now".to_string()]);
+
+        assert_eq!(diff.divergence, None);
+    }
+
+    #[test]
+    fn invocations_are_aligned_by_idempotency_key() {
+        let left = indexed(vec![
+            invoked("k1", "api.{add}", vec![number(1)]),
+            completed(1),
+            invoked("k2", "api.{add}", vec![number(2)]),
+            completed(3),
+        ]);
+        let right = indexed(vec![
+            invoked("k2", "api.{add}", vec![number(2)]),
+            completed(3),
+            invoked("k1", "api.{add}", vec![number(1)]),
+            completed(1),
+        ]);
+
+        let diff = OplogDiff::between(&left, &right, &[]);
+
+        assert_eq!(diff.matching_invocations, 2);
+        assert_eq!(diff.divergence, None);
+    }
+
+    #[test]
+    fn independent_invocations_are_aligned_by_position() {
+        let left = indexed(vec![
+            invoked("a1", "api.{add}", vec![number(1)]),
+            completed(1),
+        ]);
+        let right = indexed(vec![
+            invoked("b1", "api.{add}", vec![number(2)]),
+            completed(2),
+            invoked("b2", "api.{add}", vec![number(3)]),
+        ]);
+
+        let diff = OplogDiff::between(&left, &right, &[]);
+        let divergence = diff.divergence.unwrap();
+
+        assert_eq!(divergence.invocation, 0);
+        assert_eq!(divergence.kind, OplogDivergenceKind::Parameters);
+    }
+
+    #[test]
+    fn missing_invocations_and_failures_are_reported() {
+        let left = indexed(vec![invoked("k1", "api.{get}", vec![]), completed(1)]);
+        let right = indexed(vec![
+            invoked("k1", "api.{get}", vec![]),
+            PublicOplogEntry::Error(ErrorParameters {
+                timestamp: Timestamp::now_utc(),
+                error: "trap".to_string(),
+            }),
+            PublicOplogEntry::Suspend(TimestampParameter {
+                timestamp: Timestamp::now_utc(),
+            }),
+        ]);
+
+        let outcome = OplogDiff::between(&left, &right, &[]).divergence.unwrap();
+        assert_eq!(outcome.kind, OplogDivergenceKind::Outcome);
+        assert_eq!(outcome.right.as_deref(), Some("failed: trap"));
+
+        let extra = indexed(vec![
+            invoked("k1", "api.{get}", vec![]),
+            completed(1),
+            invoked("k2", "api.{get}", vec![]),
+        ]);
+        let missing = OplogDiff::between(&left, &extra, &[]).divergence.unwrap();
+        assert_eq!(missing.kind, OplogDivergenceKind::MissingInvocation);
+        assert_eq!(missing.invocation, 1);
+        assert_eq!(missing.left_idempotency_key, None);
+    }
+}
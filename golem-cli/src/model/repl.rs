@@ -0,0 +1,477 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use crate::model::component::format_function_name;
+use golem_wasm_ast::analysis::{AnalysedExport, AnalysedType};
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use inquire::autocompletion::{Autocomplete, Replacement};
+use inquire::CustomUserError;
+use rib::{BuiltInFunction, Expr, HigherOrderFunction, InferredType, RibInput};
+
+// Name of the variable holding the value of the last expression of a line,
+// when the line also binds variables. It cannot be written in Rib source.
+const RESULT_VARIABLE: &str = "$result";
+
+/// State of an interactive Rib session. Values bound with `let` and user-defined
+/// functions are kept between the lines entered in the REPL.
+#[derive(Clone, Debug, Default)]
+pub struct ReplSession {
+    variables: BTreeMap<String, TypeAnnotatedValue>,
+    functions: Vec<(String, Expr)>,
+}
+
+/// A line entered in the REPL, turned into a Rib program that also returns the values
+/// of the variables it binds.
+#[derive(Clone, Debug)]
+pub struct ReplLine {
+    /// The program to compile and run, or `None` if the line only defines functions
+    pub expr: Option<Expr>,
+    /// Values of the variables bound in earlier lines
+    pub input: RibInput,
+    has_result: bool,
+    bindings: Vec<String>,
+    functions: Vec<(String, Expr)>,
+}
+
+impl ReplSession {
+    pub fn variable_names(&self) -> Vec<String> {
+        self.variables.keys().cloned().collect()
+    }
+
+    pub fn function_names(&self) -> Vec<String> {
+        self.functions
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    pub fn prepare(&self, line: &str) -> Result<ReplLine, String> {
+        let expressions = match Expr::from_text(line)? {
+            Expr::ExprBlock(expressions, _, _) => expressions,
+            expr => vec![expr],
+        };
+
+        let mut functions = vec![];
+        let mut bindings = vec![];
+        let mut bound = HashSet::new();
+        let mut statements = vec![];
+
+        for mut expr in expressions {
+            seed_variable_types(&mut expr, &self.variables, &bound);
+
+            if let Expr::Let(variable_id, _, value, _, _) = &expr {
+                let name = variable_id.name();
+
+                if matches!(value.as_ref(), Expr::Lambda(..)) {
+                    functions.push((name.clone(), expr.clone()));
+                } else {
+                    bindings.retain(|binding| binding != &name);
+                    bindings.push(name.clone());
+                }
+
+                bound.insert(name);
+            }
+
+            statements.push(expr);
+        }
+
+        let mut program = self
+            .functions
+            .iter()
+            .map(|(_, function)| function.clone())
+            .collect::<Vec<_>>();
+
+        let has_result = !matches!(statements.last(), Some(Expr::Let(..)));
+
+        if statements.len() == functions.len() {
+            // Only function definitions: nothing to run, but report the errors
+            // (recursion, unknown functions, wrong number of arguments) right away
+            program.extend(statements);
+            Expr::expr_block(program)
+                .inline_functions()
+                .map_err(|err| err.to_string())?;
+
+            return Ok(ReplLine {
+                expr: None,
+                input: RibInput::default(),
+                has_result: false,
+                bindings: vec![],
+                functions,
+            });
+        }
+
+        if !bindings.is_empty() {
+            let mut outputs = bindings.iter().map(Expr::identifier).collect::<Vec<_>>();
+
+            if has_result {
+                let last = statements.pop().unwrap();
+                statements.push(Expr::let_binding(RESULT_VARIABLE, last));
+                outputs.insert(0, Expr::identifier(RESULT_VARIABLE));
+            }
+
+            statements.push(Expr::tuple(outputs));
+        }
+
+        program.extend(statements);
+
+        let expr = if program.len() == 1 {
+            program.pop().unwrap()
+        } else {
+            Expr::expr_block(program)
+        };
+
+        Ok(ReplLine {
+            expr: Some(expr),
+            input: RibInput::new(self.variables.clone().into_iter().collect()),
+            has_result,
+            bindings,
+            functions,
+        })
+    }
+
+    /// Records the variables and functions defined by an evaluated line and returns
+    /// the value to show to the user
+    pub fn complete(
+        &mut self,
+        line: ReplLine,
+        result: Option<TypeAnnotatedValue>,
+    ) -> Result<Option<TypeAnnotatedValue>, String> {
+        let result = if line.bindings.is_empty() {
+            result
+        } else {
+            let mut values = match result {
+                Some(TypeAnnotatedValue::Tuple(tuple)) => tuple
+                    .value
+                    .into_iter()
+                    .map(|value| value.type_annotated_value)
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or("Missing value in the result of the line".to_string())?,
+                _ => Err("Unexpected result of the line: tuple expected".to_string())?,
+            };
+
+            let result = if line.has_result {
+                Some(values.remove(0))
+            } else {
+                None
+            };
+
+            for (name, value) in line.bindings.into_iter().zip(values) {
+                self.functions.retain(|(function, _)| function != &name);
+                self.variables.insert(name, value);
+            }
+
+            result
+        };
+
+        for (name, function) in line.functions {
+            self.variables.remove(&name);
+            self.functions.retain(|(existing, _)| existing != &name);
+            self.functions.push((name, function));
+        }
+
+        Ok(result)
+    }
+}
+
+// Global identifiers referring to variables of earlier lines get the type of the stored value,
+// up to the point the line binds a variable of the same name.
+// In function bodies, the parameters hide the variables of the same name.
+fn seed_variable_types(
+    expr: &mut Expr,
+    variables: &BTreeMap<String, TypeAnnotatedValue>,
+    bound: &HashSet<String>,
+) {
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_back() {
+        match expr {
+            Expr::Lambda(parameters, body, _, _) => {
+                let mut bound = bound.clone();
+                bound.extend(parameters.iter().map(|parameter| parameter.name.clone()));
+                seed_variable_types(body, variables, &bound);
+            }
+            Expr::Identifier(variable_id, _, _) if variable_id.is_global() => {
+                let name = variable_id.name();
+
+                if !bound.contains(&name) {
+                    if let Some(typ) = variables
+                        .get(&name)
+                        .and_then(|value| AnalysedType::try_from(value).ok())
+                    {
+                        expr.add_infer_type_mut(InferredType::from(typ));
+                    }
+                }
+            }
+            _ => expr.visit_children_mut_bottom_up(&mut queue),
+        }
+    }
+}
+
+/// Tab completion of exported function names, built-in functions, and the variables
+/// and functions of the session
+#[derive(Clone, Debug)]
+pub struct ReplCompletion {
+    candidates: Vec<String>,
+}
+
+impl ReplCompletion {
+    pub fn new(exports: &[AnalysedExport], session: &ReplSession) -> Self {
+        let mut candidates = vec![];
+
+        for export in exports {
+            match export {
+                AnalysedExport::Instance(instance) => {
+                    for function in &instance.functions {
+                        // Resource constructors and methods are called through a resource handle
+                        if !function.name.starts_with('[') {
+                            candidates
+                                .push(format_function_name(Some(&instance.name), &function.name));
+                        }
+                    }
+                }
+                AnalysedExport::Function(function) => {
+                    candidates.push(format_function_name(None, &function.name))
+                }
+            }
+        }
+
+        candidates.extend(
+            BuiltInFunction::all()
+                .iter()
+                .map(|function| function.name().to_string()),
+        );
+        candidates.extend(
+            HigherOrderFunction::all()
+                .iter()
+                .map(|function| function.name().to_string()),
+        );
+        candidates.extend(session.function_names());
+        candidates.extend(session.variable_names());
+
+        candidates.sort();
+        candidates.dedup();
+
+        ReplCompletion { candidates }
+    }
+
+    pub fn suggestions(&self, input: &str) -> Vec<String> {
+        let word = &input[word_start(input)..];
+
+        if word.is_empty() {
+            vec![]
+        } else {
+            self.candidates
+                .iter()
+                .filter(|candidate| candidate.starts_with(word))
+                .cloned()
+                .collect()
+        }
+    }
+
+    pub fn completion(
+        &self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Option<String> {
+        let start = word_start(input);
+
+        let completed = match highlighted_suggestion {
+            Some(suggestion) => suggestion,
+            None => {
+                let suggestions = self.suggestions(input);
+                let first = suggestions.first()?;
+
+                suggestions
+                    .iter()
+                    .fold(first.clone(), |prefix, suggestion| {
+                        prefix
+                            .chars()
+                            .zip(suggestion.chars())
+                            .take_while(|(a, b)| a == b)
+                            .map(|(a, _)| a)
+                            .collect()
+                    })
+            }
+        };
+
+        if completed.len() > input.len() - start {
+            Some(format!("{}{}", &input[..start], completed))
+        } else {
+            None
+        }
+    }
+}
+
+impl Autocomplete for ReplCompletion {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(self.suggestions(input))
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(self.completion(input, highlighted_suggestion))
+    }
+}
+
+// Start of the word being typed at the end of the input
+fn word_start(input: &str) -> usize {
+    input
+        .char_indices()
+        .rfind(|(_, c)| c.is_whitespace() || "(),;[]=+*<>!&|%".contains(*c))
+        .map(|(index, c)| index + c.len_utf8())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use golem_wasm_ast::analysis::{AnalysedExport, AnalysedFunction, AnalysedInstance};
+    use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+    use golem_wasm_rpc::protobuf::{TypeAnnotatedValue as RootTypeAnnotatedValue, TypedTuple};
+
+    use crate::model::repl::{ReplCompletion, ReplSession};
+
+    fn tuple(values: Vec<TypeAnnotatedValue>) -> TypeAnnotatedValue {
+        TypeAnnotatedValue::Tuple(TypedTuple {
+            typ: vec![],
+            value: values
+                .into_iter()
+                .map(|value| RootTypeAnnotatedValue {
+                    type_annotated_value: Some(value),
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn let_bindings_persist_across_lines() {
+        let mut session = ReplSession::default();
+
+        let line = session.prepare("let x: u64 = 1; let y: u64 = 2").unwrap();
+        let expr = line.expr.clone().unwrap();
+        rib::compile(&expr, &vec![]).unwrap();
+
+        let result = session
+            .complete(
+                line,
+                Some(tuple(vec![
+                    TypeAnnotatedValue::U64(1),
+                    TypeAnnotatedValue::U64(2),
+                ])),
+            )
+            .unwrap();
+
+        assert_eq!(result, None);
+        assert_eq!(session.variable_names(), vec!["x", "y"]);
+
+        let line = session.prepare("x + y").unwrap();
+        let output = rib::compile(&line.expr.clone().unwrap(), &vec![]).unwrap();
+
+        assert!(line.input.input.contains_key("x"));
+        assert_eq!(
+            output.global_input_type_info.types.get("y"),
+            Some(&golem_wasm_ast::analysis::analysed_type::u64())
+        );
+    }
+
+    #[test]
+    fn line_with_bindings_returns_its_last_value() {
+        let mut session = ReplSession::default();
+
+        let line = session.prepare("let x: u64 = 1; x").unwrap();
+        rib::compile(&line.expr.clone().unwrap(), &vec![]).unwrap();
+
+        let result = session
+            .complete(
+                line,
+                Some(tuple(vec![
+                    TypeAnnotatedValue::U64(1),
+                    TypeAnnotatedValue::U64(1),
+                ])),
+            )
+            .unwrap();
+
+        assert_eq!(result, Some(TypeAnnotatedValue::U64(1)));
+        assert_eq!(session.variable_names(), vec!["x"]);
+    }
+
+    #[test]
+    fn function_definitions_persist_across_lines() {
+        let mut session = ReplSession::default();
+
+        let line = session.prepare("fn inc(a: u64) { a + 1u64 }").unwrap();
+        assert!(line.expr.is_none());
+        session.complete(line, None).unwrap();
+
+        assert_eq!(session.function_names(), vec!["inc"]);
+
+        let line = session.prepare("inc(41u64)").unwrap();
+        rib::compile(&line.expr.unwrap(), &vec![]).unwrap();
+    }
+
+    #[test]
+    fn invalid_function_definition_is_reported() {
+        let session = ReplSession::default();
+
+        assert!(session.prepare("fn f(a: u64) { f(a) }").is_err());
+    }
+
+    #[test]
+    fn completes_after_multi_byte_whitespace() {
+        let completion = ReplCompletion::new(&[], &ReplSession::default());
+
+        assert_eq!(
+            completion.suggestions("let x =\u{3000}to-up"),
+            vec!["to-upper"]
+        );
+    }
+
+    #[test]
+    fn completes_exported_functions() {
+        let exports = vec![AnalysedExport::Instance(AnalysedInstance {
+            name: "golem:it/api".to_string(),
+            functions: vec![
+                AnalysedFunction {
+                    name: "add-item".to_string(),
+                    parameters: vec![],
+                    results: vec![],
+                },
+                AnalysedFunction {
+                    name: "[constructor]cart".to_string(),
+                    parameters: vec![],
+                    results: vec![],
+                },
+            ],
+        })];
+
+        let completion = ReplCompletion::new(&exports, &ReplSession::default());
+
+        assert_eq!(
+            completion.suggestions("let x = golem:it"),
+            vec!["golem:it/api.{add-item}"]
+        );
+        assert_eq!(
+            completion.completion("let x = golem:it", None),
+            Some("let x = golem:it/api.{add-item}".to_string())
+        );
+        assert!(completion.suggestions("").is_empty());
+    }
+}
@@ -700,6 +700,7 @@ pub mod profile {
 pub mod worker {
     use crate::model::deploy::TryUpdateAllWorkersResult;
     use crate::model::invoke_result_view::InvokeResultView;
+    use crate::model::oplog_diff::{OplogDiff, OplogDivergenceKind};
     use crate::model::text::fmt::*;
     use crate::model::{
         IdempotencyKey, WorkerMetadata, WorkerMetadataView, WorkersMetadataResponseView,
@@ -709,7 +710,7 @@ pub mod worker {
     use chrono::{DateTime, Utc};
     use cli_table::{format::Justify, Table};
     use colored::Colorize;
-    use golem_client::model::PublicOplogEntry;
+    use golem_client::model::{PublicOplogEntry, ScheduledInvocationInfo};
     use golem_common::model::public_oplog::{PublicUpdateDescription, PublicWorkerInvocation};
     use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
     use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -814,7 +815,25 @@ pub mod worker {
                 )
                 .fmt_field_option("Last error", &self.0.last_error, |err| {
                     format_stack(err.as_ref())
-                });
+                })
+                .fmt_field_optional(
+                    "Scheduled invocations",
+                    &self.0.scheduled_invocations,
+                    !self.0.scheduled_invocations.is_empty(),
+                    |invocations| {
+                        invocations
+                            .iter()
+                            .map(|invocation| {
+                                format!(
+                                    "{} ({}, next at {})",
+                                    invocation.name.bold(),
+                                    invocation.function_name,
+                                    invocation.next_fire_at
+                                )
+                            })
+                            .join("\n")
+                    },
+                );
 
             fields.build()
         }
@@ -872,6 +891,45 @@ pub mod worker {
         }
     }
 
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ScheduledInvocationsView(pub Vec<ScheduledInvocationInfo>);
+
+    #[derive(Table)]
+    struct ScheduledInvocationTableView {
+        #[table(title = "Name")]
+        pub name: String,
+        #[table(title = "Function")]
+        pub function_name: String,
+        #[table(title = "Recurrence")]
+        pub recurrence: String,
+        #[table(title = "Next fire at")]
+        pub next_fire_at: DateTime<Utc>,
+        #[table(title = "Failed attempts")]
+        pub failed_attempts: u32,
+    }
+
+    impl From<&ScheduledInvocationInfo> for ScheduledInvocationTableView {
+        fn from(value: &ScheduledInvocationInfo) -> Self {
+            Self {
+                name: value.name.clone(),
+                function_name: value.function_name.clone(),
+                recurrence: value.recurrence.to_string(),
+                next_fire_at: value.next_fire_at,
+                failed_attempts: value.failed_attempts,
+            }
+        }
+    }
+
+    impl TextFormat for ScheduledInvocationsView {
+        fn print(&self) {
+            if self.0.is_empty() {
+                println!("No scheduled invocations.")
+            } else {
+                print_table::<_, ScheduledInvocationTableView>(&self.0);
+            }
+        }
+    }
+
     impl TextFormat for IdempotencyKey {
         fn print(&self) {
             printdoc!(
@@ -962,6 +1020,61 @@ pub mod worker {
         }
     }
 
+    impl TextFormat for OplogDiff {
+        fn print(&self) {
+            match &self.divergence {
+                None => println!(
+                    "No divergence found in {} invocations.",
+                    format_id(&self.matching_invocations)
+                ),
+                Some(divergence) => {
+                    let kind = match divergence.kind {
+                        OplogDivergenceKind::MissingInvocation => "invocation only in one worker",
+                        OplogDivergenceKind::FunctionName => "different functions invoked",
+                        OplogDivergenceKind::Parameters => "different invocation parameters",
+                        OplogDivergenceKind::ImportedFunction => "different imported function call",
+                        OplogDivergenceKind::Outcome => "different invocation outcome",
+                    };
+                    println!(
+                        "{} after {} matching invocations: {}",
+                        format_warn("Oplogs diverge"),
+                        format_id(&self.matching_invocations),
+                        format_message_highlight(kind)
+                    );
+
+                    let side = |name: &str,
+                                idempotency_key: &Option<golem_common::model::IdempotencyKey>,
+                                oplog_index: &Option<u64>,
+                                value: &Option<String>| {
+                        println!("{name}:");
+                        if let Some(oplog_index) = oplog_index {
+                            println!(
+                                "  at:              {}",
+                                format_main_id(&format!("#{oplog_index:0>5}"))
+                            );
+                        }
+                        if let Some(idempotency_key) = idempotency_key {
+                            println!("  idempotency key: {}", format_id(&idempotency_key.value));
+                        }
+                        println!("  value:           {}", value.as_deref().unwrap_or("-"));
+                    };
+                    side(
+                        "Left",
+                        &divergence.left_idempotency_key,
+                        &divergence.left_oplog_index,
+                        &divergence.left,
+                    );
+                    side(
+                        "Right",
+                        &divergence.right_idempotency_key,
+                        &divergence.right_oplog_index,
+                        &divergence.right,
+                    );
+                }
+            }
+        }
+    }
+
     impl TextFormat for Vec<(u64, PublicOplogEntry)> {
         fn print(&self) {
             for (idx, entry) in self {
@@ -1215,6 +1328,22 @@ pub mod worker {
                     println!("{}", format_message_highlight("RESTART"));
                     println!("{pad}at:                {}", format_id(&params.timestamp));
                 }
+                PublicOplogEntry::CancelPendingInvocation(params) => {
+                    println!("{}", format_message_highlight("CANCEL PENDING INVOCATION"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!(
+                        "{pad}idempotency key:   {}",
+                        format_id(&params.idempotency_key)
+                    );
+                }
+                PublicOplogEntry::Snapshot(params) => {
+                    println!("{}", format_message_highlight("SNAPSHOT"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!(
+                        "{pad}snapshot:          {}",
+                        BASE64_STANDARD.encode(&params.data)
+                    );
+                }
             }
         }
     }
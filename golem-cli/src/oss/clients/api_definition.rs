@@ -19,6 +19,7 @@ use std::io::Read;
 use async_trait::async_trait;
 use golem_client::model::HttpApiDefinitionRequest;
 use golem_client::model::HttpApiDefinitionWithTypeInfo;
+use golem_client::model::{ApiDefinitionTestRequest, ApiDefinitionTestResult};
 use serde::Deserialize;
 
 use crate::clients::api_definition::ApiDefinitionClient;
@@ -180,4 +181,31 @@ impl<C: golem_client::api::ApiDefinitionClient + Sync + Send> ApiDefinitionClien
             .delete_definition(id.0.as_str(), version.0.as_str())
             .await?)
     }
+
+    async fn export(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        _project: &Self::ProjectContext,
+    ) -> Result<serde_json::Value, GolemError> {
+        info!("Exporting api definition for {}/{}", id.0, version.0);
+        Ok(self
+            .client
+            .export_definition(id.0.as_str(), version.0.as_str())
+            .await?)
+    }
+
+    async fn test(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        request: ApiDefinitionTestRequest,
+        _project: &Self::ProjectContext,
+    ) -> Result<ApiDefinitionTestResult, GolemError> {
+        info!("Testing api definition {}/{}", id.0, version.0);
+        Ok(self
+            .client
+            .test_definition(id.0.as_str(), version.0.as_str(), &request)
+            .await?)
+    }
 }
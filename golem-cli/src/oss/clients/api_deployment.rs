@@ -61,6 +61,7 @@ impl<C: golem_client::api::ApiDeploymentClient + Sync + Send> ApiDeploymentClien
                 host: host.to_string(),
                 subdomain,
             },
+            certificate: None,
         };
 
         Ok(self.client.deploy(&deployment).await?.into())
@@ -112,6 +112,7 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
                         file,
                         files_permissions,
                         files_archive_file,
+                        None,
                     )
                     .await?
             }
@@ -129,6 +130,7 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
                         bytes,
                         files_permissions,
                         files_archive_file,
+                        None,
                     )
                     .await?
             }
@@ -170,6 +172,7 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
                         file,
                         files_permissions,
                         files_archive_file,
+                        None,
                     )
                     .await?
             }
@@ -187,6 +190,7 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
                         bytes,
                         files_permissions,
                         files_archive_file,
+                        None,
                     )
                     .await?
             }
@@ -22,9 +22,10 @@ use golem_client::model::{
     GolemErrorGetLatestVersionOfComponentFailed, GolemErrorInitialComponentFileDownloadFailed,
     GolemErrorInterrupted, GolemErrorInvalidRequest, GolemErrorInvalidShardId,
     GolemErrorPromiseAlreadyCompleted, GolemErrorPromiseDropped, GolemErrorPromiseNotFound,
-    GolemErrorRuntimeError, GolemErrorUnexpectedOplogEntry, GolemErrorUnknown,
-    GolemErrorValueMismatch, GolemErrorWorkerAlreadyExists, GolemErrorWorkerCreationFailed,
-    GolemErrorWorkerNotFound, PromiseId, WorkerId, WorkerServiceErrorsBody,
+    GolemErrorResourceLimitExceeded, GolemErrorRuntimeError, GolemErrorUnexpectedOplogEntry,
+    GolemErrorUnknown, GolemErrorValueMismatch, GolemErrorWorkerAlreadyExists,
+    GolemErrorWorkerCreationFailed, GolemErrorWorkerNotFound, PromiseId, WorkerId,
+    WorkerServiceErrorsBody,
 };
 use itertools::Itertools;
 
@@ -202,6 +203,9 @@ fn display_golem_error(error: GolemError) -> String {
         GolemError::FileSystemError(GolemErrorFileSystemError { path, reason, .. }) => {
             format!("Error working with file {}: {}", path, reason)
         }
+        GolemError::ResourceLimitExceeded(GolemErrorResourceLimitExceeded { details }) => {
+            format!("Resource limit exceeded: {}", details)
+        }
     }
 }
 
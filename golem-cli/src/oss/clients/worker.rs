@@ -22,15 +22,17 @@ use crate::model::{
     WorkersMetadataResponse,
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use futures_util::{future, pin_mut, SinkExt, StreamExt};
 use golem_client::api::WorkerError;
 use golem_client::model::{
-    InvokeParameters, InvokeResult, ScanCursor, UpdateWorkerRequest, WorkerCreationRequest,
-    WorkerFilter, WorkerId, WorkersMetadataRequest,
+    ForkWorkerRequest, InvokeParameters, InvokeResult, ScanCursor, ScheduleInvocationParameters,
+    ScheduledInvocationInfo, UpdateWorkerRequest, WorkerCreationRequest, WorkerFilter, WorkerId,
+    WorkersMetadataRequest,
 };
 use golem_client::{Context, Error};
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
-use golem_common::model::WorkerEvent;
+use golem_common::model::{RevertWorkerTarget, WorkerEvent};
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
 use native_tls::TlsConnector;
 use tokio::{task, time};
@@ -110,6 +112,7 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
         function: String,
         parameters: InvokeParameters,
         idempotency_key: Option<IdempotencyKey>,
+        schedule_at: Option<DateTime<Utc>>,
     ) -> Result<(), GolemError> {
         info!("Invoke function {function} in {worker_urn}");
 
@@ -121,6 +124,7 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                     worker_name,
                     idempotency_key.as_ref().map(|k| k.0.as_str()),
                     &function,
+                    schedule_at.as_ref(),
                     &parameters,
                 )
                 .await?;
@@ -131,6 +135,7 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                     &worker_urn.id.component_id.0,
                     idempotency_key.as_ref().map(|k| k.0.as_str()),
                     &function,
+                    schedule_at.as_ref(),
                     &parameters,
                 )
                 .await?;
@@ -479,6 +484,73 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
         Ok(())
     }
 
+    async fn fork(
+        &self,
+        worker_urn: WorkerUrn,
+        target_worker_name: WorkerName,
+        oplog_index_cutoff: u64,
+    ) -> Result<(), GolemError> {
+        info!("Forking worker {worker_urn} at oplog index {oplog_index_cutoff}");
+
+        let _ = self
+            .client
+            .fork_worker(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &ForkWorkerRequest {
+                    target_worker_name: target_worker_name.0,
+                    oplog_index_cutoff,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn revert(
+        &self,
+        worker_urn: WorkerUrn,
+        target: RevertWorkerTarget,
+    ) -> Result<(), GolemError> {
+        info!("Reverting worker {worker_urn}");
+
+        let _ = self
+            .client
+            .revert_worker(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &target,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn export(&self, worker_urn: WorkerUrn) -> Result<Vec<u8>, GolemError> {
+        info!("Exporting worker {worker_urn}");
+
+        let archive = self
+            .client
+            .export_worker(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+            )
+            .await?;
+        Ok(archive.to_vec())
+    }
+
+    async fn import(&self, worker_urn: WorkerUrn, archive: Vec<u8>) -> Result<(), GolemError> {
+        info!("Importing worker {worker_urn}");
+
+        let _ = self
+            .client
+            .import_worker(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                archive,
+            )
+            .await?;
+        Ok(())
+    }
+
     async fn get_oplog(
         &self,
         worker_urn: WorkerUrn,
@@ -556,6 +628,81 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
 
         Ok(entries)
     }
+
+    async fn create_scheduled_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        parameters: ScheduleInvocationParameters,
+    ) -> Result<ScheduledInvocationInfo, GolemError> {
+        info!(
+            "Creating scheduled invocation {} of {worker_urn}",
+            parameters.name
+        );
+
+        Ok(self
+            .client
+            .create_scheduled_invocation(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &parameters,
+            )
+            .await?)
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_urn: WorkerUrn,
+    ) -> Result<Vec<ScheduledInvocationInfo>, GolemError> {
+        info!("Getting scheduled invocations of {worker_urn}");
+
+        let response = self
+            .client
+            .get_scheduled_invocations(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+            )
+            .await?;
+        Ok(response.invocations)
+    }
+
+    async fn update_scheduled_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        parameters: ScheduleInvocationParameters,
+    ) -> Result<ScheduledInvocationInfo, GolemError> {
+        info!(
+            "Updating scheduled invocation {} of {worker_urn}",
+            parameters.name
+        );
+
+        Ok(self
+            .client
+            .update_scheduled_invocation(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &parameters.name,
+                &parameters,
+            )
+            .await?)
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        name: String,
+    ) -> Result<(), GolemError> {
+        info!("Deleting scheduled invocation {name} of {worker_urn}");
+
+        let _ = self
+            .client
+            .delete_scheduled_invocation(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &name,
+            )
+            .await?;
+        Ok(())
+    }
 }
 
 fn get_worker_golem_error(status: u16, body: Vec<u8>) -> GolemError {
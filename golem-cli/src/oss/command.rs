@@ -16,6 +16,7 @@ use crate::command::api_definition::ApiDefinitionSubcommand;
 use crate::command::api_deployment::ApiDeploymentSubcommand;
 use crate::command::component::ComponentSubCommand;
 use crate::command::profile::ProfileSubCommand;
+use crate::command::repl::ReplCommand;
 use crate::command::worker::{OssWorkerUriArg, WorkerSubcommand};
 use crate::completion;
 use crate::completion::PrintCompletion;
@@ -44,6 +45,16 @@ pub enum OssCommand<ProfileAdd: clap::Args> {
         subcommand: WorkerSubcommand<ComponentUriArg, OssWorkerUriArg>,
     },
 
+    /// Start an interactive Rib session calling the functions of a component's worker
+    ///
+    /// Each line is compiled against the component's exports and `let` bindings are kept
+    /// between lines. Press tab to complete function names.
+    #[command()]
+    Repl {
+        #[command(flatten)]
+        command: ReplCommand<ComponentUriArg>,
+    },
+
     /// Get resource by URI
     ///
     /// Use resource URN or URL to get resource metadata.
@@ -68,6 +68,16 @@ pub async fn async_main<ProfileAdd: Into<UniversalProfileAdd> + clap::Args>(
                 .handle(format, factory.worker_service(), factory.project_resolver())
                 .await
         }
+        OssCommand::Repl { command } => {
+            let factory = factory().await?;
+
+            command
+                .handle(
+                    factory.repl_service().as_ref(),
+                    factory.project_resolver().as_ref(),
+                )
+                .await
+        }
         OssCommand::Examples(golem_examples::cli::Command::New {
             name_or_language,
             package_name,
@@ -17,5 +17,6 @@ pub mod api_deployment;
 pub mod component;
 pub mod deploy;
 pub mod project;
+pub mod repl;
 pub mod version;
 pub mod worker;
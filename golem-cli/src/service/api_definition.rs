@@ -21,6 +21,7 @@ use crate::model::{
     PathBufOrStdin,
 };
 use async_trait::async_trait;
+use golem_client::model::ApiDefinitionTestRequest;
 
 #[async_trait]
 pub trait ApiDefinitionService {
@@ -60,6 +61,19 @@ pub trait ApiDefinitionService {
         version: ApiDefinitionVersion,
         project: &Self::ProjectContext,
     ) -> Result<GolemResult, GolemError>;
+    async fn export(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError>;
+    async fn test(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        request: ApiDefinitionTestRequest,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError>;
 }
 
 pub struct ApiDefinitionServiceLive<ProjectContext> {
@@ -134,4 +148,26 @@ impl<ProjectContext: Send + Sync> ApiDefinitionService
         let result = self.client.delete(id, version, project).await?;
         Ok(GolemResult::Str(result))
     }
+
+    async fn export(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError> {
+        let open_api = self.client.export(id, version, project).await?;
+        Ok(GolemResult::Json(open_api))
+    }
+
+    async fn test(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        request: ApiDefinitionTestRequest,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError> {
+        let result = self.client.test(id, version, request, project).await?;
+        let json = serde_json::to_value(result).map_err(|err| GolemError(err.to_string()))?;
+        Ok(GolemResult::Json(json))
+    }
 }
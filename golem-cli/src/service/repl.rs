@@ -0,0 +1,163 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::worker::WorkerClient;
+use crate::model::component::Component;
+use crate::model::repl::{ReplCompletion, ReplSession};
+use crate::model::{GolemError, GolemResult, WorkerName};
+use crate::service::component::ComponentService;
+use crate::service::worker::get_component_metadata_for_worker;
+use async_trait::async_trait;
+use colored::Colorize;
+use futures_util::FutureExt;
+use golem_client::model::InvokeParameters;
+use golem_common::model::TargetWorkerId;
+use golem_common::uri::oss::uri::ComponentUri;
+use golem_common::uri::oss::urn::WorkerUrn;
+use golem_wasm_rpc::json::TypeAnnotatedValueJsonExtensions;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use golem_wasm_rpc::type_annotated_value_to_string;
+use inquire::{InquireError, Text};
+use rib::RibFunctionInvoke;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait ReplService {
+    type ProjectContext: Send + Sync;
+
+    async fn start(
+        &self,
+        component_uri: ComponentUri,
+        worker_name: Option<WorkerName>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+}
+
+pub struct ReplServiceLive<ProjectContext> {
+    pub client: Arc<dyn WorkerClient + Send + Sync>,
+    pub components: Arc<dyn ComponentService<ProjectContext = ProjectContext> + Send + Sync>,
+}
+
+#[async_trait]
+impl<ProjectContext: Send + Sync> ReplService for ReplServiceLive<ProjectContext> {
+    type ProjectContext = ProjectContext;
+
+    async fn start(
+        &self,
+        component_uri: ComponentUri,
+        worker_name: Option<WorkerName>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let component_urn = self.components.resolve_uri(component_uri, &project).await?;
+
+        let worker_urn = WorkerUrn {
+            id: TargetWorkerId {
+                component_id: component_urn.id,
+                worker_name: worker_name.map(|WorkerName(name)| name),
+            },
+        };
+
+        // Using the component version of the worker if it already exists
+        let component = get_component_metadata_for_worker(
+            self.client.as_ref(),
+            self.components.as_ref(),
+            &worker_urn,
+        )
+        .await?;
+
+        let invoke = worker_invoke(self.client.clone(), worker_urn);
+        let mut session = ReplSession::default();
+
+        loop {
+            let completion = ReplCompletion::new(&component.metadata.exports, &session);
+
+            // Reading the input blocks, so it must not happen on a runtime worker thread
+            let input = tokio::task::spawn_blocking(move || {
+                Text::new(">>>").with_autocomplete(completion).prompt()
+            })
+            .await
+            .map_err(|err| GolemError(format!("Failed to read input: {err}")))?;
+
+            let line = match input {
+                Ok(line) => line,
+                Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => break,
+                Err(err) => return Err(GolemError(format!("Failed to read input: {err}"))),
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match evaluate(&mut session, &component, invoke.clone(), &line).await {
+                Ok(Some(value)) => println!("{}", format_value(&value)),
+                Ok(None) => {}
+                Err(err) => println!("{}", err.red()),
+            }
+        }
+
+        Ok(GolemResult::Str("".to_string()))
+    }
+}
+
+async fn evaluate(
+    session: &mut ReplSession,
+    component: &Component,
+    invoke: RibFunctionInvoke,
+    line: &str,
+) -> Result<Option<TypeAnnotatedValue>, String> {
+    let line = session.prepare(line)?;
+
+    let result = match &line.expr {
+        Some(expr) => {
+            let compiled =
+                rib::compile(expr, &component.metadata.exports).map_err(|err| err.to_string())?;
+
+            rib::interpret(&compiled.byte_code, &line.input, invoke)
+                .await?
+                .get_val()
+        }
+        None => None,
+    };
+
+    session.complete(line, result)
+}
+
+// Function calls go to the chosen worker, or to new ephemeral workers if no worker name is given
+fn worker_invoke(
+    client: Arc<dyn WorkerClient + Send + Sync>,
+    worker_urn: WorkerUrn,
+) -> RibFunctionInvoke {
+    Arc::new(move |function_name, parameters| {
+        let client = client.clone();
+        let worker_urn = worker_urn.clone();
+
+        async move {
+            client
+                .invoke_and_await(
+                    worker_urn,
+                    function_name,
+                    InvokeParameters { params: parameters },
+                    None,
+                )
+                .await
+                .map(|result| result.result)
+                .map_err(|err| err.to_string())
+        }
+        .boxed()
+    })
+}
+
+fn format_value(value: &TypeAnnotatedValue) -> String {
+    type_annotated_value_to_string(value).unwrap_or_else(|_| value.to_json_value().to_string())
+}
@@ -19,15 +19,23 @@ use crate::model::component::{
 };
 use crate::model::deploy::TryUpdateAllWorkersResult;
 use crate::model::invoke_result_view::InvokeResultView;
-use crate::model::text::worker::{WorkerAddView, WorkerGetView};
+use crate::model::oplog_diff::OplogDiff;
+use crate::model::text::worker::{ScheduledInvocationsView, WorkerAddView, WorkerGetView};
 use crate::model::{
     Format, GolemError, GolemResult, IdempotencyKey, WorkerMetadata, WorkerName, WorkerUpdateMode,
     WorkersMetadataResponseView,
 };
 use crate::service::component::ComponentService;
 use async_trait::async_trait;
-use golem_client::model::{AnalysedType, InvokeParameters, InvokeResult, ScanCursor, WorkerFilter};
-use golem_common::model::{StringFilterComparator, TargetWorkerId, WorkerNameFilter};
+use chrono::{DateTime, Utc};
+use golem_client::model::{
+    AnalysedType, InvokeParameters, InvokeResult, ScanCursor, ScheduleInvocationParameters,
+    WorkerFilter,
+};
+use golem_common::model::scheduled_invocation::Recurrence;
+use golem_common::model::{
+    RevertWorkerTarget, StringFilterComparator, TargetWorkerId, WorkerNameFilter,
+};
 use golem_common::uri::oss::uri::{ComponentUri, WorkerUri};
 use golem_common::uri::oss::url::{ComponentUrl, WorkerUrl};
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
@@ -37,6 +45,7 @@ use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::type_annotated_value_from_str;
 use itertools::Itertools;
 use serde_json::Value;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::task::JoinHandle;
 use tracing::{error, info};
@@ -92,6 +101,7 @@ pub trait WorkerService {
         function: String,
         parameters: Option<Value>,
         wave: Vec<String>,
+        schedule_at: Option<DateTime<Utc>>,
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError>;
 
@@ -191,6 +201,35 @@ pub trait WorkerService {
         precise: Option<bool>,
     ) -> Result<Vec<WorkerMetadata>, GolemError>;
 
+    async fn fork(
+        &self,
+        worker_uri: WorkerUri,
+        target_worker_name: WorkerName,
+        oplog_index_cutoff: u64,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn revert(
+        &self,
+        worker_uri: WorkerUri,
+        target: RevertWorkerTarget,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn export(
+        &self,
+        worker_uri: WorkerUri,
+        output: PathBuf,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn import(
+        &self,
+        worker_uri: WorkerUri,
+        archive: PathBuf,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
     async fn get_oplog(
         &self,
         worker_uri: WorkerUri,
@@ -204,6 +243,51 @@ pub trait WorkerService {
         query: String,
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError>;
+
+    async fn oplog_diff(
+        &self,
+        worker_uri: WorkerUri,
+        other_worker_name: WorkerName,
+        ignored_imports: Vec<String>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn create_schedule(
+        &self,
+        worker_uri: WorkerUri,
+        name: String,
+        function: String,
+        parameters: Option<Value>,
+        wave: Vec<String>,
+        recurrence: Recurrence,
+        start_at: Option<DateTime<Utc>>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn list_schedules(
+        &self,
+        worker_uri: WorkerUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn update_schedule(
+        &self,
+        worker_uri: WorkerUri,
+        name: String,
+        function: String,
+        parameters: Option<Value>,
+        wave: Vec<String>,
+        recurrence: Recurrence,
+        start_at: Option<DateTime<Utc>>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn delete_schedule(
+        &self,
+        worker_uri: WorkerUri,
+        name: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
 }
 
 pub struct WorkerServiceLive<ProjectContext: Send + Sync> {
@@ -265,7 +349,7 @@ fn parse_parameter(wave: &str, typ: &AnalysedType) -> Result<TypeAnnotatedValue,
     }
 }
 
-async fn get_component_metadata_for_worker<ProjectContext: Send + Sync>(
+pub(crate) async fn get_component_metadata_for_worker<ProjectContext: Send + Sync>(
     client: &(dyn WorkerClient + Send + Sync),
     components: &(dyn ComponentService<ProjectContext = ProjectContext> + Send + Sync),
     worker_urn: &WorkerUrn,
@@ -546,6 +630,7 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         function: String,
         parameters: Option<Value>,
         wave: Vec<String>,
+        schedule_at: Option<DateTime<Utc>>,
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError> {
         let worker_urn = self.resolve_uri(worker_uri, project).await?;
@@ -566,10 +651,15 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
                 function,
                 InvokeParameters { params: parameters },
                 idempotency_key,
+                schedule_at,
             )
             .await?;
 
-        Ok(GolemResult::Str("Invoked".to_string()))
+        if schedule_at.is_some() {
+            Ok(GolemResult::Str("Scheduled".to_string()))
+        } else {
+            Ok(GolemResult::Str("Invoked".to_string()))
+        }
     }
 
     async fn connect(
@@ -836,6 +926,73 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         Ok(workers)
     }
 
+    async fn fork(
+        &self,
+        worker_uri: WorkerUri,
+        target_worker_name: WorkerName,
+        oplog_index_cutoff: u64,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client
+            .fork(worker_urn, target_worker_name, oplog_index_cutoff)
+            .await?;
+
+        Ok(GolemResult::Str("Forked".to_string()))
+    }
+
+    async fn revert(
+        &self,
+        worker_uri: WorkerUri,
+        target: RevertWorkerTarget,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client.revert(worker_urn, target).await?;
+
+        Ok(GolemResult::Str("Reverted".to_string()))
+    }
+
+    async fn export(
+        &self,
+        worker_uri: WorkerUri,
+        output: PathBuf,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let archive = self.client.export(worker_urn).await?;
+        tokio::fs::write(&output, archive).await.map_err(|err| {
+            GolemError(format!(
+                "Failed to write worker archive to {}: {err}",
+                output.display()
+            ))
+        })?;
+
+        Ok(GolemResult::Str("Exported".to_string()))
+    }
+
+    async fn import(
+        &self,
+        worker_uri: WorkerUri,
+        archive: PathBuf,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let archive = tokio::fs::read(&archive).await.map_err(|err| {
+            GolemError(format!(
+                "Failed to read worker archive from {}: {err}",
+                archive.display()
+            ))
+        })?;
+        self.client.import(worker_urn, archive).await?;
+
+        Ok(GolemResult::Str("Imported".to_string()))
+    }
+
     async fn get_oplog(
         &self,
         worker_uri: WorkerUri,
@@ -859,4 +1016,140 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         let entries = self.client.search_oplog(worker_urn, query).await?;
         Ok(GolemResult::Ok(Box::new(entries)))
     }
+
+    async fn oplog_diff(
+        &self,
+        worker_uri: WorkerUri,
+        other_worker_name: WorkerName,
+        ignored_imports: Vec<String>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+        let other_worker_urn = WorkerUrn {
+            id: TargetWorkerId {
+                component_id: worker_urn.id.component_id.clone(),
+                worker_name: Some(other_worker_name.0),
+            },
+        };
+
+        let left = self.client.get_oplog(worker_urn, 0).await?;
+        let right = self.client.get_oplog(other_worker_urn, 0).await?;
+
+        let diff = OplogDiff::between(&left, &right, &ignored_imports);
+        Ok(GolemResult::Ok(Box::new(diff)))
+    }
+
+    async fn create_schedule(
+        &self,
+        worker_uri: WorkerUri,
+        name: String,
+        function: String,
+        parameters: Option<Value>,
+        wave: Vec<String>,
+        recurrence: Recurrence,
+        start_at: Option<DateTime<Utc>>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let (params, _) = resolve_parameters(
+            self.client.as_ref(),
+            self.components.as_ref(),
+            &worker_urn,
+            parameters,
+            wave,
+            &function,
+        )
+        .await?;
+
+        let info = self
+            .client
+            .create_scheduled_invocation(
+                worker_urn,
+                ScheduleInvocationParameters {
+                    name,
+                    function_name: function,
+                    params,
+                    recurrence,
+                    start_at,
+                },
+            )
+            .await?;
+
+        Ok(GolemResult::Str(format!(
+            "Scheduled {}, first invocation at {}",
+            info.name, info.next_fire_at
+        )))
+    }
+
+    async fn list_schedules(
+        &self,
+        worker_uri: WorkerUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let invocations = self.client.get_scheduled_invocations(worker_urn).await?;
+        Ok(GolemResult::Ok(Box::new(ScheduledInvocationsView(
+            invocations,
+        ))))
+    }
+
+    async fn update_schedule(
+        &self,
+        worker_uri: WorkerUri,
+        name: String,
+        function: String,
+        parameters: Option<Value>,
+        wave: Vec<String>,
+        recurrence: Recurrence,
+        start_at: Option<DateTime<Utc>>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let (params, _) = resolve_parameters(
+            self.client.as_ref(),
+            self.components.as_ref(),
+            &worker_urn,
+            parameters,
+            wave,
+            &function,
+        )
+        .await?;
+
+        let info = self
+            .client
+            .update_scheduled_invocation(
+                worker_urn,
+                ScheduleInvocationParameters {
+                    name,
+                    function_name: function,
+                    params,
+                    recurrence,
+                    start_at,
+                },
+            )
+            .await?;
+
+        Ok(GolemResult::Str(format!(
+            "Updated {}, next invocation at {}",
+            info.name, info.next_fire_at
+        )))
+    }
+
+    async fn delete_schedule(
+        &self,
+        worker_uri: WorkerUri,
+        name: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client
+            .delete_scheduled_invocation(worker_urn, name)
+            .await?;
+
+        Ok(GolemResult::Str("Deleted".to_string()))
+    }
 }
@@ -149,6 +149,22 @@ fn make(r: &mut DynamicTestRegistration, suffix: &'static str, name: &'static st
             api_definition_get((deps, name.to_string(), cli.with_args(short)))
         }
     );
+    add_test!(
+        r,
+        format!("api_definition_export{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            api_definition_export((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
+    add_test!(
+        r,
+        format!("api_definition_test{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            api_definition_test((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
     add_test!(
         r,
         format!("api_definition_delete{suffix}"),
@@ -208,6 +224,8 @@ fn golem_def_with_response(
                 idempotency_key: None,
                 response: Some(response),
                 middleware: None,
+                traffic_split: None,
+                response_cache: None,
                 allow_origin: None,
                 allow_methods: None,
                 allow_headers: None,
@@ -387,6 +405,8 @@ pub fn to_api_definition_with_type_info(
                         idempotency_key_input: None,
                         binding_type: Some(GatewayBindingType::Default),
                         cors_preflight: None,
+                        traffic_split: None,
+                        response_cache: None,
                     },
                 }
             })
@@ -642,6 +662,121 @@ fn api_definition_get(
     Ok(())
 }
 
+fn api_definition_export(
+    (deps, name, cli): (
+        &(impl TestDependencies + Send + Sync + 'static),
+        String,
+        CliLive,
+    ),
+) -> anyhow::Result<()> {
+    let component_name = format!("api_definition_export{name}");
+    let component = make_shopping_cart_component(deps, &component_name, &cli)?;
+    let component_id = component.component_urn.id.0.to_string();
+    let def = native_api_definition_request(&component_name, &component_id);
+    let path = make_json_file(&def.id, &def)?;
+
+    let _: HttpApiDefinitionWithTypeInfo =
+        cli.run(&["api-definition", "add", path.to_str().unwrap()])?;
+
+    let cfg = &cli.config;
+
+    let res: serde_json::Value = cli.run(&[
+        "api-definition",
+        "export",
+        &cfg.arg('i', "id"),
+        &component_name,
+        &cfg.arg('V', "version"),
+        "0.1.0",
+    ])?;
+
+    assert_eq!(res["x-golem-api-definition-id"], json!(component_name));
+    assert_eq!(res["x-golem-api-definition-version"], json!("0.1.0"));
+
+    let operation = &res["paths"]["/{user-id}/get-cart-contents"]["get"];
+
+    assert_eq!(
+        operation["responses"]["default"]["content"]["application/json"]["schema"],
+        json!({ "type": "string" })
+    );
+    assert_eq!(
+        operation["x-golem-api-gateway-binding"]["component-id"],
+        json!(component_id)
+    );
+
+    // The exported spec can be imported back, once the original definition is deleted
+    cli.run_unit(&[
+        "api-definition",
+        "delete",
+        &cfg.arg('i', "id"),
+        &component_name,
+        &cfg.arg('V', "version"),
+        "0.1.0",
+    ])?;
+
+    let path = make_json_file(&format!("{component_name}-export"), &res)?;
+
+    let imported: HttpApiDefinitionWithTypeInfo =
+        cli.run(&["api-definition", "import", path.to_str().unwrap()])?;
+
+    assert_eq!(imported.id, component_name);
+    assert_eq!(imported.version, "0.1.0");
+    assert_eq!(imported.routes.len(), 1);
+
+    Ok(())
+}
+
+fn api_definition_test(
+    (deps, name, cli): (
+        &(impl TestDependencies + Send + Sync + 'static),
+        String,
+        CliLive,
+    ),
+) -> anyhow::Result<()> {
+    let component_name = format!("api_definition_test{name}");
+    let component = make_shopping_cart_component(deps, &component_name, &cli)?;
+    let component_id = component.component_urn.id.0.to_string();
+    let def = native_api_definition_request(&component_name, &component_id);
+    let path = make_json_file(&def.id, &def)?;
+
+    let _: HttpApiDefinitionWithTypeInfo =
+        cli.run(&["api-definition", "add", path.to_str().unwrap()])?;
+
+    let cfg = &cli.config;
+
+    let res: serde_json::Value = cli.run(&[
+        "api-definition",
+        "test",
+        &cfg.arg('i', "id"),
+        &component_name,
+        &cfg.arg('V', "version"),
+        "0.1.0",
+        &cfg.arg('m', "method"),
+        "get",
+        "/test-user/get-cart-contents",
+    ])?;
+
+    assert_eq!(res["bindingType"], json!("default"));
+    assert_eq!(res["route"], json!("GET /{user-id}/get-cart-contents"));
+    assert_eq!(res["workerName"], json!("foo"));
+    assert_eq!(res["responseMappingResult"]["body"], json!("foo"));
+    assert_eq!(res["responseMappingResult"]["status"], json!(200));
+
+    // Requests not matching any of the routes are rejected
+    let res = cli.run_unit(&[
+        "api-definition",
+        "test",
+        &cfg.arg('i', "id"),
+        &component_name,
+        &cfg.arg('V', "version"),
+        "0.1.0",
+        "/test-user/unknown",
+    ]);
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 fn api_definition_delete(
     (deps, name, cli): (
         &(impl TestDependencies + Send + Sync + 'static),
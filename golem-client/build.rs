@@ -60,6 +60,14 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "golem_common::model::public_oplog::OplogCursor",
             ),
             ("PromiseId", "golem_common::model::PromiseId"),
+            (
+                "Recurrence",
+                "golem_common::model::scheduled_invocation::Recurrence",
+            ),
+            (
+                "RevertWorkerTarget",
+                "golem_common::model::RevertWorkerTarget",
+            ),
             ("ShardId", "golem_common::model::ShardId"),
             (
                 "TypeAnnotatedValue",
@@ -71,6 +79,10 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "WorkerBindingType",
                 "golem_common::model::WorkerBindingType",
             ),
+            (
+                "WorkerResourceLimits",
+                "golem_common::model::component_metadata::WorkerResourceLimits",
+            ),
             ("WorkerStatus", "golem_common::model::WorkerStatus"),
             (
                 "PublicOplogEntry",
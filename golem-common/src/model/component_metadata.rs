@@ -32,6 +32,7 @@ pub struct ComponentMetadata {
     pub exports: Vec<AnalysedExport>,
     pub producers: Vec<Producers>,
     pub memories: Vec<LinearMemory>,
+    pub limits: Option<WorkerResourceLimits>,
 }
 
 impl ComponentMetadata {
@@ -247,6 +248,108 @@ impl From<LinearMemory> for golem_api_grpc::proto::golem::component::LinearMemor
     }
 }
 
+/// Resource limits applied to each worker of a component
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Object, Encode, Decode)]
+pub struct WorkerResourceLimits {
+    /// Maximum amount of fuel a single invocation can consume
+    pub max_fuel_per_invocation: Option<u64>,
+    /// Maximum total size of the worker's linear memories in bytes
+    pub max_memory: Option<u64>,
+    /// Maximum number of entries in the worker's oplog
+    pub max_oplog_length: Option<u64>,
+    /// Maximum total size of the worker's oplog entries in bytes. Large payloads stored outside
+    /// of the oplog are not counted.
+    pub max_oplog_size: Option<u64>,
+}
+
+impl WorkerResourceLimits {
+    /// Environment variables a worker can be created with to lower its component's limits
+    pub const MAX_FUEL_PER_INVOCATION_ENV_VAR: &'static str = "GOLEM_MAX_FUEL_PER_INVOCATION";
+    pub const MAX_MEMORY_ENV_VAR: &'static str = "GOLEM_MAX_MEMORY";
+    pub const MAX_OPLOG_LENGTH_ENV_VAR: &'static str = "GOLEM_MAX_OPLOG_LENGTH";
+    pub const MAX_OPLOG_SIZE_ENV_VAR: &'static str = "GOLEM_MAX_OPLOG_SIZE";
+
+    /// Gets the limits set in a worker's environment variables
+    pub fn from_worker_env(env: &[(String, String)]) -> Result<Self, String> {
+        let get = |name: &str| -> Result<Option<u64>, String> {
+            match env.iter().rev().find(|(key, _)| key == name) {
+                Some((_, value)) => value
+                    .parse::<u64>()
+                    .map(Some)
+                    .map_err(|err| format!("Invalid value for {name}: {err}")),
+                None => Ok(None),
+            }
+        };
+
+        Ok(Self {
+            max_fuel_per_invocation: get(Self::MAX_FUEL_PER_INVOCATION_ENV_VAR)?,
+            max_memory: get(Self::MAX_MEMORY_ENV_VAR)?,
+            max_oplog_length: get(Self::MAX_OPLOG_LENGTH_ENV_VAR)?,
+            max_oplog_size: get(Self::MAX_OPLOG_SIZE_ENV_VAR)?,
+        })
+    }
+
+    /// Combines these limits with the given overrides. Overrides can only lower a limit, so a
+    /// worker cannot get more resources than its component allows.
+    pub fn restricted_by(&self, overrides: &WorkerResourceLimits) -> Self {
+        fn min(limit: Option<u64>, limit_override: Option<u64>) -> Option<u64> {
+            match (limit, limit_override) {
+                (Some(limit), Some(limit_override)) => Some(limit.min(limit_override)),
+                (limit, limit_override) => limit.or(limit_override),
+            }
+        }
+
+        Self {
+            max_fuel_per_invocation: min(
+                self.max_fuel_per_invocation,
+                overrides.max_fuel_per_invocation,
+            ),
+            max_memory: min(self.max_memory, overrides.max_memory),
+            max_oplog_length: min(self.max_oplog_length, overrides.max_oplog_length),
+            max_oplog_size: min(self.max_oplog_size, overrides.max_oplog_size),
+        }
+    }
+}
+
+impl Display for WorkerResourceLimits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).unwrap())
+    }
+}
+
+impl poem_openapi::types::ParseFromMultipartField for WorkerResourceLimits {
+    async fn parse_from_multipart(
+        field: Option<poem::web::Field>,
+    ) -> poem_openapi::types::ParseResult<Self> {
+        String::parse_from_multipart(field)
+            .await
+            .map_err(|err| err.propagate::<WorkerResourceLimits>())
+            .and_then(|s| serde_json::from_str(&s).map_err(poem_openapi::types::ParseError::custom))
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::WorkerResourceLimits> for WorkerResourceLimits {
+    fn from(value: golem_api_grpc::proto::golem::component::WorkerResourceLimits) -> Self {
+        Self {
+            max_fuel_per_invocation: value.max_fuel_per_invocation,
+            max_memory: value.max_memory,
+            max_oplog_length: value.max_oplog_length,
+            max_oplog_size: value.max_oplog_size,
+        }
+    }
+}
+
+impl From<WorkerResourceLimits> for golem_api_grpc::proto::golem::component::WorkerResourceLimits {
+    fn from(value: WorkerResourceLimits) -> Self {
+        Self {
+            max_fuel_per_invocation: value.max_fuel_per_invocation,
+            max_memory: value.max_memory,
+            max_oplog_length: value.max_oplog_length,
+            max_oplog_size: value.max_oplog_size,
+        }
+    }
+}
+
 impl From<RawComponentMetadata> for ComponentMetadata {
     fn from(value: RawComponentMetadata) -> Self {
         let producers = value
@@ -263,6 +366,7 @@ impl From<RawComponentMetadata> for ComponentMetadata {
             exports,
             producers,
             memories,
+            limits: None,
         }
     }
 }
@@ -289,6 +393,7 @@ impl TryFrom<golem_api_grpc::proto::golem::component::ComponentMetadata> for Com
                 .into_iter()
                 .map(|memory| memory.into())
                 .collect(),
+            limits: value.limits.map(|limits| limits.into()),
         })
     }
 }
@@ -311,6 +416,7 @@ impl From<ComponentMetadata> for golem_api_grpc::proto::golem::component::Compon
                 .into_iter()
                 .map(|memory| memory.into())
                 .collect(),
+            limits: value.limits.map(|limits| limits.into()),
         }
     }
 }
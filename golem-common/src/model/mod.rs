@@ -60,6 +60,7 @@ pub mod lucene;
 pub mod oplog;
 pub mod public_oplog;
 pub mod regions;
+pub mod scheduled_invocation;
 pub mod trim_date;
 
 newtype_uuid!(
@@ -235,6 +236,18 @@ impl From<u64> for Timestamp {
     }
 }
 
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Timestamp::from(value.timestamp_millis().max(0) as u64)
+    }
+}
+
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(value: Timestamp) -> Self {
+        chrono::DateTime::UNIX_EPOCH + chrono::Duration::milliseconds(value.to_millis() as i64)
+    }
+}
+
 pub type ComponentVersion = u64;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Encode, Decode, Serialize, Deserialize, Object)]
@@ -554,6 +567,13 @@ pub enum ScheduledAction {
         last_oplog_index: OplogIndex,
         next_after: Duration,
     },
+    /// Fires one tick of a named scheduled invocation. The tick is ignored if the scheduled
+    /// invocation was deleted or rescheduled to a different time in the meantime.
+    Invoke {
+        owned_worker_id: OwnedWorkerId,
+        name: String,
+        fire_at: Timestamp,
+    },
 }
 
 impl ScheduledAction {
@@ -566,6 +586,9 @@ impl ScheduledAction {
             ScheduledAction::ArchiveOplog {
                 owned_worker_id, ..
             } => owned_worker_id.clone(),
+            ScheduledAction::Invoke {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
         }
     }
 }
@@ -581,6 +604,13 @@ impl Display for ScheduledAction {
             } => {
                 write!(f, "archive[{}]", owned_worker_id)
             }
+            ScheduledAction::Invoke {
+                owned_worker_id,
+                name,
+                fire_at,
+            } => {
+                write!(f, "invoke[{}/{}@{}]", owned_worker_id, name, fire_at)
+            }
         }
     }
 }
@@ -871,6 +901,17 @@ impl IdempotencyKey {
         let name = format!("oplog-index-{}", oplog_index);
         Self::from_uuid(Uuid::new_v5(&namespace, name.as_bytes()))
     }
+
+    /// Generates a deterministic idempotency key for one tick of a scheduled invocation, so
+    /// firing the same tick more than once never invokes the worker more than once.
+    pub fn scheduled(worker_id: &WorkerId, schedule_name: &str, fire_at: Timestamp) -> Self {
+        let namespace = Uuid::new_v5(
+            &Self::ROOT_NS,
+            format!("scheduled-invocation-{worker_id}-{schedule_name}").as_bytes(),
+        );
+        let name = format!("tick-{}", fire_at.to_millis());
+        Self::from_uuid(Uuid::new_v5(&namespace, name.as_bytes()))
+    }
 }
 
 impl Serialize for IdempotencyKey {
@@ -1008,7 +1049,7 @@ pub struct WorkerResourceDescription {
 /// This status is just cached information, all fields must be computable by the oplog alone.
 /// By having an associated oplog_idx, the cached information can be used together with the
 /// tail of the oplog to determine the actual status of the worker.
-#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Encode)]
 pub struct WorkerStatusRecord {
     pub status: WorkerStatus,
     pub deleted_regions: DeletedRegions,
@@ -1024,6 +1065,52 @@ pub struct WorkerStatusRecord {
     pub total_linear_memory_size: u64,
     pub owned_resources: HashMap<WorkerResourceId, WorkerResourceDescription>,
     pub oplog_idx: OplogIndex,
+    /// Index of the latest snapshot entry that recovery can start from
+    pub latest_snapshot: Option<OplogIndex>,
+    /// Total encoded size of the oplog entries up to `oplog_size_measured_until`
+    pub oplog_size: u64,
+    pub oplog_size_measured_until: OplogIndex,
+}
+
+impl Decode for WorkerStatusRecord {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Ok(Self {
+            status: Decode::decode(decoder)?,
+            deleted_regions: Decode::decode(decoder)?,
+            overridden_retry_config: Decode::decode(decoder)?,
+            pending_invocations: Decode::decode(decoder)?,
+            pending_updates: Decode::decode(decoder)?,
+            failed_updates: Decode::decode(decoder)?,
+            successful_updates: Decode::decode(decoder)?,
+            invocation_results: Decode::decode(decoder)?,
+            current_idempotency_key: Decode::decode(decoder)?,
+            component_version: Decode::decode(decoder)?,
+            component_size: Decode::decode(decoder)?,
+            total_linear_memory_size: Decode::decode(decoder)?,
+            owned_resources: Decode::decode(decoder)?,
+            oplog_idx: Decode::decode(decoder)?,
+            latest_snapshot: decode_trailing_field(decoder)?,
+            oplog_size: decode_trailing_field(decoder)?,
+            oplog_size_measured_until: decode_trailing_field(decoder)?,
+        })
+    }
+}
+
+impl<'de> BorrowDecode<'de> for WorkerStatusRecord {
+    fn borrow_decode<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Decode::decode(decoder)
+    }
+}
+
+/// Decodes a field added to the end of a record after it was first stored. Records stored
+/// before the field existed end before it, and get the default value.
+fn decode_trailing_field<T: Decode + Default, D: Decoder>(
+    decoder: &mut D,
+) -> Result<T, DecodeError> {
+    match Decode::decode(decoder) {
+        Err(DecodeError::UnexpectedEnd { .. }) => Ok(T::default()),
+        result => result,
+    }
 }
 
 impl Default for WorkerStatusRecord {
@@ -1043,6 +1130,9 @@ impl Default for WorkerStatusRecord {
             total_linear_memory_size: 0,
             owned_resources: HashMap::new(),
             oplog_idx: OplogIndex::default(),
+            latest_snapshot: None,
+            oplog_size: 0,
+            oplog_size_measured_until: OplogIndex::NONE,
         }
     }
 }
@@ -1843,6 +1933,78 @@ impl From<WorkerFilter> for golem_api_grpc::proto::golem::worker::WorkerFilter {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RevertToOplogIndex {
+    pub last_oplog_index: OplogIndex,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RevertLastInvocations {
+    pub number_of_invocations: u64,
+}
+
+/// Describes how far a worker should be reverted
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Union)]
+#[oai(discriminator_name = "type", one_of = true)]
+#[serde(tag = "type")]
+pub enum RevertWorkerTarget {
+    /// Reverts the worker to the given oplog index, dropping every entry after it
+    RevertToOplogIndex(RevertToOplogIndex),
+    /// Reverts the worker by dropping the given number of its last invocations
+    RevertLastInvocations(RevertLastInvocations),
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::RevertWorkerTarget> for RevertWorkerTarget {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::RevertWorkerTarget,
+    ) -> Result<Self, Self::Error> {
+        match value.target {
+            Some(golem_api_grpc::proto::golem::worker::revert_worker_target::Target::RevertToOplogIndex(target)) => {
+                Ok(RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex {
+                    last_oplog_index: OplogIndex::from_u64(target.last_oplog_index),
+                }))
+            }
+            Some(golem_api_grpc::proto::golem::worker::revert_worker_target::Target::RevertLastInvocations(target)) => {
+                Ok(RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                    number_of_invocations: target.number_of_invocations,
+                }))
+            }
+            None => Err("Missing revert target".to_string()),
+        }
+    }
+}
+
+impl From<RevertWorkerTarget> for golem_api_grpc::proto::golem::worker::RevertWorkerTarget {
+    fn from(value: RevertWorkerTarget) -> Self {
+        let target = match value {
+            RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex { last_oplog_index }) => {
+                golem_api_grpc::proto::golem::worker::revert_worker_target::Target::RevertToOplogIndex(
+                    golem_api_grpc::proto::golem::worker::RevertToOplogIndex {
+                        last_oplog_index: last_oplog_index.into(),
+                    },
+                )
+            }
+            RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                number_of_invocations,
+            }) => golem_api_grpc::proto::golem::worker::revert_worker_target::Target::RevertLastInvocations(
+                golem_api_grpc::proto::golem::worker::RevertLastInvocations {
+                    number_of_invocations,
+                },
+            ),
+        };
+
+        golem_api_grpc::proto::golem::worker::RevertWorkerTarget {
+            target: Some(target),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Enum)]
 pub enum StringFilterComparator {
     Equal,
@@ -2860,6 +3022,7 @@ pub enum GatewayBindingType {
     Default,
     FileServer,
     CorsPreflight,
+    Stream,
 }
 
 // To keep backward compatibility as we documented wit-worker to be default
@@ -2885,6 +3048,7 @@ impl<'de> Deserialize<'de> for GatewayBindingType {
                     "default" | "wit-worker" => Ok(GatewayBindingType::Default),
                     "file-server" => Ok(GatewayBindingType::FileServer),
                     "cors-preflight" => Ok(GatewayBindingType::CorsPreflight),
+                    "stream" => Ok(GatewayBindingType::Stream),
                     _ => Err(de::Error::invalid_value(Unexpected::Str(value), &self)),
                 }
             }
@@ -2901,6 +3065,7 @@ impl TryFrom<String> for GatewayBindingType {
         match value.as_str() {
             "default" => Ok(GatewayBindingType::Default),
             "file-server" => Ok(GatewayBindingType::FileServer),
+            "stream" => Ok(GatewayBindingType::Stream),
             _ => Err(format!("Invalid WorkerBindingType: {}", value)),
         }
     }
@@ -2918,6 +3083,9 @@ impl From<golem_api_grpc::proto::golem::apidefinition::GatewayBindingType> for G
             golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::CorsPreflight => {
                 GatewayBindingType::CorsPreflight
             }
+            golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::Stream => {
+                GatewayBindingType::Stream
+            }
         }
     }
 }
@@ -2934,6 +3102,9 @@ impl From<GatewayBindingType> for golem_api_grpc::proto::golem::apidefinition::G
             GatewayBindingType::CorsPreflight => {
                 golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::CorsPreflight
             }
+            GatewayBindingType::Stream => {
+                golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::Stream
+            }
         }
     }
 }
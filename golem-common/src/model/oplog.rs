@@ -404,6 +404,17 @@ pub enum OplogEntry {
         response: OplogPayload,
         wrapped_function_type: WrappedFunctionType,
     },
+    /// A pending invocation was removed from the invocation queue before it got started
+    CancelPendingInvocation {
+        timestamp: Timestamp,
+        idempotency_key: IdempotencyKey,
+    },
+    /// Snapshot of the worker's state, taken by calling the component's save-snapshot export.
+    /// Recovery can start by loading the latest snapshot instead of replaying the whole oplog.
+    Snapshot {
+        timestamp: Timestamp,
+        data: OplogPayload,
+    },
 }
 
 impl OplogEntry {
@@ -581,6 +592,13 @@ impl OplogEntry {
         }
     }
 
+    pub fn cancel_pending_invocation(idempotency_key: IdempotencyKey) -> OplogEntry {
+        OplogEntry::CancelPendingInvocation {
+            timestamp: Timestamp::now_utc(),
+            idempotency_key,
+        }
+    }
+
     pub fn is_end_atomic_region(&self, idx: OplogIndex) -> bool {
         matches!(self, OplogEntry::EndAtomicRegion { begin_index, .. } if *begin_index == idx)
     }
@@ -630,6 +648,8 @@ impl OplogEntry {
                 | OplogEntry::DescribeResource { .. }
                 | OplogEntry::Log { .. }
                 | OplogEntry::Restart { .. }
+                | OplogEntry::CancelPendingInvocation { .. }
+                | OplogEntry::Snapshot { .. }
         )
     }
 
@@ -660,7 +680,27 @@ impl OplogEntry {
             | OplogEntry::DescribeResource { timestamp, .. }
             | OplogEntry::Log { timestamp, .. }
             | OplogEntry::Restart { timestamp }
-            | OplogEntry::ImportedFunctionInvoked { timestamp, .. } => *timestamp,
+            | OplogEntry::ImportedFunctionInvoked { timestamp, .. }
+            | OplogEntry::CancelPendingInvocation { timestamp, .. }
+            | OplogEntry::Snapshot { timestamp, .. } => *timestamp,
+        }
+    }
+
+    /// Gets mutable references to all the payloads stored in this oplog entry
+    pub fn payloads_mut(&mut self) -> Vec<&mut OplogPayload> {
+        match self {
+            OplogEntry::ImportedFunctionInvoked {
+                request, response, ..
+            } => vec![request, response],
+            OplogEntry::ImportedFunctionInvokedV1 { response, .. } => vec![response],
+            OplogEntry::ExportedFunctionInvoked { request, .. } => vec![request],
+            OplogEntry::ExportedFunctionCompleted { response, .. } => vec![response],
+            OplogEntry::PendingUpdate {
+                description: UpdateDescription::SnapshotBased { payload, .. },
+                ..
+            } => vec![payload],
+            OplogEntry::Snapshot { data, .. } => vec![data],
+            _ => vec![],
         }
     }
 }
@@ -734,6 +774,7 @@ pub enum WorkerError {
     InvalidRequest(String),
     StackOverflow,
     OutOfMemory,
+    ResourceLimitExceeded(String),
 }
 
 impl WorkerError {
@@ -748,6 +789,9 @@ impl WorkerError {
             WorkerError::InvalidRequest(message) => format!("{message}{error_logs}"),
             WorkerError::StackOverflow => format!("Stack overflow{error_logs}"),
             WorkerError::OutOfMemory => format!("Out of memory{error_logs}"),
+            WorkerError::ResourceLimitExceeded(message) => {
+                format!("Resource limit exceeded: {message}{error_logs}")
+            }
         }
     }
 }
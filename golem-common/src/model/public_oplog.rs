@@ -208,6 +208,18 @@ pub struct PendingWorkerInvocationParameters {
     pub invocation: PublicWorkerInvocation,
 }
 
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct CancelPendingInvocationParameters {
+    pub timestamp: Timestamp,
+    pub idempotency_key: IdempotencyKey,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct SnapshotParameters {
+    pub timestamp: Timestamp,
+    pub data: Vec<u8>,
+}
+
 #[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
 pub struct PendingUpdateParameters {
     pub timestamp: Timestamp,
@@ -328,6 +340,10 @@ pub enum PublicOplogEntry {
     Log(LogParameters),
     /// Marks the point where the worker was restarted from clean initial state
     Restart(TimestampParameter),
+    /// A pending invocation was removed from the invocation queue before it got started
+    CancelPendingInvocation(CancelPendingInvocationParameters),
+    /// Snapshot of the worker's state, used to shorten recovery
+    Snapshot(SnapshotParameters),
 }
 
 impl PublicOplogEntry {
@@ -402,6 +418,9 @@ impl PublicOplogEntry {
                         .any(|v| Self::match_value(v, &[], query_path, query))
                     || Self::string_match(&params.idempotency_key.value, &[], query_path, query)
             }
+            PublicOplogEntry::Snapshot(_params) => {
+                Self::string_match("snapshot", &[], query_path, query)
+            }
             PublicOplogEntry::ExportedFunctionCompleted(params) => {
                 Self::string_match("exportedfunctioncompleted", &[], query_path, query)
                     || Self::string_match("exported-function-completed", &[], query_path, query)
@@ -541,6 +560,11 @@ impl PublicOplogEntry {
             PublicOplogEntry::Restart(_params) => {
                 Self::string_match("restart", &[], query_path, query)
             }
+            PublicOplogEntry::CancelPendingInvocation(params) => {
+                Self::string_match("cancelpendinginvocation", &[], query_path, query)
+                    || Self::string_match("cancel-pending-invocation", &[], query_path, query)
+                    || Self::string_match(&params.idempotency_key.value, &[], query_path, query)
+            }
         }
     }
 
@@ -970,6 +994,24 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::OplogEntry> for PublicOplogEn
                     timestamp: restart.timestamp.ok_or("Missing timestamp field")?.into(),
                 }))
             }
+            oplog_entry::Entry::CancelPendingInvocation(cancel_pending_invocation) => Ok(
+                PublicOplogEntry::CancelPendingInvocation(CancelPendingInvocationParameters {
+                    timestamp: cancel_pending_invocation
+                        .timestamp
+                        .ok_or("Missing timestamp field")?
+                        .into(),
+                    idempotency_key: cancel_pending_invocation
+                        .idempotency_key
+                        .ok_or("Missing idempotency_key field")?
+                        .into(),
+                }),
+            ),
+            oplog_entry::Entry::Snapshot(snapshot) => {
+                Ok(PublicOplogEntry::Snapshot(SnapshotParameters {
+                    timestamp: snapshot.timestamp.ok_or("Missing timestamp field")?.into(),
+                    data: snapshot.data,
+                }))
+            }
         }
     }
 }
@@ -1265,6 +1307,26 @@ impl TryFrom<PublicOplogEntry> for golem_api_grpc::proto::golem::worker::OplogEn
                     )),
                 }
             }
+            PublicOplogEntry::CancelPendingInvocation(cancel_pending_invocation) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::CancelPendingInvocation(
+                        golem_api_grpc::proto::golem::worker::CancelPendingInvocationParameters {
+                            timestamp: Some(cancel_pending_invocation.timestamp.into()),
+                            idempotency_key: Some(cancel_pending_invocation.idempotency_key.into()),
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::Snapshot(snapshot) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::Snapshot(
+                        golem_api_grpc::proto::golem::worker::SnapshotParameters {
+                            timestamp: Some(snapshot.timestamp.into()),
+                            data: snapshot.data,
+                        },
+                    )),
+                }
+            }
         })
     }
 }
@@ -1571,13 +1633,14 @@ impl From<OplogCursor> for golem_api_grpc::proto::golem::worker::OplogCursor {
 mod tests {
 
     use super::{
-        ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, Empty,
-        EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
-        ExportedFunctionInvokedParameters, ExportedFunctionParameters, FailedUpdateParameters,
-        GrowMemoryParameters, ImportedFunctionInvokedParameters, JumpParameters, LogParameters,
-        PendingUpdateParameters, PendingWorkerInvocationParameters, PublicOplogEntry,
-        PublicRetryConfig, PublicUpdateDescription, PublicWorkerInvocation,
-        PublicWrappedFunctionType, ResourceParameters, SnapshotBasedUpdateParameters,
+        CancelPendingInvocationParameters, ChangeRetryPolicyParameters, CreateParameters,
+        DescribeResourceParameters, Empty, EndRegionParameters, ErrorParameters,
+        ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+        ExportedFunctionParameters, FailedUpdateParameters, GrowMemoryParameters,
+        ImportedFunctionInvokedParameters, JumpParameters, LogParameters, PendingUpdateParameters,
+        PendingWorkerInvocationParameters, PublicOplogEntry, PublicRetryConfig,
+        PublicUpdateDescription, PublicWorkerInvocation, PublicWrappedFunctionType,
+        ResourceParameters, SnapshotBasedUpdateParameters, SnapshotParameters,
         SuccessfulUpdateParameters, TimestampParameter,
     };
     use crate::model::oplog::{LogLevel, OplogIndex, WorkerResourceId};
@@ -1973,4 +2036,26 @@ mod tests {
         let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
         assert_eq!(entry, deserialized);
     }
+
+    #[test]
+    fn cancel_pending_invocation_serialization_poem_serde_equivalence() {
+        let entry = PublicOplogEntry::CancelPendingInvocation(CancelPendingInvocationParameters {
+            timestamp: rounded_ts(Timestamp::now_utc()),
+            idempotency_key: IdempotencyKey::new("idempotency_key".to_string()),
+        });
+        let serialized = entry.to_json_string();
+        let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(entry, deserialized);
+    }
+
+    #[test]
+    fn snapshot_serialization_poem_serde_equivalence() {
+        let entry = PublicOplogEntry::Snapshot(SnapshotParameters {
+            timestamp: rounded_ts(Timestamp::now_utc()),
+            data: "test".as_bytes().to_vec(),
+        });
+        let serialized = entry.to_json_string();
+        let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(entry, deserialized);
+    }
 }
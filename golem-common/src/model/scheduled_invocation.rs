@@ -0,0 +1,478 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use bincode::{Decode, Encode};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+use poem_openapi::{Object, Union};
+use serde::{Deserialize, Serialize};
+
+use crate::model::{IdempotencyKey, Timestamp};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Object)]
+pub struct Once {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct Interval {
+    pub interval_millis: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Object)]
+pub struct Cron {
+    pub expression: String,
+}
+
+/// Describes when a scheduled invocation fires again after one of its ticks
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode, Union)]
+#[oai(discriminator_name = "type", one_of = true)]
+#[serde(tag = "type")]
+pub enum Recurrence {
+    /// Fires only once
+    Once(Once),
+    /// Fires repeatedly, with a fixed interval between the ticks
+    Interval(Interval),
+    /// Fires every time the five-field cron expression matches (in UTC)
+    Cron(Cron),
+}
+
+impl Recurrence {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Recurrence::Once(_) => Ok(()),
+            Recurrence::Interval(Interval { interval_millis }) => {
+                if *interval_millis == 0 {
+                    Err("Recurrence interval must be greater than zero".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+            Recurrence::Cron(Cron { expression }) => {
+                CronExpression::from_str(expression).map(|_| ())
+            }
+        }
+    }
+
+    /// Calculates the first tick when no explicit start time was requested
+    pub fn first_tick(&self, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        match self {
+            Recurrence::Once(_) => Ok(now),
+            Recurrence::Interval(Interval { interval_millis }) => {
+                Ok(now + Duration::milliseconds(*interval_millis as i64))
+            }
+            Recurrence::Cron(Cron { expression }) => CronExpression::from_str(expression)?
+                .next_after(now)
+                .ok_or(format!("Cron expression {expression} never matches")),
+        }
+    }
+
+    /// Calculates the tick following `previous`. Ticks which would already be in the past at
+    /// `now` (for example because the executor was not running) are skipped, so a recurring
+    /// invocation fires at most once when catching up.
+    pub fn next_tick(&self, previous: DateTime<Utc>, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Recurrence::Once(_) => None,
+            Recurrence::Interval(Interval { interval_millis }) => {
+                let interval_millis = (*interval_millis).max(1) as i64;
+                let elapsed_millis = (now - previous).num_milliseconds().max(0);
+                let skipped = elapsed_millis / interval_millis;
+                Some(previous + Duration::milliseconds((skipped + 1) * interval_millis))
+            }
+            Recurrence::Cron(Cron { expression }) => CronExpression::from_str(expression)
+                .ok()?
+                .next_after(previous.max(now)),
+        }
+    }
+}
+
+impl Display for Recurrence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Recurrence::Once(_) => write!(f, "once"),
+            Recurrence::Interval(Interval { interval_millis }) => {
+                write!(f, "every {interval_millis}ms")
+            }
+            Recurrence::Cron(Cron { expression }) => write!(f, "cron '{expression}'"),
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::Recurrence> for Recurrence {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::Recurrence,
+    ) -> Result<Self, Self::Error> {
+        match value.recurrence {
+            Some(golem_api_grpc::proto::golem::worker::recurrence::Recurrence::Once(_)) => {
+                Ok(Recurrence::Once(Once {}))
+            }
+            Some(golem_api_grpc::proto::golem::worker::recurrence::Recurrence::IntervalMillis(
+                interval_millis,
+            )) => Ok(Recurrence::Interval(Interval { interval_millis })),
+            Some(golem_api_grpc::proto::golem::worker::recurrence::Recurrence::Cron(
+                expression,
+            )) => Ok(Recurrence::Cron(Cron { expression })),
+            None => Err("Missing recurrence".to_string()),
+        }
+    }
+}
+
+impl From<Recurrence> for golem_api_grpc::proto::golem::worker::Recurrence {
+    fn from(value: Recurrence) -> Self {
+        let recurrence = match value {
+            Recurrence::Once(_) => {
+                golem_api_grpc::proto::golem::worker::recurrence::Recurrence::Once(
+                    golem_api_grpc::proto::golem::common::Empty {},
+                )
+            }
+            Recurrence::Interval(Interval { interval_millis }) => {
+                golem_api_grpc::proto::golem::worker::recurrence::Recurrence::IntervalMillis(
+                    interval_millis,
+                )
+            }
+            Recurrence::Cron(Cron { expression }) => {
+                golem_api_grpc::proto::golem::worker::recurrence::Recurrence::Cron(expression)
+            }
+        };
+        Self {
+            recurrence: Some(recurrence),
+        }
+    }
+}
+
+/// A named invocation of one of a worker's exported functions, fired by the scheduler once or
+/// repeatedly
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct ScheduledInvocation {
+    pub name: String,
+    pub full_function_name: String,
+    pub function_input: Vec<golem_wasm_rpc::Value>,
+    pub recurrence: Recurrence,
+    pub next_fire_at: Timestamp,
+    /// Idempotency key used when firing the invocation. When not set, a key derived from the
+    /// schedule's name and the tick is used instead.
+    pub idempotency_key: Option<IdempotencyKey>,
+    /// Number of failed attempts to fire the tick at `next_fire_at`. The tick is retried until
+    /// it gets delivered to the worker.
+    pub failed_attempts: u32,
+    /// Error of the last failed attempt to fire the tick at `next_fire_at`
+    pub last_error: Option<String>,
+}
+
+impl ScheduledInvocation {
+    /// Delayed invocations are stored as scheduled invocations named after their idempotency
+    /// key with this prefix. Named scheduled invocations cannot use it, so the two never collide.
+    pub const DELAYED_NAME_PREFIX: &'static str = "delayed:";
+
+    pub fn delayed_name(idempotency_key: &IdempotencyKey) -> String {
+        format!("{}{}", Self::DELAYED_NAME_PREFIX, idempotency_key.value)
+    }
+
+    pub fn is_delayed_name(name: &str) -> bool {
+        name.starts_with(Self::DELAYED_NAME_PREFIX)
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::ScheduledInvocation> for ScheduledInvocation {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::ScheduledInvocation,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            full_function_name: value.function_name,
+            function_input: value
+                .input
+                .into_iter()
+                .map(|val| val.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            recurrence: value
+                .recurrence
+                .ok_or("Missing recurrence".to_string())?
+                .try_into()?,
+            next_fire_at: value
+                .next_fire_at
+                .ok_or("Missing next fire time".to_string())?
+                .into(),
+            idempotency_key: value.idempotency_key.map(|key| key.into()),
+            failed_attempts: value.failed_attempts,
+            last_error: value.last_error,
+        })
+    }
+}
+
+impl From<ScheduledInvocation> for golem_api_grpc::proto::golem::worker::ScheduledInvocation {
+    fn from(value: ScheduledInvocation) -> Self {
+        Self {
+            name: value.name,
+            function_name: value.full_function_name,
+            input: value
+                .function_input
+                .into_iter()
+                .map(|value| value.into())
+                .collect(),
+            recurrence: Some(value.recurrence.into()),
+            next_fire_at: Some(value.next_fire_at.into()),
+            idempotency_key: value.idempotency_key.map(|key| key.into()),
+            failed_attempts: value.failed_attempts,
+            last_error: value.last_error,
+        }
+    }
+}
+
+/// A parsed five-field cron expression: `minute hour day-of-month month day-of-week`.
+///
+/// Each field accepts `*`, single values, ranges (`1-5`), steps (`*/15`, `0-30/10`) and
+/// comma-separated lists of these. Both `0` and `7` mean Sunday in the day-of-week field. As in
+/// classic cron, when both day fields are restricted a day matches if either of them matches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronExpression {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronExpression {
+    /// Cron expressions that never match (like `0 0 30 2 *`) are only detected when searching
+    /// for the next matching time, so the search gives up after this many years.
+    const MAX_YEARS_TO_SEARCH: i32 = 5;
+
+    /// Returns the first matching minute strictly after the given time
+    pub fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut candidate = time.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let last_year = candidate.year() + Self::MAX_YEARS_TO_SEARCH;
+
+        while candidate.year() <= last_year {
+            if !Self::is_set(self.months, candidate.month()) {
+                let (year, month) = if candidate.month() == 12 {
+                    (candidate.year() + 1, 1)
+                } else {
+                    (candidate.year(), candidate.month() + 1)
+                };
+                candidate = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).single()?;
+            } else if !self.matches_day(&candidate) {
+                candidate = (candidate + Duration::days(1))
+                    .with_hour(0)?
+                    .with_minute(0)?;
+            } else if !Self::is_set(self.hours, candidate.hour()) {
+                candidate = (candidate + Duration::hours(1)).with_minute(0)?;
+            } else if !Self::is_set(self.minutes, candidate.minute()) {
+                candidate += Duration::minutes(1);
+            } else {
+                return Some(candidate);
+            }
+        }
+
+        None
+    }
+
+    fn matches_day(&self, time: &DateTime<Utc>) -> bool {
+        let day_of_month = Self::is_set(self.days_of_month, time.day());
+        let day_of_week = Self::is_set(self.days_of_week, time.weekday().num_days_from_sunday());
+        if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+
+    fn is_set(bits: u64, value: u32) -> bool {
+        bits & (1 << value) != 0
+    }
+
+    fn parse_field(field: &str, name: &str, min: u32, max: u32) -> Result<u64, String> {
+        let parse_value = |value: &str| {
+            value
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid value '{value}' in the {name} field"))
+        };
+
+        let mut bits = 0u64;
+        for part in field.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => {
+                    let step = parse_value(step)?;
+                    if step == 0 {
+                        return Err(format!(
+                            "Step must be greater than zero in the {name} field"
+                        ));
+                    }
+                    (range, Some(step))
+                }
+                None => (part, None),
+            };
+
+            let (start, end) = if range == "*" {
+                (min, max)
+            } else if let Some((start, end)) = range.split_once('-') {
+                (parse_value(start)?, parse_value(end)?)
+            } else {
+                let value = parse_value(range)?;
+                // `5/15` means "every 15 starting from 5"
+                (value, if step.is_some() { max } else { value })
+            };
+
+            if start < min || end > max || start > end {
+                return Err(format!(
+                    "Invalid range '{range}' in the {name} field, values must be between {min} and {max}"
+                ));
+            }
+
+            for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+                bits |= 1 << value;
+            }
+        }
+        Ok(bits)
+    }
+}
+
+impl FromStr for CronExpression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!(
+                "Cron expression '{s}' must have exactly 5 fields: minute, hour, day-of-month, month and day-of-week"
+            ));
+        }
+
+        let mut days_of_week = Self::parse_field(fields[4], "day-of-week", 0, 7)?;
+        if Self::is_set(days_of_week, 7) {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes: Self::parse_field(fields[0], "minute", 0, 59)?,
+            hours: Self::parse_field(fields[1], "hour", 0, 23)?,
+            days_of_month: Self::parse_field(fields[2], "day-of-month", 1, 31)?,
+            months: Self::parse_field(fields[3], "month", 1, 12)?,
+            days_of_week,
+            day_of_month_restricted: !fields[2].starts_with('*'),
+            day_of_week_restricted: !fields[4].starts_with('*'),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::str::FromStr;
+
+    use chrono::{DateTime, Utc};
+
+    use crate::model::scheduled_invocation::{Cron, CronExpression, Interval, Recurrence};
+
+    fn time(s: &str) -> DateTime<Utc> {
+        DateTime::from_str(s).unwrap()
+    }
+
+    fn next(expression: &str, after: &str) -> Option<DateTime<Utc>> {
+        CronExpression::from_str(expression)
+            .unwrap()
+            .next_after(time(after))
+    }
+
+    #[test]
+    fn every_five_minutes() {
+        assert_eq!(
+            next("*/5 * * * *", "2024-03-10T10:03:27Z"),
+            Some(time("2024-03-10T10:05:00Z"))
+        );
+        assert_eq!(
+            next("*/5 * * * *", "2024-03-10T10:05:00Z"),
+            Some(time("2024-03-10T10:10:00Z"))
+        );
+    }
+
+    #[test]
+    fn daily_at_fixed_time_rolls_over_to_next_year() {
+        assert_eq!(
+            next("30 2 * * *", "2024-12-31T03:00:00Z"),
+            Some(time("2025-01-01T02:30:00Z"))
+        );
+    }
+
+    #[test]
+    fn day_of_week_with_lists_and_ranges() {
+        // 2024-03-10 is a Sunday
+        assert_eq!(
+            next("0 9 * * 1-5", "2024-03-09T12:00:00Z"),
+            Some(time("2024-03-11T09:00:00Z"))
+        );
+        assert_eq!(
+            next("0 9 * * 6,7", "2024-03-09T12:00:00Z"),
+            Some(time("2024-03-10T09:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either() {
+        // The 15th of the month or any Monday; 2024-03-11 is a Monday
+        assert_eq!(
+            next("0 0 15 * 1", "2024-03-09T12:00:00Z"),
+            Some(time("2024-03-11T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn never_matching_expression() {
+        assert_eq!(next("0 0 30 2 *", "2024-01-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        assert!(CronExpression::from_str("* * * *").is_err());
+        assert!(CronExpression::from_str("60 * * * *").is_err());
+        assert!(CronExpression::from_str("*/0 * * * *").is_err());
+        assert!(CronExpression::from_str("5-1 * * * *").is_err());
+        assert!(CronExpression::from_str("x * * * *").is_err());
+    }
+
+    #[test]
+    fn interval_skips_missed_ticks() {
+        let recurrence = Recurrence::Interval(Interval {
+            interval_millis: 60_000,
+        });
+        assert_eq!(
+            recurrence.next_tick(time("2024-03-10T10:00:00Z"), time("2024-03-10T10:00:01Z")),
+            Some(time("2024-03-10T10:01:00Z"))
+        );
+        assert_eq!(
+            recurrence.next_tick(time("2024-03-10T10:00:00Z"), time("2024-03-10T10:05:30Z")),
+            Some(time("2024-03-10T10:06:00Z"))
+        );
+    }
+
+    #[test]
+    fn cron_recurrence_next_tick() {
+        let recurrence = Recurrence::Cron(Cron {
+            expression: "0 * * * *".to_string(),
+        });
+        assert_eq!(
+            recurrence.next_tick(time("2024-03-10T10:00:00Z"), time("2024-03-10T10:00:02Z")),
+            Some(time("2024-03-10T11:00:00Z"))
+        );
+    }
+}
@@ -30,7 +30,9 @@ use chrono::Utc;
 use golem_api_grpc::proto::golem::common::{ErrorBody, ErrorsBody};
 use golem_api_grpc::proto::golem::component::v1::component_error;
 use golem_common::model::component_constraint::FunctionConstraintCollection;
-use golem_common::model::component_metadata::{ComponentMetadata, ComponentProcessingError};
+use golem_common::model::component_metadata::{
+    ComponentMetadata, ComponentProcessingError, WorkerResourceLimits,
+};
 use golem_common::model::AccountId;
 use golem_common::model::{
     ComponentFilePath, ComponentFilePermissions, ComponentId, ComponentType, HasAccountId,
@@ -235,6 +237,7 @@ pub trait ComponentService<Namespace> {
         component_type: ComponentType,
         data: Vec<u8>,
         files: Option<InitialComponentFilesArchiveAndPermissions>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError>;
 
@@ -246,6 +249,7 @@ pub trait ComponentService<Namespace> {
         component_type: ComponentType,
         data: Vec<u8>,
         files: Vec<InitialComponentFile>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError>;
 
@@ -255,6 +259,7 @@ pub trait ComponentService<Namespace> {
         data: Vec<u8>,
         component_type: Option<ComponentType>,
         files: Option<InitialComponentFilesArchiveAndPermissions>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError>;
 
@@ -266,6 +271,7 @@ pub trait ComponentService<Namespace> {
         component_type: Option<ComponentType>,
         // None signals that files should be reused from the previous version
         files: Option<Vec<InitialComponentFile>>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError>;
 
@@ -422,6 +428,7 @@ impl ComponentServiceDefault {
         component_type: ComponentType,
         data: Vec<u8>,
         uploaded_files: Vec<InitialComponentFile>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError>
     where
@@ -431,8 +438,9 @@ impl ComponentServiceDefault {
         let versioned_component_id = create_new_versioned_component_id(component_id);
 
         // analyze component before uploading anything so we fail early
-        let component_metadata = ComponentMetadata::analyse_component(&data)
+        let mut component_metadata = ComponentMetadata::analyse_component(&data)
             .map_err(ComponentError::ComponentProcessingError)?;
+        component_metadata.limits = limits;
 
         let component_size = data.len() as u64;
 
@@ -478,6 +486,7 @@ impl ComponentServiceDefault {
         data: Vec<u8>,
         component_type: Option<ComponentType>,
         files: Option<Vec<InitialComponentFile>>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError>
     where
@@ -486,7 +495,7 @@ impl ComponentServiceDefault {
     {
         let created_at = Utc::now();
 
-        let metadata = ComponentMetadata::analyse_component(&data)
+        let mut metadata = ComponentMetadata::analyse_component(&data)
             .map_err(ComponentError::ComponentProcessingError)?;
 
         info!(namespace = %namespace, "Uploaded component - exports {:?}", metadata.exports);
@@ -518,6 +527,9 @@ impl ComponentServiceDefault {
         // Fallback to the files from the previous version if no files are provided.
         let files_to_use = files.unwrap_or_else(|| next_component.files.clone());
 
+        // Same for the resource limits
+        metadata.limits = limits.or_else(|| next_component.metadata.limits.clone());
+
         let component_size: u64 = data.len().try_into().map_err(|e: TryFromIntError| {
             ComponentError::conversion_error("data length", e.to_string())
         })?;
@@ -642,6 +654,7 @@ where
         component_type: ComponentType,
         data: Vec<u8>,
         files: Option<InitialComponentFilesArchiveAndPermissions>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError> {
         info!(namespace = %namespace, "Create component");
@@ -664,6 +677,7 @@ where
             component_type,
             data,
             uploaded_files,
+            limits,
             namespace,
         )
         .await
@@ -676,6 +690,7 @@ where
         component_type: ComponentType,
         data: Vec<u8>,
         files: Vec<InitialComponentFile>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError> {
         info!(namespace = %namespace, "Create component");
@@ -709,6 +724,7 @@ where
             component_type,
             data,
             files,
+            limits,
             namespace,
         )
         .await
@@ -720,6 +736,7 @@ where
         data: Vec<u8>,
         component_type: Option<ComponentType>,
         files: Option<InitialComponentFilesArchiveAndPermissions>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError> {
         info!(namespace = %namespace, "Update component");
@@ -737,6 +754,7 @@ where
             data,
             component_type,
             uploaded_files,
+            limits,
             namespace,
         )
         .await
@@ -748,6 +766,7 @@ where
         data: Vec<u8>,
         component_type: Option<ComponentType>,
         files: Option<Vec<InitialComponentFile>>,
+        limits: Option<WorkerResourceLimits>,
         namespace: &Namespace,
     ) -> Result<Component<Namespace>, ComponentError> {
         info!(namespace = %namespace, "Update component");
@@ -771,7 +790,7 @@ where
             }
         }
 
-        self.update_unchecked(component_id, data, component_type, files, namespace)
+        self.update_unchecked(component_id, data, component_type, files, limits, namespace)
             .await
     }
 
@@ -170,6 +170,7 @@ async fn test_component_constraint_incompatible_updates(
             ComponentType::Durable,
             get_component_data("shopping-cart"),
             None,
+            None,
             &DefaultNamespace::default(),
         )
         .await
@@ -202,6 +203,7 @@ async fn test_component_constraint_incompatible_updates(
             get_component_data("shopping-cart"),
             None,
             None,
+            None,
             &DefaultNamespace::default(),
         )
         .await
@@ -274,6 +276,7 @@ async fn test_complex_component_service_flow(
             ComponentType::Durable,
             get_component_data("shopping-cart"),
             None,
+            None,
             &DefaultNamespace::default(),
         )
         .await
@@ -286,6 +289,7 @@ async fn test_complex_component_service_flow(
             ComponentType::Durable,
             get_component_data("rust-echo"),
             None,
+            None,
             &DefaultNamespace::default(),
         )
         .await
@@ -374,6 +378,7 @@ async fn test_complex_component_service_flow(
             get_component_data("shopping-cart"),
             None,
             None,
+            None,
             &DefaultNamespace::default(),
         )
         .await
@@ -555,6 +560,7 @@ async fn test_initial_component_file_upload(
                     permissions: ComponentFilePermissions::ReadWrite,
                 }],
             }),
+            None,
             &DefaultNamespace::default(),
         )
         .await
@@ -604,6 +610,7 @@ async fn test_initial_component_file_data_sharing(
                 archive: File::open(COMPONENT_ARCHIVE).await.unwrap(),
                 files: vec![],
             }),
+            None,
             &DefaultNamespace::default(),
         )
         .await
@@ -621,6 +628,7 @@ async fn test_initial_component_file_data_sharing(
                     permissions: ComponentFilePermissions::ReadWrite,
                 }],
             }),
+            None,
             &DefaultNamespace::default(),
         )
         .await
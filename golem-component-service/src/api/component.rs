@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use futures_util::TryStreamExt;
+use golem_common::model::component_metadata::WorkerResourceLimits;
 use golem_common::model::{ComponentFilePathWithPermissionsList, ComponentId, ComponentType};
 use golem_component_service_base::model::InitialComponentFilesArchiveAndPermissions;
 use golem_component_service_base::service::component::{
@@ -71,6 +72,7 @@ pub struct UploadPayload {
     component: Upload,
     files_permissions: Option<ComponentFilePathWithPermissionsList>,
     files: Option<Upload>,
+    limits: Option<WorkerResourceLimits>,
 }
 
 #[derive(Multipart)]
@@ -80,6 +82,7 @@ pub struct UpdatePayload {
     component: Upload,
     files_permissions: Option<ComponentFilePathWithPermissionsList>,
     files: Option<Upload>,
+    limits: Option<WorkerResourceLimits>,
 }
 
 type Result<T> = std::result::Result<T, ComponentError>;
@@ -202,6 +205,7 @@ impl ComponentApi {
                     payload.component_type.unwrap_or(ComponentType::Durable),
                     data,
                     files,
+                    payload.limits,
                     &DefaultNamespace::default(),
                 )
                 .instrument(record.span.clone())
@@ -240,6 +244,7 @@ impl ComponentApi {
                     data,
                     component_type.0,
                     None,
+                    None,
                     &DefaultNamespace::default(),
                 )
                 .instrument(record.span.clone())
@@ -284,6 +289,7 @@ impl ComponentApi {
                     data,
                     payload.component_type,
                     files,
+                    payload.limits,
                     &DefaultNamespace::default(),
                 )
                 .instrument(record.span.clone())
@@ -181,6 +181,7 @@ impl ComponentGrpcApi {
                 request.component_type().into(),
                 data,
                 files,
+                request.limits.map(|limits| limits.into()),
                 &DefaultNamespace::default(),
             )
             .await?;
@@ -224,6 +225,7 @@ impl ComponentGrpcApi {
                 data,
                 component_type,
                 files,
+                request.limits.map(|limits| limits.into()),
                 &DefaultNamespace::default(),
             )
             .await?;
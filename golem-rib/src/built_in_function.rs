@@ -0,0 +1,197 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use std::fmt::Display;
+
+// The functions of the Rib standard library. Unlike worker functions, these are pure
+// and are evaluated by the interpreter itself, without invoking a worker.
+// A function exported by a component takes precedence over a built-in function of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum BuiltInFunction {
+    // string
+    ToUpper,
+    ToLower,
+    Split,
+    Trim,
+    Substring,
+    ToString,
+    ParseU64,
+    // list (`contains` and `length` work on strings as well)
+    Contains,
+    Length,
+    Flatten,
+    Sort,
+    // option and result
+    IsSome,
+    IsNone,
+    IsOk,
+    IsErr,
+    UnwrapOr,
+    // math
+    Min,
+    Max,
+    Abs,
+    // encoding
+    Base64Encode,
+    Base64Decode,
+    UrlEncode,
+    UrlDecode,
+}
+
+impl BuiltInFunction {
+    pub fn all() -> Vec<BuiltInFunction> {
+        vec![
+            BuiltInFunction::ToUpper,
+            BuiltInFunction::ToLower,
+            BuiltInFunction::Split,
+            BuiltInFunction::Trim,
+            BuiltInFunction::Substring,
+            BuiltInFunction::ToString,
+            BuiltInFunction::ParseU64,
+            BuiltInFunction::Contains,
+            BuiltInFunction::Length,
+            BuiltInFunction::Flatten,
+            BuiltInFunction::Sort,
+            BuiltInFunction::IsSome,
+            BuiltInFunction::IsNone,
+            BuiltInFunction::IsOk,
+            BuiltInFunction::IsErr,
+            BuiltInFunction::UnwrapOr,
+            BuiltInFunction::Min,
+            BuiltInFunction::Max,
+            BuiltInFunction::Abs,
+            BuiltInFunction::Base64Encode,
+            BuiltInFunction::Base64Decode,
+            BuiltInFunction::UrlEncode,
+            BuiltInFunction::UrlDecode,
+        ]
+    }
+
+    pub fn from_name(name: &str) -> Option<BuiltInFunction> {
+        BuiltInFunction::all()
+            .into_iter()
+            .find(|function| function.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuiltInFunction::ToUpper => "to-upper",
+            BuiltInFunction::ToLower => "to-lower",
+            BuiltInFunction::Split => "split",
+            BuiltInFunction::Trim => "trim",
+            BuiltInFunction::Substring => "substring",
+            BuiltInFunction::ToString => "to-string",
+            BuiltInFunction::ParseU64 => "parse-u64",
+            BuiltInFunction::Contains => "contains",
+            BuiltInFunction::Length => "length",
+            BuiltInFunction::Flatten => "flatten",
+            BuiltInFunction::Sort => "sort",
+            BuiltInFunction::IsSome => "is-some",
+            BuiltInFunction::IsNone => "is-none",
+            BuiltInFunction::IsOk => "is-ok",
+            BuiltInFunction::IsErr => "is-err",
+            BuiltInFunction::UnwrapOr => "unwrap-or",
+            BuiltInFunction::Min => "min",
+            BuiltInFunction::Max => "max",
+            BuiltInFunction::Abs => "abs",
+            BuiltInFunction::Base64Encode => "base64-encode",
+            BuiltInFunction::Base64Decode => "base64-decode",
+            BuiltInFunction::UrlEncode => "url-encode",
+            BuiltInFunction::UrlDecode => "url-decode",
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        match self {
+            BuiltInFunction::ToUpper
+            | BuiltInFunction::ToLower
+            | BuiltInFunction::Trim
+            | BuiltInFunction::ToString
+            | BuiltInFunction::ParseU64
+            | BuiltInFunction::Length
+            | BuiltInFunction::Flatten
+            | BuiltInFunction::Sort
+            | BuiltInFunction::IsSome
+            | BuiltInFunction::IsNone
+            | BuiltInFunction::IsOk
+            | BuiltInFunction::IsErr
+            | BuiltInFunction::Abs
+            | BuiltInFunction::Base64Encode
+            | BuiltInFunction::Base64Decode
+            | BuiltInFunction::UrlEncode
+            | BuiltInFunction::UrlDecode => 1,
+            BuiltInFunction::Split
+            | BuiltInFunction::Contains
+            | BuiltInFunction::UnwrapOr
+            | BuiltInFunction::Min
+            | BuiltInFunction::Max => 2,
+            BuiltInFunction::Substring => 3,
+        }
+    }
+}
+
+impl Display for BuiltInFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// The functions of the Rib standard library that take a function (a lambda, or the name of a function)
+// as their last argument. Rib has no function values at runtime, hence these are desugared into
+// list comprehensions and pattern matches calling the function, which is then inlined like any other call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HigherOrderFunction {
+    // list
+    Map,
+    Filter,
+    // option and result
+    MapSome,
+    MapOk,
+    MapErr,
+}
+
+impl HigherOrderFunction {
+    pub fn all() -> Vec<HigherOrderFunction> {
+        vec![
+            HigherOrderFunction::Map,
+            HigherOrderFunction::Filter,
+            HigherOrderFunction::MapSome,
+            HigherOrderFunction::MapOk,
+            HigherOrderFunction::MapErr,
+        ]
+    }
+
+    pub fn from_name(name: &str) -> Option<HigherOrderFunction> {
+        HigherOrderFunction::all()
+            .into_iter()
+            .find(|function| function.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HigherOrderFunction::Map => "map",
+            HigherOrderFunction::Filter => "filter",
+            HigherOrderFunction::MapSome => "map-some",
+            HigherOrderFunction::MapOk => "map-ok",
+            HigherOrderFunction::MapErr => "map-err",
+        }
+    }
+}
+
+impl Display for HigherOrderFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
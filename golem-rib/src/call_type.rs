@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DynamicParsedFunctionName, ParsedFunctionName};
+use crate::{
+    BuiltInFunction, DynamicParsedFunctionName, DynamicParsedFunctionReference, ParsedFunctionName,
+    ParsedFunctionSite,
+};
 use bincode::{Decode, Encode};
 use std::convert::TryFrom;
 use std::fmt::Display;
@@ -22,6 +25,7 @@ pub enum CallType {
     Function(DynamicParsedFunctionName),
     VariantConstructor(String),
     EnumConstructor(String),
+    BuiltIn(BuiltInFunction),
 }
 
 impl CallType {
@@ -43,6 +47,7 @@ impl Display for CallType {
             CallType::Function(parsed_fn_name) => write!(f, "{}", parsed_fn_name),
             CallType::VariantConstructor(name) => write!(f, "{}", name),
             CallType::EnumConstructor(name) => write!(f, "{}", name),
+            CallType::BuiltIn(built_in_function) => write!(f, "{}", built_in_function),
         }
     }
 }
@@ -83,6 +88,19 @@ impl From<CallType> for golem_api_grpc::proto::golem::rib::CallType {
                     golem_api_grpc::proto::golem::rib::call_type::Name::EnumConstructor(name),
                 ),
             },
+            // A built-in function call is kept as a plain function call,
+            // which will be resolved to the built-in function again while type inference
+            CallType::BuiltIn(built_in_function) => golem_api_grpc::proto::golem::rib::CallType {
+                name: Some(golem_api_grpc::proto::golem::rib::call_type::Name::Parsed(
+                    DynamicParsedFunctionName {
+                        site: ParsedFunctionSite::Global,
+                        function: DynamicParsedFunctionReference::Function {
+                            function: built_in_function.name().to_string(),
+                        },
+                    }
+                    .into(),
+                )),
+            },
         }
     }
 }
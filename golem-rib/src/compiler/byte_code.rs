@@ -14,7 +14,7 @@
 
 use crate::compiler::byte_code::internal::ExprState;
 use crate::compiler::ir::RibIR;
-use crate::{Expr, InferredExpr, InstructionId};
+use crate::{Expr, InferredExpr, InstructionId, RibCompilationError};
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::rib::RibByteCode as ProtoRibByteCode;
 
@@ -25,7 +25,7 @@ pub struct RibByteCode {
 
 impl RibByteCode {
     // Convert expression to bytecode instructions
-    pub fn from_expr(inferred_expr: &InferredExpr) -> Result<RibByteCode, String> {
+    pub fn from_expr(inferred_expr: &InferredExpr) -> Result<RibByteCode, RibCompilationError> {
         let expr = Expr::from(inferred_expr.clone());
         let mut instructions = Vec::new();
         let mut stack: Vec<ExprState> = Vec::new();
@@ -40,7 +40,8 @@ impl RibByteCode {
                         &mut stack,
                         &mut instructions,
                         &mut instruction_id,
-                    )?;
+                    )
+                    .map_err(|error| RibCompilationError::new(error, expr.source_span()))?;
                 }
 
                 ExprState::Instruction(instruction) => {
@@ -86,7 +87,7 @@ mod internal {
     use crate::compiler::desugar::desugar_pattern_match;
     use crate::{
         AnalysedTypeWithUnit, DynamicParsedFunctionReference, Expr, FunctionReferenceType,
-        InferredType, InstructionId, RibIR, VariableId,
+        InferredType, InstructionId, RibIR, SourceSpan, VariableId,
     };
     use golem_wasm_ast::analysis::AnalysedType;
     use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -102,21 +103,28 @@ mod internal {
         instruction_id: &mut InstructionId,
     ) -> Result<(), String> {
         match expr {
-            Expr::Unwrap(inner_expr, _) => {
+            Expr::Unwrap(inner_expr, _, _) => {
                 stack.push(ExprState::from_expr(inner_expr.deref()));
                 instructions.push(RibIR::Deconstruct);
             }
-            Expr::Throw(msg, _) => {
+            Expr::Throw(msg, _, _) => {
                 instructions.push(RibIR::Throw(msg.to_string()));
             }
-            Expr::Identifier(variable_id, _) => {
+            // Functions are inlined at their call sites during type inference
+            Expr::Lambda(_, _, _, _) => {
+                return Err(format!(
+                    "Invalid Rib {}. A function can only be bound to a variable and called",
+                    expr
+                ));
+            }
+            Expr::Identifier(variable_id, _, _) => {
                 instructions.push(RibIR::LoadVar(variable_id.clone()));
             }
-            Expr::Literal(str, _) => {
+            Expr::Literal(str, _, _) => {
                 let type_annotated_value = TypeAnnotatedValue::Str(str.clone());
                 instructions.push(RibIR::PushLit(type_annotated_value));
             }
-            Expr::Number(num, _, inferred_type) => {
+            Expr::Number(num, _, inferred_type, _) => {
                 let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
 
                 let type_annotated_value = num.to_val(&analysed_type).ok_or(format!(
@@ -126,96 +134,134 @@ mod internal {
 
                 instructions.push(RibIR::PushLit(type_annotated_value));
             }
-            Expr::EqualTo(lhs, rhs, _) => {
+            Expr::EqualTo(lhs, rhs, _, _) => {
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::EqualTo);
             }
-            Expr::GreaterThan(lhs, rhs, _) => {
+            Expr::NotEqualTo(lhs, rhs, _, _) => {
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::NotEqualTo);
+            }
+            Expr::GreaterThan(lhs, rhs, _, _) => {
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::GreaterThan);
             }
-            Expr::LessThan(lhs, rhs, _) => {
+            Expr::LessThan(lhs, rhs, _, _) => {
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::LessThan);
             }
-            Expr::GreaterThanOrEqualTo(lhs, rhs, _) => {
+            Expr::GreaterThanOrEqualTo(lhs, rhs, _, _) => {
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::GreaterThanOrEqualTo);
             }
-            Expr::LessThanOrEqualTo(lhs, rhs, _) => {
+            Expr::LessThanOrEqualTo(lhs, rhs, _, _) => {
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::LessThanOrEqualTo);
             }
-            Expr::Plus(lhs, rhs, inferred_type) => {
+            Expr::Plus(lhs, rhs, inferred_type, _) => {
                 let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::Plus(analysed_type));
             }
-            Expr::Minus(lhs, rhs, inferred_type) => {
+            Expr::Minus(lhs, rhs, inferred_type, _) => {
                 let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
 
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::Minus(analysed_type));
             }
-            Expr::Divide(lhs, rhs, inferred_type) => {
+            Expr::Divide(lhs, rhs, inferred_type, _) => {
                 let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
 
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::Divide(analysed_type));
             }
-            Expr::Multiply(lhs, rhs, inferred_type) => {
+            Expr::Multiply(lhs, rhs, inferred_type, _) => {
                 let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
 
                 stack.push(ExprState::from_expr(rhs.deref()));
                 stack.push(ExprState::from_expr(lhs.deref()));
                 instructions.push(RibIR::Multiply(analysed_type));
             }
-            Expr::And(lhs, rhs, _) => {
+            Expr::Modulo(lhs, rhs, inferred_type, _) => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                stack.push(ExprState::from_expr(rhs.deref()));
+                stack.push(ExprState::from_expr(lhs.deref()));
+                instructions.push(RibIR::Modulo(analysed_type));
+            }
+            Expr::Negative(inner_expr, inferred_type, _) => {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+
+                stack.push(ExprState::from_expr(inner_expr.deref()));
+                instructions.push(RibIR::Negative(analysed_type));
+            }
+            Expr::And(lhs, rhs, _, _) => {
                 // This optimization isn't optional, it's required for the correct functioning of the interpreter
                 let optimised_expr = Expr::cond(
                     Expr::EqualTo(
                         lhs.clone(),
-                        Box::new(Expr::Boolean(true, InferredType::Bool)),
+                        Box::new(Expr::Boolean(
+                            true,
+                            InferredType::Bool,
+                            SourceSpan::default(),
+                        )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
                     Expr::EqualTo(
                         rhs.clone(),
-                        Box::new(Expr::Boolean(true, InferredType::Bool)),
+                        Box::new(Expr::Boolean(
+                            true,
+                            InferredType::Bool,
+                            SourceSpan::default(),
+                        )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
-                    Expr::Boolean(false, InferredType::Bool),
+                    Expr::Boolean(false, InferredType::Bool, SourceSpan::default()),
                 );
 
                 stack.push(ExprState::from_expr(&optimised_expr));
             }
 
-            Expr::Or(lhs, rhs, _) => {
+            Expr::Or(lhs, rhs, _, _) => {
                 let optimised_expr = Expr::cond(
                     Expr::EqualTo(
                         lhs.clone(),
-                        Box::new(Expr::Boolean(true, InferredType::Bool)),
+                        Box::new(Expr::Boolean(
+                            true,
+                            InferredType::Bool,
+                            SourceSpan::default(),
+                        )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
-                    Expr::Boolean(true, InferredType::Bool),
+                    Expr::Boolean(true, InferredType::Bool, SourceSpan::default()),
                     Expr::EqualTo(
                         rhs.clone(),
-                        Box::new(Expr::Boolean(true, InferredType::Bool)),
+                        Box::new(Expr::Boolean(
+                            true,
+                            InferredType::Bool,
+                            SourceSpan::default(),
+                        )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
                 );
 
                 stack.push(ExprState::from_expr(&optimised_expr));
             }
 
-            Expr::Record(fields, inferred_type) => {
+            Expr::Record(fields, inferred_type, _) => {
                 // Push field instructions in reverse order
                 for (field_name, field_expr) in fields.iter().rev() {
                     stack.push(ExprState::from_expr(field_expr.as_ref()));
@@ -225,7 +271,7 @@ mod internal {
                 let analysed_type = convert_to_analysed_type(expr, inferred_type);
                 instructions.push(RibIR::CreateAndPushRecord(analysed_type?));
             }
-            Expr::Sequence(exprs, inferred_type) => {
+            Expr::Sequence(exprs, inferred_type, _) => {
                 // Push all expressions in reverse order
                 for expr in exprs.iter().rev() {
                     stack.push(ExprState::from_expr(expr));
@@ -234,23 +280,23 @@ mod internal {
                 let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
                 instructions.push(RibIR::PushList(analysed_type, exprs.len()));
             }
-            Expr::ExprBlock(exprs, _) => {
+            Expr::ExprBlock(exprs, _, _) => {
                 // Push all expressions in reverse order
                 for expr in exprs.iter() {
                     stack.push(ExprState::from_expr(expr));
                 }
             }
-            Expr::Let(variable_id, _, inner_expr, _) => {
+            Expr::Let(variable_id, _, inner_expr, _, _) => {
                 stack.push(ExprState::from_expr(inner_expr.deref()));
                 instructions.push(RibIR::AssignVar(variable_id.clone()));
             }
-            Expr::PatternMatch(pred, match_arms, inferred_type) => {
+            Expr::PatternMatch(pred, match_arms, inferred_type, _) => {
                 let desugared_pattern_match =
                     desugar_pattern_match(pred.deref(), match_arms, inferred_type.clone())
                         .ok_or("Desugar pattern match failed".to_string())?;
                 stack.push(ExprState::from_expr(&desugared_pattern_match));
             }
-            Expr::Cond(if_expr, then_expr, else_expr, _) => {
+            Expr::Cond(if_expr, then_expr, else_expr, _, _) => {
                 handle_if_condition(
                     instruction_id,
                     if_expr.deref(),
@@ -260,15 +306,15 @@ mod internal {
                 );
             }
 
-            Expr::SelectField(record_expr, field_name, _) => {
+            Expr::SelectField(record_expr, field_name, _, _) => {
                 stack.push(ExprState::from_expr(record_expr.deref()));
                 instructions.push(RibIR::SelectField(field_name.clone()));
             }
-            Expr::SelectIndex(sequence_expr, index, _) => {
+            Expr::SelectIndex(sequence_expr, index, _, _) => {
                 stack.push(ExprState::from_expr(sequence_expr.deref()));
                 instructions.push(RibIR::SelectIndex(*index));
             }
-            Expr::Option(Some(inner_expr), inferred_type) => {
+            Expr::Option(Some(inner_expr), inferred_type, _) => {
                 stack.push(ExprState::from_expr(inner_expr.deref()));
                 instructions.push(RibIR::PushSome(convert_to_analysed_type(
                     expr,
@@ -276,12 +322,12 @@ mod internal {
                 )?));
             }
 
-            Expr::Option(None, inferred_type) => {
+            Expr::Option(None, inferred_type, _) => {
                 let optional = convert_to_analysed_type(expr, inferred_type);
                 instructions.push(RibIR::PushNone(optional.ok()));
             }
 
-            Expr::Result(Ok(inner_expr), inferred_type) => {
+            Expr::Result(Ok(inner_expr), inferred_type, _) => {
                 stack.push(ExprState::from_expr(inner_expr.deref()));
                 instructions.push(RibIR::PushOkResult(convert_to_analysed_type(
                     expr,
@@ -289,7 +335,7 @@ mod internal {
                 )?));
             }
 
-            Expr::Result(Err(inner_expr), inferred_type) => {
+            Expr::Result(Err(inner_expr), inferred_type, _) => {
                 stack.push(ExprState::from_expr(inner_expr.deref()));
                 instructions.push(RibIR::PushErrResult(convert_to_analysed_type(
                     expr,
@@ -297,7 +343,7 @@ mod internal {
                 )?));
             }
 
-            Expr::Call(call_type, arguments, inferred_type) => {
+            Expr::Call(call_type, arguments, inferred_type, _) => {
                 for expr in arguments.iter().rev() {
                     stack.push(ExprState::from_expr(expr));
                 }
@@ -444,10 +490,20 @@ mod internal {
                             convert_to_analysed_type(expr, inferred_type)?,
                         ));
                     }
+
+                    // Built-in functions are evaluated by the interpreter itself
+                    // and there is no function name to be resolved
+                    CallType::BuiltIn(built_in_function) => {
+                        instructions.push(RibIR::InvokeBuiltInFunction(
+                            *built_in_function,
+                            arguments.len(),
+                            convert_to_analysed_type(expr, inferred_type)?,
+                        ));
+                    }
                 }
             }
 
-            Expr::Flags(flag_values, inferred_type) => match inferred_type {
+            Expr::Flags(flag_values, inferred_type, _) => match inferred_type {
                 InferredType::Flags(all_flags) => {
                     instructions.push(RibIR::PushFlag(TypeAnnotatedValue::Flags(TypedFlags {
                         typ: all_flags.clone(),
@@ -461,27 +517,27 @@ mod internal {
                     ));
                 }
             },
-            Expr::Boolean(bool, _) => {
+            Expr::Boolean(bool, _, _) => {
                 instructions.push(RibIR::PushLit(TypeAnnotatedValue::Bool(*bool)));
             }
-            Expr::GetTag(expr, _) => {
+            Expr::GetTag(expr, _, _) => {
                 stack.push(ExprState::from_expr(expr.deref()));
                 stack.push(ExprState::from_ir(RibIR::GetTag));
             }
 
-            Expr::Concat(exprs, _) => {
+            Expr::Concat(exprs, _, _) => {
                 for expr in exprs.iter().rev() {
                     stack.push(ExprState::from_expr(expr));
                 }
                 instructions.push(RibIR::Concat(exprs.len()));
             }
 
-            Expr::Not(expr, _) => {
+            Expr::Not(expr, _, _) => {
                 stack.push(ExprState::from_expr(expr.deref()));
                 instructions.push(RibIR::Negate);
             }
 
-            Expr::Tuple(exprs, analysed_type) => {
+            Expr::Tuple(exprs, analysed_type, _) => {
                 for expr in exprs.iter().rev() {
                     stack.push(ExprState::from_expr(expr));
                 }
@@ -682,14 +738,20 @@ mod compiler_tests {
     use test_r::test;
 
     use super::*;
-    use crate::{ArmPattern, FunctionTypeRegistry, InferredType, MatchArm, Number, VariableId};
+    use crate::{
+        ArmPattern, FunctionTypeRegistry, InferredType, MatchArm, Number, SourceSpan, VariableId,
+    };
     use golem_wasm_ast::analysis::analysed_type::{list, str};
     use golem_wasm_ast::analysis::{AnalysedType, NameTypePair, TypeRecord, TypeStr};
     use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
     #[test]
     fn test_instructions_for_literal() {
-        let literal = Expr::Literal("hello".to_string(), InferredType::Str);
+        let literal = Expr::Literal(
+            "hello".to_string(),
+            InferredType::Str,
+            SourceSpan::default(),
+        );
         let empty_registry = FunctionTypeRegistry::empty();
         let inferred_expr = InferredExpr::from_expr(&literal, &empty_registry).unwrap();
 
@@ -709,7 +771,11 @@ mod compiler_tests {
         let inferred_input_type = InferredType::Str;
         let variable_id = VariableId::local("request", 0);
         let empty_registry = FunctionTypeRegistry::empty();
-        let expr = Expr::Identifier(variable_id.clone(), inferred_input_type);
+        let expr = Expr::Identifier(
+            variable_id.clone(),
+            inferred_input_type,
+            SourceSpan::default(),
+        );
         let inferred_expr = InferredExpr::from_expr(&expr, &empty_registry).unwrap();
 
         let instructions = RibByteCode::from_expr(&inferred_expr).unwrap();
@@ -725,7 +791,11 @@ mod compiler_tests {
 
     #[test]
     fn test_instructions_assign_variable() {
-        let literal = Expr::Literal("hello".to_string(), InferredType::Str);
+        let literal = Expr::Literal(
+            "hello".to_string(),
+            InferredType::Str,
+            SourceSpan::default(),
+        );
 
         let variable_id = VariableId::local("request", 0);
 
@@ -734,6 +804,7 @@ mod compiler_tests {
             None,
             Box::new(literal),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let empty_registry = FunctionTypeRegistry::empty();
@@ -755,8 +826,18 @@ mod compiler_tests {
 
     #[test]
     fn test_instructions_equal_to() {
-        let number_f32 = Expr::Number(Number { value: 1f64 }, None, InferredType::F32);
-        let number_u32 = Expr::Number(Number { value: 1f64 }, None, InferredType::U32);
+        let number_f32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::F32,
+            SourceSpan::default(),
+        );
+        let number_u32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::U32,
+            SourceSpan::default(),
+        );
 
         let expr = Expr::equal_to(number_f32, number_u32);
         let empty_registry = FunctionTypeRegistry::empty();
@@ -782,8 +863,18 @@ mod compiler_tests {
 
     #[test]
     fn test_instructions_greater_than() {
-        let number_f32 = Expr::Number(Number { value: 1f64 }, None, InferredType::F32);
-        let number_u32 = Expr::Number(Number { value: 2f64 }, None, InferredType::U32);
+        let number_f32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::F32,
+            SourceSpan::default(),
+        );
+        let number_u32 = Expr::Number(
+            Number { value: 2f64 },
+            None,
+            InferredType::U32,
+            SourceSpan::default(),
+        );
 
         let expr = Expr::greater_than(number_f32, number_u32);
         let empty_registry = FunctionTypeRegistry::empty();
@@ -809,8 +900,18 @@ mod compiler_tests {
 
     #[test]
     fn test_instructions_less_than() {
-        let number_f32 = Expr::Number(Number { value: 1f64 }, None, InferredType::F32);
-        let number_u32 = Expr::Number(Number { value: 1f64 }, None, InferredType::U32);
+        let number_f32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::F32,
+            SourceSpan::default(),
+        );
+        let number_u32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::U32,
+            SourceSpan::default(),
+        );
 
         let expr = Expr::less_than(number_f32, number_u32);
         let empty_registry = FunctionTypeRegistry::empty();
@@ -836,8 +937,18 @@ mod compiler_tests {
 
     #[test]
     fn test_instructions_greater_than_or_equal_to() {
-        let number_f32 = Expr::Number(Number { value: 1f64 }, None, InferredType::F32);
-        let number_u32 = Expr::Number(Number { value: 1f64 }, None, InferredType::U32);
+        let number_f32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::F32,
+            SourceSpan::default(),
+        );
+        let number_u32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::U32,
+            SourceSpan::default(),
+        );
 
         let expr = Expr::greater_than_or_equal_to(number_f32, number_u32);
         let empty_registry = FunctionTypeRegistry::empty();
@@ -863,8 +974,18 @@ mod compiler_tests {
 
     #[test]
     fn test_instructions_less_than_or_equal_to() {
-        let number_f32 = Expr::Number(Number { value: 1f64 }, None, InferredType::F32);
-        let number_u32 = Expr::Number(Number { value: 1f64 }, None, InferredType::U32);
+        let number_f32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::F32,
+            SourceSpan::default(),
+        );
+        let number_u32 = Expr::Number(
+            Number { value: 1f64 },
+            None,
+            InferredType::U32,
+            SourceSpan::default(),
+        );
 
         let expr = Expr::less_than_or_equal_to(number_f32, number_u32);
         let empty_registry = FunctionTypeRegistry::empty();
@@ -894,17 +1015,26 @@ mod compiler_tests {
             vec![
                 (
                     "foo_key".to_string(),
-                    Box::new(Expr::Literal("foo_value".to_string(), InferredType::Str)),
+                    Box::new(Expr::Literal(
+                        "foo_value".to_string(),
+                        InferredType::Str,
+                        SourceSpan::default(),
+                    )),
                 ),
                 (
                     "bar_key".to_string(),
-                    Box::new(Expr::Literal("bar_value".to_string(), InferredType::Str)),
+                    Box::new(Expr::Literal(
+                        "bar_value".to_string(),
+                        InferredType::Str,
+                        SourceSpan::default(),
+                    )),
                 ),
             ],
             InferredType::Record(vec![
                 (String::from("foo_key"), InferredType::Str),
                 (String::from("bar_key"), InferredType::Str),
             ]),
+            SourceSpan::default(),
         );
 
         let empty_registry = FunctionTypeRegistry::empty();
@@ -945,10 +1075,11 @@ mod compiler_tests {
     fn test_instructions_for_multiple() {
         let expr = Expr::ExprBlock(
             vec![
-                Expr::Literal("foo".to_string(), InferredType::Str),
-                Expr::Literal("bar".to_string(), InferredType::Str),
+                Expr::Literal("foo".to_string(), InferredType::Str, SourceSpan::default()),
+                Expr::Literal("bar".to_string(), InferredType::Str, SourceSpan::default()),
             ],
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let empty_registry = FunctionTypeRegistry::empty();
@@ -970,15 +1101,20 @@ mod compiler_tests {
 
     #[test]
     fn test_instructions_if_conditional() {
-        let if_expr = Expr::Literal("pred".to_string(), InferredType::Bool);
-        let then_expr = Expr::Literal("then".to_string(), InferredType::Str);
-        let else_expr = Expr::Literal("else".to_string(), InferredType::Str);
+        let if_expr = Expr::Literal(
+            "pred".to_string(),
+            InferredType::Bool,
+            SourceSpan::default(),
+        );
+        let then_expr = Expr::Literal("then".to_string(), InferredType::Str, SourceSpan::default());
+        let else_expr = Expr::Literal("else".to_string(), InferredType::Str, SourceSpan::default());
 
         let expr = Expr::Cond(
             Box::new(if_expr),
             Box::new(then_expr),
             Box::new(else_expr),
             InferredType::Str,
+            SourceSpan::default(),
         );
 
         let empty_registry = FunctionTypeRegistry::empty();
@@ -1005,13 +1141,34 @@ mod compiler_tests {
 
     #[test]
     fn test_instructions_for_nested_if_else() {
-        let if_expr = Expr::Literal("if-pred1".to_string(), InferredType::Bool);
-        let then_expr = Expr::Literal("then1".to_string(), InferredType::Str);
+        let if_expr = Expr::Literal(
+            "if-pred1".to_string(),
+            InferredType::Bool,
+            SourceSpan::default(),
+        );
+        let then_expr = Expr::Literal(
+            "then1".to_string(),
+            InferredType::Str,
+            SourceSpan::default(),
+        );
         let else_expr = Expr::Cond(
-            Box::new(Expr::Literal("else-pred2".to_string(), InferredType::Bool)),
-            Box::new(Expr::Literal("else-then2".to_string(), InferredType::Str)),
-            Box::new(Expr::Literal("else-else2".to_string(), InferredType::Str)),
+            Box::new(Expr::Literal(
+                "else-pred2".to_string(),
+                InferredType::Bool,
+                SourceSpan::default(),
+            )),
+            Box::new(Expr::Literal(
+                "else-then2".to_string(),
+                InferredType::Str,
+                SourceSpan::default(),
+            )),
+            Box::new(Expr::Literal(
+                "else-else2".to_string(),
+                InferredType::Str,
+                SourceSpan::default(),
+            )),
             InferredType::Str,
+            SourceSpan::default(),
         );
 
         let expr = Expr::Cond(
@@ -1019,6 +1176,7 @@ mod compiler_tests {
             Box::new(then_expr),
             Box::new(else_expr),
             InferredType::Str,
+            SourceSpan::default(),
         );
 
         let empty_registry = FunctionTypeRegistry::empty();
@@ -1056,20 +1214,34 @@ mod compiler_tests {
             vec![
                 (
                     "foo_key".to_string(),
-                    Box::new(Expr::Literal("foo_value".to_string(), InferredType::Str)),
+                    Box::new(Expr::Literal(
+                        "foo_value".to_string(),
+                        InferredType::Str,
+                        SourceSpan::default(),
+                    )),
                 ),
                 (
                     "bar_key".to_string(),
-                    Box::new(Expr::Literal("bar_value".to_string(), InferredType::Str)),
+                    Box::new(Expr::Literal(
+                        "bar_value".to_string(),
+                        InferredType::Str,
+                        SourceSpan::default(),
+                    )),
                 ),
             ],
             InferredType::Record(vec![
                 (String::from("foo_key"), InferredType::Str),
                 (String::from("bar_key"), InferredType::Str),
             ]),
+            SourceSpan::default(),
         );
 
-        let expr = Expr::SelectField(Box::new(record), "bar_key".to_string(), InferredType::Str);
+        let expr = Expr::SelectField(
+            Box::new(record),
+            "bar_key".to_string(),
+            InferredType::Str,
+            SourceSpan::default(),
+        );
 
         let empty_registry = FunctionTypeRegistry::empty();
         let inferred_expr = InferredExpr::from_expr(&expr, &empty_registry).unwrap();
@@ -1110,13 +1282,19 @@ mod compiler_tests {
     fn test_instructions_for_select_index() {
         let sequence = Expr::Sequence(
             vec![
-                Expr::Literal("foo".to_string(), InferredType::Str),
-                Expr::Literal("bar".to_string(), InferredType::Str),
+                Expr::Literal("foo".to_string(), InferredType::Str, SourceSpan::default()),
+                Expr::Literal("bar".to_string(), InferredType::Str, SourceSpan::default()),
             ],
             InferredType::List(Box::new(InferredType::Str)),
+            SourceSpan::default(),
         );
 
-        let expr = Expr::SelectIndex(Box::new(sequence), 1, InferredType::Str);
+        let expr = Expr::SelectIndex(
+            Box::new(sequence),
+            1,
+            InferredType::Str,
+            SourceSpan::default(),
+        );
 
         let empty_registry = FunctionTypeRegistry::empty();
         let inferred_expr = InferredExpr::from_expr(&expr, &empty_registry).unwrap();
@@ -1140,31 +1318,51 @@ mod compiler_tests {
     #[test]
     fn test_instructions_for_expr_arm_pattern_match() {
         let expr = Expr::PatternMatch(
-            Box::new(Expr::Literal("pred".to_string(), InferredType::Str)),
+            Box::new(Expr::Literal(
+                "pred".to_string(),
+                InferredType::Str,
+                SourceSpan::default(),
+            )),
             vec![
                 MatchArm::new(
                     ArmPattern::Literal(Box::new(Expr::Literal(
                         "arm1_pattern_expr".to_string(),
                         InferredType::Str,
+                        SourceSpan::default(),
                     ))),
-                    Expr::Literal("arm1_resolution_expr".to_string(), InferredType::Str),
+                    Expr::Literal(
+                        "arm1_resolution_expr".to_string(),
+                        InferredType::Str,
+                        SourceSpan::default(),
+                    ),
                 ),
                 MatchArm::new(
                     ArmPattern::Literal(Box::new(Expr::Literal(
                         "arm2_pattern_expr".to_string(),
                         InferredType::Str,
+                        SourceSpan::default(),
                     ))),
-                    Expr::Literal("arm2_resolution_expr".to_string(), InferredType::Str),
+                    Expr::Literal(
+                        "arm2_resolution_expr".to_string(),
+                        InferredType::Str,
+                        SourceSpan::default(),
+                    ),
                 ),
                 MatchArm::new(
                     ArmPattern::Literal(Box::new(Expr::Literal(
                         "arm3_pattern_expr".to_string(),
                         InferredType::Str,
+                        SourceSpan::default(),
                     ))),
-                    Expr::Literal("arm3_resolution_expr".to_string(), InferredType::Str),
+                    Expr::Literal(
+                        "arm3_resolution_expr".to_string(),
+                        InferredType::Str,
+                        SourceSpan::default(),
+                    ),
                 ),
             ],
             InferredType::Str,
+            SourceSpan::default(),
         );
 
         let empty_registry = FunctionTypeRegistry::empty();
@@ -1224,7 +1422,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &vec![]).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &vec![]).unwrap_err().message;
 
             assert_eq!(compiler_error, "Unknown function call: `foo`");
         }
@@ -1240,7 +1438,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Unknown resource constructor call: `golem:it/api.{cart0(user_id).add-item}`. Resource `cart0` doesn't exist"
@@ -1258,7 +1456,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Unknown resource method call `golem:it/api.{cart(user_id).foo}`. `foo` doesn't exist in resource `cart`"
@@ -1280,7 +1478,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Incorrect number of arguments for function `foo`. Expected 1, but provided 2"
@@ -1297,7 +1495,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Incorrect number of arguments for resource constructor `cart`. Expected 1, but provided 2"
@@ -1314,7 +1512,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Incorrect number of arguments in resource method `golem:it/api.{cart(user_id).add-item}`. Expected 1, but provided 2"
@@ -1332,7 +1530,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Invalid number of arguments in variant `register-user`. Expected 1, but provided 2"
@@ -1353,7 +1551,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Invalid type for the argument in function `foo`. Expected type `str`, but provided argument `1u64` is a `number`"
@@ -1370,7 +1568,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Invalid type for the argument in resource method `golem:it/api.{cart(user_id).add-item}`. Expected type `record`, but provided argument `\"apple\"` is a `str`"
@@ -1386,7 +1584,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Invalid type for the argument in resource constructor `cart`. Expected type `str`, but provided argument `{foo: \"bar\"}` is a `record`"
@@ -1404,7 +1602,7 @@ mod compiler_tests {
             "#;
 
             let expr = Expr::from_text(expr).unwrap();
-            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err();
+            let compiler_error = compiler::compile(&expr, &metadata).unwrap_err().message;
             assert_eq!(
                 compiler_error,
                 "Invalid type for the argument in variant constructor `register-user`. Expected type `number`, but provided argument `\"foo\"` is a `str`"
@@ -0,0 +1,68 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SourceSpan;
+use std::fmt::Display;
+
+// An error in compiling (type inference, type checking or byte code generation) a Rib script,
+// pointing to the expression it is found in. The source span is unknown
+// for errors that cannot be tied to a specific expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RibCompilationError {
+    pub message: String,
+    pub source_span: SourceSpan,
+}
+
+impl RibCompilationError {
+    pub fn new(message: impl AsRef<str>, source_span: SourceSpan) -> RibCompilationError {
+        RibCompilationError {
+            message: message.as_ref().to_string(),
+            source_span,
+        }
+    }
+
+    // Multiple errors reported together (Example: failures in unifying types)
+    // are reported at the first known source span
+    pub fn from_errors(errors: Vec<RibCompilationError>) -> RibCompilationError {
+        let source_span = errors
+            .iter()
+            .map(|error| error.source_span)
+            .find(|source_span| !source_span.is_unknown())
+            .unwrap_or_default();
+
+        let message = errors
+            .into_iter()
+            .map(|error| error.message)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        RibCompilationError::new(message, source_span)
+    }
+}
+
+impl From<String> for RibCompilationError {
+    fn from(message: String) -> Self {
+        RibCompilationError::new(message, SourceSpan::default())
+    }
+}
+
+impl Display for RibCompilationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.source_span.is_unknown() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{} (at {})", self.message, self.source_span)
+        }
+    }
+}
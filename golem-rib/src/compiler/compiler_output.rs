@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::compiler::worker_functions_in_rib::WorkerFunctionsInRib;
-use crate::{RibByteCode, RibInputTypeInfo};
+use crate::{RibByteCode, RibInputTypeInfo, RibOutputTypeInfo};
 use golem_api_grpc::proto::golem::rib::CompilerOutput as ProtoCompilerOutput;
 use std::convert::TryFrom;
 
@@ -22,6 +22,7 @@ pub struct CompilerOutput {
     pub worker_invoke_calls: Option<WorkerFunctionsInRib>,
     pub byte_code: RibByteCode,
     pub global_input_type_info: RibInputTypeInfo,
+    pub output_type_info: Option<RibOutputTypeInfo>,
 }
 
 impl TryFrom<ProtoCompilerOutput> for CompilerOutput {
@@ -37,11 +38,16 @@ impl TryFrom<ProtoCompilerOutput> for CompilerOutput {
         } else {
             None
         };
+        let output_type_info = value
+            .rib_output
+            .map(RibOutputTypeInfo::try_from)
+            .transpose()?;
 
         Ok(CompilerOutput {
             worker_invoke_calls: worker_invoke_callsxxxxxxxxx,
             byte_code,
             global_input_type_info: rib_input,
+            output_type_info,
         })
     }
 }
@@ -58,6 +64,9 @@ impl From<CompilerOutput> for ProtoCompilerOutput {
             worker_invoke_calls: value
                 .worker_invoke_calls
                 .map(golem_api_grpc::proto::golem::rib::WorkerFunctionsInRib::from),
+            rib_output: value
+                .output_type_info
+                .map(golem_api_grpc::proto::golem::rib::RibOutputType::from),
         }
     }
 }
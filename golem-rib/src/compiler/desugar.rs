@@ -32,18 +32,22 @@ pub fn desugar_pattern_match(
 
 mod internal {
     use crate::call_type::CallType;
-    use crate::{ArmPattern, Expr, InferredType, MatchArm, VariableId};
+    use crate::{ArmPattern, Expr, InferredType, MatchArm, SourceSpan, VariableId};
 
     pub(crate) fn build_expr_from(if_branches: Vec<IfThenBranch>) -> Option<Expr> {
         if let Some(branch) = if_branches.first() {
             let mut expr = Expr::cond(
                 branch.condition.clone(),
                 branch.body.clone(),
-                Expr::Throw("No match found".to_string(), InferredType::Unknown),
+                Expr::Throw(
+                    "No match found".to_string(),
+                    InferredType::Unknown,
+                    SourceSpan::default(),
+                ),
             );
 
             for branch in if_branches.iter().skip(1).rev() {
-                if let Expr::Cond(_, _, else_, _) = &mut expr {
+                if let Expr::Cond(_, _, else_, _, _) = &mut expr {
                     let else_copy = *else_.clone();
                     *else_ = Box::new(
                         Expr::cond(branch.condition.clone(), branch.body.clone(), else_copy)
@@ -157,12 +161,13 @@ mod internal {
         tag: Option<Expr>,
     ) -> Option<IfThenBranch> {
         match arm_pattern_expr {
-            Expr::Identifier(identifier, inferred_type) => {
+            Expr::Identifier(identifier, inferred_type, _) => {
                 let assign_var = Expr::Let(
                     identifier.clone(),
                     None,
                     Box::new(pred_expr.clone()),
                     inferred_type.clone(),
+                    SourceSpan::default(),
                 );
 
                 let block = Expr::expr_block(vec![assign_var, resolution.clone()]);
@@ -174,7 +179,7 @@ mod internal {
                 Some(branch)
             }
 
-            Expr::Call(CallType::EnumConstructor(name), _, _) => {
+            Expr::Call(CallType::EnumConstructor(name), _, _, _) => {
                 let cond = if let Some(t) = tag {
                     Expr::and(
                         t,
@@ -513,6 +518,7 @@ mod internal {
             None,
             Box::new(pred_expr.clone()),
             pred_expr.inferred_type(),
+            SourceSpan::default(),
         );
 
         let block = Expr::expr_block(vec![binding, resolution.clone()]);
@@ -577,7 +583,7 @@ mod desugar_tests {
         expr.infer_types(&function_type_registry).unwrap();
 
         let desugared_expr = match internal::last_expr(&expr) {
-            Expr::PatternMatch(predicate, match_arms, _) => {
+            Expr::PatternMatch(predicate, match_arms, _, _) => {
                 desugar_pattern_match(predicate.deref(), &match_arms, expr.inferred_type()).unwrap()
             }
             _ => panic!("Expected a match expression"),
@@ -591,12 +597,13 @@ mod desugar_tests {
 
         pub(crate) fn last_expr(expr: &Expr) -> Expr {
             match expr {
-                Expr::ExprBlock(exprs, _) => exprs.last().unwrap().clone(),
+                Expr::ExprBlock(exprs, _, _) => exprs.last().unwrap().clone(),
                 _ => expr.clone(),
             }
         }
     }
     mod expectations {
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, TypeName, VariableId};
         pub(crate) fn expected_condition_with_identifiers() -> Expr {
             Expr::Cond(
@@ -605,11 +612,18 @@ mod desugar_tests {
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::Option(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
+                    )),
+                    Box::new(Expr::Literal(
+                        "some".to_string(),
+                        InferredType::Str,
+                        SourceSpan::default(),
                     )),
-                    Box::new(Expr::Literal("some".to_string(), InferredType::Str)),
                     InferredType::Bool,
+                    SourceSpan::default(),
                 )),
                 Box::new(Expr::ExprBlock(
                     vec![
@@ -620,17 +634,22 @@ mod desugar_tests {
                                 Box::new(Expr::Identifier(
                                     VariableId::local("x", 0),
                                     InferredType::Option(Box::new(InferredType::U64)),
+                                    SourceSpan::default(),
                                 )),
                                 InferredType::Unknown,
+                                SourceSpan::default(),
                             )),
                             InferredType::U64,
+                            SourceSpan::default(),
                         ),
                         Expr::Identifier(
                             VariableId::match_identifier("x".to_string(), 1),
                             InferredType::U64,
+                            SourceSpan::default(),
                         ),
                     ],
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
                 Box::new(Expr::Cond(
                     Box::new(Expr::EqualTo(
@@ -638,24 +657,35 @@ mod desugar_tests {
                             Box::new(Expr::Identifier(
                                 VariableId::local("x", 0),
                                 InferredType::Option(Box::new(InferredType::U64)),
+                                SourceSpan::default(),
                             )),
                             InferredType::Unknown,
+                            SourceSpan::default(),
+                        )),
+                        Box::new(Expr::Literal(
+                            "none".to_string(),
+                            InferredType::Str,
+                            SourceSpan::default(),
                         )),
-                        Box::new(Expr::Literal("none".to_string(), InferredType::Str)),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     )),
                     Box::new(Expr::Number(
                         Number { value: 1f64 },
                         Some(TypeName::U64),
                         InferredType::U64,
+                        SourceSpan::default(),
                     )),
                     Box::new(Expr::Throw(
                         "No match found".to_string(),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     )),
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
                 InferredType::U64,
+                SourceSpan::default(),
             )
         }
     }
@@ -12,14 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AnalysedTypeWithUnit, ParsedFunctionSite, VariableId};
+use crate::{AnalysedTypeWithUnit, BuiltInFunction, ParsedFunctionSite, VariableId};
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::rib::rib_ir::Instruction;
 use golem_api_grpc::proto::golem::rib::{
     And, CallInstruction, ConcatInstruction, CreateFunctionNameInstruction, EqualTo, GetTag,
-    GreaterThan, GreaterThanOrEqualTo, IsEmpty, JumpInstruction, LessThan, LessThanOrEqualTo,
-    Negate, Or, PushListInstruction, PushNoneInstruction, PushTupleInstruction,
-    RibIr as ProtoRibIR,
+    GreaterThan, GreaterThanOrEqualTo, InvokeBuiltInFunctionInstruction, IsEmpty, JumpInstruction,
+    LessThan, LessThanOrEqualTo, Negate, NotEqualTo, Or, PushListInstruction, PushNoneInstruction,
+    PushTupleInstruction, RibIr as ProtoRibIR,
 };
 use golem_wasm_ast::analysis::{AnalysedType, TypeStr};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -43,6 +43,7 @@ pub enum RibIR {
     SelectField(String),
     SelectIndex(usize),
     EqualTo,
+    NotEqualTo,
     GreaterThan,
     And,
     Or,
@@ -56,6 +57,7 @@ pub enum RibIR {
     Deconstruct,
     CreateFunctionName(ParsedFunctionSite, FunctionReferenceType),
     InvokeFunction(usize, AnalysedTypeWithUnit),
+    InvokeBuiltInFunction(BuiltInFunction, usize, AnalysedType),
     PushVariant(String, AnalysedType), // There is no arg size since the type of each variant case is only 1 from beginning
     PushEnum(String, AnalysedType),
     Throw(String),
@@ -65,6 +67,8 @@ pub enum RibIR {
     Minus(AnalysedType),
     Divide(AnalysedType),
     Multiply(AnalysedType),
+    Modulo(AnalysedType),
+    Negative(AnalysedType),
     Negate,
     ListToIterator,
     CreateSink(AnalysedType),
@@ -316,6 +320,16 @@ impl TryFrom<ProtoRibIR> for RibIR {
                     "Failed to convert CreateAndPushRecord".to_string()
                 })?))
             }
+            Instruction::Modulo(value) => Ok(RibIR::Modulo(
+                (&value)
+                    .try_into()
+                    .map_err(|_| "Failed to convert Modulo".to_string())?,
+            )),
+            Instruction::Negative(value) => Ok(RibIR::Negative(
+                (&value)
+                    .try_into()
+                    .map_err(|_| "Failed to convert Negative".to_string())?,
+            )),
 
             Instruction::UpdateRecord(value) => Ok(RibIR::UpdateRecord(value)),
             Instruction::PushList(value) => Ok(RibIR::PushList(
@@ -355,6 +369,7 @@ impl TryFrom<ProtoRibIR> for RibIR {
             Instruction::SelectField(value) => Ok(RibIR::SelectField(value)),
             Instruction::SelectIndex(value) => Ok(RibIR::SelectIndex(value as usize)),
             Instruction::EqualTo(_) => Ok(RibIR::EqualTo),
+            Instruction::NotEqualTo(_) => Ok(RibIR::NotEqualTo),
             Instruction::GreaterThan(_) => Ok(RibIR::GreaterThan),
             Instruction::LessThan(_) => Ok(RibIR::LessThan),
             Instruction::GreaterThanOrEqualTo(_) => Ok(RibIR::GreaterThanOrEqualTo),
@@ -389,6 +404,29 @@ impl TryFrom<ProtoRibIR> for RibIR {
                     return_type,
                 ))
             }
+            Instruction::InvokeBuiltInFunction(invoke_built_in_function) => {
+                let built_in_function = BuiltInFunction::from_name(
+                    &invoke_built_in_function.function_name,
+                )
+                .ok_or(format!(
+                    "Unknown built-in function {}",
+                    invoke_built_in_function.function_name
+                ))?;
+
+                let return_type = invoke_built_in_function
+                    .return_type
+                    .ok_or("Missing return_type for built-in function call".to_string())?;
+
+                let analysed_return_type = (&return_type)
+                    .try_into()
+                    .map_err(|_| "Failed to convert AnalysedType".to_string())?;
+
+                Ok(RibIR::InvokeBuiltInFunction(
+                    built_in_function,
+                    invoke_built_in_function.argument_count as usize,
+                    analysed_return_type,
+                ))
+            }
             Instruction::VariantConstruction(variant_construction) => {
                 let variant_type = variant_construction
                     .return_type
@@ -492,6 +530,8 @@ impl From<RibIR> for ProtoRibIR {
             RibIR::Minus(value) => Instruction::Minus((&value).into()),
             RibIR::Multiply(value) => Instruction::Multiply((&value).into()),
             RibIR::Divide(value) => Instruction::Divide((&value).into()),
+            RibIR::Modulo(value) => Instruction::Modulo((&value).into()),
+            RibIR::Negative(value) => Instruction::Negative((&value).into()),
             RibIR::UpdateRecord(value) => Instruction::UpdateRecord(value),
             RibIR::PushList(value, arg_size) => Instruction::PushList(PushListInstruction {
                 list_type: Some((&value).into()),
@@ -509,6 +549,7 @@ impl From<RibIR> for ProtoRibIR {
             RibIR::SelectField(value) => Instruction::SelectField(value),
             RibIR::SelectIndex(value) => Instruction::SelectIndex(value as u64),
             RibIR::EqualTo => Instruction::EqualTo(EqualTo {}),
+            RibIR::NotEqualTo => Instruction::NotEqualTo(NotEqualTo {}),
             RibIR::GreaterThan => Instruction::GreaterThan(GreaterThan {}),
             RibIR::LessThan => Instruction::LessThan(LessThan {}),
             RibIR::GreaterThanOrEqualTo => {
@@ -539,6 +580,13 @@ impl From<RibIR> for ProtoRibIR {
                     return_type: typ,
                 })
             }
+            RibIR::InvokeBuiltInFunction(built_in_function, arg_count, return_type) => {
+                Instruction::InvokeBuiltInFunction(InvokeBuiltInFunctionInstruction {
+                    function_name: built_in_function.name().to_string(),
+                    argument_count: arg_count as u64,
+                    return_type: Some((&return_type).into()),
+                })
+            }
             RibIR::PushVariant(name, return_type) => {
                 let typ = golem_wasm_ast::analysis::protobuf::Type::from(&return_type);
 
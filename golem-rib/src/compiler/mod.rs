@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub use byte_code::*;
+pub use compiler_error::*;
 pub use compiler_output::*;
 use golem_wasm_ast::analysis::AnalysedExport;
 pub use ir::*;
@@ -20,9 +21,10 @@ pub use type_with_unit::*;
 pub use worker_functions_in_rib::*;
 
 use crate::type_registry::FunctionTypeRegistry;
-use crate::{Expr, InferredExpr, RibInputTypeInfo};
+use crate::{Expr, InferredExpr, RibInputTypeInfo, RibOutputTypeInfo};
 
 mod byte_code;
+mod compiler_error;
 mod compiler_output;
 mod desugar;
 mod ir;
@@ -32,7 +34,7 @@ mod worker_functions_in_rib;
 pub fn compile(
     expr: &Expr,
     export_metadata: &Vec<AnalysedExport>,
-) -> Result<CompilerOutput, String> {
+) -> Result<CompilerOutput, RibCompilationError> {
     compile_with_limited_globals(expr, export_metadata, None)
 }
 
@@ -43,7 +45,7 @@ pub fn compile_with_limited_globals(
     expr: &Expr,
     export_metadata: &Vec<AnalysedExport>,
     allowed_global_variables: Option<Vec<String>>,
-) -> Result<CompilerOutput, String> {
+) -> Result<CompilerOutput, RibCompilationError> {
     let type_registry = FunctionTypeRegistry::from_export_metadata(export_metadata);
     let inferred_expr = InferredExpr::from_expr(expr, &type_registry)?;
     let function_calls_identified =
@@ -62,19 +64,59 @@ pub fn compile_with_limited_globals(
         }
 
         if !un_allowed_variables.is_empty() {
-            return Err(format!(
-                "Global variables not allowed: {}. Allowed: {}",
-                un_allowed_variables.join(", "),
-                allowed_global_variables.join(", ")
+            return Err(RibCompilationError::new(
+                format!(
+                    "Global variables not allowed: {}. Allowed: {}",
+                    un_allowed_variables.join(", "),
+                    allowed_global_variables.join(", ")
+                ),
+                internal::global_variable_source_span(&inferred_expr, &un_allowed_variables),
             ));
         }
     }
 
+    let output_type_info = RibOutputTypeInfo::from_expr(&inferred_expr);
+
     let byte_code = RibByteCode::from_expr(&inferred_expr)?;
 
     Ok(CompilerOutput {
         worker_invoke_calls: function_calls_identified,
         byte_code,
         global_input_type_info,
+        output_type_info,
     })
 }
+
+mod internal {
+    use crate::{Expr, InferredExpr, SourceSpan};
+    use std::collections::VecDeque;
+
+    // The first use of any of the global variables in the expression
+    pub(crate) fn global_variable_source_span(
+        inferred_expr: &InferredExpr,
+        global_variables: &[String],
+    ) -> SourceSpan {
+        let expr = Expr::from(inferred_expr.clone());
+        let mut queue = VecDeque::new();
+        queue.push_back(&expr);
+
+        let mut source_spans = vec![];
+
+        while let Some(expr) = queue.pop_back() {
+            match expr {
+                Expr::Identifier(variable_id, _, source_span) => {
+                    if variable_id.is_global() && global_variables.contains(&variable_id.name()) {
+                        source_spans.push(*source_span);
+                    }
+                }
+                _ => expr.visit_children_bottom_up(&mut queue),
+            }
+        }
+
+        source_spans
+            .into_iter()
+            .filter(|source_span| !source_span.is_unknown())
+            .min_by_key(|source_span| source_span.start())
+            .unwrap_or_default()
+    }
+}
@@ -14,11 +14,12 @@
 
 use crate::call_type::CallType;
 use crate::parser::block::block;
+use crate::parser::source_position::inherit_source_spans;
 use crate::parser::type_name::TypeName;
 use crate::type_registry::FunctionTypeRegistry;
 use crate::{
     from_string, text, type_checker, type_inference, DynamicParsedFunctionName, InferredType,
-    ParsedFunctionName, VariableId,
+    ParsedFunctionName, RibCompilationError, SourceSpan, VariableId,
 };
 use bincode::{Decode, Encode};
 use combine::parser::char::spaces;
@@ -37,44 +38,55 @@ use std::ops::Deref;
 // https://github.com/golemcloud/golem/issues/1035
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
 pub enum Expr {
-    Let(VariableId, Option<TypeName>, Box<Expr>, InferredType),
-    SelectField(Box<Expr>, String, InferredType),
-    SelectIndex(Box<Expr>, usize, InferredType),
-    Sequence(Vec<Expr>, InferredType),
-    Record(Vec<(String, Box<Expr>)>, InferredType),
-    Tuple(Vec<Expr>, InferredType),
-    Literal(String, InferredType),
-    Number(Number, Option<TypeName>, InferredType),
-    Flags(Vec<String>, InferredType),
-    Identifier(VariableId, InferredType),
-    Boolean(bool, InferredType),
-    Concat(Vec<Expr>, InferredType),
-    ExprBlock(Vec<Expr>, InferredType),
-    Not(Box<Expr>, InferredType),
-    GreaterThan(Box<Expr>, Box<Expr>, InferredType),
-    And(Box<Expr>, Box<Expr>, InferredType),
-    Or(Box<Expr>, Box<Expr>, InferredType),
-    GreaterThanOrEqualTo(Box<Expr>, Box<Expr>, InferredType),
-    LessThanOrEqualTo(Box<Expr>, Box<Expr>, InferredType),
-    Plus(Box<Expr>, Box<Expr>, InferredType),
-    Multiply(Box<Expr>, Box<Expr>, InferredType),
-    Minus(Box<Expr>, Box<Expr>, InferredType),
-    Divide(Box<Expr>, Box<Expr>, InferredType),
-    EqualTo(Box<Expr>, Box<Expr>, InferredType),
-    LessThan(Box<Expr>, Box<Expr>, InferredType),
-    Cond(Box<Expr>, Box<Expr>, Box<Expr>, InferredType),
-    PatternMatch(Box<Expr>, Vec<MatchArm>, InferredType),
-    Option(Option<Box<Expr>>, InferredType),
-    Result(Result<Box<Expr>, Box<Expr>>, InferredType),
-    Call(CallType, Vec<Expr>, InferredType),
-    Unwrap(Box<Expr>, InferredType),
-    Throw(String, InferredType),
-    GetTag(Box<Expr>, InferredType),
+    Let(
+        VariableId,
+        Option<TypeName>,
+        Box<Expr>,
+        InferredType,
+        SourceSpan,
+    ),
+    SelectField(Box<Expr>, String, InferredType, SourceSpan),
+    SelectIndex(Box<Expr>, usize, InferredType, SourceSpan),
+    Sequence(Vec<Expr>, InferredType, SourceSpan),
+    Record(Vec<(String, Box<Expr>)>, InferredType, SourceSpan),
+    Tuple(Vec<Expr>, InferredType, SourceSpan),
+    Literal(String, InferredType, SourceSpan),
+    Number(Number, Option<TypeName>, InferredType, SourceSpan),
+    Flags(Vec<String>, InferredType, SourceSpan),
+    Identifier(VariableId, InferredType, SourceSpan),
+    Boolean(bool, InferredType, SourceSpan),
+    Concat(Vec<Expr>, InferredType, SourceSpan),
+    ExprBlock(Vec<Expr>, InferredType, SourceSpan),
+    Not(Box<Expr>, InferredType, SourceSpan),
+    Negative(Box<Expr>, InferredType, SourceSpan),
+    GreaterThan(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    And(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    Or(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    GreaterThanOrEqualTo(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    LessThanOrEqualTo(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    Plus(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    Multiply(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    Minus(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    Divide(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    Modulo(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    EqualTo(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    NotEqualTo(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    LessThan(Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    Cond(Box<Expr>, Box<Expr>, Box<Expr>, InferredType, SourceSpan),
+    PatternMatch(Box<Expr>, Vec<MatchArm>, InferredType, SourceSpan),
+    Option(Option<Box<Expr>>, InferredType, SourceSpan),
+    Result(Result<Box<Expr>, Box<Expr>>, InferredType, SourceSpan),
+    Call(CallType, Vec<Expr>, InferredType, SourceSpan),
+    Unwrap(Box<Expr>, InferredType, SourceSpan),
+    Throw(String, InferredType, SourceSpan),
+    GetTag(Box<Expr>, InferredType, SourceSpan),
+    Lambda(Vec<LambdaParameter>, Box<Expr>, InferredType, SourceSpan),
     ListComprehension {
         iterated_variable: VariableId,
         iterable_expr: Box<Expr>,
         yield_expr: Box<Expr>,
         inferred_type: InferredType,
+        source_span: SourceSpan,
     },
     ListReduce {
         reduce_variable: VariableId,
@@ -83,13 +95,14 @@ pub enum Expr {
         yield_expr: Box<Expr>,
         init_value_expr: Box<Expr>,
         inferred_type: InferredType,
+        source_span: SourceSpan,
     },
 }
 
 impl Expr {
     pub fn as_record(&self) -> Option<Vec<(String, Expr)>> {
         match self {
-            Expr::Record(fields, _) => Some(
+            Expr::Record(fields, _, _) => Some(
                 fields
                     .iter()
                     .map(|(k, v)| (k.clone(), v.deref().clone()))
@@ -118,124 +131,177 @@ impl Expr {
         spaces()
             .with(block().skip(eof()))
             .easy_parse(position::Stream::new(input))
-            .map(|t| t.0)
+            .map(|t| {
+                let mut expr = t.0;
+                inherit_source_spans(&mut expr);
+                expr
+            })
             .map_err(|err| format!("{}", err))
     }
 
     pub fn is_literal(&self) -> bool {
-        matches!(self, Expr::Literal(_, _))
+        matches!(self, Expr::Literal(_, _, _))
     }
 
     pub fn is_number(&self) -> bool {
-        matches!(self, Expr::Number(_, _, _))
+        matches!(self, Expr::Number(_, _, _, _))
     }
 
     pub fn is_record(&self) -> bool {
-        matches!(self, Expr::Record(_, _))
+        matches!(self, Expr::Record(_, _, _))
     }
 
     pub fn is_result(&self) -> bool {
-        matches!(self, Expr::Result(_, _))
+        matches!(self, Expr::Result(_, _, _))
     }
 
     pub fn is_option(&self) -> bool {
-        matches!(self, Expr::Option(_, _))
+        matches!(self, Expr::Option(_, _, _))
     }
 
     pub fn is_tuple(&self) -> bool {
-        matches!(self, Expr::Tuple(_, _))
+        matches!(self, Expr::Tuple(_, _, _))
     }
 
     pub fn is_list(&self) -> bool {
-        matches!(self, Expr::Sequence(_, _))
+        matches!(self, Expr::Sequence(_, _, _))
     }
 
     pub fn is_flags(&self) -> bool {
-        matches!(self, Expr::Flags(_, _))
+        matches!(self, Expr::Flags(_, _, _))
     }
 
     pub fn is_identifier(&self) -> bool {
-        matches!(self, Expr::Identifier(_, _))
+        matches!(self, Expr::Identifier(_, _, _))
     }
 
     pub fn is_select_field(&self) -> bool {
-        matches!(self, Expr::SelectField(_, _, _))
+        matches!(self, Expr::SelectField(_, _, _, _))
     }
 
     pub fn is_if_else(&self) -> bool {
-        matches!(self, Expr::Cond(_, _, _, _))
+        matches!(self, Expr::Cond(_, _, _, _, _))
     }
 
     pub fn is_function_call(&self) -> bool {
-        matches!(self, Expr::Call(_, _, _))
+        matches!(self, Expr::Call(_, _, _, _))
     }
 
     pub fn is_match_expr(&self) -> bool {
-        matches!(self, Expr::PatternMatch(_, _, _))
+        matches!(self, Expr::PatternMatch(_, _, _, _))
     }
 
     pub fn is_select_index(&self) -> bool {
-        matches!(self, Expr::SelectIndex(_, _, _))
+        matches!(self, Expr::SelectIndex(_, _, _, _))
     }
 
     pub fn is_boolean(&self) -> bool {
-        matches!(self, Expr::Boolean(_, _))
+        matches!(self, Expr::Boolean(_, _, _))
     }
 
     pub fn is_comparison(&self) -> bool {
         matches!(
             self,
-            Expr::GreaterThan(_, _, _)
-                | Expr::GreaterThanOrEqualTo(_, _, _)
-                | Expr::LessThanOrEqualTo(_, _, _)
-                | Expr::EqualTo(_, _, _)
-                | Expr::LessThan(_, _, _)
+            Expr::GreaterThan(_, _, _, _)
+                | Expr::GreaterThanOrEqualTo(_, _, _, _)
+                | Expr::LessThanOrEqualTo(_, _, _, _)
+                | Expr::EqualTo(_, _, _, _)
+                | Expr::NotEqualTo(_, _, _, _)
+                | Expr::LessThan(_, _, _, _)
         )
     }
 
     pub fn is_concat(&self) -> bool {
-        matches!(self, Expr::Concat(_, _))
+        matches!(self, Expr::Concat(_, _, _))
     }
 
     pub fn is_multiple(&self) -> bool {
-        matches!(self, Expr::ExprBlock(_, _))
+        matches!(self, Expr::ExprBlock(_, _, _))
     }
 
     pub fn inbuilt_variant(&self) -> Option<(String, Option<Expr>)> {
         match self {
-            Expr::Option(Some(expr), _) => Some(("some".to_string(), Some(expr.deref().clone()))),
-            Expr::Option(None, _) => Some(("some".to_string(), None)),
-            Expr::Result(Ok(expr), _) => Some(("ok".to_string(), Some(expr.deref().clone()))),
-            Expr::Result(Err(expr), _) => Some(("err".to_string(), Some(expr.deref().clone()))),
+            Expr::Option(Some(expr), _, _) => {
+                Some(("some".to_string(), Some(expr.deref().clone())))
+            }
+            Expr::Option(None, _, _) => Some(("some".to_string(), None)),
+            Expr::Result(Ok(expr), _, _) => Some(("ok".to_string(), Some(expr.deref().clone()))),
+            Expr::Result(Err(expr), _, _) => Some(("err".to_string(), Some(expr.deref().clone()))),
             _ => None,
         }
     }
     pub fn unwrap(&self) -> Self {
-        Expr::Unwrap(Box::new(self.clone()), InferredType::Unknown)
+        Expr::Unwrap(
+            Box::new(self.clone()),
+            InferredType::Unknown,
+            SourceSpan::default(),
+        )
     }
 
     pub fn boolean(value: bool) -> Self {
-        Expr::Boolean(value, InferredType::Bool)
+        Expr::Boolean(value, InferredType::Bool, SourceSpan::default())
     }
 
     pub fn and(left: Expr, right: Expr) -> Self {
-        Expr::And(Box::new(left), Box::new(right), InferredType::Bool)
+        Expr::And(
+            Box::new(left),
+            Box::new(right),
+            InferredType::Bool,
+            SourceSpan::default(),
+        )
     }
 
     pub fn plus(left: Expr, right: Expr) -> Self {
-        Expr::Plus(Box::new(left), Box::new(right), InferredType::number())
+        Expr::Plus(
+            Box::new(left),
+            Box::new(right),
+            InferredType::number(),
+            SourceSpan::default(),
+        )
     }
 
     pub fn minus(left: Expr, right: Expr) -> Self {
-        Expr::Minus(Box::new(left), Box::new(right), InferredType::number())
+        Expr::Minus(
+            Box::new(left),
+            Box::new(right),
+            InferredType::number(),
+            SourceSpan::default(),
+        )
     }
 
     pub fn divide(left: Expr, right: Expr) -> Self {
-        Expr::Divide(Box::new(left), Box::new(right), InferredType::number())
+        Expr::Divide(
+            Box::new(left),
+            Box::new(right),
+            InferredType::number(),
+            SourceSpan::default(),
+        )
     }
 
     pub fn multiply(left: Expr, right: Expr) -> Self {
-        Expr::Multiply(Box::new(left), Box::new(right), InferredType::number())
+        Expr::Multiply(
+            Box::new(left),
+            Box::new(right),
+            InferredType::number(),
+            SourceSpan::default(),
+        )
+    }
+
+    pub fn modulo(left: Expr, right: Expr) -> Self {
+        Expr::Modulo(
+            Box::new(left),
+            Box::new(right),
+            InferredType::number(),
+            SourceSpan::default(),
+        )
+    }
+
+    pub fn negative(expr: Expr) -> Self {
+        Expr::Negative(
+            Box::new(expr),
+            InferredType::number(),
+            SourceSpan::default(),
+        )
     }
 
     pub fn and_combine(conditions: Vec<Expr>) -> Option<Expr> {
@@ -243,7 +309,12 @@ impl Expr {
 
         for i in conditions {
             let left = Box::new(cond.clone().unwrap_or(Expr::boolean(true)));
-            cond = Some(Expr::And(left, Box::new(i), InferredType::Bool));
+            cond = Some(Expr::And(
+                left,
+                Box::new(i),
+                InferredType::Bool,
+                SourceSpan::default(),
+            ));
         }
 
         cond
@@ -254,11 +325,12 @@ impl Expr {
             CallType::Function(dynamic_parsed_fn_name),
             args,
             InferredType::Unknown,
+            SourceSpan::default(),
         )
     }
 
     pub fn concat(expressions: Vec<Expr>) -> Self {
-        Expr::Concat(expressions, InferredType::Str)
+        Expr::Concat(expressions, InferredType::Str, SourceSpan::default())
     }
 
     pub fn cond(cond: Expr, then: Expr, else_: Expr) -> Self {
@@ -267,11 +339,26 @@ impl Expr {
             Box::new(then),
             Box::new(else_),
             InferredType::Unknown,
+            SourceSpan::default(),
         )
     }
 
     pub fn equal_to(left: Expr, right: Expr) -> Self {
-        Expr::EqualTo(Box::new(left), Box::new(right), InferredType::Bool)
+        Expr::EqualTo(
+            Box::new(left),
+            Box::new(right),
+            InferredType::Bool,
+            SourceSpan::default(),
+        )
+    }
+
+    pub fn not_equal_to(left: Expr, right: Expr) -> Self {
+        Expr::NotEqualTo(
+            Box::new(left),
+            Box::new(right),
+            InferredType::Bool,
+            SourceSpan::default(),
+        )
     }
 
     pub fn err(expr: Expr) -> Self {
@@ -282,19 +369,34 @@ impl Expr {
                 ok: Some(Box::new(InferredType::Unknown)),
                 error: Some(Box::new(inferred_type)),
             },
+            SourceSpan::default(),
         )
     }
 
     pub fn flags(flags: Vec<String>) -> Self {
-        Expr::Flags(flags.clone(), InferredType::Flags(flags))
+        Expr::Flags(
+            flags.clone(),
+            InferredType::Flags(flags),
+            SourceSpan::default(),
+        )
     }
 
     pub fn greater_than(left: Expr, right: Expr) -> Self {
-        Expr::GreaterThan(Box::new(left), Box::new(right), InferredType::Bool)
+        Expr::GreaterThan(
+            Box::new(left),
+            Box::new(right),
+            InferredType::Bool,
+            SourceSpan::default(),
+        )
     }
 
     pub fn greater_than_or_equal_to(left: Expr, right: Expr) -> Self {
-        Expr::GreaterThanOrEqualTo(Box::new(left), Box::new(right), InferredType::Bool)
+        Expr::GreaterThanOrEqualTo(
+            Box::new(left),
+            Box::new(right),
+            InferredType::Bool,
+            SourceSpan::default(),
+        )
     }
 
     // An identifier by default is global until name-binding phase is run
@@ -302,15 +404,26 @@ impl Expr {
         Expr::Identifier(
             VariableId::global(name.as_ref().to_string()),
             InferredType::Unknown,
+            SourceSpan::default(),
         )
     }
 
     pub fn less_than(left: Expr, right: Expr) -> Self {
-        Expr::LessThan(Box::new(left), Box::new(right), InferredType::Bool)
+        Expr::LessThan(
+            Box::new(left),
+            Box::new(right),
+            InferredType::Bool,
+            SourceSpan::default(),
+        )
     }
 
     pub fn less_than_or_equal_to(left: Expr, right: Expr) -> Self {
-        Expr::LessThanOrEqualTo(Box::new(left), Box::new(right), InferredType::Bool)
+        Expr::LessThanOrEqualTo(
+            Box::new(left),
+            Box::new(right),
+            InferredType::Bool,
+            SourceSpan::default(),
+        )
     }
 
     pub fn let_binding(name: impl AsRef<str>, expr: Expr) -> Self {
@@ -319,6 +432,16 @@ impl Expr {
             None,
             Box::new(expr),
             InferredType::Unknown,
+            SourceSpan::default(),
+        )
+    }
+
+    pub fn lambda(parameters: Vec<LambdaParameter>, body: Expr) -> Self {
+        Expr::Lambda(
+            parameters,
+            Box::new(body),
+            InferredType::Unknown,
+            SourceSpan::default(),
         )
     }
 
@@ -328,6 +451,7 @@ impl Expr {
             Some(type_name),
             Box::new(expr),
             InferredType::Unknown,
+            SourceSpan::default(),
         )
     }
 
@@ -346,6 +470,7 @@ impl Expr {
             yield_expr: Box::new(yield_expr),
             init_value_expr: Box::new(init_value_expr),
             inferred_type,
+            source_span: SourceSpan::default(),
         }
     }
 
@@ -377,6 +502,7 @@ impl Expr {
             iterable_expr: Box::new(iterable_expr),
             yield_expr: Box::new(yield_expr),
             inferred_type,
+            source_span: SourceSpan::default(),
         }
     }
 
@@ -394,7 +520,11 @@ impl Expr {
     }
 
     pub fn literal(value: impl AsRef<str>) -> Self {
-        Expr::Literal(value.as_ref().to_string(), InferredType::Str)
+        Expr::Literal(
+            value.as_ref().to_string(),
+            InferredType::Str,
+            SourceSpan::default(),
+        )
     }
 
     pub fn empty_expr() -> Self {
@@ -406,12 +536,12 @@ impl Expr {
             .last()
             .map_or(InferredType::Unknown, |e| e.inferred_type());
 
-        Expr::ExprBlock(expressions, inferred_type)
+        Expr::ExprBlock(expressions, inferred_type, SourceSpan::default())
     }
 
     #[allow(clippy::should_implement_trait)]
     pub fn not(expr: Expr) -> Self {
-        Expr::Not(Box::new(expr), InferredType::Bool)
+        Expr::Not(Box::new(expr), InferredType::Bool, SourceSpan::default())
     }
 
     pub fn ok(expr: Expr) -> Self {
@@ -422,6 +552,7 @@ impl Expr {
                 ok: Some(Box::new(inferred_type)),
                 error: Some(Box::new(InferredType::Unknown)),
             },
+            SourceSpan::default(),
         )
     }
 
@@ -434,15 +565,26 @@ impl Expr {
         Expr::Option(
             expr.map(Box::new),
             InferredType::Option(Box::new(inferred_type)),
+            SourceSpan::default(),
         )
     }
 
     pub fn or(left: Expr, right: Expr) -> Self {
-        Expr::Or(Box::new(left), Box::new(right), InferredType::Bool)
+        Expr::Or(
+            Box::new(left),
+            Box::new(right),
+            InferredType::Bool,
+            SourceSpan::default(),
+        )
     }
 
     pub fn pattern_match(expr: Expr, match_arms: Vec<MatchArm>) -> Self {
-        Expr::PatternMatch(Box::new(expr), match_arms, InferredType::Unknown)
+        Expr::PatternMatch(
+            Box::new(expr),
+            match_arms,
+            InferredType::Unknown,
+            SourceSpan::default(),
+        )
     }
 
     pub fn record(expressions: Vec<(String, Expr)>) -> Self {
@@ -459,6 +601,7 @@ impl Expr {
                 .map(|(field_name, expr)| (field_name, Box::new(expr)))
                 .collect(),
             inferred_type,
+            SourceSpan::default(),
         )
     }
 
@@ -467,15 +610,21 @@ impl Expr {
             Box::new(expr),
             field.as_ref().to_string(),
             InferredType::Unknown,
+            SourceSpan::default(),
         )
     }
 
     pub fn select_index(expr: Expr, index: usize) -> Self {
-        Expr::SelectIndex(Box::new(expr), index, InferredType::Unknown)
+        Expr::SelectIndex(
+            Box::new(expr),
+            index,
+            InferredType::Unknown,
+            SourceSpan::default(),
+        )
     }
 
     pub fn get_tag(expr: Expr) -> Self {
-        Expr::GetTag(Box::new(expr), InferredType::Unknown)
+        Expr::GetTag(Box::new(expr), InferredType::Unknown, SourceSpan::default())
     }
 
     pub fn tuple(expressions: Vec<Expr>) -> Self {
@@ -486,7 +635,7 @@ impl Expr {
                 .collect(),
         );
 
-        Expr::Tuple(expressions, inferred_type)
+        Expr::Tuple(expressions, inferred_type, SourceSpan::default())
     }
 
     pub fn sequence(expressions: Vec<Expr>) -> Self {
@@ -496,58 +645,155 @@ impl Expr {
                 .map_or(InferredType::Unknown, |x| x.inferred_type()),
         ));
 
-        Expr::Sequence(expressions, inferred_type)
+        Expr::Sequence(expressions, inferred_type, SourceSpan::default())
     }
 
     pub fn inferred_type(&self) -> InferredType {
         match self {
-            Expr::Let(_, _, _, inferred_type)
-            | Expr::SelectField(_, _, inferred_type)
-            | Expr::SelectIndex(_, _, inferred_type)
-            | Expr::Sequence(_, inferred_type)
-            | Expr::Record(_, inferred_type)
-            | Expr::Tuple(_, inferred_type)
-            | Expr::Literal(_, inferred_type)
-            | Expr::Number(_, _, inferred_type)
-            | Expr::Flags(_, inferred_type)
-            | Expr::Identifier(_, inferred_type)
-            | Expr::Boolean(_, inferred_type)
-            | Expr::Concat(_, inferred_type)
-            | Expr::ExprBlock(_, inferred_type)
-            | Expr::Not(_, inferred_type)
-            | Expr::GreaterThan(_, _, inferred_type)
-            | Expr::GreaterThanOrEqualTo(_, _, inferred_type)
-            | Expr::LessThanOrEqualTo(_, _, inferred_type)
-            | Expr::EqualTo(_, _, inferred_type)
-            | Expr::Plus(_, _, inferred_type)
-            | Expr::Minus(_, _, inferred_type)
-            | Expr::Divide(_, _, inferred_type)
-            | Expr::Multiply(_, _, inferred_type)
-            | Expr::LessThan(_, _, inferred_type)
-            | Expr::Cond(_, _, _, inferred_type)
-            | Expr::PatternMatch(_, _, inferred_type)
-            | Expr::Option(_, inferred_type)
-            | Expr::Result(_, inferred_type)
-            | Expr::Unwrap(_, inferred_type)
-            | Expr::Throw(_, inferred_type)
-            | Expr::GetTag(_, inferred_type)
-            | Expr::And(_, _, inferred_type)
-            | Expr::Or(_, _, inferred_type)
+            Expr::Let(_, _, _, inferred_type, _)
+            | Expr::SelectField(_, _, inferred_type, _)
+            | Expr::SelectIndex(_, _, inferred_type, _)
+            | Expr::Sequence(_, inferred_type, _)
+            | Expr::Record(_, inferred_type, _)
+            | Expr::Tuple(_, inferred_type, _)
+            | Expr::Literal(_, inferred_type, _)
+            | Expr::Number(_, _, inferred_type, _)
+            | Expr::Flags(_, inferred_type, _)
+            | Expr::Identifier(_, inferred_type, _)
+            | Expr::Boolean(_, inferred_type, _)
+            | Expr::Concat(_, inferred_type, _)
+            | Expr::ExprBlock(_, inferred_type, _)
+            | Expr::Not(_, inferred_type, _)
+            | Expr::Negative(_, inferred_type, _)
+            | Expr::GreaterThan(_, _, inferred_type, _)
+            | Expr::GreaterThanOrEqualTo(_, _, inferred_type, _)
+            | Expr::LessThanOrEqualTo(_, _, inferred_type, _)
+            | Expr::EqualTo(_, _, inferred_type, _)
+            | Expr::NotEqualTo(_, _, inferred_type, _)
+            | Expr::Plus(_, _, inferred_type, _)
+            | Expr::Minus(_, _, inferred_type, _)
+            | Expr::Divide(_, _, inferred_type, _)
+            | Expr::Multiply(_, _, inferred_type, _)
+            | Expr::Modulo(_, _, inferred_type, _)
+            | Expr::LessThan(_, _, inferred_type, _)
+            | Expr::Cond(_, _, _, inferred_type, _)
+            | Expr::PatternMatch(_, _, inferred_type, _)
+            | Expr::Option(_, inferred_type, _)
+            | Expr::Result(_, inferred_type, _)
+            | Expr::Unwrap(_, inferred_type, _)
+            | Expr::Throw(_, inferred_type, _)
+            | Expr::GetTag(_, inferred_type, _)
+            | Expr::Lambda(_, _, inferred_type, _)
+            | Expr::And(_, _, inferred_type, _)
+            | Expr::Or(_, _, inferred_type, _)
             | Expr::ListComprehension { inferred_type, .. }
             | Expr::ListReduce { inferred_type, .. }
-            | Expr::Call(_, _, inferred_type) => inferred_type.clone(),
+            | Expr::Call(_, _, inferred_type, _) => inferred_type.clone(),
+        }
+    }
+
+    pub fn source_span(&self) -> SourceSpan {
+        match self {
+            Expr::Let(_, _, _, _, source_span)
+            | Expr::SelectField(_, _, _, source_span)
+            | Expr::SelectIndex(_, _, _, source_span)
+            | Expr::Sequence(_, _, source_span)
+            | Expr::Record(_, _, source_span)
+            | Expr::Tuple(_, _, source_span)
+            | Expr::Literal(_, _, source_span)
+            | Expr::Number(_, _, _, source_span)
+            | Expr::Flags(_, _, source_span)
+            | Expr::Identifier(_, _, source_span)
+            | Expr::Boolean(_, _, source_span)
+            | Expr::Concat(_, _, source_span)
+            | Expr::ExprBlock(_, _, source_span)
+            | Expr::Not(_, _, source_span)
+            | Expr::Negative(_, _, source_span)
+            | Expr::GreaterThan(_, _, _, source_span)
+            | Expr::GreaterThanOrEqualTo(_, _, _, source_span)
+            | Expr::LessThanOrEqualTo(_, _, _, source_span)
+            | Expr::EqualTo(_, _, _, source_span)
+            | Expr::NotEqualTo(_, _, _, source_span)
+            | Expr::LessThan(_, _, _, source_span)
+            | Expr::Plus(_, _, _, source_span)
+            | Expr::Minus(_, _, _, source_span)
+            | Expr::Divide(_, _, _, source_span)
+            | Expr::Multiply(_, _, _, source_span)
+            | Expr::Modulo(_, _, _, source_span)
+            | Expr::Cond(_, _, _, _, source_span)
+            | Expr::PatternMatch(_, _, _, source_span)
+            | Expr::Option(_, _, source_span)
+            | Expr::Result(_, _, source_span)
+            | Expr::Unwrap(_, _, source_span)
+            | Expr::Throw(_, _, source_span)
+            | Expr::GetTag(_, _, source_span)
+            | Expr::Lambda(_, _, _, source_span)
+            | Expr::And(_, _, _, source_span)
+            | Expr::Or(_, _, _, source_span)
+            | Expr::ListComprehension { source_span, .. }
+            | Expr::ListReduce { source_span, .. }
+            | Expr::Call(_, _, _, source_span) => *source_span,
+        }
+    }
+
+    pub fn with_source_span(mut self, new_source_span: SourceSpan) -> Expr {
+        *self.source_span_mut() = new_source_span;
+        self
+    }
+
+    pub fn source_span_mut(&mut self) -> &mut SourceSpan {
+        match self {
+            Expr::Let(_, _, _, _, source_span)
+            | Expr::SelectField(_, _, _, source_span)
+            | Expr::SelectIndex(_, _, _, source_span)
+            | Expr::Sequence(_, _, source_span)
+            | Expr::Record(_, _, source_span)
+            | Expr::Tuple(_, _, source_span)
+            | Expr::Literal(_, _, source_span)
+            | Expr::Number(_, _, _, source_span)
+            | Expr::Flags(_, _, source_span)
+            | Expr::Identifier(_, _, source_span)
+            | Expr::Boolean(_, _, source_span)
+            | Expr::Concat(_, _, source_span)
+            | Expr::ExprBlock(_, _, source_span)
+            | Expr::Not(_, _, source_span)
+            | Expr::Negative(_, _, source_span)
+            | Expr::GreaterThan(_, _, _, source_span)
+            | Expr::GreaterThanOrEqualTo(_, _, _, source_span)
+            | Expr::LessThanOrEqualTo(_, _, _, source_span)
+            | Expr::EqualTo(_, _, _, source_span)
+            | Expr::NotEqualTo(_, _, _, source_span)
+            | Expr::LessThan(_, _, _, source_span)
+            | Expr::Plus(_, _, _, source_span)
+            | Expr::Minus(_, _, _, source_span)
+            | Expr::Divide(_, _, _, source_span)
+            | Expr::Multiply(_, _, _, source_span)
+            | Expr::Modulo(_, _, _, source_span)
+            | Expr::Cond(_, _, _, _, source_span)
+            | Expr::PatternMatch(_, _, _, source_span)
+            | Expr::Option(_, _, source_span)
+            | Expr::Result(_, _, source_span)
+            | Expr::Unwrap(_, _, source_span)
+            | Expr::Throw(_, _, source_span)
+            | Expr::GetTag(_, _, source_span)
+            | Expr::Lambda(_, _, _, source_span)
+            | Expr::And(_, _, _, source_span)
+            | Expr::Or(_, _, _, source_span)
+            | Expr::ListComprehension { source_span, .. }
+            | Expr::ListReduce { source_span, .. }
+            | Expr::Call(_, _, _, source_span) => source_span,
         }
     }
 
     pub fn infer_types(
         &mut self,
         function_type_registry: &FunctionTypeRegistry,
-    ) -> Result<(), Vec<String>> {
+    ) -> Result<(), Vec<RibCompilationError>> {
         self.infer_types_initial_phase(function_type_registry)?;
         self.infer_call_arguments_type(function_type_registry)
-            .map_err(|x| vec![x])?;
+            .map_err(|x| vec![x.into()])?;
         type_inference::type_inference_fix_point(Self::inference_scan, self)
-            .map_err(|x| vec![x])?;
+            .map_err(|x| vec![x.into()])?;
 
         self.check_types(function_type_registry)
             .map_err(|x| vec![x])?;
@@ -558,7 +804,8 @@ impl Expr {
     pub fn infer_types_initial_phase(
         &mut self,
         function_type_registry: &FunctionTypeRegistry,
-    ) -> Result<(), Vec<String>> {
+    ) -> Result<(), Vec<RibCompilationError>> {
+        self.inline_functions().map_err(|x| vec![x])?;
         self.bind_types();
         self.bind_variables_of_list_comprehension();
         self.bind_variables_of_list_reduce();
@@ -566,6 +813,7 @@ impl Expr {
         self.bind_variables_of_let_assignment();
         self.infer_variants(function_type_registry);
         self.infer_enums(function_type_registry);
+        self.infer_built_in_functions(function_type_registry);
 
         Ok(())
     }
@@ -582,6 +830,13 @@ impl Expr {
         Ok(())
     }
 
+    // User-defined functions (named functions and lambdas bound to a variable) are inlined
+    // at every call site, such that the rest of the type inference and the compilation
+    // never sees a function definition
+    pub fn inline_functions(&mut self) -> Result<(), RibCompilationError> {
+        type_inference::inline_functions(self)
+    }
+
     // Make sure the bindings in the arm pattern of a pattern match are given variable-ids.
     // The same variable-ids will be tagged to the corresponding identifiers in the arm resolution
     // to avoid conflicts.
@@ -634,11 +889,11 @@ impl Expr {
     pub fn check_types(
         &mut self,
         function_type_registry: &FunctionTypeRegistry,
-    ) -> Result<(), String> {
+    ) -> Result<(), RibCompilationError> {
         type_checker::type_check(self, function_type_registry)
     }
 
-    pub fn unify_types(&mut self) -> Result<(), Vec<String>> {
+    pub fn unify_types(&mut self) -> Result<(), Vec<RibCompilationError>> {
         type_inference::unify_types(self)
     }
 
@@ -650,41 +905,45 @@ impl Expr {
 
     pub fn add_infer_type_mut(&mut self, new_inferred_type: InferredType) {
         match self {
-            Expr::Identifier(_, inferred_type)
-            | Expr::Let(_, _, _, inferred_type)
-            | Expr::SelectField(_, _, inferred_type)
-            | Expr::SelectIndex(_, _, inferred_type)
-            | Expr::Sequence(_, inferred_type)
-            | Expr::Record(_, inferred_type)
-            | Expr::Tuple(_, inferred_type)
-            | Expr::Literal(_, inferred_type)
-            | Expr::Number(_, _, inferred_type)
-            | Expr::Flags(_, inferred_type)
-            | Expr::Boolean(_, inferred_type)
-            | Expr::Concat(_, inferred_type)
-            | Expr::ExprBlock(_, inferred_type)
-            | Expr::Not(_, inferred_type)
-            | Expr::GreaterThan(_, _, inferred_type)
-            | Expr::GreaterThanOrEqualTo(_, _, inferred_type)
-            | Expr::LessThanOrEqualTo(_, _, inferred_type)
-            | Expr::EqualTo(_, _, inferred_type)
-            | Expr::Plus(_, _, inferred_type)
-            | Expr::Minus(_, _, inferred_type)
-            | Expr::Divide(_, _, inferred_type)
-            | Expr::Multiply(_, _, inferred_type)
-            | Expr::LessThan(_, _, inferred_type)
-            | Expr::Cond(_, _, _, inferred_type)
-            | Expr::PatternMatch(_, _, inferred_type)
-            | Expr::Option(_, inferred_type)
-            | Expr::Result(_, inferred_type)
-            | Expr::Unwrap(_, inferred_type)
-            | Expr::Throw(_, inferred_type)
-            | Expr::GetTag(_, inferred_type)
-            | Expr::And(_, _, inferred_type)
-            | Expr::Or(_, _, inferred_type)
+            Expr::Identifier(_, inferred_type, _)
+            | Expr::Let(_, _, _, inferred_type, _)
+            | Expr::SelectField(_, _, inferred_type, _)
+            | Expr::SelectIndex(_, _, inferred_type, _)
+            | Expr::Sequence(_, inferred_type, _)
+            | Expr::Record(_, inferred_type, _)
+            | Expr::Tuple(_, inferred_type, _)
+            | Expr::Literal(_, inferred_type, _)
+            | Expr::Number(_, _, inferred_type, _)
+            | Expr::Flags(_, inferred_type, _)
+            | Expr::Boolean(_, inferred_type, _)
+            | Expr::Concat(_, inferred_type, _)
+            | Expr::ExprBlock(_, inferred_type, _)
+            | Expr::Not(_, inferred_type, _)
+            | Expr::Negative(_, inferred_type, _)
+            | Expr::GreaterThan(_, _, inferred_type, _)
+            | Expr::GreaterThanOrEqualTo(_, _, inferred_type, _)
+            | Expr::LessThanOrEqualTo(_, _, inferred_type, _)
+            | Expr::EqualTo(_, _, inferred_type, _)
+            | Expr::NotEqualTo(_, _, inferred_type, _)
+            | Expr::Plus(_, _, inferred_type, _)
+            | Expr::Minus(_, _, inferred_type, _)
+            | Expr::Divide(_, _, inferred_type, _)
+            | Expr::Multiply(_, _, inferred_type, _)
+            | Expr::Modulo(_, _, inferred_type, _)
+            | Expr::LessThan(_, _, inferred_type, _)
+            | Expr::Cond(_, _, _, inferred_type, _)
+            | Expr::PatternMatch(_, _, inferred_type, _)
+            | Expr::Option(_, inferred_type, _)
+            | Expr::Result(_, inferred_type, _)
+            | Expr::Unwrap(_, inferred_type, _)
+            | Expr::Throw(_, inferred_type, _)
+            | Expr::GetTag(_, inferred_type, _)
+            | Expr::Lambda(_, _, inferred_type, _)
+            | Expr::And(_, _, inferred_type, _)
+            | Expr::Or(_, _, inferred_type, _)
             | Expr::ListComprehension { inferred_type, .. }
             | Expr::ListReduce { inferred_type, .. }
-            | Expr::Call(_, _, inferred_type) => {
+            | Expr::Call(_, _, inferred_type, _) => {
                 if new_inferred_type != InferredType::Unknown {
                     *inferred_type = inferred_type.merge(new_inferred_type);
                 }
@@ -698,41 +957,45 @@ impl Expr {
 
     pub fn override_type_type_mut(&mut self, new_inferred_type: InferredType) {
         match self {
-            Expr::Identifier(_, inferred_type)
-            | Expr::Let(_, _, _, inferred_type)
-            | Expr::SelectField(_, _, inferred_type)
-            | Expr::SelectIndex(_, _, inferred_type)
-            | Expr::Sequence(_, inferred_type)
-            | Expr::Record(_, inferred_type)
-            | Expr::Tuple(_, inferred_type)
-            | Expr::Literal(_, inferred_type)
-            | Expr::Number(_, _, inferred_type)
-            | Expr::Flags(_, inferred_type)
-            | Expr::Boolean(_, inferred_type)
-            | Expr::Concat(_, inferred_type)
-            | Expr::ExprBlock(_, inferred_type)
-            | Expr::Not(_, inferred_type)
-            | Expr::GreaterThan(_, _, inferred_type)
-            | Expr::GreaterThanOrEqualTo(_, _, inferred_type)
-            | Expr::LessThanOrEqualTo(_, _, inferred_type)
-            | Expr::EqualTo(_, _, inferred_type)
-            | Expr::LessThan(_, _, inferred_type)
-            | Expr::Plus(_, _, inferred_type)
-            | Expr::Minus(_, _, inferred_type)
-            | Expr::Divide(_, _, inferred_type)
-            | Expr::Multiply(_, _, inferred_type)
-            | Expr::Cond(_, _, _, inferred_type)
-            | Expr::PatternMatch(_, _, inferred_type)
-            | Expr::Option(_, inferred_type)
-            | Expr::Result(_, inferred_type)
-            | Expr::Unwrap(_, inferred_type)
-            | Expr::Throw(_, inferred_type)
-            | Expr::And(_, _, inferred_type)
-            | Expr::Or(_, _, inferred_type)
-            | Expr::GetTag(_, inferred_type)
+            Expr::Identifier(_, inferred_type, _)
+            | Expr::Let(_, _, _, inferred_type, _)
+            | Expr::SelectField(_, _, inferred_type, _)
+            | Expr::SelectIndex(_, _, inferred_type, _)
+            | Expr::Sequence(_, inferred_type, _)
+            | Expr::Record(_, inferred_type, _)
+            | Expr::Tuple(_, inferred_type, _)
+            | Expr::Literal(_, inferred_type, _)
+            | Expr::Number(_, _, inferred_type, _)
+            | Expr::Flags(_, inferred_type, _)
+            | Expr::Boolean(_, inferred_type, _)
+            | Expr::Concat(_, inferred_type, _)
+            | Expr::ExprBlock(_, inferred_type, _)
+            | Expr::Not(_, inferred_type, _)
+            | Expr::Negative(_, inferred_type, _)
+            | Expr::GreaterThan(_, _, inferred_type, _)
+            | Expr::GreaterThanOrEqualTo(_, _, inferred_type, _)
+            | Expr::LessThanOrEqualTo(_, _, inferred_type, _)
+            | Expr::EqualTo(_, _, inferred_type, _)
+            | Expr::NotEqualTo(_, _, inferred_type, _)
+            | Expr::LessThan(_, _, inferred_type, _)
+            | Expr::Plus(_, _, inferred_type, _)
+            | Expr::Minus(_, _, inferred_type, _)
+            | Expr::Divide(_, _, inferred_type, _)
+            | Expr::Multiply(_, _, inferred_type, _)
+            | Expr::Modulo(_, _, inferred_type, _)
+            | Expr::Cond(_, _, _, inferred_type, _)
+            | Expr::PatternMatch(_, _, inferred_type, _)
+            | Expr::Option(_, inferred_type, _)
+            | Expr::Result(_, inferred_type, _)
+            | Expr::Unwrap(_, inferred_type, _)
+            | Expr::Throw(_, inferred_type, _)
+            | Expr::And(_, _, inferred_type, _)
+            | Expr::Or(_, _, inferred_type, _)
+            | Expr::GetTag(_, inferred_type, _)
+            | Expr::Lambda(_, _, inferred_type, _)
             | Expr::ListComprehension { inferred_type, .. }
             | Expr::ListReduce { inferred_type, .. }
-            | Expr::Call(_, _, inferred_type) => {
+            | Expr::Call(_, _, inferred_type, _) => {
                 if new_inferred_type != InferredType::Unknown {
                     *inferred_type = new_inferred_type;
                 }
@@ -748,6 +1011,10 @@ impl Expr {
         type_inference::infer_variants(self, function_type_registry);
     }
 
+    pub fn infer_built_in_functions(&mut self, function_type_registry: &FunctionTypeRegistry) {
+        type_inference::infer_built_in_functions(self, function_type_registry);
+    }
+
     pub fn visit_children_bottom_up<'a>(&'a self, queue: &mut VecDeque<&'a Expr>) {
         type_inference::visit_children_bottom_up(self, queue);
     }
@@ -761,7 +1028,12 @@ impl Expr {
     }
 
     pub fn number(f64: f64, inferred_type: InferredType) -> Expr {
-        Expr::Number(Number { value: f64 }, None, inferred_type)
+        Expr::Number(
+            Number { value: f64 },
+            None,
+            inferred_type,
+            SourceSpan::default(),
+        )
     }
 
     pub fn number_with_type_name(
@@ -769,7 +1041,12 @@ impl Expr {
         type_name: TypeName,
         inferred_type: InferredType,
     ) -> Expr {
-        Expr::Number(Number { value: f64 }, Some(type_name), inferred_type)
+        Expr::Number(
+            Number { value: f64 },
+            Some(type_name),
+            inferred_type,
+            SourceSpan::default(),
+        )
     }
 
     pub fn untyped_number(f64: f64) -> Expr {
@@ -813,6 +1090,21 @@ impl Display for Number {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct LambdaParameter {
+    pub name: String,
+    pub type_name: Option<TypeName>,
+}
+
+impl LambdaParameter {
+    pub fn new(name: impl AsRef<str>, type_name: Option<TypeName>) -> LambdaParameter {
+        LambdaParameter {
+            name: name.as_ref().to_string(),
+            type_name,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
 pub struct MatchArm {
     pub arm_pattern: ArmPattern,
@@ -932,11 +1224,13 @@ impl ArmPattern {
             Ok(Box::new(Expr::Identifier(
                 VariableId::global(binding_variable.to_string()),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ))),
             InferredType::Result {
                 ok: Some(Box::new(InferredType::Unknown)),
                 error: Some(Box::new(InferredType::Unknown)),
             },
+            SourceSpan::default(),
         )))
     }
 
@@ -946,11 +1240,13 @@ impl ArmPattern {
             Err(Box::new(Expr::Identifier(
                 VariableId::global(binding_variable.to_string()),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ))),
             InferredType::Result {
                 ok: Some(Box::new(InferredType::Unknown)),
                 error: Some(Box::new(InferredType::Unknown)),
             },
+            SourceSpan::default(),
         )))
     }
 
@@ -960,19 +1256,26 @@ impl ArmPattern {
             Some(Box::new(Expr::Identifier(
                 VariableId::local_with_no_id(binding_variable),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ))),
             InferredType::Unknown,
+            SourceSpan::default(),
         )))
     }
 
     pub fn none() -> ArmPattern {
-        ArmPattern::Literal(Box::new(Expr::Option(None, InferredType::Unknown)))
+        ArmPattern::Literal(Box::new(Expr::Option(
+            None,
+            InferredType::Unknown,
+            SourceSpan::default(),
+        )))
     }
 
     pub fn identifier(binding_variable: &str) -> ArmPattern {
         ArmPattern::Literal(Box::new(Expr::Identifier(
             VariableId::global(binding_variable.to_string()),
             InferredType::Unknown,
+            SourceSpan::default(),
         )))
     }
     pub fn custom_constructor(name: &str, args: Vec<ArmPattern>) -> ArmPattern {
@@ -1005,6 +1308,27 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                 Expr::not((*expr).try_into()?)
             }
 
+            golem_api_grpc::proto::golem::rib::expr::Expr::Negative(expr) => {
+                let expr = expr.expr.ok_or("Missing expr")?;
+                Expr::negative((*expr).try_into()?)
+            }
+
+            golem_api_grpc::proto::golem::rib::expr::Expr::Lambda(lambda) => {
+                let parameters = lambda
+                    .parameters
+                    .into_iter()
+                    .map(|parameter| {
+                        parameter
+                            .type_name
+                            .map(TypeName::try_from)
+                            .transpose()
+                            .map(|type_name| LambdaParameter::new(parameter.name, type_name))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                let body = lambda.body.ok_or("Missing lambda body")?;
+                Expr::lambda(parameters, (*body).try_into()?)
+            }
+
             golem_api_grpc::proto::golem::rib::expr::Expr::GreaterThan(expr) => {
                 let left = expr.left.ok_or("Missing left expr")?;
                 let right = expr.right.ok_or("Missing right expr")?;
@@ -1035,6 +1359,12 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                 Expr::equal_to((*left).try_into()?, (*right).try_into()?)
             }
 
+            golem_api_grpc::proto::golem::rib::expr::Expr::NotEqualTo(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::not_equal_to((*left).try_into()?, (*right).try_into()?)
+            }
+
             golem_api_grpc::proto::golem::rib::expr::Expr::Add(expr) => {
                 let left = expr.left.ok_or("Missing left expr")?;
                 let right = expr.right.ok_or("Missing right expr")?;
@@ -1059,6 +1389,12 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                 Expr::plus((*left).try_into()?, (*right).try_into()?)
             }
 
+            golem_api_grpc::proto::golem::rib::expr::Expr::Modulo(expr) => {
+                let left = expr.left.ok_or("Missing left expr")?;
+                let right = expr.right.ok_or("Missing right expr")?;
+                Expr::modulo((*left).try_into()?, (*right).try_into()?)
+            }
+
             golem_api_grpc::proto::golem::rib::expr::Expr::Cond(expr) => {
                 let left = expr.left.ok_or("Missing left expr")?;
                 let cond = expr.cond.ok_or("Missing cond expr")?;
@@ -1140,7 +1476,7 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
 
             golem_api_grpc::proto::golem::rib::expr::Expr::Throw(
                 golem_api_grpc::proto::golem::rib::ThrowExpr { message },
-            ) => Expr::Throw(message, InferredType::Unknown),
+            ) => Expr::Throw(message, InferredType::Unknown, SourceSpan::default()),
 
             golem_api_grpc::proto::golem::rib::expr::Expr::And(expr) => {
                 let left = expr.left.ok_or("Missing left expr")?;
@@ -1295,7 +1631,7 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
 impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
     fn from(value: Expr) -> Self {
         let expr = match value {
-            Expr::Let(variable_id, type_name, expr, _) => {
+            Expr::Let(variable_id, type_name, expr, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Let(
                     Box::new(golem_api_grpc::proto::golem::rib::LetExpr {
                         name: variable_id.name().to_string(),
@@ -1304,7 +1640,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::SelectField(expr, field, _) => {
+            Expr::SelectField(expr, field, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::SelectField(
                     Box::new(golem_api_grpc::proto::golem::rib::SelectFieldExpr {
                         expr: Some(Box::new((*expr).into())),
@@ -1312,7 +1648,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::SelectIndex(expr, index, _) => {
+            Expr::SelectIndex(expr, index, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::SelectIndex(
                     Box::new(golem_api_grpc::proto::golem::rib::SelectIndexExpr {
                         expr: Some(Box::new((*expr).into())),
@@ -1320,37 +1656,39 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::Sequence(exprs, _) => {
+            Expr::Sequence(exprs, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Sequence(
                     golem_api_grpc::proto::golem::rib::SequenceExpr {
                         exprs: exprs.into_iter().map(|expr| expr.into()).collect(),
                     },
                 ))
             }
-            Expr::Record(fields, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Record(
-                golem_api_grpc::proto::golem::rib::RecordExpr {
-                    fields: fields
-                        .into_iter()
-                        .map(
-                            |(name, expr)| golem_api_grpc::proto::golem::rib::RecordFieldExpr {
-                                name,
-                                expr: Some((*expr).into()),
-                            },
-                        )
-                        .collect(),
-                },
-            )),
-            Expr::Tuple(exprs, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Tuple(
+            Expr::Record(fields, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Record(
+                    golem_api_grpc::proto::golem::rib::RecordExpr {
+                        fields: fields
+                            .into_iter()
+                            .map(|(name, expr)| {
+                                golem_api_grpc::proto::golem::rib::RecordFieldExpr {
+                                    name,
+                                    expr: Some((*expr).into()),
+                                }
+                            })
+                            .collect(),
+                    },
+                ))
+            }
+            Expr::Tuple(exprs, _, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Tuple(
                 golem_api_grpc::proto::golem::rib::TupleExpr {
                     exprs: exprs.into_iter().map(|expr| expr.into()).collect(),
                 },
             )),
-            Expr::Literal(value, _) => {
+            Expr::Literal(value, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Literal(
                     golem_api_grpc::proto::golem::rib::LiteralExpr { value },
                 ))
             }
-            Expr::Number(number, type_name, _) => {
+            Expr::Number(number, type_name, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Number(
                     golem_api_grpc::proto::golem::rib::NumberExpr {
                         float: number.value,
@@ -1358,39 +1696,50 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     },
                 ))
             }
-            Expr::Flags(values, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Flags(
-                golem_api_grpc::proto::golem::rib::FlagsExpr { values },
-            )),
-            Expr::Identifier(variable_id, _) => {
+            Expr::Flags(values, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Flags(
+                    golem_api_grpc::proto::golem::rib::FlagsExpr { values },
+                ))
+            }
+            Expr::Identifier(variable_id, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Identifier(
                     golem_api_grpc::proto::golem::rib::IdentifierExpr {
                         name: variable_id.name(),
                     },
                 ))
             }
-            Expr::Boolean(value, _) => {
+            Expr::Boolean(value, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Boolean(
                     golem_api_grpc::proto::golem::rib::BooleanExpr { value },
                 ))
             }
-            Expr::Concat(exprs, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Concat(
-                golem_api_grpc::proto::golem::rib::ConcatExpr {
-                    exprs: exprs.into_iter().map(|expr| expr.into()).collect(),
-                },
-            )),
-            Expr::ExprBlock(exprs, _) => {
+            Expr::Concat(exprs, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Concat(
+                    golem_api_grpc::proto::golem::rib::ConcatExpr {
+                        exprs: exprs.into_iter().map(|expr| expr.into()).collect(),
+                    },
+                ))
+            }
+            Expr::ExprBlock(exprs, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Multiple(
                     golem_api_grpc::proto::golem::rib::MultipleExpr {
                         exprs: exprs.into_iter().map(|expr| expr.into()).collect(),
                     },
                 ))
             }
-            Expr::Not(expr, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Not(
+            Expr::Not(expr, _, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Not(
                 Box::new(golem_api_grpc::proto::golem::rib::NotExpr {
                     expr: Some(Box::new((*expr).into())),
                 }),
             )),
-            Expr::GreaterThan(left, right, _) => {
+            Expr::Negative(expr, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Negative(
+                    Box::new(golem_api_grpc::proto::golem::rib::NegativeExpr {
+                        expr: Some(Box::new((*expr).into())),
+                    }),
+                ))
+            }
+            Expr::GreaterThan(left, right, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::GreaterThan(
                     Box::new(golem_api_grpc::proto::golem::rib::GreaterThanExpr {
                         left: Some(Box::new((*left).into())),
@@ -1398,7 +1747,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::GreaterThanOrEqualTo(left, right, _) => Some(
+            Expr::GreaterThanOrEqualTo(left, right, _, _) => Some(
                 golem_api_grpc::proto::golem::rib::expr::Expr::GreaterThanOrEqual(Box::new(
                     golem_api_grpc::proto::golem::rib::GreaterThanOrEqualToExpr {
                         left: Some(Box::new((*left).into())),
@@ -1406,7 +1755,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     },
                 )),
             ),
-            Expr::LessThan(left, right, _) => {
+            Expr::LessThan(left, right, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::LessThan(
                     Box::new(golem_api_grpc::proto::golem::rib::LessThanExpr {
                         left: Some(Box::new((*left).into())),
@@ -1414,13 +1763,15 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::Plus(left, right, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Add(
-                Box::new(golem_api_grpc::proto::golem::rib::AddExpr {
-                    left: Some(Box::new((*left).into())),
-                    right: Some(Box::new((*right).into())),
-                }),
-            )),
-            Expr::Minus(left, right, _) => {
+            Expr::Plus(left, right, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Add(
+                    Box::new(golem_api_grpc::proto::golem::rib::AddExpr {
+                        left: Some(Box::new((*left).into())),
+                        right: Some(Box::new((*right).into())),
+                    }),
+                ))
+            }
+            Expr::Minus(left, right, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Subtract(
                     Box::new(golem_api_grpc::proto::golem::rib::SubtractExpr {
                         left: Some(Box::new((*left).into())),
@@ -1428,7 +1779,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::Divide(left, right, _) => {
+            Expr::Divide(left, right, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Divide(
                     Box::new(golem_api_grpc::proto::golem::rib::DivideExpr {
                         left: Some(Box::new((*left).into())),
@@ -1436,7 +1787,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::Multiply(left, right, _) => {
+            Expr::Multiply(left, right, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Multiply(
                     Box::new(golem_api_grpc::proto::golem::rib::MultiplyExpr {
                         left: Some(Box::new((*left).into())),
@@ -1444,7 +1795,15 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::LessThanOrEqualTo(left, right, _) => Some(
+            Expr::Modulo(left, right, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Modulo(
+                    Box::new(golem_api_grpc::proto::golem::rib::ModuloExpr {
+                        left: Some(Box::new((*left).into())),
+                        right: Some(Box::new((*right).into())),
+                    }),
+                ))
+            }
+            Expr::LessThanOrEqualTo(left, right, _, _) => Some(
                 golem_api_grpc::proto::golem::rib::expr::Expr::LessThanOrEqual(Box::new(
                     golem_api_grpc::proto::golem::rib::LessThanOrEqualToExpr {
                         left: Some(Box::new((*left).into())),
@@ -1452,7 +1811,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     },
                 )),
             ),
-            Expr::EqualTo(left, right, _) => {
+            Expr::EqualTo(left, right, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::EqualTo(
                     Box::new(golem_api_grpc::proto::golem::rib::EqualToExpr {
                         left: Some(Box::new((*left).into())),
@@ -1460,7 +1819,15 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::Cond(left, cond, right, _) => {
+            Expr::NotEqualTo(left, right, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::NotEqualTo(
+                    Box::new(golem_api_grpc::proto::golem::rib::NotEqualToExpr {
+                        left: Some(Box::new((*left).into())),
+                        right: Some(Box::new((*right).into())),
+                    }),
+                ))
+            }
+            Expr::Cond(left, cond, right, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Cond(
                     Box::new(golem_api_grpc::proto::golem::rib::CondExpr {
                         left: Some(Box::new((*left).into())),
@@ -1469,7 +1836,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::PatternMatch(expr, arms, _) => {
+            Expr::PatternMatch(expr, arms, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::PatternMatch(
                     Box::new(golem_api_grpc::proto::golem::rib::PatternMatchExpr {
                         expr: Some(Box::new((*expr).into())),
@@ -1477,12 +1844,14 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::Option(expr, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Option(
-                Box::new(golem_api_grpc::proto::golem::rib::OptionExpr {
-                    expr: expr.map(|expr| Box::new((*expr).into())),
-                }),
-            )),
-            Expr::Result(expr, _) => {
+            Expr::Option(expr, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Option(
+                    Box::new(golem_api_grpc::proto::golem::rib::OptionExpr {
+                        expr: expr.map(|expr| Box::new((*expr).into())),
+                    }),
+                ))
+            }
+            Expr::Result(expr, _, _) => {
                 let result = match expr {
                     Ok(expr) => golem_api_grpc::proto::golem::rib::result_expr::Result::Ok(
                         Box::new((*expr).into()),
@@ -1498,7 +1867,7 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     }),
                 ))
             }
-            Expr::Call(function_name, args, _) => {
+            Expr::Call(function_name, args, _, _) => {
                 Some(golem_api_grpc::proto::golem::rib::expr::Expr::Call(
                     golem_api_grpc::proto::golem::rib::CallExpr {
                         name: None,
@@ -1507,27 +1876,49 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     },
                 ))
             }
-            Expr::Unwrap(expr, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Unwrap(
-                Box::new(golem_api_grpc::proto::golem::rib::UnwrapExpr {
-                    expr: Some(Box::new((*expr).into())),
-                }),
-            )),
-            Expr::Throw(message, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Throw(
-                golem_api_grpc::proto::golem::rib::ThrowExpr { message },
-            )),
-            Expr::GetTag(expr, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Tag(
+            Expr::Unwrap(expr, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Unwrap(
+                    Box::new(golem_api_grpc::proto::golem::rib::UnwrapExpr {
+                        expr: Some(Box::new((*expr).into())),
+                    }),
+                ))
+            }
+            Expr::Throw(message, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Throw(
+                    golem_api_grpc::proto::golem::rib::ThrowExpr { message },
+                ))
+            }
+            Expr::Lambda(parameters, body, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Lambda(
+                    Box::new(golem_api_grpc::proto::golem::rib::LambdaExpr {
+                        parameters: parameters
+                            .into_iter()
+                            .map(
+                                |parameter| golem_api_grpc::proto::golem::rib::LambdaParameter {
+                                    name: parameter.name,
+                                    type_name: parameter.type_name.map(|t| t.into()),
+                                },
+                            )
+                            .collect(),
+                        body: Some(Box::new((*body).into())),
+                    }),
+                ))
+            }
+            Expr::GetTag(expr, _, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Tag(
                 Box::new(golem_api_grpc::proto::golem::rib::GetTagExpr {
                     expr: Some(Box::new((*expr).into())),
                 }),
             )),
-            Expr::And(left, right, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::And(
-                Box::new(golem_api_grpc::proto::golem::rib::AndExpr {
-                    left: Some(Box::new((*left).into())),
-                    right: Some(Box::new((*right).into())),
-                }),
-            )),
+            Expr::And(left, right, _, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::And(
+                    Box::new(golem_api_grpc::proto::golem::rib::AndExpr {
+                        left: Some(Box::new((*left).into())),
+                        right: Some(Box::new((*right).into())),
+                    }),
+                ))
+            }
 
-            Expr::Or(left, right, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Or(
+            Expr::Or(left, right, _, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Or(
                 Box::new(golem_api_grpc::proto::golem::rib::OrExpr {
                     left: Some(Box::new((*left).into())),
                     right: Some(Box::new((*right).into())),
@@ -1803,8 +2194,29 @@ mod tests {
     use crate::ParsedFunctionSite::PackagedInterface;
     use crate::{
         ArmPattern, DynamicParsedFunctionName, DynamicParsedFunctionReference, Expr, MatchArm,
+        SourcePosition,
     };
 
+    #[test]
+    fn test_source_spans_of_parsed_expr() {
+        let input = "let x = 1;\n  foo(x)";
+        let expr = Expr::from_text(input).unwrap();
+
+        let Expr::ExprBlock(exprs, _, source_span) = expr else {
+            panic!("expected a block");
+        };
+
+        assert_eq!(source_span.start(), SourcePosition::new(1, 1));
+        assert_eq!(exprs[0].source_span().start(), SourcePosition::new(1, 1));
+
+        let Expr::Call(_, args, _, source_span) = &exprs[1] else {
+            panic!("expected a call");
+        };
+
+        assert_eq!(source_span.start(), SourcePosition::new(2, 3));
+        assert_eq!(args[0].source_span().start(), SourcePosition::new(2, 7));
+    }
+
     #[test]
     fn test_single_expr_in_interpolation_wrapped_in_quotes() {
         let input = r#""${foo}""#;
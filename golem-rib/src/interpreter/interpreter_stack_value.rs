@@ -46,6 +46,17 @@ impl RibInterpreterStackValue {
     ) -> Result<CoercedNumericValue, String>
     where
         F: Fn(CoercedNumericValue, CoercedNumericValue) -> CoercedNumericValue,
+    {
+        self.try_evaluate_math_op(right, |left, right| Ok(op(left, right)))
+    }
+
+    pub fn try_evaluate_math_op<F>(
+        &self,
+        right: &RibInterpreterStackValue,
+        op: F,
+    ) -> Result<CoercedNumericValue, String>
+    where
+        F: Fn(CoercedNumericValue, CoercedNumericValue) -> Result<CoercedNumericValue, String>,
     {
         match (self.get_val(), right.get_val()) {
             (Some(left), Some(right)) => {
@@ -53,7 +64,7 @@ impl RibInterpreterStackValue {
                     left.get_literal().and_then(|x| x.get_number()),
                     right.get_literal().and_then(|x| x.get_number()),
                 ) {
-                    Ok(op(left_lit, right_lit))
+                    op(left_lit, right_lit)
                 } else {
                     Err(format!(
                         "Unable to complete the math operation on {}, {}",
@@ -103,6 +103,60 @@ pub enum CoercedNumericValue {
 }
 
 impl CoercedNumericValue {
+    pub fn is_zero(&self) -> bool {
+        match self {
+            CoercedNumericValue::PosInt(value) => *value == 0,
+            CoercedNumericValue::NegInt(value) => *value == 0,
+            CoercedNumericValue::Float(value) => *value == 0.0,
+        }
+    }
+
+    // None if the negated value doesn't fit in an i64
+    pub fn checked_neg(&self) -> Option<CoercedNumericValue> {
+        match self {
+            CoercedNumericValue::PosInt(value) => i64::try_from(*value)
+                .ok()
+                .map(|value| CoercedNumericValue::NegInt(-value)),
+            CoercedNumericValue::NegInt(value) => {
+                value.checked_neg().map(CoercedNumericValue::NegInt)
+            }
+            CoercedNumericValue::Float(value) => Some(CoercedNumericValue::Float(-value)),
+        }
+    }
+
+    // None if the divisor is an integer zero, or if the remainder overflows (Example: `i64::MIN % -1`)
+    pub fn checked_rem(&self, rhs: &CoercedNumericValue) -> Option<CoercedNumericValue> {
+        match (self, rhs) {
+            (CoercedNumericValue::Float(a), CoercedNumericValue::Float(b)) => {
+                Some(CoercedNumericValue::Float(a % b))
+            }
+            (CoercedNumericValue::Float(a), CoercedNumericValue::PosInt(b)) => {
+                Some(CoercedNumericValue::Float(a % *b as f64))
+            }
+            (CoercedNumericValue::Float(a), CoercedNumericValue::NegInt(b)) => {
+                Some(CoercedNumericValue::Float(a % *b as f64))
+            }
+            (CoercedNumericValue::PosInt(a), CoercedNumericValue::Float(b)) => {
+                Some(CoercedNumericValue::Float(*a as f64 % b))
+            }
+            (CoercedNumericValue::NegInt(a), CoercedNumericValue::Float(b)) => {
+                Some(CoercedNumericValue::Float(*a as f64 % b))
+            }
+            (CoercedNumericValue::PosInt(a), CoercedNumericValue::PosInt(b)) => {
+                a.checked_rem(*b).map(CoercedNumericValue::PosInt)
+            }
+            (CoercedNumericValue::NegInt(a), CoercedNumericValue::NegInt(b)) => {
+                a.checked_rem(*b).map(CoercedNumericValue::NegInt)
+            }
+            (CoercedNumericValue::PosInt(a), CoercedNumericValue::NegInt(b)) => {
+                (*a as i64).checked_rem(*b).map(CoercedNumericValue::NegInt)
+            }
+            (CoercedNumericValue::NegInt(a), CoercedNumericValue::PosInt(b)) => {
+                a.checked_rem(*b as i64).map(CoercedNumericValue::NegInt)
+            }
+        }
+    }
+
     pub fn cast_to(&self, analysed_type: &AnalysedType) -> Option<TypeAnnotatedValue> {
         match (self, analysed_type) {
             (CoercedNumericValue::PosInt(val), AnalysedType::U64(_)) => {
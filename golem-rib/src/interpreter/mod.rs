@@ -28,6 +28,7 @@ mod interpreter_stack_value;
 mod literal;
 mod rib_interpreter;
 mod stack;
+mod stdlib;
 mod tests;
 
 pub async fn interpret(
@@ -79,6 +79,10 @@ impl Interpreter {
                     internal::run_compare_instruction(&mut stack, |left, right| left == right)?;
                 }
 
+                RibIR::NotEqualTo => {
+                    internal::run_compare_instruction(&mut stack, |left, right| left != right)?;
+                }
+
                 RibIR::GreaterThan => {
                     internal::run_compare_instruction(&mut stack, |left, right| left > right)?;
                 }
@@ -122,6 +126,12 @@ impl Interpreter {
                         &analysed_type,
                     )?;
                 }
+                RibIR::Modulo(analysed_type) => {
+                    internal::run_modulo_instruction(&mut stack, &analysed_type)?;
+                }
+                RibIR::Negative(analysed_type) => {
+                    internal::run_negative_instruction(&mut stack, &analysed_type)?;
+                }
 
                 RibIR::AssignVar(variable_id) => {
                     internal::run_assign_var_instruction(
@@ -250,6 +260,14 @@ impl Interpreter {
                 RibIR::SinkToList => {
                     internal::run_sink_to_list_instruction(&mut stack)?;
                 }
+                RibIR::InvokeBuiltInFunction(built_in_function, arg_size, return_type) => {
+                    internal::run_invoke_built_in_function_instruction(
+                        &built_in_function,
+                        arg_size,
+                        &return_type,
+                        &mut stack,
+                    )?;
+                }
             }
         }
 
@@ -267,11 +285,13 @@ impl Interpreter {
 mod internal {
     use crate::interpreter::env::{EnvironmentKey, InterpreterEnv};
     use crate::interpreter::interpreter_stack_value::RibInterpreterStackValue;
-    use crate::interpreter::literal::LiteralValue;
+    use crate::interpreter::literal::{GetLiteralValue, LiteralValue};
     use crate::interpreter::stack::InterpreterStack;
+    use crate::interpreter::stdlib;
     use crate::{
-        CoercedNumericValue, FunctionReferenceType, InstructionId, ParsedFunctionName,
-        ParsedFunctionReference, ParsedFunctionSite, RibFunctionInvoke, VariableId,
+        BuiltInFunction, CoercedNumericValue, FunctionReferenceType, InstructionId,
+        ParsedFunctionName, ParsedFunctionReference, ParsedFunctionSite, RibFunctionInvoke,
+        VariableId,
     };
     use golem_wasm_ast::analysis::AnalysedType;
     use golem_wasm_ast::analysis::TypeResult;
@@ -654,6 +674,61 @@ mod internal {
         Ok(())
     }
 
+    pub(crate) fn run_modulo_instruction(
+        interpreter_stack: &mut InterpreterStack,
+        target_numerical_type: &AnalysedType,
+    ) -> Result<(), String> {
+        let left = interpreter_stack.try_pop()?;
+        let right = interpreter_stack.try_pop()?;
+
+        let divisor = right
+            .get_val()
+            .and_then(|value| value.get_literal())
+            .and_then(|literal| literal.get_number());
+
+        if divisor.is_some_and(|divisor| divisor.is_zero()) {
+            return Err("Modulo by zero".to_string());
+        }
+
+        let result = left.try_evaluate_math_op(&right, |left, right| {
+            left.checked_rem(&right)
+                .ok_or(format!("Overflow when computing {} % {}", left, right))
+        })?;
+        let numerical_type = result.cast_to(target_numerical_type).ok_or(format!(
+            "Failed to cast number {} to {:?}",
+            result, target_numerical_type
+        ))?;
+
+        interpreter_stack.push_val(numerical_type);
+
+        Ok(())
+    }
+
+    pub(crate) fn run_negative_instruction(
+        interpreter_stack: &mut InterpreterStack,
+        target_numerical_type: &AnalysedType,
+    ) -> Result<(), String> {
+        let value = interpreter_stack.try_pop_val()?;
+
+        let number = value
+            .get_literal()
+            .and_then(|literal| literal.get_number())
+            .ok_or("Unable to negate a value that is not a number".to_string())?;
+
+        let negated = number
+            .checked_neg()
+            .ok_or(format!("Overflow when negating {}", number))?;
+
+        let numerical_type = negated.cast_to(target_numerical_type).ok_or(format!(
+            "Failed to cast number {} to {:?}",
+            negated, target_numerical_type
+        ))?;
+
+        interpreter_stack.push_val(numerical_type);
+
+        Ok(())
+    }
+
     pub(crate) fn run_compare_instruction(
         interpreter_stack: &mut InterpreterStack,
         compare_fn: fn(LiteralValue, LiteralValue) -> bool,
@@ -1150,6 +1225,21 @@ mod internal {
 
         Ok(())
     }
+
+    pub(crate) fn run_invoke_built_in_function_instruction(
+        built_in_function: &BuiltInFunction,
+        arg_size: usize,
+        return_type: &AnalysedType,
+        interpreter_stack: &mut InterpreterStack,
+    ) -> Result<(), String> {
+        let arguments = interpreter_stack.try_pop_n_val(arg_size)?;
+
+        let result = stdlib::evaluate(built_in_function, arguments, return_type)?;
+
+        interpreter_stack.push_val(result);
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1158,7 +1248,7 @@ mod interpreter_tests {
 
     use super::*;
     use crate::{InstructionId, VariableId};
-    use golem_wasm_ast::analysis::analysed_type::{field, list, record, s32};
+    use golem_wasm_ast::analysis::analysed_type::{field, list, record, s32, s64};
     use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
     use golem_wasm_rpc::protobuf::{NameValuePair, TypedList, TypedRecord};
 
@@ -1190,6 +1280,88 @@ mod interpreter_tests {
         assert!(result.get_bool().unwrap());
     }
 
+    #[test]
+    async fn test_interpreter_for_not_equal_to() {
+        let mut interpreter = Interpreter::default();
+
+        let instructions = RibByteCode {
+            instructions: vec![
+                RibIR::PushLit(TypeAnnotatedValue::S32(1)),
+                RibIR::PushLit(TypeAnnotatedValue::U32(2)),
+                RibIR::NotEqualTo,
+            ],
+        };
+
+        let result = interpreter.run(instructions).await.unwrap();
+        assert!(result.get_bool().unwrap());
+    }
+
+    #[test]
+    async fn test_interpreter_for_modulo() {
+        let mut interpreter = Interpreter::default();
+
+        let instructions = RibByteCode {
+            instructions: vec![
+                RibIR::PushLit(TypeAnnotatedValue::S32(3)),  // rhs
+                RibIR::PushLit(TypeAnnotatedValue::S32(10)), // lhs
+                RibIR::Modulo(s32()),
+            ],
+        };
+
+        let result = interpreter.run(instructions).await.unwrap();
+        assert_eq!(result.get_val().unwrap(), TypeAnnotatedValue::S32(1));
+    }
+
+    #[test]
+    async fn test_interpreter_for_modulo_by_zero() {
+        let mut interpreter = Interpreter::default();
+
+        let instructions = RibByteCode {
+            instructions: vec![
+                RibIR::PushLit(TypeAnnotatedValue::S32(0)),  // rhs
+                RibIR::PushLit(TypeAnnotatedValue::S32(10)), // lhs
+                RibIR::Modulo(s32()),
+            ],
+        };
+
+        let result = interpreter.run(instructions).await;
+        assert_eq!(result.err(), Some("Modulo by zero".to_string()));
+    }
+
+    #[test]
+    async fn test_interpreter_for_modulo_overflow() {
+        let mut interpreter = Interpreter::default();
+
+        let instructions = RibByteCode {
+            instructions: vec![
+                RibIR::PushLit(TypeAnnotatedValue::S64(-1)),       // rhs
+                RibIR::PushLit(TypeAnnotatedValue::S64(i64::MIN)), // lhs
+                RibIR::Modulo(s64()),
+            ],
+        };
+
+        let result = interpreter.run(instructions).await;
+        assert_eq!(
+            result.err(),
+            Some(format!("Overflow when computing {} % -1", i64::MIN))
+        );
+    }
+
+    #[test]
+    async fn test_interpreter_for_negative() {
+        let mut interpreter = Interpreter::default();
+
+        let instructions = RibByteCode {
+            instructions: vec![
+                RibIR::PushLit(TypeAnnotatedValue::S32(5)),
+                RibIR::Negative(s32()),
+            ],
+        };
+
+        let result = interpreter.run(instructions).await.unwrap();
+        assert_eq!(result.get_val().unwrap(), TypeAnnotatedValue::S32(-5));
+    }
+
     #[test]
     async fn test_interpreter_for_greater_than() {
         let mut interpreter = Interpreter::default();
@@ -1542,6 +1714,310 @@ mod interpreter_tests {
         }
     }
 
+    mod operator_interpreter_tests {
+        use crate::interpreter::rib_interpreter::Interpreter;
+        use crate::{compiler, Expr};
+        use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+        use test_r::test;
+
+        #[test]
+        async fn test_modulo_not_equal_to_and_negative() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let x: s32 = 10;
+          let y: s32 = 3;
+          let remainder = x % y;
+          if remainder != 0s32 then -x else x
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            assert_eq!(result, TypeAnnotatedValue::S32(-10));
+        }
+
+        #[test]
+        async fn test_comparison_chaining() {
+            let cases = [
+                ("1u32 < x <= 2u32", true),
+                ("1u32 < x < 2u32", false),
+                ("3u32 > x != 1u32", true),
+            ];
+
+            for (comparison, expected) in cases {
+                let mut interpreter = Interpreter::default();
+
+                let rib_expr = format!(
+                    r#"
+          let x: u32 = 2;
+          {}
+          "#,
+                    comparison
+                );
+
+                let expr = Expr::from_text(&rib_expr).unwrap();
+
+                let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+                let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+                assert_eq!(result.get_bool(), Some(expected), "{}", comparison);
+            }
+        }
+    }
+
+    mod user_defined_function_interpreter_tests {
+        use crate::interpreter::rib_interpreter::Interpreter;
+        use crate::{compiler, Expr};
+        use golem_wasm_ast::analysis::analysed_type::{list, u64};
+        use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+        use test_r::test;
+
+        async fn run(rib_expr: &str) -> TypeAnnotatedValue {
+            let mut interpreter = Interpreter::default();
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap()
+        }
+
+        #[test]
+        async fn test_named_function_in_list_comprehension() {
+            let rib_expr = r#"
+          fn double(x: u64) { x * 2 };
+          let xs: list<u64> = [1, 2, 3];
+
+          for x in xs {
+            yield double(x);
+          }
+          "#;
+
+            let result = run(rib_expr).await;
+
+            let expected =
+                golem_wasm_rpc::type_annotated_value_from_str(&list(u64()), "[2, 4, 6]").unwrap();
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        async fn test_lambda_in_list_reduce() {
+            let rib_expr = r#"
+          let add = |a: u64, b: u64| a + b;
+          let xs: list<u64> = [1, 2, 3];
+
+          reduce total, x in xs from 0u64 {
+            yield add(total, x);
+          }
+          "#;
+
+            let result = run(rib_expr).await;
+
+            assert_eq!(result, TypeAnnotatedValue::U64(6));
+        }
+
+        #[test]
+        async fn test_lambda_with_local_variables() {
+            let rib_expr = r#"
+          let y: u64 = 10;
+          let increment = |x: u64| { let y: u64 = 1; x + y };
+          let result = increment(y);
+          result + y
+          "#;
+
+            let result = run(rib_expr).await;
+
+            assert_eq!(result, TypeAnnotatedValue::U64(21));
+        }
+
+        #[test]
+        async fn test_recursive_function_fails_compilation() {
+            let rib_expr = r#"
+          fn countdown(n: u64) { if n > 0u64 then countdown(n - 1u64) else n };
+          countdown(3)
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+            let compiler_error = compiler::compile(&expr, &vec![]).unwrap_err().message;
+
+            assert_eq!(
+                compiler_error,
+                "Recursive function `countdown` is not supported"
+            );
+        }
+    }
+
+    mod built_in_function_interpreter_tests {
+        use crate::interpreter::rib_interpreter::Interpreter;
+        use crate::{compiler, Expr};
+        use golem_wasm_ast::analysis::analysed_type::{list, str, u64};
+        use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+        use test_r::test;
+
+        async fn run(rib_expr: &str) -> TypeAnnotatedValue {
+            let mut interpreter = Interpreter::default();
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap()
+        }
+
+        #[test]
+        async fn test_string_functions() {
+            let rib_expr = r#"
+          let x = "  Hello World  ";
+          to-upper(substring(trim(x), 0, 5))
+          "#;
+
+            let result = run(rib_expr).await;
+
+            assert_eq!(result, TypeAnnotatedValue::Str("HELLO".to_string()));
+        }
+
+        #[test]
+        async fn test_split() {
+            let rib_expr = r#"
+          let x = "foo,bar";
+          split(x, ",")
+          "#;
+
+            let result = run(rib_expr).await;
+
+            let expected =
+                golem_wasm_rpc::type_annotated_value_from_str(&list(str()), r#"["foo", "bar"]"#)
+                    .unwrap();
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        async fn test_sort_and_length() {
+            let rib_expr = r#"
+          let x: list<u64> = [3, 1, 2];
+          sort(x)
+          "#;
+
+            let result = run(rib_expr).await;
+            let length = run(r#"length(["foo", "bar"])"#).await;
+
+            let expected =
+                golem_wasm_rpc::type_annotated_value_from_str(&list(u64()), "[1, 2, 3]").unwrap();
+
+            assert_eq!(result, expected);
+            assert_eq!(length, TypeAnnotatedValue::U64(2));
+        }
+
+        #[test]
+        async fn test_flatten() {
+            let rib_expr = r#"
+          let x: list<option<str>> = [some("foo"), none, some("bar")];
+          flatten(x)
+          "#;
+
+            let result = run(rib_expr).await;
+
+            let expected =
+                golem_wasm_rpc::type_annotated_value_from_str(&list(str()), r#"["foo", "bar"]"#)
+                    .unwrap();
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        async fn test_filter_and_map() {
+            let rib_expr = r#"
+          let x: list<u64> = [1, 2, 3, 4];
+          let double = |v: u64| v * 2;
+          map(filter(x, |v| v > 2), double)
+          "#;
+
+            let result = run(rib_expr).await;
+
+            let expected =
+                golem_wasm_rpc::type_annotated_value_from_str(&list(u64()), "[6, 8]").unwrap();
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        async fn test_map_some_map_ok_and_map_err() {
+            let map_some = run(r#"unwrap-or(map-some(some("foo"), |v| to-upper(v)), "")"#).await;
+            let map_ok = run(r#"unwrap-or(map-ok(parse-u64("12"), |v| v + 1), 0)"#).await;
+            let rib_expr = r#"
+          let x: result<u64, str> = err("foo");
+          match map-err(x, |e| length(e)) {
+            ok(_) => 0u64,
+            err(e) => e
+          }
+          "#;
+            let map_err = run(rib_expr).await;
+
+            assert_eq!(map_some, TypeAnnotatedValue::Str("FOO".to_string()));
+            assert_eq!(map_ok, TypeAnnotatedValue::U64(13));
+            assert_eq!(map_err, TypeAnnotatedValue::U64(3));
+        }
+
+        #[test]
+        async fn test_parse_u64_and_unwrap_or() {
+            let valid = run(r#"unwrap-or(parse-u64("12"), 0)"#).await;
+            let invalid = run(r#"unwrap-or(parse-u64("foo"), 0)"#).await;
+
+            assert_eq!(valid, TypeAnnotatedValue::U64(12));
+            assert_eq!(invalid, TypeAnnotatedValue::U64(0));
+        }
+
+        #[test]
+        async fn test_min_max_abs() {
+            let rib_expr = r#"
+          let x: s32 = -3;
+          let y: s32 = 2;
+          max(abs(x), y)
+          "#;
+
+            let result = run(rib_expr).await;
+
+            assert_eq!(result, TypeAnnotatedValue::S32(3));
+        }
+
+        #[test]
+        async fn test_base64_and_url_encoding() {
+            let base64 = run(r#"base64-encode("foo")"#).await;
+            let base64_round_trip =
+                run(r#"unwrap-or(base64-decode(base64-encode("foo")), "")"#).await;
+            let url = run(r#"url-encode("foo bar")"#).await;
+
+            assert_eq!(base64, TypeAnnotatedValue::Str("Zm9v".to_string()));
+            assert_eq!(
+                base64_round_trip,
+                TypeAnnotatedValue::Str("foo".to_string())
+            );
+            assert_eq!(url, TypeAnnotatedValue::Str("foo%20bar".to_string()));
+        }
+    }
+
     mod pattern_match_interpreter_tests {
         use test_r::test;
 
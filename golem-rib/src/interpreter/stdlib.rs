@@ -0,0 +1,521 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interpreter::literal::{CoercedNumericValue, GetLiteralValue};
+use crate::BuiltInFunction;
+use base64::Engine;
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use golem_wasm_rpc::type_annotated_value_to_string;
+use std::cmp::Ordering;
+
+// Evaluates a function of the Rib standard library, given the arguments (in the order of the call)
+// and the return type of the call as decided by the compiler.
+pub(crate) fn evaluate(
+    built_in_function: &BuiltInFunction,
+    arguments: Vec<TypeAnnotatedValue>,
+    return_type: &AnalysedType,
+) -> Result<TypeAnnotatedValue, String> {
+    let function_name = built_in_function.name();
+
+    if arguments.len() != built_in_function.arity() {
+        return Err(format!(
+            "Internal Error: `{}` expects {} arguments, but {} are provided",
+            function_name,
+            built_in_function.arity(),
+            arguments.len()
+        ));
+    }
+
+    let argument = |index: usize| &arguments[index];
+
+    match built_in_function {
+        BuiltInFunction::ToUpper => {
+            let str = internal::get_str(function_name, argument(0))?;
+            Ok(TypeAnnotatedValue::Str(str.to_uppercase()))
+        }
+
+        BuiltInFunction::ToLower => {
+            let str = internal::get_str(function_name, argument(0))?;
+            Ok(TypeAnnotatedValue::Str(str.to_lowercase()))
+        }
+
+        BuiltInFunction::Trim => {
+            let str = internal::get_str(function_name, argument(0))?;
+            Ok(TypeAnnotatedValue::Str(str.trim().to_string()))
+        }
+
+        BuiltInFunction::Split => {
+            let str = internal::get_str(function_name, argument(0))?;
+            let separator = internal::get_str(function_name, argument(1))?;
+
+            let values = str
+                .split(separator.as_str())
+                .map(|part| TypeAnnotatedValue::Str(part.to_string()))
+                .collect();
+
+            internal::create_list(values, return_type)
+        }
+
+        // The characters from `start` (inclusive) to `end` (exclusive),
+        // where both are clamped to the length of the string
+        BuiltInFunction::Substring => {
+            let str = internal::get_str(function_name, argument(0))?;
+            let start = internal::get_u64(function_name, argument(1))? as usize;
+            let end = internal::get_u64(function_name, argument(2))? as usize;
+
+            let substring = str
+                .chars()
+                .skip(start)
+                .take(end.saturating_sub(start))
+                .collect::<String>();
+
+            Ok(TypeAnnotatedValue::Str(substring))
+        }
+
+        BuiltInFunction::ToString => {
+            let str = match argument(0).get_literal() {
+                Some(literal) => literal.as_string(),
+                None => type_annotated_value_to_string(argument(0))?,
+            };
+
+            Ok(TypeAnnotatedValue::Str(str))
+        }
+
+        BuiltInFunction::ParseU64 => {
+            let str = internal::get_str(function_name, argument(0))?;
+
+            match str.parse::<u64>() {
+                Ok(value) => internal::create_ok(TypeAnnotatedValue::U64(value), return_type),
+                Err(_) => internal::create_err(
+                    TypeAnnotatedValue::Str(format!("Invalid u64 `{}`", str)),
+                    return_type,
+                ),
+            }
+        }
+
+        BuiltInFunction::Contains => match argument(0) {
+            TypeAnnotatedValue::Str(str) => {
+                let pattern = internal::get_str(function_name, argument(1))?;
+                Ok(TypeAnnotatedValue::Bool(str.contains(pattern.as_str())))
+            }
+            value => {
+                let values = internal::get_list(function_name, value)?;
+                let contains = values
+                    .iter()
+                    .any(|value| internal::equal_values(value, argument(1)));
+
+                Ok(TypeAnnotatedValue::Bool(contains))
+            }
+        },
+
+        BuiltInFunction::Length => match argument(0) {
+            TypeAnnotatedValue::Str(str) => Ok(TypeAnnotatedValue::U64(str.chars().count() as u64)),
+            value => {
+                let values = internal::get_list(function_name, value)?;
+                Ok(TypeAnnotatedValue::U64(values.len() as u64))
+            }
+        },
+
+        BuiltInFunction::Flatten => {
+            let values = internal::get_list(function_name, argument(0))?;
+
+            let mut present_values = vec![];
+
+            for value in values {
+                if let Some(value) = internal::get_option(function_name, &value)? {
+                    present_values.push(value);
+                }
+            }
+
+            internal::create_list(present_values, return_type)
+        }
+
+        BuiltInFunction::Sort => {
+            let values = internal::get_list(function_name, argument(0))?;
+
+            let mut values_with_literals = values
+                .into_iter()
+                .map(|value| {
+                    value
+                        .get_literal()
+                        .map(|literal| (literal, value))
+                        .ok_or(format!(
+                            "`{}` can only sort a list of numbers, strings, characters or booleans",
+                            function_name
+                        ))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+
+            values_with_literals.sort_by(|(left, _), (right, _)| {
+                left.partial_cmp(right).unwrap_or(Ordering::Equal)
+            });
+
+            let sorted_values = values_with_literals
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect();
+
+            internal::create_list(sorted_values, return_type)
+        }
+
+        BuiltInFunction::IsSome => {
+            let value = internal::get_option(function_name, argument(0))?;
+            Ok(TypeAnnotatedValue::Bool(value.is_some()))
+        }
+
+        BuiltInFunction::IsNone => {
+            let value = internal::get_option(function_name, argument(0))?;
+            Ok(TypeAnnotatedValue::Bool(value.is_none()))
+        }
+
+        BuiltInFunction::IsOk => {
+            let value = internal::get_result(function_name, argument(0))?;
+            Ok(TypeAnnotatedValue::Bool(value.is_ok()))
+        }
+
+        BuiltInFunction::IsErr => {
+            let value = internal::get_result(function_name, argument(0))?;
+            Ok(TypeAnnotatedValue::Bool(value.is_err()))
+        }
+
+        BuiltInFunction::UnwrapOr => {
+            let value = match argument(0) {
+                TypeAnnotatedValue::Result(_) => {
+                    internal::get_result(function_name, argument(0))?.ok()
+                }
+                value => internal::get_option(function_name, value)?,
+            };
+
+            Ok(value.unwrap_or_else(|| argument(1).clone()))
+        }
+
+        BuiltInFunction::Min | BuiltInFunction::Max => {
+            let left = internal::get_number(function_name, argument(0))?;
+            let right = internal::get_number(function_name, argument(1))?;
+
+            let left_is_less = left <= right;
+
+            let pick_left = match built_in_function {
+                BuiltInFunction::Min => left_is_less,
+                _ => !left_is_less,
+            };
+
+            if pick_left {
+                Ok(argument(0).clone())
+            } else {
+                Ok(argument(1).clone())
+            }
+        }
+
+        BuiltInFunction::Abs => {
+            let number = internal::get_number(function_name, argument(0))?;
+
+            let absolute = match number {
+                CoercedNumericValue::PosInt(value) => CoercedNumericValue::PosInt(value),
+                CoercedNumericValue::NegInt(value) => {
+                    CoercedNumericValue::NegInt(value.checked_abs().ok_or(format!(
+                        "`{}` overflowed for the number {}",
+                        function_name, value
+                    ))?)
+                }
+                CoercedNumericValue::Float(value) => CoercedNumericValue::Float(value.abs()),
+            };
+
+            absolute.cast_to(return_type).ok_or(format!(
+                "Failed to cast number {} to {:?}",
+                absolute, return_type
+            ))
+        }
+
+        BuiltInFunction::Base64Encode => {
+            let str = internal::get_str(function_name, argument(0))?;
+            let encoded = base64::engine::general_purpose::STANDARD.encode(str.as_bytes());
+
+            Ok(TypeAnnotatedValue::Str(encoded))
+        }
+
+        BuiltInFunction::Base64Decode => {
+            let str = internal::get_str(function_name, argument(0))?;
+
+            let decoded = base64::engine::general_purpose::STANDARD
+                .decode(str.as_bytes())
+                .map_err(|err| err.to_string())
+                .and_then(|bytes| String::from_utf8(bytes).map_err(|err| err.to_string()));
+
+            match decoded {
+                Ok(decoded) => internal::create_ok(TypeAnnotatedValue::Str(decoded), return_type),
+                Err(err) => internal::create_err(
+                    TypeAnnotatedValue::Str(format!("Invalid base64 `{}`. {}", str, err)),
+                    return_type,
+                ),
+            }
+        }
+
+        BuiltInFunction::UrlEncode => {
+            let str = internal::get_str(function_name, argument(0))?;
+            Ok(TypeAnnotatedValue::Str(internal::url_encode(&str)))
+        }
+
+        BuiltInFunction::UrlDecode => {
+            let str = internal::get_str(function_name, argument(0))?;
+
+            match internal::url_decode(&str) {
+                Ok(decoded) => internal::create_ok(TypeAnnotatedValue::Str(decoded), return_type),
+                Err(err) => internal::create_err(
+                    TypeAnnotatedValue::Str(format!("Invalid url encoding `{}`. {}", str, err)),
+                    return_type,
+                ),
+            }
+        }
+    }
+}
+
+mod internal {
+    use crate::interpreter::literal::{CoercedNumericValue, GetLiteralValue};
+    use golem_wasm_ast::analysis::{AnalysedType, TypeResult};
+    use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+    use golem_wasm_rpc::protobuf::typed_result::ResultValue;
+    use golem_wasm_rpc::protobuf::{TypedList, TypedResult};
+    use std::ops::Deref;
+
+    pub(crate) fn get_str(
+        function_name: &str,
+        value: &TypeAnnotatedValue,
+    ) -> Result<String, String> {
+        match value {
+            TypeAnnotatedValue::Str(str) => Ok(str.clone()),
+            _ => Err(format!(
+                "Invalid argument to `{}`. Expected a string",
+                function_name
+            )),
+        }
+    }
+
+    pub(crate) fn get_number(
+        function_name: &str,
+        value: &TypeAnnotatedValue,
+    ) -> Result<CoercedNumericValue, String> {
+        value
+            .get_literal()
+            .and_then(|literal| literal.get_number())
+            .ok_or(format!(
+                "Invalid argument to `{}`. Expected a number",
+                function_name
+            ))
+    }
+
+    pub(crate) fn get_u64(function_name: &str, value: &TypeAnnotatedValue) -> Result<u64, String> {
+        match get_number(function_name, value)? {
+            CoercedNumericValue::PosInt(value) => Ok(value),
+            CoercedNumericValue::NegInt(value) if value >= 0 => Ok(value as u64),
+            _ => Err(format!(
+                "Invalid argument to `{}`. Expected a non-negative integer",
+                function_name
+            )),
+        }
+    }
+
+    pub(crate) fn get_list(
+        function_name: &str,
+        value: &TypeAnnotatedValue,
+    ) -> Result<Vec<TypeAnnotatedValue>, String> {
+        match value {
+            TypeAnnotatedValue::List(TypedList { values, .. }) => Ok(values
+                .iter()
+                .filter_map(|value| value.type_annotated_value.clone())
+                .collect()),
+            _ => Err(format!(
+                "Invalid argument to `{}`. Expected a list",
+                function_name
+            )),
+        }
+    }
+
+    pub(crate) fn get_option(
+        function_name: &str,
+        value: &TypeAnnotatedValue,
+    ) -> Result<Option<TypeAnnotatedValue>, String> {
+        match value {
+            TypeAnnotatedValue::Option(typed_option) => Ok(typed_option
+                .value
+                .as_ref()
+                .and_then(|value| value.type_annotated_value.clone())),
+            _ => Err(format!(
+                "Invalid argument to `{}`. Expected an option",
+                function_name
+            )),
+        }
+    }
+
+    // The ok and err values of a result, if any
+    pub(crate) fn get_result(
+        function_name: &str,
+        value: &TypeAnnotatedValue,
+    ) -> Result<Result<TypeAnnotatedValue, Option<TypeAnnotatedValue>>, String> {
+        match value {
+            TypeAnnotatedValue::Result(typed_result) => match &typed_result.result_value {
+                Some(ResultValue::OkValue(value)) => {
+                    value.type_annotated_value.clone().map(Ok).ok_or(format!(
+                        "Internal Error: Missing ok value in `{}`",
+                        function_name
+                    ))
+                }
+                Some(ResultValue::ErrorValue(value)) => Ok(Err(value.type_annotated_value.clone())),
+                None => Err(format!(
+                    "Internal Error: Missing result value in `{}`",
+                    function_name
+                )),
+            },
+            _ => Err(format!(
+                "Invalid argument to `{}`. Expected a result",
+                function_name
+            )),
+        }
+    }
+
+    // Values are compared as literals whenever possible, as the
+    // same number can be represented by different types (Example: S32(1) and U32(1))
+    pub(crate) fn equal_values(left: &TypeAnnotatedValue, right: &TypeAnnotatedValue) -> bool {
+        match (left.get_literal(), right.get_literal()) {
+            (Some(left), Some(right)) => left == right,
+            _ => left == right,
+        }
+    }
+
+    pub(crate) fn create_list(
+        values: Vec<TypeAnnotatedValue>,
+        list_type: &AnalysedType,
+    ) -> Result<TypeAnnotatedValue, String> {
+        match list_type {
+            AnalysedType::List(type_list) => Ok(TypeAnnotatedValue::List(TypedList {
+                values: values
+                    .into_iter()
+                    .map(|value| golem_wasm_rpc::protobuf::TypeAnnotatedValue {
+                        type_annotated_value: Some(value),
+                    })
+                    .collect(),
+                typ: Some(golem_wasm_ast::analysis::protobuf::Type::from(
+                    type_list.inner.deref(),
+                )),
+            })),
+            _ => Err(format!(
+                "Internal Error: Expected a list type, but found {:?}",
+                list_type
+            )),
+        }
+    }
+
+    pub(crate) fn create_ok(
+        value: TypeAnnotatedValue,
+        result_type: &AnalysedType,
+    ) -> Result<TypeAnnotatedValue, String> {
+        create_result(ResultValue::OkValue, value, result_type)
+    }
+
+    pub(crate) fn create_err(
+        value: TypeAnnotatedValue,
+        result_type: &AnalysedType,
+    ) -> Result<TypeAnnotatedValue, String> {
+        create_result(ResultValue::ErrorValue, value, result_type)
+    }
+
+    fn create_result(
+        result_value: fn(Box<golem_wasm_rpc::protobuf::TypeAnnotatedValue>) -> ResultValue,
+        value: TypeAnnotatedValue,
+        result_type: &AnalysedType,
+    ) -> Result<TypeAnnotatedValue, String> {
+        match result_type {
+            AnalysedType::Result(TypeResult { ok, err }) => {
+                Ok(TypeAnnotatedValue::Result(Box::new(TypedResult {
+                    result_value: Some(result_value(Box::new(
+                        golem_wasm_rpc::protobuf::TypeAnnotatedValue {
+                            type_annotated_value: Some(value),
+                        },
+                    ))),
+                    ok: ok
+                        .as_ref()
+                        .map(|ok| golem_wasm_ast::analysis::protobuf::Type::from(ok.deref())),
+                    error: err
+                        .as_ref()
+                        .map(|err| golem_wasm_ast::analysis::protobuf::Type::from(err.deref())),
+                })))
+            }
+            _ => Err(format!(
+                "Internal Error: Expected a result type, but found {:?}",
+                result_type
+            )),
+        }
+    }
+
+    // Percent-encodes everything except the unreserved characters of RFC 3986
+    pub(crate) fn url_encode(str: &str) -> String {
+        let mut encoded = String::new();
+
+        for byte in str.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+                encoded.push(byte as char);
+            } else {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        }
+
+        encoded
+    }
+
+    pub(crate) fn url_decode(str: &str) -> Result<String, String> {
+        let bytes = str.as_bytes();
+        let mut decoded = vec![];
+        let mut index = 0;
+
+        while index < bytes.len() {
+            if bytes[index] == b'%' {
+                let hex = bytes
+                    .get(index + 1..index + 3)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or(format!("Invalid escape sequence at {}", index))?;
+
+                decoded.push(hex);
+                index += 3;
+            } else {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+
+        String::from_utf8(decoded).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod stdlib_tests {
+    use test_r::test;
+
+    use crate::interpreter::stdlib::internal::{url_decode, url_encode};
+
+    #[test]
+    fn test_url_encode_decode() {
+        let encoded = url_encode("a b/c?d=é");
+
+        assert_eq!(encoded, "a%20b%2Fc%3Fd%3D%C3%A9");
+        assert_eq!(url_decode(&encoded), Ok("a b/c?d=é".to_string()));
+    }
+
+    #[test]
+    fn test_url_decode_invalid_escape() {
+        assert!(url_decode("a%2").is_err());
+        assert!(url_decode("a%zz").is_err());
+    }
+}
@@ -12,17 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use built_in_function::*;
 pub use compiler::*;
 pub use expr::*;
 pub use function_name::*;
 pub use inferred_type::*;
 pub use interpreter::*;
 pub use parser::type_name::TypeName;
+pub use source_span::*;
 pub use text::*;
 pub use type_inference::*;
 pub use type_registry::*;
 pub use variable_id::*;
 
+mod built_in_function;
 mod call_type;
 mod compiler;
 mod expr;
@@ -30,6 +33,7 @@ mod function_name;
 mod inferred_type;
 mod interpreter;
 mod parser;
+mod source_span;
 mod text;
 mod type_checker;
 mod type_inference;
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 use crate::parser::errors::RibParseError;
+use crate::parser::source_position::GetSourcePosition;
 use combine::parser::char::string;
 use combine::{attempt, choice, ParseError, Parser};
 
 pub fn binary_op<Input>() -> impl Parser<Input, Output = BinaryOp>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -27,6 +29,7 @@ where
         attempt(string(">=")).map(|_| BinaryOp::GreaterThanOrEqualTo),
         attempt(string("<=")).map(|_| BinaryOp::LessThanOrEqualTo),
         attempt(string("==")).map(|_| BinaryOp::EqualTo),
+        attempt(string("!=")).map(|_| BinaryOp::NotEqualTo),
         string("<").map(|_| BinaryOp::LessThan),
         string(">").map(|_| BinaryOp::GreaterThan),
         string("&&").map(|_| BinaryOp::And),
@@ -35,6 +38,7 @@ where
         string("-").map(|_| BinaryOp::Subtract),
         string("*").map(|_| BinaryOp::Multiply),
         string("/").map(|_| BinaryOp::Divide),
+        string("%").map(|_| BinaryOp::Modulo),
     ))
 }
 
@@ -44,12 +48,28 @@ pub enum BinaryOp {
     LessThanOrEqualTo,
     GreaterThanOrEqualTo,
     EqualTo,
+    NotEqualTo,
     And,
     Or,
     Add,
     Subtract,
     Multiply,
     Divide,
+    Modulo,
+}
+
+impl BinaryOp {
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            BinaryOp::GreaterThan
+                | BinaryOp::LessThan
+                | BinaryOp::LessThanOrEqualTo
+                | BinaryOp::GreaterThanOrEqualTo
+                | BinaryOp::EqualTo
+                | BinaryOp::NotEqualTo
+        )
+    }
 }
 
 #[cfg(test)]
@@ -127,6 +147,153 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_not_equal_to() {
+        let input = "foo != bar";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::not_equal_to(Expr::identifier("foo"), Expr::identifier("bar")),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_modulo() {
+        let input = "foo % bar";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::modulo(Expr::identifier("foo"), Expr::identifier("bar")),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_comparison_chaining() {
+        let input = "foo < bar < baz";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::and(
+                    Expr::less_than(Expr::identifier("foo"), Expr::identifier("bar")),
+                    Expr::less_than(Expr::identifier("bar"), Expr::identifier("baz"))
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_comparison_chaining_of_mixed_operators() {
+        let input = "foo <= bar != baz > qux";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::and(
+                    Expr::less_than_or_equal_to(Expr::identifier("foo"), Expr::identifier("bar")),
+                    Expr::and(
+                        Expr::not_equal_to(Expr::identifier("bar"), Expr::identifier("baz")),
+                        Expr::greater_than(Expr::identifier("baz"), Expr::identifier("qux"))
+                    )
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_comparison_chaining_evaluates_shared_operand_once() {
+        let input = "foo < bar(1) < baz";
+        let result = rib_expr().easy_parse(input);
+        let call = Expr::call(
+            DynamicParsedFunctionName {
+                site: ParsedFunctionSite::Global,
+                function: DynamicParsedFunctionReference::Function {
+                    function: "bar".to_string(),
+                },
+            },
+            vec![Expr::untyped_number(1f64)],
+        );
+
+        assert_eq!(
+            result,
+            Ok((
+                Expr::expr_block(vec![
+                    Expr::let_binding("comparison.0.0.1", call),
+                    Expr::and(
+                        Expr::less_than(
+                            Expr::identifier("foo"),
+                            Expr::identifier("comparison.0.0.1")
+                        ),
+                        Expr::less_than(
+                            Expr::identifier("comparison.0.0.1"),
+                            Expr::identifier("baz")
+                        )
+                    )
+                ]),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_comparison_chaining_binds_left_operand_before_shared_operand() {
+        let input = "foo(1) < bar(2) < baz";
+        let result = Expr::from_text(input).unwrap();
+        let call = |function: &str, arg: f64| {
+            Expr::call(
+                DynamicParsedFunctionName {
+                    site: ParsedFunctionSite::Global,
+                    function: DynamicParsedFunctionReference::Function {
+                        function: function.to_string(),
+                    },
+                },
+                vec![Expr::untyped_number(arg)],
+            )
+        };
+
+        assert_eq!(
+            result,
+            Expr::expr_block(vec![
+                Expr::let_binding("comparison.1.1.0", call("foo", 1f64)),
+                Expr::let_binding("comparison.1.10.1", call("bar", 2f64)),
+                Expr::and(
+                    Expr::less_than(
+                        Expr::identifier("comparison.1.1.0"),
+                        Expr::identifier("comparison.1.10.1")
+                    ),
+                    Expr::less_than(
+                        Expr::identifier("comparison.1.10.1"),
+                        Expr::identifier("baz")
+                    )
+                )
+            ])
+        );
+    }
+
+    #[test]
+    fn test_comparison_not_chained_across_other_operators() {
+        let input = "foo < bar && baz";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::and(
+                    Expr::less_than(Expr::identifier("foo"), Expr::identifier("bar")),
+                    Expr::identifier("baz")
+                ),
+                ""
+            ))
+        );
+    }
+
     #[test]
     fn test_binary_op_in_if_condition() {
         let input = "if true then foo > bar  else  bar == foo";
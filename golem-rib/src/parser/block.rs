@@ -1,12 +1,14 @@
 use crate::parser::errors::RibParseError;
 use crate::parser::rib_expr::rib_expr;
-use crate::Expr;
+use crate::parser::source_position::GetSourcePosition;
+use crate::{Expr, SourceSpan};
 use combine::parser::char::{char, spaces};
 use combine::{sep_by, ParseError, Parser};
 
 pub fn block<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -15,7 +17,12 @@ where
         if expressions.len() == 1 {
             expressions.first().unwrap().clone()
         } else {
-            Expr::expr_block(expressions)
+            let source_span = match (expressions.first(), expressions.last()) {
+                (Some(first), Some(last)) => first.source_span().merge(&last.source_span()),
+                _ => SourceSpan::default(),
+            };
+
+            Expr::expr_block(expressions).with_source_span(source_span)
         }
     })
 }
@@ -14,6 +14,7 @@
 
 use crate::parser::errors::RibParseError;
 use crate::parser::rib_expr::rib_expr;
+use crate::parser::source_position::GetSourcePosition;
 use crate::Expr;
 use combine::parser::char::{char, spaces};
 use combine::{attempt, sep_end_by, ParseError, Parser};
@@ -25,6 +26,7 @@ use combine::{attempt, sep_end_by, ParseError, Parser};
 pub fn block_without_return<Input>() -> impl Parser<Input, Output = Vec<Expr>>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -18,10 +18,12 @@ use combine::{attempt, ParseError, Parser};
 
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
+use crate::parser::source_position::GetSourcePosition;
 
 pub fn boolean_literal<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
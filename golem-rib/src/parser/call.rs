@@ -24,11 +24,13 @@ use crate::expr::Expr;
 use crate::function_name::{ParsedFunctionSite, SemVer};
 use crate::parser::errors::RibParseError;
 use crate::parser::rib_expr::rib_expr;
+use crate::parser::source_position::GetSourcePosition;
 
 // A call can be a function or constructing an anonymous variant at the type of writing Rib which user expects to work at runtime
 pub fn call<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -48,6 +50,7 @@ where
 pub fn function_name<Input>() -> impl Parser<Input, Output = DynamicParsedFunctionName>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
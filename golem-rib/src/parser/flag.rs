@@ -22,10 +22,12 @@ use combine::{
 
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
+use crate::parser::source_position::GetSourcePosition;
 
 pub fn flag<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
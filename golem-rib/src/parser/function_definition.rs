@@ -0,0 +1,115 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use combine::parser::char::{alpha_num, char as char_, spaces, string};
+use combine::{attempt, between, not_followed_by, ParseError, Parser};
+
+use crate::expr::Expr;
+use crate::parser::errors::RibParseError;
+use crate::parser::identifier::identifier_text;
+use crate::parser::lambda::lambda_parameters;
+use crate::parser::multi_line_code_block::multi_line_block;
+use crate::parser::source_position::GetSourcePosition;
+
+// A named function `fn add(x, y) { x + y }` is the same as `let add = |x, y| { x + y }`
+pub fn function_definition<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    attempt(
+        string("fn")
+            .skip(not_followed_by(alpha_num().or(char_('-')).or(char_('_'))).skip(spaces())),
+    )
+    .with(
+        (
+            identifier_text()
+                .skip(spaces())
+                .message("Unable to parse function name"),
+            between(
+                char_('(').skip(spaces()),
+                char_(')').skip(spaces()),
+                lambda_parameters(),
+            ),
+            multi_line_block(),
+        )
+            .map(|(name, parameters, body)| {
+                Expr::let_binding(name, Expr::lambda(parameters, body))
+            }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use combine::EasyParser;
+
+    use crate::parser::rib_expr::rib_expr;
+    use crate::parser::type_name::TypeName;
+    use crate::{Expr, LambdaParameter};
+
+    #[test]
+    fn test_function_definition() {
+        let input = "fn add(x: u64, y: u64) { x + y }";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::let_binding(
+                    "add",
+                    Expr::lambda(
+                        vec![
+                            LambdaParameter::new("x", Some(TypeName::U64)),
+                            LambdaParameter::new("y", Some(TypeName::U64))
+                        ],
+                        Expr::plus(Expr::identifier("x"), Expr::identifier("y"))
+                    )
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_function_definition_without_parameters() {
+        let input = r#"fn greeting() { let name = "foo"; name }"#;
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::let_binding(
+                    "greeting",
+                    Expr::lambda(
+                        vec![],
+                        Expr::expr_block(vec![
+                            Expr::let_binding("name", Expr::literal("foo")),
+                            Expr::identifier("name")
+                        ])
+                    )
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_identifier_starting_with_fn() {
+        let input = "fnord";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(result, Ok((Expr::identifier("fnord"), "")));
+    }
+}
@@ -18,15 +18,18 @@ use combine::{many, ParseError, Parser, Stream};
 
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
+use crate::parser::source_position::GetSourcePosition;
 
 const RESERVED_KEYWORDS: &[&str] = &[
     "if", "then", "else", "match", "ok", "some", "err", "none", "let", "for", "yield", "reduce",
+    "fn",
 ];
 
 pub fn identifier<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: Stream<Token = char>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -39,6 +42,7 @@ pub fn identifier_text<Input>() -> impl Parser<Input, Output = String>
 where
     Input: Stream<Token = char>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
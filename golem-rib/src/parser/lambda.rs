@@ -0,0 +1,161 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use combine::parser::char::{char as char_, spaces};
+use combine::{between, optional, sep_by, ParseError, Parser};
+
+use crate::expr::{Expr, LambdaParameter};
+use crate::parser::errors::RibParseError;
+use crate::parser::identifier::identifier_text;
+use crate::parser::rib_expr::rib_expr;
+use crate::parser::source_position::GetSourcePosition;
+use crate::parser::type_name::parse_type_name;
+
+// A lambda is an anonymous function such as `|x, y: u64| x + y`.
+// The body extends as far as possible, and `{ ... }` can be used for a multi-line body
+pub fn lambda<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    spaces()
+        .with(
+            (
+                between(
+                    char_('|').skip(spaces()),
+                    char_('|').skip(spaces()),
+                    lambda_parameters(),
+                ),
+                rib_expr(),
+            )
+                .map(|(parameters, body)| Expr::lambda(parameters, body)),
+        )
+        .message("Unable to parse lambda")
+}
+
+pub fn lambda_parameters<Input>() -> impl Parser<Input, Output = Vec<LambdaParameter>>
+where
+    Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    sep_by(lambda_parameter().skip(spaces()), char_(',').skip(spaces()))
+}
+
+fn lambda_parameter<Input>() -> impl Parser<Input, Output = LambdaParameter>
+where
+    Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    (
+        identifier_text().skip(spaces()),
+        optional(char_(':').skip(spaces()).with(parse_type_name())),
+    )
+        .map(|(name, type_name)| LambdaParameter::new(name, type_name))
+        .message("Unable to parse lambda parameter")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use combine::EasyParser;
+
+    use crate::parser::rib_expr::rib_expr;
+    use crate::parser::type_name::TypeName;
+    use crate::{Expr, LambdaParameter};
+
+    #[test]
+    fn test_lambda() {
+        let input = "|x, y| x + y";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::lambda(
+                    vec![
+                        LambdaParameter::new("x", None),
+                        LambdaParameter::new("y", None)
+                    ],
+                    Expr::plus(Expr::identifier("x"), Expr::identifier("y"))
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_lambda_with_typed_parameter() {
+        let input = "|x: u64| x * 2";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::lambda(
+                    vec![LambdaParameter::new("x", Some(TypeName::U64))],
+                    Expr::multiply(Expr::identifier("x"), Expr::untyped_number(2f64))
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_lambda_with_block_body() {
+        let input = "|x| { let y = x + 1; y }";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::lambda(
+                    vec![LambdaParameter::new("x", None)],
+                    Expr::expr_block(vec![
+                        Expr::let_binding(
+                            "y",
+                            Expr::plus(Expr::identifier("x"), Expr::untyped_number(1f64))
+                        ),
+                        Expr::identifier("y")
+                    ])
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_lambda_in_let_binding() {
+        let input = "let double = |x| x * 2";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::let_binding(
+                    "double",
+                    Expr::lambda(
+                        vec![LambdaParameter::new("x", None)],
+                        Expr::multiply(Expr::identifier("x"), Expr::untyped_number(2f64))
+                    )
+                ),
+                ""
+            ))
+        );
+    }
+}
@@ -23,11 +23,13 @@ use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
 use crate::parser::identifier::identifier_text;
 use crate::parser::rib_expr::rib_expr;
+use crate::parser::source_position::GetSourcePosition;
 use crate::parser::type_name::parse_type_name;
 
 pub fn let_binding<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -61,6 +63,7 @@ fn let_variable<Input>() -> impl Parser<Input, Output = String>
 where
     Input: combine::Stream<Token = char>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -75,7 +78,7 @@ mod tests {
     use combine::EasyParser;
 
     use crate::parser::type_name::TypeName;
-    use crate::{InferredType, VariableId};
+    use crate::{InferredType, SourceSpan, VariableId};
 
     use super::*;
 
@@ -183,7 +186,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::U8,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -200,7 +207,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::U16,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -217,7 +228,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::U32,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -234,7 +249,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::U64,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -251,7 +270,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::S8,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -268,7 +291,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::S16,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -285,7 +312,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::S32,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -302,7 +333,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::S64,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -319,7 +354,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::F32,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -336,7 +375,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::F64,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -353,7 +396,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::Chr,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -370,7 +417,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::Str,
-                    Expr::Identifier(VariableId::global("bar".to_string()), InferredType::Unknown)
+                    Expr::Identifier(
+                        VariableId::global("bar".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
@@ -387,7 +438,11 @@ mod tests {
                 Expr::let_binding_with_type(
                     "foo",
                     TypeName::List(Box::new(TypeName::U8)),
-                    Expr::Sequence(vec![], InferredType::List(Box::new(InferredType::Unknown)))
+                    Expr::Sequence(
+                        vec![],
+                        InferredType::List(Box::new(InferredType::Unknown)),
+                        SourceSpan::default()
+                    )
                 ),
                 ""
             ))
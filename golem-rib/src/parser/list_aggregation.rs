@@ -16,6 +16,7 @@ use crate::parser::block_without_return::block_without_return;
 use crate::parser::errors::RibParseError;
 use crate::parser::identifier::identifier_text;
 use crate::parser::rib_expr::rib_expr as expr;
+use crate::parser::source_position::GetSourcePosition;
 use crate::{Expr, VariableId};
 use combine::parser::char::{alpha_num, char, spaces, string};
 use combine::{attempt, not_followed_by, optional, ParseError, Parser, Stream};
@@ -23,6 +24,7 @@ use combine::{attempt, not_followed_by, optional, ParseError, Parser, Stream};
 pub fn list_aggregation<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -17,11 +17,13 @@ use combine::{parser, ParseError, Stream};
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
 use crate::parser::literal::internal::literal_;
+use crate::parser::source_position::GetSourcePosition;
 
 parser! {
     pub fn literal[Input]()(Input) -> Expr
     where [
         Input: Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,
     ]
     {
@@ -33,6 +35,7 @@ mod internal {
     use crate::expr::Expr;
     use crate::parser::block::block;
     use crate::parser::errors::RibParseError;
+    use crate::parser::source_position::GetSourcePosition;
     use combine::parser::char::char as char_;
     use combine::parser::char::spaces;
     use combine::parser::repeat::many;
@@ -41,6 +44,7 @@ mod internal {
     pub fn literal_<Input>() -> impl Parser<Input, Output = Expr>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -60,7 +64,7 @@ mod internal {
                         match first {
                             LiteralTerm::Static(s) => Expr::literal(s),
                             LiteralTerm::Dynamic(expr) => match expr {
-                                Expr::Literal(s, _) => Expr::literal(s),
+                                Expr::Literal(s, _, _) => Expr::literal(s),
                                 _ => Expr::concat(vec![expr.clone()]),
                             },
                         }
@@ -75,6 +79,7 @@ mod internal {
     fn static_term<Input>() -> impl Parser<Input, Output = LiteralTerm>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -87,6 +92,7 @@ mod internal {
     fn dynamic_term<Input>() -> impl Parser<Input, Output = LiteralTerm>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -20,12 +20,15 @@ pub(crate) mod call;
 mod cond;
 mod errors;
 mod flag;
+mod function_definition;
 mod identifier;
+mod lambda;
 mod let_binding;
 mod list_aggregation;
 mod list_comprehension;
 pub(crate) mod literal;
 mod multi_line_code_block;
+mod negative;
 mod not;
 mod number;
 mod optional;
@@ -36,5 +39,6 @@ pub(crate) mod rib_expr;
 mod select_field;
 mod select_index;
 mod sequence;
+pub(crate) mod source_position;
 mod tuple;
 pub(crate) mod type_name;
@@ -20,10 +20,12 @@ use combine::{
 
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
+use crate::parser::source_position::GetSourcePosition;
 
 pub fn multi_line_block<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -41,12 +43,14 @@ mod internal {
 
     use crate::parser::errors::RibParseError;
     use crate::parser::rib_expr::rib_expr;
+    use crate::parser::source_position::GetSourcePosition;
     use crate::Expr;
 
     // A block is different to a complete rib-program that the it may not be the end of the stream
     pub fn block<Input>() -> impl Parser<Input, Output = Expr>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -0,0 +1,93 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use combine::parser::char::{char, spaces};
+use combine::{ParseError, Parser};
+
+use crate::expr::Expr;
+use crate::parser::errors::RibParseError;
+use crate::parser::rib_expr::simple_expr;
+use crate::parser::source_position::GetSourcePosition;
+
+// Unary minus binds only to the simple expression that follows,
+// so that `-x + y` is `(-x) + y`. Number literals such as `-1` are parsed by the number parser
+pub fn negative<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    spaces()
+        .with(
+            (char('-').skip(spaces()), simple_expr())
+                .map(|(_, expr)| Expr::negative(expr))
+                .message("Unable to parse negative"),
+        )
+        .message("Unable to parse negative")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use combine::EasyParser;
+
+    use crate::parser::rib_expr::rib_expr;
+    use crate::Expr;
+
+    #[test]
+    fn test_negative_identifier() {
+        let input = "-foo";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(result, Ok((Expr::negative(Expr::identifier("foo")), "")));
+    }
+
+    #[test]
+    fn test_negative_number_is_a_number() {
+        let input = "-1";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(result, Ok((Expr::untyped_number(-1f64), "")));
+    }
+
+    #[test]
+    fn test_negative_in_binary_op() {
+        let input = "-foo + bar";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::plus(
+                    Expr::negative(Expr::identifier("foo")),
+                    Expr::identifier("bar")
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_negative_select_field() {
+        let input = "-foo.bar";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::negative(Expr::select_field(Expr::identifier("foo"), "bar")),
+                ""
+            ))
+        );
+    }
+}
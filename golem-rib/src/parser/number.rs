@@ -17,12 +17,14 @@ use combine::{many1, optional, ParseError, Parser};
 
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
+use crate::parser::source_position::GetSourcePosition;
 use crate::parser::type_name::{parse_basic_type, TypeName};
 
 pub fn number<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -20,10 +20,12 @@ use match_arm::*;
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
 use crate::parser::rib_expr::rib_expr;
+use crate::parser::source_position::GetSourcePosition;
 
 pub fn pattern_match<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -54,6 +56,7 @@ mod match_arm {
     use crate::expr::MatchArm;
     use crate::parser::errors::RibParseError;
     use crate::parser::rib_expr::rib_expr;
+    use crate::parser::source_position::GetSourcePosition;
 
     use super::arm_pattern::*;
 
@@ -61,6 +64,7 @@ mod match_arm {
     pub(crate) fn match_arm<Input>() -> impl Parser<Input, Output = MatchArm>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -85,11 +89,13 @@ mod arm_pattern {
     use crate::expr::ArmPattern;
     use crate::parser::errors::RibParseError;
     use crate::parser::pattern_match::internal::*;
+    use crate::parser::source_position::GetSourcePosition;
 
     // LHS of a match arm
     fn arm_pattern_<Input>() -> impl Parser<Input, Output = ArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -111,7 +117,7 @@ mod arm_pattern {
 
     parser! {
         pub(crate) fn arm_pattern[Input]()(Input) -> ArmPattern
-         where [Input: Stream<Token = char>, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]{
+         where [Input: Stream<Token = char>, Input::Position: GetSourcePosition, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]{
             arm_pattern_()
         }
     }
@@ -129,12 +135,14 @@ mod internal {
     use crate::expr::ArmPattern;
     use crate::parser::errors::RibParseError;
     use crate::parser::pattern_match::arm_pattern::*;
+    use crate::parser::source_position::GetSourcePosition;
 
     use crate::parser::rib_expr::rib_expr;
 
     pub(crate) fn arm_pattern_constructor<Input>() -> impl Parser<Input, Output = ArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -150,6 +158,7 @@ mod internal {
     pub(crate) fn arm_pattern_literal<Input>() -> impl Parser<Input, Output = ArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -160,6 +169,7 @@ mod internal {
     pub(crate) fn alias_name<Input>() -> impl Parser<Input, Output = String>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -172,6 +182,7 @@ mod internal {
     fn arm_pattern_constructor_with_name<Input>() -> impl Parser<Input, Output = ArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -190,6 +201,7 @@ mod internal {
     fn none_constructor<Input>() -> impl Parser<Input, Output = ArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -200,6 +212,7 @@ mod internal {
     fn tuple_arm_pattern_constructor<Input>() -> impl Parser<Input, Output = ArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -215,6 +228,7 @@ mod internal {
     fn list_arm_pattern_constructor<Input>() -> impl Parser<Input, Output = ArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -235,6 +249,7 @@ mod internal {
     fn record_arm_pattern_constructor<Input>() -> impl Parser<Input, Output = ArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -258,6 +273,7 @@ mod internal {
     fn key_arm_pattern<Input>() -> impl Parser<Input, Output = KeyArmPattern>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -276,6 +292,7 @@ mod internal {
     fn record_key<Input>() -> impl Parser<Input, Output = String>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -288,6 +305,7 @@ mod internal {
     fn constructor_type_name<Input>() -> impl Parser<Input, Output = String>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
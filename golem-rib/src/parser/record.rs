@@ -20,6 +20,7 @@ use combine::{
 
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
+use crate::parser::source_position::GetSourcePosition;
 
 use super::rib_expr::rib_expr;
 
@@ -27,6 +28,7 @@ parser! {
     pub fn record[Input]()(Input) -> Expr
     where [
         Input: Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,
     ]
     {
@@ -37,6 +39,7 @@ parser! {
 pub fn record_<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -63,6 +66,7 @@ where
 fn field_key<Input>() -> impl Parser<Input, Output = String>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -80,6 +84,7 @@ struct Field {
 fn field<Input>() -> impl Parser<Input, Output = Field>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -19,13 +19,14 @@ use combine::{ParseError, Parser};
 
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
+use crate::parser::source_position::GetSourcePosition;
 
-use super::binary_op::BinaryOp;
+pub(crate) use internal::simple_expr;
 
 // A rib expression := (simple_expr, rib_expr_rest*)
 parser! {
     pub fn rib_expr[Input]()(Input) -> Expr
-    where [Input: combine::Stream<Token = char>, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]
+    where [Input: combine::Stream<Token = char>, Input::Position: GetSourcePosition, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]
     {
        rib_expr_()
     }
@@ -34,6 +35,7 @@ parser! {
 pub fn rib_expr_<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -42,19 +44,24 @@ where
         .with(
             (internal::simple_expr(), internal::rib_expr_rest()).map(|(expr, rest)| {
                 // FIXME: Respect operator precedence
-                rest.into_iter().fold(expr, |acc, (op, next)| match op {
-                    BinaryOp::GreaterThan => Expr::greater_than(acc, next),
-                    BinaryOp::LessThan => Expr::less_than(acc, next),
-                    BinaryOp::LessThanOrEqualTo => Expr::less_than_or_equal_to(acc, next),
-                    BinaryOp::GreaterThanOrEqualTo => Expr::greater_than_or_equal_to(acc, next),
-                    BinaryOp::EqualTo => Expr::equal_to(acc, next),
-                    BinaryOp::And => Expr::and(acc, next),
-                    BinaryOp::Or => Expr::or(acc, next),
-                    BinaryOp::Add => Expr::plus(acc, next),
-                    BinaryOp::Subtract => Expr::minus(acc, next),
-                    BinaryOp::Multiply => Expr::multiply(acc, next),
-                    BinaryOp::Divide => Expr::divide(acc, next),
-                })
+                let mut rest = rest.into_iter().peekable();
+                let mut acc = expr;
+
+                while let Some((op, next)) = rest.next() {
+                    // Comparisons are chained, such that `a < b < c` is `a < b && b < c`
+                    if op.is_comparison() && rest.peek().is_some_and(|(op, _)| op.is_comparison()) {
+                        let mut chain = vec![(op, next)];
+                        while let Some(comparison) = rest.next_if(|(op, _)| op.is_comparison()) {
+                            chain.push(comparison);
+                        }
+                        acc = internal::comparison_chain(acc, chain);
+                    } else {
+                        let source_span = acc.source_span().merge(&next.source_span());
+                        acc = internal::binary_expr(op, acc, next).with_source_span(source_span);
+                    }
+                }
+
+                acc
             }),
         )
         .skip(spaces())
@@ -67,16 +74,20 @@ mod internal {
     use crate::parser::cond::conditional;
     use crate::parser::errors::RibParseError;
     use crate::parser::flag::flag;
+    use crate::parser::function_definition::function_definition;
     use crate::parser::identifier::identifier;
+    use crate::parser::lambda::lambda;
     use crate::parser::let_binding::let_binding;
     use crate::parser::literal::literal;
     use crate::parser::multi_line_code_block::multi_line_block;
+    use crate::parser::negative::negative;
     use crate::parser::not::not;
     use crate::parser::number::number;
     use crate::parser::optional::option;
     use crate::parser::pattern_match::pattern_match;
     use crate::parser::record::record;
     use crate::parser::result::result;
+    use crate::parser::source_position::{source_span, GetSourcePosition};
 
     use crate::parser::list_aggregation::list_aggregation;
     use crate::parser::list_comprehension::list_comprehension;
@@ -86,43 +97,142 @@ mod internal {
     use crate::parser::tuple::tuple;
     use crate::Expr;
     use combine::parser::char::spaces;
-    use combine::{attempt, choice, many, parser, ParseError, Parser, Stream};
+    use combine::{attempt, choice, many, parser, position, ParseError, Parser, Stream};
+
+    pub(crate) fn binary_expr(op: BinaryOp, left: Expr, right: Expr) -> Expr {
+        match op {
+            BinaryOp::GreaterThan => Expr::greater_than(left, right),
+            BinaryOp::LessThan => Expr::less_than(left, right),
+            BinaryOp::LessThanOrEqualTo => Expr::less_than_or_equal_to(left, right),
+            BinaryOp::GreaterThanOrEqualTo => Expr::greater_than_or_equal_to(left, right),
+            BinaryOp::EqualTo => Expr::equal_to(left, right),
+            BinaryOp::NotEqualTo => Expr::not_equal_to(left, right),
+            BinaryOp::And => Expr::and(left, right),
+            BinaryOp::Or => Expr::or(left, right),
+            BinaryOp::Add => Expr::plus(left, right),
+            BinaryOp::Subtract => Expr::minus(left, right),
+            BinaryOp::Multiply => Expr::multiply(left, right),
+            BinaryOp::Divide => Expr::divide(left, right),
+            BinaryOp::Modulo => Expr::modulo(left, right),
+        }
+    }
+
+    // Builds `left op1 e1 op2 e2 ...` as `left op1 e1 && (e1 op2 e2 && ...)`.
+    // An operand shared by two comparisons is bound to a variable, such that it is evaluated only once,
+    // in which case the left operand is bound as well to keep the evaluation order.
+    // Like with `&&`, the comparisons after the first false one are not evaluated.
+    pub(crate) fn comparison_chain(left: Expr, chain: Vec<(BinaryOp, Expr)>) -> Expr {
+        comparison_chain_from(0, left, chain)
+    }
+
+    fn comparison_chain_from(index: usize, left: Expr, chain: Vec<(BinaryOp, Expr)>) -> Expr {
+        let mut chain = chain.into_iter();
+
+        let Some((op, right)) = chain.next() else {
+            return left;
+        };
+
+        let rest = chain.collect::<Vec<_>>();
+        let source_span = left.source_span().merge(
+            &rest
+                .last()
+                .map_or(right.source_span(), |(_, last)| last.source_span()),
+        );
+
+        if rest.is_empty() {
+            return binary_expr(op, left, right).with_source_span(source_span);
+        }
+
+        let mut bindings = vec![];
+
+        let (left, right) = if needs_binding(&right) {
+            let left = if needs_binding(&left) {
+                bind(index, left, &mut bindings)
+            } else {
+                left
+            };
+
+            (left, bind(index + 1, right, &mut bindings))
+        } else {
+            (left, right)
+        };
+
+        let comparison_span = left.source_span().merge(&right.source_span());
+        let comparison = binary_expr(op, left, right.clone()).with_source_span(comparison_span);
+
+        let expr = Expr::and(comparison, comparison_chain_from(index + 1, right, rest))
+            .with_source_span(source_span);
+
+        if bindings.is_empty() {
+            expr
+        } else {
+            bindings.push(expr);
+            Expr::expr_block(bindings).with_source_span(source_span)
+        }
+    }
+
+    fn needs_binding(expr: &Expr) -> bool {
+        !matches!(
+            expr,
+            Expr::Identifier(..) | Expr::Literal(..) | Expr::Number(..) | Expr::Boolean(..)
+        )
+    }
+
+    // The variable is named after the position of the operand and its index in the chain,
+    // which is not a valid identifier, and hence never clashes with the variables of the script
+    fn bind(index: usize, expr: Expr, bindings: &mut Vec<Expr>) -> Expr {
+        let source_span = expr.source_span();
+        let start = source_span.start();
+        let name = format!("comparison.{}.{}.{}", start.line, start.column, index);
+
+        bindings.push(Expr::let_binding(&name, expr).with_source_span(source_span));
+        Expr::identifier(&name).with_source_span(source_span)
+    }
 
     // A simple expression is a composition of all parsers that doesn't involve left recursion
     pub fn simple_expr_<Input>() -> impl Parser<Input, Output = Expr>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
     {
         spaces()
-            .with(choice((
-                list_comprehension(),
-                list_aggregation(),
-                pattern_match(),
-                let_binding(),
-                conditional(),
-                selection_expr(),
-                flag_or_record(),
-                multi_line_block(),
-                tuple(),
-                sequence(),
-                boolean_literal(),
-                literal(),
-                not(),
-                option(),
-                result(),
-                attempt(call()),
-                identifier(),
-                number(),
-            )))
+            .with((
+                position(),
+                choice((
+                    list_comprehension(),
+                    list_aggregation(),
+                    pattern_match(),
+                    let_binding(),
+                    function_definition(),
+                    conditional(),
+                    selection_expr(),
+                    flag_or_record(),
+                    multi_line_block(),
+                    tuple(),
+                    sequence(),
+                    boolean_literal(),
+                    literal(),
+                    not(),
+                    option(),
+                    result(),
+                    lambda(),
+                    attempt(call()),
+                    identifier(),
+                    attempt(number()),
+                    negative(),
+                )),
+                position(),
+            ))
+            .map(|(start, expr, end)| expr.with_source_span(source_span(&start, &end)))
             .skip(spaces())
     }
 
     parser! {
         pub(crate) fn simple_expr[Input]()(Input) -> Expr
-        where [Input: Stream<Token = char>, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]
+        where [Input: Stream<Token = char>, Input::Position: GetSourcePosition, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]
         {
             simple_expr_()
         }
@@ -131,6 +241,7 @@ mod internal {
     pub fn rib_expr_rest_<Input>() -> impl Parser<Input, Output = Vec<(BinaryOp, Expr)>>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -140,7 +251,7 @@ mod internal {
 
     parser! {
         pub(crate) fn rib_expr_rest[Input]()(Input) -> Vec<(BinaryOp, Expr)>
-        where [Input: Stream<Token = char>, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]
+        where [Input: Stream<Token = char>, Input::Position: GetSourcePosition, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]
         {
             rib_expr_rest_()
         }
@@ -149,6 +260,7 @@ mod internal {
     fn flag_or_record<Input>() -> impl Parser<Input, Output = Expr>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -159,6 +271,7 @@ mod internal {
     fn selection_expr<Input>() -> impl Parser<Input, Output = Expr>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -22,10 +22,11 @@ use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
 use crate::parser::identifier::identifier;
 use crate::parser::record::record;
+use crate::parser::source_position::GetSourcePosition;
 
 parser! {
     pub fn select_field[Input]()(Input) -> Expr
-    where [Input: Stream<Token = char>, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]
+    where [Input: Stream<Token = char>, Input::Position: GetSourcePosition, RibParseError: Into<<Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError>,]
     {
         select_field_()
     }
@@ -37,6 +38,7 @@ mod internal {
 
     use crate::parser::errors::RibParseError;
     use crate::parser::select_index::select_index;
+    use crate::parser::source_position::GetSourcePosition;
 
     use super::*;
 
@@ -45,6 +47,7 @@ mod internal {
     pub(crate) fn select_field_<Input>() -> impl Parser<Input, Output = Expr>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -73,14 +76,14 @@ mod internal {
     fn build_selector(base: Expr, nest: Expr) -> Option<Expr> {
         // a.b
         match nest {
-            Expr::Identifier(variable_id, _) => {
+            Expr::Identifier(variable_id, _, _) => {
                 Some(Expr::select_field(base, variable_id.name().as_str()))
             }
-            Expr::SelectField(second, last, _) => {
+            Expr::SelectField(second, last, _, _) => {
                 let inner_select = build_selector(base, *second)?;
                 Some(Expr::select_field(inner_select, last.as_str()))
             }
-            Expr::SelectIndex(second, last_index, _) => {
+            Expr::SelectIndex(second, last_index, _, _) => {
                 let inner_select = build_selector(base, *second)?;
                 Some(Expr::select_index(inner_select, last_index))
             }
@@ -91,6 +94,7 @@ mod internal {
     fn base_expr<Input>() -> impl Parser<Input, Output = Expr>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -105,6 +109,7 @@ mod internal {
     fn field_name<Input>() -> impl Parser<Input, Output = String>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
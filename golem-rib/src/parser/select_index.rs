@@ -20,10 +20,12 @@ use internal::*;
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
 use crate::parser::identifier::identifier;
+use crate::parser::source_position::GetSourcePosition;
 
 pub fn select_index<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: combine::Stream<Token = char>,
+    Input::Position: GetSourcePosition,
     RibParseError: Into<
         <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
     >,
@@ -66,6 +68,7 @@ mod internal {
     pub(crate) fn nested_indices<Input>() -> impl Parser<Input, Output = Vec<usize>>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
@@ -84,12 +87,13 @@ mod internal {
     pub(crate) fn pos_num<Input>() -> impl Parser<Input, Output = usize>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
     {
         number().map(|s: Expr| match s {
-            Expr::Number(number, _, _) => {
+            Expr::Number(number, _, _, _) => {
                 if number.value < 0.0 {
                     panic!("Cannot use a negative number to index",)
                 } else {
@@ -103,6 +107,7 @@ mod internal {
     pub(crate) fn base_expr<Input>() -> impl Parser<Input, Output = Expr>
     where
         Input: combine::Stream<Token = char>,
+        Input::Position: GetSourcePosition,
         RibParseError: Into<
             <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
         >,
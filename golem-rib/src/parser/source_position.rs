@@ -0,0 +1,70 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use combine::stream::position;
+use combine::stream::PointerOffset;
+use std::collections::VecDeque;
+
+use crate::{Expr, SourcePosition, SourceSpan};
+
+// The parsers can run on any stream of chars, however only the streams
+// keeping track of line and column (Example: `Expr::from_text`) can give
+// the source span of the parsed expressions
+pub trait GetSourcePosition {
+    fn get_source_position(&self) -> Option<SourcePosition>;
+}
+
+impl GetSourcePosition for position::SourcePosition {
+    fn get_source_position(&self) -> Option<SourcePosition> {
+        Some(SourcePosition::new(
+            self.line as usize,
+            self.column as usize,
+        ))
+    }
+}
+
+impl<T: ?Sized> GetSourcePosition for PointerOffset<T> {
+    fn get_source_position(&self) -> Option<SourcePosition> {
+        None
+    }
+}
+
+pub fn source_span<P: GetSourcePosition>(start: &P, end: &P) -> SourceSpan {
+    match (start.get_source_position(), end.get_source_position()) {
+        (Some(start), Some(end)) => SourceSpan::new(start, end),
+        _ => SourceSpan::default(),
+    }
+}
+
+// Expressions built by the parsers out of other parsed expressions (Example: the inner
+// selections of `a.b.c`) take the span of the closest parent with a known span
+pub fn inherit_source_spans(expr: &mut Expr) {
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_back() {
+        let source_span = expr.source_span();
+
+        let mut children = VecDeque::new();
+        expr.visit_children_mut_top_down(&mut children);
+
+        for child in children {
+            if child.source_span().is_unknown() {
+                *child.source_span_mut() = source_span;
+            }
+
+            queue.push_back(child);
+        }
+    }
+}
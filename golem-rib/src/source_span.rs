@@ -0,0 +1,118 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{BorrowDecode, Decode, Encode};
+use std::fmt::Display;
+
+// Line and column (both starting at 1) of a position in a Rib script
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourcePosition {
+    pub fn new(line: usize, column: usize) -> SourcePosition {
+        SourcePosition { line, column }
+    }
+}
+
+impl Display for SourcePosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+// The span of an expression in the Rib script it is parsed from.
+// Expressions that are not parsed from a script (Example: created while type inference or desugaring)
+// have an unknown span, which is the default.
+//
+// A span is only a diagnostic information, and is not part of the identity of an expression:
+// two expressions that differ only in their spans are equal, and spans are not encoded.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SourceSpan {
+    start: SourcePosition,
+    end: SourcePosition,
+}
+
+impl SourceSpan {
+    pub fn new(start: SourcePosition, end: SourcePosition) -> SourceSpan {
+        SourceSpan { start, end }
+    }
+
+    pub fn start(&self) -> SourcePosition {
+        self.start
+    }
+
+    pub fn end(&self) -> SourcePosition {
+        self.end
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        self.start.line == 0
+    }
+
+    // The span starting from this span and ending at the end of the other span,
+    // which is unknown if any of them is unknown
+    pub fn merge(&self, other: &SourceSpan) -> SourceSpan {
+        if self.is_unknown() || other.is_unknown() {
+            SourceSpan::default()
+        } else {
+            SourceSpan::new(self.start, other.end)
+        }
+    }
+}
+
+impl Display for SourceSpan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_unknown() {
+            write!(f, "unknown position")
+        } else {
+            write!(f, "{}", self.start)
+        }
+    }
+}
+
+impl PartialEq for SourceSpan {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SourceSpan {}
+
+impl Encode for SourceSpan {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        _encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        Ok(())
+    }
+}
+
+impl Decode for SourceSpan {
+    fn decode<D: bincode::de::Decoder>(
+        _decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        Ok(SourceSpan::default())
+    }
+}
+
+impl<'de> BorrowDecode<'de> for SourceSpan {
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de>>(
+        _decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        Ok(SourceSpan::default())
+    }
+}
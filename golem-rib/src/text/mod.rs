@@ -864,6 +864,21 @@ mod tuple_tests {
         assert_eq!((expr_str, input_expr), (expected_str, output_expr));
     }
 
+    #[test]
+    fn test_round_trip_read_write_tuple_of_modulo_and_not_equal_to() {
+        let input_expr = Expr::tuple(vec![
+            Expr::modulo(Expr::identifier("foo"), Expr::untyped_number(2f64)),
+            Expr::not_equal_to(
+                Expr::negative(Expr::identifier("foo")),
+                Expr::identifier("bar"),
+            ),
+        ]);
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = "(foo % 2, -foo != bar)".to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+
     #[test]
     fn test_round_trip_read_write_tuple_of_constructor() {
         let input_expr = Expr::tuple(vec![
@@ -963,7 +978,7 @@ mod let_tests {
     use crate::expr::Expr;
     use crate::parser::type_name::TypeName;
     use crate::text::{from_string, to_string};
-    use crate::{InferredType, VariableId};
+    use crate::{InferredType, SourceSpan, VariableId};
 
     #[test]
     fn test_round_trip_read_write_let() {
@@ -985,12 +1000,14 @@ mod let_tests {
                 Some(TypeName::Str),
                 Box::new(Expr::literal("hello")),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
             Expr::Let(
                 VariableId::global("y".to_string()),
                 Some(TypeName::Str),
                 Box::new(Expr::literal("bar")),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
         ]);
         let expr_str = to_string(&input_expr).unwrap();
@@ -1007,12 +1024,14 @@ mod let_tests {
                 Some(TypeName::U8),
                 Box::new(Expr::untyped_number(1f64)),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
             Expr::Let(
                 VariableId::global("y".to_string()),
                 Some(TypeName::U8),
                 Box::new(Expr::untyped_number(2f64)),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
         ]);
         let expr_str = to_string(&input_expr).unwrap();
@@ -1029,12 +1048,14 @@ mod let_tests {
                 Some(TypeName::U16),
                 Box::new(Expr::untyped_number(1f64)),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
             Expr::Let(
                 VariableId::global("y".to_string()),
                 Some(TypeName::U16),
                 Box::new(Expr::untyped_number(2f64)),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
         ]);
         let expr_str = to_string(&input_expr).unwrap();
@@ -1051,12 +1072,14 @@ mod let_tests {
                 Some(TypeName::U32),
                 Box::new(Expr::untyped_number(1f64)),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
             Expr::Let(
                 VariableId::global("y".to_string()),
                 Some(TypeName::U32),
                 Box::new(Expr::untyped_number(2f64)),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
         ]);
         let expr_str = to_string(&input_expr).unwrap();
@@ -1074,8 +1097,10 @@ mod let_tests {
                 Box::new(Expr::Option(
                     Some(Box::new(Expr::literal("foo"))),
                     InferredType::Option(Box::new(InferredType::Str)),
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
             Expr::Let(
                 VariableId::global("y".to_string()),
@@ -1083,8 +1108,10 @@ mod let_tests {
                 Box::new(Expr::Option(
                     Some(Box::new(Expr::literal("bar"))),
                     InferredType::Option(Box::new(InferredType::Str)),
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
         ]);
         let expr_str = to_string(&input_expr).unwrap();
@@ -1103,8 +1130,10 @@ mod let_tests {
                 Box::new(Expr::Sequence(
                     vec![Expr::literal("foo")],
                     InferredType::List(Box::new(InferredType::Str)),
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
             Expr::Let(
                 VariableId::global("y".to_string()),
@@ -1112,8 +1141,10 @@ mod let_tests {
                 Box::new(Expr::Sequence(
                     vec![Expr::literal("bar")],
                     InferredType::List(Box::new(InferredType::Str)),
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
         ]);
         let expr_str = to_string(&input_expr).unwrap();
@@ -1132,8 +1163,10 @@ mod let_tests {
                 Box::new(Expr::Tuple(
                     vec![Expr::literal("foo")],
                     InferredType::Tuple(vec![InferredType::Str]),
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
             Expr::Let(
                 VariableId::global("y".to_string()),
@@ -1141,8 +1174,10 @@ mod let_tests {
                 Box::new(Expr::Tuple(
                     vec![Expr::literal("bar")],
                     InferredType::Tuple(vec![InferredType::Str]),
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             ),
         ]);
         let expr_str = to_string(&input_expr).unwrap();
@@ -1789,3 +1824,32 @@ mod if_cond_tests {
         assert_eq!((expr_str, input_expr), (expected_str, output_expr));
     }
 }
+
+#[cfg(test)]
+mod lambda_tests {
+    use test_r::test;
+
+    use crate::expr::Expr;
+    use crate::parser::type_name::TypeName;
+    use crate::text::{from_string, to_string};
+    use crate::LambdaParameter;
+
+    #[test]
+    fn test_round_trip_read_write_lambda() {
+        let input_expr = Expr::let_binding(
+            "add",
+            Expr::lambda(
+                vec![
+                    LambdaParameter::new("x", Some(TypeName::U64)),
+                    LambdaParameter::new("y", None),
+                ],
+                Expr::plus(Expr::identifier("x"), Expr::identifier("y")),
+            ),
+        );
+
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = "let add = |x: u64, y| x + y".to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+}
@@ -75,14 +75,14 @@ impl<W: Write> Writer<W> {
 
     fn write_expr(&mut self, expr: &Expr) -> Result<(), WriterError> {
         match expr {
-            Expr::Literal(string, _) => {
+            Expr::Literal(string, _, _) => {
                 self.write_display("\"")?;
                 self.write_str(string)?;
                 self.write_display("\"")
             }
-            Expr::Identifier(identifier, _) => self.write_str(identifier.name()),
+            Expr::Identifier(identifier, _, _) => self.write_str(identifier.name()),
 
-            Expr::Let(variable_id, type_name, expr, _) => {
+            Expr::Let(variable_id, type_name, expr, _, _) => {
                 self.write_str("let ")?;
                 self.write_str(variable_id.name())?;
                 if let Some(type_name) = type_name {
@@ -92,18 +92,18 @@ impl<W: Write> Writer<W> {
                 self.write_str(" = ")?;
                 self.write_expr(expr)
             }
-            Expr::SelectField(expr, field_name, _) => {
+            Expr::SelectField(expr, field_name, _, _) => {
                 self.write_expr(expr)?;
                 self.write_str(".")?;
                 self.write_str(field_name)
             }
-            Expr::SelectIndex(expr, index, _) => {
+            Expr::SelectIndex(expr, index, _, _) => {
                 self.write_expr(expr)?;
                 self.write_display("[")?;
                 self.write_display(index)?;
                 self.write_display("]")
             }
-            Expr::Sequence(sequence, _) => {
+            Expr::Sequence(sequence, _, _) => {
                 self.write_display("[")?;
                 for (idx, expr) in sequence.iter().enumerate() {
                     if idx != 0 {
@@ -114,7 +114,7 @@ impl<W: Write> Writer<W> {
                 }
                 self.write_display("]")
             }
-            Expr::Record(record, _) => {
+            Expr::Record(record, _, _) => {
                 self.write_display("{")?;
                 for (idx, (key, value)) in record.iter().enumerate() {
                     if idx != 0 {
@@ -128,7 +128,7 @@ impl<W: Write> Writer<W> {
                 }
                 self.write_display("}")
             }
-            Expr::Tuple(tuple, _) => {
+            Expr::Tuple(tuple, _, _) => {
                 self.write_display("(")?;
                 for (idx, expr) in tuple.iter().enumerate() {
                     if idx != 0 {
@@ -139,14 +139,14 @@ impl<W: Write> Writer<W> {
                 }
                 self.write_display(")")
             }
-            Expr::Number(number, type_name, _) => {
+            Expr::Number(number, type_name, _, _) => {
                 self.write_display(number.value)?;
                 if let Some(type_name) = type_name {
                     self.write_display(type_name)?;
                 }
                 Ok(())
             }
-            Expr::Flags(flags, _) => {
+            Expr::Flags(flags, _, _) => {
                 self.write_display("{")?;
                 for (idx, flag) in flags.iter().enumerate() {
                     if idx != 0 {
@@ -157,13 +157,13 @@ impl<W: Write> Writer<W> {
                 }
                 self.write_display("}")
             }
-            Expr::Boolean(bool, _) => self.write_display(bool),
-            Expr::Concat(concatenated, _) => {
+            Expr::Boolean(bool, _, _) => self.write_display(bool),
+            Expr::Concat(concatenated, _, _) => {
                 self.write_display("\"")?;
                 internal::write_concatenated_exprs(self, concatenated)?;
                 self.write_display("\"")
             }
-            Expr::ExprBlock(expr, _) => {
+            Expr::ExprBlock(expr, _, _) => {
                 for (idx, expr) in expr.iter().enumerate() {
                     if idx != 0 {
                         self.write_display(";")?;
@@ -173,56 +173,70 @@ impl<W: Write> Writer<W> {
                 }
                 Ok(())
             }
-            Expr::Not(expr, _) => {
+            Expr::Not(expr, _, _) => {
                 self.write_str("!")?;
                 self.write_expr(expr)
             }
-            Expr::GreaterThan(left, right, _) => {
+            Expr::Negative(expr, _, _) => {
+                self.write_str("-")?;
+                self.write_expr(expr)
+            }
+            Expr::GreaterThan(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" > ")?;
                 self.write_expr(right)
             }
-            Expr::Plus(left, right, _) => {
+            Expr::Plus(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" + ")?;
                 self.write_expr(right)
             }
-            Expr::Minus(left, right, _) => {
+            Expr::Minus(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" - ")?;
                 self.write_expr(right)
             }
-            Expr::Divide(left, right, _) => {
+            Expr::Divide(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" / ")?;
                 self.write_expr(right)
             }
-            Expr::Multiply(left, right, _) => {
+            Expr::Multiply(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" * ")?;
                 self.write_expr(right)
             }
-            Expr::GreaterThanOrEqualTo(left, right, _) => {
+            Expr::Modulo(left, right, _, _) => {
+                self.write_expr(left)?;
+                self.write_str(" % ")?;
+                self.write_expr(right)
+            }
+            Expr::GreaterThanOrEqualTo(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" >= ")?;
                 self.write_expr(right)
             }
-            Expr::LessThanOrEqualTo(left, right, _) => {
+            Expr::LessThanOrEqualTo(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" <= ")?;
                 self.write_expr(right)
             }
-            Expr::EqualTo(left, right, _) => {
+            Expr::EqualTo(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" == ")?;
                 self.write_expr(right)
             }
-            Expr::LessThan(left, right, _) => {
+            Expr::NotEqualTo(left, right, _, _) => {
+                self.write_expr(left)?;
+                self.write_str(" != ")?;
+                self.write_expr(right)
+            }
+            Expr::LessThan(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" < ")?;
                 self.write_expr(right)
             }
-            Expr::Cond(if_expr, left, right, _) => {
+            Expr::Cond(if_expr, left, right, _, _) => {
                 self.write_str("if ")?;
                 self.write_expr(if_expr)?;
                 self.write_str(" then ")?;
@@ -230,7 +244,7 @@ impl<W: Write> Writer<W> {
                 self.write_str(" else ")?;
                 self.write_expr(right)
             }
-            Expr::PatternMatch(match_expr, match_terms, _) => {
+            Expr::PatternMatch(match_expr, match_terms, _, _) => {
                 self.write_str("match ")?;
                 self.write_expr(match_expr)?;
                 self.write_str(" { ")?;
@@ -249,7 +263,7 @@ impl<W: Write> Writer<W> {
                 }
                 self.write_str(" } ")
             }
-            Expr::Option(constructor, _) => match constructor {
+            Expr::Option(constructor, _, _) => match constructor {
                 Some(expr) => {
                     self.write_str("some(")?;
                     self.write_expr(expr)?;
@@ -257,7 +271,7 @@ impl<W: Write> Writer<W> {
                 }
                 None => self.write_str("none"),
             },
-            Expr::Result(constructor, _) => match constructor {
+            Expr::Result(constructor, _, _) => match constructor {
                 Ok(expr) => {
                     self.write_str("ok(")?;
                     self.write_expr(expr)?;
@@ -270,7 +284,7 @@ impl<W: Write> Writer<W> {
                 }
             },
 
-            Expr::Call(invocation_name, params, _) => {
+            Expr::Call(invocation_name, params, _, _) => {
                 let function_name = invocation_name.to_string();
 
                 self.write_str(function_name)?;
@@ -285,28 +299,45 @@ impl<W: Write> Writer<W> {
                 self.write_display(")")
             }
 
-            Expr::Unwrap(expr, _) => {
+            Expr::Unwrap(expr, _, _) => {
                 self.write_str("unwrap(")?;
                 self.write_expr(expr)?;
                 self.write_str(")")
             }
 
-            Expr::Throw(msg, _) => {
+            Expr::Throw(msg, _, _) => {
                 self.write_str("throw(")?;
                 self.write_str(msg)?;
                 self.write_str(")")
             }
-            Expr::GetTag(expr, _) => {
+            Expr::Lambda(parameters, body, _, _) => {
+                self.write_display("|")?;
+                for (idx, parameter) in parameters.iter().enumerate() {
+                    if idx != 0 {
+                        self.write_display(",")?;
+                        self.write_display(" ")?;
+                    }
+                    self.write_str(&parameter.name)?;
+                    if let Some(type_name) = &parameter.type_name {
+                        self.write_str(": ")?;
+                        self.write_display(type_name)?;
+                    }
+                }
+                self.write_display("|")?;
+                self.write_display(" ")?;
+                self.write_expr(body)
+            }
+            Expr::GetTag(expr, _, _) => {
                 self.write_str("get_tag(")?;
                 self.write_expr(expr)?;
                 self.write_str(")")
             }
-            Expr::And(left, right, _) => {
+            Expr::And(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" && ")?;
                 self.write_expr(right)
             }
-            Expr::Or(left, right, _) => {
+            Expr::Or(left, right, _, _) => {
                 self.write_expr(left)?;
                 self.write_str(" || ")?;
                 self.write_expr(right)
@@ -380,7 +411,7 @@ mod internal {
     where
         W: std::io::Write,
     {
-        if let Expr::ExprBlock(yield_lines, _) = expr {
+        if let Expr::ExprBlock(yield_lines, _, _) = expr {
             let last_line_index = yield_lines.len() - 1;
 
             for (index, line) in yield_lines.iter().enumerate() {
@@ -403,8 +434,8 @@ mod internal {
 
     pub(crate) fn get_expr_type(expr: &Expr) -> ExprType {
         match expr {
-            Expr::Literal(str, _) => ExprType::Text(str),
-            Expr::Concat(_, _) => ExprType::StringInterpolated,
+            Expr::Literal(str, _, _) => ExprType::Text(str),
+            Expr::Concat(_, _, _) => ExprType::StringInterpolated,
             expr => ExprType::Code(expr),
         }
     }
@@ -513,7 +544,7 @@ mod internal {
             }
 
             ArmPattern::Literal(expr) => match *expr.clone() {
-                Expr::Identifier(s, _) => writer.write_str(s.name()),
+                Expr::Identifier(s, _, _) => writer.write_str(s.name()),
                 any_expr => writer.write_expr(&any_expr),
             },
         }
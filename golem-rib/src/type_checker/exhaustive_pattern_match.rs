@@ -1,4 +1,4 @@
-use crate::{ArmPattern, Expr, FunctionTypeRegistry};
+use crate::{ArmPattern, Expr, FunctionTypeRegistry, RibCompilationError};
 use std::collections::VecDeque;
 
 // When checking exhaustive pattern match, there is no need to ensure
@@ -8,18 +8,21 @@ use std::collections::VecDeque;
 pub fn check_exhaustive_pattern_match(
     expr: &mut Expr,
     function_type_registry: &FunctionTypeRegistry,
-) -> Result<(), ExhaustiveCheckError> {
+) -> Result<(), RibCompilationError> {
     let mut queue = VecDeque::new();
     queue.push_back(expr);
 
     while let Some(expr) = queue.pop_back() {
         match expr {
-            Expr::PatternMatch(_, patterns, _) => {
+            Expr::PatternMatch(_, patterns, _, source_span) => {
                 let match_arm = patterns
                     .iter()
                     .map(|p| p.arm_pattern.clone())
                     .collect::<Vec<_>>();
-                internal::check_exhaustive_pattern_match(&match_arm, function_type_registry)?;
+                internal::check_exhaustive_pattern_match(&match_arm, function_type_registry)
+                    .map_err(|exhaustive_check_error| {
+                        RibCompilationError::new(exhaustive_check_error.to_string(), *source_span)
+                    })?;
             }
 
             expr => expr.visit_children_mut_bottom_up(&mut queue),
@@ -211,7 +214,7 @@ mod internal {
                     }
                 }
                 arm_pattern @ ArmPattern::Literal(expr) => {
-                    if let Expr::Call(call_type, args, _) = expr.deref() {
+                    if let Expr::Call(call_type, args, _, _) = expr.deref() {
                         let ctor_name = call_type.to_string();
                         let arm_patterns = args
                             .iter()
@@ -514,7 +517,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Dead code detected. The pattern `some(_)` is unreachable due to the existence of the pattern `_` prior to it")
     }
@@ -530,7 +533,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
         assert_eq!(result, "Error: Dead code detected. The pattern `none` is unreachable due to the existence of the pattern `_` prior to it")
     }
 
@@ -545,7 +548,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
         assert_eq!(result, "Error: Dead code detected. The pattern `some(_)` is unreachable due to the existence of the pattern `something` prior to it")
     }
 
@@ -560,7 +563,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
         assert_eq!(result, "Error: Dead code detected. The pattern `none` is unreachable due to the existence of the pattern `something` prior to it")
     }
 
@@ -574,7 +577,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Non-exhaustive pattern match. The following patterns are not covered: `none`. To ensure a complete match, add these patterns or cover them with a wildcard (`_`) or an identifier.")
     }
@@ -589,7 +592,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Non-exhaustive pattern match. The following patterns are not covered: `some`. To ensure a complete match, add these patterns or cover them with a wildcard (`_`) or an identifier.")
     }
@@ -605,7 +608,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Non-exhaustive pattern match. The following patterns are not covered: `some(none)`. To ensure a complete match, add these patterns or cover them with a wildcard (`_`) or an identifier.")
     }
@@ -729,7 +732,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Dead code detected. The pattern `ok(_)` is unreachable due to the existence of the pattern `_` prior to it")
     }
@@ -745,7 +748,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
         assert_eq!(result, "Error: Dead code detected. The pattern `err(msg)` is unreachable due to the existence of the pattern `_` prior to it")
     }
 
@@ -760,7 +763,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
         assert_eq!(result, "Error: Dead code detected. The pattern `ok(_)` is unreachable due to the existence of the pattern `something` prior to it")
     }
 
@@ -775,7 +778,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
         assert_eq!(result, "Error: Dead code detected. The pattern `err(msg)` is unreachable due to the existence of the pattern `something` prior to it")
     }
 
@@ -790,7 +793,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Non-exhaustive pattern match. The following patterns are not covered: `err`. To ensure a complete match, add these patterns or cover them with a wildcard (`_`) or an identifier.")
     }
@@ -806,7 +809,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Non-exhaustive pattern match. The following patterns are not covered: `ok`. To ensure a complete match, add these patterns or cover them with a wildcard (`_`) or an identifier.")
     }
@@ -822,7 +825,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Non-exhaustive pattern match. The following patterns are not covered: `ok(ok)`. To ensure a complete match, add these patterns or cover them with a wildcard (`_`) or an identifier.")
     }
@@ -838,7 +841,7 @@ mod pattern_match_exhaustive_tests {
         "#;
 
         let expr = Expr::from_text(expr).unwrap();
-        let result = compile(&expr, &vec![]).unwrap_err();
+        let result = compile(&expr, &vec![]).unwrap_err().message;
 
         assert_eq!(result, "Error: Non-exhaustive pattern match. The following patterns are not covered: `ok(err)`. To ensure a complete match, add these patterns or cover them with a wildcard (`_`) or an identifier.")
     }
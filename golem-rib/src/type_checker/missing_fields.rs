@@ -11,7 +11,7 @@ pub fn find_missing_fields(expr: &Expr, expected: &AnalysedType) -> Vec<Path> {
             .iter()
             .map(|name_typ| (name_typ.name.clone(), name_typ.typ.clone()))
         {
-            if let Expr::Record(actual_reord, _) = expr {
+            if let Expr::Record(actual_reord, _, _) = expr {
                 let actual_value_opt = actual_reord
                     .iter()
                     .find(|(name, _)| *name == field_name)
@@ -11,20 +11,30 @@ mod type_check_error;
 mod type_mismatch;
 mod type_mismatch_call_args;
 mod unresolved_types;
+mod unsigned_negation;
 
 use crate::type_checker::exhaustive_pattern_match::check_exhaustive_pattern_match;
 use crate::type_checker::type_mismatch_call_args::check_type_errors_in_function_call;
-use crate::{Expr, FunctionTypeRegistry};
+use crate::type_checker::unsigned_negation::check_unsigned_negation;
+use crate::{Expr, FunctionTypeRegistry, RibCompilationError};
 
 pub fn type_check(
     expr: &mut Expr,
     function_type_registry: &FunctionTypeRegistry,
-) -> Result<(), String> {
-    check_type_errors_in_function_call(expr, function_type_registry)
-        .map_err(|function_call_type_check_error| function_call_type_check_error.to_string())?;
-    check_unresolved_types(expr).map_err(|unresolved_error| unresolved_error.to_string())?;
-    check_exhaustive_pattern_match(expr, function_type_registry)
-        .map_err(|exhaustive_check_error| exhaustive_check_error.to_string())?;
+) -> Result<(), RibCompilationError> {
+    check_type_errors_in_function_call(expr, function_type_registry).map_err(
+        |function_call_type_check_error| {
+            RibCompilationError::new(
+                function_call_type_check_error.to_string(),
+                function_call_type_check_error.source_span(),
+            )
+        },
+    )?;
+    check_unresolved_types(expr).map_err(|unresolved_error| {
+        RibCompilationError::new(unresolved_error.to_string(), unresolved_error.source_span())
+    })?;
+    check_exhaustive_pattern_match(expr, function_type_registry)?;
+    check_unsigned_negation(expr)?;
     Ok(())
 }
 
@@ -48,7 +58,7 @@ mod type_check_tests {
 
             let metadata = internal::get_metadata_record();
 
-            let result = compile(&expr, &metadata).unwrap_err();
+            let result = compile(&expr, &metadata).unwrap_err().message;
 
             let expected = "Invalid argument in `foo`: `{x: 3, a: {aa: 1, ab: 2, ac: [1, 2], ad: {ada: 1}, ae: (1, \"foo\")}, b: \"foo\", c: [1, 2, 3], d: {da: 4}}`. Expected type: record<a: record<aa: s32, ab: s32, ac: list<s32>, ad: record<ada: s32>, ae: tuple<s32, str>>, b: u64, c: list<s32>, d: record<da: s32>>. Unable to determine the type of `3` in the record at path `x`. Number literals must have a type annotation. Example: `1u64`";
             assert_eq!(result, expected);
@@ -72,7 +82,7 @@ mod type_check_tests {
 
             let metadata = internal::get_metadata_record();
 
-            let result = compile(&expr, &metadata).unwrap_err();
+            let result = compile(&expr, &metadata).unwrap_err().message;
 
             let expected = "Invalid argument in `foo`: `{a: {aa: 1, ab: 2, ac: [1, 2], ad: {ada: 1}, ae: (1, \"foo\")}, b: \"foo\", c: [1, 2, 3], d: {da: 4}}`. Type mismatch for `b`. Expected `u64`";
             assert_eq!(result, expected);
@@ -89,7 +99,7 @@ mod type_check_tests {
 
             let metadata = internal::get_metadata_record();
 
-            let result = compile(&expr, &metadata).unwrap_err();
+            let result = compile(&expr, &metadata).unwrap_err().message;
 
             let expected = "Invalid argument in `foo`: `{a: {aa: 1, ab: 2, ac: [1, 2], ad: {ada: 1}, ae: (1, \"foo\")}, b: 2, c: [\"foo\", \"bar\"], d: {da: 4}}`. Type mismatch for `c`. Expected `list<s32>`";
             assert_eq!(result, expected);
@@ -106,7 +116,7 @@ mod type_check_tests {
 
             let metadata = internal::get_metadata_record();
 
-            let result = compile(&expr, &metadata).unwrap_err();
+            let result = compile(&expr, &metadata).unwrap_err().message;
 
             let expected = "Invalid argument in `foo`: `{a: {aa: 1, ab: 2, ac: [1, 2], ad: {ada: 1}, ae: (1, \"foo\")}, b: 2, c: [1, 2], d: {da: \"foo\"}}`. Type mismatch for `d.da`. Expected `s32`";
             assert_eq!(result, expected);
@@ -123,7 +133,7 @@ mod type_check_tests {
 
             let metadata = internal::get_metadata_record();
 
-            let result = compile(&expr, &metadata).unwrap_err();
+            let result = compile(&expr, &metadata).unwrap_err().message;
 
             let expected = "Invalid argument in `foo`: `{a: {aa: \"foo\", ab: 2, ac: [1, 2], ad: {ada: \"1\"}, ae: (1, \"foo\")}, b: 3, c: [1, 2, 3], d: {da: 4}}`. Type mismatch for `a.aa`. Expected `s32`";
             assert_eq!(result, expected);
@@ -140,7 +150,7 @@ mod type_check_tests {
 
             let metadata = internal::get_metadata_record();
 
-            let result = compile(&expr, &metadata).unwrap_err();
+            let result = compile(&expr, &metadata).unwrap_err().message;
 
             let expected = "Invalid argument in `foo`: `{a: {aa: 1, ab: 2, ac: [1, 2], ad: {ada: \"1\"}, ae: (1, \"foo\")}, b: 3, c: [1, 2, 3], d: {da: 4}}`. Type mismatch for `a.ad.ada`. Expected `s32`";
             assert_eq!(result, expected);
@@ -157,7 +167,7 @@ mod type_check_tests {
 
             let metadata = internal::get_metadata_record();
 
-            let result = compile(&expr, &metadata).unwrap_err();
+            let result = compile(&expr, &metadata).unwrap_err().message;
 
             let expected = "Invalid argument in `foo`: `{a: {aa: 1, ab: 2, ac: 1, ad: {ada: 1}, ae: (1, \"foo\")}, b: 3, c: [1, 2, 3], d: {da: 4}}`. Type mismatch for `a.ac`. Expected `list<s32>`";
             assert_eq!(result, expected);
@@ -174,13 +184,70 @@ mod type_check_tests {
 
             let metadata = internal::get_metadata_record();
 
-            let result = compile(&expr, &metadata).unwrap_err();
+            let result = compile(&expr, &metadata).unwrap_err().message;
 
             let expected = "Invalid argument in `foo`: `{a: {aa: 1, ab: 2, ac: [1, 2], ad: {ada: 1}, ae: (1, 2)}, b: 3, c: [1, 2, 3], d: {da: 4}}`. Type mismatch for `a.ae[1]`. Expected `str`";
             assert_eq!(result, expected);
         }
     }
 
+    mod unsigned_negation_error {
+        use test_r::test;
+
+        use crate::{compile, Expr};
+
+        #[test]
+        fn test_negation_of_unsigned_type() {
+            let expr = r#"
+          let x: u32 = 5;
+          -x
+        "#;
+
+            let expr = Expr::from_text(expr).unwrap();
+
+            let result = compile(&expr, &vec![]).unwrap_err().message;
+
+            let expected = "Unary minus cannot be applied to `x` of unsigned type u32";
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn test_negation_of_signed_type() {
+            let expr = r#"
+          let x: s32 = 5;
+          -x
+        "#;
+
+            let expr = Expr::from_text(expr).unwrap();
+
+            assert!(compile(&expr, &vec![]).is_ok());
+        }
+    }
+
+    mod source_span_errors {
+        use test_r::test;
+
+        use crate::type_checker::type_check_tests::internal;
+        use crate::{compile, Expr, SourcePosition};
+
+        #[test]
+        fn test_type_mismatch_error_points_to_the_argument() {
+            let expr = r#"
+          let result = foo({a: {aa: 1, ab: 2, ac: [1, 2], ad: {ada: 1}, ae: (1, "foo")}, b: "foo", c: [1, 2, 3], d: {da: 4}});
+          result
+        "#;
+
+            let expr = Expr::from_text(expr).unwrap();
+
+            let metadata = internal::get_metadata_record();
+
+            let error = compile(&expr, &metadata).unwrap_err();
+
+            assert_eq!(error.source_span.start(), SourcePosition::new(2, 28));
+            assert!(error.to_string().ends_with("(at line 2, column 28)"));
+        }
+    }
+
     mod internal {
         use golem_wasm_ast::analysis::analysed_type::{list, record, s32, str, tuple, u64};
         use golem_wasm_ast::analysis::{
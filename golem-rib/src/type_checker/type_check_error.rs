@@ -1,5 +1,5 @@
 use crate::type_checker::{Path, PathElem, PathType};
-use crate::{Expr, InferredType, TypeName};
+use crate::{Expr, InferredType, SourceSpan, TypeName};
 use golem_wasm_ast::analysis::AnalysedType;
 use std::fmt;
 use std::fmt::Display;
@@ -51,6 +51,14 @@ impl UnResolvedTypesError {
             .push_front(PathElem::Index(index));
         unresolved_error
     }
+    pub fn source_span(&self) -> SourceSpan {
+        let source_span = self.unresolved_expr.source_span();
+
+        match &self.parent_expr {
+            Some(parent_expr) if source_span.is_unknown() => parent_expr.source_span(),
+            _ => source_span,
+        }
+    }
 }
 
 impl Display for UnResolvedTypesError {
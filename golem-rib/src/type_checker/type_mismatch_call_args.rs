@@ -1,5 +1,6 @@
+use crate::call_type::CallType;
 use crate::type_checker::{Path, TypeMismatchError, UnResolvedTypesError};
-use crate::{Expr, FunctionTypeRegistry, RegistryKey, TypeName};
+use crate::{Expr, FunctionTypeRegistry, RegistryKey, SourceSpan, TypeName};
 use golem_wasm_ast::analysis::AnalysedType;
 use std::collections::VecDeque;
 use std::fmt::Display;
@@ -19,8 +20,20 @@ pub fn check_type_errors_in_function_call(
 
     while let Some(expr) = queue.pop_front() {
         match expr {
-            Expr::Call(call_type, args, ..) => {
-                internal::check_type_mismatch_in_function_call(call_type, args, type_registry)?;
+            Expr::Call(CallType::BuiltIn(built_in_function), args, _, _) => {
+                internal::check_type_mismatch_in_built_in_function_call(built_in_function, args)?;
+
+                for arg in args {
+                    queue.push_back(arg);
+                }
+            }
+            Expr::Call(call_type, args, _, source_span) => {
+                internal::check_type_mismatch_in_function_call(
+                    call_type,
+                    args,
+                    type_registry,
+                    *source_span,
+                )?;
             }
             _ => expr.visit_children_mut_bottom_up(&mut queue),
         }
@@ -32,6 +45,7 @@ pub fn check_type_errors_in_function_call(
 pub enum FunctionCallTypeError {
     InvalidFunctionCall {
         function_call_name: String,
+        source_span: SourceSpan,
     },
     TypeMisMatch {
         function_call_name: String,
@@ -49,6 +63,37 @@ pub enum FunctionCallTypeError {
         unresolved_error: UnResolvedTypesError,
         expected_type: AnalysedType,
     },
+    InvalidBuiltInFunctionArgument {
+        function_call_name: String,
+        argument: Expr,
+        expected: String,
+    },
+}
+
+impl FunctionCallTypeError {
+    pub fn source_span(&self) -> SourceSpan {
+        match self {
+            FunctionCallTypeError::InvalidFunctionCall { source_span, .. } => *source_span,
+            FunctionCallTypeError::TypeMisMatch { argument, .. } => argument.source_span(),
+            FunctionCallTypeError::MissingRecordFields { argument, .. } => argument.source_span(),
+            FunctionCallTypeError::InvalidBuiltInFunctionArgument { argument, .. } => {
+                argument.source_span()
+            }
+            FunctionCallTypeError::UnResolvedTypes {
+                argument,
+                unresolved_error,
+                ..
+            } => {
+                let source_span = unresolved_error.source_span();
+
+                if source_span.is_unknown() {
+                    argument.source_span()
+                } else {
+                    source_span
+                }
+            }
+        }
+    }
 }
 
 impl Display for FunctionCallTypeError {
@@ -56,6 +101,7 @@ impl Display for FunctionCallTypeError {
         match self {
             FunctionCallTypeError::InvalidFunctionCall {
                 function_call_name: function_name,
+                ..
             } => {
                 write!(
                     f,
@@ -110,26 +156,101 @@ impl Display for FunctionCallTypeError {
                     unresolved_error
                 )
             }
+
+            FunctionCallTypeError::InvalidBuiltInFunctionArgument {
+                function_call_name,
+                argument,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "Invalid argument in `{}`: `{}`. Expected {}",
+                    function_call_name, argument, expected
+                )
+            }
         }
     }
 }
 
 mod internal {
     use super::*;
-    use crate::call_type::CallType;
     use crate::type_checker;
+    use crate::type_refinement::precise_types::{
+        ErrType, ListType, NumberType, OkType, OptionalType, StringType,
+    };
+    use crate::type_refinement::TypeRefinement;
+    use crate::BuiltInFunction;
+
+    // Built-in functions that are generic over the type of their first argument
+    // accept only specific kinds of types (Example: `sort` accepts only lists)
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn check_type_mismatch_in_built_in_function_call(
+        built_in_function: &BuiltInFunction,
+        args: &[Expr],
+    ) -> Result<(), FunctionCallTypeError> {
+        let argument = match args.first() {
+            Some(argument) => argument,
+            None => return Ok(()),
+        };
+
+        let inferred_type = argument.inferred_type();
+
+        // Reported as unresolved types instead
+        if inferred_type.un_resolved() {
+            return Ok(());
+        }
+
+        let is_list = || ListType::refine(&inferred_type).is_some();
+        let is_string = || StringType::refine(&inferred_type).is_some();
+        let is_option = || OptionalType::refine(&inferred_type).is_some();
+        let is_result = || {
+            OkType::refine(&inferred_type).is_some() || ErrType::refine(&inferred_type).is_some()
+        };
+
+        let (valid, expected) = match built_in_function {
+            BuiltInFunction::Length | BuiltInFunction::Contains => {
+                (is_list() || is_string(), "a list or a string")
+            }
+            BuiltInFunction::Sort => (is_list(), "a list"),
+            BuiltInFunction::Flatten => (
+                ListType::refine(&inferred_type)
+                    .and_then(|list_type| OptionalType::refine(&list_type.inner_type()))
+                    .is_some(),
+                "a list of options",
+            ),
+            BuiltInFunction::IsSome | BuiltInFunction::IsNone => (is_option(), "an option"),
+            BuiltInFunction::IsOk | BuiltInFunction::IsErr => (is_result(), "a result"),
+            BuiltInFunction::UnwrapOr => (is_option() || is_result(), "an option or a result"),
+            BuiltInFunction::Min | BuiltInFunction::Max | BuiltInFunction::Abs => {
+                (NumberType::refine(&inferred_type).is_some(), "a number")
+            }
+            _ => (true, ""),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(FunctionCallTypeError::InvalidBuiltInFunctionArgument {
+                function_call_name: built_in_function.to_string(),
+                argument: argument.clone(),
+                expected: expected.to_string(),
+            })
+        }
+    }
 
     #[allow(clippy::result_large_err)]
     pub(crate) fn check_type_mismatch_in_function_call(
         call_type: &mut CallType,
         args: &mut [Expr],
         type_registry: &FunctionTypeRegistry,
+        source_span: SourceSpan,
     ) -> Result<(), FunctionCallTypeError> {
         let registry_value = type_registry
             .types
             .get(&RegistryKey::from_call_type(call_type))
             .ok_or(FunctionCallTypeError::InvalidFunctionCall {
                 function_call_name: call_type.to_string(),
+                source_span,
             })?;
 
         let expected_arg_types = registry_value.argument_types();
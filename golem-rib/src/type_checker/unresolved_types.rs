@@ -9,29 +9,29 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
 
     while let Some(expr) = queue.pop_back() {
         match expr {
-            Expr::Let(_, _, expr, _) => {
+            Expr::Let(_, _, expr, _, _) => {
                 queue.push_back(expr);
             }
-            Expr::SelectField(expr, field, inferred_type) => {
+            Expr::SelectField(expr, field, inferred_type, _) => {
                 queue.push_back(expr);
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr).at_field(field.clone()));
                 }
             }
-            Expr::SelectIndex(expr, index, inferred_type) => {
+            Expr::SelectIndex(expr, index, inferred_type, _) => {
                 queue.push_back(expr);
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr).at_index(*index));
                 }
             }
-            Expr::Sequence(exprs, inferred_type) => {
+            Expr::Sequence(exprs, inferred_type, _) => {
                 internal::unresolved_types_in_list(exprs)?;
 
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            Expr::Record(fields, inferred_type) => {
+            Expr::Record(fields, inferred_type, _) => {
                 internal::unresolved_types_in_record(
                     &fields
                         .iter()
@@ -43,83 +43,103 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            Expr::Tuple(exprs, _) => {
+            Expr::Tuple(exprs, _, _) => {
                 internal::unresolved_types_in_tuple(exprs)?;
             }
-            Expr::Literal(_, inferred_type) => {
+            Expr::Literal(_, inferred_type, _) => {
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            Expr::Number(_, _, inferred_type) => {
+            Expr::Number(_, _, inferred_type, _) => {
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr).with_additional_message(
                         "Number literals must have a type annotation. Example: `1u64`",
                     ));
                 }
             }
-            Expr::Flags(_, inferred_type) => {
+            Expr::Flags(_, inferred_type, _) => {
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            Expr::Identifier(_, inferred_type) => {
+            Expr::Identifier(_, inferred_type, _) => {
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr).with_additional_message(
                         format!("`{}` is unknown identifier", expr).as_str(),
                     ));
                 }
             }
-            Expr::Boolean(_, inferred_type) => {
+            Expr::Boolean(_, inferred_type, _) => {
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            Expr::Concat(exprs, inferred_type) => {
+            Expr::Concat(exprs, inferred_type, _) => {
                 internal::unresolved_type_for_concat(exprs)?;
 
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            Expr::ExprBlock(exprs, _) => {
+            Expr::ExprBlock(exprs, _, _) => {
                 for expr in exprs {
                     queue.push_back(expr);
                 }
             }
-            Expr::Not(expr, inferred_type) => {
+            Expr::Not(expr, inferred_type, _) => {
                 queue.push_back(expr);
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            Expr::GreaterThan(left, right, _) => {
+            Expr::Negative(expr, inferred_type, _) => {
+                queue.push_back(expr);
+                if inferred_type.un_resolved() {
+                    return Err(UnResolvedTypesError::new(expr));
+                }
+            }
+            Expr::GreaterThan(left, right, _, _) => {
                 internal::unresolved_type_for_binary_op(left, right)?;
             }
-            Expr::And(left, right, _) => internal::unresolved_type_for_binary_op(left, right)?,
-            Expr::Plus(left, right, _) => internal::unresolved_type_for_binary_op(left, right)?,
-            Expr::Minus(left, right, _) => internal::unresolved_type_for_binary_op(left, right)?,
-            Expr::Multiply(left, right, _) => internal::unresolved_type_for_binary_op(left, right)?,
-            Expr::Divide(left, right, _) => internal::unresolved_type_for_binary_op(left, right)?,
-            Expr::Or(left, right, _) => internal::unresolved_type_for_binary_op(left, right)?,
-            Expr::GreaterThanOrEqualTo(left, right, _) => {
+            Expr::And(left, right, _, _) => internal::unresolved_type_for_binary_op(left, right)?,
+            Expr::Plus(left, right, _, _) => internal::unresolved_type_for_binary_op(left, right)?,
+            Expr::Minus(left, right, _, _) => internal::unresolved_type_for_binary_op(left, right)?,
+            Expr::Multiply(left, right, _, _) => {
+                internal::unresolved_type_for_binary_op(left, right)?
+            }
+            Expr::Divide(left, right, _, _) => {
+                internal::unresolved_type_for_binary_op(left, right)?
+            }
+            Expr::Modulo(left, right, _, _) => {
+                internal::unresolved_type_for_binary_op(left, right)?
+            }
+            Expr::Or(left, right, _, _) => internal::unresolved_type_for_binary_op(left, right)?,
+            Expr::GreaterThanOrEqualTo(left, right, _, _) => {
                 internal::unresolved_type_for_binary_op(left, right)?;
             }
-            Expr::LessThanOrEqualTo(left, right, _) => {
+            Expr::LessThanOrEqualTo(left, right, _, _) => {
                 internal::unresolved_type_for_binary_op(left, right)?;
             }
-            Expr::EqualTo(left, right, _) => internal::unresolved_type_for_binary_op(left, right)?,
-            Expr::LessThan(left, right, _) => internal::unresolved_type_for_binary_op(left, right)?,
-            Expr::Cond(cond, left, right, inferred_type) => {
+            Expr::EqualTo(left, right, _, _) => {
+                internal::unresolved_type_for_binary_op(left, right)?
+            }
+            Expr::NotEqualTo(left, right, _, _) => {
+                internal::unresolved_type_for_binary_op(left, right)?
+            }
+            Expr::LessThan(left, right, _, _) => {
+                internal::unresolved_type_for_binary_op(left, right)?
+            }
+            Expr::Cond(cond, left, right, inferred_type, _) => {
                 internal::unresolved_type_for_if_condition(cond, left, right)?;
                 if inferred_type.un_resolved() {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            Expr::PatternMatch(cond, arms, _) => {
+            Expr::PatternMatch(cond, arms, _, _) => {
                 internal::unresolved_type_for_pattern_match(cond, arms)?;
             }
-            Expr::Option(option, inferred_type) => {
+            Expr::Option(option, inferred_type, _) => {
                 if let Some(expr) = option {
                     queue.push_back(expr);
                 }
@@ -128,15 +148,18 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
                     return Err(UnResolvedTypesError::new(expr));
                 }
             }
-            expr @ Expr::Result(ok_err, _) => internal::unresolved_type_for_result(ok_err, expr)?,
-            Expr::Call(_, args, _) => {
+            expr @ Expr::Result(ok_err, _, _) => {
+                internal::unresolved_type_for_result(ok_err, expr)?
+            }
+            Expr::Call(_, args, _, _) => {
                 for arg in args {
                     queue.push_back(arg);
                 }
             }
-            Expr::Unwrap(_, _) => {}
-            Expr::Throw(_, _) => {}
-            Expr::GetTag(_, _) => {}
+            Expr::Unwrap(_, _, _) => {}
+            Expr::Throw(_, _, _) => {}
+            Expr::GetTag(_, _, _) => {}
+            Expr::Lambda(_, body, _, _) => queue.push_back(body),
             Expr::ListComprehension {
                 iterable_expr,
                 yield_expr,
@@ -385,7 +408,7 @@ mod unresolved_types_tests {
         let expr = Expr::from_text("hello").unwrap();
         compile(&expr, &vec![]).unwrap_err();
         assert_eq!(
-            compile(&expr, &vec![]).unwrap_err().to_string(),
+            compile(&expr, &vec![]).unwrap_err().message,
             "Unable to determine the type of `hello`. `hello` is unknown identifier"
         );
     }
@@ -394,14 +417,14 @@ mod unresolved_types_tests {
     fn test_unresolved_type_record() {
         let expr = Expr::from_text("{a: 1, b: \"hello\"}").unwrap();
         compile(&expr, &vec![]).unwrap_err();
-        assert_eq!(compile(&expr, &vec![]).unwrap_err().to_string(), "Unable to determine the type of `1` in the record at path `a`. Number literals must have a type annotation. Example: `1u64`");
+        assert_eq!(compile(&expr, &vec![]).unwrap_err().message, "Unable to determine the type of `1` in the record at path `a`. Number literals must have a type annotation. Example: `1u64`");
     }
 
     #[test]
     fn test_unresolved_type_nested_record() {
         let expr = Expr::from_text("{foo: {a: 1, b: \"hello\"}}").unwrap();
         compile(&expr, &vec![]).unwrap_err();
-        assert_eq!(compile(&expr, &vec![]).unwrap_err().to_string(), "Unable to determine the type of `1` in the record at path `foo.a`. Number literals must have a type annotation. Example: `1u64`");
+        assert_eq!(compile(&expr, &vec![]).unwrap_err().message, "Unable to determine the type of `1` in the record at path `foo.a`. Number literals must have a type annotation. Example: `1u64`");
     }
 
     #[test]
@@ -409,7 +432,7 @@ mod unresolved_types_tests {
         let expr = Expr::from_text("{foo: {a: \"bar\", b: (\"foo\", hello)}}").unwrap();
         compile(&expr, &vec![]).unwrap_err();
         assert_eq!(
-            compile(&expr, &vec![]).unwrap_err().to_string(),
+            compile(&expr, &vec![]).unwrap_err().message,
             "Unable to determine the type of `hello` in the record at path `foo.b[1]`. `hello` is unknown identifier. Invalid element in Tuple"
         );
     }
@@ -418,7 +441,7 @@ mod unresolved_types_tests {
     fn test_unresolved_type_result_ok() {
         let expr = Expr::from_text("ok(hello)").unwrap();
         assert_eq!(
-            compile(&expr, &vec![]).unwrap_err().to_string(),
+            compile(&expr, &vec![]).unwrap_err().message,
             "Unable to determine the type of `hello` in ok(hello). `hello` is unknown identifier"
         );
     }
@@ -427,7 +450,7 @@ mod unresolved_types_tests {
     fn test_unresolved_type_result_err() {
         let expr = Expr::from_text("err(hello)").unwrap();
         assert_eq!(
-            compile(&expr, &vec![]).unwrap_err().to_string(),
+            compile(&expr, &vec![]).unwrap_err().message,
             "Unable to determine the type of `hello` in err(hello). `hello` is unknown identifier"
         );
     }
@@ -0,0 +1,40 @@
+use crate::{Expr, InferredType, RibCompilationError};
+use std::collections::VecDeque;
+
+// Negating an unsigned number can never result in a value of the same type,
+// so it is rejected at compile time rather than failing when the Rib script runs
+pub fn check_unsigned_negation(expr: &Expr) -> Result<(), RibCompilationError> {
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_back() {
+        match expr {
+            Expr::Negative(inner, inferred_type, source_span) => {
+                // Failures to unify are reported by the type unification
+                let unsigned_type = match inferred_type.unify().unwrap_or(InferredType::Unknown) {
+                    InferredType::U8 => Some("u8"),
+                    InferredType::U16 => Some("u16"),
+                    InferredType::U32 => Some("u32"),
+                    InferredType::U64 => Some("u64"),
+                    _ => None,
+                };
+
+                if let Some(unsigned_type) = unsigned_type {
+                    return Err(RibCompilationError::new(
+                        format!(
+                            "Unary minus cannot be applied to `{}` of unsigned type {}",
+                            inner, unsigned_type
+                        ),
+                        *source_span,
+                    ));
+                }
+
+                queue.push_back(inner);
+            }
+
+            expr => expr.visit_children_bottom_up(&mut queue),
+        }
+    }
+
+    Ok(())
+}
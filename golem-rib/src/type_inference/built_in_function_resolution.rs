@@ -0,0 +1,119 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::call_type::CallType;
+use crate::{Expr, FunctionTypeRegistry};
+use std::collections::VecDeque;
+
+// Initially every call type is dynamic-parsed function name, and a call to a global function
+// that is not part of the registry is resolved to a call to the built-in function of the same name
+pub fn infer_built_in_functions(expr: &mut Expr, function_type_registry: &FunctionTypeRegistry) {
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_back() {
+        if let Expr::Call(call_type, _, _, _) = expr {
+            if let Some(built_in_function) =
+                internal::get_built_in_function(call_type, function_type_registry)
+            {
+                *call_type = CallType::BuiltIn(built_in_function);
+            }
+        }
+
+        expr.visit_children_mut_bottom_up(&mut queue);
+    }
+}
+
+mod internal {
+    use crate::call_type::CallType;
+    use crate::{
+        BuiltInFunction, DynamicParsedFunctionName, DynamicParsedFunctionReference,
+        FunctionTypeRegistry, ParsedFunctionSite, RegistryKey,
+    };
+
+    pub(crate) fn get_built_in_function(
+        call_type: &CallType,
+        function_type_registry: &FunctionTypeRegistry,
+    ) -> Option<BuiltInFunction> {
+        match call_type {
+            CallType::Function(DynamicParsedFunctionName {
+                site: ParsedFunctionSite::Global,
+                function: DynamicParsedFunctionReference::Function { function },
+            }) => {
+                let registry_key = RegistryKey::from_call_type(call_type);
+
+                if function_type_registry.types.contains_key(&registry_key) {
+                    None
+                } else {
+                    BuiltInFunction::from_name(function)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod built_in_function_resolution_tests {
+    use test_r::test;
+
+    use crate::call_type::CallType;
+    use crate::{BuiltInFunction, Expr, FunctionTypeRegistry, InferredType, SourceSpan};
+    use golem_wasm_ast::analysis::analysed_type::str;
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+    };
+
+    #[test]
+    fn test_built_in_function_resolution() {
+        let mut expr = Expr::from_text(r#"to-upper(trim("foo"))"#).unwrap();
+        expr.infer_built_in_functions(&FunctionTypeRegistry::empty());
+
+        let expected = Expr::Call(
+            CallType::BuiltIn(BuiltInFunction::ToUpper),
+            vec![Expr::Call(
+                CallType::BuiltIn(BuiltInFunction::Trim),
+                vec![Expr::literal("foo")],
+                InferredType::Unknown,
+                SourceSpan::default(),
+            )],
+            InferredType::Unknown,
+            SourceSpan::default(),
+        );
+
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn test_worker_function_takes_precedence_over_built_in_function() {
+        let metadata = vec![AnalysedExport::Function(AnalysedFunction {
+            name: "trim".to_string(),
+            parameters: vec![AnalysedFunctionParameter {
+                name: "arg".to_string(),
+                typ: str(),
+            }],
+            results: vec![AnalysedFunctionResult {
+                name: None,
+                typ: str(),
+            }],
+        })];
+
+        let function_type_registry = FunctionTypeRegistry::from_export_metadata(&metadata);
+
+        let mut expr = Expr::from_text(r#"trim("foo")"#).unwrap();
+        expr.infer_built_in_functions(&function_type_registry);
+
+        assert!(matches!(expr, Expr::Call(CallType::Function(_), _, _, _)));
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::type_refinement::precise_types::{ListType, OkType, OptionalType, StringType};
+use crate::type_refinement::TypeRefinement;
+use crate::{BuiltInFunction, Expr, InferredType};
+
+// The types of the arguments of a built-in function. Some of them are fixed (Example: `str` for `to-upper`),
+// while the others depend on the types of the other arguments (Example: the second argument of `contains`
+// for a `list<T>` is `T`). `InferredType::Unknown` is used for the arguments that cannot be (yet) decided.
+pub fn get_argument_types(
+    built_in_function: &BuiltInFunction,
+    arguments: &[Expr],
+) -> Vec<InferredType> {
+    let argument_type = |index: usize| {
+        arguments
+            .get(index)
+            .map(|expr| expr.inferred_type())
+            .unwrap_or(InferredType::Unknown)
+    };
+
+    match built_in_function {
+        BuiltInFunction::ToUpper
+        | BuiltInFunction::ToLower
+        | BuiltInFunction::Trim
+        | BuiltInFunction::ParseU64
+        | BuiltInFunction::Base64Encode
+        | BuiltInFunction::Base64Decode
+        | BuiltInFunction::UrlEncode
+        | BuiltInFunction::UrlDecode => vec![InferredType::Str],
+        BuiltInFunction::Split => vec![InferredType::Str, InferredType::Str],
+        BuiltInFunction::Substring => {
+            vec![InferredType::Str, InferredType::U64, InferredType::U64]
+        }
+        BuiltInFunction::Contains => {
+            let container_type = argument_type(0);
+
+            let element_type = if StringType::refine(&container_type).is_some() {
+                InferredType::Str
+            } else {
+                ListType::refine(&container_type)
+                    .map(|list_type| list_type.inner_type())
+                    .unwrap_or(InferredType::Unknown)
+            };
+
+            vec![InferredType::Unknown, element_type]
+        }
+        BuiltInFunction::UnwrapOr => vec![InferredType::Unknown, inner_type(&argument_type(0))],
+        // Both the arguments should be of the same type
+        BuiltInFunction::Min | BuiltInFunction::Max => vec![argument_type(1), argument_type(0)],
+        BuiltInFunction::ToString
+        | BuiltInFunction::Length
+        | BuiltInFunction::Flatten
+        | BuiltInFunction::Sort
+        | BuiltInFunction::IsSome
+        | BuiltInFunction::IsNone
+        | BuiltInFunction::IsOk
+        | BuiltInFunction::IsErr
+        | BuiltInFunction::Abs => vec![InferredType::Unknown],
+    }
+}
+
+// The return type of a built-in function, which can depend on the types of the arguments
+// (Example: `sort` returns a list of the same type as its argument)
+pub fn get_return_type(built_in_function: &BuiltInFunction, arguments: &[Expr]) -> InferredType {
+    let argument_type = |index: usize| {
+        arguments
+            .get(index)
+            .map(|expr| expr.inferred_type())
+            .unwrap_or(InferredType::Unknown)
+    };
+
+    match built_in_function {
+        BuiltInFunction::ToUpper
+        | BuiltInFunction::ToLower
+        | BuiltInFunction::Trim
+        | BuiltInFunction::Substring
+        | BuiltInFunction::ToString
+        | BuiltInFunction::Base64Encode
+        | BuiltInFunction::UrlEncode => InferredType::Str,
+        BuiltInFunction::Split => InferredType::List(Box::new(InferredType::Str)),
+        BuiltInFunction::ParseU64 => InferredType::Result {
+            ok: Some(Box::new(InferredType::U64)),
+            error: Some(Box::new(InferredType::Str)),
+        },
+        BuiltInFunction::Base64Decode | BuiltInFunction::UrlDecode => InferredType::Result {
+            ok: Some(Box::new(InferredType::Str)),
+            error: Some(Box::new(InferredType::Str)),
+        },
+        BuiltInFunction::Contains
+        | BuiltInFunction::IsSome
+        | BuiltInFunction::IsNone
+        | BuiltInFunction::IsOk
+        | BuiltInFunction::IsErr => InferredType::Bool,
+        BuiltInFunction::Length => InferredType::U64,
+        // `flatten` keeps the values of a list of options that are present
+        BuiltInFunction::Flatten => ListType::refine(&argument_type(0))
+            .map(|list_type| list_type.inner_type())
+            .and_then(|element_type| OptionalType::refine(&element_type))
+            .map(|optional_type| InferredType::List(Box::new(optional_type.inner_type())))
+            .unwrap_or(InferredType::Unknown),
+        BuiltInFunction::Sort
+        | BuiltInFunction::Min
+        | BuiltInFunction::Max
+        | BuiltInFunction::Abs => argument_type(0),
+        BuiltInFunction::UnwrapOr => {
+            let inner_type = inner_type(&argument_type(0));
+
+            if inner_type.is_unknown() {
+                argument_type(1)
+            } else {
+                inner_type
+            }
+        }
+    }
+}
+
+// The type of the value in an option, or the type of the success value in a result
+fn inner_type(inferred_type: &InferredType) -> InferredType {
+    OptionalType::refine(inferred_type)
+        .map(|optional_type| optional_type.inner_type())
+        .or_else(|| OkType::refine(inferred_type).map(|ok_type| ok_type.inner_type()))
+        .unwrap_or(InferredType::Unknown)
+}
+
+#[cfg(test)]
+mod built_in_function_signature_tests {
+    use test_r::test;
+
+    use crate::type_inference::built_in_function_signature::{get_argument_types, get_return_type};
+    use crate::{BuiltInFunction, Expr, InferredType};
+
+    #[test]
+    fn test_argument_types_of_contains() {
+        let list = Expr::sequence(vec![Expr::literal("foo")]);
+        let argument_types =
+            get_argument_types(&BuiltInFunction::Contains, &[list, Expr::literal("bar")]);
+
+        assert_eq!(
+            argument_types,
+            vec![InferredType::Unknown, InferredType::Str]
+        );
+    }
+
+    #[test]
+    fn test_return_type_of_flatten() {
+        let list = Expr::sequence(vec![Expr::option(Some(Expr::literal("foo")))]);
+        let return_type = get_return_type(&BuiltInFunction::Flatten, &[list]);
+
+        assert_eq!(return_type, InferredType::List(Box::new(InferredType::Str)));
+    }
+
+    #[test]
+    fn test_return_type_of_unwrap_or() {
+        let option = Expr::option(Some(Expr::literal("foo")));
+        let return_type =
+            get_return_type(&BuiltInFunction::UnwrapOr, &[option, Expr::literal("bar")]);
+
+        assert_eq!(return_type, InferredType::Str);
+    }
+}
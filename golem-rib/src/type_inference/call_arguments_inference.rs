@@ -24,13 +24,18 @@ pub fn infer_call_arguments_type(
     queue.push_back(expr);
     while let Some(expr) = queue.pop_back() {
         match expr {
-            Expr::Call(parsed_fn_name, args, inferred_type) => {
+            Expr::Call(parsed_fn_name, args, inferred_type, _) => {
                 internal::resolve_call_argument_types(
                     parsed_fn_name,
                     function_type_registry,
                     args,
                     inferred_type,
                 )?;
+
+                // The arguments can be function calls as well (Example: `to-upper(foo(x))`)
+                for arg in args {
+                    queue.push_back(arg);
+                }
             }
             _ => expr.visit_children_mut_bottom_up(&mut queue),
         }
@@ -41,10 +46,11 @@ pub fn infer_call_arguments_type(
 
 mod internal {
     use crate::call_type::CallType;
+    use crate::type_inference::built_in_function_signature;
     use crate::type_inference::kind::GetTypeKind;
     use crate::{
-        DynamicParsedFunctionName, Expr, FunctionTypeRegistry, InferredType, RegistryKey,
-        RegistryValue,
+        BuiltInFunction, DynamicParsedFunctionName, Expr, FunctionTypeRegistry, InferredType,
+        RegistryKey, RegistryValue,
     };
     use golem_wasm_ast::analysis::AnalysedType;
     use std::fmt::Display;
@@ -103,7 +109,41 @@ mod internal {
                 )
                 .map_err(|e| e.to_string())
             }
+
+            CallType::BuiltIn(built_in_function) => {
+                infer_built_in_function_call(built_in_function, args, function_result_inferred_type)
+                    .map_err(|e| e.to_string())
+            }
+        }
+    }
+
+    // The argument types of a built-in function that are known at this point are tagged
+    // to the arguments, and the rest is inferred as part of pushing down and pulling up the types
+    fn infer_built_in_function_call(
+        built_in_function: &BuiltInFunction,
+        args: &mut [Expr],
+        function_result_inferred_type: &mut InferredType,
+    ) -> Result<(), FunctionArgsTypeInferenceError> {
+        if built_in_function.arity() != args.len() {
+            return Err(FunctionArgsTypeInferenceError::ArgumentSizeMisMatch {
+                function_type_internal: FunctionDetails::Fqn(built_in_function.to_string()),
+                expected: built_in_function.arity(),
+                provided: args.len(),
+            });
         }
+
+        let argument_types =
+            built_in_function_signature::get_argument_types(built_in_function, args);
+
+        for (arg, argument_type) in args.iter_mut().zip(argument_types) {
+            arg.add_infer_type_mut(argument_type);
+        }
+
+        *function_result_inferred_type = function_result_inferred_type.merge(
+            built_in_function_signature::get_return_type(built_in_function, args),
+        );
+
+        Ok(())
     }
 
     // An internal error type for all possibilities of errors
@@ -478,7 +518,7 @@ mod function_parameters_inference_tests {
     use crate::call_type::CallType;
     use crate::function_name::{DynamicParsedFunctionName, DynamicParsedFunctionReference};
     use crate::type_registry::FunctionTypeRegistry;
-    use crate::{Expr, InferredType, ParsedFunctionSite, VariableId};
+    use crate::{Expr, InferredType, ParsedFunctionSite, SourceSpan, VariableId};
     use golem_wasm_ast::analysis::{
         AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedType, TypeU32, TypeU64,
     };
@@ -530,11 +570,17 @@ mod function_parameters_inference_tests {
             vec![Expr::Identifier(
                 VariableId::global("x".to_string()),
                 InferredType::U64, // Call argument's types are updated
+                SourceSpan::default(),
             )],
             InferredType::Sequence(vec![]), // Call Expressions return type is updated
+            SourceSpan::default(),
         );
 
-        let expected = Expr::ExprBlock(vec![let_binding, call_expr], InferredType::Unknown);
+        let expected = Expr::ExprBlock(
+            vec![let_binding, call_expr],
+            InferredType::Unknown,
+            SourceSpan::default(),
+        );
 
         assert_eq!(expr, expected);
     }
@@ -0,0 +1,1016 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Expr, RibCompilationError};
+use std::collections::HashMap;
+
+// User-defined functions are either named functions (`fn add(x, y) { x + y }`), or lambdas
+// bound to a variable (`let add = |x, y| x + y`). Both are inlined at their call sites
+// before any other phase of type inference, i.e, the body of the function replaces the call,
+// with the arguments bound to the parameters using let bindings.
+//
+// Example:
+//
+// ```rib
+//   fn add(x: u64, y: u64) { x + y }
+//   add(1, 2)
+// ```
+//
+// becomes
+//
+// ```rib
+//   let add.1.x: u64 = 1;
+//   let add.2.y: u64 = 2;
+//   add.1.x + add.2.y
+// ```
+//
+// This implies the types of the parameters and the return type are inferred per call site,
+// and nothing changes in the compilation to byte code. A function is visible only after its definition,
+// which disallows recursion. Variables in the body that are neither parameters nor local bindings
+// are captured from the definition site, and calling the function where one of them is shadowed
+// (Example: by a variable of a list comprehension) is an error. The size of a function, once the functions
+// it calls are inlined, is bounded, as a chain of functions calling the previous one twice grows exponentially.
+//
+// A function can also be passed to a higher-order function of the standard library, which is desugared
+// into a list comprehension or a pattern match before the function is inlined. Example: `map-some(x, |v| v + 1)`
+// becomes `match x { some(v) => some(inlined body of the lambda), none => none }`.
+pub fn inline_functions(expr: &mut Expr) -> Result<(), RibCompilationError> {
+    let source_span = expr.source_span();
+    let mut variable_index = 0;
+
+    // A program with a single expression is a block of one expression,
+    // and a program with only function definitions ends up being an empty block
+    let mut exprs = vec![std::mem::replace(expr, Expr::expr_block(vec![]))];
+    internal::inline_functions_in_block(&mut exprs, &HashMap::new(), &mut variable_index)?;
+
+    *expr = if exprs.len() == 1 {
+        exprs.remove(0)
+    } else {
+        Expr::expr_block(exprs).with_source_span(source_span)
+    };
+
+    Ok(())
+}
+
+mod internal {
+    use crate::call_type::CallType;
+    use crate::{
+        ArmPattern, BuiltInFunction, DynamicParsedFunctionName, DynamicParsedFunctionReference,
+        Expr, HigherOrderFunction, InferredType, LambdaParameter, MatchArm, ParsedFunctionSite,
+        RibCompilationError, SourceSpan, VariableId,
+    };
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    // The maximum number of expressions in the body of a function, once the functions it calls are inlined
+    const MAX_FUNCTION_SIZE: usize = 10_000;
+
+    #[derive(Clone)]
+    pub(crate) struct Function {
+        parameters: Vec<LambdaParameter>,
+        body: Expr,
+        // The free variables of the body, and the first of them that got shadowed since the definition
+        captured_variables: HashSet<String>,
+        shadowed_variable: Option<String>,
+    }
+
+    pub(crate) type Functions = HashMap<String, Function>;
+
+    // Functions defined within a block are visible only to the rest of the block
+    pub(crate) fn inline_functions_in_block(
+        exprs: &mut Vec<Expr>,
+        functions: &Functions,
+        variable_index: &mut usize,
+    ) -> Result<(), RibCompilationError> {
+        let mut functions = functions.clone();
+        let mut inlined_exprs = vec![];
+
+        for mut expr in std::mem::take(exprs) {
+            if let Expr::Let(variable_id, _, value, _, _) = &expr {
+                if let Expr::Lambda(parameters, body, _, source_span) = value.as_ref() {
+                    let name = variable_id.name();
+                    let function = define_function(
+                        &name,
+                        parameters,
+                        body,
+                        *source_span,
+                        &functions,
+                        variable_index,
+                    )?;
+                    functions.insert(name, function);
+                    continue;
+                }
+            }
+
+            inline_functions(&mut expr, &functions, variable_index)?;
+
+            if let Expr::Let(variable_id, _, _, _, _) = &expr {
+                bind_variables(&mut functions, &[variable_id.name()]);
+            }
+
+            inlined_exprs.push(expr);
+        }
+
+        *exprs = inlined_exprs;
+        Ok(())
+    }
+
+    fn define_function(
+        name: &str,
+        parameters: &[LambdaParameter],
+        body: &Expr,
+        source_span: SourceSpan,
+        functions: &Functions,
+        variable_index: &mut usize,
+    ) -> Result<Function, RibCompilationError> {
+        if calls_function(body, name) {
+            return Err(RibCompilationError::new(
+                format!("Recursive function `{}` is not supported", name),
+                source_span,
+            ));
+        }
+
+        let parameter_names = parameters
+            .iter()
+            .map(|parameter| parameter.name.clone())
+            .collect::<Vec<_>>();
+        let functions = with_bound_variables(functions, &parameter_names);
+
+        let mut body = body.clone();
+        inline_functions(&mut body, &functions, variable_index)?;
+
+        if size(&body) > MAX_FUNCTION_SIZE {
+            return Err(RibCompilationError::new(
+                format!(
+                    "Function `{}` exceeds the maximum size of {} expressions once inlined",
+                    name, MAX_FUNCTION_SIZE
+                ),
+                source_span,
+            ));
+        }
+
+        let mut captured_variables = HashSet::new();
+        free_variables(
+            &body,
+            &parameter_names.into_iter().collect(),
+            &mut captured_variables,
+        );
+
+        Ok(Function {
+            parameters: parameters.to_vec(),
+            body,
+            captured_variables,
+            shadowed_variable: None,
+        })
+    }
+
+    pub(crate) fn inline_functions(
+        expr: &mut Expr,
+        functions: &Functions,
+        variable_index: &mut usize,
+    ) -> Result<(), RibCompilationError> {
+        match expr {
+            Expr::ExprBlock(exprs, _, _) => {
+                inline_functions_in_block(exprs, functions, variable_index)
+            }
+
+            Expr::Lambda(_, _, _, source_span) => Err(RibCompilationError::new(
+                "A lambda can only be bound to a variable using `let`, or passed to a higher-order function",
+                *source_span,
+            )),
+
+            Expr::Identifier(variable_id, _, source_span)
+                if functions.contains_key(&variable_id.name()) =>
+            {
+                Err(RibCompilationError::new(
+                    format!(
+                        "Function `{}` can only be called, or passed to a higher-order function",
+                        variable_id.name()
+                    ),
+                    *source_span,
+                ))
+            }
+
+            Expr::ListComprehension {
+                iterated_variable,
+                iterable_expr,
+                yield_expr,
+                ..
+            } => {
+                inline_functions(iterable_expr, functions, variable_index)?;
+                let functions = with_bound_variables(functions, &[iterated_variable.name()]);
+                inline_functions(yield_expr, &functions, variable_index)
+            }
+
+            Expr::ListReduce {
+                reduce_variable,
+                iterated_variable,
+                iterable_expr,
+                init_value_expr,
+                yield_expr,
+                ..
+            } => {
+                inline_functions(iterable_expr, functions, variable_index)?;
+                inline_functions(init_value_expr, functions, variable_index)?;
+                let functions = with_bound_variables(
+                    functions,
+                    &[reduce_variable.name(), iterated_variable.name()],
+                );
+                inline_functions(yield_expr, &functions, variable_index)
+            }
+
+            Expr::PatternMatch(predicate, match_arms, _, _) => {
+                inline_functions(predicate, functions, variable_index)?;
+                for match_arm in match_arms {
+                    let functions = with_bound_variables(
+                        functions,
+                        &arm_pattern_bindings(&match_arm.arm_pattern),
+                    );
+                    inline_functions(
+                        &mut match_arm.arm_resolution_expr,
+                        &functions,
+                        variable_index,
+                    )?;
+                }
+                Ok(())
+            }
+
+            Expr::Call(_, _, _, _) => {
+                if let Some(desugared) = desugar_higher_order_call(expr, functions, variable_index)? {
+                    *expr = desugared;
+                    return Ok(());
+                }
+
+                let mut queue = VecDeque::new();
+                expr.visit_children_mut_bottom_up(&mut queue);
+                for expr in queue {
+                    inline_functions(expr, functions, variable_index)?;
+                }
+
+                if let Expr::Call(call_type, arguments, _, source_span) = expr {
+                    if let Some((name, function)) =
+                        function_name(call_type).and_then(|name| functions.get_key_value(name))
+                    {
+                        let inlined = inline_call(
+                            name,
+                            function,
+                            std::mem::take(arguments),
+                            *source_span,
+                            variable_index,
+                        )?;
+                        *expr = inlined;
+                    }
+                }
+
+                Ok(())
+            }
+
+            _ => {
+                let mut queue = VecDeque::new();
+                expr.visit_children_mut_bottom_up(&mut queue);
+                for expr in queue {
+                    inline_functions(expr, functions, variable_index)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    // A call to a higher-order function is recognised by its function argument, as neither a worker function
+    // nor a built-in function can take a function. A user-defined function of the same name takes precedence.
+    fn desugar_higher_order_call(
+        expr: &Expr,
+        functions: &Functions,
+        variable_index: &mut usize,
+    ) -> Result<Option<Expr>, RibCompilationError> {
+        let (call_type, arguments, source_span) = match expr {
+            Expr::Call(call_type, arguments, _, source_span) => {
+                (call_type, arguments, *source_span)
+            }
+            _ => return Ok(None),
+        };
+
+        let higher_order_function = match function_name(call_type)
+            .filter(|name| !functions.contains_key(*name))
+            .and_then(|name| HigherOrderFunction::from_name(name))
+        {
+            Some(higher_order_function) => higher_order_function,
+            None => return Ok(None),
+        };
+
+        let is_function = |argument: &Expr| match argument {
+            Expr::Lambda(_, _, _, _) => true,
+            Expr::Identifier(variable_id, _, _) => functions.contains_key(&variable_id.name()),
+            _ => false,
+        };
+
+        if !arguments.iter().any(is_function) {
+            return Ok(None);
+        }
+
+        let (target, (name, function)) = match arguments.as_slice() {
+            [target, function] if !is_function(target) && is_function(function) => (
+                target,
+                get_function(higher_order_function, function, functions, variable_index)?,
+            ),
+            _ => {
+                return Err(RibCompilationError::new(
+                    format!(
+                        "`{}` expects a value and a function as its arguments",
+                        higher_order_function
+                    ),
+                    source_span,
+                ))
+            }
+        };
+
+        if function.parameters.len() != 1 {
+            return Err(RibCompilationError::new(
+                format!(
+                    "The function passed to `{}` should take exactly one parameter",
+                    higher_order_function
+                ),
+                source_span,
+            ));
+        }
+
+        let mut target = target.clone();
+        inline_functions(&mut target, functions, variable_index)?;
+
+        let value = unique_variable(higher_order_function.name(), "value", variable_index);
+
+        let mut call = |argument: Expr| {
+            inline_call(
+                &name,
+                &function,
+                vec![argument],
+                source_span,
+                variable_index,
+            )
+        };
+
+        let desugared = match higher_order_function {
+            HigherOrderFunction::Map | HigherOrderFunction::Filter => {
+                let iterated_variable = VariableId::list_comprehension_identifier(&value);
+                let identifier = Expr::Identifier(
+                    iterated_variable.clone(),
+                    InferredType::Unknown,
+                    source_span,
+                );
+
+                if higher_order_function == HigherOrderFunction::Map {
+                    Expr::list_comprehension(iterated_variable, target, call(identifier)?)
+                } else {
+                    let yield_expr = Expr::cond(
+                        call(identifier.clone())?,
+                        Expr::option(Some(identifier)),
+                        Expr::option(None),
+                    );
+
+                    Expr::Call(
+                        CallType::BuiltIn(BuiltInFunction::Flatten),
+                        vec![Expr::list_comprehension(
+                            iterated_variable,
+                            target,
+                            yield_expr,
+                        )],
+                        InferredType::Unknown,
+                        source_span,
+                    )
+                }
+            }
+
+            HigherOrderFunction::MapSome => Expr::pattern_match(
+                target,
+                vec![
+                    MatchArm::new(
+                        constructor_pattern("some", &value),
+                        Expr::option(Some(call(Expr::identifier(&value))?)),
+                    ),
+                    MatchArm::new(ArmPattern::constructor("none", vec![]), Expr::option(None)),
+                ],
+            ),
+
+            HigherOrderFunction::MapOk => Expr::pattern_match(
+                target,
+                vec![
+                    MatchArm::new(
+                        constructor_pattern("ok", &value),
+                        Expr::ok(call(Expr::identifier(&value))?),
+                    ),
+                    MatchArm::new(
+                        constructor_pattern("err", &value),
+                        Expr::err(Expr::identifier(&value)),
+                    ),
+                ],
+            ),
+
+            HigherOrderFunction::MapErr => Expr::pattern_match(
+                target,
+                vec![
+                    MatchArm::new(
+                        constructor_pattern("ok", &value),
+                        Expr::ok(Expr::identifier(&value)),
+                    ),
+                    MatchArm::new(
+                        constructor_pattern("err", &value),
+                        Expr::err(call(Expr::identifier(&value))?),
+                    ),
+                ],
+            ),
+        };
+
+        Ok(Some(desugared.with_source_span(source_span)))
+    }
+
+    // The name and the definition of a function passed to a higher-order function. A lambda is given
+    // a name that cannot be called, such that calls in its body are never mistaken for recursion
+    fn get_function(
+        higher_order_function: HigherOrderFunction,
+        argument: &Expr,
+        functions: &Functions,
+        variable_index: &mut usize,
+    ) -> Result<(String, Function), RibCompilationError> {
+        match argument {
+            Expr::Lambda(parameters, body, _, source_span) => {
+                let name = format!("{}.lambda", higher_order_function);
+                let function = define_function(
+                    &name,
+                    parameters,
+                    body,
+                    *source_span,
+                    functions,
+                    variable_index,
+                )?;
+                Ok((name, function))
+            }
+            Expr::Identifier(variable_id, _, _) if functions.contains_key(&variable_id.name()) => {
+                let name = variable_id.name();
+                let function = functions[&name].clone();
+                Ok((name, function))
+            }
+            _ => Err(RibCompilationError::new(
+                format!(
+                    "`{}` expects a function as its last argument",
+                    higher_order_function
+                ),
+                argument.source_span(),
+            )),
+        }
+    }
+
+    fn constructor_pattern(name: &str, variable: &str) -> ArmPattern {
+        ArmPattern::constructor(name, vec![ArmPattern::identifier(variable)])
+    }
+
+    fn inline_call(
+        name: &str,
+        function: &Function,
+        arguments: Vec<Expr>,
+        source_span: SourceSpan,
+        variable_index: &mut usize,
+    ) -> Result<Expr, RibCompilationError> {
+        if let Some(variable) = &function.shadowed_variable {
+            return Err(RibCompilationError::new(
+                format!(
+                    "Function `{}` captures the variable `{}`, which is shadowed at the call site",
+                    name, variable
+                ),
+                source_span,
+            ));
+        }
+
+        if arguments.len() != function.parameters.len() {
+            return Err(RibCompilationError::new(
+                format!(
+                    "Function `{}` expects {} arguments, but {} were provided",
+                    name,
+                    function.parameters.len(),
+                    arguments.len()
+                ),
+                source_span,
+            ));
+        }
+
+        let mut variables = HashMap::new();
+        let mut exprs = vec![];
+
+        for (parameter, argument) in function.parameters.iter().zip(arguments) {
+            let variable = unique_variable(name, &parameter.name, variable_index);
+            variables.insert(parameter.name.clone(), variable.clone());
+
+            exprs.push(Expr::Let(
+                VariableId::global(variable),
+                parameter.type_name.clone(),
+                Box::new(argument),
+                InferredType::Unknown,
+                source_span,
+            ));
+        }
+
+        let mut body = function.body.clone();
+        rename_variables(&mut body, name, &mut variables, variable_index);
+        exprs.push(body);
+
+        Ok(Expr::expr_block(exprs).with_source_span(source_span))
+    }
+
+    // The parameters and the local variables of every inlined body are given
+    // unique names, such that they never clash with the variables at the call site
+    fn rename_variables(
+        expr: &mut Expr,
+        function_name: &str,
+        variables: &mut HashMap<String, String>,
+        variable_index: &mut usize,
+    ) {
+        match expr {
+            Expr::Let(variable_id, _, value, _, _) => {
+                rename_variables(value, function_name, variables, variable_index);
+                let name = variable_id.name();
+                let variable = unique_variable(function_name, &name, variable_index);
+                *variable_id = VariableId::global(variable.clone());
+                variables.insert(name, variable);
+            }
+
+            Expr::Identifier(variable_id, _, _) => {
+                if let Some(variable) = variables.get(&variable_id.name()) {
+                    *variable_id = VariableId::global(variable.clone());
+                }
+            }
+
+            Expr::ListComprehension {
+                iterated_variable,
+                iterable_expr,
+                yield_expr,
+                ..
+            } => {
+                rename_variables(iterable_expr, function_name, variables, variable_index);
+                let mut variables = without(variables, &[iterated_variable.name()]);
+                rename_variables(yield_expr, function_name, &mut variables, variable_index);
+            }
+
+            Expr::ListReduce {
+                reduce_variable,
+                iterated_variable,
+                iterable_expr,
+                init_value_expr,
+                yield_expr,
+                ..
+            } => {
+                rename_variables(iterable_expr, function_name, variables, variable_index);
+                rename_variables(init_value_expr, function_name, variables, variable_index);
+                let mut variables = without(
+                    variables,
+                    &[reduce_variable.name(), iterated_variable.name()],
+                );
+                rename_variables(yield_expr, function_name, &mut variables, variable_index);
+            }
+
+            Expr::PatternMatch(predicate, match_arms, _, _) => {
+                rename_variables(predicate, function_name, variables, variable_index);
+                for match_arm in match_arms {
+                    let mut variables =
+                        without(variables, &arm_pattern_bindings(&match_arm.arm_pattern));
+                    rename_variables(
+                        &mut match_arm.arm_resolution_expr,
+                        function_name,
+                        &mut variables,
+                        variable_index,
+                    );
+                }
+            }
+
+            _ => {
+                let mut queue = VecDeque::new();
+                expr.visit_children_mut_bottom_up(&mut queue);
+                for expr in queue {
+                    rename_variables(expr, function_name, variables, variable_index);
+                }
+            }
+        }
+    }
+
+    fn unique_variable(function_name: &str, variable: &str, variable_index: &mut usize) -> String {
+        *variable_index += 1;
+        format!("{}.{}.{}", function_name, variable_index, variable)
+    }
+
+    fn function_name(call_type: &CallType) -> Option<&String> {
+        match call_type {
+            CallType::Function(DynamicParsedFunctionName {
+                site: ParsedFunctionSite::Global,
+                function: DynamicParsedFunctionReference::Function { function },
+            }) => Some(function),
+            _ => None,
+        }
+    }
+
+    fn calls_function(expr: &Expr, name: &str) -> bool {
+        let mut queue = VecDeque::new();
+        queue.push_back(expr);
+
+        while let Some(expr) = queue.pop_back() {
+            if let Expr::Call(call_type, _, _, _) = expr {
+                if function_name(call_type).is_some_and(|function| function == name) {
+                    return true;
+                }
+            }
+
+            expr.visit_children_bottom_up(&mut queue);
+        }
+
+        false
+    }
+
+    // A variable hides the function of the same name, and shadows the variable captured by the other functions
+    fn bind_variables(functions: &mut Functions, names: &[String]) {
+        for name in names {
+            functions.remove(name);
+
+            for function in functions.values_mut() {
+                if function.shadowed_variable.is_none()
+                    && function.captured_variables.contains(name)
+                {
+                    function.shadowed_variable = Some(name.clone());
+                }
+            }
+        }
+    }
+
+    fn with_bound_variables(functions: &Functions, names: &[String]) -> Functions {
+        let mut functions = functions.clone();
+        bind_variables(&mut functions, names);
+        functions
+    }
+
+    fn free_variables(expr: &Expr, bound: &HashSet<String>, free: &mut HashSet<String>) {
+        match expr {
+            Expr::ExprBlock(exprs, _, _) => {
+                let mut bound = bound.clone();
+                for expr in exprs {
+                    free_variables(expr, &bound, free);
+                    if let Expr::Let(variable_id, _, _, _, _) = expr {
+                        bound.insert(variable_id.name());
+                    }
+                }
+            }
+
+            Expr::Identifier(variable_id, _, _) => {
+                if !bound.contains(&variable_id.name()) {
+                    free.insert(variable_id.name());
+                }
+            }
+
+            Expr::ListComprehension {
+                iterated_variable,
+                iterable_expr,
+                yield_expr,
+                ..
+            } => {
+                free_variables(iterable_expr, bound, free);
+                let mut bound = bound.clone();
+                bound.insert(iterated_variable.name());
+                free_variables(yield_expr, &bound, free);
+            }
+
+            Expr::ListReduce {
+                reduce_variable,
+                iterated_variable,
+                iterable_expr,
+                init_value_expr,
+                yield_expr,
+                ..
+            } => {
+                free_variables(iterable_expr, bound, free);
+                free_variables(init_value_expr, bound, free);
+                let mut bound = bound.clone();
+                bound.insert(reduce_variable.name());
+                bound.insert(iterated_variable.name());
+                free_variables(yield_expr, &bound, free);
+            }
+
+            Expr::PatternMatch(predicate, match_arms, _, _) => {
+                free_variables(predicate, bound, free);
+                for match_arm in match_arms {
+                    let mut bound = bound.clone();
+                    bound.extend(arm_pattern_bindings(&match_arm.arm_pattern));
+                    free_variables(&match_arm.arm_resolution_expr, &bound, free);
+                }
+            }
+
+            _ => {
+                let mut queue = VecDeque::new();
+                expr.visit_children_bottom_up(&mut queue);
+                for expr in queue {
+                    free_variables(expr, bound, free);
+                }
+            }
+        }
+    }
+
+    fn size(expr: &Expr) -> usize {
+        let mut size = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back(expr);
+
+        while let Some(expr) = queue.pop_back() {
+            size += 1;
+            expr.visit_children_bottom_up(&mut queue);
+        }
+
+        size
+    }
+
+    // The variables bound by an arm pattern, which hide any variable or function of the same name
+    fn arm_pattern_bindings(arm_pattern: &ArmPattern) -> Vec<String> {
+        match arm_pattern {
+            ArmPattern::WildCard => vec![],
+            ArmPattern::As(name, arm_pattern) => {
+                let mut bindings = vec![name.clone()];
+                bindings.extend(arm_pattern_bindings(arm_pattern));
+                bindings
+            }
+            ArmPattern::Constructor(_, arm_patterns)
+            | ArmPattern::TupleConstructor(arm_patterns)
+            | ArmPattern::ListConstructor(arm_patterns) => {
+                arm_patterns.iter().flat_map(arm_pattern_bindings).collect()
+            }
+            ArmPattern::RecordConstructor(fields) => fields
+                .iter()
+                .flat_map(|(_, arm_pattern)| arm_pattern_bindings(arm_pattern))
+                .collect(),
+            ArmPattern::Literal(expr) => {
+                let mut bindings = vec![];
+                let mut queue = VecDeque::new();
+                queue.push_back(expr.as_ref());
+
+                while let Some(expr) = queue.pop_back() {
+                    if let Expr::Identifier(variable_id, _, _) = expr {
+                        bindings.push(variable_id.name());
+                    }
+
+                    expr.visit_children_bottom_up(&mut queue);
+                }
+
+                bindings
+            }
+        }
+    }
+
+    fn without<V: Clone>(map: &HashMap<String, V>, names: &[String]) -> HashMap<String, V> {
+        let mut map = map.clone();
+        for name in names {
+            map.remove(name);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod function_inlining_tests {
+    use test_r::test;
+
+    use crate::parser::type_name::TypeName;
+    use crate::{ArmPattern, Expr, MatchArm};
+
+    #[test]
+    fn test_inline_named_function() {
+        let rib_expr = r#"
+          fn add(x: u64, y: u64) { x + y };
+          add(1, 2)
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        expr.inline_functions().unwrap();
+
+        let expected = Expr::expr_block(vec![Expr::expr_block(vec![
+            Expr::let_binding_with_type("add.1.x", TypeName::U64, Expr::untyped_number(1f64)),
+            Expr::let_binding_with_type("add.2.y", TypeName::U64, Expr::untyped_number(2f64)),
+            Expr::plus(Expr::identifier("add.1.x"), Expr::identifier("add.2.y")),
+        ])]);
+
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn test_inline_lambda_does_not_capture_variables_at_call_site() {
+        let rib_expr = r#"
+          let x = 1;
+          let inc = |x| { let y = x + 1; y };
+          let y = inc(x);
+          y
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        expr.inline_functions().unwrap();
+
+        let expected = Expr::expr_block(vec![Expr::expr_block(vec![
+            Expr::let_binding("x", Expr::untyped_number(1f64)),
+            Expr::let_binding(
+                "y",
+                Expr::expr_block(vec![
+                    Expr::let_binding("inc.1.x", Expr::identifier("x")),
+                    Expr::expr_block(vec![
+                        Expr::let_binding(
+                            "inc.2.y",
+                            Expr::plus(Expr::identifier("inc.1.x"), Expr::untyped_number(1f64)),
+                        ),
+                        Expr::identifier("inc.2.y"),
+                    ]),
+                ]),
+            ),
+            Expr::identifier("y"),
+        ])]);
+
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn test_inline_lambda_captures_variables_at_definition() {
+        let rib_expr = r#"
+          let y = 1;
+          let add_y = |x| x + y;
+          add_y(2)
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        expr.inline_functions().unwrap();
+
+        let expected = Expr::expr_block(vec![
+            Expr::let_binding("y", Expr::untyped_number(1f64)),
+            Expr::expr_block(vec![
+                Expr::let_binding("add_y.1.x", Expr::untyped_number(2f64)),
+                Expr::plus(Expr::identifier("add_y.1.x"), Expr::identifier("y")),
+            ]),
+        ]);
+
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn test_captured_variable_shadowed_at_call_site() {
+        let rib_expr = r#"
+          let y = 1;
+          let add_y = |x| x + y;
+          let ys = [1, 2, 3];
+          for y in ys {
+            yield add_y(0);
+          }
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        let error = expr.inline_functions().unwrap_err();
+
+        assert_eq!(
+            error.message,
+            "Function `add_y` captures the variable `y`, which is shadowed at the call site"
+        );
+    }
+
+    #[test]
+    fn test_function_exceeding_the_maximum_size() {
+        let mut rib_expr = "fn f0(x) { x + 1 };".to_string();
+        for index in 1..20 {
+            rib_expr.push_str(&format!(
+                "fn f{}(x) {{ f{}(x) + f{}(x) }};",
+                index,
+                index - 1,
+                index - 1
+            ));
+        }
+        rib_expr.push_str("f19(1)");
+
+        let mut expr = Expr::from_text(&rib_expr).unwrap();
+        let error = expr.inline_functions().unwrap_err();
+
+        assert!(error
+            .message
+            .ends_with("exceeds the maximum size of 10000 expressions once inlined"));
+    }
+
+    #[test]
+    fn test_recursive_function_is_not_supported() {
+        let rib_expr = r#"
+          fn loop(x) { loop(x) };
+          loop(1)
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        let error = expr.inline_functions().unwrap_err();
+
+        assert_eq!(error.message, "Recursive function `loop` is not supported");
+    }
+
+    #[test]
+    fn test_function_called_with_wrong_number_of_arguments() {
+        let rib_expr = r#"
+          let add = |x, y| x + y;
+          add(1)
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        let error = expr.inline_functions().unwrap_err();
+
+        assert_eq!(
+            error.message,
+            "Function `add` expects 2 arguments, but 1 were provided"
+        );
+    }
+
+    #[test]
+    fn test_function_used_as_a_value() {
+        let rib_expr = r#"
+          let inc = |x| x + 1;
+          [inc]
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        let error = expr.inline_functions().unwrap_err();
+
+        assert_eq!(
+            error.message,
+            "Function `inc` can only be called, or passed to a higher-order function"
+        );
+    }
+
+    #[test]
+    fn test_lambda_that_is_not_bound_to_a_variable() {
+        let rib_expr = r#"
+          let x = [|x| x + 1];
+          x
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        let error = expr.inline_functions().unwrap_err();
+
+        assert_eq!(
+            error.message,
+            "A lambda can only be bound to a variable using `let`, or passed to a higher-order function"
+        );
+    }
+
+    #[test]
+    fn test_inline_lambda_passed_to_map_some() {
+        let rib_expr = r#"
+          let x = some(1);
+          map-some(x, |v| v + 1)
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        expr.inline_functions().unwrap();
+
+        let expected = Expr::expr_block(vec![
+            Expr::let_binding("x", Expr::option(Some(Expr::untyped_number(1f64)))),
+            Expr::pattern_match(
+                Expr::identifier("x"),
+                vec![
+                    MatchArm::new(
+                        ArmPattern::constructor(
+                            "some",
+                            vec![ArmPattern::identifier("map-some.1.value")],
+                        ),
+                        Expr::option(Some(Expr::expr_block(vec![
+                            Expr::let_binding(
+                                "map-some.lambda.2.v",
+                                Expr::identifier("map-some.1.value"),
+                            ),
+                            Expr::plus(
+                                Expr::identifier("map-some.lambda.2.v"),
+                                Expr::untyped_number(1f64),
+                            ),
+                        ]))),
+                    ),
+                    MatchArm::new(ArmPattern::constructor("none", vec![]), Expr::option(None)),
+                ],
+            ),
+        ]);
+
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn test_higher_order_function_with_wrong_arguments() {
+        let rib_expr = r#"
+          let inc = |x| x + 1;
+          map(inc, [1, 2])
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+        let error = expr.inline_functions().unwrap_err();
+
+        assert_eq!(
+            error.message,
+            "`map` expects a value and a function as its arguments"
+        );
+    }
+}
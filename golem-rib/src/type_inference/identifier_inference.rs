@@ -37,14 +37,14 @@ mod internal {
 
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::Identifier(variable_id, existing_type) => {
+                Expr::Identifier(variable_id, existing_type, _) => {
                     if let Some(new_inferred_type) = identifier_lookup.lookup(variable_id) {
                         *existing_type = existing_type.merge(new_inferred_type)
                     }
 
                     identifier_lookup.update(variable_id.clone(), existing_type.clone());
                 }
-                Expr::Let(variable_id, _, expr, _) => {
+                Expr::Let(variable_id, _, expr, _, _) => {
                     if let Some(inferred_type) = identifier_lookup.lookup(variable_id) {
                         expr.add_infer_type_mut(inferred_type);
                     }
@@ -65,7 +65,7 @@ mod internal {
 
         while let Some(expr) = queue.pop_front() {
             match expr {
-                Expr::Let(variable_id, _, expr, _) => {
+                Expr::Let(variable_id, _, expr, _, _) => {
                     if let Some(inferred_type) = identifier_lookup.lookup(variable_id) {
                         expr.add_infer_type_mut(inferred_type);
                     }
@@ -73,7 +73,7 @@ mod internal {
                     identifier_lookup.update(variable_id.clone(), expr.inferred_type());
                     queue.push_front(expr)
                 }
-                Expr::Identifier(variable_id, existing_type) => {
+                Expr::Identifier(variable_id, existing_type, _) => {
                     if let Some(new_inferred_type) = identifier_lookup.lookup(variable_id) {
                         *existing_type = existing_type.merge(new_inferred_type)
                     }
@@ -94,7 +94,7 @@ mod internal {
 
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::PatternMatch(_, arms, _) => {
+                Expr::PatternMatch(_, arms, _, _) => {
                     for arm in arms {
                         process_arm(arm)
                     }
@@ -169,7 +169,7 @@ mod internal {
 
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::Identifier(variable_id, inferred_type) => {
+                Expr::Identifier(variable_id, inferred_type, _) => {
                     if !inferred_type.is_unknown() {
                         state.update(variable_id.clone(), inferred_type.clone())
                     }
@@ -189,7 +189,9 @@ mod internal {
 
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::Identifier(variable_id, inferred_type) if variable_id.is_match_binding() => {
+                Expr::Identifier(variable_id, inferred_type, _)
+                    if variable_id.is_match_binding() =>
+                {
                     if let Some(new_inferred_type) = state.lookup(variable_id) {
                         *inferred_type = inferred_type.merge(new_inferred_type)
                     }
@@ -201,7 +201,7 @@ mod tests {
     use crate::type_inference::inference_fix_point::internal::{
         equivalent_exprs, equivalent_types, non_equivalent_types,
     };
-    use crate::{Expr, FunctionTypeRegistry, InferredType, Number, VariableId};
+    use crate::{Expr, FunctionTypeRegistry, InferredType, Number, SourceSpan, VariableId};
 
     #[test]
     fn test_inferred_type_equality_1() {
@@ -398,33 +398,42 @@ mod tests {
                         Number { value: 1f64 },
                         None,
                         InferredType::U64,
+                        SourceSpan::default(),
                     )),
                     InferredType::Unknown,
+                    SourceSpan::default(),
                 ),
                 Expr::Cond(
                     Box::new(Expr::EqualTo(
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     )),
                     Box::new(Expr::Identifier(
                         VariableId::local("x", 0),
                         InferredType::U64,
+                        SourceSpan::default(),
                     )),
                     Box::new(Expr::Identifier(
                         VariableId::global("y".to_string()),
                         InferredType::U64,
+                        SourceSpan::default(),
                     )),
                     InferredType::U64,
+                    SourceSpan::default(),
                 ),
             ],
             InferredType::U64,
+            SourceSpan::default(),
         );
 
         assert_eq!(expr, expected)
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use crate::call_type::CallType;
-use crate::{DynamicParsedFunctionName, Expr, FunctionTypeRegistry, RegistryKey};
+use crate::{
+    DynamicParsedFunctionName, Expr, FunctionTypeRegistry, RegistryKey, RibCompilationError,
+};
 use std::collections::{HashSet, VecDeque};
 
 #[derive(Debug, Clone)]
@@ -23,11 +25,11 @@ impl InferredExpr {
     pub fn from_expr(
         expr: &Expr,
         function_type_registry: &FunctionTypeRegistry,
-    ) -> Result<InferredExpr, String> {
+    ) -> Result<InferredExpr, RibCompilationError> {
         let mut mutable_expr = expr.clone();
         mutable_expr
             .infer_types(function_type_registry)
-            .map_err(|err| err.join("\n"))?;
+            .map_err(RibCompilationError::from_errors)?;
         Ok(InferredExpr(mutable_expr))
     }
 
@@ -39,7 +41,7 @@ impl InferredExpr {
         queue.push_back(&self.0);
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::Call(CallType::Function(function_name), _, _) => {
+                Expr::Call(CallType::Function(function_name), _, _, _) => {
                     worker_calls.push(function_name.clone())
                 }
                 _ => expr.visit_children_bottom_up(&mut queue),
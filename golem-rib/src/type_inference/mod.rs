@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use built_in_function_resolution::*;
 pub use call_arguments_inference::*;
 pub use enum_resolution::*;
 pub use expr_visitor::*;
+pub use function_inlining::*;
 pub use global_input_inference::*;
 pub use identifier_inference::*;
 pub use inference_fix_point::*;
 pub use inferred_expr::*;
 pub use rib_input_type::*;
+pub use rib_output_type::*;
 pub(crate) use type_binding::*;
 pub use type_pull_up::*;
 pub use type_push_down::*;
@@ -31,10 +34,14 @@ pub use variable_binding_list_reduce::*;
 pub use variable_binding_pattern_match::*;
 pub use variant_resolution::*;
 
+mod built_in_function_resolution;
+pub(crate) mod built_in_function_signature;
 mod call_arguments_inference;
 mod expr_visitor;
+mod function_inlining;
 mod identifier_inference;
 mod rib_input_type;
+mod rib_output_type;
 mod type_pull_up;
 mod type_push_down;
 mod type_reset;
@@ -61,6 +68,7 @@ mod type_inference_tests {
         use crate::call_type::CallType;
         use crate::function_name::{DynamicParsedFunctionName, DynamicParsedFunctionReference};
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, ParsedFunctionSite, VariableId};
 
         #[test]
@@ -83,8 +91,10 @@ mod type_inference_tests {
                     Number { value: 1f64 },
                     None,
                     InferredType::U64,
+                    SourceSpan::default(),
                 )), // The number in let expression is identified to be a U64
                 InferredType::Unknown, // Type of a let expression can be unit, we are not updating this part
+                SourceSpan::default(),
             );
 
             let call_expr = Expr::Call(
@@ -97,12 +107,17 @@ mod type_inference_tests {
                 vec![Expr::Identifier(
                     VariableId::local("x", 0),
                     InferredType::U64, // Variable identified to be a U64
+                    SourceSpan::default(),
                 )],
                 InferredType::Sequence(vec![]),
+                SourceSpan::default(),
             );
 
-            let expected =
-                Expr::ExprBlock(vec![let_binding, call_expr], InferredType::Sequence(vec![]));
+            let expected = Expr::ExprBlock(
+                vec![let_binding, call_expr],
+                InferredType::Sequence(vec![]),
+                SourceSpan::default(),
+            );
 
             assert_eq!(expr, expected);
         }
@@ -129,8 +144,10 @@ mod type_inference_tests {
                     Number { value: 1f64 },
                     None,
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             );
 
             let let_binding2 = Expr::Let(
@@ -140,8 +157,10 @@ mod type_inference_tests {
                     Number { value: 2f64 },
                     None,
                     InferredType::U32,
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             );
 
             let call_expr1 = Expr::Call(
@@ -154,8 +173,10 @@ mod type_inference_tests {
                 vec![Expr::Identifier(
                     VariableId::local("x", 0),
                     InferredType::U64,
+                    SourceSpan::default(),
                 )],
                 InferredType::Sequence(vec![]),
+                SourceSpan::default(),
             );
 
             let call_expr2 = Expr::Call(
@@ -168,13 +189,16 @@ mod type_inference_tests {
                 vec![Expr::Identifier(
                     VariableId::local("y", 0),
                     InferredType::U32,
+                    SourceSpan::default(),
                 )],
                 InferredType::Sequence(vec![]),
+                SourceSpan::default(),
             );
 
             let expected = Expr::ExprBlock(
                 vec![let_binding1, let_binding2, call_expr1, call_expr2],
                 InferredType::Sequence(vec![]),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -185,6 +209,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -208,12 +233,19 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
+                    ),
+                    Expr::Identifier(
+                        VariableId::local("x", 0),
+                        InferredType::U64,
+                        SourceSpan::default(),
                     ),
-                    Expr::Identifier(VariableId::local("x", 0), InferredType::U64),
                 ],
                 InferredType::U64,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -238,10 +270,16 @@ mod type_inference_tests {
                         None,
                         Box::new(Expr::literal("1")),
                         InferredType::Unknown,
+                        SourceSpan::default(),
+                    ),
+                    Expr::Identifier(
+                        VariableId::local("x", 0),
+                        InferredType::Str,
+                        SourceSpan::default(),
                     ),
-                    Expr::Identifier(VariableId::local("x", 0), InferredType::Str),
                 ],
                 InferredType::Str,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -252,6 +290,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -279,8 +318,10 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
@@ -289,66 +330,84 @@ mod type_inference_tests {
                             Number { value: 2f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::GreaterThan(
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         Box::new(Expr::Identifier(
                             VariableId::local("y", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
                     Expr::GreaterThanOrEqualTo(
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         Box::new(Expr::Identifier(
                             VariableId::local("y", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
                     Expr::LessThan(
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         Box::new(Expr::Identifier(
                             VariableId::local("y", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
                     Expr::LessThanOrEqualTo(
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         Box::new(Expr::Identifier(
                             VariableId::local("y", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
                     Expr::EqualTo(
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         Box::new(Expr::Identifier(
                             VariableId::local("y", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Bool,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Bool,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -503,6 +562,7 @@ mod type_inference_tests {
         use test_r::test;
 
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, VariableId};
 
         #[test]
@@ -524,22 +584,34 @@ mod type_inference_tests {
                         None,
                         Box::new(Expr::literal("1")),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
                         None,
                         Box::new(Expr::literal("2")),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Concat(
                         vec![
-                            Expr::Identifier(VariableId::local("x", 0), InferredType::Str),
-                            Expr::Identifier(VariableId::local("y", 0), InferredType::Str),
+                            Expr::Identifier(
+                                VariableId::local("x", 0),
+                                InferredType::Str,
+                                SourceSpan::default(),
+                            ),
+                            Expr::Identifier(
+                                VariableId::local("y", 0),
+                                InferredType::Str,
+                                SourceSpan::default(),
+                            ),
                         ],
                         InferredType::Str,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Str,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -549,6 +621,7 @@ mod type_inference_tests {
         use test_r::test;
 
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, VariableId};
 
         #[test]
@@ -570,10 +643,16 @@ mod type_inference_tests {
                         None,
                         Box::new(Expr::boolean(true)),
                         InferredType::Unknown,
+                        SourceSpan::default(),
+                    ),
+                    Expr::Identifier(
+                        VariableId::local("x", 0),
+                        InferredType::Bool,
+                        SourceSpan::default(),
                     ),
-                    Expr::Identifier(VariableId::local("x", 0), InferredType::Bool),
                 ],
                 InferredType::Bool,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -584,6 +663,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -609,8 +689,10 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
@@ -619,45 +701,56 @@ mod type_inference_tests {
                             Number { value: 2f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("res1", 0),
                         None,
                         Box::new(Expr::literal("foo")),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("res2", 0),
                         None,
                         Box::new(Expr::literal("bar")),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Cond(
                         Box::new(Expr::GreaterThan(
                             Box::new(Expr::Identifier(
                                 VariableId::local("x", 0),
                                 InferredType::U64,
+                                SourceSpan::default(),
                             )),
                             Box::new(Expr::Identifier(
                                 VariableId::local("y", 0),
                                 InferredType::U64,
+                                SourceSpan::default(),
                             )),
                             InferredType::Bool,
+                            SourceSpan::default(),
                         )),
                         Box::new(Expr::Identifier(
                             VariableId::local("res1", 0),
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                         Box::new(Expr::Identifier(
                             VariableId::local("res2", 0),
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                         InferredType::Str,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Str,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -668,6 +761,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -690,6 +784,7 @@ mod type_inference_tests {
                         None,
                         Box::new(Expr::literal("1")),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
@@ -697,12 +792,19 @@ mod type_inference_tests {
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
+                    ),
+                    Expr::Identifier(
+                        VariableId::local("y", 0),
+                        InferredType::Str,
+                        SourceSpan::default(),
                     ),
-                    Expr::Identifier(VariableId::local("y", 0), InferredType::Str),
                 ],
                 InferredType::Str,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -731,8 +833,10 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
@@ -740,8 +844,10 @@ mod type_inference_tests {
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("z", 0),
@@ -749,12 +855,19 @@ mod type_inference_tests {
                         Box::new(Expr::Identifier(
                             VariableId::local("y", 0),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
+                    ),
+                    Expr::Identifier(
+                        VariableId::local("z", 0),
+                        InferredType::U64,
+                        SourceSpan::default(),
                     ),
-                    Expr::Identifier(VariableId::local("z", 0), InferredType::U64),
                 ],
                 InferredType::U64,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -765,6 +878,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -786,20 +900,39 @@ mod type_inference_tests {
                         Some(TypeName::List(Box::new(TypeName::U64))),
                         Box::new(Expr::Sequence(
                             vec![
-                                Expr::Number(Number { value: 1f64 }, None, InferredType::U64),
-                                Expr::Number(Number { value: 2f64 }, None, InferredType::U64),
-                                Expr::Number(Number { value: 3f64 }, None, InferredType::U64),
+                                Expr::Number(
+                                    Number { value: 1f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
+                                Expr::Number(
+                                    Number { value: 2f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
+                                Expr::Number(
+                                    Number { value: 3f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
                             ],
                             InferredType::List(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Identifier(
                         VariableId::local("x", 0),
                         InferredType::List(Box::new(InferredType::U64)),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::List(Box::new(InferredType::U64)),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -810,6 +943,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -831,24 +965,44 @@ mod type_inference_tests {
                         Some(TypeName::List(Box::new(TypeName::U64))),
                         Box::new(Expr::Sequence(
                             vec![
-                                Expr::Number(Number { value: 1f64 }, None, InferredType::U64),
-                                Expr::Number(Number { value: 2f64 }, None, InferredType::U64),
-                                Expr::Number(Number { value: 3f64 }, None, InferredType::U64),
+                                Expr::Number(
+                                    Number { value: 1f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
+                                Expr::Number(
+                                    Number { value: 2f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
+                                Expr::Number(
+                                    Number { value: 3f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
                             ],
                             InferredType::List(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::SelectIndex(
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::List(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         0,
                         InferredType::U64,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::U64,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -859,6 +1013,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -883,8 +1038,10 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("x", 0),
@@ -895,22 +1052,28 @@ mod type_inference_tests {
                                 Box::new(Expr::Identifier(
                                     VariableId::local("n", 0),
                                     InferredType::U64,
+                                    SourceSpan::default(),
                                 )),
                             )],
                             InferredType::Record(vec![("foo".to_string(), InferredType::U64)]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::SelectField(
                         Box::new(Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::Record(vec![("foo".to_string(), InferredType::U64)]),
+                            SourceSpan::default(),
                         )),
                         "foo".to_string(),
                         InferredType::U64,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::U64,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -921,6 +1084,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -942,19 +1106,28 @@ mod type_inference_tests {
                         Some(TypeName::Tuple(vec![TypeName::U64, TypeName::Str])),
                         Box::new(Expr::Tuple(
                             vec![
-                                Expr::Number(Number { value: 1f64 }, None, InferredType::U64),
+                                Expr::Number(
+                                    Number { value: 1f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
                                 Expr::literal("2"),
                             ],
                             InferredType::Tuple(vec![InferredType::U64, InferredType::Str]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Identifier(
                         VariableId::local("x", 0),
                         InferredType::Tuple(vec![InferredType::U64, InferredType::Str]),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Tuple(vec![InferredType::U64, InferredType::Str]),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -964,6 +1137,7 @@ mod type_inference_tests {
         use test_r::test;
 
         use crate::parser::type_name::TypeName;
+        use crate::SourceSpan;
         use crate::{
             ArmPattern, Expr, FunctionTypeRegistry, InferredType, MatchArm, Number, VariableId,
         };
@@ -993,8 +1167,10 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("z", 0),
@@ -1003,15 +1179,19 @@ mod type_inference_tests {
                             Some(Box::new(Expr::Identifier(
                                 VariableId::local("y", 0),
                                 InferredType::U64,
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::PatternMatch(
                         Box::new(Expr::Identifier(
                             VariableId::local("z", 0),
                             InferredType::Option(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         vec![
                             MatchArm::new(
@@ -1020,9 +1200,14 @@ mod type_inference_tests {
                                     vec![ArmPattern::literal(Expr::Identifier(
                                         VariableId::match_identifier("z".to_string(), 1),
                                         InferredType::U64,
+                                        SourceSpan::default(),
                                     ))],
                                 ),
-                                Expr::Identifier(VariableId::local("y", 0), InferredType::U64),
+                                Expr::Identifier(
+                                    VariableId::local("y", 0),
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
                             ),
                             MatchArm::new(
                                 ArmPattern::constructor("none", vec![]),
@@ -1030,13 +1215,16 @@ mod type_inference_tests {
                                     Number { value: 0f64 },
                                     Some(TypeName::U64),
                                     InferredType::U64,
+                                    SourceSpan::default(),
                                 ),
                             ),
                         ],
                         InferredType::U64,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::U64,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected)
@@ -1049,6 +1237,7 @@ mod type_inference_tests {
         use crate::function_name::{DynamicParsedFunctionName, DynamicParsedFunctionReference};
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{
             ArmPattern, Expr, FunctionTypeRegistry, InferredType, MatchArm, Number,
             ParsedFunctionSite, VariableId,
@@ -1076,8 +1265,10 @@ mod type_inference_tests {
                     Number { value: 1f64 },
                     None,
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             );
 
             let let_binding2 = Expr::Let(
@@ -1087,14 +1278,17 @@ mod type_inference_tests {
                     Number { value: 2f64 },
                     None,
                     InferredType::U32,
+                    SourceSpan::default(),
                 )),
                 InferredType::Unknown,
+                SourceSpan::default(),
             );
 
             let match_expr_expected = Expr::PatternMatch(
                 Box::new(Expr::Identifier(
                     VariableId::local("x", 0),
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
                 vec![
                     MatchArm::new(
@@ -1102,6 +1296,7 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         ))),
                         Expr::Call(
                             CallType::Function(DynamicParsedFunctionName {
@@ -1113,8 +1308,10 @@ mod type_inference_tests {
                             vec![Expr::Identifier(
                                 VariableId::local("x", 0),
                                 InferredType::U64,
+                                SourceSpan::default(),
                             )],
                             InferredType::Sequence(vec![]),
+                            SourceSpan::default(),
                         ),
                     ),
                     MatchArm::new(
@@ -1122,6 +1319,7 @@ mod type_inference_tests {
                             Number { value: 2f64 },
                             None,
                             InferredType::U64, // because predicate is u64
+                            SourceSpan::default(),
                         ))),
                         Expr::Call(
                             CallType::Function(DynamicParsedFunctionName {
@@ -1133,17 +1331,21 @@ mod type_inference_tests {
                             vec![Expr::Identifier(
                                 VariableId::local("y", 0),
                                 InferredType::U32,
+                                SourceSpan::default(),
                             )],
                             InferredType::Sequence(vec![]),
+                            SourceSpan::default(),
                         ),
                     ),
                 ],
                 InferredType::Sequence(vec![]),
+                SourceSpan::default(),
             );
 
             let expected = Expr::ExprBlock(
                 vec![let_binding1, let_binding2, match_expr_expected],
                 InferredType::Sequence(vec![]),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -1192,8 +1394,10 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
@@ -1202,16 +1406,20 @@ mod type_inference_tests {
                             Number { value: 2f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::PatternMatch(
                         Box::new(Expr::Option(
                             Some(Box::new(Expr::Identifier(
                                 VariableId::local("x", 0),
                                 InferredType::U64,
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         vec![
                             MatchArm::new(
@@ -1220,6 +1428,7 @@ mod type_inference_tests {
                                     vec![ArmPattern::Literal(Box::new(Expr::Identifier(
                                         VariableId::match_identifier("x".to_string(), 1),
                                         InferredType::U64,
+                                        SourceSpan::default(),
                                     )))],
                                 ),
                                 Expr::Option(
@@ -1227,12 +1436,15 @@ mod type_inference_tests {
                                         Some(Box::new(Expr::Identifier(
                                             VariableId::match_identifier("x".to_string(), 1),
                                             InferredType::U64,
+                                            SourceSpan::default(),
                                         ))),
                                         InferredType::Option(Box::new(InferredType::U64)),
+                                        SourceSpan::default(),
                                     ))),
                                     InferredType::Option(Box::new(InferredType::Option(Box::new(
                                         InferredType::U64,
                                     )))),
+                                    SourceSpan::default(),
                                 ),
                             ),
                             MatchArm::new(
@@ -1242,21 +1454,26 @@ mod type_inference_tests {
                                         Some(Box::new(Expr::Identifier(
                                             VariableId::local("y", 0),
                                             InferredType::U64,
+                                            SourceSpan::default(),
                                         ))),
                                         InferredType::Option(Box::new(InferredType::U64)),
+                                        SourceSpan::default(),
                                     ))),
                                     InferredType::Option(Box::new(InferredType::Option(Box::new(
                                         InferredType::U64,
                                     )))),
+                                    SourceSpan::default(),
                                 ),
                             ),
                         ],
                         InferredType::Option(Box::new(InferredType::Option(Box::new(
                             InferredType::U64,
                         )))),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Option(Box::new(InferredType::Option(Box::new(InferredType::U64)))),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected)
@@ -1284,19 +1501,23 @@ mod type_inference_tests {
                         Box::new(Expr::Record(
                             vec![("foo".to_string(), Box::new(Expr::literal("bar")))],
                             InferredType::Record(vec![("foo".to_string(), InferredType::Str)]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::PatternMatch(
                         Box::new(Expr::Option(
                             Some(Box::new(Expr::Identifier(
                                 VariableId::local("x", 0),
                                 InferredType::Record(vec![("foo".to_string(), InferredType::Str)]),
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::Record(vec![(
                                 "foo".to_string(),
                                 InferredType::Str,
                             )]))),
+                            SourceSpan::default(),
                         )),
                         vec![
                             MatchArm::new(
@@ -1308,6 +1529,7 @@ mod type_inference_tests {
                                             "foo".to_string(),
                                             InferredType::Str,
                                         )]),
+                                        SourceSpan::default(),
                                     )))],
                                 ),
                                 Expr::Identifier(
@@ -1316,6 +1538,7 @@ mod type_inference_tests {
                                         "foo".to_string(),
                                         InferredType::Str,
                                     )]),
+                                    SourceSpan::default(),
                                 ),
                             ),
                             MatchArm::new(
@@ -1326,13 +1549,16 @@ mod type_inference_tests {
                                         "foo".to_string(),
                                         InferredType::Str,
                                     )]),
+                                    SourceSpan::default(),
                                 ),
                             ),
                         ],
                         InferredType::Record(vec![("foo".to_string(), InferredType::Str)]),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Record(vec![("foo".to_string(), InferredType::Str)]),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected)
@@ -1360,19 +1586,23 @@ mod type_inference_tests {
                         Box::new(Expr::Record(
                             vec![("foo".to_string(), Box::new(Expr::literal("bar")))],
                             InferredType::Record(vec![("foo".to_string(), InferredType::Str)]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::PatternMatch(
                         Box::new(Expr::Option(
                             Some(Box::new(Expr::Identifier(
                                 VariableId::local("x", 0),
                                 InferredType::Record(vec![("foo".to_string(), InferredType::Str)]),
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::Record(vec![(
                                 "foo".to_string(),
                                 InferredType::Str,
                             )]))),
+                            SourceSpan::default(),
                         )),
                         vec![
                             MatchArm::new(
@@ -1384,6 +1614,7 @@ mod type_inference_tests {
                                             "foo".to_string(),
                                             InferredType::Str,
                                         )]),
+                                        SourceSpan::default(),
                                     ))],
                                 ),
                                 Expr::SelectField(
@@ -1393,9 +1624,11 @@ mod type_inference_tests {
                                             "foo".to_string(),
                                             InferredType::Str,
                                         )]),
+                                        SourceSpan::default(),
                                     )),
                                     "foo".to_string(),
                                     InferredType::Str,
+                                    SourceSpan::default(),
                                 ),
                             ),
                             MatchArm::new(
@@ -1404,9 +1637,11 @@ mod type_inference_tests {
                             ),
                         ],
                         InferredType::Str,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Str,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected)
@@ -1441,32 +1676,53 @@ mod type_inference_tests {
                         Box::new(Expr::Record(
                             vec![("foo".to_string(), Box::new(Expr::literal("bar")))],
                             InferredType::Record(vec![("foo".to_string(), InferredType::Str)]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
                         Some(TypeName::List(Box::new(TypeName::U64))),
                         Box::new(Expr::Sequence(
                             vec![
-                                Expr::Number(Number { value: 1f64 }, None, InferredType::U64),
-                                Expr::Number(Number { value: 2f64 }, None, InferredType::U64),
-                                Expr::Number(Number { value: 3f64 }, None, InferredType::U64),
+                                Expr::Number(
+                                    Number { value: 1f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
+                                Expr::Number(
+                                    Number { value: 2f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
+                                Expr::Number(
+                                    Number { value: 3f64 },
+                                    None,
+                                    InferredType::U64,
+                                    SourceSpan::default(),
+                                ),
                             ],
                             InferredType::List(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::PatternMatch(
                         Box::new(Expr::Option(
                             Some(Box::new(Expr::Identifier(
                                 VariableId::local("x", 0),
                                 InferredType::Record(vec![("foo".to_string(), InferredType::Str)]),
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::Record(vec![(
                                 "foo".to_string(),
                                 InferredType::Str,
                             )]))),
+                            SourceSpan::default(),
                         )),
                         vec![
                             MatchArm::new(
@@ -1478,6 +1734,7 @@ mod type_inference_tests {
                                             "foo".to_string(),
                                             InferredType::Str,
                                         )]),
+                                        SourceSpan::default(),
                                     )))],
                                 ),
                                 Expr::SelectField(
@@ -1487,9 +1744,11 @@ mod type_inference_tests {
                                             "foo".to_string(),
                                             InferredType::Str,
                                         )]),
+                                        SourceSpan::default(),
                                     )),
                                     "foo".to_string(),
                                     InferredType::Str,
+                                    SourceSpan::default(),
                                 ),
                             ),
                             MatchArm::new(
@@ -1498,16 +1757,19 @@ mod type_inference_tests {
                             ),
                         ],
                         InferredType::Str,
+                        SourceSpan::default(),
                     ),
                     Expr::PatternMatch(
                         Box::new(Expr::Option(
                             Some(Box::new(Expr::Identifier(
                                 VariableId::local("y", 0),
                                 InferredType::List(Box::new(InferredType::U64)),
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::List(Box::new(
                                 InferredType::U64,
                             )))),
+                            SourceSpan::default(),
                         )),
                         vec![
                             MatchArm::new(
@@ -1516,15 +1778,18 @@ mod type_inference_tests {
                                     vec![ArmPattern::Literal(Box::new(Expr::Identifier(
                                         VariableId::match_identifier("y".to_string(), 3),
                                         InferredType::List(Box::new(InferredType::U64)),
+                                        SourceSpan::default(),
                                     )))],
                                 ),
                                 Expr::SelectIndex(
                                     Box::new(Expr::Identifier(
                                         VariableId::match_identifier("y".to_string(), 3),
                                         InferredType::List(Box::new(InferredType::U64)),
+                                        SourceSpan::default(),
                                     )),
                                     0,
                                     InferredType::U64,
+                                    SourceSpan::default(),
                                 ),
                             ),
                             MatchArm::new(
@@ -1533,13 +1798,16 @@ mod type_inference_tests {
                                     Number { value: 0f64 },
                                     Some(TypeName::U64),
                                     InferredType::U64,
+                                    SourceSpan::default(),
                                 ),
                             ),
                         ],
                         InferredType::U64,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::U64,
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected)
@@ -1550,6 +1818,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, Number, VariableId};
 
         #[test]
@@ -1574,17 +1843,22 @@ mod type_inference_tests {
                                 Number { value: 1f64 },
                                 None,
                                 InferredType::U64,
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Identifier(
                         VariableId::local("x", 0),
                         InferredType::Option(Box::new(InferredType::U64)),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Option(Box::new(InferredType::U64)),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -1613,10 +1887,13 @@ mod type_inference_tests {
                                 Number { value: 1f64 },
                                 None,
                                 InferredType::U64,
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
@@ -1625,21 +1902,26 @@ mod type_inference_tests {
                             Some(Box::new(Expr::Identifier(
                                 VariableId::local("x", 0),
                                 InferredType::Option(Box::new(InferredType::U64)),
+                                SourceSpan::default(),
                             ))),
                             InferredType::Option(Box::new(InferredType::Option(Box::new(
                                 InferredType::U64,
                             )))),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Identifier(
                         VariableId::local("y", 0),
                         InferredType::Option(Box::new(InferredType::Option(Box::new(
                             InferredType::U64,
                         )))),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Option(Box::new(InferredType::Option(Box::new(InferredType::U64)))),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -1650,6 +1932,7 @@ mod type_inference_tests {
 
         use crate::parser::type_name::TypeName;
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, FunctionTypeRegistry, InferredType, Number, VariableId};
         use golem_wasm_ast::analysis::analysed_type::{list, option, str};
         use golem_wasm_ast::analysis::AnalysedType;
@@ -1676,8 +1959,10 @@ mod type_inference_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("x", 0),
@@ -1688,18 +1973,23 @@ mod type_inference_tests {
                                 Box::new(Expr::Identifier(
                                     VariableId::local("number", 0),
                                     InferredType::U64,
+                                    SourceSpan::default(),
                                 )),
                             )],
                             InferredType::Record(vec![("foo".to_string(), InferredType::U64)]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Identifier(
                         VariableId::local("x", 0),
                         InferredType::Record(vec![("foo".to_string(), InferredType::U64)]),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Record(vec![("foo".to_string(), InferredType::U64)]),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -1731,15 +2021,19 @@ mod type_inference_tests {
                                 Number { value: 1f64 },
                                 Some(TypeName::U64),
                                 InferredType::U64,
+                                SourceSpan::default(),
                             )),
                             Box::new(Expr::Number(
                                 Number { value: 20f64 },
                                 Some(TypeName::U64),
                                 InferredType::U64,
+                                SourceSpan::default(),
                             )),
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
@@ -1755,14 +2049,17 @@ mod type_inference_tests {
                                             Box::new(Expr::Identifier(
                                                 VariableId::local("x", 0),
                                                 InferredType::U64,
+                                                SourceSpan::default(),
                                             )),
                                         )],
                                         InferredType::Record(vec![(
                                             "x".to_string(),
                                             InferredType::U64,
                                         )]),
+                                        SourceSpan::default(),
                                     )),
                                     InferredType::Unknown,
+                                    SourceSpan::default(),
                                 ),
                                 Expr::Identifier(
                                     VariableId::local("z", 0),
@@ -1770,18 +2067,23 @@ mod type_inference_tests {
                                         "x".to_string(),
                                         InferredType::U64,
                                     )]),
+                                    SourceSpan::default(),
                                 ),
                             ],
                             InferredType::Record(vec![("x".to_string(), InferredType::U64)]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Identifier(
                         VariableId::local("y", 0),
                         InferredType::Record(vec![("x".to_string(), InferredType::U64)]),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Record(vec![("x".to_string(), InferredType::U64)]),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -1834,6 +2136,7 @@ mod type_inference_tests {
     }
 
     mod list_aggregation_tests {
+        use crate::SourceSpan;
         use crate::{Expr, FunctionTypeRegistry, InferredExpr, InferredType, TypeName, VariableId};
         use test_r::test;
 
@@ -1864,8 +2167,10 @@ mod type_inference_tests {
                                 Expr::number(3f64, InferredType::U64),
                             ],
                             InferredType::List(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::typed_list_reduce(
                         VariableId::list_reduce_identifier("z"),
@@ -1873,6 +2178,7 @@ mod type_inference_tests {
                         Expr::Identifier(
                             VariableId::local("ages", 0),
                             InferredType::List(Box::new(InferredType::U64)),
+                            SourceSpan::default(),
                         ),
                         Expr::number_with_type_name(0f64, TypeName::U64, InferredType::U64),
                         Expr::ExprBlock(
@@ -1884,23 +2190,33 @@ mod type_inference_tests {
                                         Box::new(Expr::Identifier(
                                             VariableId::list_reduce_identifier("z"),
                                             InferredType::U64,
+                                            SourceSpan::default(),
                                         )),
                                         Box::new(Expr::Identifier(
                                             VariableId::list_comprehension_identifier("a"),
                                             InferredType::U64,
+                                            SourceSpan::default(),
                                         )),
                                         InferredType::U64,
+                                        SourceSpan::default(),
                                     )),
                                     InferredType::Unknown,
+                                    SourceSpan::default(),
+                                ),
+                                Expr::Identifier(
+                                    VariableId::local("result", 0),
+                                    InferredType::U64,
+                                    SourceSpan::default(),
                                 ),
-                                Expr::Identifier(VariableId::local("result", 0), InferredType::U64),
                             ],
                             InferredType::U64,
+                            SourceSpan::default(),
                         ),
                         InferredType::U64,
                     ),
                 ],
                 InferredType::U64,
+                SourceSpan::default(),
             );
 
             assert_eq!(Expr::from(inferred_expr), expected);
@@ -1908,6 +2224,7 @@ mod type_inference_tests {
     }
 
     mod list_comprehension_tests {
+        use crate::SourceSpan;
         use crate::{Expr, FunctionTypeRegistry, InferredExpr, InferredType, VariableId};
         use test_r::test;
 
@@ -1935,26 +2252,32 @@ mod type_inference_tests {
                         Box::new(Expr::Sequence(
                             vec![Expr::literal("foo"), Expr::literal("bar")],
                             InferredType::List(Box::new(InferredType::Str)),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::typed_list_comprehension(
                         VariableId::list_comprehension_identifier("i"),
                         Expr::Identifier(
                             VariableId::local("x", 0),
                             InferredType::List(Box::new(InferredType::Str)),
+                            SourceSpan::default(),
                         ),
                         Expr::ExprBlock(
                             vec![Expr::Identifier(
                                 VariableId::list_comprehension_identifier("i"),
                                 InferredType::Str,
+                                SourceSpan::default(),
                             )],
                             InferredType::Str,
+                            SourceSpan::default(),
                         ),
                         InferredType::List(Box::new(InferredType::Str)),
                     ),
                 ],
                 InferredType::List(Box::new(InferredType::Str)),
+                SourceSpan::default(),
             );
 
             assert_eq!(Expr::from(inferred_expr), expected);
@@ -1965,6 +2288,7 @@ mod type_inference_tests {
         use test_r::test;
 
         use crate::type_inference::type_inference_tests::internal;
+        use crate::SourceSpan;
         use crate::{Expr, InferredType, VariableId};
 
         #[test]
@@ -1990,8 +2314,10 @@ mod type_inference_tests {
                                 ok: Some(Box::new(InferredType::Unknown)),
                                 error: Some(Box::new(InferredType::Str)),
                             },
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("q", 0),
@@ -2002,8 +2328,10 @@ mod type_inference_tests {
                                 ok: Some(Box::new(InferredType::Str)),
                                 error: Some(Box::new(InferredType::Unknown)),
                             },
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Record(
                         vec![
@@ -2015,6 +2343,7 @@ mod type_inference_tests {
                                         ok: Some(Box::new(InferredType::Unknown)),
                                         error: Some(Box::new(InferredType::Str)),
                                     },
+                                    SourceSpan::default(),
                                 )),
                             ),
                             (
@@ -2025,6 +2354,7 @@ mod type_inference_tests {
                                         ok: Some(Box::new(InferredType::Str)),
                                         error: Some(Box::new(InferredType::Unknown)),
                                     },
+                                    SourceSpan::default(),
                                 )),
                             ),
                         ],
@@ -2044,6 +2374,7 @@ mod type_inference_tests {
                                 },
                             ),
                         ]),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Record(vec![
@@ -2062,6 +2393,7 @@ mod type_inference_tests {
                         },
                     ),
                 ]),
+                SourceSpan::default(),
             );
 
             assert_eq!(expr, expected);
@@ -2072,6 +2404,7 @@ mod type_inference_tests {
         use crate::call_type::CallType;
         use crate::function_name::{DynamicParsedFunctionName, DynamicParsedFunctionReference};
         use crate::parser::type_name::TypeName;
+        use crate::SourceSpan;
         use crate::{
             ArmPattern, Expr, FunctionTypeRegistry, InferredType, MatchArm, MatchIdentifier,
             ParsedFunctionSite, VariableId,
@@ -2191,17 +2524,21 @@ mod type_inference_tests {
                                             InferredType::Str,
                                         )]),
                                     )]),
+                                    SourceSpan::default(),
                                 )),
                                 "body".to_string(),
                                 InferredType::Record(vec![(
                                     "user-id".to_string(),
                                     InferredType::Str,
                                 )]),
+                                SourceSpan::default(),
                             )),
                             "user-id".to_string(),
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("query1", 0),
@@ -2214,8 +2551,10 @@ mod type_inference_tests {
                                 "bar".to_string(),
                                 "foo-bar".to_string(),
                             ]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("query2", 0),
@@ -2228,8 +2567,10 @@ mod type_inference_tests {
                                 "bar".to_string(),
                                 "foo-bar".to_string(),
                             ]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("query3", 0),
@@ -2242,8 +2583,10 @@ mod type_inference_tests {
                                 "bar".to_string(),
                                 "foo-bar".to_string(),
                             ]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("result", 0),
@@ -2263,6 +2606,7 @@ mod type_inference_tests {
                                         "bar".to_string(),
                                         "foo-bar".to_string(),
                                     ]),
+                                    SourceSpan::default(),
                                 ),
                                 Expr::Identifier(
                                     VariableId::local("query2", 0),
@@ -2271,6 +2615,7 @@ mod type_inference_tests {
                                         "bar".to_string(),
                                         "foo-bar".to_string(),
                                     ]),
+                                    SourceSpan::default(),
                                 ),
                                 Expr::Identifier(
                                     VariableId::local("query3", 0),
@@ -2279,16 +2624,23 @@ mod type_inference_tests {
                                         "bar".to_string(),
                                         "foo-bar".to_string(),
                                     ]),
+                                    SourceSpan::default(),
+                                ),
+                                Expr::Identifier(
+                                    VariableId::local("user", 0),
+                                    InferredType::Str,
+                                    SourceSpan::default(),
                                 ),
-                                Expr::Identifier(VariableId::local("user", 0), InferredType::Str),
                             ],
                             InferredType::Enum(vec![
                                 "success".to_string(),
                                 "failure".to_string(),
                                 "in-progress".to_string(),
                             ]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("x", 0),
@@ -2301,6 +2653,7 @@ mod type_inference_tests {
                                     "failure".to_string(),
                                     "in-progress".to_string(),
                                 ]),
+                                SourceSpan::default(),
                             )),
                             vec![
                                 MatchArm {
@@ -2312,19 +2665,23 @@ mod type_inference_tests {
                                             "failure".to_string(),
                                             "in-progress".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Concat(
                                         vec![
                                             Expr::Literal(
                                                 "success ".to_string(),
                                                 InferredType::Str,
+                                                SourceSpan::default(),
                                             ),
                                             Expr::Identifier(
                                                 VariableId::local("user", 0),
                                                 InferredType::Str,
+                                                SourceSpan::default(),
                                             ),
                                         ],
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                                 MatchArm {
@@ -2336,16 +2693,23 @@ mod type_inference_tests {
                                             "failure".to_string(),
                                             "in-progress".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Concat(
                                         vec![
-                                            Expr::Literal("failed ".to_string(), InferredType::Str),
+                                            Expr::Literal(
+                                                "failed ".to_string(),
+                                                InferredType::Str,
+                                                SourceSpan::default(),
+                                            ),
                                             Expr::Identifier(
                                                 VariableId::local("user", 0),
                                                 InferredType::Str,
+                                                SourceSpan::default(),
                                             ),
                                         ],
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                                 MatchArm {
@@ -2357,16 +2721,20 @@ mod type_inference_tests {
                                             "failure".to_string(),
                                             "in-progress".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Literal(
                                         "in-progress".to_string(),
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                             ],
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("y", 0),
@@ -2379,6 +2747,7 @@ mod type_inference_tests {
                                     "bar".to_string(),
                                     "foo-bar".to_string(),
                                 ]),
+                                SourceSpan::default(),
                             )),
                             vec![
                                 MatchArm {
@@ -2390,16 +2759,23 @@ mod type_inference_tests {
                                             "bar".to_string(),
                                             "foo-bar".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Concat(
                                         vec![
-                                            Expr::Literal("y foo ".to_string(), InferredType::Str),
+                                            Expr::Literal(
+                                                "y foo ".to_string(),
+                                                InferredType::Str,
+                                                SourceSpan::default(),
+                                            ),
                                             Expr::Identifier(
                                                 VariableId::local("user", 0),
                                                 InferredType::Str,
+                                                SourceSpan::default(),
                                             ),
                                         ],
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                                 MatchArm {
@@ -2411,16 +2787,23 @@ mod type_inference_tests {
                                             "bar".to_string(),
                                             "foo-bar".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Concat(
                                         vec![
-                                            Expr::Literal("y bar ".to_string(), InferredType::Str),
+                                            Expr::Literal(
+                                                "y bar ".to_string(),
+                                                InferredType::Str,
+                                                SourceSpan::default(),
+                                            ),
                                             Expr::Identifier(
                                                 VariableId::local("user", 0),
                                                 InferredType::Str,
+                                                SourceSpan::default(),
                                             ),
                                         ],
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                                 MatchArm {
@@ -2432,16 +2815,20 @@ mod type_inference_tests {
                                             "bar".to_string(),
                                             "foo-bar".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Literal(
                                         "y foo-bar".to_string(),
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                             ],
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("z", 0),
@@ -2454,6 +2841,7 @@ mod type_inference_tests {
                                     "bar".to_string(),
                                     "foo-bar".to_string(),
                                 ]),
+                                SourceSpan::default(),
                             )),
                             vec![
                                 MatchArm {
@@ -2465,16 +2853,23 @@ mod type_inference_tests {
                                             "bar".to_string(),
                                             "foo-bar".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Concat(
                                         vec![
-                                            Expr::Literal("z foo ".to_string(), InferredType::Str),
+                                            Expr::Literal(
+                                                "z foo ".to_string(),
+                                                InferredType::Str,
+                                                SourceSpan::default(),
+                                            ),
                                             Expr::Identifier(
                                                 VariableId::local("user", 0),
                                                 InferredType::Str,
+                                                SourceSpan::default(),
                                             ),
                                         ],
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                                 MatchArm {
@@ -2486,16 +2881,23 @@ mod type_inference_tests {
                                             "bar".to_string(),
                                             "foo-bar".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Concat(
                                         vec![
-                                            Expr::Literal("z bar ".to_string(), InferredType::Str),
+                                            Expr::Literal(
+                                                "z bar ".to_string(),
+                                                InferredType::Str,
+                                                SourceSpan::default(),
+                                            ),
                                             Expr::Identifier(
                                                 VariableId::local("user", 0),
                                                 InferredType::Str,
+                                                SourceSpan::default(),
                                             ),
                                         ],
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                                 MatchArm {
@@ -2507,16 +2909,20 @@ mod type_inference_tests {
                                             "bar".to_string(),
                                             "foo-bar".to_string(),
                                         ]),
+                                        SourceSpan::default(),
                                     ))),
                                     arm_resolution_expr: Box::new(Expr::Literal(
                                         "z foo-bar".to_string(),
                                         InferredType::Str,
+                                        SourceSpan::default(),
                                     )),
                                 },
                             ],
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Record(
                         vec![
@@ -2525,6 +2931,7 @@ mod type_inference_tests {
                                 Box::new(Expr::Identifier(
                                     VariableId::local("x", 0),
                                     InferredType::Str,
+                                    SourceSpan::default(),
                                 )),
                             ),
                             (
@@ -2532,6 +2939,7 @@ mod type_inference_tests {
                                 Box::new(Expr::Identifier(
                                     VariableId::local("y", 0),
                                     InferredType::Str,
+                                    SourceSpan::default(),
                                 )),
                             ),
                             (
@@ -2539,6 +2947,7 @@ mod type_inference_tests {
                                 Box::new(Expr::Identifier(
                                     VariableId::local("z", 0),
                                     InferredType::Str,
+                                    SourceSpan::default(),
                                 )),
                             ),
                         ],
@@ -2547,6 +2956,7 @@ mod type_inference_tests {
                             ("y".to_string(), InferredType::Str),
                             ("z".to_string(), InferredType::Str),
                         ]),
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Record(vec![
@@ -2554,6 +2964,7 @@ mod type_inference_tests {
                     ("y".to_string(), InferredType::Str),
                     ("z".to_string(), InferredType::Str),
                 ]),
+                SourceSpan::default(),
             )
         }
 
@@ -2600,6 +3011,7 @@ mod type_inference_tests {
                                                                 ),
                                                             ]),
                                                         )]),
+                                                        SourceSpan::default(),
                                                     )),
                                                     "body".to_string(),
                                                     InferredType::Record(vec![
@@ -2623,9 +3035,11 @@ mod type_inference_tests {
                                                             )),
                                                         ),
                                                     ]),
+                                                    SourceSpan::default(),
                                                 )),
                                                 "titles".to_string(),
                                                 InferredType::List(Box::new(InferredType::Str)),
+                                                SourceSpan::default(),
                                             )),
                                         ),
                                         (
@@ -2658,6 +3072,7 @@ mod type_inference_tests {
                                                                 ),
                                                             ]),
                                                         )]),
+                                                        SourceSpan::default(),
                                                     )),
                                                     "body".to_string(),
                                                     InferredType::Record(vec![
@@ -2681,12 +3096,14 @@ mod type_inference_tests {
                                                             )),
                                                         ),
                                                     ]),
+                                                    SourceSpan::default(),
                                                 )),
                                                 "address".to_string(),
                                                 InferredType::Record(vec![
                                                     ("street".to_string(), InferredType::Str),
                                                     ("city".to_string(), InferredType::Str),
                                                 ]),
+                                                SourceSpan::default(),
                                             )),
                                         ),
                                     ],
@@ -2705,6 +3122,7 @@ mod type_inference_tests {
                                             InferredType::List(Box::new(InferredType::Str)),
                                         ),
                                     ]),
+                                    SourceSpan::default(),
                                 )),
                             )],
                             InferredType::Record(vec![(
@@ -2725,8 +3143,10 @@ mod type_inference_tests {
                                     ),
                                 ]),
                             )]),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::Let(
                         VariableId::local("result", 0),
@@ -2758,12 +3178,15 @@ mod type_inference_tests {
                                         ),
                                     ]),
                                 )]),
+                                SourceSpan::default(),
                             )],
                             InferredType::Option(Box::new(InferredType::Option(Box::new(
                                 InferredType::Str,
                             )))),
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
                     Expr::PatternMatch(
                         Box::new(Expr::Identifier(
@@ -2771,6 +3194,7 @@ mod type_inference_tests {
                             InferredType::Option(Box::new(InferredType::Option(Box::new(
                                 InferredType::Str,
                             )))),
+                            SourceSpan::default(),
                         )),
                         vec![
                             MatchArm {
@@ -2782,6 +3206,7 @@ mod type_inference_tests {
                                             1,
                                         )),
                                         InferredType::Option(Box::new(InferredType::Str)),
+                                        SourceSpan::default(),
                                     ))],
                                 ),
                                 arm_resolution_expr: Box::new(Expr::literal("personal-id")),
@@ -2819,6 +3244,7 @@ mod type_inference_tests {
                                                         ),
                                                     ]),
                                                 )]),
+                                                SourceSpan::default(),
                                             )),
                                             "body".to_string(),
                                             InferredType::Record(vec![
@@ -2836,19 +3262,24 @@ mod type_inference_tests {
                                                     InferredType::List(Box::new(InferredType::Str)),
                                                 ),
                                             ]),
+                                            SourceSpan::default(),
                                         )),
                                         "titles".to_string(),
                                         InferredType::List(Box::new(InferredType::Str)),
+                                        SourceSpan::default(),
                                     )),
                                     1,
                                     InferredType::Str,
+                                    SourceSpan::default(),
                                 )),
                             },
                         ],
                         InferredType::Str,
+                        SourceSpan::default(),
                     ),
                 ],
                 InferredType::Str,
+                SourceSpan::default(),
             )
         }
     }
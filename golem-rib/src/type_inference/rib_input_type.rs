@@ -43,7 +43,7 @@ impl RibInputTypeInfo {
 
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::Identifier(variable_id, inferred_type) => {
+                Expr::Identifier(variable_id, inferred_type, _) => {
                     if variable_id.is_global() {
                         let analysed_type = AnalysedType::try_from(inferred_type)?;
                         global_variables.insert(variable_id.name(), analysed_type);
@@ -0,0 +1,57 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Expr, InferredExpr};
+use bincode::{Decode, Encode};
+use golem_api_grpc::proto::golem::rib::RibOutputType as ProtoRibOutputType;
+use golem_wasm_ast::analysis::AnalysedType;
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+// RibOutputTypeInfo refers to the type of the value a Rib script evaluates to.
+// Example: the response mapping of an API gateway binding evaluating to a `Record` of status and body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode, Object)]
+pub struct RibOutputTypeInfo {
+    pub analysed_type: AnalysedType,
+}
+
+impl RibOutputTypeInfo {
+    // Rib scripts evaluating to unit have no output type
+    pub fn from_expr(inferred_expr: &InferredExpr) -> Option<RibOutputTypeInfo> {
+        let expr: Expr = Expr::from(inferred_expr.clone());
+
+        AnalysedType::try_from(&expr.inferred_type())
+            .ok()
+            .map(|analysed_type| RibOutputTypeInfo { analysed_type })
+    }
+}
+
+impl TryFrom<ProtoRibOutputType> for RibOutputTypeInfo {
+    type Error = String;
+    fn try_from(value: ProtoRibOutputType) -> Result<Self, String> {
+        let proto_type = value.r#type.ok_or("Missing type")?;
+        let analysed_type = AnalysedType::try_from(&proto_type)?;
+        Ok(RibOutputTypeInfo { analysed_type })
+    }
+}
+
+impl From<RibOutputTypeInfo> for ProtoRibOutputType {
+    fn from(value: RibOutputTypeInfo) -> Self {
+        ProtoRibOutputType {
+            r#type: Some(golem_wasm_ast::analysis::protobuf::Type::from(
+                &value.analysed_type,
+            )),
+        }
+    }
+}
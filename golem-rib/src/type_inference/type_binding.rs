@@ -21,14 +21,14 @@ pub(crate) fn bind_type(expr: &mut Expr) {
 
     while let Some(expr) = queue.pop_back() {
         match expr {
-            Expr::Let(_, optional_type_name, expr, _) => {
+            Expr::Let(_, optional_type_name, expr, _, _) => {
                 if let Some(type_name) = optional_type_name {
                     internal::override_type(expr, type_name.clone().into());
                 }
                 queue.push_back(expr);
             }
 
-            Expr::Number(_, optional_type_name, inferred_type) => {
+            Expr::Number(_, optional_type_name, inferred_type, _) => {
                 if let Some(type_name) = optional_type_name {
                     *inferred_type = type_name.clone().into();
                 }
@@ -44,41 +44,45 @@ mod internal {
 
     pub(crate) fn override_type(expr: &mut Expr, new_type: InferredType) {
         match expr {
-            Expr::Identifier(_, inferred_type)
-            | Expr::Let(_, _, _, inferred_type)
-            | Expr::SelectField(_, _, inferred_type)
-            | Expr::SelectIndex(_, _, inferred_type)
-            | Expr::Sequence(_, inferred_type)
-            | Expr::Record(_, inferred_type)
-            | Expr::Tuple(_, inferred_type)
-            | Expr::Literal(_, inferred_type)
-            | Expr::Number(_, _, inferred_type)
-            | Expr::Flags(_, inferred_type)
-            | Expr::Boolean(_, inferred_type)
-            | Expr::Concat(_, inferred_type)
-            | Expr::ExprBlock(_, inferred_type)
-            | Expr::Not(_, inferred_type)
-            | Expr::GreaterThan(_, _, inferred_type)
-            | Expr::GreaterThanOrEqualTo(_, _, inferred_type)
-            | Expr::LessThanOrEqualTo(_, _, inferred_type)
-            | Expr::EqualTo(_, _, inferred_type)
-            | Expr::LessThan(_, _, inferred_type)
-            | Expr::Plus(_, _, inferred_type)
-            | Expr::Minus(_, _, inferred_type)
-            | Expr::Divide(_, _, inferred_type)
-            | Expr::Multiply(_, _, inferred_type)
-            | Expr::Cond(_, _, _, inferred_type)
-            | Expr::PatternMatch(_, _, inferred_type)
-            | Expr::Option(_, inferred_type)
-            | Expr::Result(_, inferred_type)
-            | Expr::Unwrap(_, inferred_type)
-            | Expr::Throw(_, inferred_type)
-            | Expr::GetTag(_, inferred_type)
-            | Expr::And(_, _, inferred_type)
-            | Expr::Or(_, _, inferred_type)
+            Expr::Identifier(_, inferred_type, _)
+            | Expr::Let(_, _, _, inferred_type, _)
+            | Expr::SelectField(_, _, inferred_type, _)
+            | Expr::SelectIndex(_, _, inferred_type, _)
+            | Expr::Sequence(_, inferred_type, _)
+            | Expr::Record(_, inferred_type, _)
+            | Expr::Tuple(_, inferred_type, _)
+            | Expr::Literal(_, inferred_type, _)
+            | Expr::Number(_, _, inferred_type, _)
+            | Expr::Flags(_, inferred_type, _)
+            | Expr::Boolean(_, inferred_type, _)
+            | Expr::Concat(_, inferred_type, _)
+            | Expr::ExprBlock(_, inferred_type, _)
+            | Expr::Not(_, inferred_type, _)
+            | Expr::Negative(_, inferred_type, _)
+            | Expr::GreaterThan(_, _, inferred_type, _)
+            | Expr::GreaterThanOrEqualTo(_, _, inferred_type, _)
+            | Expr::LessThanOrEqualTo(_, _, inferred_type, _)
+            | Expr::EqualTo(_, _, inferred_type, _)
+            | Expr::NotEqualTo(_, _, inferred_type, _)
+            | Expr::LessThan(_, _, inferred_type, _)
+            | Expr::Plus(_, _, inferred_type, _)
+            | Expr::Minus(_, _, inferred_type, _)
+            | Expr::Divide(_, _, inferred_type, _)
+            | Expr::Multiply(_, _, inferred_type, _)
+            | Expr::Modulo(_, _, inferred_type, _)
+            | Expr::Cond(_, _, _, inferred_type, _)
+            | Expr::PatternMatch(_, _, inferred_type, _)
+            | Expr::Option(_, inferred_type, _)
+            | Expr::Result(_, inferred_type, _)
+            | Expr::Unwrap(_, inferred_type, _)
+            | Expr::Throw(_, inferred_type, _)
+            | Expr::GetTag(_, inferred_type, _)
+            | Expr::Lambda(_, _, inferred_type, _)
+            | Expr::And(_, _, inferred_type, _)
+            | Expr::Or(_, _, inferred_type, _)
             | Expr::ListComprehension { inferred_type, .. }
             | Expr::ListReduce { inferred_type, .. }
-            | Expr::Call(_, _, inferred_type) => {
+            | Expr::Call(_, _, inferred_type, _) => {
                 *inferred_type = new_type;
             }
         }
@@ -91,7 +95,7 @@ mod type_binding_tests {
 
     use super::*;
     use crate::parser::type_name::TypeName;
-    use crate::{ArmPattern, InferredType, MatchArm, Number, VariableId};
+    use crate::{ArmPattern, InferredType, MatchArm, Number, SourceSpan, VariableId};
 
     #[test]
     fn test_bind_type() {
@@ -110,8 +114,10 @@ mod type_binding_tests {
                 Number { value: 1f64 },
                 None,
                 InferredType::U64,
+                SourceSpan::default(),
             )),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         assert_eq!(expr, expected);
@@ -134,8 +140,10 @@ mod type_binding_tests {
                 Number { value: 1f64 },
                 Some(TypeName::U64),
                 InferredType::U64,
+                SourceSpan::default(),
             )),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         assert_eq!(expr, expected);
@@ -166,14 +174,22 @@ mod type_binding_tests {
                             Number { value: 1f64 },
                             None,
                             InferredType::U64,
+                            SourceSpan::default(),
                         )),
                         InferredType::Unknown,
+                        SourceSpan::default(),
+                    ),
+                    Expr::Identifier(
+                        VariableId::global("y".to_string()),
+                        InferredType::Unknown,
+                        SourceSpan::default(),
                     ),
-                    Expr::Identifier(VariableId::global("y".to_string()), InferredType::Unknown),
                 ],
                 InferredType::Unknown,
+                SourceSpan::default(),
             )),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         assert_eq!(expr, expected);
@@ -195,19 +211,23 @@ mod type_binding_tests {
             Box::new(Expr::Identifier(
                 VariableId::global("x".to_string()),
                 InferredType::Unknown,
+                SourceSpan::default(),
             )),
             vec![MatchArm {
                 arm_pattern: ArmPattern::Literal(Box::new(Expr::Identifier(
                     VariableId::global("a".to_string()),
                     InferredType::Unknown,
+                    SourceSpan::default(),
                 ))),
                 arm_resolution_expr: Box::new(Expr::Number(
                     Number { value: 2f64 },
                     Some(TypeName::U64),
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
             }],
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         assert_eq!(expr, expected);
@@ -230,18 +250,22 @@ mod type_binding_tests {
             Box::new(Expr::Identifier(
                 VariableId::global("x".to_string()),
                 InferredType::Unknown,
+                SourceSpan::default(),
             )),
             Box::new(Expr::Number(
                 Number { value: 1f64 },
                 Some(TypeName::U64),
                 InferredType::U64,
+                SourceSpan::default(),
             )),
             Box::new(Expr::Number(
                 Number { value: 2f64 },
                 Some(TypeName::U64),
                 InferredType::U64,
+                SourceSpan::default(),
             )),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         assert_eq!(expr, expected);
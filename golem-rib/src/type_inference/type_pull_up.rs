@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Expr, InferredType};
+use crate::{Expr, InferredType, SourceSpan};
 use std::collections::VecDeque;
 
 // Initialize a queue with all expr in the tree, with the root node first:
@@ -55,7 +55,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
 
     while let Some(expr) = expr_queue.pop_back() {
         match expr {
-            Expr::Tuple(tuple_elems, current_inferred_type) => {
+            Expr::Tuple(tuple_elems, current_inferred_type, _) => {
                 internal::handle_tuple(
                     tuple_elems,
                     current_inferred_type,
@@ -63,15 +63,15 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            expr @ Expr::Identifier(_, _) => {
+            expr @ Expr::Identifier(_, _, _) => {
                 inferred_type_stack.push_front(expr.clone());
             }
 
-            expr @ Expr::Flags(_, _) => {
+            expr @ Expr::Flags(_, _, _) => {
                 inferred_type_stack.push_front(expr.clone());
             }
 
-            Expr::SelectField(expr, field, current_inferred_type) => {
+            Expr::SelectField(expr, field, current_inferred_type, _) => {
                 internal::handle_select_field(
                     expr,
                     field,
@@ -80,7 +80,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 )?;
             }
 
-            Expr::SelectIndex(expr, index, current_inferred_type) => {
+            Expr::SelectIndex(expr, index, current_inferred_type, _) => {
                 internal::handle_select_index(
                     expr,
                     index,
@@ -89,11 +89,11 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 )?;
             }
 
-            Expr::Result(Ok(_), current_inferred_type) => {
+            Expr::Result(Ok(_), current_inferred_type, _) => {
                 internal::handle_result_ok(expr, current_inferred_type, &mut inferred_type_stack);
             }
 
-            Expr::Result(Err(_), current_inferred_type) => {
+            Expr::Result(Err(_), current_inferred_type, _) => {
                 internal::handle_result_error(
                     expr,
                     current_inferred_type,
@@ -101,15 +101,19 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::Option(Some(expr), current_inferred_type) => {
+            Expr::Option(Some(expr), current_inferred_type, _) => {
                 internal::handle_option_some(expr, current_inferred_type, &mut inferred_type_stack);
             }
 
-            Expr::Option(None, current_inferred_type) => {
-                inferred_type_stack.push_front(Expr::Option(None, current_inferred_type.clone()));
+            Expr::Option(None, current_inferred_type, _) => {
+                inferred_type_stack.push_front(Expr::Option(
+                    None,
+                    current_inferred_type.clone(),
+                    SourceSpan::default(),
+                ));
             }
 
-            Expr::Cond(pred, then, else_, current_inferred_type) => {
+            Expr::Cond(pred, then, else_, current_inferred_type, _) => {
                 internal::handle_if_else(
                     pred,
                     then,
@@ -120,7 +124,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
             }
 
             //
-            Expr::PatternMatch(predicate, match_arms, current_inferred_type) => {
+            Expr::PatternMatch(predicate, match_arms, current_inferred_type, _) => {
                 internal::handle_pattern_match(
                     predicate,
                     match_arms,
@@ -129,19 +133,23 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::Concat(exprs, _) => {
+            Expr::Concat(exprs, _, _) => {
                 internal::handle_concat(exprs, &mut inferred_type_stack);
             }
 
-            Expr::ExprBlock(exprs, current_inferred_type) => {
+            Expr::ExprBlock(exprs, current_inferred_type, _) => {
                 internal::handle_multiple(exprs, current_inferred_type, &mut inferred_type_stack);
             }
 
-            Expr::Not(_, current_inferred_type) => {
+            Expr::Not(_, current_inferred_type, _) => {
                 internal::handle_not(expr, current_inferred_type, &mut inferred_type_stack);
             }
 
-            Expr::GreaterThan(left, right, current_inferred_type) => {
+            Expr::Negative(_, current_inferred_type, _) => {
+                internal::handle_negative(expr, current_inferred_type, &mut inferred_type_stack);
+            }
+
+            Expr::GreaterThan(left, right, current_inferred_type, _) => {
                 internal::handle_comparison_op(
                     left,
                     right,
@@ -151,7 +159,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::GreaterThanOrEqualTo(left, right, current_inferred_type) => {
+            Expr::GreaterThanOrEqualTo(left, right, current_inferred_type, _) => {
                 internal::handle_comparison_op(
                     left,
                     right,
@@ -161,7 +169,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::LessThanOrEqualTo(left, right, current_inferred_type) => {
+            Expr::LessThanOrEqualTo(left, right, current_inferred_type, _) => {
                 internal::handle_comparison_op(
                     left,
                     right,
@@ -170,7 +178,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                     Expr::LessThanOrEqualTo,
                 );
             }
-            Expr::Plus(left, right, current_inferred_type) => {
+            Expr::Plus(left, right, current_inferred_type, _) => {
                 internal::handle_math_op(
                     left,
                     right,
@@ -180,7 +188,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::Minus(left, right, current_inferred_type) => {
+            Expr::Minus(left, right, current_inferred_type, _) => {
                 internal::handle_math_op(
                     left,
                     right,
@@ -190,7 +198,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::Multiply(left, right, current_inferred_type) => {
+            Expr::Multiply(left, right, current_inferred_type, _) => {
                 internal::handle_math_op(
                     left,
                     right,
@@ -200,7 +208,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::Divide(left, right, current_inferred_type) => {
+            Expr::Divide(left, right, current_inferred_type, _) => {
                 internal::handle_math_op(
                     left,
                     right,
@@ -210,7 +218,17 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::EqualTo(left, right, current_inferred_type) => {
+            Expr::Modulo(left, right, current_inferred_type, _) => {
+                internal::handle_math_op(
+                    left,
+                    right,
+                    current_inferred_type,
+                    &mut inferred_type_stack,
+                    Expr::Modulo,
+                );
+            }
+
+            Expr::EqualTo(left, right, current_inferred_type, _) => {
                 internal::handle_comparison_op(
                     left,
                     right,
@@ -220,7 +238,17 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::LessThan(left, right, current_inferred_type) => {
+            Expr::NotEqualTo(left, right, current_inferred_type, _) => {
+                internal::handle_comparison_op(
+                    left,
+                    right,
+                    current_inferred_type,
+                    &mut inferred_type_stack,
+                    Expr::NotEqualTo,
+                );
+            }
+
+            Expr::LessThan(left, right, current_inferred_type, _) => {
                 internal::handle_comparison_op(
                     left,
                     right,
@@ -230,7 +258,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::Let(variable_id, typ, expr, inferred_type) => {
+            Expr::Let(variable_id, typ, expr, inferred_type, _) => {
                 internal::handle_let(
                     variable_id,
                     expr,
@@ -239,22 +267,22 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                     &mut inferred_type_stack,
                 );
             }
-            Expr::Sequence(exprs, current_inferred_type) => {
+            Expr::Sequence(exprs, current_inferred_type, _) => {
                 internal::handle_sequence(exprs, current_inferred_type, &mut inferred_type_stack);
             }
-            Expr::Record(expr, inferred_type) => {
+            Expr::Record(expr, inferred_type, _) => {
                 internal::handle_record(expr, inferred_type, &mut inferred_type_stack);
             }
-            Expr::Literal(_, _) => {
+            Expr::Literal(_, _, _) => {
                 inferred_type_stack.push_front(expr.clone());
             }
-            Expr::Number(_, _, _) => {
+            Expr::Number(_, _, _, _) => {
                 inferred_type_stack.push_front(expr.clone());
             }
-            Expr::Boolean(_, _) => {
+            Expr::Boolean(_, _, _) => {
                 inferred_type_stack.push_front(expr.clone());
             }
-            Expr::And(left, right, _) => {
+            Expr::And(left, right, _, _) => {
                 internal::handle_comparison_op(
                     left,
                     right,
@@ -264,7 +292,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::Or(left, right, _) => {
+            Expr::Or(left, right, _, _) => {
                 internal::handle_comparison_op(
                     left,
                     right,
@@ -274,22 +302,26 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 );
             }
 
-            Expr::Call(call_type, exprs, inferred_type) => {
+            Expr::Call(call_type, exprs, inferred_type, _) => {
                 internal::handle_call(call_type, exprs, inferred_type, &mut inferred_type_stack);
             }
 
-            Expr::Unwrap(expr, inferred_type) => {
+            Expr::Unwrap(expr, inferred_type, _) => {
                 internal::handle_unwrap(expr, inferred_type, &mut inferred_type_stack);
             }
 
-            Expr::Throw(_, _) => {
+            Expr::Throw(_, _, _) => {
                 inferred_type_stack.push_front(expr.clone());
             }
 
-            Expr::GetTag(_, inferred_type) => {
+            Expr::GetTag(_, inferred_type, _) => {
                 internal::handle_get_tag(expr, inferred_type, &mut inferred_type_stack);
             }
 
+            Expr::Lambda(parameters, body, inferred_type, _) => {
+                internal::handle_lambda(parameters, body, inferred_type, &mut inferred_type_stack);
+            }
+
             Expr::ListComprehension {
                 iterated_variable,
                 iterable_expr,
@@ -313,6 +345,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 init_value_expr,
                 yield_expr,
                 inferred_type,
+                ..
             } => internal::handle_list_reduce(
                 reduce_variable,
                 iterated_variable,
@@ -323,6 +356,11 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 &mut inferred_type_stack,
             ),
         }
+
+        // The expressions are rebuilt while pulling up the types, and they keep their source span
+        if let Some(pulled_up_expr) = inferred_type_stack.front_mut() {
+            *pulled_up_expr.source_span_mut() = expr.source_span();
+        }
     }
 
     inferred_type_stack
@@ -333,9 +371,10 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
 mod internal {
     use crate::call_type::CallType;
 
+    use crate::type_inference::built_in_function_signature;
     use crate::type_refinement::precise_types::{ListType, RecordType};
     use crate::type_refinement::TypeRefinement;
-    use crate::{Expr, InferredType, MatchArm, VariableId};
+    use crate::{Expr, InferredType, LambdaParameter, MatchArm, SourceSpan, VariableId};
     use std::collections::VecDeque;
     use std::ops::Deref;
 
@@ -426,7 +465,7 @@ mod internal {
             InferredType::Tuple(new_tuple_elems.iter().map(|x| x.inferred_type()).collect());
 
         let merged_tuple_type = current_tuple_type.merge(new_tuple_type);
-        let new_tuple = Expr::Tuple(new_tuple_elems, merged_tuple_type);
+        let new_tuple = Expr::Tuple(new_tuple_elems, merged_tuple_type, SourceSpan::default());
         inferred_type_stack.push_front(new_tuple);
     }
 
@@ -447,6 +486,7 @@ mod internal {
             Box::new(expr.clone()),
             field.to_string(),
             current_field_type.merge(selection_field_type),
+            SourceSpan::default(),
         );
 
         inferred_type_stack.push_front(new_select_field);
@@ -470,6 +510,7 @@ mod internal {
             Box::new(expr.clone()),
             *index,
             current_index_type.merge(list_type),
+            SourceSpan::default(),
         );
         inferred_type_stack.push_front(new_select_index);
 
@@ -492,6 +533,7 @@ mod internal {
         let new_result = Expr::Result(
             Ok(Box::new(ok_expr.clone())),
             current_ok_type.merge(result_type),
+            SourceSpan::default(),
         );
         inferred_type_stack.push_front(new_result);
     }
@@ -512,6 +554,7 @@ mod internal {
         let new_result = Expr::Result(
             Err(Box::new(expr.clone())),
             current_error_type.merge(result_type),
+            SourceSpan::default(),
         );
         inferred_type_stack.push_front(new_result);
     }
@@ -529,6 +572,7 @@ mod internal {
         let new_option = Expr::Option(
             Some(Box::new(expr.clone())),
             current_some_type.merge(option_type),
+            SourceSpan::default(),
         );
         inferred_type_stack.push_front(new_option);
     }
@@ -560,6 +604,7 @@ mod internal {
             Box::new(then_expr.clone()),
             Box::new(else_expr.clone()),
             new_type,
+            SourceSpan::default(),
         );
 
         inferred_type_stack.push_front(new_expr);
@@ -628,7 +673,12 @@ mod internal {
 
         let pred = inferred_type_stack.pop_front().unwrap_or(predicate.clone());
 
-        let new_expr = Expr::PatternMatch(Box::new(pred.clone()), new_match_arms, new_type);
+        let new_expr = Expr::PatternMatch(
+            Box::new(pred.clone()),
+            new_match_arms,
+            new_type,
+            SourceSpan::default(),
+        );
 
         inferred_type_stack.push_front(new_expr);
     }
@@ -642,7 +692,7 @@ mod internal {
 
         new_exprs.reverse();
 
-        let new_concat = Expr::Concat(new_exprs, InferredType::Str);
+        let new_concat = Expr::Concat(new_exprs, InferredType::Str, SourceSpan::default());
         inferred_type_stack.push_front(new_concat);
     }
 
@@ -669,8 +719,11 @@ mod internal {
             InferredType::Unknown
         };
 
-        let new_multiple =
-            Expr::ExprBlock(new_exprs, current_inferred_type.merge(new_inferred_type));
+        let new_multiple = Expr::ExprBlock(
+            new_exprs,
+            current_inferred_type.merge(new_inferred_type),
+            SourceSpan::default(),
+        );
         inferred_type_stack.push_front(new_multiple);
     }
 
@@ -682,10 +735,31 @@ mod internal {
         let expr = inferred_type_stack
             .pop_front()
             .unwrap_or(original_not_expr.clone());
-        let new_not = Expr::Not(Box::new(expr), current_not_type.clone());
+        let new_not = Expr::Not(
+            Box::new(expr),
+            current_not_type.clone(),
+            SourceSpan::default(),
+        );
         inferred_type_stack.push_front(new_not);
     }
 
+    // The type of a negated number is the type of the number itself
+    pub(crate) fn handle_negative(
+        original_negative_expr: &Expr,
+        current_negative_type: &InferredType,
+        inferred_type_stack: &mut VecDeque<Expr>,
+    ) {
+        let expr = inferred_type_stack
+            .pop_front()
+            .unwrap_or(original_negative_expr.clone());
+
+        let new_negative_type = current_negative_type.merge(expr.inferred_type());
+
+        let new_negative = Expr::Negative(Box::new(expr), new_negative_type, SourceSpan::default());
+
+        inferred_type_stack.push_front(new_negative);
+    }
+
     pub(crate) fn handle_math_op<F>(
         original_left_expr: &Expr,
         original_right_expr: &Expr,
@@ -693,7 +767,7 @@ mod internal {
         inferred_type_stack: &mut VecDeque<Expr>,
         f: F,
     ) where
-        F: Fn(Box<Expr>, Box<Expr>, InferredType) -> Expr,
+        F: Fn(Box<Expr>, Box<Expr>, InferredType, SourceSpan) -> Expr,
     {
         let right_expr = inferred_type_stack
             .pop_front()
@@ -710,6 +784,7 @@ mod internal {
             Box::new(left_expr),
             Box::new(right_expr),
             new_result_type.clone(),
+            SourceSpan::default(),
         );
 
         inferred_type_stack.push_front(new_math_op);
@@ -722,7 +797,7 @@ mod internal {
         inferred_type_stack: &mut VecDeque<Expr>,
         f: F,
     ) where
-        F: Fn(Box<Expr>, Box<Expr>, InferredType) -> Expr,
+        F: Fn(Box<Expr>, Box<Expr>, InferredType, SourceSpan) -> Expr,
     {
         let right_expr = inferred_type_stack
             .pop_front()
@@ -734,6 +809,7 @@ mod internal {
             Box::new(left_expr),
             Box::new(right_expr),
             result_type.clone(),
+            SourceSpan::default(),
         );
         inferred_type_stack.push_front(new_binary);
     }
@@ -780,6 +856,7 @@ mod internal {
                     CallType::Function(function_name),
                     new_arg_exprs,
                     inferred_type.clone(),
+                    SourceSpan::default(),
                 );
                 inferred_type_stack.push_front(new_call);
             }
@@ -789,6 +866,7 @@ mod internal {
                     CallType::VariantConstructor(str.clone()),
                     new_arg_exprs,
                     inferred_type.clone(),
+                    SourceSpan::default(),
                 );
                 inferred_type_stack.push_front(new_call);
             }
@@ -798,6 +876,20 @@ mod internal {
                     CallType::EnumConstructor(str.clone()),
                     new_arg_exprs,
                     inferred_type.clone(),
+                    SourceSpan::default(),
+                );
+                inferred_type_stack.push_front(new_call);
+            }
+
+            CallType::BuiltIn(built_in_function) => {
+                let return_type =
+                    built_in_function_signature::get_return_type(built_in_function, &new_arg_exprs);
+
+                let new_call = Expr::Call(
+                    CallType::BuiltIn(*built_in_function),
+                    new_arg_exprs,
+                    inferred_type.merge(return_type),
+                    SourceSpan::default(),
                 );
                 inferred_type_stack.push_front(new_call);
             }
@@ -813,6 +905,7 @@ mod internal {
         let new_unwrap = Expr::Unwrap(
             Box::new(expr.clone()),
             current_inferred_type.merge(expr.inferred_type()),
+            SourceSpan::default(),
         );
         inferred_type_stack.push_front(new_unwrap);
     }
@@ -826,10 +919,29 @@ mod internal {
         let new_get_tag = Expr::GetTag(
             Box::new(expr.clone()),
             current_inferred_type.merge(expr.inferred_type()),
+            SourceSpan::default(),
         );
         inferred_type_stack.push_front(new_get_tag);
     }
 
+    pub(crate) fn handle_lambda(
+        parameters: &[LambdaParameter],
+        original_body: &Expr,
+        current_inferred_type: &InferredType,
+        inferred_type_stack: &mut VecDeque<Expr>,
+    ) {
+        let body = inferred_type_stack
+            .pop_front()
+            .unwrap_or(original_body.clone());
+        let new_lambda = Expr::Lambda(
+            parameters.to_vec(),
+            Box::new(body),
+            current_inferred_type.clone(),
+            SourceSpan::default(),
+        );
+        inferred_type_stack.push_front(new_lambda);
+    }
+
     pub(crate) fn handle_let(
         original_variable_id: &VariableId,
         original_expr: &Expr,
@@ -845,6 +957,7 @@ mod internal {
             optional_type.clone(),
             Box::new(expr),
             current_inferred_type.clone(),
+            SourceSpan::default(),
         );
         inferred_type_stack.push_front(new_let);
     }
@@ -870,9 +983,14 @@ mod internal {
                     current_inferred_type
                         .clone()
                         .merge(InferredType::List(Box::new(first_expr.inferred_type()))),
+                    SourceSpan::default(),
                 )
             } else {
-                Expr::Sequence(new_exprs, current_inferred_type.clone())
+                Expr::Sequence(
+                    new_exprs,
+                    current_inferred_type.clone(),
+                    SourceSpan::default(),
+                )
             }
         };
 
@@ -902,7 +1020,11 @@ mod internal {
 
         let merged_record_type = current_inferred_type.merge(new_record_type);
 
-        let new_record = Expr::Record(new_exprs.to_vec(), merged_record_type);
+        let new_record = Expr::Record(
+            new_exprs.to_vec(),
+            merged_record_type,
+            SourceSpan::default(),
+        );
         inferred_type_stack.push_front(new_record);
     }
 
@@ -940,7 +1062,8 @@ mod type_pull_up_tests {
     use crate::DynamicParsedFunctionReference::IndexedResourceMethod;
     use crate::ParsedFunctionSite::PackagedInterface;
     use crate::{
-        ArmPattern, Expr, FunctionTypeRegistry, InferredType, MatchArm, Number, VariableId,
+        ArmPattern, Expr, FunctionTypeRegistry, InferredType, MatchArm, Number, SourceSpan,
+        VariableId,
     };
 
     #[test]
@@ -978,16 +1101,30 @@ mod type_pull_up_tests {
     #[test]
     pub fn test_pull_up_for_sequence() {
         let elems = vec![
-            Expr::Number(Number { value: 1f64 }, None, InferredType::U64),
-            Expr::Number(Number { value: 2f64 }, None, InferredType::U64),
+            Expr::Number(
+                Number { value: 1f64 },
+                None,
+                InferredType::U64,
+                SourceSpan::default(),
+            ),
+            Expr::Number(
+                Number { value: 2f64 },
+                None,
+                InferredType::U64,
+                SourceSpan::default(),
+            ),
         ];
 
-        let expr = Expr::Sequence(elems.clone(), InferredType::Unknown);
+        let expr = Expr::Sequence(elems.clone(), InferredType::Unknown, SourceSpan::default());
         let new_expr = expr.pull_types_up().unwrap();
 
         assert_eq!(
             new_expr,
-            Expr::Sequence(elems, InferredType::List(Box::new(InferredType::U64)))
+            Expr::Sequence(
+                elems,
+                InferredType::List(Box::new(InferredType::U64)),
+                SourceSpan::default()
+            )
         );
     }
 
@@ -995,7 +1132,12 @@ mod type_pull_up_tests {
     pub fn test_pull_up_for_tuple() {
         let expr = Expr::tuple(vec![
             Expr::literal("foo"),
-            Expr::Number(Number { value: 1f64 }, None, InferredType::U64),
+            Expr::Number(
+                Number { value: 1f64 },
+                None,
+                InferredType::U64,
+                SourceSpan::default(),
+            ),
         ]);
         let new_expr = expr.pull_types_up().unwrap();
         assert_eq!(
@@ -1013,6 +1155,7 @@ mod type_pull_up_tests {
                     Number { value: 1f64 },
                     None,
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
             ),
             (
@@ -1021,6 +1164,7 @@ mod type_pull_up_tests {
                     Number { value: 2f64 },
                     None,
                     InferredType::U32,
+                    SourceSpan::default(),
                 )),
             ),
         ];
@@ -1030,6 +1174,7 @@ mod type_pull_up_tests {
                 ("foo".to_string(), InferredType::Unknown),
                 ("bar".to_string(), InferredType::Unknown),
             ]),
+            SourceSpan::default(),
         );
         let new_expr = expr.pull_types_up().unwrap();
 
@@ -1046,7 +1191,8 @@ mod type_pull_up_tests {
                         ("foo".to_string(), InferredType::Unknown),
                         ("bar".to_string(), InferredType::Unknown)
                     ])
-                ])
+                ]),
+                SourceSpan::default()
             )
         );
     }
@@ -1058,6 +1204,7 @@ mod type_pull_up_tests {
         let expected = Expr::Concat(
             vec![Expr::literal("foo"), Expr::literal("bar")],
             InferredType::Str,
+            SourceSpan::default(),
         );
         assert_eq!(new_expr, expected);
     }
@@ -1096,37 +1243,47 @@ mod type_pull_up_tests {
                     Box::new(Expr::Identifier(
                         VariableId::global("foo".to_string()),
                         InferredType::List(Box::new(InferredType::U64)),
+                        SourceSpan::default(),
                     )),
                     0,
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
                 Box::new(Expr::SelectIndex(
                     Box::new(Expr::Identifier(
                         VariableId::global("foo".to_string()),
                         InferredType::List(Box::new(InferredType::U64)),
+                        SourceSpan::default(),
                     )),
                     1,
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
                 InferredType::Bool,
+                SourceSpan::default(),
             )),
             Box::new(Expr::SelectIndex(
                 Box::new(Expr::Identifier(
                     VariableId::global("bar".to_string()),
                     InferredType::List(Box::new(InferredType::U64)),
+                    SourceSpan::default(),
                 )),
                 0,
                 InferredType::U64,
+                SourceSpan::default(),
             )),
             Box::new(Expr::SelectIndex(
                 Box::new(Expr::Identifier(
                     VariableId::global("bar".to_string()),
                     InferredType::List(Box::new(InferredType::U64)),
+                    SourceSpan::default(),
                 )),
                 1,
                 InferredType::U64,
+                SourceSpan::default(),
             )),
             InferredType::U64,
+            SourceSpan::default(),
         );
         assert_eq!(new_expr, expected);
     }
@@ -1257,19 +1414,29 @@ mod type_pull_up_tests {
                         vec![
                             (
                                 "foo".to_string(),
-                                Box::new(Expr::Literal("afs".to_string(), InferredType::Str)),
+                                Box::new(Expr::Literal(
+                                    "afs".to_string(),
+                                    InferredType::Str,
+                                    SourceSpan::default(),
+                                )),
                             ),
                             (
                                 "bar".to_string(),
-                                Box::new(Expr::Literal("al".to_string(), InferredType::Str)),
+                                Box::new(Expr::Literal(
+                                    "al".to_string(),
+                                    InferredType::Str,
+                                    SourceSpan::default(),
+                                )),
                             ),
                         ],
                         InferredType::Record(vec![
                             ("foo".to_string(), InferredType::Str),
                             ("bar".to_string(), InferredType::Str),
                         ]),
+                        SourceSpan::default(),
                     )),
                     InferredType::Unknown,
+                    SourceSpan::default(),
                 ),
                 Expr::Call(
                     CallType::Function(DynamicParsedFunctionName {
@@ -1288,18 +1455,22 @@ mod type_pull_up_tests {
                                         ("foo".to_string(), InferredType::Str),
                                         ("bar".to_string(), InferredType::Str),
                                     ]),
+                                    SourceSpan::default(),
                                 )),
                                 "foo".to_string(),
                                 InferredType::Str,
+                                SourceSpan::default(),
                             )],
                             method: "checkout".to_string(),
                         },
                     }),
                     vec![],
                     InferredType::Unknown,
+                    SourceSpan::default(),
                 ),
             ],
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         assert_eq!(new_expr, expected);
@@ -1349,6 +1520,7 @@ mod type_pull_up_tests {
                             ))),
                             "bar".to_string(),
                             InferredType::Unknown,
+                            SourceSpan::default(),
                         )))],
                     ),
                     arm_resolution_expr: Box::new(Expr::SelectField(
@@ -1357,6 +1529,7 @@ mod type_pull_up_tests {
                         ))),
                         "qux".to_string(),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     )),
                 },
                 MatchArm {
@@ -1371,6 +1544,7 @@ mod type_pull_up_tests {
                             )),
                             "corge".to_string(),
                             InferredType::Unknown,
+                            SourceSpan::default(),
                         )))],
                     ),
                     arm_resolution_expr: Box::new(Expr::SelectField(
@@ -1381,6 +1555,7 @@ mod type_pull_up_tests {
                         ),
                         "garply".to_string(),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     )),
                 },
             ],
@@ -1391,6 +1566,7 @@ mod type_pull_up_tests {
     }
 
     mod internal {
+        use crate::SourceSpan;
         use crate::{ArmPattern, Expr, InferredType, MatchArm, VariableId};
 
         pub(crate) fn expected_pattern_match() -> Expr {
@@ -1399,9 +1575,11 @@ mod type_pull_up_tests {
                     Box::new(Expr::Identifier(
                         VariableId::global("foo".to_string()),
                         InferredType::Record(vec![("bar".to_string(), InferredType::Str)]),
+                        SourceSpan::default(),
                     )),
                     "bar".to_string(),
                     InferredType::Str,
+                    SourceSpan::default(),
                 )),
                 vec![
                     MatchArm {
@@ -1414,18 +1592,22 @@ mod type_pull_up_tests {
                                         "bar".to_string(),
                                         InferredType::Str,
                                     )]),
+                                    SourceSpan::default(),
                                 )),
                                 "bar".to_string(),
                                 InferredType::Str,
+                                SourceSpan::default(),
                             )))],
                         ),
                         arm_resolution_expr: Box::new(Expr::SelectField(
                             Box::new(Expr::Identifier(
                                 VariableId::global("baz".to_string()),
                                 InferredType::Record(vec![("qux".to_string(), InferredType::Str)]),
+                                SourceSpan::default(),
                             )),
                             "qux".to_string(),
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                     },
                     MatchArm {
@@ -1438,9 +1620,11 @@ mod type_pull_up_tests {
                                         "corge".to_string(),
                                         InferredType::Str,
                                     )]),
+                                    SourceSpan::default(),
                                 )),
                                 "corge".to_string(),
                                 InferredType::Str,
+                                SourceSpan::default(),
                             )))],
                         ),
                         arm_resolution_expr: Box::new(Expr::SelectField(
@@ -1450,13 +1634,16 @@ mod type_pull_up_tests {
                                     "garply".to_string(),
                                     InferredType::Str,
                                 )]),
+                                SourceSpan::default(),
                             )),
                             "garply".to_string(),
                             InferredType::Str,
+                            SourceSpan::default(),
                         )),
                     },
                 ],
                 InferredType::Str,
+                SourceSpan::default(),
             )
         }
     }
@@ -24,7 +24,7 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
 
     while let Some(expr) = queue.pop_back() {
         match expr {
-            Expr::SelectField(expr, field, inferred_type) => {
+            Expr::SelectField(expr, field, inferred_type, _) => {
                 let field_type = inferred_type.clone();
                 let record_type = vec![(field.to_string(), field_type)];
                 let inferred_record_type = InferredType::Record(record_type);
@@ -33,13 +33,13 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
                 queue.push_back(expr);
             }
 
-            Expr::SelectIndex(expr, _, inferred_type) => {
+            Expr::SelectIndex(expr, _, inferred_type, _) => {
                 let field_type = inferred_type.clone();
                 let inferred_record_type = InferredType::List(Box::new(field_type));
                 expr.add_infer_type_mut(inferred_record_type);
                 queue.push_back(expr);
             }
-            Expr::Cond(cond, then, else_, inferred_type) => {
+            Expr::Cond(cond, then, else_, inferred_type, _) => {
                 then.add_infer_type_mut(inferred_type.clone());
                 else_.add_infer_type_mut(inferred_type.clone());
 
@@ -48,26 +48,26 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
                 queue.push_back(then);
                 queue.push_back(else_);
             }
-            Expr::Not(expr, inferred_type) => {
+            Expr::Not(expr, inferred_type, _) => {
                 expr.add_infer_type_mut(inferred_type.clone());
                 queue.push_back(expr);
             }
-            Expr::Option(Some(expr), inferred_type) => {
+            Expr::Option(Some(expr), inferred_type, _) => {
                 internal::handle_option(expr, inferred_type)?;
                 queue.push_back(expr);
             }
 
-            Expr::Result(Ok(expr), inferred_type) => {
+            Expr::Result(Ok(expr), inferred_type, _) => {
                 internal::handle_ok(expr, inferred_type)?;
                 queue.push_back(expr);
             }
 
-            Expr::Result(Err(expr), inferred_type) => {
+            Expr::Result(Err(expr), inferred_type, _) => {
                 internal::handle_err(expr, inferred_type)?;
                 queue.push_back(expr);
             }
 
-            Expr::PatternMatch(pred, match_arms, inferred_type) => {
+            Expr::PatternMatch(pred, match_arms, inferred_type, _) => {
                 for MatchArm {
                     arm_resolution_expr,
                     arm_pattern,
@@ -80,18 +80,18 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
                 }
             }
 
-            Expr::Tuple(exprs, inferred_type) => {
+            Expr::Tuple(exprs, inferred_type, _) => {
                 internal::handle_tuple(exprs, inferred_type, &mut queue)?;
             }
-            Expr::Sequence(expressions, inferred_type) => {
+            Expr::Sequence(expressions, inferred_type, _) => {
                 internal::handle_sequence(expressions, inferred_type, &mut queue)?;
             }
 
-            Expr::Record(expressions, inferred_type) => {
+            Expr::Record(expressions, inferred_type, _) => {
                 internal::handle_record(expressions, inferred_type, &mut queue)?;
             }
 
-            Expr::Call(call_type, expressions, inferred_type) => {
+            Expr::Call(call_type, expressions, inferred_type, _) => {
                 internal::handle_call(call_type, expressions, inferred_type, &mut queue);
             }
 
@@ -100,6 +100,7 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
                 iterable_expr,
                 yield_expr,
                 inferred_type,
+                ..
             } => {
                 handle_list_comprehension(
                     iterated_variable,
@@ -118,6 +119,7 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
                 init_value_expr,
                 yield_expr,
                 inferred_type,
+                ..
             } => {
                 handle_list_reduce(
                     reduce_variable,
@@ -141,6 +143,7 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
 
 mod internal {
     use crate::call_type::CallType;
+    use crate::type_inference::built_in_function_signature;
     use crate::type_refinement::precise_types::*;
     use crate::type_refinement::TypeRefinement;
     use crate::{ArmPattern, Expr, InferredType, VariableId};
@@ -210,7 +213,7 @@ mod internal {
 
             while let Some(expr) = queue.pop_back() {
                 match expr {
-                    Expr::Identifier(v, existing_inferred_type) => {
+                    Expr::Identifier(v, existing_inferred_type, _) => {
                         if let VariableId::ListComprehension(l) = v {
                             if l.name == variable_id.name() {
                                 *existing_inferred_type =
@@ -245,7 +248,7 @@ mod internal {
 
             while let Some(expr) = queue.pop_back() {
                 match expr {
-                    Expr::Identifier(v, existing_inferred_type) => {
+                    Expr::Identifier(v, existing_inferred_type, _) => {
                         if let VariableId::ListComprehension(l) = v {
                             if l.name == iterated_variable.name() {
                                 *existing_inferred_type =
@@ -387,6 +390,16 @@ mod internal {
                     }
                 }
             }
+            // For built-in functions, the type of an argument can depend on the types of the other arguments
+            CallType::BuiltIn(built_in_function) => {
+                let argument_types =
+                    built_in_function_signature::get_argument_types(built_in_function, expressions);
+
+                for (expr, argument_type) in expressions.iter_mut().zip(argument_types) {
+                    expr.add_infer_type_mut(argument_type);
+                    queue.push_back(expr);
+                }
+            }
             _ => {
                 for expr in expressions {
                     queue.push_back(expr);
@@ -533,7 +546,7 @@ mod internal {
 mod type_push_down_tests {
     use test_r::test;
 
-    use crate::{Expr, InferredType, VariableId};
+    use crate::{Expr, InferredType, SourceSpan, VariableId};
 
     #[test]
     fn test_push_down_for_record() {
@@ -543,6 +556,7 @@ mod type_push_down_tests {
                 InferredType::Record(vec![("titles".to_string(), InferredType::Unknown)]),
                 InferredType::Record(vec![("titles".to_string(), InferredType::U64)]),
             ]),
+            SourceSpan::default(),
         );
 
         expr.push_types_down().unwrap();
@@ -552,12 +566,14 @@ mod type_push_down_tests {
                 Box::new(Expr::Identifier(
                     VariableId::global("x".to_string()),
                     InferredType::U64,
+                    SourceSpan::default(),
                 )),
             )],
             InferredType::AllOf(vec![
                 InferredType::Record(vec![("titles".to_string(), InferredType::Unknown)]),
                 InferredType::Record(vec![("titles".to_string(), InferredType::U64)]),
             ]),
+            SourceSpan::default(),
         );
         assert_eq!(expr, expected);
     }
@@ -570,6 +586,7 @@ mod type_push_down_tests {
                 InferredType::List(Box::new(InferredType::U32)),
                 InferredType::List(Box::new(InferredType::U64)),
             ]),
+            SourceSpan::default(),
         );
 
         expr.push_types_down().unwrap();
@@ -578,16 +595,19 @@ mod type_push_down_tests {
                 Expr::Identifier(
                     VariableId::global("x".to_string()),
                     InferredType::AllOf(vec![InferredType::U32, InferredType::U64]),
+                    SourceSpan::default(),
                 ),
                 Expr::Identifier(
                     VariableId::global("y".to_string()),
                     InferredType::AllOf(vec![InferredType::U32, InferredType::U64]),
+                    SourceSpan::default(),
                 ),
             ],
             InferredType::AllOf(vec![
                 InferredType::List(Box::new(InferredType::U32)),
                 InferredType::List(Box::new(InferredType::U64)),
             ]),
+            SourceSpan::default(),
         );
         assert_eq!(expr, expected);
     }
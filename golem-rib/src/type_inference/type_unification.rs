@@ -12,29 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{ArmPattern, Expr};
+use crate::{ArmPattern, Expr, RibCompilationError};
 
-pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
+pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<RibCompilationError>> {
     let mut queue = vec![];
     queue.push(expr);
     let mut errors = vec![];
 
     while let Some(expr) = queue.pop() {
         let expr_str = &mut expr.to_string();
+        let source_span = expr.source_span();
 
         match expr {
-            Expr::Number(_, _, inferred_type) => {
+            Expr::Number(_, _, inferred_type, _) => {
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
 
-            Expr::Record(vec, inferred_type) => {
+            Expr::Record(vec, inferred_type, _) => {
                 queue.extend(vec.iter_mut().map(|(_, expr)| &mut **expr));
 
                 let unified_inferred_type = inferred_type.unify();
@@ -42,12 +43,15 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of record {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of record {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Tuple(vec, inferred_type) => {
+            Expr::Tuple(vec, inferred_type, _) => {
                 queue.extend(vec.iter_mut());
 
                 let unified_inferred_type = inferred_type.unify();
@@ -55,67 +59,76 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of tuple {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of tuple {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Sequence(vec, inferred_type) => {
+            Expr::Sequence(vec, inferred_type, _) => {
                 queue.extend(vec.iter_mut());
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of sequence {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of sequence {}", expr_str),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Option(Some(expr), inferred_type) => {
+            Expr::Option(Some(expr), inferred_type, _) => {
                 queue.push(expr);
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of option {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of option {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
 
-            Expr::Option(None, inferred_type) => {
+            Expr::Option(None, inferred_type, _) => {
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of option {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of option {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
 
-            Expr::Result(Ok(expr), inferred_type) => {
+            Expr::Result(Ok(expr), inferred_type, _) => {
                 queue.push(expr);
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of `result::ok` {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of `result::ok` {}", expr_str),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Result(Err(expr), inferred_type) => {
+            Expr::Result(Err(expr), inferred_type, _) => {
                 queue.push(expr);
 
                 let unified_inferred_type = inferred_type.unify();
@@ -123,15 +136,15 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of `result::err` {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of `result::err` {}", expr_str),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Cond(cond, then, else_, inferred_type) => {
+            Expr::Cond(cond, then, else_, inferred_type, _) => {
                 queue.push(cond);
                 queue.push(then);
                 queue.push(else_);
@@ -141,11 +154,14 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of condition expression {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!(
+                                "Unable to resolve the type of condition expression {}",
+                                expr_str
+                            ),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
@@ -164,12 +180,15 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of list comprehension {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!(
+                                "Unable to resolve the type of list comprehension {}",
+                                expr_str
+                            ),
+                            source_span,
                         ));
 
-                        errors.push(e)
+                        errors.push(RibCompilationError::new(e, source_span))
                     }
                 }
             }
@@ -190,17 +209,20 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of list aggregation {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!(
+                                "Unable to resolve the type of list aggregation {}",
+                                expr_str
+                            ),
+                            source_span,
                         ));
 
-                        errors.push(e)
+                        errors.push(RibCompilationError::new(e, source_span))
                     }
                 }
             }
 
-            Expr::PatternMatch(expr, arms, inferred_type) => {
+            Expr::PatternMatch(expr, arms, inferred_type, _) => {
                 queue.push(expr);
                 for arm in arms.iter_mut().rev() {
                     let arm_resolution_expr = &mut *arm.arm_resolution_expr;
@@ -213,15 +235,18 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of pattern match expression {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!(
+                                "Unable to resolve the type of pattern match expression {}",
+                                expr_str
+                            ),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Call(function_call, vec, inferred_type) => {
+            Expr::Call(function_call, vec, inferred_type, _) => {
                 queue.extend(vec.iter_mut());
 
                 let unified_inferred_type = inferred_type.unify();
@@ -229,88 +254,94 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of function return {}",
-                            function_call
+                        errors.push(RibCompilationError::new(
+                            format!(
+                                "Unable to resolve the type of function return {}",
+                                function_call
+                            ),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::SelectField(expr, _, inferred_type) => {
+            Expr::SelectField(expr, _, inferred_type, _) => {
                 queue.push(expr);
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of field selection {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of field selection {}", expr_str),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::SelectIndex(expr, _, inferred_type) => {
+            Expr::SelectIndex(expr, _, inferred_type, _) => {
                 queue.push(expr);
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of index selection {}",
-                            expr_str
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of index selection {}", expr_str),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
 
-            Expr::Let(_, _, expr, _) => {
+            Expr::Let(_, _, expr, _, _) => {
                 queue.push(expr);
             }
-            Expr::Literal(_, inferred_type) => {
+            Expr::Literal(_, inferred_type, _) => {
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Flags(_, inferred_type) => {
+            Expr::Flags(_, inferred_type, _) => {
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of flags {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of flags {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Identifier(_, inferred_type) => {
+            Expr::Identifier(_, inferred_type, _) => {
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!(
-                            "Unable to resolve the type of identifier {:?}",
-                            expr
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of identifier {:?}", expr),
+                            source_span,
                         ));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Boolean(_, _) => {}
-            Expr::Concat(exprs, _) => {
+            Expr::Boolean(_, _, _) => {}
+            Expr::Concat(exprs, _, _) => {
                 queue.extend(exprs);
             }
-            Expr::ExprBlock(expr, inferred_type) => {
+            Expr::ExprBlock(expr, inferred_type, _) => {
                 queue.extend(expr);
 
                 let unified_inferred_type = inferred_type.unify();
@@ -318,123 +349,185 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
 
-            Expr::Not(expr, inferred_type) => {
+            Expr::Not(expr, inferred_type, _) => {
                 queue.push(expr);
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
-            Expr::Unwrap(expr, inferred_type) => {
+            Expr::Unwrap(expr, inferred_type, _) => {
                 queue.push(expr);
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
 
-            Expr::Throw(_, inferred_type) => {
+            Expr::Throw(_, inferred_type, _) => {
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
 
-            Expr::GetTag(_, inferred_type) => {
+            Expr::Lambda(_, body, inferred_type, _) => {
+                queue.push(body);
                 let unified_inferred_type = inferred_type.unify();
 
                 match unified_inferred_type {
                     Ok(unified_type) => *inferred_type = unified_type,
                     Err(e) => {
-                        errors.push(format!("Unable to resolve the type of {}", expr_str));
-                        errors.push(e);
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
                     }
                 }
             }
 
-            Expr::GreaterThan(left, right, _) => {
+            Expr::GetTag(_, inferred_type, _) => {
+                let unified_inferred_type = inferred_type.unify();
+
+                match unified_inferred_type {
+                    Ok(unified_type) => *inferred_type = unified_type,
+                    Err(e) => {
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
+                    }
+                }
+            }
+
+            Expr::GreaterThan(left, right, _, _) => {
                 queue.push(left);
                 queue.push(right);
             }
 
-            Expr::Plus(left, right, inferred_type) => internal::handle_math_op(
+            Expr::Plus(left, right, inferred_type, _) => internal::handle_math_op(
                 &mut queue,
                 left,
                 right,
                 inferred_type,
                 &mut errors,
                 expr_str,
+                source_span,
             ),
 
-            Expr::Minus(left, right, inferred_type) => internal::handle_math_op(
+            Expr::Minus(left, right, inferred_type, _) => internal::handle_math_op(
                 &mut queue,
                 left,
                 right,
                 inferred_type,
                 &mut errors,
                 expr_str,
+                source_span,
             ),
 
-            Expr::Divide(left, right, inferred_type) => internal::handle_math_op(
+            Expr::Divide(left, right, inferred_type, _) => internal::handle_math_op(
                 &mut queue,
                 left,
                 right,
                 inferred_type,
                 &mut errors,
                 expr_str,
+                source_span,
             ),
 
-            Expr::Multiply(left, right, inferred_type) => internal::handle_math_op(
+            Expr::Multiply(left, right, inferred_type, _) => internal::handle_math_op(
                 &mut queue,
                 left,
                 right,
                 inferred_type,
                 &mut errors,
                 expr_str,
+                source_span,
             ),
 
-            Expr::And(left, right, _) => {
+            Expr::Modulo(left, right, inferred_type, _) => internal::handle_math_op(
+                &mut queue,
+                left,
+                right,
+                inferred_type,
+                &mut errors,
+                expr_str,
+                source_span,
+            ),
+
+            Expr::Negative(expr, inferred_type, _) => {
+                queue.push(expr);
+                let unified_inferred_type = inferred_type.unify();
+
+                match unified_inferred_type {
+                    Ok(unified_type) => *inferred_type = unified_type,
+                    Err(e) => {
+                        errors.push(RibCompilationError::new(
+                            format!("Unable to resolve the type of {}", expr_str),
+                            source_span,
+                        ));
+                        errors.push(RibCompilationError::new(e, source_span));
+                    }
+                }
+            }
+
+            Expr::And(left, right, _, _) => {
                 queue.push(left);
                 queue.push(right);
             }
-            Expr::Or(left, right, _) => {
+            Expr::Or(left, right, _, _) => {
                 queue.push(left);
                 queue.push(right);
             }
 
-            Expr::GreaterThanOrEqualTo(left, right, _) => {
+            Expr::GreaterThanOrEqualTo(left, right, _, _) => {
+                queue.push(left);
+                queue.push(right);
+            }
+            Expr::LessThanOrEqualTo(left, right, _, _) => {
                 queue.push(left);
                 queue.push(right);
             }
-            Expr::LessThanOrEqualTo(left, right, _) => {
+            Expr::EqualTo(left, right, _, _) => {
                 queue.push(left);
                 queue.push(right);
             }
-            Expr::EqualTo(left, right, _) => {
+            Expr::NotEqualTo(left, right, _, _) => {
                 queue.push(left);
                 queue.push(right);
             }
-            Expr::LessThan(left, right, _) => {
+            Expr::LessThan(left, right, _, _) => {
                 queue.push(left);
                 queue.push(right);
             }
@@ -449,15 +542,16 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
 }
 
 mod internal {
-    use crate::{ArmPattern, Expr, InferredType};
+    use crate::{ArmPattern, Expr, InferredType, RibCompilationError, SourceSpan};
 
     pub(crate) fn handle_math_op<'a>(
         queue: &mut Vec<&'a mut Expr>,
         left: &'a mut Expr,
         right: &'a mut Expr,
         inferred_type: &mut InferredType,
-        errors: &mut Vec<String>,
+        errors: &mut Vec<RibCompilationError>,
         expr_str: &str,
+        source_span: SourceSpan,
     ) {
         queue.push(left);
         queue.push(right);
@@ -466,8 +560,11 @@ mod internal {
         match unified_inferred_type {
             Ok(unified_type) => *inferred_type = unified_type,
             Err(e) => {
-                errors.push(format!("Unable to resolve the type of {}", expr_str));
-                errors.push(e);
+                errors.push(RibCompilationError::new(
+                    format!("Unable to resolve the type of {}", expr_str),
+                    source_span,
+                ));
+                errors.push(RibCompilationError::new(e, source_span));
             }
         }
     }
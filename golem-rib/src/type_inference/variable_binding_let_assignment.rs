@@ -23,14 +23,14 @@ pub fn bind_variables_of_let_assignment(expr: &mut Expr) {
     // Start from the end
     while let Some(expr) = queue.pop_front() {
         match expr {
-            Expr::Let(variable_id, _, expr, _) => {
+            Expr::Let(variable_id, _, expr, _, _) => {
                 let field_name = variable_id.name();
                 identifier_id_state.update_variable_id(&field_name); // Increment the variable_id
                 *variable_id = identifier_id_state.lookup(&field_name).unwrap();
                 queue.push_front(expr);
             }
 
-            Expr::Identifier(variable_id, _) if !variable_id.is_match_binding() => {
+            Expr::Identifier(variable_id, _, _) if !variable_id.is_match_binding() => {
                 let field_name = variable_id.name();
                 if let Some(latest_variable_id) = identifier_id_state.lookup(&field_name) {
                     // If there existed a let statement, this ensures global is changed to local
@@ -77,7 +77,7 @@ mod name_binding_tests {
 
     use crate::call_type::CallType;
     use crate::function_name::{DynamicParsedFunctionName, DynamicParsedFunctionReference};
-    use crate::{Expr, InferredType, ParsedFunctionSite, VariableId};
+    use crate::{Expr, InferredType, ParsedFunctionSite, SourceSpan, VariableId};
 
     #[test]
     fn test_name_binding_simple() {
@@ -96,6 +96,7 @@ mod name_binding_tests {
             None,
             Box::new(Expr::untyped_number(1f64)),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let call_expr = Expr::Call(
@@ -108,8 +109,10 @@ mod name_binding_tests {
             vec![Expr::Identifier(
                 VariableId::local("x", 0),
                 InferredType::Unknown,
+                SourceSpan::default(),
             )],
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let expected = Expr::expr_block(vec![let_binding, call_expr]);
@@ -136,6 +139,7 @@ mod name_binding_tests {
             None,
             Box::new(Expr::untyped_number(1f64)),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let let_binding2 = Expr::Let(
@@ -143,6 +147,7 @@ mod name_binding_tests {
             None,
             Box::new(Expr::untyped_number(2f64)),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let call_expr1 = Expr::Call(
@@ -155,8 +160,10 @@ mod name_binding_tests {
             vec![Expr::Identifier(
                 VariableId::local("x", 0),
                 InferredType::Unknown,
+                SourceSpan::default(),
             )],
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let call_expr2 = Expr::Call(
@@ -169,8 +176,10 @@ mod name_binding_tests {
             vec![Expr::Identifier(
                 VariableId::local("y", 0),
                 InferredType::Unknown,
+                SourceSpan::default(),
             )],
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let expected = Expr::expr_block(vec![let_binding1, let_binding2, call_expr1, call_expr2]);
@@ -197,6 +206,7 @@ mod name_binding_tests {
             None,
             Box::new(Expr::untyped_number(1f64)),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let let_binding2 = Expr::Let(
@@ -204,6 +214,7 @@ mod name_binding_tests {
             None,
             Box::new(Expr::untyped_number(2f64)),
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let call_expr1 = Expr::Call(
@@ -216,8 +227,10 @@ mod name_binding_tests {
             vec![Expr::Identifier(
                 VariableId::local("x", 0),
                 InferredType::Unknown,
+                SourceSpan::default(),
             )],
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let call_expr2 = Expr::Call(
@@ -230,8 +243,10 @@ mod name_binding_tests {
             vec![Expr::Identifier(
                 VariableId::local("x", 1),
                 InferredType::Unknown,
+                SourceSpan::default(),
             )],
             InferredType::Unknown,
+            SourceSpan::default(),
         );
 
         let expected = Expr::expr_block(vec![let_binding1, call_expr1, let_binding2, call_expr2]);
@@ -52,7 +52,7 @@ mod internal {
 
         while let Some(expr) = queue.pop_front() {
             match expr {
-                Expr::Identifier(variable_in_yield, _) => {
+                Expr::Identifier(variable_in_yield, _, _) => {
                     if variable_id.name() == variable_in_yield.name() {
                         *variable_in_yield = variable_id.clone();
                     }
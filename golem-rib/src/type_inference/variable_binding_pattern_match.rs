@@ -40,7 +40,7 @@ mod internal {
         // Start from the end
         while let Some(expr) = queue.pop_front() {
             match expr {
-                Expr::PatternMatch(expr, match_arms, _) => {
+                Expr::PatternMatch(expr, match_arms, _, _) => {
                     queue.push_front(expr);
                     for arm in match_arms {
                         // We increment the index for each arm regardless of whether there is an identifier exist or not
@@ -51,11 +51,11 @@ mod internal {
                         index = latest
                     }
                 }
-                Expr::Let(variable_id, _, expr, _) => {
+                Expr::Let(variable_id, _, expr, _, _) => {
                     queue.push_front(expr);
                     shadowed_let_binding.push(variable_id.name());
                 }
-                Expr::Identifier(variable_id, _) => {
+                Expr::Identifier(variable_id, _, _) => {
                     let identifier_name = variable_id.name();
                     if let Some(x) = match_identifiers.iter().find(|x| x.name == identifier_name) {
                         if !shadowed_let_binding.contains(&identifier_name) {
@@ -148,7 +148,7 @@ mod internal {
 
         while let Some(expr) = queue.pop_front() {
             match expr {
-                Expr::Identifier(variable_id, _) => {
+                Expr::Identifier(variable_id, _, _) => {
                     let match_identifier =
                         MatchIdentifier::new(variable_id.name(), global_arm_index);
                     identifier_names.push(match_identifier);
@@ -171,7 +171,7 @@ mod internal {
 mod pattern_match_bindings {
     use test_r::test;
 
-    use crate::{Expr, InferredType};
+    use crate::{Expr, InferredType, SourceSpan};
     use expectations::*;
 
     #[test]
@@ -233,7 +233,11 @@ mod pattern_match_bindings {
         let first_expr = expected_match(1);
         let second_expr = expected_match(3); // 3 because first block has 2 arms
 
-        let block = Expr::ExprBlock(vec![first_expr, second_expr], InferredType::Unknown);
+        let block = Expr::ExprBlock(
+            vec![first_expr, second_expr],
+            InferredType::Unknown,
+            SourceSpan::default(),
+        );
 
         assert_eq!(expr, block);
     }
@@ -258,6 +262,7 @@ mod pattern_match_bindings {
     }
 
     mod expectations {
+        use crate::SourceSpan;
         use crate::{ArmPattern, Expr, InferredType, MatchArm, MatchIdentifier, VariableId};
 
         pub(crate) fn expected_match(index: usize) -> Expr {
@@ -266,8 +271,10 @@ mod pattern_match_bindings {
                     Some(Box::new(Expr::Identifier(
                         VariableId::Global("x".to_string()),
                         InferredType::Unknown,
+                        SourceSpan::default(),
                     ))),
                     InferredType::Option(Box::new(InferredType::Unknown)),
+                    SourceSpan::default(),
                 )),
                 vec![
                     MatchArm {
@@ -279,6 +286,7 @@ mod pattern_match_bindings {
                                     index,
                                 )),
                                 InferredType::Unknown,
+                                SourceSpan::default(),
                             ))],
                         ),
                         arm_resolution_expr: Box::new(Expr::Identifier(
@@ -287,6 +295,7 @@ mod pattern_match_bindings {
                                 index,
                             )),
                             InferredType::Unknown,
+                            SourceSpan::default(),
                         )),
                     },
                     MatchArm {
@@ -295,14 +304,22 @@ mod pattern_match_bindings {
                     },
                 ],
                 InferredType::Unknown,
+                SourceSpan::default(),
             )
         }
 
         pub(crate) fn expected_match_with_let_binding(index: usize) -> Expr {
             let let_binding = Expr::let_binding("x", Expr::untyped_number(1f64));
-            let identifier_expr =
-                Expr::Identifier(VariableId::Global("x".to_string()), InferredType::Unknown);
-            let block = Expr::ExprBlock(vec![let_binding, identifier_expr], InferredType::Unknown);
+            let identifier_expr = Expr::Identifier(
+                VariableId::Global("x".to_string()),
+                InferredType::Unknown,
+                SourceSpan::default(),
+            );
+            let block = Expr::ExprBlock(
+                vec![let_binding, identifier_expr],
+                InferredType::Unknown,
+                SourceSpan::default(),
+            );
 
             Expr::PatternMatch(
                 Box::new(Expr::option(Some(Expr::identifier("x")))), // x is still global
@@ -316,6 +333,7 @@ mod pattern_match_bindings {
                                     index,
                                 )),
                                 InferredType::Unknown,
+                                SourceSpan::default(),
                             ))],
                         ),
                         arm_resolution_expr: Box::new(block),
@@ -326,6 +344,7 @@ mod pattern_match_bindings {
                     },
                 ],
                 InferredType::Unknown,
+                SourceSpan::default(),
             )
         }
 
@@ -336,8 +355,10 @@ mod pattern_match_bindings {
                         Some(Box::new(Expr::Identifier(
                             VariableId::Global("x".to_string()),
                             InferredType::Unknown,
+                            SourceSpan::default(),
                         ))),
                         InferredType::Option(Box::new(InferredType::Unknown)),
+                        SourceSpan::default(),
                     ))),
                     InferredType::Result {
                         ok: Some(Box::new(InferredType::Option(Box::new(
@@ -345,6 +366,7 @@ mod pattern_match_bindings {
                         )))),
                         error: Some(Box::new(InferredType::Unknown)),
                     },
+                    SourceSpan::default(),
                 )),
                 vec![
                     MatchArm {
@@ -356,6 +378,7 @@ mod pattern_match_bindings {
                                     1,
                                 )),
                                 InferredType::Unknown,
+                                SourceSpan::default(),
                             ))],
                         ),
                         arm_resolution_expr: Box::new(Expr::PatternMatch(
@@ -365,6 +388,7 @@ mod pattern_match_bindings {
                                     1,
                                 )),
                                 InferredType::Unknown,
+                                SourceSpan::default(),
                             )),
                             vec![
                                 MatchArm {
@@ -376,6 +400,7 @@ mod pattern_match_bindings {
                                                 2,
                                             )),
                                             InferredType::Unknown,
+                                            SourceSpan::default(),
                                         ))],
                                     ),
                                     arm_resolution_expr: Box::new(Expr::Identifier(
@@ -384,6 +409,7 @@ mod pattern_match_bindings {
                                             2,
                                         )),
                                         InferredType::Unknown,
+                                        SourceSpan::default(),
                                     )),
                                 },
                                 MatchArm {
@@ -392,6 +418,7 @@ mod pattern_match_bindings {
                                 },
                             ],
                             InferredType::Unknown,
+                            SourceSpan::default(),
                         )),
                     },
                     MatchArm {
@@ -403,12 +430,14 @@ mod pattern_match_bindings {
                                     4,
                                 )),
                                 InferredType::Unknown,
+                                SourceSpan::default(),
                             ))],
                         ),
                         arm_resolution_expr: Box::new(Expr::untyped_number(0f64)),
                     },
                 ],
                 InferredType::Unknown,
+                SourceSpan::default(),
             )
         }
     }
@@ -40,12 +40,18 @@ mod internal {
 
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::Call(CallType::Function(parsed_function_name), args, inferred_type) => {
+                Expr::Call(
+                    CallType::Function(parsed_function_name),
+                    args,
+                    inferred_type,
+                    source_span,
+                ) => {
                     if variants.contains(&parsed_function_name.to_string()) {
                         *expr = Expr::Call(
                             CallType::VariantConstructor(parsed_function_name.to_string()),
                             args.clone(),
                             inferred_type.clone(),
+                            *source_span,
                         );
                     }
                 }
@@ -65,12 +71,13 @@ mod internal {
 
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::Identifier(variable_id, inferred_type) => {
+                Expr::Identifier(variable_id, inferred_type, source_span) => {
                     if variants.contains(&variable_id.name()) {
                         *expr = Expr::Call(
                             CallType::VariantConstructor(variable_id.name()),
                             vec![],
                             inferred_type.clone(),
+                            *source_span,
                         );
                     }
                 }
@@ -90,7 +97,7 @@ mod internal {
 
         while let Some(expr) = queue.pop_back() {
             match expr {
-                Expr::Identifier(variable_id, inferred_type) => {
+                Expr::Identifier(variable_id, inferred_type, source_span) => {
                     let key = RegistryKey::FunctionName(variable_id.name().clone());
                     if let Some(RegistryValue::Value(AnalysedType::Variant(type_variant))) =
                         function_type_registry.types.get(&key)
@@ -101,7 +108,7 @@ mod internal {
                     }
                 }
 
-                Expr::Call(CallType::Function(parsed_function_name), exprs, inferred_type) => {
+                Expr::Call(CallType::Function(parsed_function_name), exprs, inferred_type, _) => {
                     let key = RegistryKey::FunctionName(parsed_function_name.to_string());
                     if let Some(RegistryValue::Variant { variant_type, .. }) =
                         function_type_registry.types.get(&key)
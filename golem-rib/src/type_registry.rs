@@ -72,6 +72,7 @@ impl FunctionTypeRegistry {
             CallType::EnumConstructor(enum_name) => self
                 .types
                 .get(&RegistryKey::FunctionName(enum_name.clone())),
+            CallType::BuiltIn(_) => None,
         }
     }
 
@@ -274,6 +275,9 @@ impl RegistryKey {
                 RegistryKey::FunctionName(variant_name.clone())
             }
             CallType::EnumConstructor(enum_name) => RegistryKey::FunctionName(enum_name.clone()),
+            CallType::BuiltIn(built_in_function) => {
+                RegistryKey::FunctionName(built_in_function.name().to_string())
+            }
             CallType::Function(function_name) => match function_name.site.interface_name() {
                 None => {
                     RegistryKey::FunctionName(function_name.function_name_with_prefix_identifiers())
@@ -17,6 +17,7 @@ use golem_api_grpc::proto::golem::worker::OplogEntryWithIndex;
 use golem_common::model::component_metadata::ComponentMetadata;
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
+use golem_common::model::scheduled_invocation::{Recurrence, ScheduledInvocation};
 use golem_common::model::AccountId;
 use golem_common::model::{
     ComponentFilePermissions, ComponentFileSystemNode, ComponentFileSystemNodeDetails, ComponentId,
@@ -1022,6 +1023,38 @@ impl From<GolemErrorFileSystemError> for golem_api_grpc::proto::golem::worker::v
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, thiserror::Error)]
+#[error("Resource limit exceeded: {details}")]
+pub struct GolemErrorResourceLimitExceeded {
+    pub details: String,
+}
+
+impl SafeDisplay for GolemErrorResourceLimitExceeded {
+    fn to_safe_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::worker::v1::ResourceLimitExceeded>
+    for GolemErrorResourceLimitExceeded
+{
+    fn from(value: golem_api_grpc::proto::golem::worker::v1::ResourceLimitExceeded) -> Self {
+        Self {
+            details: value.details,
+        }
+    }
+}
+
+impl From<GolemErrorResourceLimitExceeded>
+    for golem_api_grpc::proto::golem::worker::v1::ResourceLimitExceeded
+{
+    fn from(value: GolemErrorResourceLimitExceeded) -> Self {
+        Self {
+            details: value.details,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object, thiserror::Error)]
 #[error("Invalid account")]
 pub struct GolemErrorShardingNotReady {}
@@ -1068,6 +1101,78 @@ pub struct ResumeResponse {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 pub struct UpdateWorkerResponse {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct ForkWorkerResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct RevertWorkerResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct CancelInvocationResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct ImportWorkerResponse {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ScheduleInvocationParameters {
+    pub name: String,
+    pub function_name: String,
+    pub params: Vec<TypeAnnotatedValue>,
+    pub recurrence: Recurrence,
+    /// Time of the first invocation. If not set, it is calculated from the recurrence.
+    pub start_at: Option<Timestamp>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ScheduledInvocationInfo {
+    pub name: String,
+    pub function_name: String,
+    pub recurrence: Recurrence,
+    pub next_fire_at: Timestamp,
+    pub failed_attempts: u32,
+    pub last_error: Option<String>,
+}
+
+impl From<ScheduledInvocation> for ScheduledInvocationInfo {
+    fn from(value: ScheduledInvocation) -> Self {
+        Self {
+            name: value.name,
+            function_name: value.full_function_name,
+            recurrence: value.recurrence,
+            next_fire_at: value.next_fire_at,
+            failed_attempts: value.failed_attempts,
+            last_error: value.last_error,
+        }
+    }
+}
+
+impl From<ScheduledInvocationInfo> for golem_api_grpc::proto::golem::worker::ScheduledInvocation {
+    fn from(value: ScheduledInvocationInfo) -> Self {
+        Self {
+            name: value.name,
+            function_name: value.function_name,
+            input: Vec::new(),
+            recurrence: Some(value.recurrence.into()),
+            next_fire_at: Some(value.next_fire_at.into()),
+            idempotency_key: None,
+            failed_attempts: value.failed_attempts,
+            last_error: value.last_error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ScheduledInvocationsResponse {
+    pub invocations: Vec<ScheduledInvocationInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct DeleteScheduledInvocationResponse {}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -1201,6 +1306,14 @@ pub struct UpdateWorkerRequest {
     pub target_version: ComponentVersion,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ForkWorkerRequest {
+    pub target_worker_name: String,
+    pub oplog_index_cutoff: OplogIndex,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
 pub struct WorkersMetadataRequest {
     pub filter: Option<WorkerFilter>,
@@ -1232,6 +1345,7 @@ pub struct WorkerMetadata {
     pub component_size: u64,
     pub total_linear_memory_size: u64,
     pub owned_resources: HashMap<u64, ResourceMetadata>,
+    pub scheduled_invocations: Vec<ScheduledInvocationInfo>,
 }
 
 impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMetadata {
@@ -1262,6 +1376,11 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMet
                 .into_iter()
                 .map(|(k, v)| v.try_into().map(|v| (k, v)))
                 .collect::<Result<HashMap<_, _>, _>>()?,
+            scheduled_invocations: value
+                .scheduled_invocations
+                .into_iter()
+                .map(|invocation| ScheduledInvocation::try_from(invocation).map(|i| i.into()))
+                .collect::<Result<Vec<_>, _>>()?,
         })
     }
 }
@@ -1287,6 +1406,12 @@ impl From<WorkerMetadata> for golem_api_grpc::proto::golem::worker::WorkerMetada
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            scheduled_invocations: value
+                .scheduled_invocations
+                .into_iter()
+                .map(|invocation| invocation.into())
+                .collect(),
+            latest_snapshot: None,
         }
     }
 }
@@ -1514,6 +1639,8 @@ pub enum GolemError {
     InitialComponentFileDownloadFailed(GolemErrorInitialComponentFileDownloadFailed),
     #[error(transparent)]
     FileSystemError(GolemErrorFileSystemError),
+    #[error(transparent)]
+    ResourceLimitExceeded(GolemErrorResourceLimitExceeded),
 }
 
 impl SafeDisplay for GolemError {
@@ -1544,6 +1671,7 @@ impl SafeDisplay for GolemError {
             GolemError::ShardingNotReady(inner) => inner.to_safe_string(),
             GolemError::InitialComponentFileDownloadFailed(inner) => inner.to_safe_string(),
             GolemError::FileSystemError(inner) => inner.to_safe_string(),
+            GolemError::ResourceLimitExceeded(inner) => inner.to_safe_string(),
         }
     }
 }
@@ -1634,6 +1762,9 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::v1::WorkerExecutionError> for
             Some(golem_api_grpc::proto::golem::worker::v1::worker_execution_error::Error::FileSystemError(err)) => {
                 Ok(GolemError::FileSystemError(err.into()))
             }
+            Some(golem_api_grpc::proto::golem::worker::v1::worker_execution_error::Error::ResourceLimitExceeded(err)) => {
+                Ok(GolemError::ResourceLimitExceeded(err.into()))
+            }
             None => Err("Missing field: error".to_string()),
         }
     }
@@ -1725,6 +1856,9 @@ impl From<GolemError> for golem_api_grpc::proto::golem::worker::v1::worker_execu
             GolemError::FileSystemError(err) => {
                 golem_api_grpc::proto::golem::worker::v1::worker_execution_error::Error::FileSystemError(err.into())
             }
+            GolemError::ResourceLimitExceeded(err) => {
+                golem_api_grpc::proto::golem::worker::v1::worker_execution_error::Error::ResourceLimitExceeded(err.into())
+            }
         }
     }
 }
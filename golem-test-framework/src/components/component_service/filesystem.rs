@@ -16,7 +16,7 @@ use crate::components::component_service::{AddComponentError, ComponentService};
 use async_trait::async_trait;
 use golem_api_grpc::proto::golem::component::v1::component_service_client::ComponentServiceClient;
 use golem_common::model::{
-    component_metadata::{LinearMemory, RawComponentMetadata},
+    component_metadata::{LinearMemory, RawComponentMetadata, WorkerResourceLimits},
     ComponentId, ComponentType, ComponentVersion, InitialComponentFile,
 };
 use golem_wasm_ast::analysis::AnalysedExport;
@@ -48,6 +48,7 @@ impl FileSystemComponentService {
         component_version: ComponentVersion,
         component_type: ComponentType,
         files: &[InitialComponentFile],
+        limits: Option<WorkerResourceLimits>,
         skip_analysis: bool,
     ) -> Result<ComponentId, AddComponentError> {
         let target_dir = &self.root;
@@ -98,6 +99,7 @@ impl FileSystemComponentService {
             size,
             memories,
             exports,
+            limits: limits.unwrap_or_default(),
         };
         metadata
             .write_to_file(&target_dir.join(format!("{component_id}-{component_version}.json")))
@@ -158,6 +160,7 @@ impl ComponentService for FileSystemComponentService {
             0,
             component_type,
             &[],
+            None,
             true,
         )
         .await
@@ -170,8 +173,16 @@ impl ComponentService for FileSystemComponentService {
         component_id: &ComponentId,
         component_type: ComponentType,
     ) -> Result<(), AddComponentError> {
-        self.write_component_to_filesystem(local_path, component_id, 0, component_type, &[], false)
-            .await?;
+        self.write_component_to_filesystem(
+            local_path,
+            component_id,
+            0,
+            component_type,
+            &[],
+            None,
+            false,
+        )
+        .await?;
         Ok(())
     }
 
@@ -186,6 +197,7 @@ impl ComponentService for FileSystemComponentService {
             0,
             component_type,
             &[],
+            None,
             false,
         )
         .await
@@ -203,6 +215,7 @@ impl ComponentService for FileSystemComponentService {
             0,
             component_type,
             &[],
+            None,
             false,
         )
         .await
@@ -221,6 +234,26 @@ impl ComponentService for FileSystemComponentService {
             0,
             component_type,
             files,
+            None,
+            false,
+        )
+        .await
+    }
+
+    async fn add_component_with_limits(
+        &self,
+        local_path: &Path,
+        _name: &str,
+        component_type: ComponentType,
+        limits: &WorkerResourceLimits,
+    ) -> Result<ComponentId, AddComponentError> {
+        self.write_component_to_filesystem(
+            local_path,
+            &ComponentId(Uuid::new_v4()),
+            0,
+            component_type,
+            &[],
+            Some(limits.clone()),
             false,
         )
         .await
@@ -253,6 +286,7 @@ impl ComponentService for FileSystemComponentService {
             new_version,
             component_type,
             &[],
+            None,
             false,
         )
         .await
@@ -308,6 +342,7 @@ pub struct ComponentMetadata {
     pub exports: Vec<AnalysedExport>,
     pub component_type: ComponentType,
     pub files: Vec<InitialComponentFile>,
+    pub limits: WorkerResourceLimits,
 }
 
 impl ComponentMetadata {
@@ -36,6 +36,7 @@ use tonic::transport::Channel;
 use tracing::{debug, info, Level};
 
 use golem_api_grpc::proto::golem::component::v1::component_service_client::ComponentServiceClient;
+use golem_common::model::component_metadata::WorkerResourceLimits;
 use golem_common::model::{ComponentId, ComponentType, InitialComponentFile};
 
 use crate::components::rdb::Rdb;
@@ -180,80 +181,33 @@ pub trait ComponentService {
         component_type: ComponentType,
         files: &[InitialComponentFile],
     ) -> Result<ComponentId, AddComponentError> {
-        let mut client = self.client().await;
-        let mut file = File::open(local_path).await.map_err(|_| {
-            AddComponentError::Other(format!("Failed to read component from {local_path:?}"))
-        })?;
-
-        let component_type: golem_api_grpc::proto::golem::component::ComponentType =
-            component_type.into();
-
-        let files = files.iter().map(|f| f.clone().into()).collect();
-
-        let mut chunks: Vec<CreateComponentRequest> = vec![CreateComponentRequest {
-            data: Some(Data::Header(CreateComponentRequestHeader {
-                project_id: None,
-                component_name: name.to_string(),
-                component_type: Some(component_type as i32),
-                files,
-            })),
-        }];
-
-        loop {
-            let mut buffer = [0; 4096];
-
-            let n = file.read(&mut buffer).await.map_err(|_| {
-                AddComponentError::Other(format!("Failed to read component from {local_path:?}"))
-            })?;
+        create_component(
+            self.client().await,
+            local_path,
+            name,
+            component_type,
+            files,
+            None,
+        )
+        .await
+    }
 
-            if n == 0 {
-                break;
-            } else {
-                chunks.push(CreateComponentRequest {
-                    data: Some(Data::Chunk(CreateComponentRequestChunk {
-                        component_chunk: buffer[0..n].to_vec(),
-                    })),
-                });
-            }
-        }
-        let response = client
-            .create_component(tokio_stream::iter(chunks))
-            .await
-            .map_err(|status| {
-                AddComponentError::Other(format!("Failed to call create_component: {status:?}"))
-            })?
-            .into_inner();
-        match response.result {
-            None => Err(AddComponentError::Other(
-                "Missing response from golem-component-service for create-component".to_string(),
-            )),
-            Some(create_component_response::Result::Success(component)) => {
-                info!("Created component {component:?}");
-                Ok(component
-                    .versioned_component_id
-                    .ok_or(AddComponentError::Other(
-                        "Missing versioned_component_id field".to_string(),
-                    ))?
-                    .component_id
-                    .ok_or(AddComponentError::Other(
-                        "Missing component_id field".to_string(),
-                    ))?
-                    .try_into()
-                    .map_err(|error| {
-                        AddComponentError::Other(format!(
-                            "component_id has unexpected format: {error}"
-                        ))
-                    })?)
-            }
-            Some(create_component_response::Result::Error(error)) => match error.error {
-                Some(component_error::Error::AlreadyExists(_)) => {
-                    Err(AddComponentError::AlreadyExists)
-                }
-                _ => Err(AddComponentError::Other(format!(
-                    "Failed to create component in golem-component-service: {error:?}"
-                ))),
-            },
-        }
+    async fn add_component_with_limits(
+        &self,
+        local_path: &Path,
+        name: &str,
+        component_type: ComponentType,
+        limits: &WorkerResourceLimits,
+    ) -> Result<ComponentId, AddComponentError> {
+        create_component(
+            self.client().await,
+            local_path,
+            name,
+            component_type,
+            &[],
+            Some(limits.clone()),
+        )
+        .await
     }
 
     async fn update_component(
@@ -296,6 +250,7 @@ pub trait ComponentService {
                     component_type: Some(component_type as i32),
                     update_files,
                     files,
+                    limits: None,
                 },
             )),
         }];
@@ -386,6 +341,86 @@ pub trait ComponentService {
     async fn kill(&self);
 }
 
+async fn create_component(
+    mut client: ComponentServiceClient<Channel>,
+    local_path: &Path,
+    name: &str,
+    component_type: ComponentType,
+    files: &[InitialComponentFile],
+    limits: Option<WorkerResourceLimits>,
+) -> Result<ComponentId, AddComponentError> {
+    let mut file = File::open(local_path).await.map_err(|_| {
+        AddComponentError::Other(format!("Failed to read component from {local_path:?}"))
+    })?;
+
+    let component_type: golem_api_grpc::proto::golem::component::ComponentType =
+        component_type.into();
+
+    let files = files.iter().map(|f| f.clone().into()).collect();
+
+    let mut chunks: Vec<CreateComponentRequest> = vec![CreateComponentRequest {
+        data: Some(Data::Header(CreateComponentRequestHeader {
+            project_id: None,
+            component_name: name.to_string(),
+            component_type: Some(component_type as i32),
+            files,
+            limits: limits.map(|limits| limits.into()),
+        })),
+    }];
+
+    loop {
+        let mut buffer = [0; 4096];
+
+        let n = file.read(&mut buffer).await.map_err(|_| {
+            AddComponentError::Other(format!("Failed to read component from {local_path:?}"))
+        })?;
+
+        if n == 0 {
+            break;
+        } else {
+            chunks.push(CreateComponentRequest {
+                data: Some(Data::Chunk(CreateComponentRequestChunk {
+                    component_chunk: buffer[0..n].to_vec(),
+                })),
+            });
+        }
+    }
+    let response = client
+        .create_component(tokio_stream::iter(chunks))
+        .await
+        .map_err(|status| {
+            AddComponentError::Other(format!("Failed to call create_component: {status:?}"))
+        })?
+        .into_inner();
+    match response.result {
+        None => Err(AddComponentError::Other(
+            "Missing response from golem-component-service for create-component".to_string(),
+        )),
+        Some(create_component_response::Result::Success(component)) => {
+            info!("Created component {component:?}");
+            Ok(component
+                .versioned_component_id
+                .ok_or(AddComponentError::Other(
+                    "Missing versioned_component_id field".to_string(),
+                ))?
+                .component_id
+                .ok_or(AddComponentError::Other(
+                    "Missing component_id field".to_string(),
+                ))?
+                .try_into()
+                .map_err(|error| {
+                    AddComponentError::Other(format!("component_id has unexpected format: {error}"))
+                })?)
+        }
+        Some(create_component_response::Result::Error(error)) => match error.error {
+            Some(component_error::Error::AlreadyExists(_)) => Err(AddComponentError::AlreadyExists),
+            _ => Err(AddComponentError::Other(format!(
+                "Failed to create component in golem-component-service: {error:?}"
+            ))),
+        },
+    }
+}
+
 async fn new_client(host: &str, grpc_port: u16) -> ComponentServiceClient<Channel> {
     ComponentServiceClient::connect(format!("http://{host}:{grpc_port}"))
         .await
@@ -23,15 +23,21 @@ use async_trait::async_trait;
 use golem_api_grpc::proto::golem::common::{Empty, ResourceLimits};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::v1::{
-    ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse, GetFileContentsRequest,
-    GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse, GetWorkerMetadataRequest,
-    GetWorkerMetadataResponse, InterruptWorkerRequest, InterruptWorkerResponse,
-    InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest,
-    InvokeAndAwaitResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse,
-    LaunchNewWorkerRequest, LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse,
-    ListDirectoryRequest, ListDirectoryResponse, ListDirectorySuccessResponse, ResumeWorkerRequest,
-    ResumeWorkerResponse, SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse,
-    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
+    CancelInvocationRequest, CancelInvocationResponse, ConnectWorkerRequest,
+    CreateScheduledInvocationRequest, CreateScheduledInvocationResponse,
+    DeleteScheduledInvocationRequest, DeleteScheduledInvocationResponse, DeleteWorkerRequest,
+    DeleteWorkerResponse, ExportWorkerRequest, ExportWorkerResponse, ForkWorkerRequest,
+    ForkWorkerResponse, GetFileContentsRequest, GetOplogRequest, GetOplogResponse,
+    GetOplogSuccessResponse, GetScheduledInvocationsRequest, GetScheduledInvocationsResponse,
+    GetWorkerMetadataRequest, GetWorkerMetadataResponse, ImportWorkerRequest, ImportWorkerResponse,
+    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
+    InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest,
+    InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
+    LaunchNewWorkerSuccessResponse, ListDirectoryRequest, ListDirectoryResponse,
+    ListDirectorySuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest,
+    RevertWorkerResponse, SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse,
+    UpdateScheduledInvocationRequest, UpdateScheduledInvocationResponse, UpdateWorkerRequest,
+    UpdateWorkerResponse, WorkerError,
 };
 use golem_api_grpc::proto::golem::worker::{InvokeResult, LogEvent, WorkerId};
 use golem_api_grpc::proto::golem::workerexecutor::v1::CreateWorkerRequest;
@@ -294,6 +300,7 @@ impl WorkerService for ForwardingWorkerService {
                         max_memory_per_worker: i64::MAX,
                     }),
                     context: request.context.clone(),
+                    schedule_at: request.schedule_at,
                 })
                 .await;
 
@@ -708,6 +715,488 @@ impl WorkerService for ForwardingWorkerService {
         }
     }
 
+    async fn fork_worker(&self, request: ForkWorkerRequest) -> crate::Result<ForkWorkerResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .fork_worker(workerexecutor::v1::ForkWorkerRequest {
+                    source_worker_id: request.source_worker_id.clone(),
+                    target_worker_id: request.target_worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    oplog_index_cutoff: request.oplog_index_cutoff,
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor fork-worker call"
+            )),
+            Some(workerexecutor::v1::fork_worker_response::Result::Success(_)) => {
+                Ok(ForkWorkerResponse {
+                    result: Some(worker::v1::fork_worker_response::Result::Success(Empty {})),
+                })
+            }
+            Some(workerexecutor::v1::fork_worker_response::Result::Failure(error)) => {
+                Ok(ForkWorkerResponse {
+                    result: Some(worker::v1::fork_worker_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+        }
+    }
+
+    async fn revert_worker(
+        &self,
+        request: RevertWorkerRequest,
+    ) -> crate::Result<RevertWorkerResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .revert_worker(workerexecutor::v1::RevertWorkerRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    target: request.target.clone(),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor revert-worker call"
+            )),
+            Some(workerexecutor::v1::revert_worker_response::Result::Success(_)) => {
+                Ok(RevertWorkerResponse {
+                    result: Some(worker::v1::revert_worker_response::Result::Success(
+                        Empty {},
+                    )),
+                })
+            }
+            Some(workerexecutor::v1::revert_worker_response::Result::Failure(error)) => {
+                Ok(RevertWorkerResponse {
+                    result: Some(worker::v1::revert_worker_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+        }
+    }
+
+    async fn cancel_invocation(
+        &self,
+        request: CancelInvocationRequest,
+    ) -> crate::Result<CancelInvocationResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .cancel_invocation(workerexecutor::v1::CancelInvocationRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    idempotency_key: request.idempotency_key.clone(),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor cancel-invocation call"
+            )),
+            Some(workerexecutor::v1::cancel_invocation_response::Result::Success(_)) => {
+                Ok(CancelInvocationResponse {
+                    result: Some(worker::v1::cancel_invocation_response::Result::Success(
+                        Empty {},
+                    )),
+                })
+            }
+            Some(workerexecutor::v1::cancel_invocation_response::Result::Failure(error)) => {
+                Ok(CancelInvocationResponse {
+                    result: Some(worker::v1::cancel_invocation_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+        }
+    }
+
+    async fn export_worker(
+        &self,
+        request: ExportWorkerRequest,
+    ) -> crate::Result<ExportWorkerResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .export_worker(workerexecutor::v1::ExportWorkerRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor export-worker call"
+            )),
+            Some(workerexecutor::v1::export_worker_response::Result::Success(archive)) => {
+                Ok(ExportWorkerResponse {
+                    result: Some(worker::v1::export_worker_response::Result::Success(archive)),
+                })
+            }
+            Some(workerexecutor::v1::export_worker_response::Result::Failure(error)) => {
+                Ok(ExportWorkerResponse {
+                    result: Some(worker::v1::export_worker_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+        }
+    }
+
+    async fn import_worker(
+        &self,
+        request: ImportWorkerRequest,
+    ) -> crate::Result<ImportWorkerResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .import_worker(workerexecutor::v1::ImportWorkerRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    archive: request.archive.clone(),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor import-worker call"
+            )),
+            Some(workerexecutor::v1::import_worker_response::Result::Success(_)) => {
+                Ok(ImportWorkerResponse {
+                    result: Some(worker::v1::import_worker_response::Result::Success(
+                        Empty {},
+                    )),
+                })
+            }
+            Some(workerexecutor::v1::import_worker_response::Result::Failure(error)) => {
+                Ok(ImportWorkerResponse {
+                    result: Some(worker::v1::import_worker_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+        }
+    }
+
+    async fn create_scheduled_invocation(
+        &self,
+        request: CreateScheduledInvocationRequest,
+    ) -> crate::Result<CreateScheduledInvocationResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .create_scheduled_invocation(workerexecutor::v1::CreateScheduledInvocationRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    invocation: request.invocation.clone(),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor create-scheduled-invocation call"
+            )),
+            Some(workerexecutor::v1::create_scheduled_invocation_response::Result::Success(
+                success,
+            )) => Ok(CreateScheduledInvocationResponse {
+                result: Some(
+                    worker::v1::create_scheduled_invocation_response::Result::Success(success),
+                ),
+            }),
+            Some(workerexecutor::v1::create_scheduled_invocation_response::Result::Failure(
+                error,
+            )) => Ok(CreateScheduledInvocationResponse {
+                result: Some(
+                    worker::v1::create_scheduled_invocation_response::Result::Error(WorkerError {
+                        error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                    }),
+                ),
+            }),
+        }
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        request: GetScheduledInvocationsRequest,
+    ) -> crate::Result<GetScheduledInvocationsResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .get_scheduled_invocations(workerexecutor::v1::GetScheduledInvocationsRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor get-scheduled-invocations call"
+            )),
+            Some(workerexecutor::v1::get_scheduled_invocations_response::Result::Success(
+                success,
+            )) => Ok(GetScheduledInvocationsResponse {
+                result: Some(
+                    worker::v1::get_scheduled_invocations_response::Result::Success(
+                        worker::v1::GetScheduledInvocationsSuccessResponse {
+                            invocations: success.invocations,
+                        },
+                    ),
+                ),
+            }),
+            Some(workerexecutor::v1::get_scheduled_invocations_response::Result::Failure(
+                error,
+            )) => Ok(GetScheduledInvocationsResponse {
+                result: Some(
+                    worker::v1::get_scheduled_invocations_response::Result::Error(WorkerError {
+                        error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                    }),
+                ),
+            }),
+        }
+    }
+
+    async fn update_scheduled_invocation(
+        &self,
+        request: UpdateScheduledInvocationRequest,
+    ) -> crate::Result<UpdateScheduledInvocationResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .update_scheduled_invocation(workerexecutor::v1::UpdateScheduledInvocationRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    invocation: request.invocation.clone(),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor update-scheduled-invocation call"
+            )),
+            Some(workerexecutor::v1::update_scheduled_invocation_response::Result::Success(
+                success,
+            )) => Ok(UpdateScheduledInvocationResponse {
+                result: Some(
+                    worker::v1::update_scheduled_invocation_response::Result::Success(success),
+                ),
+            }),
+            Some(workerexecutor::v1::update_scheduled_invocation_response::Result::Failure(
+                error,
+            )) => Ok(UpdateScheduledInvocationResponse {
+                result: Some(
+                    worker::v1::update_scheduled_invocation_response::Result::Error(WorkerError {
+                        error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                    }),
+                ),
+            }),
+        }
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        request: DeleteScheduledInvocationRequest,
+    ) -> crate::Result<DeleteScheduledInvocationResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .delete_scheduled_invocation(workerexecutor::v1::DeleteScheduledInvocationRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    name: request.name.clone(),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor delete-scheduled-invocation call"
+            )),
+            Some(workerexecutor::v1::delete_scheduled_invocation_response::Result::Success(_)) => {
+                Ok(DeleteScheduledInvocationResponse {
+                    result: Some(
+                        worker::v1::delete_scheduled_invocation_response::Result::Success(Empty {}),
+                    ),
+                })
+            }
+            Some(workerexecutor::v1::delete_scheduled_invocation_response::Result::Failure(
+                error,
+            )) => Ok(DeleteScheduledInvocationResponse {
+                result: Some(
+                    worker::v1::delete_scheduled_invocation_response::Result::Error(WorkerError {
+                        error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                    }),
+                ),
+            }),
+        }
+    }
+
     async fn list_directory(
         &self,
         request: ListDirectoryRequest,
@@ -26,14 +26,20 @@ use tracing::Level;
 use anyhow::anyhow;
 use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::v1::{
-    get_file_contents_response, ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse,
-    GetFileContentsRequest, GetOplogRequest, GetOplogResponse, GetWorkerMetadataRequest,
+    get_file_contents_response, CancelInvocationRequest, CancelInvocationResponse,
+    ConnectWorkerRequest, CreateScheduledInvocationRequest, CreateScheduledInvocationResponse,
+    DeleteScheduledInvocationRequest, DeleteScheduledInvocationResponse, DeleteWorkerRequest,
+    DeleteWorkerResponse, ExportWorkerRequest, ExportWorkerResponse, ForkWorkerRequest,
+    ForkWorkerResponse, GetFileContentsRequest, GetOplogRequest, GetOplogResponse,
+    GetScheduledInvocationsRequest, GetScheduledInvocationsResponse, GetWorkerMetadataRequest,
     GetWorkerMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
-    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
-    InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest,
-    InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
-    ListDirectoryRequest, ListDirectoryResponse, ResumeWorkerRequest, ResumeWorkerResponse,
-    SearchOplogRequest, SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    ImportWorkerRequest, ImportWorkerResponse, InterruptWorkerRequest, InterruptWorkerResponse,
+    InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest,
+    InvokeAndAwaitResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse,
+    LaunchNewWorkerRequest, LaunchNewWorkerResponse, ListDirectoryRequest, ListDirectoryResponse,
+    ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse,
+    SearchOplogRequest, SearchOplogResponse, UpdateScheduledInvocationRequest,
+    UpdateScheduledInvocationResponse, UpdateWorkerRequest, UpdateWorkerResponse,
 };
 use golem_api_grpc::proto::golem::worker::LogEvent;
 
@@ -204,6 +210,111 @@ pub trait WorkerService {
             .into_inner())
     }
 
+    async fn fork_worker(&self, request: ForkWorkerRequest) -> crate::Result<ForkWorkerResponse> {
+        Ok(self
+            .client()
+            .await?
+            .fork_worker(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn revert_worker(
+        &self,
+        request: RevertWorkerRequest,
+    ) -> crate::Result<RevertWorkerResponse> {
+        Ok(self
+            .client()
+            .await?
+            .revert_worker(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn cancel_invocation(
+        &self,
+        request: CancelInvocationRequest,
+    ) -> crate::Result<CancelInvocationResponse> {
+        Ok(self
+            .client()
+            .await?
+            .cancel_invocation(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn export_worker(
+        &self,
+        request: ExportWorkerRequest,
+    ) -> crate::Result<ExportWorkerResponse> {
+        Ok(self
+            .client()
+            .await?
+            .export_worker(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn import_worker(
+        &self,
+        request: ImportWorkerRequest,
+    ) -> crate::Result<ImportWorkerResponse> {
+        Ok(self
+            .client()
+            .await?
+            .import_worker(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn create_scheduled_invocation(
+        &self,
+        request: CreateScheduledInvocationRequest,
+    ) -> crate::Result<CreateScheduledInvocationResponse> {
+        Ok(self
+            .client()
+            .await?
+            .create_scheduled_invocation(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        request: GetScheduledInvocationsRequest,
+    ) -> crate::Result<GetScheduledInvocationsResponse> {
+        Ok(self
+            .client()
+            .await?
+            .get_scheduled_invocations(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn update_scheduled_invocation(
+        &self,
+        request: UpdateScheduledInvocationRequest,
+    ) -> crate::Result<UpdateScheduledInvocationResponse> {
+        Ok(self
+            .client()
+            .await?
+            .update_scheduled_invocation(request)
+            .await?
+            .into_inner())
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        request: DeleteScheduledInvocationRequest,
+    ) -> crate::Result<DeleteScheduledInvocationResponse> {
+        Ok(self
+            .client()
+            .await?
+            .delete_scheduled_invocation(request)
+            .await?
+            .into_inner())
+    }
+
     async fn list_directory(
         &self,
         request: ListDirectoryRequest,
@@ -21,29 +21,38 @@ use bytes::Bytes;
 use golem_api_grpc::proto::golem::worker::update_record::Update;
 use golem_api_grpc::proto::golem::worker::v1::worker_error::Error;
 use golem_api_grpc::proto::golem::worker::v1::{
-    get_oplog_response, get_worker_metadata_response, get_workers_metadata_response,
-    interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response,
-    invoke_response, launch_new_worker_response, list_directory_response, resume_worker_response,
-    search_oplog_response, update_worker_response, worker_execution_error, ConnectWorkerRequest,
-    DeleteWorkerRequest, GetFileContentsRequest, GetOplogRequest, GetWorkerMetadataRequest,
-    GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest,
-    InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitRequest, InvokeRequest,
-    LaunchNewWorkerRequest, ListDirectoryRequest, ResumeWorkerRequest, SearchOplogRequest,
-    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError, WorkerExecutionError,
+    cancel_invocation_response, create_scheduled_invocation_response,
+    delete_scheduled_invocation_response, export_worker_response, fork_worker_response,
+    get_oplog_response, get_scheduled_invocations_response, get_worker_metadata_response,
+    get_workers_metadata_response, import_worker_response, interrupt_worker_response,
+    invoke_and_await_json_response, invoke_and_await_response, invoke_response,
+    launch_new_worker_response, list_directory_response, resume_worker_response,
+    revert_worker_response, search_oplog_response, update_worker_response, worker_execution_error,
+    CancelInvocationRequest, ConnectWorkerRequest, CreateScheduledInvocationRequest,
+    DeleteScheduledInvocationRequest, DeleteWorkerRequest, ExportWorkerRequest, ForkWorkerRequest,
+    ForkWorkerResponse, GetFileContentsRequest, GetOplogRequest, GetScheduledInvocationsRequest,
+    GetWorkerMetadataRequest, GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse,
+    ImportWorkerRequest, InterruptWorkerRequest, InterruptWorkerResponse,
+    InvokeAndAwaitJsonRequest, InvokeAndAwaitRequest, InvokeRequest, LaunchNewWorkerRequest,
+    ListDirectoryRequest, ResumeWorkerRequest, RevertWorkerRequest, RevertWorkerResponse,
+    SearchOplogRequest, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
+    WorkerExecutionError,
 };
 use golem_api_grpc::proto::golem::worker::{
     log_event, InvokeParameters, LogEvent, StdErrLog, StdOutLog, UpdateMode,
 };
+use golem_common::model::component_metadata::WorkerResourceLimits;
 use golem_common::model::oplog::{
     OplogIndex, TimestampedUpdateDescription, UpdateDescription, WorkerResourceId,
 };
 use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_common::model::regions::DeletedRegions;
+use golem_common::model::scheduled_invocation::{Recurrence, ScheduledInvocation};
 use golem_common::model::AccountId;
 use golem_common::model::{
     ComponentFileSystemNode, ComponentId, ComponentType, ComponentVersion, FailedUpdateRecord,
-    IdempotencyKey, InitialComponentFile, InitialComponentFileKey, ScanCursor,
-    SuccessfulUpdateRecord, TargetWorkerId, WorkerFilter, WorkerId, WorkerMetadata,
+    IdempotencyKey, InitialComponentFile, InitialComponentFileKey, RevertWorkerTarget, ScanCursor,
+    SuccessfulUpdateRecord, TargetWorkerId, Timestamp, WorkerFilter, WorkerId, WorkerMetadata,
     WorkerResourceDescription, WorkerStatusRecord,
 };
 use golem_service_base::model::PublicOplogEntryWithIndex;
@@ -63,6 +72,11 @@ pub trait TestDsl {
     async fn store_unique_component(&self, name: &str) -> ComponentId;
     async fn store_component_unverified(&self, name: &str) -> ComponentId;
     async fn store_component_with_id(&self, name: &str, component_id: &ComponentId);
+    async fn store_unique_component_with_limits(
+        &self,
+        name: &str,
+        limits: &WorkerResourceLimits,
+    ) -> ComponentId;
     async fn store_unique_component_with_files(
         &self,
         name: &str,
@@ -136,6 +150,14 @@ pub trait TestDsl {
         function_name: &str,
         params: Vec<Value>,
     ) -> crate::Result<Result<(), Error>>;
+    async fn invoke_at_with_key(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        idempotency_key: &IdempotencyKey,
+        function_name: &str,
+        params: Vec<Value>,
+        schedule_at: Timestamp,
+    ) -> crate::Result<Result<(), Error>>;
     async fn invoke_and_await(
         &self,
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
@@ -194,6 +216,41 @@ pub trait TestDsl {
         worker_id: &WorkerId,
         target_version: ComponentVersion,
     ) -> crate::Result<()>;
+    async fn fork_worker(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+    ) -> crate::Result<()>;
+    async fn revert_worker(
+        &self,
+        worker_id: &WorkerId,
+        target: RevertWorkerTarget,
+    ) -> crate::Result<()>;
+    async fn cancel_invocation(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> crate::Result<Result<(), Error>>;
+    async fn export_worker(&self, worker_id: &WorkerId) -> crate::Result<Vec<u8>>;
+    async fn import_worker(&self, worker_id: &WorkerId, archive: Vec<u8>) -> crate::Result<()>;
+    async fn create_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: &str,
+        function_name: &str,
+        params: Vec<Value>,
+        recurrence: Recurrence,
+    ) -> crate::Result<ScheduledInvocation>;
+    async fn get_scheduled_invocations(
+        &self,
+        worker_id: &WorkerId,
+    ) -> crate::Result<Vec<ScheduledInvocation>>;
+    async fn delete_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: &str,
+    ) -> crate::Result<()>;
     async fn get_oplog(
         &self,
         worker_id: &WorkerId,
@@ -261,6 +318,20 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
             .expect("Failed to store component");
     }
 
+    async fn store_unique_component_with_limits(
+        &self,
+        name: &str,
+        limits: &WorkerResourceLimits,
+    ) -> ComponentId {
+        let source_path = self.component_directory().join(format!("{name}.wasm"));
+        let uuid = Uuid::new_v4();
+        let unique_name = format!("{name}-{uuid}");
+        self.component_service()
+            .add_component_with_limits(&source_path, &unique_name, ComponentType::Durable, limits)
+            .await
+            .expect("Failed to store component")
+    }
+
     async fn store_unique_component_with_files(
         &self,
         name: &str,
@@ -477,6 +548,7 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                     params: params.into_iter().map(|v| v.into()).collect(),
                 }),
                 context: None,
+                schedule_at: None,
             })
             .await?;
 
@@ -510,6 +582,42 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                     params: params.into_iter().map(|v| v.into()).collect(),
                 }),
                 context: None,
+                schedule_at: None,
+            })
+            .await?;
+
+        match invoke_response.result {
+            None => Err(anyhow!("No response from invoke_worker")),
+            Some(invoke_response::Result::Success(_)) => Ok(Ok(())),
+            Some(invoke_response::Result::Error(WorkerError { error: Some(error) })) => {
+                Ok(Err(error))
+            }
+            Some(invoke_response::Result::Error(_)) => {
+                Err(anyhow!("Empty error response from invoke_worker"))
+            }
+        }
+    }
+
+    async fn invoke_at_with_key(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        idempotency_key: &IdempotencyKey,
+        function_name: &str,
+        params: Vec<Value>,
+        schedule_at: Timestamp,
+    ) -> crate::Result<Result<(), Error>> {
+        let target_worker_id: TargetWorkerId = worker_id.into();
+        let invoke_response = self
+            .worker_service()
+            .invoke(InvokeRequest {
+                worker_id: Some(target_worker_id.into()),
+                idempotency_key: Some(idempotency_key.clone().into()),
+                function: function_name.to_string(),
+                invoke_parameters: Some(InvokeParameters {
+                    params: params.into_iter().map(|v| v.into()).collect(),
+                }),
+                context: None,
+                schedule_at: Some(schedule_at.into()),
             })
             .await?;
 
@@ -863,6 +971,205 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         }
     }
 
+    async fn fork_worker(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+    ) -> crate::Result<()> {
+        let response = self
+            .worker_service()
+            .fork_worker(ForkWorkerRequest {
+                source_worker_id: Some(source_worker_id.clone().into()),
+                target_worker_id: Some(target_worker_id.clone().into()),
+                oplog_index_cutoff: oplog_index_cutoff.into(),
+            })
+            .await?;
+
+        match response {
+            ForkWorkerResponse {
+                result: Some(fork_worker_response::Result::Success(_)),
+            } => Ok(()),
+            ForkWorkerResponse {
+                result: Some(fork_worker_response::Result::Error(error)),
+            } => Err(anyhow!("Failed to fork worker: {error:?}")),
+            _ => Err(anyhow!("Failed to fork worker: unknown error")),
+        }
+    }
+
+    async fn revert_worker(
+        &self,
+        worker_id: &WorkerId,
+        target: RevertWorkerTarget,
+    ) -> crate::Result<()> {
+        let response = self
+            .worker_service()
+            .revert_worker(RevertWorkerRequest {
+                worker_id: Some(worker_id.clone().into()),
+                target: Some(target.into()),
+            })
+            .await?;
+
+        match response {
+            RevertWorkerResponse {
+                result: Some(revert_worker_response::Result::Success(_)),
+            } => Ok(()),
+            RevertWorkerResponse {
+                result: Some(revert_worker_response::Result::Error(error)),
+            } => Err(anyhow!("Failed to revert worker: {error:?}")),
+            _ => Err(anyhow!("Failed to revert worker: unknown error")),
+        }
+    }
+
+    async fn cancel_invocation(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> crate::Result<Result<(), Error>> {
+        let response = self
+            .worker_service()
+            .cancel_invocation(CancelInvocationRequest {
+                worker_id: Some(worker_id.clone().into()),
+                idempotency_key: Some(idempotency_key.clone().into()),
+            })
+            .await?;
+
+        match response.result {
+            None => Err(anyhow!("No response from cancel_invocation")),
+            Some(cancel_invocation_response::Result::Success(_)) => Ok(Ok(())),
+            Some(cancel_invocation_response::Result::Error(WorkerError { error: Some(error) })) => {
+                Ok(Err(error))
+            }
+            Some(cancel_invocation_response::Result::Error(_)) => {
+                Err(anyhow!("Empty error response from cancel_invocation"))
+            }
+        }
+    }
+
+    async fn export_worker(&self, worker_id: &WorkerId) -> crate::Result<Vec<u8>> {
+        let response = self
+            .worker_service()
+            .export_worker(ExportWorkerRequest {
+                worker_id: Some(worker_id.clone().into()),
+            })
+            .await?;
+
+        match response.result {
+            Some(export_worker_response::Result::Success(archive)) => Ok(archive),
+            Some(export_worker_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to export worker: {error:?}"))
+            }
+            None => Err(anyhow!("Failed to export worker: unknown error")),
+        }
+    }
+
+    async fn import_worker(&self, worker_id: &WorkerId, archive: Vec<u8>) -> crate::Result<()> {
+        let response = self
+            .worker_service()
+            .import_worker(ImportWorkerRequest {
+                worker_id: Some(worker_id.clone().into()),
+                archive,
+            })
+            .await?;
+
+        match response.result {
+            Some(import_worker_response::Result::Success(_)) => Ok(()),
+            Some(import_worker_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to import worker: {error:?}"))
+            }
+            None => Err(anyhow!("Failed to import worker: unknown error")),
+        }
+    }
+
+    async fn create_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: &str,
+        function_name: &str,
+        params: Vec<Value>,
+        recurrence: Recurrence,
+    ) -> crate::Result<ScheduledInvocation> {
+        let response = self
+            .worker_service()
+            .create_scheduled_invocation(CreateScheduledInvocationRequest {
+                worker_id: Some(worker_id.clone().into()),
+                invocation: Some(golem_api_grpc::proto::golem::worker::ScheduledInvocation {
+                    name: name.to_string(),
+                    function_name: function_name.to_string(),
+                    input: params.into_iter().map(|v| v.into()).collect(),
+                    recurrence: Some(recurrence.into()),
+                    next_fire_at: None,
+                    idempotency_key: None,
+                    failed_attempts: 0,
+                    last_error: None,
+                }),
+            })
+            .await?;
+
+        match response.result {
+            Some(create_scheduled_invocation_response::Result::Success(invocation)) => invocation
+                .try_into()
+                .map_err(|err| anyhow!("Invalid scheduled invocation: {err}")),
+            Some(create_scheduled_invocation_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to create scheduled invocation: {error:?}"))
+            }
+            None => Err(anyhow!(
+                "Failed to create scheduled invocation: unknown error"
+            )),
+        }
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_id: &WorkerId,
+    ) -> crate::Result<Vec<ScheduledInvocation>> {
+        let response = self
+            .worker_service()
+            .get_scheduled_invocations(GetScheduledInvocationsRequest {
+                worker_id: Some(worker_id.clone().into()),
+            })
+            .await?;
+
+        match response.result {
+            Some(get_scheduled_invocations_response::Result::Success(success)) => success
+                .invocations
+                .into_iter()
+                .map(|invocation| invocation.try_into())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| anyhow!("Invalid scheduled invocation: {err}")),
+            Some(get_scheduled_invocations_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to get scheduled invocations: {error:?}"))
+            }
+            None => Err(anyhow!(
+                "Failed to get scheduled invocations: unknown error"
+            )),
+        }
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: &str,
+    ) -> crate::Result<()> {
+        let response = self
+            .worker_service()
+            .delete_scheduled_invocation(DeleteScheduledInvocationRequest {
+                worker_id: Some(worker_id.clone().into()),
+                name: name.to_string(),
+            })
+            .await?;
+
+        match response.result {
+            Some(delete_scheduled_invocation_response::Result::Success(_)) => Ok(()),
+            Some(delete_scheduled_invocation_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to delete scheduled invocation: {error:?}"))
+            }
+            None => Err(anyhow!(
+                "Failed to delete scheduled invocation: unknown error"
+            )),
+        }
+    }
+
     async fn get_oplog(
         &self,
         worker_id: &WorkerId,
@@ -1191,6 +1498,9 @@ pub fn worker_error_message(error: &Error) -> String {
                 worker_execution_error::Error::FileSystemError(error) => {
                     format!("File system error: {}", error.reason)
                 }
+                worker_execution_error::Error::ResourceLimitExceeded(error) => {
+                    format!("Resource limit exceeded: {}", error.details)
+                }
             },
         },
     }
@@ -1307,6 +1617,9 @@ pub fn to_worker_metadata(
                         )
                     })
                     .collect(),
+                latest_snapshot: metadata.latest_snapshot.map(OplogIndex::from_u64),
+                oplog_size: 0, // not passed through gRPC
+                oplog_size_measured_until: OplogIndex::NONE,
             },
             parent: None,
         },
@@ -1321,6 +1634,11 @@ pub trait TestDslUnsafe {
     async fn store_unique_component(&self, name: &str) -> ComponentId;
     async fn store_component_unverified(&self, name: &str) -> ComponentId;
     async fn store_component_with_id(&self, name: &str, component_id: &ComponentId);
+    async fn store_unique_component_with_limits(
+        &self,
+        name: &str,
+        limits: &WorkerResourceLimits,
+    ) -> ComponentId;
     async fn store_unique_component_with_files(
         &self,
         name: &str,
@@ -1393,6 +1711,14 @@ pub trait TestDslUnsafe {
         function_name: &str,
         params: Vec<Value>,
     ) -> Result<(), Error>;
+    async fn invoke_at_with_key(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        idempotency_key: &IdempotencyKey,
+        function_name: &str,
+        params: Vec<Value>,
+        schedule_at: Timestamp,
+    ) -> Result<(), Error>;
     async fn invoke_and_await(
         &self,
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
@@ -1430,6 +1756,30 @@ pub trait TestDslUnsafe {
     async fn simulated_crash(&self, worker_id: &WorkerId);
     async fn auto_update_worker(&self, worker_id: &WorkerId, target_version: ComponentVersion);
     async fn manual_update_worker(&self, worker_id: &WorkerId, target_version: ComponentVersion);
+    async fn fork_worker(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+    );
+    async fn revert_worker(&self, worker_id: &WorkerId, target: RevertWorkerTarget);
+    async fn cancel_invocation(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<(), Error>;
+    async fn export_worker(&self, worker_id: &WorkerId) -> Vec<u8>;
+    async fn import_worker(&self, worker_id: &WorkerId, archive: Vec<u8>);
+    async fn create_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: &str,
+        function_name: &str,
+        params: Vec<Value>,
+        recurrence: Recurrence,
+    ) -> ScheduledInvocation;
+    async fn get_scheduled_invocations(&self, worker_id: &WorkerId) -> Vec<ScheduledInvocation>;
+    async fn delete_scheduled_invocation(&self, worker_id: &WorkerId, name: &str);
     async fn get_oplog(&self, worker_id: &WorkerId, from: OplogIndex) -> Vec<PublicOplogEntry>;
     async fn search_oplog(
         &self,
@@ -1471,6 +1821,14 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
         <T as TestDsl>::store_component_with_id(self, name, component_id).await
     }
 
+    async fn store_unique_component_with_limits(
+        &self,
+        name: &str,
+        limits: &WorkerResourceLimits,
+    ) -> ComponentId {
+        <T as TestDsl>::store_unique_component_with_limits(self, name, limits).await
+    }
+
     async fn store_unique_component_with_files(
         &self,
         name: &str,
@@ -1601,6 +1959,26 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .expect("Failed to invoke function")
     }
 
+    async fn invoke_at_with_key(
+        &self,
+        worker_id: impl Into<TargetWorkerId> + Send + Sync,
+        idempotency_key: &IdempotencyKey,
+        function_name: &str,
+        params: Vec<Value>,
+        schedule_at: Timestamp,
+    ) -> Result<(), Error> {
+        <T as TestDsl>::invoke_at_with_key(
+            self,
+            worker_id,
+            idempotency_key,
+            function_name,
+            params,
+            schedule_at,
+        )
+        .await
+        .expect("Failed to invoke function")
+    }
+
     async fn invoke_and_await(
         &self,
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
@@ -1693,6 +2071,77 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .expect("Failed to update worker")
     }
 
+    async fn fork_worker(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+    ) {
+        <T as TestDsl>::fork_worker(self, source_worker_id, target_worker_id, oplog_index_cutoff)
+            .await
+            .expect("Failed to fork worker")
+    }
+
+    async fn revert_worker(&self, worker_id: &WorkerId, target: RevertWorkerTarget) {
+        <T as TestDsl>::revert_worker(self, worker_id, target)
+            .await
+            .expect("Failed to revert worker")
+    }
+
+    async fn cancel_invocation(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<(), Error> {
+        <T as TestDsl>::cancel_invocation(self, worker_id, idempotency_key)
+            .await
+            .expect("Failed to cancel invocation")
+    }
+
+    async fn export_worker(&self, worker_id: &WorkerId) -> Vec<u8> {
+        <T as TestDsl>::export_worker(self, worker_id)
+            .await
+            .expect("Failed to export worker")
+    }
+
+    async fn import_worker(&self, worker_id: &WorkerId, archive: Vec<u8>) {
+        <T as TestDsl>::import_worker(self, worker_id, archive)
+            .await
+            .expect("Failed to import worker")
+    }
+
+    async fn create_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: &str,
+        function_name: &str,
+        params: Vec<Value>,
+        recurrence: Recurrence,
+    ) -> ScheduledInvocation {
+        <T as TestDsl>::create_scheduled_invocation(
+            self,
+            worker_id,
+            name,
+            function_name,
+            params,
+            recurrence,
+        )
+        .await
+        .expect("Failed to create scheduled invocation")
+    }
+
+    async fn get_scheduled_invocations(&self, worker_id: &WorkerId) -> Vec<ScheduledInvocation> {
+        <T as TestDsl>::get_scheduled_invocations(self, worker_id)
+            .await
+            .expect("Failed to get scheduled invocations")
+    }
+
+    async fn delete_scheduled_invocation(&self, worker_id: &WorkerId, name: &str) {
+        <T as TestDsl>::delete_scheduled_invocation(self, worker_id, name)
+            .await
+            .expect("Failed to delete scheduled invocation")
+    }
+
     async fn get_oplog(&self, worker_id: &WorkerId, from: OplogIndex) -> Vec<PublicOplogEntry> {
         <T as TestDsl>::get_oplog(self, worker_id, from)
             .await
@@ -43,7 +43,7 @@ use crate::services::worker_proxy::WorkerProxy;
 use crate::services::HasOplogService;
 use crate::services::{worker_enumeration, HasAll, HasConfig, HasOplog, HasWorker};
 use crate::wasi_host;
-use crate::worker::{calculate_last_known_status, is_worker_error_retriable};
+use crate::worker::{calculate_last_known_status, is_worker_error_retriable, oplog_entry_size};
 use crate::worker::{RetryDecision, Worker};
 use crate::workerctx::{
     ExternalOperations, FileSystemReading, IndexedResourceStore, InvocationHooks,
@@ -180,6 +180,11 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         let stderr = ManagedStdErr::from_stderr(Stderr);
 
         let last_oplog_index = oplog.current_oplog_index().await;
+        let (oplog_size, oplog_size_measured_until) = {
+            let execution_status = execution_status.read().unwrap();
+            let status = execution_status.last_known_status();
+            (status.oplog_size, status.oplog_size_measured_until)
+        };
 
         let (wasi, table) = wasi_host::create_context(
             &worker_config.args,
@@ -222,6 +227,8 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 last_oplog_index,
                 component_metadata,
                 worker_config.total_linear_memory_size,
+                oplog_size,
+                oplog_size_measured_until,
             )
             .await,
             _temp_dir: temp_dir,
@@ -328,6 +335,8 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
             .clone_from(&self.state.current_idempotency_key);
         status.total_linear_memory_size = self.state.total_linear_memory_size;
         status.oplog_idx = self.state.oplog.current_oplog_index().await;
+        status.oplog_size = self.state.oplog_size;
+        status.oplog_size_measured_until = self.state.oplog_size_measured_until;
         f(&mut status);
         self.public_state.worker().update_status(status).await;
     }
@@ -353,6 +362,16 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
             // In live mode we need to try to get more memory permits and if we can't,
             // we fail the worker, unload it from memory and schedule a retry.
             // let current_size = self.update_worker_status();
+            if let Some(max_memory) = self.state.component_metadata.limits.max_memory {
+                let requested = self.state.total_linear_memory_size + delta;
+                if requested > max_memory {
+                    return Err(GolemError::resource_limit_exceeded(format!(
+                        "Worker requested {requested} bytes of linear memory, exceeding the limit of {max_memory}"
+                    ))
+                    .into());
+                }
+            }
+
             self.state
                 .oplog
                 .add_and_commit(OplogEntry::grow_memory(delta))
@@ -505,57 +524,12 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> DurableWorkerCtx<Ctx> {
                                     .set_current_idempotency_key(idempotency_key.clone())
                                     .await;
 
-                                store
-                                    .as_context_mut()
-                                    .data_mut()
-                                    .begin_call_snapshotting_function();
-                                let load_result = invoke_worker(
-                                    "golem:api/load-snapshot@0.2.0.{load}".to_string(),
-                                    vec![Value::List(data.iter().map(|b| Value::U8(*b)).collect())],
-                                    store,
-                                    instance,
-                                )
-                                .await;
-                                store
-                                    .as_context_mut()
-                                    .data_mut()
-                                    .end_call_snapshotting_function();
-
-                                let failed = match load_result {
-                                    Err(error) => Some(format!(
-                                        "Manual update failed to load snapshot: {error}"
-                                    )),
-                                    Ok(InvokeResult::Failed { error, .. }) => {
-                                        let stderr = store
-                                            .as_context()
-                                            .data()
-                                            .get_public_state()
-                                            .event_service()
-                                            .get_last_invocation_errors();
-                                        let error = error.to_string(&stderr);
-                                        Some(format!(
-                                            "Manual update failed to load snapshot: {error}"
-                                        ))
-                                    }
-                                    Ok(InvokeResult::Succeeded { output, .. }) => {
-                                        if output.len() == 1 {
-                                            match &output[0] {
-                                                Value::Result(Err(Some(boxed_error_value))) => {
-                                                    match &**boxed_error_value {
-                                                        Value::String(error) =>
-                                                            Some(format!("Manual update failed to load snapshot: {error}")),
-                                                        _ =>
-                                                            Some("Unexpected result value from the snapshot load function".to_string())
-                                                    }
-                                                }
-                                                _ => None
-                                            }
-                                        } else {
-                                            Some("Unexpected result value from the snapshot load function".to_string())
-                                        }
-                                    }
-                                    _ => None,
-                                };
+                                let failed = Self::load_snapshot(store, instance, &data)
+                                    .await
+                                    .err()
+                                    .map(|error| {
+                                        format!("Manual update failed to load snapshot: {error}")
+                                    });
 
                                 if let Some(error) = failed {
                                     store
@@ -646,6 +620,144 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> DurableWorkerCtx<Ctx> {
             }
         }
     }
+
+    /// Loads the latest snapshot of the worker if its component has snapshots enabled, and moves
+    /// the replay position after it, so only the oplog entries written since the snapshot are replayed.
+    async fn recover_from_latest_snapshot(
+        worker_id: &WorkerId,
+        instance: &Instance,
+        store: &mut (impl AsContextMut<Data = Ctx> + Send),
+    ) -> Result<(), GolemError> {
+        let durable_ctx = store.as_context().data().durable_ctx();
+        if !durable_ctx
+            .state
+            .config
+            .snapshot
+            .is_enabled_for(&worker_id.component_id)
+        {
+            return Ok(());
+        }
+        if !durable_ctx
+            .public_state
+            .worker()
+            .pending_updates()
+            .0
+            .is_empty()
+        {
+            // Updates are recovering either by replaying the whole oplog or from their own snapshot
+            return Ok(());
+        }
+
+        // The index of the latest snapshot is tracked in the worker status, so recovery does not
+        // have to search the oplog for it
+        let latest_snapshot = durable_ctx
+            .execution_status
+            .read()
+            .unwrap()
+            .last_known_status()
+            .latest_snapshot;
+
+        let latest_snapshot = match latest_snapshot {
+            Some(snapshot_idx) => durable_ctx
+                .state
+                .replay_state
+                .get_snapshot(snapshot_idx)
+                .await
+                .map(|payload| (snapshot_idx, payload)),
+            None => None,
+        };
+
+        if let Some((snapshot_idx, payload)) = latest_snapshot {
+            debug!("Recovering {worker_id} from the snapshot at {snapshot_idx}");
+
+            let data = store
+                .as_context()
+                .data()
+                .get_public_state()
+                .oplog()
+                .download_payload(&payload)
+                .await
+                .map_err(|error| {
+                    GolemError::runtime(format!("Failed to download snapshot: {error}"))
+                })?;
+
+            let idempotency_key = IdempotencyKey::fresh();
+            store
+                .as_context_mut()
+                .data_mut()
+                .durable_ctx_mut()
+                .set_current_idempotency_key(idempotency_key)
+                .await;
+
+            Self::load_snapshot(store, instance, &data)
+                .await
+                .map_err(|error| {
+                    GolemError::runtime(format!("Failed to load snapshot: {error}"))
+                })?;
+
+            store
+                .as_context_mut()
+                .data_mut()
+                .durable_ctx_mut()
+                .state
+                .replay_state
+                .skip_to(snapshot_idx)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    /// Calls the component's load-snapshot export with the given snapshot data
+    async fn load_snapshot(
+        store: &mut (impl AsContextMut<Data = Ctx> + Send),
+        instance: &Instance,
+        data: &[u8],
+    ) -> Result<(), String> {
+        store
+            .as_context_mut()
+            .data_mut()
+            .begin_call_snapshotting_function();
+        let load_result = invoke_worker(
+            "golem:api/load-snapshot@0.2.0.{load}".to_string(),
+            vec![Value::List(data.iter().map(|b| Value::U8(*b)).collect())],
+            store,
+            instance,
+        )
+        .await;
+        store
+            .as_context_mut()
+            .data_mut()
+            .end_call_snapshotting_function();
+
+        match load_result {
+            Err(error) => Err(error.to_string()),
+            Ok(InvokeResult::Failed { error, .. }) => {
+                let stderr = store
+                    .as_context()
+                    .data()
+                    .get_public_state()
+                    .event_service()
+                    .get_last_invocation_errors();
+                Err(error.to_string(&stderr))
+            }
+            Ok(InvokeResult::Succeeded { output, .. }) => {
+                if output.len() == 1 {
+                    match &output[0] {
+                        Value::Result(Err(Some(boxed_error_value))) => match &**boxed_error_value {
+                            Value::String(error) => Err(error.clone()),
+                            _ => Err("Unexpected result value from the snapshot load function"
+                                .to_string()),
+                        },
+                        _ => Ok(()),
+                    }
+                } else {
+                    Err("Unexpected result value from the snapshot load function".to_string())
+                }
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -665,6 +777,14 @@ impl<Ctx: WorkerCtx> InvocationManagement for DurableWorkerCtx<Ctx> {
     fn is_replay(&self) -> bool {
         self.state.is_replay()
     }
+
+    fn set_invocation_start_fuel_level(&mut self, level: u64) {
+        self.state.invocation_start_fuel_level = level;
+    }
+
+    fn get_invocation_start_fuel_level(&self) -> u64 {
+        self.state.invocation_start_fuel_level
+    }
 }
 
 #[async_trait]
@@ -802,6 +922,24 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         function_input: &Vec<Value>,
     ) -> Result<(), GolemError> {
         if self.state.snapshotting_mode.is_none() {
+            if let Some(max_oplog_length) = self.state.component_metadata.limits.max_oplog_length {
+                let oplog_length: u64 = self.state.oplog.current_oplog_index().await.into();
+                if oplog_length >= max_oplog_length {
+                    return Err(GolemError::resource_limit_exceeded(format!(
+                        "Oplog length {oplog_length} reached the limit of {max_oplog_length}"
+                    )));
+                }
+            }
+
+            if let Some(max_oplog_size) = self.state.component_metadata.limits.max_oplog_size {
+                let oplog_size = self.state.measure_oplog_size().await;
+                if oplog_size >= max_oplog_size {
+                    return Err(GolemError::resource_limit_exceeded(format!(
+                        "Oplog size of {oplog_size} bytes reached the limit of {max_oplog_size}"
+                    )));
+                }
+            }
+
             let proto_function_input: Vec<golem_wasm_rpc::protobuf::Val> = function_input
                 .iter()
                 .map(|value| value.clone().into())
@@ -1052,6 +1190,8 @@ impl<Ctx: WorkerCtx> UpdateManagement for DurableWorkerCtx<Ctx> {
         self.public_state.oplog.add_and_commit(entry).await;
         self.update_worker_status(|status| {
             status.component_version = target_version;
+            // Snapshots of the previous component version cannot be used for recovery
+            status.latest_snapshot = None;
             status.successful_updates.push(SuccessfulUpdateRecord {
                 timestamp,
                 target_version,
@@ -1184,6 +1324,9 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                 .get_out_of_deleted_region()
                 .await;
 
+            // Skipping the part of the oplog covered by the latest snapshot, if there is any
+            Self::recover_from_latest_snapshot(worker_id, instance, store).await?;
+
             let result = loop {
                 let cont = store.as_context().data().durable_ctx().state.is_replay();
 
@@ -1721,6 +1864,7 @@ pub struct PrivateDurableWorkerState {
     config: Arc<GolemConfig>,
     owned_worker_id: OwnedWorkerId,
     current_idempotency_key: Option<IdempotencyKey>,
+    invocation_start_fuel_level: u64,
     rpc: Arc<dyn Rpc + Send + Sync>,
     worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
     resources: HashMap<WorkerResourceId, ResourceAny>,
@@ -1741,6 +1885,11 @@ pub struct PrivateDurableWorkerState {
 
     total_linear_memory_size: u64,
     sync_helper: SyncHelper,
+
+    /// Total encoded size of the oplog entries up to `oplog_size_measured_until`, only measured
+    /// further when the worker has an oplog size limit
+    oplog_size: u64,
+    oplog_size_measured_until: OplogIndex,
 }
 
 impl PrivateDurableWorkerState {
@@ -1764,6 +1913,8 @@ impl PrivateDurableWorkerState {
         last_oplog_index: OplogIndex,
         component_metadata: ComponentMetadata,
         total_linear_memory_size: u64,
+        oplog_size: u64,
+        oplog_size_measured_until: OplogIndex,
     ) -> Self {
         let replay_state = ReplayState::new(
             owned_worker_id.clone(),
@@ -1786,6 +1937,7 @@ impl PrivateDurableWorkerState {
             config,
             owned_worker_id,
             current_idempotency_key: None,
+            invocation_start_fuel_level: 0,
             rpc,
             worker_proxy,
             resources: HashMap::new(),
@@ -1801,7 +1953,40 @@ impl PrivateDurableWorkerState {
             total_linear_memory_size,
             sync_helper: SyncHelper::new(oplog.clone(), replay_state.clone()),
             replay_state,
+            oplog_size,
+            oplog_size_measured_until,
+        }
+    }
+
+    /// Gets the total encoded size of the worker's committed oplog entries. The measurement
+    /// continues from the one cached in the worker status, so only the new entries are read.
+    async fn measure_oplog_size(&mut self) -> u64 {
+        const CHUNK_SIZE: u64 = 1024;
+
+        let last_index = self.oplog.current_oplog_index().await;
+        while self.oplog_size_measured_until < last_index {
+            let count =
+                (u64::from(last_index) - u64::from(self.oplog_size_measured_until)).min(CHUNK_SIZE);
+            let entries = self
+                .oplog_service
+                .read(
+                    &self.owned_worker_id,
+                    self.oplog_size_measured_until.next(),
+                    count,
+                )
+                .await;
+            if entries.is_empty() {
+                // The remaining entries are not committed yet
+                break;
+            }
+
+            for (idx, entry) in entries {
+                self.oplog_size += oplog_entry_size(&entry);
+                self.oplog_size_measured_until = idx;
+            }
         }
+
+        self.oplog_size
     }
 
     pub async fn begin_function(
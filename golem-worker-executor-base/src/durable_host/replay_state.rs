@@ -14,7 +14,9 @@
 
 use crate::error::GolemError;
 use crate::services::oplog::{Oplog, OplogOps, OplogService};
-use golem_common::model::oplog::{AtomicOplogIndex, LogLevel, OplogEntry, OplogIndex};
+use golem_common::model::oplog::{
+    AtomicOplogIndex, LogLevel, OplogEntry, OplogIndex, OplogPayload,
+};
 use golem_common::model::regions::{DeletedRegions, OplogRegion};
 use golem_common::model::{IdempotencyKey, OwnedWorkerId};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -233,6 +235,28 @@ impl ReplayState {
         None
     }
 
+    /// Reads the snapshot entry at the given oplog index, if it is part of the oplog still to be replayed
+    pub async fn get_snapshot(&self, idx: OplogIndex) -> Option<OplogPayload> {
+        if idx <= self.last_replayed_index.get() || idx > self.replay_target.get() {
+            return None;
+        }
+
+        let entries = self.oplog_service.read(&self.owned_worker_id, idx, 1).await;
+        match entries.get(&idx) {
+            Some(OplogEntry::Snapshot { data, .. }) => Some(data.clone()),
+            _ => None,
+        }
+    }
+
+    /// Continues the replay after the given oplog index, skipping every entry before it
+    pub async fn skip_to(&mut self, idx: OplogIndex) {
+        {
+            let mut internal = self.internal.write().await;
+            internal.next_deleted_region = internal.deleted_regions.find_next_deleted_region(idx);
+        }
+        self.move_replay_idx(idx).await;
+    }
+
     pub async fn get_oplog_entry_exported_function_invoked(
         &mut self,
     ) -> Result<Option<(String, Vec<Value>, IdempotencyKey)>, GolemError> {
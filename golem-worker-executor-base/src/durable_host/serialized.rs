@@ -685,6 +685,7 @@ mod tests {
             Just(GolemError::PreviousInvocationExited),
             ".*".prop_map(|details| GolemError::Unknown { details }),
             (".*", ".*").prop_map(|(path, reason)| GolemError::InitialComponentFileDownloadFailed { path, reason }),
+            ".*".prop_map(|details| GolemError::ResourceLimitExceeded { details }),
         }
     }
 
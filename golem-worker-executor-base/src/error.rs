@@ -105,6 +105,9 @@ pub enum GolemError {
         path: String,
         reason: String,
     },
+    ResourceLimitExceeded {
+        details: String,
+    },
 }
 
 impl GolemError {
@@ -146,6 +149,12 @@ impl GolemError {
         GolemError::InitialComponentFileDownloadFailed { path, reason }
     }
 
+    pub fn resource_limit_exceeded(details: impl Into<String>) -> Self {
+        GolemError::ResourceLimitExceeded {
+            details: details.into(),
+        }
+    }
+
     pub fn invalid_request(details: impl Into<String>) -> Self {
         GolemError::InvalidRequest {
             details: details.into(),
@@ -286,6 +295,9 @@ impl Display for GolemError {
                     "Failed to access file in worker filesystem {path}: {reason}"
                 )
             }
+            GolemError::ResourceLimitExceeded { details } => {
+                write!(f, "Resource limit exceeded: {details}")
+            }
         }
     }
 }
@@ -322,6 +334,7 @@ impl Error for GolemError {
             GolemError::Unknown { .. } => "Unknown error",
             GolemError::ShardingNotReady => "Sharding not ready",
             GolemError::FileSystemError { .. } => "File system error",
+            GolemError::ResourceLimitExceeded { .. } => "Resource limit exceeded",
         }
     }
 }
@@ -358,6 +371,7 @@ impl TraceErrorKind for GolemError {
             GolemError::Unknown { .. } => "Unknown",
             GolemError::ShardingNotReady => "ShardingNotReady",
             GolemError::FileSystemError { .. } => "FileSystemError",
+            GolemError::ResourceLimitExceeded { .. } => "ResourceLimitExceeded",
         }
     }
 }
@@ -399,6 +413,9 @@ impl From<GolemError> for Status {
                 Status::invalid_argument(format!("Value mismatch: {details}"))
             }
             GolemError::Unknown { details } => Status::unknown(details),
+            GolemError::ResourceLimitExceeded { details } => {
+                Status::resource_exhausted(format!("Resource limit exceeded: {details}"))
+            }
             _ => Status::internal(format!("{value}")),
         }
     }
@@ -635,6 +652,15 @@ impl From<GolemError> for golem::worker::v1::WorkerExecutionError {
                     ),
                 ),
             },
+            GolemError::ResourceLimitExceeded { details } => {
+                golem::worker::v1::WorkerExecutionError {
+                    error: Some(
+                        golem::worker::v1::worker_execution_error::Error::ResourceLimitExceeded(
+                            golem::worker::v1::ResourceLimitExceeded { details },
+                        ),
+                    ),
+                }
+            }
         }
     }
 }
@@ -815,6 +841,11 @@ impl TryFrom<golem::worker::v1::WorkerExecutionError> for GolemError {
                 path: file_system_error.path,
                 reason: file_system_error.reason,
             }),
+            Some(golem::worker::v1::worker_execution_error::Error::ResourceLimitExceeded(
+                resource_limit_exceeded,
+            )) => Ok(GolemError::ResourceLimitExceeded {
+                details: resource_limit_exceeded.details,
+            }),
         }
     }
 }
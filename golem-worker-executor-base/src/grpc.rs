@@ -21,11 +21,18 @@ use golem_api_grpc::proto::golem::common::ResourceLimits as GrpcResourceLimits;
 use golem_api_grpc::proto::golem::worker::{Cursor, ResourceMetadata, UpdateMode};
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::WorkerExecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    ConnectWorkerRequest, DeleteWorkerRequest, GetFileContentsRequest, GetFileContentsResponse,
-    GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest,
-    GetRunningWorkersMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
-    InvokeAndAwaitWorkerRequest, InvokeAndAwaitWorkerResponseTyped, InvokeAndAwaitWorkerSuccess,
-    ListDirectoryRequest, ListDirectoryResponse, SearchOplogRequest, SearchOplogResponse,
+    CancelInvocationRequest, CancelInvocationResponse, ConnectWorkerRequest,
+    CreateScheduledInvocationRequest, CreateScheduledInvocationResponse,
+    DeleteScheduledInvocationRequest, DeleteScheduledInvocationResponse, DeleteWorkerRequest,
+    ExportWorkerRequest, ExportWorkerResponse, ForkWorkerRequest, ForkWorkerResponse,
+    GetFileContentsRequest, GetFileContentsResponse, GetOplogRequest, GetOplogResponse,
+    GetRunningWorkersMetadataRequest, GetRunningWorkersMetadataResponse,
+    GetScheduledInvocationsRequest, GetScheduledInvocationsResponse,
+    GetScheduledInvocationsSuccessResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
+    ImportWorkerRequest, ImportWorkerResponse, InvokeAndAwaitWorkerRequest,
+    InvokeAndAwaitWorkerResponseTyped, InvokeAndAwaitWorkerSuccess, ListDirectoryRequest,
+    ListDirectoryResponse, RevertWorkerRequest, RevertWorkerResponse, SearchOplogRequest,
+    SearchOplogResponse, UpdateScheduledInvocationRequest, UpdateScheduledInvocationResponse,
     UpdateWorkerRequest, UpdateWorkerResponse,
 };
 use golem_common::grpc::{
@@ -33,11 +40,14 @@ use golem_common::grpc::{
     proto_promise_id_string, proto_target_worker_id_string, proto_worker_id_string,
 };
 use golem_common::metrics::api::record_new_grpc_api_active_stream;
-use golem_common::model::oplog::{OplogIndex, UpdateDescription};
+use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload, UpdateDescription};
+use golem_common::model::regions::{DeletedRegions, OplogRegion};
+use golem_common::model::scheduled_invocation::{Once, Recurrence, ScheduledInvocation};
 use golem_common::model::{
     AccountId, ComponentFilePath, ComponentId, ComponentType, IdempotencyKey, OwnedWorkerId,
-    ScanCursor, ShardId, TargetWorkerId, TimestampedWorkerInvocation, WorkerEvent, WorkerFilter,
-    WorkerId, WorkerInvocation, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
+    RevertLastInvocations, RevertToOplogIndex, RevertWorkerTarget, ScanCursor, ShardId,
+    TargetWorkerId, TimestampedWorkerInvocation, WorkerEvent, WorkerFilter, WorkerId,
+    WorkerInvocation, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::{model as common_model, recorded_grpc_api_request};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -59,16 +69,19 @@ use wasmtime::Error;
 use crate::model::public_oplog::{
     find_component_version_at, get_public_oplog_chunk, search_public_oplog,
 };
+use crate::model::worker_archive::{remap_promise_ids, ArchivedPromise, WorkerArchive};
 use crate::model::{InterruptKind, LastError, ListDirectoryResult, ReadFileResult};
 use crate::services::events::Event;
+use crate::services::oplog::CommitLevel;
 use crate::services::worker_activator::{DefaultWorkerActivator, LazyWorkerActivator};
 use crate::services::worker_event::WorkerEventReceiver;
 use crate::services::{
-    All, HasActiveWorkers, HasAll, HasComponentService, HasEvents, HasOplogService,
-    HasPromiseService, HasRunningWorkerEnumerationService, HasShardManagerService, HasShardService,
-    HasWorkerEnumerationService, HasWorkerService, UsesAllDeps,
+    All, HasActiveWorkers, HasAll, HasComponentService, HasEvents, HasOplog, HasOplogService,
+    HasPromiseService, HasRunningWorkerEnumerationService, HasSchedulerService,
+    HasShardManagerService, HasShardService, HasWorkerEnumerationService, HasWorkerService,
+    UsesAllDeps,
 };
-use crate::worker::Worker;
+use crate::worker::{recalculate_last_known_status, Worker};
 use crate::workerctx::WorkerCtx;
 use tokio;
 
@@ -411,6 +424,18 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         self.worker_service().remove(&owned_worker_id).await;
         self.active_workers().remove(&worker_id);
 
+        let scheduled_invocations = self
+            .scheduler_service()
+            .get_scheduled_invocations(&owned_worker_id)
+            .await
+            .map_err(GolemError::unknown)?;
+        for invocation in scheduled_invocations {
+            self.scheduler_service()
+                .delete_scheduled_invocation(&owned_worker_id, &invocation.name)
+                .await
+                .map_err(GolemError::unknown)?;
+        }
+
         Ok(())
     }
 
@@ -680,9 +705,31 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .collect::<Result<Vec<_>, _>>()
             .map_err(|msg| GolemError::ValueMismatch { details: msg })?;
 
-        worker
-            .invoke(idempotency_key, full_function_name, function_input)
-            .await?;
+        match request.schedule_at() {
+            Some(schedule_at) => {
+                // Delayed invocations are stored as one-shot scheduled invocations named after
+                // their idempotency key, so they can be cancelled with the same key
+                let invocation = ScheduledInvocation {
+                    name: ScheduledInvocation::delayed_name(&idempotency_key),
+                    full_function_name,
+                    function_input,
+                    recurrence: Recurrence::Once(Once {}),
+                    next_fire_at: schedule_at,
+                    idempotency_key: Some(idempotency_key),
+                    failed_attempts: 0,
+                    last_error: None,
+                };
+                self.scheduler_service()
+                    .schedule_invocation(worker.owned_worker_id(), invocation)
+                    .await
+                    .map_err(GolemError::unknown)?;
+            }
+            None => {
+                worker
+                    .invoke(idempotency_key, full_function_name, function_input)
+                    .await?;
+            }
+        }
 
         Ok(())
     }
@@ -755,11 +802,17 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                 .await?;
         let last_error_and_retry_count =
             Ctx::get_last_error_and_retry_count(self, &owned_worker_id).await;
+        let scheduled_invocations = self
+            .scheduler_service()
+            .get_scheduled_invocations(&owned_worker_id)
+            .await
+            .map_err(GolemError::unknown)?;
 
         Ok(Self::create_proto_metadata(
             metadata,
             latest_status,
             last_error_and_retry_count,
+            scheduled_invocations,
         ))
     }
 
@@ -786,7 +839,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .into_iter()
             .map(|worker| {
                 let status = worker.last_known_status.clone();
-                Self::create_proto_metadata(worker, status, None)
+                Self::create_proto_metadata(worker, status, None, Vec::new())
             })
             .collect();
 
@@ -830,13 +883,30 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             )
             .await?;
 
+        let owned_worker_ids = workers
+            .iter()
+            .map(|worker| worker.owned_worker_id())
+            .collect::<Vec<_>>();
+        let scheduled_invocations_of_workers = self
+            .scheduler_service()
+            .get_scheduled_invocations_of_workers(&owned_worker_ids)
+            .await
+            .map_err(GolemError::unknown)?;
+
         let mut result = Vec::new();
 
-        for worker in workers {
+        for (worker, scheduled_invocations) in
+            workers.into_iter().zip(scheduled_invocations_of_workers)
+        {
             let status = worker.last_known_status.clone();
             let last_error_and_retry_count =
                 Ctx::get_last_error_and_retry_count(self, &worker.owned_worker_id()).await;
-            let metadata = Self::create_proto_metadata(worker, status, last_error_and_retry_count);
+            let metadata = Self::create_proto_metadata(
+                worker,
+                status,
+                last_error_and_retry_count,
+                scheduled_invocations,
+            );
             result.push(metadata);
         }
 
@@ -1211,6 +1281,708 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         })
     }
 
+    async fn fork_worker_internal(&self, request: ForkWorkerRequest) -> Result<(), GolemError> {
+        let source_worker_id = request
+            .source_worker_id
+            .ok_or(GolemError::invalid_request("source_worker_id not found"))?;
+        let source_worker_id: WorkerId = source_worker_id
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        let target_worker_id = request
+            .target_worker_id
+            .ok_or(GolemError::invalid_request("target_worker_id not found"))?;
+        let target_worker_id: WorkerId = target_worker_id
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        let account_id = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        if source_worker_id.component_id != target_worker_id.component_id {
+            return Err(GolemError::invalid_request(
+                "The forked worker must belong to the same component as the source worker",
+            ));
+        }
+
+        let owned_source_worker_id = OwnedWorkerId::new(&account_id, &source_worker_id);
+        let owned_target_worker_id = OwnedWorkerId::new(&account_id, &target_worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&target_worker_id)?;
+
+        let source_metadata = self
+            .worker_service()
+            .get(&owned_source_worker_id)
+            .await
+            .ok_or(GolemError::worker_not_found(source_worker_id.clone()))?;
+
+        if self
+            .worker_service()
+            .get(&owned_target_worker_id)
+            .await
+            .is_some()
+        {
+            return Err(GolemError::worker_already_exists(target_worker_id.clone()));
+        }
+
+        let component_metadata = self
+            .component_service()
+            .get_metadata(
+                &source_worker_id.component_id,
+                Some(source_metadata.last_known_status.component_version),
+            )
+            .await?;
+        if component_metadata.component_type == ComponentType::Ephemeral {
+            return Err(GolemError::invalid_request(
+                "Ephemeral workers cannot be forked",
+            ));
+        }
+
+        let cutoff = OplogIndex::from_u64(request.oplog_index_cutoff);
+        let last_index = self
+            .oplog_service()
+            .get_last_index(&owned_source_worker_id)
+            .await;
+        if cutoff == OplogIndex::NONE || cutoff > last_index {
+            return Err(GolemError::invalid_request(format!(
+                "Oplog index cutoff must be between {} and {last_index}",
+                OplogIndex::INITIAL
+            )));
+        }
+
+        let mut entries = self
+            .oplog_service()
+            .read_range(&owned_source_worker_id, OplogIndex::INITIAL, cutoff)
+            .await
+            .into_iter();
+
+        let initial_entry = match entries.next() {
+            Some((
+                _,
+                OplogEntry::Create {
+                    timestamp,
+                    component_version,
+                    args,
+                    env,
+                    account_id,
+                    parent,
+                    component_size,
+                    initial_total_linear_memory_size,
+                    ..
+                },
+            )) => OplogEntry::Create {
+                timestamp,
+                worker_id: target_worker_id.clone(),
+                component_version,
+                args,
+                env,
+                account_id,
+                parent,
+                component_size,
+                initial_total_linear_memory_size,
+            },
+            _ => {
+                return Err(GolemError::unknown(format!(
+                    "Unexpected initial oplog entry for worker {source_worker_id}"
+                )))
+            }
+        };
+
+        let oplog = self
+            .oplog_service()
+            .create(
+                &owned_target_worker_id,
+                initial_entry,
+                component_metadata.component_type,
+            )
+            .await;
+
+        for (_, mut entry) in entries {
+            // External payloads are stored per worker, so they have to be copied to the new worker
+            for payload in entry.payloads_mut() {
+                if let OplogPayload::External { .. } = payload {
+                    let data = self
+                        .oplog_service()
+                        .download_payload(&owned_source_worker_id, payload)
+                        .await
+                        .map_err(GolemError::unknown)?;
+                    *payload = self
+                        .oplog_service()
+                        .upload_payload(&owned_target_worker_id, &data)
+                        .await
+                        .map_err(GolemError::unknown)?;
+                }
+            }
+            oplog.add(entry).await;
+        }
+        oplog.commit(CommitLevel::Always).await;
+
+        info!(
+            source_worker_id = source_worker_id.to_string(),
+            "Forked worker at oplog index {cutoff}"
+        );
+
+        Worker::get_or_create_running(self, &owned_target_worker_id, None, None, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revert_worker_internal(&self, request: RevertWorkerRequest) -> Result<(), GolemError> {
+        let worker_id = request
+            .worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        let account_id = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        let target: RevertWorkerTarget = request
+            .target
+            .ok_or(GolemError::invalid_request("target not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        let metadata = self.worker_service().get(&owned_worker_id).await;
+        let worker_status =
+            Ctx::compute_latest_worker_status(self, &owned_worker_id, &metadata).await?;
+        if metadata.is_none() {
+            return Err(GolemError::worker_not_found(worker_id.clone()));
+        }
+
+        let component_metadata = self
+            .component_service()
+            .get_metadata(
+                &worker_id.component_id,
+                Some(worker_status.component_version),
+            )
+            .await?;
+        if component_metadata.component_type == ComponentType::Ephemeral {
+            return Err(GolemError::invalid_request(
+                "Ephemeral workers cannot be reverted",
+            ));
+        }
+
+        let last_oplog_index = self.oplog_service().get_last_index(&owned_worker_id).await;
+        let revert_to = match target {
+            RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex { last_oplog_index }) => {
+                last_oplog_index
+            }
+            RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                number_of_invocations,
+            }) => {
+                self.find_revert_target_before_last_invocations(
+                    &owned_worker_id,
+                    &worker_status.deleted_regions,
+                    last_oplog_index,
+                    number_of_invocations,
+                )
+                .await?
+            }
+        };
+
+        if revert_to < OplogIndex::INITIAL || revert_to >= last_oplog_index {
+            return Err(GolemError::invalid_request(format!(
+                "Cannot revert worker to oplog index {revert_to}, it must be between {} and {}",
+                OplogIndex::INITIAL,
+                last_oplog_index.previous()
+            )));
+        }
+        if worker_status
+            .deleted_regions
+            .is_in_deleted_region(revert_to)
+        {
+            return Err(GolemError::invalid_request(format!(
+                "Cannot revert worker to oplog index {revert_to} because it is in a deleted region"
+            )));
+        }
+
+        let was_active = match &worker_status.status {
+            WorkerStatus::Idle
+            | WorkerStatus::Running
+            | WorkerStatus::Suspended
+            | WorkerStatus::Retrying => true,
+            WorkerStatus::Exited | WorkerStatus::Failed | WorkerStatus::Interrupted => false,
+        };
+
+        let worker =
+            Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None).await?;
+        if was_active {
+            if let Some(mut await_interrupted) =
+                worker.set_interrupting(InterruptKind::Interrupt).await
+            {
+                await_interrupted.recv().await.unwrap();
+            }
+        }
+        worker.stop().await;
+
+        // The jump entry itself is part of the deleted region, just like the ones written by `set_oplog_index`
+        let jump_source = worker.oplog().current_oplog_index().await.next();
+        let reverted_region = OplogRegion {
+            start: revert_to.next(),
+            end: jump_source,
+        };
+        worker
+            .oplog()
+            .add_and_commit(OplogEntry::jump(reverted_region))
+            .await;
+
+        // The already cached status contains information from the reverted region, so it
+        // has to be recalculated from scratch
+        let new_status = recalculate_last_known_status(self, &owned_worker_id).await?;
+        self.worker_service()
+            .update_status(
+                &owned_worker_id,
+                &new_status,
+                component_metadata.component_type,
+            )
+            .await;
+        self.active_workers().remove(&worker_id);
+
+        info!("Reverted worker to oplog index {revert_to}");
+
+        if was_active {
+            Worker::get_or_create_running(self, &owned_worker_id, None, None, None, None).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn cancel_invocation_internal(
+        &self,
+        request: CancelInvocationRequest,
+    ) -> Result<(), GolemError> {
+        let worker_id = request
+            .worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        let account_id = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        let idempotency_key: IdempotencyKey = request
+            .idempotency_key
+            .ok_or(GolemError::invalid_request("idempotency_key not found"))?
+            .into();
+
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        let metadata = self.worker_service().get(&owned_worker_id).await;
+        if metadata.is_none() {
+            return Err(GolemError::worker_not_found(worker_id.clone()));
+        }
+
+        let delayed_name = ScheduledInvocation::delayed_name(&idempotency_key);
+        if self
+            .scheduler_service()
+            .delete_scheduled_invocation(&owned_worker_id, &delayed_name)
+            .await
+            .map_err(GolemError::unknown)?
+        {
+            return Ok(());
+        }
+
+        let worker =
+            Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None).await?;
+        worker.cancel_invocation(idempotency_key).await
+    }
+
+    async fn export_worker_internal(
+        &self,
+        request: ExportWorkerRequest,
+    ) -> Result<Vec<u8>, GolemError> {
+        let worker_id = request
+            .worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        let account_id = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        let metadata = self
+            .worker_service()
+            .get(&owned_worker_id)
+            .await
+            .ok_or(GolemError::worker_not_found(worker_id.clone()))?;
+
+        let component_metadata = self
+            .component_service()
+            .get_metadata(
+                &worker_id.component_id,
+                Some(metadata.last_known_status.component_version),
+            )
+            .await?;
+        if component_metadata.component_type == ComponentType::Ephemeral {
+            return Err(GolemError::invalid_request(
+                "Ephemeral workers cannot be exported",
+            ));
+        }
+
+        let last_index = self.oplog_service().get_last_index(&owned_worker_id).await;
+        let entries = self
+            .oplog_service()
+            .read_range(&owned_worker_id, OplogIndex::INITIAL, last_index)
+            .await;
+
+        let mut oplog = Vec::with_capacity(entries.len());
+        for (_, mut entry) in entries {
+            // External payloads are stored per installation, so they get inlined into the archive
+            for payload in entry.payloads_mut() {
+                if let OplogPayload::External { .. } = payload {
+                    let data = self
+                        .oplog_service()
+                        .download_payload(&owned_worker_id, payload)
+                        .await
+                        .map_err(GolemError::unknown)?;
+                    *payload = OplogPayload::Inline(data.to_vec());
+                }
+            }
+            oplog.push(entry);
+        }
+
+        let promises = self
+            .promise_service()
+            .get_all(&worker_id)
+            .await?
+            .into_iter()
+            .map(|(promise_id, result)| ArchivedPromise {
+                oplog_idx: promise_id.oplog_idx,
+                result,
+            })
+            .collect();
+
+        let archive = WorkerArchive {
+            worker_id,
+            oplog,
+            promises,
+        };
+        archive.to_bytes().map_err(GolemError::unknown)
+    }
+
+    async fn import_worker_internal(&self, request: ImportWorkerRequest) -> Result<(), GolemError> {
+        let worker_id = request
+            .worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        let account_id = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        let archive =
+            WorkerArchive::from_bytes(&request.archive).map_err(GolemError::invalid_request)?;
+
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        if self.worker_service().get(&owned_worker_id).await.is_some() {
+            return Err(GolemError::worker_already_exists(worker_id.clone()));
+        }
+
+        let mut entries = archive.oplog.into_iter();
+        let (initial_entry, component_version) = match entries.next() {
+            Some(OplogEntry::Create {
+                timestamp,
+                component_version,
+                args,
+                env,
+                parent,
+                component_size,
+                initial_total_linear_memory_size,
+                ..
+            }) => (
+                OplogEntry::Create {
+                    timestamp,
+                    worker_id: worker_id.clone(),
+                    component_version,
+                    args,
+                    env,
+                    account_id: account_id.clone(),
+                    parent,
+                    component_size,
+                    initial_total_linear_memory_size,
+                },
+                component_version,
+            ),
+            _ => {
+                return Err(GolemError::invalid_request(
+                    "The worker archive does not start with a create oplog entry",
+                ))
+            }
+        };
+
+        let component_metadata = self
+            .component_service()
+            .get_metadata(&worker_id.component_id, Some(component_version))
+            .await?;
+        if component_metadata.component_type == ComponentType::Ephemeral {
+            return Err(GolemError::invalid_request(
+                "Workers cannot be imported into an ephemeral component",
+            ));
+        }
+
+        let oplog = self
+            .oplog_service()
+            .create(
+                &owned_worker_id,
+                initial_entry,
+                component_metadata.component_type,
+            )
+            .await;
+
+        for mut entry in entries {
+            remap_promise_ids(&mut entry, &archive.worker_id, &worker_id)
+                .map_err(GolemError::invalid_request)?;
+            // Large payloads get moved to the blob storage of this installation again
+            for payload in entry.payloads_mut() {
+                if let OplogPayload::Inline(data) = payload {
+                    let uploaded = self
+                        .oplog_service()
+                        .upload_payload(&owned_worker_id, data)
+                        .await
+                        .map_err(GolemError::unknown)?;
+                    *payload = uploaded;
+                }
+            }
+            oplog.add(entry).await;
+        }
+        oplog.commit(CommitLevel::Always).await;
+
+        for promise in archive.promises {
+            let promise_id = self
+                .promise_service()
+                .create(&worker_id, promise.oplog_idx)
+                .await;
+            if let Some(data) = promise.result {
+                self.promise_service().complete(promise_id, data).await?;
+            }
+        }
+
+        info!(
+            source_worker_id = archive.worker_id.to_string(),
+            "Imported worker from archive"
+        );
+
+        Worker::get_or_create_running(self, &owned_worker_id, None, None, None, None).await?;
+
+        Ok(())
+    }
+
+    /// Finds the oplog index right before the `number_of_invocations`th last invocation of the worker
+    async fn create_scheduled_invocation_internal(
+        &self,
+        request: CreateScheduledInvocationRequest,
+    ) -> Result<ScheduledInvocation, GolemError> {
+        let owned_worker_id = self
+            .scheduled_invocation_owner(request.worker_id, request.account_id)
+            .await?;
+        let invocation = Self::scheduled_invocation_from_request(request.invocation)?;
+
+        if self
+            .scheduler_service()
+            .get_scheduled_invocation(&owned_worker_id, &invocation.name)
+            .await
+            .map_err(GolemError::unknown)?
+            .is_some()
+        {
+            return Err(GolemError::invalid_request(format!(
+                "Scheduled invocation {} already exists",
+                invocation.name
+            )));
+        }
+
+        self.scheduler_service()
+            .schedule_invocation(&owned_worker_id, invocation.clone())
+            .await
+            .map_err(GolemError::unknown)?;
+        Ok(invocation)
+    }
+
+    async fn get_scheduled_invocations_internal(
+        &self,
+        request: GetScheduledInvocationsRequest,
+    ) -> Result<Vec<ScheduledInvocation>, GolemError> {
+        let owned_worker_id = self
+            .scheduled_invocation_owner(request.worker_id, request.account_id)
+            .await?;
+
+        self.scheduler_service()
+            .get_scheduled_invocations(&owned_worker_id)
+            .await
+            .map_err(GolemError::unknown)
+    }
+
+    async fn update_scheduled_invocation_internal(
+        &self,
+        request: UpdateScheduledInvocationRequest,
+    ) -> Result<ScheduledInvocation, GolemError> {
+        let owned_worker_id = self
+            .scheduled_invocation_owner(request.worker_id, request.account_id)
+            .await?;
+        let invocation = Self::scheduled_invocation_from_request(request.invocation)?;
+
+        if self
+            .scheduler_service()
+            .get_scheduled_invocation(&owned_worker_id, &invocation.name)
+            .await
+            .map_err(GolemError::unknown)?
+            .is_none()
+        {
+            return Err(GolemError::invalid_request(format!(
+                "Scheduled invocation {} not found",
+                invocation.name
+            )));
+        }
+
+        self.scheduler_service()
+            .schedule_invocation(&owned_worker_id, invocation.clone())
+            .await
+            .map_err(GolemError::unknown)?;
+        Ok(invocation)
+    }
+
+    async fn delete_scheduled_invocation_internal(
+        &self,
+        request: DeleteScheduledInvocationRequest,
+    ) -> Result<(), GolemError> {
+        let owned_worker_id = self
+            .scheduled_invocation_owner(request.worker_id, request.account_id)
+            .await?;
+
+        if ScheduledInvocation::is_delayed_name(&request.name) {
+            return Err(GolemError::invalid_request(
+                "Delayed invocations can only be cancelled by their idempotency key",
+            ));
+        }
+
+        let deleted = self
+            .scheduler_service()
+            .delete_scheduled_invocation(&owned_worker_id, &request.name)
+            .await
+            .map_err(GolemError::unknown)?;
+
+        if deleted {
+            Ok(())
+        } else {
+            Err(GolemError::invalid_request(format!(
+                "Scheduled invocation {} not found",
+                request.name
+            )))
+        }
+    }
+
+    async fn scheduled_invocation_owner(
+        &self,
+        worker_id: Option<golem::worker::WorkerId>,
+        account_id: Option<golem::common::AccountId>,
+    ) -> Result<OwnedWorkerId, GolemError> {
+        let worker_id = worker_id.ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        let account_id = account_id.ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        if self.worker_service().get(&owned_worker_id).await.is_none() {
+            return Err(GolemError::worker_not_found(worker_id));
+        }
+
+        Ok(owned_worker_id)
+    }
+
+    fn scheduled_invocation_from_request(
+        invocation: Option<golem::worker::ScheduledInvocation>,
+    ) -> Result<ScheduledInvocation, GolemError> {
+        let mut invocation =
+            invocation.ok_or(GolemError::invalid_request("invocation not found"))?;
+
+        if invocation.name.is_empty() {
+            return Err(GolemError::invalid_request(
+                "Scheduled invocation name must not be empty",
+            ));
+        }
+        if ScheduledInvocation::is_delayed_name(&invocation.name) {
+            return Err(GolemError::invalid_request(format!(
+                "Scheduled invocation names must not start with {}",
+                ScheduledInvocation::DELAYED_NAME_PREFIX
+            )));
+        }
+        invocation.failed_attempts = 0;
+        invocation.last_error = None;
+
+        let recurrence: Recurrence = invocation
+            .recurrence
+            .clone()
+            .ok_or(GolemError::invalid_request("recurrence not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+        recurrence.validate().map_err(GolemError::invalid_request)?;
+
+        if invocation.next_fire_at.is_none() {
+            let first_tick = recurrence
+                .first_tick(chrono::Utc::now())
+                .map_err(GolemError::invalid_request)?;
+            invocation.next_fire_at = Some(common_model::Timestamp::from(first_tick).into());
+        }
+
+        invocation.try_into().map_err(GolemError::invalid_request)
+    }
+
+    async fn find_revert_target_before_last_invocations(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        deleted_regions: &DeletedRegions,
+        last_oplog_index: OplogIndex,
+        number_of_invocations: u64,
+    ) -> Result<OplogIndex, GolemError> {
+        if number_of_invocations == 0 {
+            return Err(GolemError::invalid_request(
+                "The number of invocations to revert must be positive",
+            ));
+        }
+
+        let entries = self
+            .oplog_service()
+            .read_range(owned_worker_id, OplogIndex::INITIAL, last_oplog_index)
+            .await;
+
+        entries
+            .iter()
+            .rev()
+            .filter(|(idx, entry)| {
+                matches!(entry, OplogEntry::ExportedFunctionInvoked { .. })
+                    && !deleted_regions.is_in_deleted_region(**idx)
+            })
+            .nth((number_of_invocations - 1) as usize)
+            .map(|(idx, _)| idx.previous())
+            .ok_or(GolemError::invalid_request(format!(
+                "The worker has less than {number_of_invocations} invocations to revert"
+            )))
+    }
+
     async fn list_directory_internal(
         &self,
         request: ListDirectoryRequest,
@@ -1332,6 +2104,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         metadata: WorkerMetadata,
         latest_status: WorkerStatusRecord,
         last_error_and_retry_count: Option<LastError>,
+        scheduled_invocations: Vec<ScheduledInvocation>,
     ) -> golem::worker::WorkerMetadata {
         let mut updates = Vec::new();
 
@@ -1415,6 +2188,11 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             component_size: metadata.last_known_status.component_size,
             total_linear_memory_size: metadata.last_known_status.total_linear_memory_size,
             owned_resources,
+            scheduled_invocations: scheduled_invocations
+                .into_iter()
+                .map(|invocation| invocation.into())
+                .collect(),
+            latest_snapshot: latest_status.latest_snapshot.map(|idx| idx.into()),
         }
     }
 }
@@ -2067,6 +2845,324 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    async fn fork_worker(
+        &self,
+        request: Request<ForkWorkerRequest>,
+    ) -> Result<Response<ForkWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "fork_worker",
+            source_worker_id = proto_worker_id_string(&request.source_worker_id),
+            target_worker_id = proto_worker_id_string(&request.target_worker_id),
+            oplog_index_cutoff = request.oplog_index_cutoff,
+        );
+
+        match self
+            .fork_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(ForkWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::fork_worker_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(ForkWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::fork_worker_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn revert_worker(
+        &self,
+        request: Request<RevertWorkerRequest>,
+    ) -> Result<Response<RevertWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "revert_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        match self
+            .revert_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(RevertWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::revert_worker_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(RevertWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::revert_worker_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn cancel_invocation(
+        &self,
+        request: Request<CancelInvocationRequest>,
+    ) -> Result<Response<CancelInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "cancel_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        match self
+            .cancel_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(CancelInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::cancel_invocation_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(CancelInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::cancel_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn export_worker(
+        &self,
+        request: Request<ExportWorkerRequest>,
+    ) -> Result<Response<ExportWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "export_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        match self
+            .export_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(archive) => record.succeed(Ok(Response::new(ExportWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::export_worker_response::Result::Success(archive),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(ExportWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::export_worker_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn import_worker(
+        &self,
+        request: Request<ImportWorkerRequest>,
+    ) -> Result<Response<ImportWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "import_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        match self
+            .import_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(ImportWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::import_worker_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(ImportWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::import_worker_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn create_scheduled_invocation(
+        &self,
+        request: Request<CreateScheduledInvocationRequest>,
+    ) -> Result<Response<CreateScheduledInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "create_scheduled_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        match self
+            .create_scheduled_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(invocation) => record.succeed(Ok(Response::new(CreateScheduledInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::create_scheduled_invocation_response::Result::Success(
+                        invocation.into(),
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(CreateScheduledInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::create_scheduled_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        request: Request<GetScheduledInvocationsRequest>,
+    ) -> Result<Response<GetScheduledInvocationsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_scheduled_invocations",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        match self
+            .get_scheduled_invocations_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(invocations) => record.succeed(Ok(Response::new(GetScheduledInvocationsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_scheduled_invocations_response::Result::Success(
+                        GetScheduledInvocationsSuccessResponse {
+                            invocations: invocations.into_iter().map(|i| i.into()).collect(),
+                        },
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetScheduledInvocationsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_scheduled_invocations_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn update_scheduled_invocation(
+        &self,
+        request: Request<UpdateScheduledInvocationRequest>,
+    ) -> Result<Response<UpdateScheduledInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "update_scheduled_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        match self
+            .update_scheduled_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(invocation) => record.succeed(Ok(Response::new(UpdateScheduledInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::update_scheduled_invocation_response::Result::Success(
+                        invocation.into(),
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(UpdateScheduledInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::update_scheduled_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        request: Request<DeleteScheduledInvocationRequest>,
+    ) -> Result<Response<DeleteScheduledInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "delete_scheduled_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        match self
+            .delete_scheduled_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(DeleteScheduledInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::delete_scheduled_invocation_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(DeleteScheduledInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::delete_scheduled_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
     async fn list_directory(
         &self,
         request: Request<ListDirectoryRequest>,
@@ -2150,6 +3246,7 @@ trait GrpcInvokeRequest: CanStartWorker {
     fn input(&self) -> Vec<Val>;
     fn idempotency_key(&self) -> Result<Option<IdempotencyKey>, GolemError>;
     fn name(&self) -> String;
+    fn schedule_at(&self) -> Option<common_model::Timestamp>;
 }
 
 impl CanStartWorker for golem::workerexecutor::v1::ListDirectoryRequest {
@@ -2266,12 +3363,16 @@ impl GrpcInvokeRequest for golem::workerexecutor::v1::InvokeWorkerRequest {
     }
 
     fn idempotency_key(&self) -> Result<Option<IdempotencyKey>, GolemError> {
-        Ok(None)
+        Ok(self.idempotency_key.clone().map(IdempotencyKey::from))
     }
 
     fn name(&self) -> String {
         self.name.clone()
     }
+
+    fn schedule_at(&self) -> Option<common_model::Timestamp> {
+        self.schedule_at.map(|ts| ts.into())
+    }
 }
 
 impl CanStartWorker for golem::workerexecutor::v1::InvokeAndAwaitWorkerRequest {
@@ -2326,6 +3427,10 @@ impl GrpcInvokeRequest for golem::workerexecutor::v1::InvokeAndAwaitWorkerReques
     fn name(&self) -> String {
         self.name.clone()
     }
+
+    fn schedule_at(&self) -> Option<common_model::Timestamp> {
+        None
+    }
 }
 
 pub trait UriBackConversion {
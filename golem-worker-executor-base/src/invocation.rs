@@ -457,6 +457,11 @@ async fn call_exported_function<Ctx: WorkerCtx>(
         .map(|_| Val::Bool(false))
         .collect();
 
+    let start_fuel_level = store.get_fuel().unwrap_or(0);
+    store
+        .data_mut()
+        .set_invocation_start_fuel_level(start_fuel_level);
+
     let result = function.call_async(&mut store, &params, &mut results).await;
     let result = if result.is_ok() {
         function.post_return_async(&mut store).await.map_err(|e| {
@@ -55,10 +55,12 @@ use crate::services::worker_enumeration::{
 };
 use crate::services::worker_proxy::{RemoteWorkerProxy, WorkerProxy};
 use crate::services::{component, shard_manager, All};
+use crate::storage::indexed::postgres::PostgresIndexedStorage;
 use crate::storage::indexed::redis::RedisIndexedStorage;
 use crate::storage::indexed::sqlite::SqliteIndexedStorage;
 use crate::storage::indexed::IndexedStorage;
 use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+use crate::storage::keyvalue::postgres::PostgresKeyValueStorage;
 use crate::storage::keyvalue::redis::RedisKeyValueStorage;
 use crate::storage::keyvalue::KeyValueStorage;
 use crate::workerctx::WorkerCtx;
@@ -73,6 +75,7 @@ use golem_service_base::service::initial_component_files::InitialComponentFilesS
 use golem_service_base::storage::blob::s3::S3BlobStorage;
 use golem_service_base::storage::blob::sqlite::SqliteBlobStorage;
 use golem_service_base::storage::blob::BlobStorage;
+use golem_service_base::storage::postgres::PostgresPool;
 use golem_service_base::storage::sqlite::SqlitePool;
 use humansize::{ISizeFormatter, BINARY};
 use nonempty_collections::NEVec;
@@ -178,9 +181,10 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             "Worker executor is running",
         );
 
-        let (redis, sqlite, key_value_storage): (
+        let (redis, sqlite, postgres, key_value_storage): (
             Option<RedisPool>,
             Option<SqlitePool>,
+            Option<PostgresPool>,
             Arc<dyn KeyValueStorage + Send + Sync>,
         ) = match &golem_config.key_value_storage {
             KeyValueStorageConfig::Redis(redis) => {
@@ -190,11 +194,11 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                     .map_err(|err| anyhow!(err))?;
                 let key_value_storage: Arc<dyn KeyValueStorage + Send + Sync> =
                     Arc::new(RedisKeyValueStorage::new(pool.clone()));
-                (Some(pool), None, key_value_storage)
+                (Some(pool), None, None, key_value_storage)
             }
             KeyValueStorageConfig::InMemory => {
                 info!("Using in-memory key-value storage");
-                (None, None, Arc::new(InMemoryKeyValueStorage::new()))
+                (None, None, None, Arc::new(InMemoryKeyValueStorage::new()))
             }
             KeyValueStorageConfig::Sqlite(sqlite) => {
                 info!("Using Sqlite for key-value storage at {}", sqlite.database);
@@ -206,7 +210,22 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                         .await
                         .map_err(|err| anyhow!(err))?,
                 );
-                (None, Some(pool), key_value_storage)
+                (None, Some(pool), None, key_value_storage)
+            }
+            KeyValueStorageConfig::Postgres(postgres) => {
+                info!(
+                    "Using Postgres for key-value storage at {}:{}/{}",
+                    postgres.host, postgres.port, postgres.database
+                );
+                let pool = PostgresPool::configured(postgres)
+                    .await
+                    .map_err(|err| anyhow!(err))?;
+                let key_value_storage: Arc<dyn KeyValueStorage + Send + Sync> = Arc::new(
+                    PostgresKeyValueStorage::new(pool.clone())
+                        .await
+                        .map_err(|err| anyhow!(err))?,
+                );
+                (None, None, Some(pool), key_value_storage)
             }
         };
 
@@ -246,6 +265,31 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                             .map_err(|err| anyhow!(err))?,
                     )
                 }
+                IndexedStorageConfig::KVStorePostgres => {
+                    info!("Using the same Postgres for indexed-storage");
+                    let postgres = postgres.expect(
+                        "Postgres must be configured as key-value storage to use KVStorePostgres",
+                    );
+                    Arc::new(
+                        PostgresIndexedStorage::new(postgres.clone())
+                            .await
+                            .map_err(|err| anyhow!(err))?,
+                    )
+                }
+                IndexedStorageConfig::Postgres(postgres) => {
+                    info!(
+                        "Using Postgres for indexed storage at {}:{}/{}",
+                        postgres.host, postgres.port, postgres.database
+                    );
+                    let pool = PostgresPool::configured(postgres)
+                        .await
+                        .map_err(|err| anyhow!(err))?;
+                    Arc::new(
+                        PostgresIndexedStorage::new(pool.clone())
+                            .await
+                            .map_err(|err| anyhow!(err))?,
+                    )
+                }
                 IndexedStorageConfig::InMemory => {
                     info!("Using in-memory indexed storage");
                     Arc::new(storage::indexed::memory::InMemoryIndexedStorage::new())
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod public_oplog;
+pub mod worker_archive;
 
 use crate::error::{GolemError, WorkerOutOfMemory};
 use crate::workerctx::WorkerCtx;
@@ -251,6 +252,9 @@ impl TrapType {
                             Some(GolemError::InvalidRequest { details }) => {
                                 TrapType::Error(WorkerError::InvalidRequest(details.clone()))
                             }
+                            Some(GolemError::ResourceLimitExceeded { details }) => TrapType::Error(
+                                WorkerError::ResourceLimitExceeded(details.clone()),
+                            ),
                             _ => TrapType::Error(WorkerError::Unknown(format!("{:#}", error))),
                         },
                     },
@@ -266,6 +270,9 @@ impl TrapType {
             }
             TrapType::Error(error) => match error {
                 WorkerError::InvalidRequest(msg) => Some(GolemError::invalid_request(msg.clone())),
+                WorkerError::ResourceLimitExceeded(msg) => {
+                    Some(GolemError::resource_limit_exceeded(msg.clone()))
+                }
                 _ => Some(GolemError::runtime(error.to_string(error_logs))),
             },
             TrapType::Exit => Some(GolemError::runtime("Process exited")),
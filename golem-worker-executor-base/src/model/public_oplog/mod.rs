@@ -39,13 +39,15 @@ use golem_common::model::exports::{find_resource_site, function_by_name};
 use golem_common::model::lucene::Query;
 use golem_common::model::oplog::{OplogEntry, OplogIndex, UpdateDescription};
 use golem_common::model::public_oplog::{
-    ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, Empty,
-    EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
-    ExportedFunctionInvokedParameters, ExportedFunctionParameters, FailedUpdateParameters,
-    GrowMemoryParameters, ImportedFunctionInvokedParameters, JumpParameters, LogParameters,
-    ManualUpdateParameters, PendingUpdateParameters, PendingWorkerInvocationParameters,
-    PublicOplogEntry, PublicUpdateDescription, PublicWorkerInvocation, ResourceParameters,
-    SnapshotBasedUpdateParameters, SuccessfulUpdateParameters, TimestampParameter,
+    CancelPendingInvocationParameters, ChangeRetryPolicyParameters, CreateParameters,
+    DescribeResourceParameters, Empty, EndRegionParameters, ErrorParameters,
+    ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+    ExportedFunctionParameters, FailedUpdateParameters, GrowMemoryParameters,
+    ImportedFunctionInvokedParameters, JumpParameters, LogParameters, ManualUpdateParameters,
+    PendingUpdateParameters, PendingWorkerInvocationParameters, PublicOplogEntry,
+    PublicUpdateDescription, PublicWorkerInvocation, ResourceParameters,
+    SnapshotBasedUpdateParameters, SnapshotParameters, SuccessfulUpdateParameters,
+    TimestampParameter,
 };
 use golem_common::model::{
     ComponentId, ComponentVersion, IdempotencyKey, OwnedWorkerId, PromiseId, ShardId, WorkerId,
@@ -608,6 +610,24 @@ impl PublicOplogEntryOps for PublicOplogEntry {
             OplogEntry::Restart { timestamp } => {
                 Ok(PublicOplogEntry::Restart(TimestampParameter { timestamp }))
             }
+            OplogEntry::CancelPendingInvocation {
+                timestamp,
+                idempotency_key,
+            } => Ok(PublicOplogEntry::CancelPendingInvocation(
+                CancelPendingInvocationParameters {
+                    timestamp,
+                    idempotency_key,
+                },
+            )),
+            OplogEntry::Snapshot { timestamp, data } => {
+                let bytes = oplog_service
+                    .download_payload(owned_worker_id, &data)
+                    .await?;
+                Ok(PublicOplogEntry::Snapshot(SnapshotParameters {
+                    timestamp,
+                    data: bytes.to_vec(),
+                }))
+            }
         }
     }
 }
@@ -1834,6 +1854,10 @@ impl IntoValue for GolemError {
                         reason.into_value(),
                     ]))),
                 },
+                GolemError::ResourceLimitExceeded { details } => Value::Variant {
+                    case_idx: 25,
+                    case_value: Some(Box::new(Value::Record(vec![details.into_value()]))),
+                },
             }
         }
         into_value(self, true)
@@ -1931,6 +1955,10 @@ impl IntoValue for GolemError {
                     "InitialComponentFileDownloadFailed",
                     record(vec![field("path", str()), field("reason", str())]),
                 ),
+                case(
+                    "ResourceLimitExceeded",
+                    record(vec![field("details", str())]),
+                ),
             ])
         }
         get_type(true)
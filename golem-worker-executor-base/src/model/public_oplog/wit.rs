@@ -16,14 +16,15 @@ use crate::model::public_oplog::{PublicOplogEntry, PublicUpdateDescription};
 use crate::preview2::golem::api1_1_0_rc1::oplog;
 use crate::preview2::wasi::clocks::wall_clock::Datetime;
 use golem_common::model::public_oplog::{
-    ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, EndRegionParameters,
-    ErrorParameters, ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+    CancelPendingInvocationParameters, ChangeRetryPolicyParameters, CreateParameters,
+    DescribeResourceParameters, EndRegionParameters, ErrorParameters,
+    ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
     ExportedFunctionParameters, FailedUpdateParameters, GrowMemoryParameters,
     ImportedFunctionInvokedParameters, JumpParameters, LogParameters, ManualUpdateParameters,
     PendingUpdateParameters, PendingWorkerInvocationParameters, PublicRetryConfig,
     PublicWorkerInvocation, PublicWrappedFunctionType, ResourceParameters,
-    SnapshotBasedUpdateParameters, SuccessfulUpdateParameters, TimestampParameter,
-    WriteRemoteBatchedParameters,
+    SnapshotBasedUpdateParameters, SnapshotParameters, SuccessfulUpdateParameters,
+    TimestampParameter, WriteRemoteBatchedParameters,
 };
 use golem_common::model::Timestamp;
 
@@ -218,6 +219,19 @@ impl From<PublicOplogEntry> for oplog::OplogEntry {
             PublicOplogEntry::Restart(TimestampParameter { timestamp }) => {
                 Self::Restart(timestamp.into())
             }
+            PublicOplogEntry::CancelPendingInvocation(CancelPendingInvocationParameters {
+                timestamp,
+                ..
+            }) => {
+                // The oplog WIT interface has no dedicated case for cancellations, and it only
+                // affects the invocation queue, so it is exposed to guests as a no-op
+                Self::NoOp(timestamp.into())
+            }
+            PublicOplogEntry::Snapshot(SnapshotParameters { timestamp, .. }) => {
+                // Snapshots only affect how the worker is recovered, and the oplog WIT interface
+                // has no dedicated case for them
+                Self::NoOp(timestamp.into())
+            }
         }
     }
 }
@@ -0,0 +1,197 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
+use golem_common::model::{PromiseId, WorkerId};
+use golem_common::serialization::{deserialize, serialize};
+
+/// Magic bytes every worker archive starts with
+const WORKER_ARCHIVE_MAGIC: &[u8] = b"GOLEMWRK";
+
+/// The version of the archive format written by `WorkerArchive::to_bytes`
+pub const WORKER_ARCHIVE_VERSION: u8 = 1;
+
+/// A portable copy of a worker's durable state, used to move workers between Golem installations.
+///
+/// External oplog payloads are inlined into the oplog entries, so the archive does not depend on
+/// the blob storage of the installation it was exported from.
+///
+/// Key-value buckets are shared by all workers of an account, so their contents are not part of
+/// the archive and have to be migrated separately.
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct WorkerArchive {
+    /// The worker the archive was exported from
+    pub worker_id: WorkerId,
+    /// The full oplog, starting with the `Create` entry
+    pub oplog: Vec<OplogEntry>,
+    /// Promises created by the worker
+    pub promises: Vec<ArchivedPromise>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct ArchivedPromise {
+    pub oplog_idx: OplogIndex,
+    pub result: Option<Vec<u8>>,
+}
+
+impl WorkerArchive {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let data = bincode::encode_to_vec(self, bincode::config::standard())
+            .map_err(|err| format!("Failed to serialize worker archive: {err}"))?;
+        let mut result = Vec::with_capacity(WORKER_ARCHIVE_MAGIC.len() + 1 + data.len());
+        result.extend_from_slice(WORKER_ARCHIVE_MAGIC);
+        result.push(WORKER_ARCHIVE_VERSION);
+        result.extend_from_slice(&data);
+        Ok(result)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let data = bytes
+            .strip_prefix(WORKER_ARCHIVE_MAGIC)
+            .ok_or("Not a worker archive".to_string())?;
+        match data.split_first() {
+            Some((&WORKER_ARCHIVE_VERSION, data)) => {
+                let (archive, _) = bincode::decode_from_slice(data, bincode::config::standard())
+                    .map_err(|err| format!("Failed to deserialize worker archive: {err}"))?;
+                Ok(archive)
+            }
+            Some((version, _)) => Err(format!("Unsupported worker archive version: {version}")),
+            None => Err("Worker archive is truncated".to_string()),
+        }
+    }
+}
+
+/// Rewrites the ids of the worker's own promises referenced by a persisted promise host function
+/// call, so they point to the worker the archive gets imported as.
+///
+/// Promises of other workers are left untouched.
+pub fn remap_promise_ids(
+    entry: &mut OplogEntry,
+    source_worker_id: &WorkerId,
+    target_worker_id: &WorkerId,
+) -> Result<(), String> {
+    if let OplogEntry::ImportedFunctionInvoked {
+        function_name,
+        request: OplogPayload::Inline(request),
+        ..
+    } = entry
+    {
+        if function_name == "golem_complete_promise" || function_name == "golem_delete_promise" {
+            let mut promise_id: PromiseId = deserialize(request)?;
+            if promise_id.worker_id == *source_worker_id {
+                promise_id.worker_id = target_worker_id.clone();
+                *request = serialize(&promise_id)?.to_vec();
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::worker_archive::{remap_promise_ids, ArchivedPromise, WorkerArchive};
+    use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload, WrappedFunctionType};
+    use golem_common::model::{AccountId, ComponentId, PromiseId, Timestamp, WorkerId};
+    use golem_common::serialization::serialize;
+    use uuid::Uuid;
+
+    #[test]
+    fn archive_roundtrip() {
+        let worker_id = WorkerId {
+            component_id: ComponentId(Uuid::new_v4()),
+            worker_name: "test".to_string(),
+        };
+        let archive = WorkerArchive {
+            worker_id: worker_id.clone(),
+            oplog: vec![
+                OplogEntry::Create {
+                    timestamp: Timestamp::now_utc(),
+                    worker_id,
+                    component_version: 1,
+                    args: vec![],
+                    env: vec![],
+                    account_id: AccountId::generate(),
+                    parent: None,
+                    component_size: 100,
+                    initial_total_linear_memory_size: 200,
+                },
+                OplogEntry::NoOp {
+                    timestamp: Timestamp::now_utc(),
+                },
+            ],
+            promises: vec![ArchivedPromise {
+                oplog_idx: OplogIndex::from_u64(2),
+                result: Some(vec![1, 2, 3]),
+            }],
+        };
+
+        let bytes = archive.to_bytes().unwrap();
+        let result = WorkerArchive::from_bytes(&bytes).unwrap();
+
+        assert_eq!(result, archive);
+    }
+
+    #[test]
+    fn invalid_archive_is_rejected() {
+        assert!(WorkerArchive::from_bytes(b"not an archive").is_err());
+        assert!(WorkerArchive::from_bytes(b"GOLEMWRK").is_err());
+        assert!(WorkerArchive::from_bytes(b"GOLEMWRK\x02").is_err());
+    }
+
+    #[test]
+    fn own_promise_ids_are_remapped() {
+        let source_worker_id = WorkerId {
+            component_id: ComponentId(Uuid::new_v4()),
+            worker_name: "source".to_string(),
+        };
+        let target_worker_id = WorkerId {
+            component_id: ComponentId(Uuid::new_v4()),
+            worker_name: "target".to_string(),
+        };
+        let other_worker_id = WorkerId {
+            component_id: ComponentId(Uuid::new_v4()),
+            worker_name: "other".to_string(),
+        };
+
+        let timestamp = Timestamp::now_utc();
+        let entry = |function_name: &str, worker_id: &WorkerId| {
+            let promise_id = PromiseId {
+                worker_id: worker_id.clone(),
+                oplog_idx: OplogIndex::from_u64(3),
+            };
+            OplogEntry::ImportedFunctionInvoked {
+                timestamp,
+                function_name: function_name.to_string(),
+                request: OplogPayload::Inline(serialize(&promise_id).unwrap().to_vec()),
+                response: OplogPayload::Inline(serialize(&true).unwrap().to_vec()),
+                wrapped_function_type: WrappedFunctionType::WriteLocal,
+            }
+        };
+
+        let mut own = entry("golem_complete_promise", &source_worker_id);
+        remap_promise_ids(&mut own, &source_worker_id, &target_worker_id).unwrap();
+        assert_eq!(own, entry("golem_complete_promise", &target_worker_id));
+
+        let mut deleted = entry("golem_delete_promise", &source_worker_id);
+        remap_promise_ids(&mut deleted, &source_worker_id, &target_worker_id).unwrap();
+        assert_eq!(deleted, entry("golem_delete_promise", &target_worker_id));
+
+        let mut other = entry("golem_complete_promise", &other_worker_id);
+        remap_promise_ids(&mut other, &source_worker_id, &target_worker_id).unwrap();
+        assert_eq!(other, entry("golem_complete_promise", &other_worker_id));
+    }
+}
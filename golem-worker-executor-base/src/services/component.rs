@@ -35,7 +35,7 @@ use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode,
 use golem_common::client::{GrpcClient, GrpcClientConfig};
 use golem_common::config::RetryConfig;
 use golem_common::metrics::external_calls::record_external_call_response_size_bytes;
-use golem_common::model::component_metadata::LinearMemory;
+use golem_common::model::component_metadata::{LinearMemory, WorkerResourceLimits};
 use golem_common::model::{ComponentId, ComponentType, ComponentVersion, InitialComponentFile};
 use golem_common::retries::with_retries;
 use golem_service_base::storage::blob::BlobStorage;
@@ -60,6 +60,8 @@ pub struct ComponentMetadata {
     pub exports: Vec<AnalysedExport>,
     pub component_type: ComponentType,
     pub files: Vec<InitialComponentFile>,
+    #[serde(default)]
+    pub limits: WorkerResourceLimits,
 }
 
 /// Service for downloading a specific Golem component from the Golem Component API
@@ -453,6 +455,12 @@ async fn get_metadata_via_grpc(
                             metadata.memories.iter().map(|m| m.clone().into()).collect()
                         })
                         .unwrap_or_default(),
+                    limits: component
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.limits.clone())
+                        .map(|limits| limits.into())
+                        .unwrap_or_default(),
                     exports: component
                         .metadata
                         .map(|metadata| {
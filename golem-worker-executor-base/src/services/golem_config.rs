@@ -25,8 +25,10 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use golem_common::config::{
-    ConfigExample, ConfigLoader, DbSqliteConfig, HasConfigExamples, RedisConfig, RetryConfig,
+    ConfigExample, ConfigLoader, DbPostgresConfig, DbSqliteConfig, HasConfigExamples, RedisConfig,
+    RetryConfig,
 };
+use golem_common::model::ComponentId;
 use golem_common::tracing::TracingConfig;
 
 /// The shared global Golem configuration
@@ -44,6 +46,7 @@ pub struct GolemConfig {
     pub compiled_component_service: CompiledComponentServiceConfig,
     pub shard_manager_service: ShardManagerServiceConfig,
     pub oplog: OplogConfig,
+    pub snapshot: SnapshotConfig,
     pub suspend: SuspendConfig,
     pub active_workers: ActiveWorkersConfig,
     pub scheduler: SchedulerConfig,
@@ -249,11 +252,27 @@ pub struct OplogConfig {
     pub archive_interval: Duration,
 }
 
+/// Periodic snapshots of worker state, allowing recovery to start from the latest snapshot
+/// instead of replaying the whole oplog. Only the listed components are snapshotted, and they
+/// must export the `golem:api/save-snapshot` and `golem:api/load-snapshot` interfaces.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SnapshotConfig {
+    pub enabled_components: Vec<ComponentId>,
+    pub invocations_between_snapshots: u64,
+}
+
+impl SnapshotConfig {
+    pub fn is_enabled_for(&self, component_id: &ComponentId) -> bool {
+        self.enabled_components.contains(component_id)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type", content = "config")]
 pub enum KeyValueStorageConfig {
     Redis(RedisConfig),
     Sqlite(DbSqliteConfig),
+    Postgres(DbPostgresConfig),
     InMemory,
 }
 
@@ -264,6 +283,8 @@ pub enum IndexedStorageConfig {
     Redis(RedisConfig),
     KVStoreSqlite,
     Sqlite(DbSqliteConfig),
+    KVStorePostgres,
+    Postgres(DbPostgresConfig),
     InMemory,
 }
 
@@ -312,6 +333,7 @@ impl Default for GolemConfig {
             compiled_component_service: CompiledComponentServiceConfig::default(),
             shard_manager_service: ShardManagerServiceConfig::default(),
             oplog: OplogConfig::default(),
+            snapshot: SnapshotConfig::default(),
             suspend: SuspendConfig::default(),
             scheduler: SchedulerConfig::default(),
             active_workers: ActiveWorkersConfig::default(),
@@ -440,6 +462,15 @@ impl Default for OplogConfig {
     }
 }
 
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            enabled_components: Vec::new(),
+            invocations_between_snapshots: 100,
+        }
+    }
+}
+
 impl Default for SuspendConfig {
     fn default() -> Self {
         Self {
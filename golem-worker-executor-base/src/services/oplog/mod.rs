@@ -286,6 +286,16 @@ pub trait OplogOps: Oplog {
         })
     }
 
+    async fn add_snapshot(&self, data: &[u8]) -> Result<OplogEntry, String> {
+        let payload = self.upload_payload(data).await?;
+        let entry = OplogEntry::Snapshot {
+            timestamp: Timestamp::now_utc(),
+            data: payload,
+        };
+        self.add_and_commit(entry.clone()).await;
+        Ok(entry)
+    }
+
     async fn get_payload_of_entry<T: Decode>(
         &self,
         entry: &OplogEntry,
@@ -237,6 +237,17 @@ fn rounded(entry: OplogEntry) -> OplogEntry {
         OplogEntry::Restart { timestamp } => OplogEntry::Restart {
             timestamp: rounded_ts(timestamp),
         },
+        OplogEntry::CancelPendingInvocation {
+            timestamp,
+            idempotency_key,
+        } => OplogEntry::CancelPendingInvocation {
+            timestamp: rounded_ts(timestamp),
+            idempotency_key,
+        },
+        OplogEntry::Snapshot { timestamp, data } => OplogEntry::Snapshot {
+            timestamp: rounded_ts(timestamp),
+            data,
+        },
     }
 }
 
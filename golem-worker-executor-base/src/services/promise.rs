@@ -44,6 +44,12 @@ pub trait PromiseService {
     async fn complete(&self, promise_id: PromiseId, data: Vec<u8>) -> Result<bool, GolemError>;
 
     async fn delete(&self, promise_id: PromiseId);
+
+    /// Gets all promises created by the given worker, with their results if they are completed
+    async fn get_all(
+        &self,
+        worker_id: &WorkerId,
+    ) -> Result<Vec<(PromiseId, Option<Vec<u8>>)>, GolemError>;
 }
 
 #[derive(Clone, Debug)]
@@ -108,6 +114,15 @@ impl PromiseService for DefaultPromiseService {
             )
             .await
             .unwrap_or_else(|err| panic!("failed to set promise {promise_id} in Redis: {err}"));
+        self.key_value_storage
+            .with_entity("promise", "create", "promise")
+            .add_to_set(
+                KeyValueStorageNamespace::Promise,
+                &get_worker_promises_redis_key(worker_id),
+                &oplog_idx,
+            )
+            .await
+            .unwrap_or_else(|err| panic!("failed to index promise {promise_id} in Redis: {err}"));
 
         record_promise_created();
         promise_id
@@ -246,6 +261,49 @@ impl PromiseService for DefaultPromiseService {
             .unwrap_or_else(|err| {
                 panic!("failed to delete promise {promise_id} from Redis: {err}")
             });
+        self.key_value_storage
+            .with_entity("promise", "delete", "promise")
+            .remove_from_set(
+                KeyValueStorageNamespace::Promise,
+                &get_worker_promises_redis_key(&promise_id.worker_id),
+                &promise_id.oplog_idx,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to remove promise {promise_id} from its index in Redis: {err}")
+            });
+    }
+
+    async fn get_all(
+        &self,
+        worker_id: &WorkerId,
+    ) -> Result<Vec<(PromiseId, Option<Vec<u8>>)>, GolemError> {
+        let mut oplog_indices: Vec<OplogIndex> = self
+            .key_value_storage
+            .with_entity("promise", "get_all", "promise")
+            .members_of_set(
+                KeyValueStorageNamespace::Promise,
+                &get_worker_promises_redis_key(worker_id),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::unknown(format!(
+                    "Failed to get the promises of {worker_id} from Redis: {err}"
+                ))
+            })?;
+        oplog_indices.sort();
+
+        let promise_ids = oplog_indices.into_iter().map(|oplog_idx| PromiseId {
+            worker_id: worker_id.clone(),
+            oplog_idx,
+        });
+
+        let mut result = Vec::new();
+        for promise_id in promise_ids {
+            let data = self.poll(promise_id.clone()).await?;
+            result.push((promise_id, data));
+        }
+        Ok(result)
     }
 }
 
@@ -257,6 +315,11 @@ fn get_promise_result_redis_key(promise_id: &PromiseId) -> String {
     format!("{}:completed", promise_id.to_redis_key())
 }
 
+/// Key of the set holding the oplog indices of all promises created by a worker
+fn get_worker_promises_redis_key(worker_id: &WorkerId) -> String {
+    format!("{}:promises", worker_id.to_redis_key())
+}
+
 #[derive(Debug)]
 enum PromiseState {
     Pending(
@@ -320,4 +383,11 @@ impl PromiseService for PromiseServiceMock {
     async fn delete(&self, _promise_id: PromiseId) {
         unimplemented!()
     }
+
+    async fn get_all(
+        &self,
+        _worker_id: &WorkerId,
+    ) -> Result<Vec<(PromiseId, Option<Vec<u8>>)>, GolemError> {
+        unimplemented!()
+    }
 }
@@ -19,9 +19,10 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use chrono::{DateTime, TimeZone, Utc};
+use futures::future::try_join_all;
 use tokio::task::JoinHandle;
 use tokio::time::Instant;
-use tracing::{error, info, span, warn, Instrument, Level};
+use tracing::{debug, error, info, span, warn, Instrument, Level};
 
 use crate::metrics::oplog::record_scheduled_archive;
 use crate::metrics::promises::record_scheduled_promise_completed;
@@ -33,13 +34,51 @@ use crate::services::worker_activator::WorkerActivator;
 use crate::storage::keyvalue::{
     KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
 };
-use golem_common::model::{ComponentType, ScheduleId, ScheduledAction};
+use golem_common::config::RetryConfig;
+use golem_common::model::scheduled_invocation::ScheduledInvocation;
+use golem_common::model::{
+    ComponentType, IdempotencyKey, OwnedWorkerId, ScheduleId, ScheduledAction, Timestamp,
+};
+use golem_common::retries::get_delay;
 
 #[async_trait]
 pub trait SchedulerService {
     async fn schedule(&self, time: DateTime<Utc>, action: ScheduledAction) -> ScheduleId;
 
     async fn cancel(&self, id: ScheduleId);
+
+    /// Stores a named scheduled invocation of a worker and schedules its next tick. If a
+    /// scheduled invocation with the same name already exists, it gets replaced.
+    async fn schedule_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        invocation: ScheduledInvocation,
+    ) -> Result<(), String>;
+
+    async fn get_scheduled_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+    ) -> Result<Option<ScheduledInvocation>, String>;
+
+    async fn get_scheduled_invocations(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<ScheduledInvocation>, String>;
+
+    /// Gets the scheduled invocations of several workers, in the order of the given workers,
+    /// reading the invocations of all of them in a single batch.
+    async fn get_scheduled_invocations_of_workers(
+        &self,
+        owned_worker_ids: &[OwnedWorkerId],
+    ) -> Result<Vec<Vec<ScheduledInvocation>>, String>;
+
+    /// Deletes a named scheduled invocation and its pending tick. Returns false if it did not exist.
+    async fn delete_scheduled_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+    ) -> Result<bool, String>;
 }
 
 #[derive(Clone)]
@@ -200,6 +239,14 @@ impl SchedulerServiceDefault {
                         // TODO: metrics
                     }
                 }
+                ScheduledAction::Invoke {
+                    owned_worker_id,
+                    name,
+                    fire_at,
+                } => {
+                    self.fire_scheduled_invocation(now, &owned_worker_id, &name, fire_at)
+                        .await?;
+                }
             }
         }
 
@@ -218,8 +265,174 @@ impl SchedulerServiceDefault {
         Ok(())
     }
 
+    async fn fire_scheduled_invocation(
+        &self,
+        now: DateTime<Utc>,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+        fire_at: Timestamp,
+    ) -> Result<(), String> {
+        let invocation = self.get_scheduled_invocation(owned_worker_id, name).await?;
+        match invocation {
+            Some(mut invocation) if invocation.next_fire_at == fire_at => {
+                // Unless the invocation was scheduled with an explicit idempotency key, the key is
+                // derived from the tick, so if the same tick gets fired again (for example after
+                // a crash), the worker performs it only once
+                let idempotency_key = invocation.idempotency_key.clone().unwrap_or_else(|| {
+                    IdempotencyKey::scheduled(&owned_worker_id.worker_id, name, fire_at)
+                });
+                if let Err(err) = self
+                    .worker_activator
+                    .enqueue_invocation(
+                        owned_worker_id,
+                        idempotency_key,
+                        invocation.full_function_name.clone(),
+                        invocation.function_input.clone(),
+                    )
+                    .await
+                {
+                    // The tick is kept and fired again later, so it is not lost if the worker
+                    // cannot be reached temporarily
+                    invocation.failed_attempts += 1;
+                    invocation.last_error = Some(err.to_string());
+                    let delay = get_delay(
+                        &Self::INVOKE_RETRY_CONFIG,
+                        invocation.failed_attempts as u64,
+                    )
+                    .unwrap_or(Self::INVOKE_RETRY_CONFIG.max_delay);
+                    warn!(
+                        worker_id = owned_worker_id.worker_id.to_string(),
+                        "Failed to enqueue scheduled invocation {name} (attempt {}), retrying in {delay:?}: {err}",
+                        invocation.failed_attempts
+                    );
+
+                    self.store_scheduled_invocation(owned_worker_id, &invocation)
+                        .await?;
+                    self.schedule(
+                        now.add(delay),
+                        Self::invoke_action(owned_worker_id, &invocation),
+                    )
+                    .await;
+                    return Ok(());
+                }
+
+                invocation.failed_attempts = 0;
+                invocation.last_error = None;
+                match invocation.recurrence.next_tick(fire_at.into(), now) {
+                    Some(next_tick) => {
+                        invocation.next_fire_at = next_tick.into();
+                        self.store_scheduled_invocation(owned_worker_id, &invocation)
+                            .await?;
+                        self.schedule(next_tick, Self::invoke_action(owned_worker_id, &invocation))
+                            .await;
+                    }
+                    None => {
+                        self.remove_scheduled_invocation(owned_worker_id, name)
+                            .await?;
+                    }
+                }
+            }
+            _ => {
+                debug!(
+                    worker_id = owned_worker_id.worker_id.to_string(),
+                    "Ignoring outdated tick of scheduled invocation {name}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn store_scheduled_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        invocation: &ScheduledInvocation,
+    ) -> Result<(), String> {
+        self.key_value_storage
+            .with_entity(
+                "scheduler",
+                "store_scheduled_invocation",
+                "scheduled_invocation",
+            )
+            .set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocation_key(owned_worker_id, &invocation.name),
+                invocation,
+            )
+            .await?;
+        self.key_value_storage
+            .with_entity(
+                "scheduler",
+                "store_scheduled_invocation",
+                "scheduled_invocation",
+            )
+            .add_to_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocations_key(owned_worker_id),
+                &invocation.name,
+            )
+            .await
+    }
+
+    async fn remove_scheduled_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+    ) -> Result<(), String> {
+        self.key_value_storage
+            .with_entity(
+                "scheduler",
+                "remove_scheduled_invocation",
+                "scheduled_invocation",
+            )
+            .remove_from_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocations_key(owned_worker_id),
+                &name.to_string(),
+            )
+            .await?;
+        self.key_value_storage
+            .with("scheduler", "remove_scheduled_invocation")
+            .del(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocation_key(owned_worker_id, name),
+            )
+            .await
+    }
+
+    fn invoke_action(
+        owned_worker_id: &OwnedWorkerId,
+        invocation: &ScheduledInvocation,
+    ) -> ScheduledAction {
+        ScheduledAction::Invoke {
+            owned_worker_id: owned_worker_id.clone(),
+            name: invocation.name.clone(),
+            fire_at: invocation.next_fire_at,
+        }
+    }
+
+    fn invoke_schedule_id(
+        owned_worker_id: &OwnedWorkerId,
+        invocation: &ScheduledInvocation,
+    ) -> ScheduleId {
+        let (hours_since_epoch, _) = Self::split_time::<Utc>(invocation.next_fire_at.into());
+        ScheduleId {
+            timestamp: hours_since_epoch,
+            action: Self::invoke_action(owned_worker_id, invocation),
+        }
+    }
+
     const HOUR_IN_MILLIS: i64 = 1000 * 60 * 60;
 
+    /// Backoff between attempts to fire a tick of a scheduled invocation which could not be
+    /// enqueued. Attempts never run out, the delay stays at the maximum instead.
+    const INVOKE_RETRY_CONFIG: RetryConfig = RetryConfig {
+        max_attempts: u32::MAX,
+        min_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(60 * 60),
+        multiplier: 2.0,
+        max_jitter_factor: Some(0.15),
+    };
+
     fn split_time<Tz: TimeZone>(time: DateTime<Tz>) -> (i64, f64) {
         let millis = time.timestamp_millis();
         let hours_since_epoch = millis / Self::HOUR_IN_MILLIS;
@@ -234,6 +447,21 @@ impl SchedulerServiceDefault {
     fn schedule_key_from_timestamp(timestamp: i64) -> String {
         format!("worker:schedule:{}", timestamp)
     }
+
+    fn scheduled_invocations_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!(
+            "worker:scheduled-invocations:{}",
+            owned_worker_id.worker_id.to_redis_key()
+        )
+    }
+
+    fn scheduled_invocation_key(owned_worker_id: &OwnedWorkerId, name: &str) -> String {
+        format!(
+            "worker:scheduled-invocation:{}:{}",
+            owned_worker_id.worker_id.to_redis_key(),
+            name
+        )
+    }
 }
 
 impl Drop for SchedulerServiceDefault {
@@ -286,6 +514,128 @@ impl SchedulerService for SchedulerServiceDefault {
                 )
             });
     }
+
+    async fn schedule_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        invocation: ScheduledInvocation,
+    ) -> Result<(), String> {
+        if let Some(previous) = self
+            .get_scheduled_invocation(owned_worker_id, &invocation.name)
+            .await?
+        {
+            self.cancel(Self::invoke_schedule_id(owned_worker_id, &previous))
+                .await;
+        }
+
+        self.store_scheduled_invocation(owned_worker_id, &invocation)
+            .await?;
+        self.schedule(
+            invocation.next_fire_at.into(),
+            Self::invoke_action(owned_worker_id, &invocation),
+        )
+        .await;
+        Ok(())
+    }
+
+    async fn get_scheduled_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+    ) -> Result<Option<ScheduledInvocation>, String> {
+        self.key_value_storage
+            .with_entity(
+                "scheduler",
+                "get_scheduled_invocation",
+                "scheduled_invocation",
+            )
+            .get(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocation_key(owned_worker_id, name),
+            )
+            .await
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<ScheduledInvocation>, String> {
+        let mut invocations = self
+            .get_scheduled_invocations_of_workers(std::slice::from_ref(owned_worker_id))
+            .await?;
+        Ok(invocations.pop().unwrap_or_default())
+    }
+
+    async fn get_scheduled_invocations_of_workers(
+        &self,
+        owned_worker_ids: &[OwnedWorkerId],
+    ) -> Result<Vec<Vec<ScheduledInvocation>>, String> {
+        let names_of_workers: Vec<Vec<String>> =
+            try_join_all(owned_worker_ids.iter().map(|owned_worker_id| async move {
+                let mut names: Vec<String> = self
+                    .key_value_storage
+                    .with_entity(
+                        "scheduler",
+                        "get_scheduled_invocations",
+                        "scheduled_invocation",
+                    )
+                    .members_of_set(
+                        KeyValueStorageNamespace::Schedule,
+                        &Self::scheduled_invocations_key(owned_worker_id),
+                    )
+                    .await?;
+                names.sort();
+                Ok::<_, String>(names)
+            }))
+            .await?;
+
+        let keys: Vec<String> = owned_worker_ids
+            .iter()
+            .zip(&names_of_workers)
+            .flat_map(|(owned_worker_id, names)| {
+                names
+                    .iter()
+                    .map(move |name| Self::scheduled_invocation_key(owned_worker_id, name))
+            })
+            .collect();
+
+        // Reading no keys at all is rejected by some of the storages
+        let invocations: Vec<Option<ScheduledInvocation>> = if keys.is_empty() {
+            Vec::new()
+        } else {
+            self.key_value_storage
+                .with_entity(
+                    "scheduler",
+                    "get_scheduled_invocations",
+                    "scheduled_invocation",
+                )
+                .get_many(KeyValueStorageNamespace::Schedule, keys)
+                .await?
+        };
+
+        let mut invocations = invocations.into_iter();
+        Ok(names_of_workers
+            .iter()
+            .map(|names| invocations.by_ref().take(names.len()).flatten().collect())
+            .collect())
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+    ) -> Result<bool, String> {
+        match self.get_scheduled_invocation(owned_worker_id, name).await? {
+            Some(invocation) => {
+                self.cancel(Self::invoke_schedule_id(owned_worker_id, &invocation))
+                    .await;
+                self.remove_scheduled_invocation(owned_worker_id, name)
+                    .await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -312,8 +662,12 @@ mod tests {
     use crate::storage::indexed::memory::InMemoryIndexedStorage;
     use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
     use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::scheduled_invocation::{
+        Interval, Once, Recurrence, ScheduledInvocation,
+    };
     use golem_common::model::{
-        AccountId, ComponentId, PromiseId, ScheduledAction, ShardId, WorkerId,
+        AccountId, ComponentId, IdempotencyKey, OwnedWorkerId, PromiseId, ScheduledAction, ShardId,
+        Timestamp, WorkerId,
     };
     use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
 
@@ -1000,4 +1354,279 @@ mod tests {
         assert!(completed_promises.contains(&p3));
         assert!(completed_promises.contains(&p2));
     }
+
+    #[test]
+    pub async fn recurring_invocation_fires_once_per_tick() {
+        let c1: ComponentId = ComponentId(Uuid::new_v4());
+        let i1: WorkerId = WorkerId {
+            component_id: c1.clone(),
+            worker_name: "inst1".to_string(),
+        };
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &i1);
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_activator = Arc::new(WorkerActivatorMock::new());
+        let oplog_service = create_oplog_service_mock().await;
+        let worker_service =
+            create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_activator.clone(),
+            oplog_service,
+            worker_service,
+            Duration::from_secs(1000), // explicitly calling process for testing
+        );
+
+        let first_tick: DateTime<chrono::Utc> = DateTime::from_str("2023-07-17T10:05:00Z").unwrap();
+        svc.schedule_invocation(
+            &owned_worker_id,
+            ScheduledInvocation {
+                name: "heartbeat".to_string(),
+                full_function_name: "golem:it/api.{heartbeat}".to_string(),
+                function_input: vec![],
+                recurrence: Recurrence::Interval(Interval {
+                    interval_millis: 10 * 60 * 1000,
+                }),
+                next_fire_at: first_tick.into(),
+                idempotency_key: None,
+                failed_attempts: 0,
+                last_error: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        svc.process(DateTime::from_str("2023-07-17T10:07:00Z").unwrap())
+            .await
+            .unwrap();
+
+        // A duplicate of the already fired tick must be ignored
+        svc.schedule(
+            first_tick,
+            ScheduledAction::Invoke {
+                owned_worker_id: owned_worker_id.clone(),
+                name: "heartbeat".to_string(),
+                fire_at: first_tick.into(),
+            },
+        )
+        .await;
+        svc.process(DateTime::from_str("2023-07-17T10:08:00Z").unwrap())
+            .await
+            .unwrap();
+
+        let enqueued = worker_activator.all_enqueued();
+        assert_eq!(
+            enqueued,
+            vec![(
+                owned_worker_id.clone(),
+                IdempotencyKey::scheduled(&i1, "heartbeat", first_tick.into()),
+                "golem:it/api.{heartbeat}".to_string()
+            )]
+        );
+
+        let stored = svc
+            .get_scheduled_invocations(&owned_worker_id)
+            .await
+            .unwrap();
+        let expected_next: Timestamp = DateTime::<chrono::Utc>::from_str("2023-07-17T10:15:00Z")
+            .unwrap()
+            .into();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].next_fire_at, expected_next);
+
+        assert!(svc
+            .delete_scheduled_invocation(&owned_worker_id, "heartbeat")
+            .await
+            .unwrap());
+        svc.process(DateTime::from_str("2023-07-17T10:20:00Z").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(worker_activator.all_enqueued().len(), 1);
+        assert!(svc
+            .get_scheduled_invocations(&owned_worker_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    pub async fn undelivered_tick_is_retried() {
+        let c1: ComponentId = ComponentId(Uuid::new_v4());
+        let i1: WorkerId = WorkerId {
+            component_id: c1.clone(),
+            worker_name: "inst1".to_string(),
+        };
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &i1);
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_activator = Arc::new(WorkerActivatorMock::new());
+        let oplog_service = create_oplog_service_mock().await;
+        let worker_service =
+            create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_activator.clone(),
+            oplog_service,
+            worker_service,
+            Duration::from_secs(1000), // explicitly calling process for testing
+        );
+
+        let tick: DateTime<chrono::Utc> = DateTime::from_str("2023-07-17T10:05:00Z").unwrap();
+        svc.schedule_invocation(
+            &owned_worker_id,
+            ScheduledInvocation {
+                name: "reminder".to_string(),
+                full_function_name: "golem:it/api.{remind}".to_string(),
+                function_input: vec![],
+                recurrence: Recurrence::Once(Once {}),
+                next_fire_at: tick.into(),
+                idempotency_key: None,
+                failed_attempts: 0,
+                last_error: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        worker_activator.set_failing(true);
+        svc.process(DateTime::from_str("2023-07-17T10:06:00Z").unwrap())
+            .await
+            .unwrap();
+
+        let stored = svc
+            .get_scheduled_invocation(&owned_worker_id, "reminder")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(worker_activator.all_enqueued().is_empty());
+        assert_eq!(stored.next_fire_at, tick.into());
+        assert_eq!(stored.failed_attempts, 1);
+        assert!(stored.last_error.is_some());
+
+        worker_activator.set_failing(false);
+        svc.process(DateTime::from_str("2023-07-17T10:07:00Z").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            worker_activator.all_enqueued(),
+            vec![(
+                owned_worker_id.clone(),
+                IdempotencyKey::scheduled(&i1, "reminder", tick.into()),
+                "golem:it/api.{remind}".to_string()
+            )]
+        );
+        assert!(svc
+            .get_scheduled_invocations(&owned_worker_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    pub async fn scheduled_invocations_of_workers_are_read_in_worker_order() {
+        let c1: ComponentId = ComponentId(Uuid::new_v4());
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+        let owned_worker_ids = ["inst1", "inst2", "inst3"]
+            .iter()
+            .map(|worker_name| {
+                OwnedWorkerId::new(
+                    &account_id,
+                    &WorkerId {
+                        component_id: c1.clone(),
+                        worker_name: worker_name.to_string(),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_activator = Arc::new(WorkerActivatorMock::new());
+        let oplog_service = create_oplog_service_mock().await;
+        let worker_service =
+            create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_activator,
+            oplog_service,
+            worker_service,
+            Duration::from_secs(1000), // explicitly calling process for testing
+        );
+
+        let tick: DateTime<chrono::Utc> = DateTime::from_str("2023-07-17T10:05:00Z").unwrap();
+        let invocation = |name: &str| ScheduledInvocation {
+            name: name.to_string(),
+            full_function_name: "golem:it/api.{remind}".to_string(),
+            function_input: vec![],
+            recurrence: Recurrence::Once(Once {}),
+            next_fire_at: tick.into(),
+            idempotency_key: None,
+            failed_attempts: 0,
+            last_error: None,
+        };
+
+        // The second worker has no scheduled invocations
+        for (owned_worker_id, name) in [
+            (&owned_worker_ids[0], "b"),
+            (&owned_worker_ids[0], "a"),
+            (&owned_worker_ids[2], "c"),
+        ] {
+            svc.schedule_invocation(owned_worker_id, invocation(name))
+                .await
+                .unwrap();
+        }
+
+        let names = svc
+            .get_scheduled_invocations_of_workers(&owned_worker_ids)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|invocations| {
+                invocations
+                    .into_iter()
+                    .map(|invocation| invocation.name)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec![],
+                vec!["c".to_string()]
+            ]
+        );
+        assert!(svc
+            .get_scheduled_invocations_of_workers(&[])
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
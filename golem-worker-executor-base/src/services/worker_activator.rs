@@ -16,9 +16,11 @@ use std::marker::PhantomData;
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
-use golem_common::model::OwnedWorkerId;
+use golem_common::model::{IdempotencyKey, OwnedWorkerId};
+use golem_wasm_rpc::Value;
 use tracing::{error, warn};
 
+use crate::error::GolemError;
 use crate::services::HasAll;
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
@@ -28,6 +30,15 @@ use crate::workerctx::WorkerCtx;
 pub trait WorkerActivator {
     /// Makes sure an already existing worker is active in a background task. Returns immediately
     async fn activate_worker(&self, owned_worker_id: &OwnedWorkerId);
+
+    /// Enqueues an invocation on an existing worker without awaiting its result
+    async fn enqueue_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<Value>,
+    ) -> Result<(), GolemError>;
 }
 
 pub struct LazyWorkerActivator {
@@ -61,6 +72,32 @@ impl WorkerActivator for LazyWorkerActivator {
             None => warn!("WorkerActivator is disabled, not activating instance"),
         }
     }
+
+    async fn enqueue_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<Value>,
+    ) -> Result<(), GolemError> {
+        let maybe_worker_activator = self.worker_activator.lock().unwrap().clone();
+        match maybe_worker_activator {
+            Some(worker_activator) => {
+                worker_activator
+                    .enqueue_invocation(
+                        owned_worker_id,
+                        idempotency_key,
+                        full_function_name,
+                        function_input,
+                    )
+                    .await
+            }
+            // Failing lets the caller retry the invocation later instead of dropping it
+            None => Err(GolemError::runtime(
+                "WorkerActivator is disabled, not enqueuing invocation",
+            )),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -104,10 +141,44 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + Send + Sync + 'static> WorkerActivator
             }
         }
     }
+
+    async fn enqueue_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<Value>,
+    ) -> Result<(), GolemError> {
+        let metadata = self.all.worker_service().get(owned_worker_id).await;
+        match metadata {
+            Some(_) => {
+                // The worker may have been unloaded since the invocation was scheduled
+                let worker = Worker::get_or_create_running(
+                    &self.all,
+                    owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+                worker
+                    .invoke(idempotency_key, full_function_name, function_input)
+                    .await?;
+                Ok(())
+            }
+            None => Err(GolemError::worker_not_found(
+                owned_worker_id.worker_id.clone(),
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
-pub struct WorkerActivatorMock {}
+pub struct WorkerActivatorMock {
+    enqueued: Mutex<Vec<(OwnedWorkerId, IdempotencyKey, String)>>,
+    failing: Mutex<bool>,
+}
 
 #[cfg(test)]
 impl Default for WorkerActivatorMock {
@@ -119,7 +190,19 @@ impl Default for WorkerActivatorMock {
 #[cfg(test)]
 impl WorkerActivatorMock {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            enqueued: Mutex::new(Vec::new()),
+            failing: Mutex::new(false),
+        }
+    }
+
+    pub fn all_enqueued(&self) -> Vec<(OwnedWorkerId, IdempotencyKey, String)> {
+        self.enqueued.lock().unwrap().clone()
+    }
+
+    /// Makes the following enqueue attempts fail, as if the worker could not be reached
+    pub fn set_failing(&self, failing: bool) {
+        *self.failing.lock().unwrap() = failing;
     }
 }
 
@@ -129,4 +212,23 @@ impl WorkerActivator for WorkerActivatorMock {
     async fn activate_worker(&self, _owned_worker_id: &OwnedWorkerId) {
         tracing::info!("WorkerActivatorMock::activate_worker");
     }
+
+    async fn enqueue_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        _function_input: Vec<Value>,
+    ) -> Result<(), GolemError> {
+        tracing::info!("WorkerActivatorMock::enqueue_invocation");
+        if *self.failing.lock().unwrap() {
+            return Err(GolemError::runtime("worker is not reachable"));
+        }
+        self.enqueued.lock().unwrap().push((
+            owned_worker_id.clone(),
+            idempotency_key,
+            full_function_name,
+        ));
+        Ok(())
+    }
 }
@@ -272,6 +272,7 @@ impl WorkerProxy for RemoteWorkerProxy {
                             args: caller_args.clone(),
                             env: caller_env.clone(),
                         }),
+                        schedule_at: None,
                     },
                     &self.access_token,
                 )))
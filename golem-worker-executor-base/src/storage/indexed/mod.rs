@@ -22,6 +22,7 @@ use bytes::Bytes;
 use golem_common::serialization::{deserialize, serialize};
 
 pub mod memory;
+pub mod postgres;
 pub mod redis;
 pub mod sqlite;
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod memory;
+pub mod postgres;
 pub mod redis;
 pub mod sqlite;
 
@@ -42,6 +42,7 @@ use anyhow::anyhow;
 use drop_stream::DropStream;
 use futures::channel::oneshot;
 use golem_common::config::RetryConfig;
+use golem_common::model::component_metadata::WorkerResourceLimits;
 use golem_common::model::oplog::{
     OplogEntry, OplogIndex, TimestampedUpdateDescription, UpdateDescription, WorkerError,
     WorkerResourceId,
@@ -54,6 +55,7 @@ use golem_common::model::{
     WorkerResourceDescription, WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::retries::get_delay;
+use golem_common::serialization::serialize;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::Value;
 use tokio::sync::broadcast::error::RecvError;
@@ -361,6 +363,10 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         self.event_service.clone()
     }
 
+    pub fn owned_worker_id(&self) -> &OwnedWorkerId {
+        &self.owned_worker_id
+    }
+
     pub fn is_loading(&self) -> bool {
         matches!(
             &*self.execution_status.read().unwrap(),
@@ -564,6 +570,53 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             .collect()
     }
 
+    /// Removes a not yet started invocation from the invocation queue.
+    ///
+    /// The cancellation is recorded in the oplog so the invocation does not get restored to the
+    /// queue on recovery. Fails if the invocation has already been started.
+    pub async fn cancel_invocation(
+        &self,
+        idempotency_key: IdempotencyKey,
+    ) -> Result<(), GolemError> {
+        let removed = {
+            let mut queue = self.queue.write().unwrap();
+            let position = queue.iter().position(|item| {
+                item.as_external()
+                    .and_then(|inv| inv.invocation.idempotency_key())
+                    == Some(&idempotency_key)
+            });
+            position.and_then(|idx| queue.remove(idx)).is_some()
+        };
+
+        if removed {
+            self.oplog
+                .add_and_commit(OplogEntry::cancel_pending_invocation(idempotency_key))
+                .await;
+            self.update_metadata().await
+        } else {
+            let has_result = self
+                .invocation_results
+                .read()
+                .unwrap()
+                .contains_key(&idempotency_key);
+            let is_running = self
+                .get_metadata()
+                .await?
+                .last_known_status
+                .current_idempotency_key
+                == Some(idempotency_key.clone());
+            if has_result || is_running {
+                Err(GolemError::invalid_request(format!(
+                    "Invocation {idempotency_key} has already been started and cannot be cancelled"
+                )))
+            } else {
+                Err(GolemError::invalid_request(format!(
+                    "Invocation {idempotency_key} is not pending"
+                )))
+            }
+        }
+    }
+
     pub fn pending_updates(&self) -> (VecDeque<TimestampedUpdateDescription>, DeletedRegions) {
         let pending_updates = self.pending_updates.read().unwrap().clone();
         let mut deleted_regions = DeletedRegionsBuilder::new();
@@ -661,6 +714,19 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         map.remove(key);
     }
 
+    /// Records a snapshot written to the oplog in the worker's status. Status updates in live mode
+    /// only move the status' oplog index forward, so the snapshot would otherwise be missed.
+    async fn record_snapshot(&self, snapshot_idx: OplogIndex) {
+        let mut status = self
+            .execution_status
+            .read()
+            .unwrap()
+            .last_known_status()
+            .clone();
+        status.latest_snapshot = Some(snapshot_idx);
+        self.update_status(status).await;
+    }
+
     pub async fn update_status(&self, status_value: WorkerStatusRecord) {
         // Need to make sure the oplog is committed, because the updated status stores the current
         // last oplog index as reference.
@@ -1023,23 +1089,35 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     .get_metadata(&component_id, component_version)
                     .await?;
 
+                let worker_env = worker_env.unwrap_or_default();
+                let limits = component_metadata.limits.restricted_by(
+                    &WorkerResourceLimits::from_worker_env(&worker_env)
+                        .map_err(GolemError::invalid_request)?,
+                );
+
+                let initial_total_linear_memory_size: u64 =
+                    component_metadata.memories.iter().map(|m| m.initial).sum();
+                if let Some(max_memory) = limits.max_memory {
+                    if initial_total_linear_memory_size > max_memory {
+                        return Err(GolemError::resource_limit_exceeded(format!(
+                            "Initial linear memory of {initial_total_linear_memory_size} bytes exceeds the limit of {max_memory}"
+                        )));
+                    }
+                }
+
                 let initial_status =
                     calculate_last_known_status(this, owned_worker_id, &None).await?;
                 let worker_metadata = WorkerMetadata {
                     worker_id: owned_worker_id.worker_id(),
                     args: worker_args.unwrap_or_default(),
-                    env: worker_env.unwrap_or_default(),
+                    env: worker_env,
                     account_id: owned_worker_id.account_id(),
                     created_at: Timestamp::now_utc(),
                     parent,
                     last_known_status: WorkerStatusRecord {
                         component_version: component_metadata.version,
                         component_size: component_metadata.size,
-                        total_linear_memory_size: component_metadata
-                            .memories
-                            .iter()
-                            .map(|m| m.initial)
-                            .sum(),
+                        total_linear_memory_size: initial_total_linear_memory_size,
                         ..initial_status
                     },
                 };
@@ -1261,11 +1339,17 @@ impl RunningWorker {
                     target_version
                 },
             );
-        let (component, component_metadata) = parent
+        let (component, mut component_metadata) = parent
             .component_service()
             .get(&parent.engine(), &component_id, component_version)
             .await?;
 
+        // Limits set in the worker's environment can lower the component's limits
+        component_metadata.limits = component_metadata.limits.restricted_by(
+            &WorkerResourceLimits::from_worker_env(&worker_metadata.env)
+                .map_err(GolemError::invalid_request)?,
+        );
+
         let context = Ctx::create(
             OwnedWorkerId::new(&worker_metadata.account_id, &worker_metadata.worker_id),
             component_metadata,
@@ -1308,6 +1392,24 @@ impl RunningWorker {
                 store.data_mut().borrow_fuel_sync();
             }
 
+            if let Some(max_fuel) = store
+                .data()
+                .component_metadata()
+                .limits
+                .max_fuel_per_invocation
+            {
+                let consumed_fuel = store
+                    .data()
+                    .get_invocation_start_fuel_level()
+                    .saturating_sub(current_level);
+                if store.data().is_live() && consumed_fuel > max_fuel {
+                    return Err(GolemError::resource_limit_exceeded(format!(
+                        "Invocation consumed {consumed_fuel} fuel, exceeding the limit of {max_fuel}"
+                    ))
+                    .into());
+                }
+            }
+
             match store.data_mut().check_interrupt() {
                 Some(kind) => Err(kind.into()),
                 None => Ok(UpdateDeadline::Yield(1)),
@@ -1353,6 +1455,11 @@ impl RunningWorker {
         waiting_for_command: Arc<AtomicBool>,
         oom_retry_count: u64,
     ) {
+        let snapshot_config = parent.config().snapshot.clone();
+        let snapshots_enabled =
+            snapshot_config.is_enabled_for(&owned_worker_id.worker_id.component_id);
+        let mut invocations_since_snapshot = 0;
+
         loop {
             debug!("Invocation queue loop creating the instance");
 
@@ -1421,11 +1528,12 @@ impl RunningWorker {
                     waiting_for_command.store(false, Ordering::Release);
                     match cmd {
                         WorkerCommand::Invocation => {
-                            let message = active
-                                .write()
-                                .unwrap()
-                                .pop_front()
-                                .expect("Message should be present");
+                            let message = active.write().unwrap().pop_front();
+                            let Some(message) = message else {
+                                // The invocation has been cancelled after the command was sent
+                                waiting_for_command.store(true, Ordering::Release);
+                                continue;
+                            };
 
                             let mut store_mutex = store.lock().await;
                             let store = store_mutex.deref_mut();
@@ -1552,6 +1660,13 @@ impl RunningWorker {
                                                                                 RetryDecision::None;
                                                                             true // stop after the invocation
                                                                         } else {
+                                                                            if snapshots_enabled {
+                                                                                invocations_since_snapshot += 1;
+                                                                                if invocations_since_snapshot >= snapshot_config.invocations_between_snapshots {
+                                                                                    invocations_since_snapshot = 0;
+                                                                                    Self::save_snapshot(store, &instance).await;
+                                                                                }
+                                                                            }
                                                                             false // continue processing the queue
                                                                         }
                                                                     }
@@ -1815,6 +1930,61 @@ impl RunningWorker {
             .await;
     }
 
+    /// Saves the worker's state by calling the component's save-snapshot export, and records it in
+    /// the oplog so recovery can start from it. Failures are only logged, as the worker can still
+    /// be recovered by replaying the oplog.
+    async fn save_snapshot<Ctx: WorkerCtx>(store: &mut Store<Ctx>, instance: &Instance) {
+        let idempotency_key = IdempotencyKey::fresh();
+        store
+            .data_mut()
+            .set_current_idempotency_key(idempotency_key)
+            .await;
+
+        store.data_mut().begin_call_snapshotting_function();
+        let result = invoke_worker(
+            "golem:api/save-snapshot@0.2.0.{save}".to_string(),
+            vec![],
+            store,
+            instance,
+        )
+        .await;
+        store.data_mut().end_call_snapshotting_function();
+
+        match result {
+            Ok(InvokeResult::Succeeded { output, .. }) => {
+                if let Some(bytes) = Self::decode_snapshot_result(output) {
+                    let public_state = store.data().get_public_state();
+                    match public_state.oplog().add_snapshot(&bytes).await {
+                        Ok(_) => {
+                            let snapshot_idx = public_state.oplog().current_oplog_index().await;
+                            public_state.worker().record_snapshot(snapshot_idx).await;
+                        }
+                        Err(error) => {
+                            warn!("Failed to store the snapshot: {error}");
+                        }
+                    }
+                } else {
+                    warn!("Failed to save snapshot: invalid snapshot result");
+                }
+            }
+            Ok(InvokeResult::Failed { error, .. }) => {
+                let stderr = store
+                    .data()
+                    .get_public_state()
+                    .event_service()
+                    .get_last_invocation_errors();
+                let error = error.to_string(&stderr);
+                warn!("Failed to save snapshot: {error}");
+            }
+            Ok(other) => {
+                warn!("Failed to save snapshot: {other:?}");
+            }
+            Err(error) => {
+                warn!("Failed to save snapshot: {error}");
+            }
+        }
+    }
+
     /// Attempts to interpret the save snapshot result as a byte vector
     fn decode_snapshot_result(values: Vec<Value>) -> Option<Vec<u8>> {
         if values.len() == 1 {
@@ -1952,79 +2122,159 @@ where
             )
             .await;
 
-        let overridden_retry_config = calculate_overridden_retry_policy(
-            last_known.overridden_retry_config.clone(),
-            &new_entries,
-        );
-        let status = calculate_latest_worker_status(
-            &last_known.status,
+        Ok(calculate_status_record(
+            last_known,
             &this.config().retry,
-            last_known.overridden_retry_config.clone(),
+            last_oplog_index,
             &new_entries,
-        );
+        ))
+    }
+}
 
-        let mut initial_deleted_regions = last_known.deleted_regions;
-        if initial_deleted_regions.is_overridden() {
-            initial_deleted_regions.drop_override();
-        }
+/// Recalculates the worker status from the whole oplog, ignoring all the entries that belong to
+/// deleted regions.
+///
+/// Unlike `calculate_last_known_status` this does not build on the previously cached status, so it
+/// has to be used when already processed oplog entries get deleted, for example when reverting a worker.
+pub async fn recalculate_last_known_status<T>(
+    this: &T,
+    owned_worker_id: &OwnedWorkerId,
+) -> Result<WorkerStatusRecord, GolemError>
+where
+    T: HasOplogService + HasConfig,
+{
+    let last_oplog_index = this.oplog_service().get_last_index(owned_worker_id).await;
+    let entries: BTreeMap<OplogIndex, OplogEntry> = this
+        .oplog_service()
+        .read_range(owned_worker_id, OplogIndex::INITIAL, last_oplog_index)
+        .await;
 
-        let mut deleted_regions = calculate_deleted_regions(initial_deleted_regions, &new_entries);
-        let pending_invocations =
-            calculate_pending_invocations(last_known.pending_invocations, &new_entries);
-        let (
-            pending_updates,
-            failed_updates,
-            successful_updates,
+    let initial = match entries.get(&OplogIndex::INITIAL) {
+        Some(OplogEntry::Create {
             component_version,
             component_size,
-        ) = calculate_update_fields(
+            initial_total_linear_memory_size,
+            ..
+        }) => WorkerStatusRecord {
+            component_version: *component_version,
+            component_size: *component_size,
+            total_linear_memory_size: *initial_total_linear_memory_size,
+            ..WorkerStatusRecord::default()
+        },
+        _ => {
+            return Err(GolemError::unknown(format!(
+                "Unexpected initial oplog entry for worker {owned_worker_id}"
+            )))
+        }
+    };
+
+    // The oplog size covers all the stored entries, including the ones in deleted regions
+    let (oplog_size, oplog_size_measured_until) =
+        calculate_oplog_size(0, OplogIndex::NONE, &entries);
+    let initial = WorkerStatusRecord {
+        oplog_size,
+        oplog_size_measured_until,
+        ..initial
+    };
+
+    let deleted_regions = calculate_deleted_regions(DeletedRegions::new(), &entries);
+    let kept_entries: BTreeMap<OplogIndex, OplogEntry> = entries
+        .into_iter()
+        .filter(|(idx, _)| !deleted_regions.is_in_deleted_region(*idx))
+        .collect();
+
+    Ok(calculate_status_record(
+        WorkerStatusRecord {
+            deleted_regions,
+            ..initial
+        },
+        &this.config().retry,
+        last_oplog_index,
+        &kept_entries,
+    ))
+}
+
+fn calculate_status_record(
+    last_known: WorkerStatusRecord,
+    default_retry_policy: &RetryConfig,
+    last_oplog_index: OplogIndex,
+    new_entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> WorkerStatusRecord {
+    let overridden_retry_config =
+        calculate_overridden_retry_policy(last_known.overridden_retry_config.clone(), new_entries);
+    let status = calculate_latest_worker_status(
+        &last_known.status,
+        default_retry_policy,
+        last_known.overridden_retry_config.clone(),
+        new_entries,
+    );
+
+    let mut initial_deleted_regions = last_known.deleted_regions;
+    if initial_deleted_regions.is_overridden() {
+        initial_deleted_regions.drop_override();
+    }
+
+    let mut deleted_regions = calculate_deleted_regions(initial_deleted_regions, new_entries);
+    let latest_snapshot =
+        calculate_latest_snapshot(last_known.latest_snapshot, &deleted_regions, new_entries);
+    let pending_invocations =
+        calculate_pending_invocations(last_known.pending_invocations, new_entries);
+    let (pending_updates, failed_updates, successful_updates, component_version, component_size) =
+        calculate_update_fields(
             last_known.pending_updates,
             last_known.failed_updates,
             last_known.successful_updates,
             last_known.component_version,
             last_known.component_size,
-            &new_entries,
+            new_entries,
         );
 
-        if let Some(TimestampedUpdateDescription {
-            oplog_index,
-            description: UpdateDescription::SnapshotBased { .. },
-            ..
-        }) = pending_updates.front()
-        {
-            deleted_regions.set_override(DeletedRegions::from_regions(vec![
-                OplogRegion::from_index_range(OplogIndex::INITIAL.next()..=*oplog_index),
-            ]));
-        }
-
-        let (invocation_results, current_idempotency_key) = calculate_invocation_results(
-            last_known.invocation_results,
-            last_known.current_idempotency_key,
-            &new_entries,
-        );
-
-        let total_linear_memory_size =
-            calculate_total_linear_memory_size(last_known.total_linear_memory_size, &new_entries);
-
-        let owned_resources = calculate_owned_resources(last_known.owned_resources, &new_entries);
-
-        let result = WorkerStatusRecord {
-            oplog_idx: last_oplog_index,
-            status,
-            overridden_retry_config,
-            pending_invocations,
-            deleted_regions,
-            pending_updates,
-            failed_updates,
-            successful_updates,
-            invocation_results,
-            current_idempotency_key,
-            component_version,
-            component_size,
-            owned_resources,
-            total_linear_memory_size,
-        };
-        Ok(result)
+    if let Some(TimestampedUpdateDescription {
+        oplog_index,
+        description: UpdateDescription::SnapshotBased { .. },
+        ..
+    }) = pending_updates.front()
+    {
+        deleted_regions.set_override(DeletedRegions::from_regions(vec![
+            OplogRegion::from_index_range(OplogIndex::INITIAL.next()..=*oplog_index),
+        ]));
+    }
+
+    let (invocation_results, current_idempotency_key) = calculate_invocation_results(
+        last_known.invocation_results,
+        last_known.current_idempotency_key,
+        new_entries,
+    );
+
+    let total_linear_memory_size =
+        calculate_total_linear_memory_size(last_known.total_linear_memory_size, new_entries);
+
+    let owned_resources = calculate_owned_resources(last_known.owned_resources, new_entries);
+
+    let (oplog_size, oplog_size_measured_until) = calculate_oplog_size(
+        last_known.oplog_size,
+        last_known.oplog_size_measured_until,
+        new_entries,
+    );
+
+    WorkerStatusRecord {
+        oplog_idx: last_oplog_index,
+        status,
+        overridden_retry_config,
+        pending_invocations,
+        deleted_regions,
+        pending_updates,
+        failed_updates,
+        successful_updates,
+        invocation_results,
+        current_idempotency_key,
+        component_version,
+        component_size,
+        owned_resources,
+        total_linear_memory_size,
+        latest_snapshot,
+        oplog_size,
+        oplog_size_measured_until,
     }
 }
 
@@ -2122,6 +2372,8 @@ fn calculate_latest_worker_status(
             OplogEntry::Restart { .. } => {
                 result = WorkerStatus::Idle;
             }
+            OplogEntry::CancelPendingInvocation { .. } => {}
+            OplogEntry::Snapshot { .. } => {}
         }
     }
     result
@@ -2172,6 +2424,9 @@ fn calculate_pending_invocations(
             }
             OplogEntry::ExportedFunctionInvoked {
                 idempotency_key, ..
+            }
+            | OplogEntry::CancelPendingInvocation {
+                idempotency_key, ..
             } => {
                 result.retain(|invocation| match invocation {
                     TimestampedWorkerInvocation {
@@ -2330,6 +2585,30 @@ fn calculate_total_linear_memory_size(
     result
 }
 
+/// Adds the encoded size of the entries directly following `measured_until`. Entries after a gap
+/// are not counted, they get measured by the worker when it needs the oplog size.
+fn calculate_oplog_size(
+    size: u64,
+    measured_until: OplogIndex,
+    entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> (u64, OplogIndex) {
+    let mut size = size;
+    let mut measured_until = measured_until;
+    for (idx, entry) in entries.range(measured_until.next()..) {
+        if *idx != measured_until.next() {
+            break;
+        }
+        size += oplog_entry_size(entry);
+        measured_until = *idx;
+    }
+    (size, measured_until)
+}
+
+/// The encoded size of an oplog entry, as counted against the worker's oplog size limit
+pub fn oplog_entry_size(entry: &OplogEntry) -> u64 {
+    serialize(entry).map_or(0, |bytes| bytes.len() as u64)
+}
+
 fn calculate_owned_resources(
     initial: HashMap<WorkerResourceId, WorkerResourceDescription>,
     entries: &BTreeMap<OplogIndex, OplogEntry>,
@@ -2364,6 +2643,31 @@ fn calculate_owned_resources(
     result
 }
 
+/// Snapshots taken before a successful update belong to a different component version, and
+/// snapshots in deleted regions (for example after a revert) are no longer part of the worker's history.
+fn calculate_latest_snapshot(
+    initial: Option<OplogIndex>,
+    deleted_regions: &DeletedRegions,
+    entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> Option<OplogIndex> {
+    let mut result = initial;
+    for (idx, entry) in entries {
+        if deleted_regions.is_in_deleted_region(*idx) {
+            continue;
+        }
+        match entry {
+            OplogEntry::Snapshot { .. } => {
+                result = Some(*idx);
+            }
+            OplogEntry::SuccessfulUpdate { .. } => {
+                result = None;
+            }
+            _ => {}
+        }
+    }
+    result.filter(|idx| !deleted_regions.is_in_deleted_region(*idx))
+}
+
 pub fn is_worker_error_retriable(
     retry_config: &RetryConfig,
     error: &WorkerError,
@@ -2374,6 +2678,7 @@ pub fn is_worker_error_retriable(
         WorkerError::InvalidRequest(_) => false,
         WorkerError::StackOverflow => false,
         WorkerError::OutOfMemory => true,
+        WorkerError::ResourceLimitExceeded(_) => false,
     }
 }
 
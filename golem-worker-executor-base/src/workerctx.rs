@@ -203,6 +203,13 @@ pub trait InvocationManagement {
 
     /// Returns whether we are in replay mode where we are replaying old calls.
     fn is_replay(&self) -> bool;
+
+    /// Records the fuel level at the beginning of the current invocation. Used together with
+    /// `get_invocation_start_fuel_level` to enforce the component's per-invocation fuel limit.
+    fn set_invocation_start_fuel_level(&mut self, level: u64);
+
+    /// Gets the fuel level recorded at the beginning of the current invocation
+    fn get_invocation_start_fuel_level(&self) -> u64;
 }
 
 /// The status management interface of a worker context is responsible for querying and storing
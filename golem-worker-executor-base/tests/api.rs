@@ -31,16 +31,21 @@ use golem_api_grpc::proto::golem::worker::v1::{worker_execution_error, Component
 use golem_api_grpc::proto::golem::worker::LogEvent;
 use golem_api_grpc::proto::golem::workerexecutor::v1::CompletePromiseRequest;
 use golem_common::model::{
-    AccountId, ComponentId, FilterComparator, IdempotencyKey, PromiseId, ScanCursor,
-    StringFilterComparator, TargetWorkerId, Timestamp, WorkerFilter, WorkerId, WorkerMetadata,
-    WorkerResourceDescription, WorkerStatus,
+    AccountId, ComponentId, FilterComparator, IdempotencyKey, PromiseId, RevertLastInvocations,
+    RevertWorkerTarget, ScanCursor, StringFilterComparator, TargetWorkerId, Timestamp,
+    WorkerFilter, WorkerId, WorkerMetadata, WorkerResourceDescription, WorkerStatus,
 };
 use golem_wasm_rpc::Value;
 
 use crate::common::{start, TestContext, TestWorkerExecutor};
 use crate::compatibility::worker_recovery::save_recovery_golden_file;
 use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
+use golem_common::model::component_metadata::WorkerResourceLimits;
 use golem_common::model::oplog::{IndexedResourceKey, OplogIndex, WorkerResourceId};
+use golem_common::model::public_oplog::{ExportedFunctionInvokedParameters, PublicOplogEntry};
+use golem_common::model::scheduled_invocation::{
+    Cron, Interval, Once, Recurrence, ScheduledInvocation,
+};
 use golem_test_framework::config::TestDependencies;
 use golem_test_framework::dsl::{
     drain_connection, is_worker_execution_error, stdout_event_matching, stdout_events,
@@ -2722,3 +2727,822 @@ async fn stderr_returned_for_failed_component(
     check!(all[0].1.is_some());
     check!(all[0].1.clone().unwrap().ends_with(&expected_stderr));
 }
+
+#[test]
+#[tracing::instrument]
+async fn fork_worker(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-fork-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])],
+        )
+        .await;
+
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+    let cutoff = OplogIndex::from_u64(oplog.len() as u64);
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1001".to_string()),
+                Value::String("Golem Cloud Subscription 1y".to_string()),
+                Value::F32(999999.0),
+                Value::U32(1),
+            ])],
+        )
+        .await;
+
+    let target_worker_id = WorkerId {
+        component_id: component_id.clone(),
+        worker_name: "shopping-cart-fork-2".to_string(),
+    };
+    executor
+        .fork_worker(&worker_id, &target_worker_id, cutoff)
+        .await;
+
+    let original_contents = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+    let forked_contents = executor
+        .invoke_and_await(
+            &target_worker_id,
+            "golem:it/api.{get-cart-contents}",
+            vec![],
+        )
+        .await;
+
+    drop(executor);
+
+    check!(
+        original_contents
+            == Ok(vec![Value::List(vec![
+                Value::Record(vec![
+                    Value::String("G1000".to_string()),
+                    Value::String("Golem T-Shirt M".to_string()),
+                    Value::F32(100.0),
+                    Value::U32(5),
+                ]),
+                Value::Record(vec![
+                    Value::String("G1001".to_string()),
+                    Value::String("Golem Cloud Subscription 1y".to_string()),
+                    Value::F32(999999.0),
+                    Value::U32(1),
+                ]),
+            ])])
+    );
+    check!(
+        forked_contents
+            == Ok(vec![Value::List(vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])])])
+    );
+}
+
+#[test]
+#[tracing::instrument]
+async fn export_and_import_worker(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-export-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])],
+        )
+        .await;
+
+    let archive = executor.export_worker(&worker_id).await;
+
+    let target_worker_id = WorkerId {
+        component_id: component_id.clone(),
+        worker_name: "shopping-cart-export-2".to_string(),
+    };
+    executor.import_worker(&target_worker_id, archive).await;
+
+    let _ = executor
+        .invoke_and_await(
+            &target_worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1001".to_string()),
+                Value::String("Golem Cloud Subscription 1y".to_string()),
+                Value::F32(999999.0),
+                Value::U32(1),
+            ])],
+        )
+        .await;
+
+    let original_contents = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+    let imported_contents = executor
+        .invoke_and_await(
+            &target_worker_id,
+            "golem:it/api.{get-cart-contents}",
+            vec![],
+        )
+        .await;
+
+    drop(executor);
+
+    check!(
+        original_contents
+            == Ok(vec![Value::List(vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])])])
+    );
+    check!(
+        imported_contents
+            == Ok(vec![Value::List(vec![
+                Value::Record(vec![
+                    Value::String("G1000".to_string()),
+                    Value::String("Golem T-Shirt M".to_string()),
+                    Value::F32(100.0),
+                    Value::U32(5),
+                ]),
+                Value::Record(vec![
+                    Value::String("G1001".to_string()),
+                    Value::String("Golem Cloud Subscription 1y".to_string()),
+                    Value::F32(999999.0),
+                    Value::U32(1),
+                ]),
+            ])])
+    );
+}
+
+#[test]
+#[tracing::instrument]
+async fn scheduled_invocations(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-scheduled-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let _ = executor
+        .create_scheduled_invocation(
+            &worker_id,
+            "add-t-shirt",
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])],
+            Recurrence::Once(Once {}),
+        )
+        .await;
+    let daily = executor
+        .create_scheduled_invocation(
+            &worker_id,
+            "daily-checkout",
+            "golem:it/api.{checkout}",
+            vec![],
+            Recurrence::Cron(Cron {
+                expression: "0 3 * * *".to_string(),
+            }),
+        )
+        .await;
+
+    // Waiting for the scheduler to fire the one-off invocation
+    sleep(Duration::from_secs(6)).await;
+
+    let contents = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+    let remaining = executor.get_scheduled_invocations(&worker_id).await;
+
+    executor
+        .delete_scheduled_invocation(&worker_id, "daily-checkout")
+        .await;
+    let after_delete = executor.get_scheduled_invocations(&worker_id).await;
+
+    drop(executor);
+
+    check!(
+        contents
+            == Ok(vec![Value::List(vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])])])
+    );
+    check!(remaining == vec![daily]);
+    check!(after_delete.is_empty());
+}
+
+#[test]
+#[tracing::instrument]
+async fn scheduled_invocation_of_unloaded_worker(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-scheduled-2")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let _ = executor
+        .create_scheduled_invocation(
+            &worker_id,
+            "add-t-shirt",
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(1),
+            ])],
+            Recurrence::Interval(Interval {
+                interval_millis: 5000,
+            }),
+        )
+        .await;
+
+    // Restarting the executor unloads the idle worker before the first tick
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    sleep(Duration::from_secs(8)).await;
+
+    // Checking the oplog does not load the worker, so the invocation must have been performed
+    // by the scheduler
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+
+    executor
+        .delete_scheduled_invocation(&worker_id, "add-t-shirt")
+        .await;
+    drop(executor);
+
+    check!(count_invocations(&oplog, "golem:it/api.{add-item}") >= 1);
+}
+
+#[test]
+#[tracing::instrument]
+async fn delayed_invocations(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-delayed-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let schedule_at = Timestamp::from(Timestamp::now_utc().to_millis() + 3000);
+    let kept_key = IdempotencyKey::fresh();
+    let cancelled_key = IdempotencyKey::fresh();
+
+    let _ = executor
+        .invoke_at_with_key(
+            &worker_id,
+            &kept_key,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])],
+            schedule_at,
+        )
+        .await;
+    let _ = executor
+        .invoke_at_with_key(
+            &worker_id,
+            &cancelled_key,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1001".to_string()),
+                Value::String("Golem Cloud Subscription 1y".to_string()),
+                Value::F32(999999.0),
+                Value::U32(1),
+            ])],
+            schedule_at,
+        )
+        .await;
+
+    let contents_before = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+    let cancel_result = executor.cancel_invocation(&worker_id, &cancelled_key).await;
+    let scheduled = executor.get_scheduled_invocations(&worker_id).await;
+
+    // Waiting for the scheduler to fire the delayed invocation
+    sleep(Duration::from_secs(6)).await;
+
+    let contents_after = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+    let scheduled_after = executor.get_scheduled_invocations(&worker_id).await;
+
+    drop(executor);
+
+    check!(contents_before == Ok(vec![Value::List(vec![])]));
+    check!(cancel_result.is_ok());
+    check!(scheduled.len() == 1);
+    check!(scheduled[0].name == ScheduledInvocation::delayed_name(&kept_key));
+    check!(scheduled[0].idempotency_key == Some(kept_key));
+    check!(
+        contents_after
+            == Ok(vec![Value::List(vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])])])
+    );
+    check!(scheduled_after.is_empty());
+}
+
+#[test]
+#[tracing::instrument]
+async fn delayed_invocation_of_unloaded_worker(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-delayed-2")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let schedule_at = Timestamp::from(Timestamp::now_utc().to_millis() + 4000);
+    let key = IdempotencyKey::fresh();
+    let _ = executor
+        .invoke_at_with_key(
+            &worker_id,
+            &key,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])],
+            schedule_at,
+        )
+        .await;
+
+    // A named scheduled invocation cannot take over the delayed invocation's schedule
+    let conflicting = golem_test_framework::dsl::TestDsl::create_scheduled_invocation(
+        &executor,
+        &worker_id,
+        &ScheduledInvocation::delayed_name(&key),
+        "golem:it/api.{checkout}",
+        vec![],
+        Recurrence::Once(Once {}),
+    )
+    .await;
+
+    // Restarting the executor unloads the idle worker before the fire time
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    sleep(Duration::from_secs(7)).await;
+
+    // Checking the oplog does not load the worker, so the invocation must have been performed
+    // by the scheduler
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+    let scheduled_after = executor.get_scheduled_invocations(&worker_id).await;
+
+    drop(executor);
+
+    check!(conflicting.is_err());
+    check!(count_invocations(&oplog, "golem:it/api.{add-item}") == 1);
+    check!(count_invocations(&oplog, "golem:it/api.{checkout}") == 0);
+    check!(scheduled_after.is_empty());
+}
+
+#[test]
+#[tracing::instrument]
+async fn revert_worker_last_invocation(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "shopping-cart-revert-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(5),
+            ])],
+        )
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1001".to_string()),
+                Value::String("Golem Cloud Subscription 1y".to_string()),
+                Value::F32(999999.0),
+                Value::U32(1),
+            ])],
+        )
+        .await;
+
+    executor
+        .revert_worker(
+            &worker_id,
+            RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                number_of_invocations: 1,
+            }),
+        )
+        .await;
+
+    let contents_after_revert = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    let contents_after_restart = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+
+    drop(executor);
+
+    let expected = Ok(vec![Value::List(vec![Value::Record(vec![
+        Value::String("G1000".to_string()),
+        Value::String("Golem T-Shirt M".to_string()),
+        Value::F32(100.0),
+        Value::U32(5),
+    ])])]);
+    check!(contents_after_revert == expected);
+    check!(contents_after_restart == expected);
+}
+
+#[test]
+#[tracing::instrument]
+async fn cancel_pending_invocation(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("interruption").await;
+    let worker_id = executor
+        .start_worker(&component_id, "cancel-pending-invocation-1")
+        .await;
+
+    let running_key = IdempotencyKey::fresh();
+    let pending_key = IdempotencyKey::fresh();
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let running_key_clone = running_key.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await_with_key(worker_id_clone, &running_key_clone, "run", vec![])
+            .await
+    });
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let _ = executor
+        .invoke_with_key(&worker_id, &pending_key, "run", vec![])
+        .await;
+
+    let cancel_pending = executor.cancel_invocation(&worker_id, &pending_key).await;
+    let cancel_pending_again = executor.cancel_invocation(&worker_id, &pending_key).await;
+    let cancel_running = executor.cancel_invocation(&worker_id, &running_key).await;
+
+    let _ = executor.interrupt(&worker_id).await;
+    let _ = fiber.await.unwrap();
+
+    let cancellations = executor
+        .search_oplog(&worker_id, "cancel-pending-invocation")
+        .await;
+
+    drop(executor);
+
+    check!(cancel_pending.is_ok());
+    check!(cancel_pending_again.is_err());
+    check!(cancel_running.is_err());
+    check!(cancellations.len() == 1);
+}
+
+#[test]
+#[tracing::instrument]
+async fn oplog_length_limit_fails_worker(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor
+        .store_unique_component_with_limits(
+            "shopping-cart",
+            &WorkerResourceLimits {
+                max_oplog_length: Some(10),
+                ..Default::default()
+            },
+        )
+        .await;
+    let worker_id = executor
+        .start_worker(&component_id, "oplog-length-limit-1")
+        .await;
+
+    let first_error = add_items_until_failure(&executor, &worker_id).await;
+
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+    drop(executor);
+
+    check!(first_error.is_some());
+    check!(worker_error_message(&first_error.unwrap()).starts_with("Resource limit exceeded"));
+    check!(metadata.last_known_status.status == WorkerStatus::Failed);
+}
+
+#[test]
+#[tracing::instrument]
+async fn oplog_size_limit_fails_worker(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor
+        .store_unique_component_with_limits(
+            "shopping-cart",
+            &WorkerResourceLimits {
+                max_oplog_size: Some(1024),
+                ..Default::default()
+            },
+        )
+        .await;
+    let worker_id = executor
+        .start_worker(&component_id, "oplog-size-limit-1")
+        .await;
+
+    let first_error = add_items_until_failure(&executor, &worker_id).await;
+
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+    drop(executor);
+
+    check!(first_error.is_some());
+    check!(worker_error_message(&first_error.unwrap()).contains("Oplog size"));
+    check!(metadata.last_known_status.status == WorkerStatus::Failed);
+}
+
+#[test]
+#[tracing::instrument]
+async fn worker_env_overrides_component_limits(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor
+        .store_unique_component_with_limits(
+            "shopping-cart",
+            &WorkerResourceLimits {
+                max_oplog_length: Some(1000),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    let limited_worker_id = executor
+        .start_worker_with(
+            &component_id,
+            "worker-limits-override-1",
+            vec![],
+            HashMap::from([(
+                WorkerResourceLimits::MAX_OPLOG_LENGTH_ENV_VAR.to_string(),
+                "10".to_string(),
+            )]),
+        )
+        .await;
+    let unlimited_worker_id = executor
+        .start_worker(&component_id, "worker-limits-override-2")
+        .await;
+    let invalid_limit = executor
+        .try_start_worker_with(
+            &component_id,
+            "worker-limits-override-3",
+            vec![],
+            HashMap::from([(
+                WorkerResourceLimits::MAX_OPLOG_LENGTH_ENV_VAR.to_string(),
+                "unlimited".to_string(),
+            )]),
+        )
+        .await;
+
+    let strict_component_id = executor
+        .store_unique_component_with_limits(
+            "shopping-cart",
+            &WorkerResourceLimits {
+                max_oplog_length: Some(10),
+                ..Default::default()
+            },
+        )
+        .await;
+    let raised_worker_id = executor
+        .start_worker_with(
+            &strict_component_id,
+            "worker-limits-override-4",
+            vec![],
+            HashMap::from([(
+                WorkerResourceLimits::MAX_OPLOG_LENGTH_ENV_VAR.to_string(),
+                "1000".to_string(),
+            )]),
+        )
+        .await;
+
+    let limited_error = add_items_until_failure(&executor, &limited_worker_id).await;
+    let unlimited_error = add_items_until_failure(&executor, &unlimited_worker_id).await;
+    let raised_error = add_items_until_failure(&executor, &raised_worker_id).await;
+
+    drop(executor);
+
+    check!(limited_error.is_some());
+    check!(worker_error_message(&limited_error.unwrap()).contains("Oplog length"));
+    check!(unlimited_error.is_none());
+    check!(invalid_limit.is_err());
+    check!(raised_error.is_some());
+    check!(worker_error_message(&raised_error.unwrap()).contains("Oplog length"));
+}
+
+/// Adds items to a shopping cart worker until an invocation fails, returning the first error
+async fn add_items_until_failure(
+    executor: &TestWorkerExecutor,
+    worker_id: &WorkerId,
+) -> Option<golem_api_grpc::proto::golem::worker::v1::worker_error::Error> {
+    let _ = executor
+        .invoke_and_await(
+            worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    for _ in 0..10 {
+        let result = executor
+            .invoke_and_await(
+                worker_id,
+                "golem:it/api.{add-item}",
+                vec![Value::Record(vec![
+                    Value::String("G1000".to_string()),
+                    Value::String("Golem T-Shirt M".to_string()),
+                    Value::F32(100.0),
+                    Value::U32(5),
+                ])],
+            )
+            .await;
+        if let Err(error) = result {
+            return Some(error);
+        }
+    }
+
+    None
+}
+
+/// Counts the invocations of the given exported function in the oplog
+fn count_invocations(oplog: &[PublicOplogEntry], function_name: &str) -> usize {
+    oplog
+        .iter()
+        .filter(|entry| {
+            matches!(entry, PublicOplogEntry::ExportedFunctionInvoked(
+                ExportedFunctionInvokedParameters { function_name: name, .. }
+            ) if name == function_name)
+        })
+        .count()
+}
@@ -24,7 +24,7 @@ use golem_worker_executor_base::error::GolemError;
 use golem_worker_executor_base::services::golem_config::{
     CompiledComponentServiceConfig, CompiledComponentServiceEnabledConfig, ComponentServiceConfig,
     ComponentServiceLocalConfig, GolemConfig, IndexedStorageConfig, KeyValueStorageConfig,
-    MemoryConfig, ShardManagerServiceConfig, WorkerServiceGrpcConfig,
+    ShardManagerServiceConfig, WorkerServiceGrpcConfig,
 };
 
 use golem_worker_executor_base::durable_host::{
@@ -293,6 +293,17 @@ pub async fn start_limited(
     deps: &WorkerExecutorTestDependencies,
     context: &TestContext,
     system_memory_override: Option<u64>,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_customized(deps, context, |config| {
+        config.memory.system_memory_override = system_memory_override;
+    })
+    .await
+}
+
+pub async fn start_customized(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+    customize: impl FnOnce(&mut GolemConfig),
 ) -> anyhow::Result<TestWorkerExecutor> {
     let redis = deps.redis();
     let redis_monitor = deps.redis_monitor();
@@ -301,7 +312,7 @@ pub async fn start_limited(
     println!("Using Redis on port {}", redis.public_port());
 
     let prometheus = golem_worker_executor_base::metrics::register_all();
-    let config = GolemConfig {
+    let mut config = GolemConfig {
         key_value_storage: KeyValueStorageConfig::Redis(RedisConfig {
             port: redis.public_port(),
             key_prefix: context.redis_prefix(),
@@ -325,12 +336,9 @@ pub async fn start_limited(
             port: context.grpc_port(),
             access_token: "03494299-B515-4427-8C37-4C1C915679B7".to_string(),
         },
-        memory: MemoryConfig {
-            system_memory_override,
-            ..Default::default()
-        },
         ..Default::default()
     };
+    customize(&mut config);
 
     let handle = Handle::current();
 
@@ -513,6 +521,14 @@ impl InvocationManagement for TestWorkerCtx {
     fn is_replay(&self) -> bool {
         self.durable_ctx.is_replay()
     }
+
+    fn set_invocation_start_fuel_level(&mut self, level: u64) {
+        self.durable_ctx.set_invocation_start_fuel_level(level)
+    }
+
+    fn get_invocation_start_fuel_level(&self) -> u64 {
+        self.durable_ctx.get_invocation_start_fuel_level()
+    }
 }
 
 #[async_trait]
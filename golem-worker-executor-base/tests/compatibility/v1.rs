@@ -448,6 +448,9 @@ pub fn worker_status_record() {
             },
         )]),
         oplog_idx: OplogIndex::from_u64(10000),
+        latest_snapshot: None,
+        oplog_size: 0,
+        oplog_size_measured_until: OplogIndex::NONE,
     };
 
     let wsr2 = WorkerStatusRecord {
@@ -484,6 +487,9 @@ pub fn worker_status_record() {
             },
         )]),
         oplog_idx: OplogIndex::from_u64(10000),
+        latest_snapshot: None,
+        oplog_size: 0,
+        oplog_size_measured_until: OplogIndex::NONE,
     };
 
     let mut mint = Mint::new("tests/goldenfiles");
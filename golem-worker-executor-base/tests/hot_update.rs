@@ -17,8 +17,11 @@ use test_r::{inherit_test_dep, test};
 use crate::{common, LastUniqueId, Tracing, WorkerExecutorTestDependencies};
 use assert2::check;
 use async_mutex::Mutex;
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_test_framework::dsl::TestDslUnsafe;
 use golem_wasm_rpc::Value;
+use golem_worker_executor_base::services::golem_config::SnapshotConfig;
 use http_02::{Response, StatusCode};
 use log::info;
 use std::collections::HashMap;
@@ -712,3 +715,76 @@ async fn manual_update_on_idle_with_failing_load(
     check!(metadata.last_known_status.failed_updates.len() == 1);
     check!(metadata.last_known_status.successful_updates.is_empty());
 }
+
+#[test]
+#[tracing::instrument]
+async fn recovering_from_the_latest_snapshot(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = common::TestContext::new(last_unique_id);
+    let executor = common::start(deps, &context).await.unwrap();
+    let component_id = executor.store_unique_component("update-test-v3").await;
+    drop(executor);
+
+    let snapshot_config = SnapshotConfig {
+        enabled_components: vec![component_id.clone()],
+        invocations_between_snapshots: 2,
+    };
+    let executor = common::start_customized(deps, &context, |config| {
+        config.snapshot = snapshot_config.clone();
+    })
+    .await
+    .unwrap();
+
+    let worker_id = executor
+        .start_worker(&component_id, "recovering_from_the_latest_snapshot")
+        .await;
+
+    for value in [10, 20, 30] {
+        let _ = executor
+            .invoke_and_await(
+                &worker_id,
+                "golem:component/api.{set}",
+                vec![Value::U64(value)],
+            )
+            .await
+            .unwrap();
+    }
+
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+    let snapshots = oplog
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| matches!(entry, PublicOplogEntry::Snapshot(_)))
+        .map(|(idx, _)| OplogIndex::from_u64(idx as u64 + 1))
+        .collect::<Vec<_>>();
+    let last_invocation = oplog
+        .iter()
+        .rposition(|entry| matches!(entry, PublicOplogEntry::ExportedFunctionInvoked(_)))
+        .map(|idx| OplogIndex::from_u64(idx as u64 + 1));
+
+    drop(executor);
+    let executor = common::start_customized(deps, &context, |config| {
+        config.snapshot = snapshot_config.clone();
+    })
+    .await
+    .unwrap();
+
+    // Recovery loads the snapshot taken after the second invocation, and replays only the
+    // third invocation written after it
+    let result = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{get}", vec![])
+        .await
+        .unwrap();
+
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+
+    drop(executor);
+
+    check!(snapshots.len() == 1);
+    check!(snapshots.first().copied() < last_invocation);
+    check!(result == vec![Value::U64(30)]);
+    check!(metadata.last_known_status.latest_snapshot == snapshots.first().copied());
+}
@@ -13,13 +13,15 @@
 // limitations under the License.
 
 use crate::WorkerExecutorTestDependencies;
-use golem_common::config::RedisConfig;
+use golem_common::config::{DbPostgresConfig, RedisConfig};
 use golem_common::redis::RedisPool;
+use golem_service_base::storage::postgres::PostgresPool;
 use golem_service_base::storage::sqlite::SqlitePool;
 use golem_test_framework::components::redis::Redis;
 use golem_test_framework::components::redis_monitor::RedisMonitor;
 use golem_test_framework::config::TestDependencies;
 use golem_worker_executor_base::storage::indexed::memory::InMemoryIndexedStorage;
+use golem_worker_executor_base::storage::indexed::postgres::PostgresIndexedStorage;
 use golem_worker_executor_base::storage::indexed::redis::RedisIndexedStorage;
 use golem_worker_executor_base::storage::indexed::sqlite::SqliteIndexedStorage;
 use golem_worker_executor_base::storage::indexed::{IndexedStorage, IndexedStorageNamespace};
@@ -114,6 +116,35 @@ pub(crate) async fn sqlite_storage(
     SqliteIndexedStorageWrapper { sis }
 }
 
+struct PostgresIndexedStorageWrapper {
+    sis: PostgresIndexedStorage,
+}
+
+impl GetIndexedStorage for PostgresIndexedStorageWrapper {
+    fn get_indexed_storage(&self) -> &dyn IndexedStorage {
+        &self.sis
+    }
+}
+
+pub(crate) async fn postgres_storage(
+    deps: &WorkerExecutorTestDependencies,
+) -> impl GetIndexedStorage {
+    let info = deps.postgres().await;
+    let pool = PostgresPool::configured(&DbPostgresConfig {
+        host: info.host,
+        database: info.database_name,
+        username: info.username,
+        password: info.password,
+        port: info.port,
+        max_connections: 10,
+        schema: Some(format!("test_{}", Uuid::new_v4().simple())),
+    })
+    .await
+    .expect("Cannot connect to postgres db");
+    let sis = PostgresIndexedStorage::new(pool).await.unwrap();
+    PostgresIndexedStorageWrapper { sis }
+}
+
 pub fn ns() -> IndexedStorageNamespace {
     IndexedStorageNamespace::OpLog
 }
@@ -895,3 +926,4 @@ macro_rules! test_indexed_storage {
 test_indexed_storage!(in_memory, crate::indexed_storage::in_memory_storage);
 test_indexed_storage!(redis, crate::indexed_storage::redis_storage);
 test_indexed_storage!(sqlite, crate::indexed_storage::sqlite_storage);
+test_indexed_storage!(postgres, crate::indexed_storage::postgres_storage);
@@ -13,14 +13,16 @@
 // limitations under the License.
 
 use crate::WorkerExecutorTestDependencies;
-use golem_common::config::RedisConfig;
+use golem_common::config::{DbPostgresConfig, RedisConfig};
 use golem_common::model::AccountId;
 use golem_common::redis::RedisPool;
+use golem_service_base::storage::postgres::PostgresPool;
 use golem_service_base::storage::sqlite::SqlitePool;
 use golem_test_framework::components::redis::Redis;
 use golem_test_framework::components::redis_monitor::RedisMonitor;
 use golem_test_framework::config::TestDependencies;
 use golem_worker_executor_base::storage::keyvalue::memory::InMemoryKeyValueStorage;
+use golem_worker_executor_base::storage::keyvalue::postgres::PostgresKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::redis::RedisKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::sqlite::SqliteKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
@@ -118,6 +120,36 @@ pub(crate) async fn sqlite_storage(
     SqliteKeyValueStorageWrapper { kvs }
 }
 
+struct PostgresKeyValueStorageWrapper {
+    kvs: PostgresKeyValueStorage,
+}
+
+impl GetKeyValueStorage for PostgresKeyValueStorageWrapper {
+    fn get_key_value_storage(&self) -> &dyn KeyValueStorage {
+        &self.kvs
+    }
+}
+
+pub(crate) async fn postgres_storage(
+    deps: &WorkerExecutorTestDependencies,
+) -> impl GetKeyValueStorage {
+    let info = deps.postgres().await;
+    let pool = PostgresPool::configured(&DbPostgresConfig {
+        host: info.host,
+        database: info.database_name,
+        username: info.username,
+        password: info.password,
+        port: info.port,
+        max_connections: 10,
+        schema: Some(format!("test_{}", Uuid::new_v4().simple())),
+    })
+    .await
+    .expect("Cannot connect to postgres db");
+
+    let kvs = PostgresKeyValueStorage::new(pool).await.unwrap();
+    PostgresKeyValueStorageWrapper { kvs }
+}
+
 pub fn ns() -> KeyValueStorageNamespace {
     KeyValueStorageNamespace::Worker
 }
@@ -788,3 +820,9 @@ test_kv_storage!(
     crate::key_value_storage::ns2,
     crate::key_value_storage::ns
 );
+test_kv_storage!(
+    postgres,
+    crate::key_value_storage::postgres_storage,
+    crate::key_value_storage::ns,
+    crate::key_value_storage::ns2
+);
@@ -20,6 +20,7 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicU16;
 use std::sync::Arc;
 use test_r::{tag_suite, test_dep};
+use tokio::sync::OnceCell;
 use tracing::Level;
 
 use golem_common::tracing::{init_tracing_with_default_debug_env_filter, TracingConfig};
@@ -27,7 +28,8 @@ use golem_service_base::storage::blob::fs::FileSystemBlobStorage;
 use golem_test_framework::components::component_compilation_service::ComponentCompilationService;
 use golem_test_framework::components::component_service::filesystem::FileSystemComponentService;
 use golem_test_framework::components::component_service::ComponentService;
-use golem_test_framework::components::rdb::Rdb;
+use golem_test_framework::components::rdb::docker_postgres::DockerPostgresRdb;
+use golem_test_framework::components::rdb::{DbInfo, PostgresInfo, Rdb};
 use golem_test_framework::components::redis::provided::ProvidedRedis;
 use golem_test_framework::components::redis::spawned::SpawnedRedis;
 use golem_test_framework::components::redis::Redis;
@@ -153,6 +155,7 @@ pub struct WorkerExecutorTestDependencies {
     blob_storage: Arc<dyn BlobStorage + Send + Sync + 'static>,
     initial_component_files_service: Arc<InitialComponentFilesService>,
     component_directory: PathBuf,
+    postgres: OnceCell<Arc<dyn Rdb + Send + Sync + 'static>>,
 }
 
 impl Debug for WorkerExecutorTestDependencies {
@@ -191,6 +194,23 @@ impl WorkerExecutorTestDependencies {
             component_service,
             blob_storage,
             initial_component_files_service,
+            postgres: OnceCell::new(),
+        }
+    }
+
+    /// Connection info of a shared Postgres instance, started on first use
+    pub async fn postgres(&self) -> PostgresInfo {
+        let rdb = self
+            .postgres
+            .get_or_init(|| async {
+                let rdb: Arc<dyn Rdb + Send + Sync + 'static> =
+                    Arc::new(DockerPostgresRdb::new(true, false).await);
+                rdb
+            })
+            .await;
+        match rdb.info() {
+            DbInfo::Postgres(info) => info,
+            _ => panic!("Expected Postgres database"),
         }
     }
 
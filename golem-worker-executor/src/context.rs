@@ -158,6 +158,14 @@ impl InvocationManagement for Context {
     fn is_replay(&self) -> bool {
         self.durable_ctx.is_replay()
     }
+
+    fn set_invocation_start_fuel_level(&mut self, level: u64) {
+        self.durable_ctx.set_invocation_start_fuel_level(level)
+    }
+
+    fn get_invocation_start_fuel_level(&self) -> u64 {
+        self.durable_ctx.get_invocation_start_fuel_level()
+    }
 }
 
 #[async_trait]
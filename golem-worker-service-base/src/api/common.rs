@@ -213,6 +213,7 @@ mod conversion {
                 ApiDeploymentError::ComponentConstraintCreateError(_) => {
                     ApiEndpointError::internal(error)
                 }
+                ApiDeploymentError::InvalidCertificate(_) => ApiEndpointError::bad_request(error),
             }
         }
     }
@@ -1,3 +1,4 @@
+use std::fmt::Display;
 use std::future::Future;
 use std::sync::Arc;
 
@@ -5,19 +6,24 @@ use crate::gateway_api_definition::http::CompiledHttpApiDefinition;
 use crate::gateway_execution::file_server_binding_handler::FileServerBindingHandler;
 use crate::gateway_rib_interpreter::DefaultRibInterpreter;
 use futures_util::FutureExt;
-use hyper::header::HOST;
+use hyper::header::{HOST, WWW_AUTHENTICATE};
 use poem::http::StatusCode;
-use poem::{Body, Endpoint, Request, Response};
+use poem::web::websocket::WebSocket;
+use poem::{Body, Endpoint, FromRequest, Request, Response};
 use tracing::{error, info};
 
 use crate::gateway_execution::api_definition_lookup::ApiDefinitionsLookup;
 
-use crate::gateway_binding::GatewayBindingResolver;
+use crate::gateway_binding::{GatewayBindingResolver, GatewayBindingResolverError};
 use crate::gateway_execution::gateway_binding_executor::{
     DefaultGatewayBindingExecutor, GatewayBindingExecutor,
 };
+use crate::gateway_execution::gateway_rate_limiter::GatewayRateLimiter;
+use crate::gateway_execution::gateway_response_cache::GatewayResponseCache;
+use crate::gateway_execution::worker_stream_binding_handler::WorkerStreamBindingHandler;
 use crate::gateway_execution::GatewayWorkerRequestExecutor;
 use crate::gateway_request::http_request::{ApiInputPath, InputHttpRequest};
+use crate::gateway_request::request_details::WebSocketUpgrade;
 
 // Executes custom request with the help of worker_request_executor and definition_service
 // This is a common API projects can make use of, similar to healthcheck service
@@ -32,7 +38,7 @@ pub struct CustomHttpRequestApi<Namespace> {
         Arc<dyn GatewayBindingExecutor<Namespace, poem::Response> + Sync + Send>,
 }
 
-impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
+impl<Namespace: Display + Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
     pub fn new(
         worker_request_executor_service: Arc<dyn GatewayWorkerRequestExecutor + Sync + Send>,
         api_definition_lookup_service: Arc<
@@ -41,6 +47,9 @@ impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
                 + Send,
         >,
         fileserver_binding_handler: Arc<dyn FileServerBindingHandler<Namespace> + Sync + Send>,
+        rate_limiter: Arc<dyn GatewayRateLimiter + Sync + Send>,
+        response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
+        worker_stream_binding_handler: Arc<dyn WorkerStreamBindingHandler<Namespace> + Sync + Send>,
     ) -> Self {
         let evaluator = Arc::new(DefaultRibInterpreter::from_worker_request_executor(
             worker_request_executor_service.clone(),
@@ -49,6 +58,9 @@ impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
         let gateway_binding_executor = Arc::new(DefaultGatewayBindingExecutor {
             evaluator: evaluator.clone(),
             file_server_binding_handler: fileserver_binding_handler.clone(),
+            rate_limiter,
+            response_cache,
+            worker_stream_binding_handler,
         });
 
         Self {
@@ -58,6 +70,12 @@ impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
     }
 
     pub async fn execute(&self, request: Request) -> Response {
+        let client_address = request.remote_addr().as_socket_addr().map(|addr| addr.ip());
+        // Only requests asking for a WebSocket connection can be upgraded
+        let websocket_upgrade = WebSocket::from_request_without_body(&request)
+            .await
+            .ok()
+            .map(WebSocketUpgrade::new);
         let (req_parts, body) = request.into_parts();
         let headers = req_parts.headers;
         let uri = req_parts.uri;
@@ -95,6 +113,8 @@ impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
             headers,
             req_method: req_parts.method,
             req_body: json_request_body,
+            client_address,
+            websocket_upgrade,
         };
 
         let possible_api_definitions = match self
@@ -127,6 +147,27 @@ impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
                 response
             }
 
+            Err(GatewayBindingResolverError::Unauthorized(msg)) => {
+                info!("API request host: {} - unauthorized: {}", host, msg);
+
+                Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .header(WWW_AUTHENTICATE, "Bearer")
+                    .finish()
+            }
+
+            Err(GatewayBindingResolverError::InvalidRequest(errors)) => {
+                info!("API request host: {} - invalid request: {}", host, errors);
+
+                Response::builder()
+                    .status(StatusCode::BAD_REQUEST)
+                    .content_type("application/json")
+                    .body(
+                        Body::from_json(&errors)
+                            .unwrap_or_else(|_| Body::from_string(errors.to_string())),
+                    )
+            }
+
             Err(msg) => {
                 error!("Failed to resolve the API definition; error: {}", msg);
 
@@ -138,7 +179,9 @@ impl<Namespace: Clone + Send + Sync + 'static> CustomHttpRequestApi<Namespace> {
     }
 }
 
-impl<Namespace: Clone + Send + Sync + 'static> Endpoint for CustomHttpRequestApi<Namespace> {
+impl<Namespace: Display + Clone + Send + Sync + 'static> Endpoint
+    for CustomHttpRequestApi<Namespace>
+{
     type Output = Response;
 
     fn call(&self, req: Request) -> impl Future<Output = poem::Result<Self::Output>> + Send {
@@ -2,17 +2,26 @@ use crate::gateway_api_definition::http::{
     AllPathPatterns, CompiledHttpApiDefinition, CompiledRoute, MethodPattern,
 };
 use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
-use crate::gateway_api_deployment::ApiSite;
+use crate::gateway_api_deployment::{ApiSite, ApiSiteCertificate};
 use crate::gateway_binding::{
-    GatewayBinding, GatewayBindingCompiled, StaticBinding, WorkerBinding, WorkerBindingCompiled,
+    GatewayBinding, GatewayBindingCompiled, ResponseCache, StaticBinding, TrafficSplit,
+    WorkerBinding, WorkerBindingCompiled,
 };
-use crate::gateway_middleware::{Cors, CorsPreflightExpr, HttpMiddleware, Middleware};
+use crate::gateway_execution::api_definition_tester;
+use crate::gateway_middleware::{
+    Cors, CorsPreflightExpr, HttpMiddleware, JwtAuthentication, Middleware, RateLimit,
+    Transformation,
+};
+use crate::gateway_request::http_request::{ApiInputPath, InputHttpRequest};
 use golem_api_grpc::proto::golem::apidefinition as grpc_apidefinition;
 use golem_common::model::GatewayBindingType;
 use golem_service_base::model::VersionedComponentId;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use http::{HeaderMap, HeaderName, HeaderValue};
 use poem_openapi::*;
 use rib::RibInputTypeInfo;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::result::Result;
 use std::time::SystemTime;
 
@@ -22,6 +31,7 @@ use std::time::SystemTime;
 pub struct ApiDeploymentRequest {
     pub api_definitions: Vec<ApiDefinitionInfo>,
     pub site: ApiSite,
+    pub certificate: Option<ApiSiteCertificate>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
@@ -30,6 +40,8 @@ pub struct ApiDeploymentRequest {
 pub struct ApiDeployment {
     pub api_definitions: Vec<ApiDefinitionInfo>,
     pub site: ApiSite,
+    // PEM encoded certificate chain of the site, the private key is never returned
+    pub certificate: Option<String>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
@@ -41,6 +53,88 @@ pub struct ApiDefinitionInfo {
     pub version: ApiVersion,
 }
 
+// Sample request sent to an API definition in a dry-run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiDefinitionTestRequest {
+    pub method: MethodPattern,
+    // Path of the request including the query, Example: /users/1?verbose=true
+    pub path: String,
+    #[serde(default)]
+    #[oai(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<serde_json::Value>,
+    // Returned for every worker call instead of invoking the worker,
+    // in the same format as the result of invoking a worker function
+    pub mock_worker_result: Option<TypeAnnotatedValue>,
+}
+
+impl ApiDefinitionTestRequest {
+    pub fn to_input_http_request(&self) -> Result<InputHttpRequest, String> {
+        let (base_path, query_path) = match self.path.split_once('?') {
+            Some((base_path, query_path)) => (base_path.to_string(), Some(query_path.to_string())),
+            None => (self.path.clone(), None),
+        };
+
+        let mut headers = HeaderMap::new();
+
+        for (name, value) in &self.headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| format!("Invalid header name: {}", name))?;
+            let header_value = HeaderValue::from_str(value)
+                .map_err(|_| format!("Invalid value of header {}", name))?;
+
+            headers.insert(header_name, header_value);
+        }
+
+        Ok(InputHttpRequest {
+            input_path: ApiInputPath {
+                base_path,
+                query_path,
+            },
+            headers,
+            req_method: self.method.clone().into(),
+            req_body: self.body.clone().unwrap_or(serde_json::Value::Null),
+            client_address: None,
+            websocket_upgrade: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiDefinitionTestResult {
+    pub binding_type: GatewayBindingType,
+    // Method and path pattern of the matched route
+    pub route: Option<String>,
+    pub worker_name: Option<String>,
+    pub idempotency_key: Option<String>,
+    pub response_mapping_result: Option<serde_json::Value>,
+    pub response_mapping_error: Option<String>,
+}
+
+impl From<api_definition_tester::ApiDefinitionTestResult> for ApiDefinitionTestResult {
+    fn from(value: api_definition_tester::ApiDefinitionTestResult) -> Self {
+        let (response_mapping_result, response_mapping_error) = match value.response_mapping_result
+        {
+            Some(Ok(result)) => (Some(result), None),
+            Some(Err(error)) => (None, Some(error)),
+            None => (None, None),
+        };
+
+        Self {
+            binding_type: value.binding_type,
+            route: value.route,
+            worker_name: value.worker_name,
+            idempotency_key: value.idempotency_key.map(|key| key.value),
+            response_mapping_result,
+            response_mapping_error,
+        }
+    }
+}
+
 // Mostly this data structures that represents the actual incoming request
 // exist due to the presence of complicated Expr data type in gateway_api_definition::ApiDefinition.
 // Consider them to be otherwise same
@@ -147,6 +241,10 @@ pub struct GatewayBindingData {
     // For binding type - worker
     // Optional only to keep backward compatibility
     pub middleware: Option<MiddlewareData>,
+    // For binding type - worker
+    pub traffic_split: Option<TrafficSplit>,
+    // For binding type - worker
+    pub response_cache: Option<ResponseCache>,
 
     // CORS
     //  For binding type - cors-middleware
@@ -186,11 +284,25 @@ impl GatewayBindingData {
         };
 
         let middleware = worker_binding.middleware.and_then(|x| {
-            x.0.iter().find_map(|m| {
-                m.get_cors().map(|c| MiddlewareData {
-                    cors: Some(c.clone()),
+            let cors = x.get_cors();
+            let jwt_authentication = x.get_jwt_authentication();
+            let rate_limit = x.get_rate_limit();
+            let transformation = x.get_transformation();
+
+            if cors.is_none()
+                && jwt_authentication.is_none()
+                && rate_limit.is_none()
+                && transformation.is_none()
+            {
+                None
+            } else {
+                Some(MiddlewareData {
+                    cors,
+                    jwt_authentication,
+                    rate_limit,
+                    transformation,
                 })
-            })
+            }
         });
 
         Ok(Self {
@@ -206,6 +318,8 @@ impl GatewayBindingData {
             max_age: None,
             allow_credentials: None,
             middleware,
+            traffic_split: worker_binding.traffic_split,
+            response_cache: worker_binding.response_cache,
         })
     }
 }
@@ -215,6 +329,9 @@ impl GatewayBindingData {
 #[oai(rename_all = "camelCase")]
 pub struct MiddlewareData {
     pub cors: Option<Cors>,
+    pub jwt_authentication: Option<JwtAuthentication>,
+    pub rate_limit: Option<RateLimit>,
+    pub transformation: Option<Transformation>,
 }
 
 // GolemWorkerBindingWithTypeInfo is a subset of CompiledGolemWorkerBinding
@@ -234,6 +351,8 @@ pub struct GatewayBindingWithTypeInfo {
     pub worker_name_input: Option<RibInputTypeInfo>,
     pub idempotency_key_input: Option<RibInputTypeInfo>,
     pub cors_preflight: Option<Cors>,
+    pub traffic_split: Option<TrafficSplit>,
+    pub response_cache: Option<ResponseCache>,
 }
 
 impl GatewayBindingWithTypeInfo {
@@ -265,6 +384,8 @@ impl GatewayBindingWithTypeInfo {
                 .idempotency_key_compiled
                 .map(|idempotency_key_compiled| idempotency_key_compiled.rib_input),
             cors_preflight: None,
+            traffic_split: worker_binding.traffic_split,
+            response_cache: worker_binding.response_cache,
         }
     }
 }
@@ -286,6 +407,12 @@ impl From<GatewayBindingCompiled> for GatewayBindingWithTypeInfo {
                     GatewayBindingType::Default,
                 )
             }
+            GatewayBindingCompiled::Stream(worker_binding) => {
+                GatewayBindingWithTypeInfo::from_worker_binding_compiled(
+                    worker_binding,
+                    GatewayBindingType::Stream,
+                )
+            }
             GatewayBindingCompiled::Static(static_binding) => GatewayBindingWithTypeInfo {
                 component_id: None,
                 worker_name: None,
@@ -296,6 +423,8 @@ impl From<GatewayBindingCompiled> for GatewayBindingWithTypeInfo {
                 worker_name_input: None,
                 idempotency_key_input: None,
                 cors_preflight: static_binding.get_cors_preflight(),
+                traffic_split: None,
+                response_cache: None,
             },
         }
     }
@@ -315,6 +444,7 @@ impl<N> From<crate::gateway_api_deployment::ApiDeployment<N>> for ApiDeployment
         Self {
             api_definitions,
             site: value.site,
+            certificate: value.certificate,
             created_at: Some(value.created_at),
         }
     }
@@ -412,6 +542,10 @@ impl TryFrom<GatewayBinding> for GatewayBindingData {
                 GatewayBindingType::FileServer,
             ),
 
+            GatewayBinding::Stream(worker_binding) => {
+                GatewayBindingData::from_worker_binding(worker_binding, GatewayBindingType::Stream)
+            }
+
             GatewayBinding::Static(StaticBinding::HttpCorsPreflight(cors)) => Ok(Self {
                 binding_type: Some(GatewayBindingType::CorsPreflight),
                 component_id: None,
@@ -425,6 +559,8 @@ impl TryFrom<GatewayBinding> for GatewayBindingData {
                 max_age: cors.get_max_age(),
                 allow_credentials: cors.get_allow_credentials(),
                 middleware: None,
+                traffic_split: None,
+                response_cache: None,
             }),
         }
     }
@@ -437,7 +573,10 @@ impl TryFrom<GatewayBindingData> for GatewayBinding {
         let v = gateway_binding_data.clone().binding_type;
 
         match v {
-            Some(GatewayBindingType::Default) | Some(GatewayBindingType::FileServer) | None => {
+            Some(GatewayBindingType::Default)
+            | Some(GatewayBindingType::FileServer)
+            | Some(GatewayBindingType::Stream)
+            | None => {
                 let response = gateway_binding_data
                     .response
                     .ok_or("Missing response field in binding")?;
@@ -466,6 +605,26 @@ impl TryFrom<GatewayBindingData> for GatewayBinding {
                     if let Some(cors) = middle_ware_daa.cors {
                         middlewares.push(Middleware::http(HttpMiddleware::cors(cors)));
                     }
+                    if let Some(jwt_authentication) = middle_ware_daa.jwt_authentication {
+                        jwt_authentication.validate()?;
+                        middlewares.push(Middleware::http(HttpMiddleware::jwt_authentication(
+                            jwt_authentication,
+                        )));
+                    }
+                    if let Some(rate_limit) = middle_ware_daa.rate_limit {
+                        rate_limit.validate()?;
+                        middlewares.push(Middleware::http(HttpMiddleware::rate_limit(rate_limit)));
+                    }
+                    if let Some(transformation) = middle_ware_daa.transformation {
+                        transformation.validate()?;
+                        middlewares.push(Middleware::http(HttpMiddleware::transformation(
+                            transformation,
+                        )));
+                    }
+                }
+
+                if let Some(response_cache) = &gateway_binding_data.response_cache {
+                    response_cache.validate()?;
                 }
 
                 let worker_binding = WorkerBinding {
@@ -478,12 +637,16 @@ impl TryFrom<GatewayBindingData> for GatewayBinding {
                     } else {
                         Some(crate::gateway_middleware::Middlewares(middlewares))
                     },
+                    traffic_split: gateway_binding_data.traffic_split,
+                    response_cache: gateway_binding_data.response_cache,
                 };
 
-                if v == Some(GatewayBindingType::FileServer) {
-                    Ok(GatewayBinding::FileServer(worker_binding))
-                } else {
-                    Ok(GatewayBinding::Default(worker_binding))
+                match v {
+                    Some(GatewayBindingType::FileServer) => {
+                        Ok(GatewayBinding::FileServer(worker_binding))
+                    }
+                    Some(GatewayBindingType::Stream) => Ok(GatewayBinding::Stream(worker_binding)),
+                    _ => Ok(GatewayBinding::Default(worker_binding)),
                 }
             }
 
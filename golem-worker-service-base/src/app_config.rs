@@ -23,6 +23,7 @@ pub struct WorkerServiceBaseConfig {
     pub component_service: ComponentServiceConfig,
     pub port: u16,
     pub custom_request_port: u16,
+    pub custom_request_tls: CustomRequestTlsConfig,
     pub worker_grpc_port: u16,
     pub routing_table: RoutingTableConfig,
     pub worker_executor_retries: RetryConfig,
@@ -47,6 +48,7 @@ impl Default for WorkerServiceBaseConfig {
             tracing: TracingConfig::local_dev("worker-service"),
             port: 9005,
             custom_request_port: 9006,
+            custom_request_tls: CustomRequestTlsConfig::default(),
             worker_grpc_port: 9007,
             routing_table: RoutingTableConfig::default(),
             worker_executor_retries: RetryConfig {
@@ -118,3 +120,24 @@ impl Default for ComponentServiceConfig {
         }
     }
 }
+
+// Serves the custom request API over HTTPS, using the certificates of the API deployments.
+// The certificate is selected based on the SNI of the client, and certificates
+// are reloaded from the deployment repo every `certificate_refresh_interval`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomRequestTlsConfig {
+    pub enabled: bool,
+    pub port: u16,
+    #[serde(with = "humantime_serde")]
+    pub certificate_refresh_interval: Duration,
+}
+
+impl Default for CustomRequestTlsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 9443,
+            certificate_refresh_interval: Duration::from_secs(30),
+        }
+    }
+}
@@ -6,8 +6,8 @@ use Iterator;
 
 use crate::gateway_api_definition::http::path_pattern_parser::parse_path_pattern;
 use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion, HasGolemBindings};
-use crate::gateway_binding::WorkerBindingCompiled;
 use crate::gateway_binding::{GatewayBinding, GatewayBindingCompiled};
+use crate::gateway_binding::{WorkerBinding, WorkerBindingCompiled};
 use crate::gateway_middleware::Cors;
 use bincode::{Decode, Encode};
 use derive_more::Display;
@@ -442,6 +442,8 @@ impl CompiledRoute {
                     WorkerBindingCompiled::from_raw_worker_binding(worker_binding, metadata)
                         .map_err(RouteCompilationErrors::RibCompilationError)?;
 
+                Self::compile_traffic_split_targets(worker_binding, metadata_dictionary)?;
+
                 Ok(CompiledRoute {
                     method: route.method.clone(),
                     path: route.path.clone(),
@@ -461,6 +463,8 @@ impl CompiledRoute {
                     WorkerBindingCompiled::from_raw_worker_binding(worker_binding, metadata)
                         .map_err(RouteCompilationErrors::RibCompilationError)?;
 
+                Self::compile_traffic_split_targets(worker_binding, metadata_dictionary)?;
+
                 Ok(CompiledRoute {
                     method: route.method.clone(),
                     path: route.path.clone(),
@@ -468,6 +472,27 @@ impl CompiledRoute {
                 })
             }
 
+            GatewayBinding::Stream(worker_binding) => {
+                let metadata = metadata_dictionary
+                    .metadata
+                    .get(&worker_binding.component_id)
+                    .ok_or(RouteCompilationErrors::MetadataNotFoundError(
+                        worker_binding.component_id.clone(),
+                    ))?;
+
+                let binding =
+                    WorkerBindingCompiled::from_raw_worker_binding(worker_binding, metadata)
+                        .map_err(RouteCompilationErrors::RibCompilationError)?;
+
+                Self::compile_traffic_split_targets(worker_binding, metadata_dictionary)?;
+
+                Ok(CompiledRoute {
+                    method: route.method.clone(),
+                    path: route.path.clone(),
+                    binding: GatewayBindingCompiled::Stream(binding),
+                })
+            }
+
             GatewayBinding::Static(static_binding) => Ok(CompiledRoute {
                 method: route.method.clone(),
                 path: route.path.clone(),
@@ -475,6 +500,36 @@ impl CompiledRoute {
             }),
         }
     }
+
+    // The Rib expressions of the binding are compiled against the component of the binding,
+    // but they are evaluated against the traffic split targets as well, and hence need to compile for them too
+    fn compile_traffic_split_targets(
+        worker_binding: &WorkerBinding,
+        metadata_dictionary: &ComponentMetadataDictionary,
+    ) -> Result<(), RouteCompilationErrors> {
+        let targets = worker_binding
+            .traffic_split
+            .as_ref()
+            .map(|traffic_split| traffic_split.component_ids())
+            .unwrap_or_default();
+
+        for component_id in targets {
+            let metadata = metadata_dictionary.metadata.get(&component_id).ok_or(
+                RouteCompilationErrors::MetadataNotFoundError(component_id.clone()),
+            )?;
+
+            WorkerBindingCompiled::from_raw_worker_binding(worker_binding, metadata).map_err(
+                |err| {
+                    RouteCompilationErrors::RibCompilationError(format!(
+                        "Traffic split target {}. {}",
+                        component_id, err
+                    ))
+                },
+            )?;
+        }
+
+        Ok(())
+    }
 }
 
 impl From<CompiledRoute> for Route {
@@ -97,16 +97,20 @@ impl poem_openapi::types::Type for OpenApiDefinitionRequest {
     }
 }
 
-mod internal {
+pub(crate) mod internal {
     use crate::gateway_api_definition::http::{AllPathPatterns, MethodPattern, Route};
     use golem_common::model::{ComponentId, GatewayBindingType};
     use openapiv3::{OpenAPI, Operation, Paths, ReferenceOr};
     use rib::Expr;
     use serde_json::Value;
 
-    use crate::gateway_binding::{GatewayBinding, ResponseMapping, StaticBinding, WorkerBinding};
+    use crate::gateway_binding::{
+        GatewayBinding, ResponseCache, ResponseMapping, StaticBinding, TrafficSplit, TrafficTarget,
+        WorkerBinding,
+    };
     use crate::gateway_middleware::{
-        Cors, CorsPreflightExpr, HttpMiddleware, Middleware, Middlewares,
+        Cors, CorsPreflightExpr, HttpMiddleware, JwtAuthentication, Middleware, Middlewares,
+        RateLimit, Transformation,
     };
     use golem_service_base::model::VersionedComponentId;
     use uuid::Uuid;
@@ -215,9 +219,23 @@ mod internal {
                         })
                     }
 
+                    (GatewayBindingType::Stream, MethodPattern::Get) => {
+                        let binding = get_worker_binding(worker_gateway_info)?;
+
+                        Ok(Route {
+                            path: path_pattern.clone(),
+                            method,
+                            binding: GatewayBinding::Stream(binding),
+                        })
+                    }
+
                     (GatewayBindingType::CorsPreflight, method) => {
                         Err(format!("cors-preflight binding type is supported only for 'options' method, but found method '{}'", method))
                     }
+
+                    (GatewayBindingType::Stream, method) => {
+                        Err(format!("stream binding type is supported only for 'get' method, but found method '{}'", method))
+                    }
                 }
             }
 
@@ -259,6 +277,8 @@ mod internal {
             idempotency_key: get_idempotency_key(worker_gateway_info)?,
             response_mapping: get_response_mapping(worker_gateway_info)?,
             middleware: binding_middleware,
+            traffic_split: get_traffic_split(worker_gateway_info)?,
+            response_cache: get_response_cache(worker_gateway_info)?,
         };
 
         Ok(binding)
@@ -309,6 +329,56 @@ mod internal {
         })
     }
 
+    // Targets are listed the same way as the component of the binding,
+    // Example: [{"component-id": "...", "component-version": 2, "weight": 10}]
+    pub(crate) fn get_traffic_split(
+        worker_gateway_info: &Value,
+    ) -> Result<Option<TrafficSplit>, String> {
+        match worker_gateway_info.get("traffic-split") {
+            Some(Value::Array(targets)) => {
+                let targets = targets
+                    .iter()
+                    .map(|target| {
+                        let weight = target
+                            .get("weight")
+                            .ok_or("No weight found for traffic split target")?
+                            .as_u64()
+                            .and_then(|weight| u32::try_from(weight).ok())
+                            .ok_or("weight of traffic split target is not a u32")?;
+
+                        Ok(TrafficTarget {
+                            component_id: get_component_id(target)?,
+                            weight,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+
+                Ok(Some(TrafficSplit { targets }))
+            }
+            Some(_) => {
+                Err("Invalid schema for traffic-split. It should be a list of targets".to_string())
+            }
+            None => Ok(None),
+        }
+    }
+
+    // Example: {"keyExpr": "let user: str = request.path.user; user", "ttlSeconds": 30}
+    pub(crate) fn get_response_cache(
+        worker_gateway_info: &Value,
+    ) -> Result<Option<ResponseCache>, String> {
+        let response_cache: Option<ResponseCache> = worker_gateway_info
+            .get("response-cache")
+            .map(|json_value| serde_json::from_value(json_value.clone()))
+            .transpose()
+            .map_err(|err| format!("Invalid schema for response-cache. {}", err))?;
+
+        if let Some(response_cache) = &response_cache {
+            response_cache.validate()?;
+        }
+
+        Ok(response_cache)
+    }
+
     pub(crate) fn get_binding_type(
         worker_gateway_info: &Value,
     ) -> Result<GatewayBindingType, String> {
@@ -337,6 +407,39 @@ mod internal {
                     if let Some(cors_preflight) = cors_preflight {
                         middlewares.push(HttpMiddleware::cors(cors_preflight));
                     }
+
+                    let jwt_authentication: Option<JwtAuthentication> = map
+                        .get("jwt-authentication")
+                        .map(|json_value| serde_json::from_value(json_value.clone()))
+                        .transpose()
+                        .map_err(|err| format!("Invalid schema for JWT authentication {}", err))?;
+
+                    if let Some(jwt_authentication) = jwt_authentication {
+                        jwt_authentication.validate()?;
+                        middlewares.push(HttpMiddleware::jwt_authentication(jwt_authentication));
+                    }
+
+                    let rate_limit: Option<RateLimit> = map
+                        .get("rate-limit")
+                        .map(|json_value| serde_json::from_value(json_value.clone()))
+                        .transpose()
+                        .map_err(|err| format!("Invalid schema for rate limit {}", err))?;
+
+                    if let Some(rate_limit) = rate_limit {
+                        rate_limit.validate()?;
+                        middlewares.push(HttpMiddleware::rate_limit(rate_limit));
+                    }
+
+                    let transformation: Option<Transformation> = map
+                        .get("transformation")
+                        .map(|json_value| serde_json::from_value(json_value.clone()))
+                        .transpose()
+                        .map_err(|err| format!("Invalid schema for transformation {}", err))?;
+
+                    if let Some(transformation) = transformation {
+                        transformation.validate()?;
+                        middlewares.push(HttpMiddleware::transformation(transformation));
+                    }
                 }
                 _ => return Err(
                     "Invalid response mapping type. It should be a string representing expression"
@@ -404,12 +507,18 @@ mod tests {
 
     use super::*;
     use crate::gateway_api_definition::http::{AllPathPatterns, MethodPattern, Route};
-    use crate::gateway_binding::{GatewayBinding, ResponseMapping, StaticBinding, WorkerBinding};
-    use crate::gateway_middleware::{Cors, HttpMiddleware, Middleware, Middlewares};
+    use crate::gateway_binding::{
+        GatewayBinding, ResponseCache, ResponseMapping, StaticBinding, TrafficSplit, TrafficTarget,
+        WorkerBinding,
+    };
+    use crate::gateway_middleware::{
+        Cors, HttpMiddleware, JwtAuthentication, Middleware, Middlewares, RateLimit, Transformation,
+    };
     use golem_common::model::ComponentId;
     use openapiv3::Operation;
     use rib::Expr;
     use serde_json::json;
+    use std::collections::BTreeMap;
     use uuid::Uuid;
 
     #[test]
@@ -443,6 +552,255 @@ mod tests {
         assert_eq!(result, Ok(expected));
     }
 
+    #[test]
+    fn test_get_route_from_path_with_worker_binding_with_jwt_authentication() {
+        let path_item = Operation {
+            extensions: vec![(
+                "x-golem-api-gateway-binding".to_string(),
+                json!({
+                    "worker-name": "let x: str = request.auth.claims.sub; \"worker-${x}\"",
+                    "component-id": "00000000-0000-0000-0000-000000000000",
+                    "component-version": 0,
+                    "response": "${worker.response}",
+                    "middlewares": {
+                        "jwt-authentication" : {
+                            "issuer": "https://issuer.golem.cloud",
+                            "audience": "shopping-cart",
+                            "jwksUri": "https://issuer.golem.cloud/.well-known/jwks.json"
+                        }
+                    }
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/test").unwrap();
+
+        let result = get_route_from_path_item("get", &path_item, &path_pattern).unwrap();
+
+        let expected = JwtAuthentication::from_parameters(
+            "https://issuer.golem.cloud".to_string(),
+            Some("shopping-cart".to_string()),
+            "https://issuer.golem.cloud/.well-known/jwks.json".to_string(),
+        )
+        .unwrap();
+
+        match result.binding {
+            GatewayBinding::Default(worker_binding) => assert_eq!(
+                worker_binding.middleware,
+                Some(Middlewares(vec![Middleware::jwt_authentication(&expected)]))
+            ),
+            _ => panic!("Expected a worker binding"),
+        }
+    }
+
+    #[test]
+    fn test_get_route_with_invalid_jwks_uri() {
+        let path_item = Operation {
+            extensions: vec![(
+                "x-golem-api-gateway-binding".to_string(),
+                json!({
+                    "component-id": "00000000-0000-0000-0000-000000000000",
+                    "component-version": 0,
+                    "response": "${worker.response}",
+                    "middlewares": {
+                        "jwt-authentication" : {
+                            "issuer": "https://issuer.golem.cloud",
+                            "jwksUri": "jwks.json"
+                        }
+                    }
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/test").unwrap();
+
+        let result = get_route_from_path_item("get", &path_item, &path_pattern);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_route_from_path_with_worker_binding_with_rate_limit() {
+        let path_item = Operation {
+            extensions: vec![(
+                "x-golem-api-gateway-binding".to_string(),
+                json!({
+                    "component-id": "00000000-0000-0000-0000-000000000000",
+                    "component-version": 0,
+                    "response": "${worker.response}",
+                    "middlewares": {
+                        "rate-limit" : {
+                            "requests": 10,
+                            "periodSeconds": 60,
+                            "keyExpr": "let user: str = request.headers.user; user"
+                        }
+                    }
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/test").unwrap();
+
+        let result = get_route_from_path_item("get", &path_item, &path_pattern).unwrap();
+
+        let expected = RateLimit::from_parameters(
+            10,
+            60,
+            None,
+            Some("let user: str = request.headers.user; user".to_string()),
+        )
+        .unwrap();
+
+        match result.binding {
+            GatewayBinding::Default(worker_binding) => assert_eq!(
+                worker_binding.middleware,
+                Some(Middlewares(vec![Middleware::rate_limit(&expected)]))
+            ),
+            _ => panic!("Expected a worker binding"),
+        }
+    }
+
+    #[test]
+    fn test_get_route_from_path_with_worker_binding_with_traffic_split() {
+        let path_item = Operation {
+            extensions: vec![(
+                "x-golem-api-gateway-binding".to_string(),
+                json!({
+                    "component-id": "00000000-0000-0000-0000-000000000000",
+                    "component-version": 0,
+                    "response": "${worker.response}",
+                    "traffic-split": [
+                        {
+                            "component-id": "00000000-0000-0000-0000-000000000000",
+                            "component-version": 1,
+                            "weight": 10
+                        }
+                    ]
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/test").unwrap();
+
+        let result = get_route_from_path_item("get", &path_item, &path_pattern).unwrap();
+
+        let expected = TrafficSplit {
+            targets: vec![TrafficTarget {
+                component_id: golem_service_base::model::VersionedComponentId {
+                    component_id: ComponentId(Uuid::nil()),
+                    version: 1,
+                },
+                weight: 10,
+            }],
+        };
+
+        match result.binding {
+            GatewayBinding::Default(worker_binding) => {
+                assert_eq!(worker_binding.traffic_split, Some(expected))
+            }
+            _ => panic!("Expected a worker binding"),
+        }
+    }
+
+    #[test]
+    fn test_get_route_from_path_with_worker_binding_with_response_cache() {
+        let path_item = Operation {
+            extensions: vec![(
+                "x-golem-api-gateway-binding".to_string(),
+                json!({
+                    "component-id": "00000000-0000-0000-0000-000000000000",
+                    "component-version": 0,
+                    "response": "${worker.response}",
+                    "response-cache" : {
+                        "keyExpr": "let user: str = request.path.user; user",
+                        "ttlSeconds": 30
+                    }
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/test/{user}").unwrap();
+
+        let result = get_route_from_path_item("get", &path_item, &path_pattern).unwrap();
+
+        let expected = ResponseCache::from_parameters(
+            "let user: str = request.path.user; user".to_string(),
+            30,
+        )
+        .unwrap();
+
+        match result.binding {
+            GatewayBinding::Default(worker_binding) => {
+                assert_eq!(worker_binding.response_cache, Some(expected))
+            }
+            _ => panic!("Expected a worker binding"),
+        }
+    }
+
+    #[test]
+    fn test_get_route_from_path_with_worker_binding_with_transformation() {
+        let path_item = Operation {
+            extensions: vec![(
+                "x-golem-api-gateway-binding".to_string(),
+                json!({
+                    "component-id": "00000000-0000-0000-0000-000000000000",
+                    "component-version": 0,
+                    "response": "${worker.response}",
+                    "middlewares": {
+                        "transformation" : {
+                            "removeRequestHeaders": ["cookie"],
+                            "rewritePath": "/v2/test/{user}",
+                            "correlationIdHeader": "x-correlation-id",
+                            "addResponseHeaders": {
+                                "x-frame-options": "DENY"
+                            }
+                        }
+                    }
+                }),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/test/{user}").unwrap();
+
+        let result = get_route_from_path_item("get", &path_item, &path_pattern).unwrap();
+
+        let expected = Transformation::from_parameters(
+            BTreeMap::new(),
+            vec!["cookie".to_string()],
+            Some("/v2/test/{user}".to_string()),
+            Some("x-correlation-id".to_string()),
+            BTreeMap::from([("x-frame-options".to_string(), "DENY".to_string())]),
+            vec![],
+        )
+        .unwrap();
+
+        match result.binding {
+            GatewayBinding::Default(worker_binding) => assert_eq!(
+                worker_binding.middleware,
+                Some(Middlewares(vec![Middleware::transformation(&expected)]))
+            ),
+            _ => panic!("Expected a worker binding"),
+        }
+    }
+
     #[test]
     fn test_get_route_with_cors_preflight_binding() {
         let path_item = Operation {
@@ -568,6 +926,8 @@ mod tests {
                     )
                     .unwrap(),
                 ))])),
+                traffic_split: None,
+                response_cache: None,
             }),
         }
     }
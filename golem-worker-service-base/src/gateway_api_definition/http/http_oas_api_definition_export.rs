@@ -0,0 +1,634 @@
+use crate::gateway_api_definition::http::CompiledHttpApiDefinition;
+use internal::*;
+use openapiv3::OpenAPI;
+use poem_openapi::registry::{MetaSchema, MetaSchemaRef};
+use poem_openapi::types::ToJSON;
+use serde_json::Value;
+use std::borrow::Cow;
+
+// The OpenAPI 3 representation of a deployed (compiled) API definition.
+// Schemas of path, query and header parameters, the request body and the response body
+// are derived from the types Rib inferred for `request` and for the response mapping.
+// Every operation keeps the `x-golem-api-gateway-binding` extension, such that
+// the exported spec can be imported back as an API definition.
+pub struct OpenApiHttpApiDefinitionResponse(pub OpenAPI);
+
+impl OpenApiHttpApiDefinitionResponse {
+    pub fn from_compiled_http_api_definition<Namespace>(
+        compiled_http_api_definition: &CompiledHttpApiDefinition<Namespace>,
+    ) -> Result<OpenApiHttpApiDefinitionResponse, String> {
+        let open_api = get_open_api(compiled_http_api_definition)?;
+
+        serde_json::from_value(open_api)
+            .map(OpenApiHttpApiDefinitionResponse)
+            .map_err(|err| format!("Failed to export API definition as OpenAPI. {}", err))
+    }
+}
+
+impl ToJSON for OpenApiHttpApiDefinitionResponse {
+    fn to_json(&self) -> Option<Value> {
+        serde_json::to_value(&self.0).ok()
+    }
+}
+
+impl poem_openapi::types::Type for OpenApiHttpApiDefinitionResponse {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "OpenApiHttpApiDefinitionResponse".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            title: Some("API definition in OpenAPI format".to_string()),
+            description: Some(
+                "Deployed API definition in OpenAPI format with the request and response schemas inferred by Rib",
+            ),
+            ..MetaSchema::new("object")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+mod internal {
+    use crate::gateway_api_definition::http::http_oas_api_definition::internal::{
+        GOLEM_API_DEFINITION_ID_EXTENSION, GOLEM_API_DEFINITION_VERSION, GOLEM_API_GATEWAY_BINDING,
+    };
+    use crate::gateway_api_definition::http::{
+        CompiledHttpApiDefinition, CompiledRoute, MethodPattern, PathPattern,
+    };
+    use crate::gateway_binding::{GatewayBindingCompiled, StaticBinding, WorkerBindingCompiled};
+    use crate::gateway_middleware::{CorsPreflightExpr, Middlewares};
+    use golem_common::model::GatewayBindingType;
+    use golem_wasm_ast::analysis::{AnalysedType, TypeRecord};
+    use rib::{RibInputTypeInfo, RibOutputTypeInfo};
+    use serde_json::{json, Map, Value};
+
+    pub(crate) fn get_open_api<Namespace>(
+        compiled_http_api_definition: &CompiledHttpApiDefinition<Namespace>,
+    ) -> Result<Value, String> {
+        let mut paths = Map::new();
+
+        for route in &compiled_http_api_definition.routes {
+            let path = route
+                .path
+                .path_patterns
+                .iter()
+                .map(|pattern| format!("/{}", pattern))
+                .collect::<String>();
+
+            let path_item = paths
+                .entry(if path.is_empty() {
+                    "/".to_string()
+                } else {
+                    path
+                })
+                .or_insert_with(|| Value::Object(Map::new()));
+
+            if let Value::Object(path_item) = path_item {
+                path_item.insert(get_method(&route.method), get_operation(route)?);
+            }
+        }
+
+        Ok(json!({
+            "openapi": "3.0.0",
+            "info": {
+                "title": compiled_http_api_definition.id.0,
+                "version": compiled_http_api_definition.version.0
+            },
+            GOLEM_API_DEFINITION_ID_EXTENSION: compiled_http_api_definition.id.0,
+            GOLEM_API_DEFINITION_VERSION: compiled_http_api_definition.version.0,
+            "paths": paths
+        }))
+    }
+
+    fn get_method(method: &MethodPattern) -> String {
+        method.to_string().to_lowercase()
+    }
+
+    fn get_operation(route: &CompiledRoute) -> Result<Value, String> {
+        match &route.binding {
+            GatewayBindingCompiled::Worker(worker_binding) => {
+                get_worker_operation(route, worker_binding, GatewayBindingType::Default)
+            }
+            GatewayBindingCompiled::FileServer(worker_binding) => {
+                get_worker_operation(route, worker_binding, GatewayBindingType::FileServer)
+            }
+            GatewayBindingCompiled::Stream(worker_binding) => {
+                get_worker_operation(route, worker_binding, GatewayBindingType::Stream)
+            }
+            GatewayBindingCompiled::Static(StaticBinding::HttpCorsPreflight(cors)) => {
+                let response = rib::to_string(&CorsPreflightExpr::from_cors(cors).0)
+                    .map_err(|err| err.to_string())?;
+
+                Ok(json!({
+                    "responses": {
+                        "200": { "description": "CORS preflight response" }
+                    },
+                    GOLEM_API_GATEWAY_BINDING: {
+                        "binding-type": GatewayBindingType::CorsPreflight,
+                        "response": response
+                    }
+                }))
+            }
+        }
+    }
+
+    fn get_worker_operation(
+        route: &CompiledRoute,
+        worker_binding: &WorkerBindingCompiled,
+        binding_type: GatewayBindingType,
+    ) -> Result<Value, String> {
+        let request = RequestType::from_worker_binding(worker_binding);
+
+        let mut parameters = vec![];
+
+        for path_pattern in &route.path.path_patterns {
+            if let PathPattern::Var(var_info) = path_pattern {
+                parameters.push(get_parameter(
+                    &var_info.key_name,
+                    "path",
+                    request.get_path_field(&var_info.key_name),
+                ));
+            }
+        }
+
+        for query_info in &route.path.query_params {
+            parameters.push(get_parameter(
+                &query_info.key_name,
+                "query",
+                request.get_path_field(&query_info.key_name),
+            ));
+        }
+
+        for (header_name, header_type) in &request.headers {
+            parameters.push(get_parameter(header_name, "header", Some(header_type)));
+        }
+
+        let mut operation = Map::new();
+
+        if !parameters.is_empty() {
+            operation.insert("parameters".to_string(), Value::Array(parameters));
+        }
+
+        // The body of a stream binding is the content of each inbound message,
+        // which OpenAPI has no way to describe
+        if let (Some(body), false) = (&request.body, binding_type == GatewayBindingType::Stream) {
+            operation.insert(
+                "requestBody".to_string(),
+                json!({
+                    "required": true,
+                    "content": {
+                        "application/json": { "schema": get_schema(body) }
+                    }
+                }),
+            );
+        }
+
+        let response = match binding_type {
+            GatewayBindingType::FileServer => json!({
+                "description": "Content of the file",
+                "content": {
+                    "application/octet-stream": {
+                        "schema": { "type": "string", "format": "binary" }
+                    }
+                }
+            }),
+            GatewayBindingType::Stream => json!({
+                "description": "WebSocket connection, or server-sent events of the worker",
+                "content": {
+                    "text/event-stream": {
+                        "schema": { "type": "string" }
+                    }
+                }
+            }),
+            _ => match get_response_body_type(&worker_binding.response_compiled.rib_output) {
+                Some(body) => json!({
+                    "description": "Response of the worker binding",
+                    "content": {
+                        "application/json": { "schema": get_schema(&body) }
+                    }
+                }),
+                None => json!({ "description": "Response of the worker binding" }),
+            },
+        };
+
+        operation.insert("responses".to_string(), json!({ "default": response }));
+
+        operation.insert(
+            GOLEM_API_GATEWAY_BINDING.to_string(),
+            get_worker_binding_extension(worker_binding, binding_type)?,
+        );
+
+        Ok(Value::Object(operation))
+    }
+
+    // Path and query parameters that are not used in any of the Rib scripts
+    // are not typed, and are hence exported as strings
+    fn get_parameter(name: &str, location: &str, analysed_type: Option<&AnalysedType>) -> Value {
+        let schema = analysed_type
+            .map(get_schema)
+            .unwrap_or(json!({ "type": "string" }));
+
+        json!({
+            "name": name,
+            "in": location,
+            "required": true,
+            "schema": schema
+        })
+    }
+
+    fn get_worker_binding_extension(
+        worker_binding: &WorkerBindingCompiled,
+        binding_type: GatewayBindingType,
+    ) -> Result<Value, String> {
+        let mut binding = Map::new();
+
+        binding.insert("binding-type".to_string(), json!(binding_type));
+        binding.insert(
+            "component-id".to_string(),
+            json!(worker_binding.component_id.component_id.0.to_string()),
+        );
+        binding.insert(
+            "component-version".to_string(),
+            json!(worker_binding.component_id.version),
+        );
+
+        if let Some(worker_name) = &worker_binding.worker_name_compiled {
+            binding.insert(
+                "worker-name".to_string(),
+                json!(rib::to_string(&worker_name.worker_name).map_err(|err| err.to_string())?),
+            );
+        }
+
+        if let Some(idempotency_key) = &worker_binding.idempotency_key_compiled {
+            binding.insert(
+                "idempotency-key".to_string(),
+                json!(rib::to_string(&idempotency_key.idempotency_key)
+                    .map_err(|err| err.to_string())?),
+            );
+        }
+
+        binding.insert(
+            "response".to_string(),
+            json!(
+                rib::to_string(&worker_binding.response_compiled.response_mapping_expr)
+                    .map_err(|err| err.to_string())?
+            ),
+        );
+
+        if let Some(middlewares) = &worker_binding.middlewares {
+            binding.insert("middlewares".to_string(), get_middlewares(middlewares)?);
+        }
+
+        if let Some(traffic_split) = &worker_binding.traffic_split {
+            binding.insert(
+                "traffic-split".to_string(),
+                Value::Array(
+                    traffic_split
+                        .targets
+                        .iter()
+                        .map(|target| {
+                            json!({
+                                "component-id": target.component_id.component_id.0.to_string(),
+                                "component-version": target.component_id.version,
+                                "weight": target.weight
+                            })
+                        })
+                        .collect(),
+                ),
+            );
+        }
+
+        if let Some(response_cache) = &worker_binding.response_cache {
+            binding.insert(
+                "response-cache".to_string(),
+                serde_json::to_value(response_cache).map_err(|err| err.to_string())?,
+            );
+        }
+
+        Ok(Value::Object(binding))
+    }
+
+    fn get_middlewares(middlewares: &Middlewares) -> Result<Value, String> {
+        let mut result = Map::new();
+
+        if let Some(cors) = middlewares.get_cors() {
+            result.insert(
+                "cors".to_string(),
+                serde_json::to_value(cors).map_err(|err| err.to_string())?,
+            );
+        }
+
+        if let Some(jwt_authentication) = middlewares.get_jwt_authentication() {
+            result.insert(
+                "jwt-authentication".to_string(),
+                serde_json::to_value(jwt_authentication).map_err(|err| err.to_string())?,
+            );
+        }
+
+        if let Some(rate_limit) = middlewares.get_rate_limit() {
+            result.insert(
+                "rate-limit".to_string(),
+                serde_json::to_value(rate_limit).map_err(|err| err.to_string())?,
+            );
+        }
+
+        if let Some(transformation) = middlewares.get_transformation() {
+            result.insert(
+                "transformation".to_string(),
+                serde_json::to_value(transformation).map_err(|err| err.to_string())?,
+            );
+        }
+
+        Ok(Value::Object(result))
+    }
+
+    // The parts of `request` used by any of the Rib scripts of a binding.
+    // Query parameters are looked up in `request.path`, the same as path variables.
+    #[derive(Default)]
+    pub(crate) struct RequestType {
+        pub(crate) path: Vec<(String, AnalysedType)>,
+        pub(crate) headers: Vec<(String, AnalysedType)>,
+        pub(crate) body: Option<AnalysedType>,
+    }
+
+    impl RequestType {
+        pub(crate) fn from_worker_binding(worker_binding: &WorkerBindingCompiled) -> RequestType {
+            let mut rib_inputs: Vec<&RibInputTypeInfo> = vec![];
+
+            if let Some(worker_name) = &worker_binding.worker_name_compiled {
+                rib_inputs.push(&worker_name.rib_input_type_info);
+            }
+
+            if let Some(idempotency_key) = &worker_binding.idempotency_key_compiled {
+                rib_inputs.push(&idempotency_key.rib_input);
+            }
+
+            rib_inputs.push(&worker_binding.response_compiled.rib_input);
+
+            RequestType::from_rib_inputs(&rib_inputs)
+        }
+
+        pub(crate) fn from_rib_inputs(rib_inputs: &[&RibInputTypeInfo]) -> RequestType {
+            let mut request_type = RequestType::default();
+
+            for rib_input in rib_inputs {
+                if let Some(AnalysedType::Record(TypeRecord { fields })) =
+                    rib_input.types.get("request")
+                {
+                    for field in fields {
+                        match (field.name.as_str(), &field.typ) {
+                            ("path", AnalysedType::Record(path)) => {
+                                merge_fields(&mut request_type.path, path)
+                            }
+                            ("headers", AnalysedType::Record(headers)) => {
+                                merge_fields(&mut request_type.headers, headers)
+                            }
+                            ("body", body) => {
+                                request_type.body.get_or_insert_with(|| body.clone());
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+
+            request_type
+        }
+
+        fn get_path_field(&self, name: &str) -> Option<&AnalysedType> {
+            self.path
+                .iter()
+                .find(|(field_name, _)| field_name == name)
+                .map(|(_, typ)| typ)
+        }
+    }
+
+    fn merge_fields(fields: &mut Vec<(String, AnalysedType)>, record: &TypeRecord) {
+        for field in &record.fields {
+            if !fields.iter().any(|(name, _)| name == &field.name) {
+                fields.push((field.name.clone(), field.typ.clone()));
+            }
+        }
+    }
+
+    // Mirrors how the gateway forms the http response from the result of the response mapping:
+    // a record with a `body` field is responded with that field,
+    // a record with only `status` and `headers` has no body, and anything else is the body itself
+    pub(crate) fn get_response_body_type(
+        rib_output: &Option<RibOutputTypeInfo>,
+    ) -> Option<AnalysedType> {
+        match rib_output.as_ref().map(|output| &output.analysed_type) {
+            Some(AnalysedType::Record(TypeRecord { fields })) => {
+                match fields.iter().find(|field| field.name == "body") {
+                    Some(body) => Some(body.typ.clone()),
+                    None if fields
+                        .iter()
+                        .all(|field| field.name == "status" || field.name == "headers") =>
+                    {
+                        None
+                    }
+                    None => Some(AnalysedType::Record(TypeRecord {
+                        fields: fields.clone(),
+                    })),
+                }
+            }
+            Some(analysed_type) => Some(analysed_type.clone()),
+            None => None,
+        }
+    }
+
+    // JSON schema of the JSON representation of a value of the given type,
+    // which is what the gateway accepts as request body and responds with
+    pub(crate) fn get_schema(analysed_type: &AnalysedType) -> Value {
+        match analysed_type {
+            AnalysedType::Bool(_) => json!({ "type": "boolean" }),
+            AnalysedType::S8(_) | AnalysedType::S16(_) | AnalysedType::S32(_) => {
+                json!({ "type": "integer", "format": "int32" })
+            }
+            AnalysedType::U8(_) | AnalysedType::U16(_) => {
+                json!({ "type": "integer", "format": "int32", "minimum": 0 })
+            }
+            AnalysedType::S64(_) => json!({ "type": "integer", "format": "int64" }),
+            AnalysedType::U32(_) | AnalysedType::U64(_) => {
+                json!({ "type": "integer", "format": "int64", "minimum": 0 })
+            }
+            AnalysedType::F32(_) => json!({ "type": "number", "format": "float" }),
+            AnalysedType::F64(_) => json!({ "type": "number", "format": "double" }),
+            AnalysedType::Chr(_) => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+            AnalysedType::Str(_) | AnalysedType::Handle(_) => json!({ "type": "string" }),
+            AnalysedType::List(list) => json!({
+                "type": "array",
+                "items": get_schema(&list.inner)
+            }),
+            AnalysedType::Tuple(tuple) => json!({
+                "type": "array",
+                "items": { "oneOf": tuple.items.iter().map(get_schema).collect::<Vec<_>>() },
+                "minItems": tuple.items.len(),
+                "maxItems": tuple.items.len()
+            }),
+            AnalysedType::Option(option) => {
+                let mut schema = get_schema(&option.inner);
+                if let Value::Object(schema) = &mut schema {
+                    schema.insert("nullable".to_string(), Value::Bool(true));
+                }
+                schema
+            }
+            AnalysedType::Result(result) => json!({
+                "oneOf": [
+                    get_case_schema("ok", result.ok.as_deref()),
+                    get_case_schema("err", result.err.as_deref())
+                ]
+            }),
+            AnalysedType::Record(record) => {
+                let properties = record
+                    .fields
+                    .iter()
+                    .map(|field| (field.name.clone(), get_schema(&field.typ)))
+                    .collect::<Map<_, _>>();
+
+                let required = record
+                    .fields
+                    .iter()
+                    .filter(|field| !matches!(field.typ, AnalysedType::Option(_)))
+                    .map(|field| Value::String(field.name.clone()))
+                    .collect::<Vec<_>>();
+
+                let mut schema = Map::new();
+                schema.insert("type".to_string(), json!("object"));
+                schema.insert("properties".to_string(), Value::Object(properties));
+                if !required.is_empty() {
+                    schema.insert("required".to_string(), Value::Array(required));
+                }
+                Value::Object(schema)
+            }
+            AnalysedType::Variant(variant) => json!({
+                "oneOf": variant
+                    .cases
+                    .iter()
+                    .map(|case| get_case_schema(&case.name, case.typ.as_ref()))
+                    .collect::<Vec<_>>()
+            }),
+            AnalysedType::Enum(enum_type) => json!({
+                "type": "string",
+                "enum": enum_type.cases
+            }),
+            AnalysedType::Flags(flags) => json!({
+                "type": "array",
+                "items": { "type": "string", "enum": flags.names },
+                "uniqueItems": true
+            }),
+        }
+    }
+
+    // Variant cases and results are represented as an object with a single key
+    fn get_case_schema(name: &str, analysed_type: Option<&AnalysedType>) -> Value {
+        let schema = analysed_type
+            .map(get_schema)
+            .unwrap_or(json!({ "nullable": true }));
+
+        json!({
+            "type": "object",
+            "properties": { name: schema },
+            "required": [name]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::internal::{get_response_body_type, get_schema, RequestType};
+    use golem_wasm_ast::analysis::analysed_type::{field, list, option, record, str, u16, u64};
+    use rib::{RibInputTypeInfo, RibOutputTypeInfo};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_schema_of_record() {
+        let analysed_type = record(vec![
+            field("name", str()),
+            field("quantity", u64()),
+            field("tags", list(str())),
+            field("description", option(str())),
+        ]);
+
+        let expected = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "quantity": { "type": "integer", "format": "int64", "minimum": 0 },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "description": { "type": "string", "nullable": true }
+            },
+            "required": ["name", "quantity", "tags"]
+        });
+
+        assert_eq!(get_schema(&analysed_type), expected);
+    }
+
+    #[test]
+    fn test_response_body_type_of_record_with_status_and_body() {
+        let rib_output = RibOutputTypeInfo {
+            analysed_type: record(vec![field("status", u16()), field("body", list(str()))]),
+        };
+
+        assert_eq!(get_response_body_type(&Some(rib_output)), Some(list(str())));
+    }
+
+    #[test]
+    fn test_response_body_type_of_record_with_status_only() {
+        let rib_output = RibOutputTypeInfo {
+            analysed_type: record(vec![field("status", u16())]),
+        };
+
+        assert_eq!(get_response_body_type(&Some(rib_output)), None);
+    }
+
+    #[test]
+    fn test_request_type_merges_rib_inputs() {
+        let worker_name_input = RibInputTypeInfo {
+            types: HashMap::from([(
+                "request".to_string(),
+                record(vec![field("path", record(vec![field("user-id", u64())]))]),
+            )]),
+        };
+
+        let response_input = RibInputTypeInfo {
+            types: HashMap::from([(
+                "request".to_string(),
+                record(vec![
+                    field(
+                        "path",
+                        record(vec![field("user-id", u64()), field("q", str())]),
+                    ),
+                    field("body", record(vec![field("item", str())])),
+                ]),
+            )]),
+        };
+
+        let request_type = RequestType::from_rib_inputs(&[&worker_name_input, &response_input]);
+
+        assert_eq!(
+            request_type.path,
+            vec![("user-id".to_string(), u64()), ("q".to_string(), str())]
+        );
+        assert_eq!(request_type.body, Some(record(vec![field("item", str())])));
+        assert!(request_type.headers.is_empty());
+    }
+}
@@ -1,7 +1,9 @@
 pub use http_api_definition::*;
 pub use http_oas_api_definition::*;
+pub use http_oas_api_definition_export::*;
 
 mod http_api_definition;
 mod http_oas_api_definition;
+mod http_oas_api_definition_export;
 pub(crate) mod path_pattern_parser;
 pub(crate) mod place_holder_parser;
@@ -1,13 +1,15 @@
 use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use poem_openapi::{NewType, Object};
+use rustls::pki_types::ServerName;
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::fmt::{Debug, Display};
 
 #[derive(Eq, Hash, PartialEq, Clone, Debug, serde::Deserialize)]
 pub struct ApiDeploymentRequest<Namespace> {
     pub namespace: Namespace,
     pub api_definition_keys: Vec<ApiDefinitionIdWithVersion>,
     pub site: ApiSite,
+    pub certificate: Option<ApiSiteCertificate>,
 }
 
 #[derive(Eq, Hash, PartialEq, Clone, Debug, serde::Deserialize)]
@@ -15,6 +17,9 @@ pub struct ApiDeployment<Namespace> {
     pub namespace: Namespace,
     pub api_definition_keys: Vec<ApiDefinitionIdWithVersion>,
     pub site: ApiSite,
+    // PEM encoded certificate chain of the site. The private key is only ever read
+    // when loading the certificates of the TLS listener
+    pub certificate: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -34,9 +39,71 @@ impl Display for ApiSite {
     }
 }
 
+// PEM encoded certificate chain and private key, used to terminate TLS for a site.
+// The certificate is selected based on the server name (SNI) the client asks for.
+#[derive(Eq, Clone, Hash, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiSiteCertificate {
+    pub certificate: String,
+    pub private_key: String,
+}
+
+impl ApiSiteCertificate {
+    // Checks that the certificate chain and the private key can be parsed, that the key
+    // belongs to the leaf certificate, and that the leaf certificate is valid for `server_name`
+    pub fn validate(&self, server_name: &str) -> Result<(), String> {
+        let certificates = rustls_pemfile::certs(&mut self.certificate.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| {
+                format!(
+                    "Certificate is not a PEM encoded certificate chain. {}",
+                    err
+                )
+            })?;
+
+        let leaf = certificates
+            .first()
+            .ok_or("Certificate is not a PEM encoded certificate chain")?;
+
+        // Accepts PKCS#8, PKCS#1 (RSA) and SEC1 (EC) keys
+        let private_key = rustls_pemfile::private_key(&mut self.private_key.as_bytes())
+            .map_err(|err| format!("Private key is not a PEM encoded private key. {}", err))?
+            .ok_or("Private key is not a PEM encoded private key")?;
+
+        let end_entity = webpki::EndEntityCert::try_from(leaf)
+            .map_err(|err| format!("Invalid certificate. {:?}", err))?;
+
+        let subject_name = ServerName::try_from(server_name)
+            .map_err(|err| format!("Invalid server name {}. {}", server_name, err))?;
+
+        end_entity
+            .verify_is_valid_for_subject_name(&subject_name)
+            .map_err(|_| format!("Certificate is not valid for {}", server_name))?;
+
+        internal::verify_key_matches(&end_entity, &private_key)
+    }
+}
+
+// The private key is never printed
+impl Debug for ApiSiteCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiSiteCertificate")
+            .field("certificate", &self.certificate)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Debug, Hash, Serialize, Deserialize, NewType)]
 pub struct ApiSiteString(pub String);
 
+impl ApiSiteString {
+    // The host name used for SNI, which never includes the port
+    pub fn server_name(&self) -> &str {
+        self.0.split(':').next().unwrap_or_default()
+    }
+}
+
 impl From<&ApiSite> for ApiSiteString {
     fn from(value: &ApiSite) -> Self {
         ApiSiteString(value.to_string())
@@ -48,3 +115,52 @@ impl Display for ApiSiteString {
         write!(f, "{}", self.0)
     }
 }
+
+mod internal {
+    use rustls::crypto::ring::{default_provider, sign};
+    use rustls::pki_types::PrivateKeyDer;
+
+    // Signs a message with the private key and verifies it with the public key of the certificate
+    pub(crate) fn verify_key_matches(
+        end_entity: &webpki::EndEntityCert,
+        private_key: &PrivateKeyDer,
+    ) -> Result<(), String> {
+        let message = b"golem api site certificate";
+
+        let signing_key = sign::any_supported_type(private_key)
+            .map_err(|err| format!("Unsupported private key. {}", err))?;
+
+        let algorithms = default_provider().signature_verification_algorithms;
+
+        let schemes = algorithms
+            .mapping
+            .iter()
+            .map(|(scheme, _)| *scheme)
+            .collect::<Vec<_>>();
+
+        let signer = signing_key
+            .choose_scheme(&schemes)
+            .ok_or("Unsupported private key")?;
+
+        let signature = signer
+            .sign(message)
+            .map_err(|err| format!("Unsupported private key. {}", err))?;
+
+        let matches = algorithms
+            .mapping
+            .iter()
+            .filter(|(scheme, _)| *scheme == signer.scheme())
+            .flat_map(|(_, algorithms)| algorithms.iter())
+            .any(|algorithm| {
+                end_entity
+                    .verify_signature(*algorithm, message, &signature)
+                    .is_ok()
+            });
+
+        if matches {
+            Ok(())
+        } else {
+            Err("Private key does not match the certificate".to_string())
+        }
+    }
+}
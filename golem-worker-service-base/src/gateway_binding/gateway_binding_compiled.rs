@@ -1,8 +1,8 @@
-use crate::gateway_binding::StaticBinding;
 use crate::gateway_binding::{
     GatewayBinding, IdempotencyKeyCompiled, ResponseMappingCompiled, WorkerBinding,
     WorkerBindingCompiled, WorkerNameCompiled,
 };
+use crate::gateway_binding::{ResponseCache, StaticBinding, TrafficSplit};
 use crate::gateway_middleware::Middlewares;
 use golem_common::model::GatewayBindingType;
 
@@ -13,6 +13,7 @@ pub enum GatewayBindingCompiled {
     Worker(WorkerBindingCompiled),
     Static(StaticBinding),
     FileServer(WorkerBindingCompiled),
+    Stream(WorkerBindingCompiled),
 }
 
 impl From<GatewayBindingCompiled> for GatewayBinding {
@@ -35,6 +36,11 @@ impl From<GatewayBindingCompiled> for GatewayBinding {
 
                 GatewayBinding::FileServer(worker_binding)
             }
+            GatewayBindingCompiled::Stream(value) => {
+                let worker_binding = WorkerBinding::from(value);
+
+                GatewayBinding::Stream(worker_binding)
+            }
         }
     }
 }
@@ -58,6 +64,13 @@ impl From<GatewayBindingCompiled>
                 )
             }
 
+            GatewayBindingCompiled::Stream(worker_binding) => {
+                internal::to_gateway_binding_compiled_proto(
+                    worker_binding,
+                    GatewayBindingType::Stream,
+                )
+            }
+
             GatewayBindingCompiled::Static(static_binding) => {
                 golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding {
                     component: None,
@@ -74,6 +87,9 @@ impl From<GatewayBindingCompiled>
                     binding_type: Some(1),
                     static_binding: Some(static_binding.into()),
                     middleware: None,
+                    response_rib_output: None,
+                    traffic_split: None,
+                    response_cache: None,
                 }
             }
         }
@@ -89,7 +105,7 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding
         value: golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding,
     ) -> Result<Self, Self::Error> {
         match value.binding_type {
-            Some(0) | Some(1) => {
+            Some(0) | Some(1) | Some(3) => {
                 // Convert fields for the Worker variant
                 let component_id = value
                     .component
@@ -148,28 +164,40 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding
                         .worker_functions_in_response
                         .map(rib::WorkerFunctionsInRib::try_from)
                         .transpose()?,
+                    rib_output: value
+                        .response_rib_output
+                        .map(rib::RibOutputTypeInfo::try_from)
+                        .transpose()?,
                 };
 
                 let middleware = value.middleware.map(Middlewares::try_from).transpose()?;
 
+                let traffic_split = value
+                    .traffic_split
+                    .map(TrafficSplit::try_from)
+                    .transpose()?;
+
+                let response_cache = value
+                    .response_cache
+                    .map(ResponseCache::try_from)
+                    .transpose()?;
+
                 let binding_type = value.binding_type.ok_or("Missing binding_type")?;
 
-                if binding_type == 0 {
-                    Ok(GatewayBindingCompiled::Worker(WorkerBindingCompiled {
-                        component_id,
-                        worker_name_compiled,
-                        idempotency_key_compiled,
-                        response_compiled,
-                        middlewares: middleware,
-                    }))
-                } else {
-                    Ok(GatewayBindingCompiled::FileServer(WorkerBindingCompiled {
-                        component_id,
-                        worker_name_compiled,
-                        idempotency_key_compiled,
-                        response_compiled,
-                        middlewares: middleware,
-                    }))
+                let worker_binding = WorkerBindingCompiled {
+                    component_id,
+                    worker_name_compiled,
+                    idempotency_key_compiled,
+                    response_compiled,
+                    middlewares: middleware,
+                    traffic_split,
+                    response_cache,
+                };
+
+                match binding_type {
+                    0 => Ok(GatewayBindingCompiled::Worker(worker_binding)),
+                    1 => Ok(GatewayBindingCompiled::FileServer(worker_binding)),
+                    _ => Ok(GatewayBindingCompiled::Stream(worker_binding)),
                 }
             }
             Some(2) => {
@@ -231,21 +259,22 @@ mod internal {
             .response_compiled
             .worker_calls
             .map(|x| x.into());
+        let response_rib_output = worker_binding
+            .response_compiled
+            .rib_output
+            .map(|x| x.into());
+
+        let middleware = worker_binding.middlewares.map(|m| m.into());
+
+        let traffic_split = worker_binding.traffic_split.map(|x| x.into());
 
-        let middleware = worker_binding
-            .middlewares
-            .iter()
-            .map(
-                |m| golem_api_grpc::proto::golem::apidefinition::Middleware {
-                    cors: m.get_cors().map(|x| x.into()),
-                },
-            )
-            .next();
+        let response_cache = worker_binding.response_cache.map(|x| x.into());
 
         let binding_type = match binding_type {
             GatewayBindingType::Default => 0,
             GatewayBindingType::FileServer => 1,
             GatewayBindingType::CorsPreflight => 2,
+            GatewayBindingType::Stream => 3,
         };
 
         golem_api_grpc::proto::golem::apidefinition::CompiledGatewayBinding {
@@ -263,6 +292,9 @@ mod internal {
             binding_type: Some(binding_type),
             static_binding: None,
             middleware,
+            response_rib_output,
+            traffic_split,
+            response_cache,
         }
     }
 }
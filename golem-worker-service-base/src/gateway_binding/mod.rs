@@ -4,13 +4,17 @@ use crate::gateway_middleware::{HttpMiddleware, Middlewares};
 pub(crate) use crate::gateway_request::request_details::*;
 pub(crate) use gateway_binding_compiled::*;
 use golem_service_base::model::VersionedComponentId;
+pub(crate) use response_cache::*;
 use rib::Expr;
 pub(crate) use static_binding::*;
+pub(crate) use traffic_split::*;
 pub(crate) use worker_binding::*;
 pub(crate) use worker_binding_compiled::*;
 
 mod gateway_binding_compiled;
+mod response_cache;
 mod static_binding;
+mod traffic_split;
 mod worker_binding;
 mod worker_binding_compiled;
 // A gateway binding is integration to the backend. This is similar to AWS's x-amazon-gateway-integration
@@ -23,6 +27,9 @@ mod worker_binding_compiled;
 pub enum GatewayBinding {
     Default(WorkerBinding),
     FileServer(WorkerBinding),
+    // Upgrades the request to a WebSocket (or server-sent events) connection to the worker,
+    // where the response mapping is evaluated for each inbound message
+    Stream(WorkerBinding),
     Static(StaticBinding),
 }
 
@@ -32,12 +39,14 @@ impl GatewayBinding {
             Self::Default(_) => false,
             Self::Static(StaticBinding::HttpCorsPreflight(_)) => true,
             Self::FileServer(_) => false,
+            Self::Stream(_) => false,
         }
     }
     pub fn get_worker_binding(&self) -> Option<WorkerBinding> {
         match self {
             Self::Default(worker_binding) => Some(worker_binding.clone()),
             Self::FileServer(worker_binding) => Some(worker_binding.clone()),
+            Self::Stream(worker_binding) => Some(worker_binding.clone()),
             Self::Static(_) => None,
         }
     }
@@ -46,6 +55,7 @@ impl GatewayBinding {
         match self {
             Self::Default(worker_binding) => Some(worker_binding),
             Self::FileServer(worker_binding) => Some(worker_binding),
+            Self::Stream(worker_binding) => Some(worker_binding),
             Self::Static(_) => None,
         }
     }
@@ -54,6 +64,7 @@ impl GatewayBinding {
         match self {
             Self::Default(_) => None,
             Self::FileServer(_) => None,
+            Self::Stream(_) => None,
             Self::Static(static_binding) => static_binding
                 .get_cors_preflight()
                 .map(HttpMiddleware::cors),
@@ -75,6 +86,8 @@ impl From<GatewayBinding> for golem_api_grpc::proto::golem::apidefinition::Gatew
                     idempotency_key: worker_binding.idempotency_key.map(|x| x.into()),
                     middleware,
                     static_binding: None,
+                    traffic_split: worker_binding.traffic_split.map(|x| x.into()),
+                    response_cache: worker_binding.response_cache.map(|x| x.into()),
                 }
             }
             GatewayBinding::FileServer(worker_binding) => {
@@ -88,6 +101,23 @@ impl From<GatewayBinding> for golem_api_grpc::proto::golem::apidefinition::Gatew
                     idempotency_key: worker_binding.idempotency_key.map(|x| x.into()),
                     middleware,
                     static_binding: None,
+                    traffic_split: worker_binding.traffic_split.map(|x| x.into()),
+                    response_cache: worker_binding.response_cache.map(|x| x.into()),
+                }
+            }
+            GatewayBinding::Stream(worker_binding) => {
+                let middleware = worker_binding.middleware.map(|x| x.into());
+
+                golem_api_grpc::proto::golem::apidefinition::GatewayBinding {
+                    binding_type: Some(3),
+                    component: Some(worker_binding.component_id.into()),
+                    worker_name: worker_binding.worker_name.map(|x| x.into()),
+                    response: Some(worker_binding.response_mapping.0.into()),
+                    idempotency_key: worker_binding.idempotency_key.map(|x| x.into()),
+                    middleware,
+                    static_binding: None,
+                    traffic_split: worker_binding.traffic_split.map(|x| x.into()),
+                    response_cache: worker_binding.response_cache.map(|x| x.into()),
                 }
             }
             GatewayBinding::Static(static_binding) => {
@@ -99,6 +129,8 @@ impl From<GatewayBinding> for golem_api_grpc::proto::golem::apidefinition::Gatew
                     idempotency_key: None,
                     middleware: None,
                     static_binding: Some(static_binding.into()),
+                    traffic_split: None,
+                    response_cache: None,
                 }
             }
         }
@@ -127,6 +159,14 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::GatewayBinding> for Ga
                 let response_proto = value.response.ok_or("Missing response field")?;
                 let response = Expr::try_from(response_proto)?;
                 let middleware = value.middleware.map(Middlewares::try_from).transpose()?;
+                let traffic_split = value
+                    .traffic_split
+                    .map(TrafficSplit::try_from)
+                    .transpose()?;
+                let response_cache = value
+                    .response_cache
+                    .map(ResponseCache::try_from)
+                    .transpose()?;
 
                 Ok(GatewayBinding::Default(WorkerBinding {
                     component_id,
@@ -134,6 +174,8 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::GatewayBinding> for Ga
                     idempotency_key,
                     response_mapping: ResponseMapping(response),
                     middleware,
+                    traffic_split,
+                    response_cache,
                 }))
             }
             golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::FileServer => {
@@ -145,6 +187,14 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::GatewayBinding> for Ga
                 let response_proto = value.response.ok_or("Missing response field")?;
                 let response = Expr::try_from(response_proto)?;
                 let middleware = value.middleware.map(Middlewares::try_from).transpose()?;
+                let traffic_split = value
+                    .traffic_split
+                    .map(TrafficSplit::try_from)
+                    .transpose()?;
+                let response_cache = value
+                    .response_cache
+                    .map(ResponseCache::try_from)
+                    .transpose()?;
 
                 Ok(GatewayBinding::FileServer(WorkerBinding {
                     component_id,
@@ -152,6 +202,36 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::GatewayBinding> for Ga
                     idempotency_key,
                     response_mapping: ResponseMapping(response),
                     middleware,
+                    traffic_split,
+                    response_cache,
+                }))
+            }
+            golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::Stream => {
+                let component_id = VersionedComponentId::try_from(
+                    value.component.ok_or("Missing component id".to_string())?,
+                )?;
+                let worker_name = value.worker_name.map(Expr::try_from).transpose()?;
+                let idempotency_key = value.idempotency_key.map(Expr::try_from).transpose()?;
+                let response_proto = value.response.ok_or("Missing response field")?;
+                let response = Expr::try_from(response_proto)?;
+                let middleware = value.middleware.map(Middlewares::try_from).transpose()?;
+                let traffic_split = value
+                    .traffic_split
+                    .map(TrafficSplit::try_from)
+                    .transpose()?;
+                let response_cache = value
+                    .response_cache
+                    .map(ResponseCache::try_from)
+                    .transpose()?;
+
+                Ok(GatewayBinding::Stream(WorkerBinding {
+                    component_id,
+                    worker_name,
+                    idempotency_key,
+                    response_mapping: ResponseMapping(response),
+                    middleware,
+                    traffic_split,
+                    response_cache,
                 }))
             }
             golem_api_grpc::proto::golem::apidefinition::GatewayBindingType::CorsPreflight => {
@@ -0,0 +1,73 @@
+use crate::gateway_rib_compiler::{DefaultRibCompiler, WorkerServiceRibCompiler};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Opt-in caching policy of a worker binding of a (GET) route.
+// Successful responses are cached in the worker service for `ttl_seconds`, keyed by the value of
+// a Rib expression over the request (`key_expr`, Example: `request.path.user-id`),
+// such that requests with the same key are served without invoking the worker.
+// Cached responses carry an `ETag`, and requests with a matching `If-None-Match` get a 304.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ResponseCache {
+    key_expr: String,
+    ttl_seconds: u64,
+}
+
+impl ResponseCache {
+    pub fn get_key_expr(&self) -> String {
+        self.key_expr.clone()
+    }
+
+    pub fn get_ttl(&self) -> Duration {
+        Duration::from_secs(self.ttl_seconds)
+    }
+
+    pub fn from_parameters(key_expr: String, ttl_seconds: u64) -> Result<ResponseCache, String> {
+        let response_cache = ResponseCache {
+            key_expr,
+            ttl_seconds,
+        };
+
+        response_cache.validate()?;
+
+        Ok(response_cache)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.ttl_seconds == 0 {
+            return Err("ttlSeconds of a response cache must be greater than 0.".to_string());
+        }
+
+        let expr = rib::from_string(&self.key_expr)
+            .map_err(|err| format!("Invalid response cache key expression. {}", err))?;
+
+        DefaultRibCompiler::compile(&expr, &[])
+            .map(|_| ())
+            .map_err(|err| format!("Rib compilation for response cache key. {}", err))
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::apidefinition::ResponseCache> for ResponseCache {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::apidefinition::ResponseCache,
+    ) -> Result<Self, Self::Error> {
+        Ok(ResponseCache {
+            key_expr: value.key_expr.ok_or("Missing key expr")?,
+            ttl_seconds: value.ttl_seconds.ok_or("Missing ttl seconds")?,
+        })
+    }
+}
+
+impl From<ResponseCache> for golem_api_grpc::proto::golem::apidefinition::ResponseCache {
+    fn from(value: ResponseCache) -> Self {
+        golem_api_grpc::proto::golem::apidefinition::ResponseCache {
+            key_expr: Some(value.key_expr),
+            ttl_seconds: Some(value.ttl_seconds),
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use golem_service_base::model::VersionedComponentId;
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+// Splits the traffic of a worker binding between components (Example: canary releases).
+// Each target gets `weight` percent of the traffic, and the rest goes to the component of the binding.
+// The split is sticky per worker name, such that a worker is always served by the same target.
+// Workers are identified by their component and name, and keep the component version they were created with,
+// hence the targets have to be different components rather than versions of the same component.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct TrafficSplit {
+    pub targets: Vec<TrafficTarget>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct TrafficTarget {
+    pub component_id: VersionedComponentId,
+    pub weight: u32,
+}
+
+impl TrafficSplit {
+    pub fn component_ids(&self) -> Vec<VersionedComponentId> {
+        self.targets
+            .iter()
+            .map(|target| target.component_id.clone())
+            .collect()
+    }
+
+    pub fn validate(&self, component_id: &VersionedComponentId) -> Result<(), String> {
+        if self.targets.is_empty() {
+            return Err("Traffic split requires at least one target".to_string());
+        }
+
+        let mut component_ids = HashSet::from([&component_id.component_id]);
+
+        for target in &self.targets {
+            if target.weight == 0 {
+                return Err(format!(
+                    "Weight of traffic split target {} must be greater than 0",
+                    target.component_id
+                ));
+            }
+
+            if !component_ids.insert(&target.component_id.component_id) {
+                return Err(format!(
+                    "Traffic split target {} must be a different component than the binding and the other targets",
+                    target.component_id
+                ));
+            }
+        }
+
+        let total_weight: u32 = self.targets.iter().map(|target| target.weight).sum();
+
+        if total_weight > 100 {
+            return Err(format!(
+                "Weights of traffic split targets add up to {}, which is more than 100",
+                total_weight
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Picks the component serving the worker. Workers without a name (ephemeral workers)
+    // are not sticky, and are spread randomly according to the weights.
+    pub fn select(
+        &self,
+        component_id: &VersionedComponentId,
+        worker_name: Option<&str>,
+    ) -> VersionedComponentId {
+        let bucket = match worker_name {
+            Some(worker_name) => Self::bucket(worker_name),
+            None => fastrand::u32(0..100),
+        };
+
+        let mut upper_bound = 0;
+
+        for target in &self.targets {
+            upper_bound += target.weight;
+
+            if bucket < upper_bound {
+                return target.component_id.clone();
+            }
+        }
+
+        component_id.clone()
+    }
+
+    // FNV-1a is stable across Rust versions and platforms, such that a worker name
+    // falls into the same bucket across requests, worker service instances and releases
+    fn bucket(worker_name: &str) -> u32 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        let hash = worker_name.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        });
+
+        (hash % 100) as u32
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::apidefinition::TrafficSplit> for TrafficSplit {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::apidefinition::TrafficSplit,
+    ) -> Result<Self, Self::Error> {
+        let targets = value
+            .targets
+            .into_iter()
+            .map(|target| {
+                Ok(TrafficTarget {
+                    component_id: VersionedComponentId::try_from(
+                        target.component.ok_or("Missing component id")?,
+                    )?,
+                    weight: target.weight.ok_or("Missing weight")?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(TrafficSplit { targets })
+    }
+}
+
+impl From<TrafficSplit> for golem_api_grpc::proto::golem::apidefinition::TrafficSplit {
+    fn from(value: TrafficSplit) -> Self {
+        golem_api_grpc::proto::golem::apidefinition::TrafficSplit {
+            targets: value
+                .targets
+                .into_iter()
+                .map(
+                    |target| golem_api_grpc::proto::golem::apidefinition::TrafficTarget {
+                        component: Some(target.component_id.into()),
+                        weight: Some(target.weight),
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use golem_common::model::ComponentId;
+
+    fn component_id(id: u128) -> VersionedComponentId {
+        VersionedComponentId {
+            component_id: ComponentId(uuid::Uuid::from_u128(id)),
+            version: 0,
+        }
+    }
+
+    #[test]
+    fn test_traffic_split_is_sticky_per_worker_name() {
+        let traffic_split = TrafficSplit {
+            targets: vec![TrafficTarget {
+                component_id: component_id(2),
+                weight: 30,
+            }],
+        };
+
+        let mut canary = 0;
+
+        for i in 0..1000 {
+            let worker_name = format!("worker-{}", i);
+            let selected = traffic_split.select(&component_id(1), Some(&worker_name));
+
+            assert_eq!(
+                selected,
+                traffic_split.select(&component_id(1), Some(&worker_name))
+            );
+
+            if selected == component_id(2) {
+                canary += 1;
+            }
+        }
+
+        assert!((200..400).contains(&canary));
+    }
+
+    #[test]
+    fn test_traffic_split_bucket_is_stable() {
+        // FNV-1a reference hashes modulo 100
+        assert_eq!(
+            TrafficSplit::bucket(""),
+            (0xcbf29ce484222325u64 % 100) as u32
+        );
+        assert_eq!(
+            TrafficSplit::bucket("a"),
+            (0xaf63dc4c8601ec8cu64 % 100) as u32
+        );
+        assert_eq!(
+            TrafficSplit::bucket("foobar"),
+            (0x85944171f73967e8u64 % 100) as u32
+        );
+    }
+
+    #[test]
+    fn test_traffic_split_validation() {
+        let split = |weights: Vec<(u128, u32)>| TrafficSplit {
+            targets: weights
+                .into_iter()
+                .map(|(id, weight)| TrafficTarget {
+                    component_id: component_id(id),
+                    weight,
+                })
+                .collect(),
+        };
+
+        let other_version = |id: u128, version: u64| VersionedComponentId {
+            version,
+            ..component_id(id)
+        };
+
+        assert!(split(vec![(2, 10), (3, 90)])
+            .validate(&component_id(1))
+            .is_ok());
+        assert!(split(vec![]).validate(&component_id(1)).is_err());
+        assert!(split(vec![(2, 0)]).validate(&component_id(1)).is_err());
+        assert!(split(vec![(1, 10)]).validate(&component_id(1)).is_err());
+        assert!(split(vec![(2, 10), (2, 10)])
+            .validate(&component_id(1))
+            .is_err());
+        assert!(split(vec![(1, 10)]).validate(&other_version(1, 1)).is_err());
+        assert!(split(vec![(2, 60), (3, 50)])
+            .validate(&component_id(1))
+            .is_err());
+    }
+}
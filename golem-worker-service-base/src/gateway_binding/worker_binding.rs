@@ -1,7 +1,7 @@
 use bincode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
-use crate::gateway_binding::WorkerBindingCompiled;
+use crate::gateway_binding::{ResponseCache, TrafficSplit, WorkerBindingCompiled};
 use crate::gateway_middleware::{Cors, Middleware, Middlewares};
 use golem_service_base::model::VersionedComponentId;
 use rib::Expr;
@@ -13,6 +13,8 @@ pub struct WorkerBinding {
     pub idempotency_key: Option<Expr>,
     pub response_mapping: ResponseMapping,
     pub middleware: Option<Middlewares>,
+    pub traffic_split: Option<TrafficSplit>,
+    pub response_cache: Option<ResponseCache>,
 }
 
 impl WorkerBinding {
@@ -24,6 +26,17 @@ impl WorkerBinding {
         }
     }
 
+    // Components the binding may send traffic to
+    pub fn get_component_ids(&self) -> Vec<VersionedComponentId> {
+        let mut component_ids = vec![self.component_id.clone()];
+
+        if let Some(traffic_split) = &self.traffic_split {
+            component_ids.extend(traffic_split.component_ids());
+        }
+
+        component_ids
+    }
+
     pub fn get_cors_middleware(&self) -> Option<Cors> {
         self.middleware.as_ref().and_then(|m| m.get_cors())
     }
@@ -49,6 +62,8 @@ impl From<WorkerBindingCompiled> for WorkerBinding {
                 worker_binding.response_compiled.response_mapping_expr,
             ),
             middleware: value.middlewares,
+            traffic_split: value.traffic_split,
+            response_cache: value.response_cache,
         }
     }
 }
@@ -1,10 +1,10 @@
-use crate::gateway_binding::{ResponseMapping, WorkerBinding};
+use crate::gateway_binding::{ResponseCache, ResponseMapping, TrafficSplit, WorkerBinding};
 use crate::gateway_middleware::Middlewares;
 use crate::gateway_rib_compiler::{DefaultRibCompiler, WorkerServiceRibCompiler};
 use bincode::{Decode, Encode};
 use golem_service_base::model::VersionedComponentId;
 use golem_wasm_ast::analysis::AnalysedExport;
-use rib::{Expr, RibByteCode, RibInputTypeInfo, WorkerFunctionsInRib};
+use rib::{Expr, RibByteCode, RibInputTypeInfo, RibOutputTypeInfo, WorkerFunctionsInRib};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct WorkerBindingCompiled {
@@ -13,6 +13,8 @@ pub struct WorkerBindingCompiled {
     pub idempotency_key_compiled: Option<IdempotencyKeyCompiled>,
     pub response_compiled: ResponseMappingCompiled,
     pub middlewares: Option<Middlewares>,
+    pub traffic_split: Option<TrafficSplit>,
+    pub response_cache: Option<ResponseCache>,
 }
 
 impl WorkerBindingCompiled {
@@ -48,6 +50,8 @@ impl WorkerBindingCompiled {
             idempotency_key_compiled,
             response_compiled,
             middlewares: middleware,
+            traffic_split: gateway_worker_binding.traffic_split.clone(),
+            response_cache: gateway_worker_binding.response_cache.clone(),
         })
     }
 }
@@ -102,6 +106,8 @@ pub struct ResponseMappingCompiled {
     pub response_mapping_compiled: RibByteCode,
     pub rib_input: RibInputTypeInfo,
     pub worker_calls: Option<WorkerFunctionsInRib>,
+    // Optional only to keep backward compatibility with bindings compiled before this was recorded
+    pub rib_output: Option<RibOutputTypeInfo>,
 }
 
 impl ResponseMappingCompiled {
@@ -116,6 +122,7 @@ impl ResponseMappingCompiled {
             response_mapping_compiled: response_compiled.byte_code,
             rib_input: response_compiled.global_input_type_info,
             worker_calls: response_compiled.worker_invoke_calls,
+            rib_output: response_compiled.output_type_info,
         })
     }
 }
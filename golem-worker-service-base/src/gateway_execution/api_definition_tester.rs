@@ -0,0 +1,199 @@
+use crate::gateway_api_definition::http::CompiledHttpApiDefinition;
+use crate::gateway_binding::{
+    GatewayBindingResolver, GatewayBindingResolverError, GatewayRequestDetails, ResolvedBinding,
+    ResolvedWorkerBinding, RibInputValueResolver,
+};
+use crate::gateway_execution::GatewayWorkerRequestExecutor;
+use crate::gateway_request::http_request::InputHttpRequest;
+use crate::gateway_rib_interpreter::{
+    DefaultRibInterpreter, EvaluationError, WorkerServiceRibInterpreter,
+};
+use async_trait::async_trait;
+use futures_util::FutureExt;
+use golem_common::model::{ComponentId, GatewayBindingType, IdempotencyKey};
+use golem_wasm_rpc::json::TypeAnnotatedValueJsonExtensions;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use rib::{RibByteCode, RibFunctionInvoke, RibInput, RibResult};
+use serde_json::Value;
+use std::fmt::Display;
+use std::sync::Arc;
+
+// Dry-run of an API definition (Example: before it gets deployed).
+// A sample request is resolved against the routes of the definition, and the response mapping
+// of the matched route is evaluated, either calling the actual worker or returning a mock result
+// for every worker call.
+#[async_trait]
+pub trait ApiDefinitionTester<Namespace> {
+    async fn test(
+        &self,
+        definition: CompiledHttpApiDefinition<Namespace>,
+        request: InputHttpRequest,
+        mock_worker_result: Option<TypeAnnotatedValue>,
+    ) -> Result<ApiDefinitionTestResult, ApiDefinitionTestError>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiDefinitionTestResult {
+    pub binding_type: GatewayBindingType,
+    // Method and path pattern of the matched route, not available for static bindings
+    pub route: Option<String>,
+    pub worker_name: Option<String>,
+    pub idempotency_key: Option<IdempotencyKey>,
+    // Result of the response mapping, or the reason it failed to evaluate
+    pub response_mapping_result: Option<Result<Value, String>>,
+}
+
+#[derive(Debug)]
+pub struct ApiDefinitionTestError(pub GatewayBindingResolverError);
+
+impl Display for ApiDefinitionTestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            GatewayBindingResolverError::Unauthorized(msg) => {
+                write!(f, "Request is not authorized: {}", msg)
+            }
+            GatewayBindingResolverError::InvalidRequest(errors) => {
+                write!(f, "Invalid request: {}", errors)
+            }
+            GatewayBindingResolverError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+pub struct DefaultApiDefinitionTester {
+    worker_request_executor: Arc<dyn GatewayWorkerRequestExecutor + Sync + Send>,
+}
+
+impl DefaultApiDefinitionTester {
+    pub fn new(
+        worker_request_executor: Arc<dyn GatewayWorkerRequestExecutor + Sync + Send>,
+    ) -> Self {
+        DefaultApiDefinitionTester {
+            worker_request_executor,
+        }
+    }
+
+    fn evaluator(
+        &self,
+        mock_worker_result: Option<TypeAnnotatedValue>,
+    ) -> Arc<dyn WorkerServiceRibInterpreter + Sync + Send> {
+        match mock_worker_result {
+            Some(result) => Arc::new(MockRibInterpreter { result }),
+            None => Arc::new(DefaultRibInterpreter::from_worker_request_executor(
+                self.worker_request_executor.clone(),
+            )),
+        }
+    }
+
+    async fn evaluate_response_mapping<Namespace>(
+        evaluator: Arc<dyn WorkerServiceRibInterpreter + Sync + Send>,
+        request_details: &GatewayRequestDetails,
+        resolved_binding: &ResolvedWorkerBinding<Namespace>,
+    ) -> Result<Value, String> {
+        let request_rib_input = request_details
+            .resolve_rib_input_value(&resolved_binding.compiled_response_mapping.rib_input)
+            .map_err(|err| err.to_string())?;
+
+        let worker_rib_input = resolved_binding
+            .worker_detail
+            .resolve_rib_input_value(&resolved_binding.compiled_response_mapping.rib_input)
+            .map_err(|err| err.to_string())?;
+
+        let result = evaluator
+            .evaluate(
+                resolved_binding.worker_detail.worker_name.as_deref(),
+                &resolved_binding.worker_detail.component_id.component_id,
+                &resolved_binding.worker_detail.idempotency_key,
+                &resolved_binding
+                    .compiled_response_mapping
+                    .response_mapping_compiled,
+                &request_rib_input.merge(worker_rib_input),
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        match result {
+            RibResult::Val(value) => Ok(value.to_json_value()),
+            RibResult::Unit => Ok(Value::Null),
+        }
+    }
+}
+
+#[async_trait]
+impl<Namespace: Clone + Send + Sync + 'static> ApiDefinitionTester<Namespace>
+    for DefaultApiDefinitionTester
+{
+    async fn test(
+        &self,
+        definition: CompiledHttpApiDefinition<Namespace>,
+        request: InputHttpRequest,
+        mock_worker_result: Option<TypeAnnotatedValue>,
+    ) -> Result<ApiDefinitionTestResult, ApiDefinitionTestError> {
+        let resolved = request
+            .resolve_worker_binding(vec![definition])
+            .await
+            .map_err(ApiDefinitionTestError)?;
+
+        let (binding_type, resolved_binding) = match &resolved.resolved_binding {
+            ResolvedBinding::Static(_) => {
+                return Ok(ApiDefinitionTestResult {
+                    binding_type: GatewayBindingType::CorsPreflight,
+                    route: None,
+                    worker_name: None,
+                    idempotency_key: None,
+                    response_mapping_result: None,
+                });
+            }
+            ResolvedBinding::Worker(binding) => (GatewayBindingType::Default, binding),
+            ResolvedBinding::FileServer(binding) => (GatewayBindingType::FileServer, binding),
+            // Evaluated as if the body of the request was a message sent by the client
+            ResolvedBinding::Stream(binding) => (GatewayBindingType::Stream, binding),
+        };
+
+        let response_mapping_result = Self::evaluate_response_mapping(
+            self.evaluator(mock_worker_result),
+            &resolved.request_details,
+            resolved_binding,
+        )
+        .await;
+
+        Ok(ApiDefinitionTestResult {
+            binding_type,
+            route: Some(resolved_binding.route.clone()),
+            worker_name: resolved_binding.worker_detail.worker_name.clone(),
+            idempotency_key: resolved_binding.worker_detail.idempotency_key.clone(),
+            response_mapping_result: Some(response_mapping_result),
+        })
+    }
+}
+
+// Returns the same result for every worker call, without invoking the worker.
+// The result is in the same format as the result of invoking a worker function.
+struct MockRibInterpreter {
+    result: TypeAnnotatedValue,
+}
+
+#[async_trait]
+impl WorkerServiceRibInterpreter for MockRibInterpreter {
+    async fn evaluate(
+        &self,
+        _worker_name: Option<&str>,
+        _component_id: &ComponentId,
+        _idempotency_key: &Option<IdempotencyKey>,
+        rib_byte_code: &RibByteCode,
+        rib_input: &RibInput,
+    ) -> Result<RibResult, EvaluationError> {
+        let result = self.result.clone();
+
+        let worker_invoke_function: RibFunctionInvoke = Arc::new(
+            move |_function_name: String, _parameters: Vec<TypeAnnotatedValue>| {
+                let result = result.clone();
+                async move { Ok(result) }.boxed()
+            },
+        );
+
+        rib::interpret(rib_byte_code, rib_input, worker_invoke_function)
+            .await
+            .map_err(EvaluationError)
+    }
+}
@@ -5,12 +5,19 @@ use crate::gateway_binding::{
 use crate::gateway_execution::file_server_binding_handler::{
     FileServerBindingHandler, FileServerBindingResult,
 };
+use crate::gateway_execution::gateway_rate_limiter::{GatewayRateLimiter, RateLimitError};
+use crate::gateway_execution::gateway_response_cache::{
+    CacheLookup, CachedResponse, GatewayResponseCache, ResponseCacheError,
+};
 use crate::gateway_execution::to_response::ToResponse;
+use crate::gateway_execution::worker_stream_binding_handler::{
+    StreamMessageHandler, WorkerStreamBindingHandler, WorkerStreamBindingResult,
+};
 use crate::gateway_middleware::{Cors as CorsPreflight, Middlewares};
 use crate::gateway_rib_interpreter::{EvaluationError, WorkerServiceRibInterpreter};
 use async_trait::async_trait;
 use rib::{RibInput, RibResult};
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::sync::Arc;
 
 #[async_trait]
@@ -21,22 +28,79 @@ pub trait GatewayBindingExecutor<Namespace, Response> {
         EvaluationError: ToResponse<Response>,
         RibInputTypeMismatch: ToResponse<Response>,
         FileServerBindingResult: ToResponse<Response>,
-        CorsPreflight: ToResponse<Response>;
+        CorsPreflight: ToResponse<Response>,
+        RateLimitError: ToResponse<Response>,
+        CachedResponse: ToResponse<Response>,
+        ResponseCacheError: ToResponse<Response>,
+        WorkerStreamBindingResult: ToResponse<Response>;
 }
 
 pub struct DefaultGatewayBindingExecutor<Namespace> {
     pub evaluator: Arc<dyn WorkerServiceRibInterpreter + Sync + Send>,
     pub file_server_binding_handler: Arc<dyn FileServerBindingHandler<Namespace> + Sync + Send>,
+    pub rate_limiter: Arc<dyn GatewayRateLimiter + Sync + Send>,
+    pub response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
+    pub worker_stream_binding_handler: Arc<dyn WorkerStreamBindingHandler<Namespace> + Sync + Send>,
 }
 
-impl<N> DefaultGatewayBindingExecutor<N> {
+impl<N: Display> DefaultGatewayBindingExecutor<N> {
     pub fn new(
         evaluator: Arc<dyn WorkerServiceRibInterpreter + Sync + Send>,
         file_server_binding_handler: Arc<dyn FileServerBindingHandler<N> + Sync + Send>,
+        rate_limiter: Arc<dyn GatewayRateLimiter + Sync + Send>,
+        response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
+        worker_stream_binding_handler: Arc<dyn WorkerStreamBindingHandler<N> + Sync + Send>,
     ) -> Self {
         Self {
             evaluator,
             file_server_binding_handler,
+            rate_limiter,
+            response_cache,
+            worker_stream_binding_handler,
+        }
+    }
+
+    // Rate limits are checked before the worker is invoked,
+    // such that rejected requests never reach the worker
+    async fn check_rate_limit<R>(
+        &self,
+        request_details: &GatewayRequestDetails,
+        resolved_worker_binding: &ResolvedWorkerBinding<N>,
+    ) -> Result<(), R>
+    where
+        RateLimitError: ToResponse<R>,
+    {
+        match resolved_worker_binding.middlewares.get_rate_limit() {
+            Some(rate_limit) => self
+                .rate_limiter
+                .check_rate_limit(&resolved_worker_binding.route, &rate_limit, request_details)
+                .await
+                .map_err(|err| {
+                    err.to_response(request_details, &resolved_worker_binding.middlewares)
+                }),
+            None => Ok(()),
+        }
+    }
+
+    // Key of the response in the response cache, if the worker binding has a response cache
+    async fn get_response_cache_key<R>(
+        &self,
+        request_details: &GatewayRequestDetails,
+        resolved_worker_binding: &ResolvedWorkerBinding<N>,
+    ) -> Result<Option<String>, R>
+    where
+        ResponseCacheError: ToResponse<R>,
+    {
+        match &resolved_worker_binding.response_cache {
+            Some(response_cache) => self
+                .response_cache
+                .cache_key(response_cache, request_details)
+                .await
+                .map(Some)
+                .map_err(|err| {
+                    err.to_response(request_details, &resolved_worker_binding.middlewares)
+                }),
+            None => Ok(None),
         }
     }
 
@@ -96,7 +160,49 @@ impl<N> DefaultGatewayBindingExecutor<N> {
         RibResult: ToResponse<R>,
         EvaluationError: ToResponse<R>,
         RibInputTypeMismatch: ToResponse<R>,
+        RateLimitError: ToResponse<R>,
+        CachedResponse: ToResponse<R>,
+        ResponseCacheError: ToResponse<R>,
     {
+        if let Err(err_response) = self
+            .check_rate_limit(&binding.request_details, resolved_binding)
+            .await
+        {
+            return err_response;
+        }
+
+        let cache_key = match self
+            .get_response_cache_key(&binding.request_details, resolved_binding)
+            .await
+        {
+            Ok(cache_key) => cache_key,
+            Err(err_response) => return err_response,
+        };
+
+        let namespace = resolved_binding.namespace.to_string();
+
+        // The generation observed on a cache miss, for the response to be cached with
+        let cache_generation = match &cache_key {
+            Some(cache_key) => match self
+                .response_cache
+                .get(
+                    &namespace,
+                    &resolved_binding.api_definition,
+                    &resolved_binding.route,
+                    cache_key,
+                )
+                .await
+            {
+                CacheLookup::Hit(cached_response) => {
+                    return cached_response
+                        .to_response(&binding.request_details, &resolved_binding.middlewares);
+                }
+                CacheLookup::Miss { generation } => Some(generation),
+                CacheLookup::Unavailable => None,
+            },
+            None => None,
+        };
+
         match self
             .resolve_rib_inputs(&binding.request_details, resolved_binding)
             .await
@@ -106,9 +212,33 @@ impl<N> DefaultGatewayBindingExecutor<N> {
                     .get_rib_result(request_rib_input, worker_rib_input, resolved_binding)
                     .await
                 {
-                    Ok(result) => {
-                        result.to_response(&binding.request_details, &resolved_binding.middlewares)
-                    }
+                    Ok(result) => match (
+                        cache_key,
+                        cache_generation,
+                        &resolved_binding.response_cache,
+                    ) {
+                        (Some(cache_key), Some(generation), Some(response_cache))
+                            if CachedResponse::is_cacheable(&result) =>
+                        {
+                            self.response_cache
+                                .put(
+                                    &namespace,
+                                    &resolved_binding.api_definition,
+                                    &resolved_binding.route,
+                                    &cache_key,
+                                    generation,
+                                    result,
+                                    response_cache.get_ttl(),
+                                )
+                                .await
+                                .to_response(
+                                    &binding.request_details,
+                                    &resolved_binding.middlewares,
+                                )
+                        }
+                        _ => result
+                            .to_response(&binding.request_details, &resolved_binding.middlewares),
+                    },
                     Err(err) => {
                         err.to_response(&binding.request_details, &resolved_binding.middlewares)
                     }
@@ -128,7 +258,15 @@ impl<N> DefaultGatewayBindingExecutor<N> {
         RibResult: ToResponse<R>,
         EvaluationError: ToResponse<R>,
         RibInputTypeMismatch: ToResponse<R>,
+        RateLimitError: ToResponse<R>,
     {
+        if let Err(err_response) = self
+            .check_rate_limit(&binding.request_details, resolved_binding)
+            .await
+        {
+            return err_response;
+        }
+
         match self
             .resolve_rib_inputs(&binding.request_details, resolved_binding)
             .await
@@ -155,10 +293,45 @@ impl<N> DefaultGatewayBindingExecutor<N> {
             Err(err_response) => err_response,
         }
     }
+
+    // The response mapping of a stream binding is not evaluated for the request itself,
+    // but for each message the client sends once the connection is established
+    async fn handle_stream_binding<R>(
+        &self,
+        binding: &ResolvedGatewayBinding<N>,
+        resolved_binding: &ResolvedWorkerBinding<N>,
+    ) -> R
+    where
+        WorkerStreamBindingResult: ToResponse<R>,
+        RateLimitError: ToResponse<R>,
+    {
+        if let Err(err_response) = self
+            .check_rate_limit(&binding.request_details, resolved_binding)
+            .await
+        {
+            return err_response;
+        }
+
+        let message_handler = StreamMessageHandler::new(
+            self.evaluator.clone(),
+            resolved_binding.worker_detail.clone(),
+            resolved_binding.compiled_response_mapping.clone(),
+            binding.request_details.clone(),
+        );
+
+        self.worker_stream_binding_handler
+            .connect_worker_stream(
+                &resolved_binding.namespace,
+                &resolved_binding.worker_detail,
+                message_handler,
+            )
+            .await
+            .to_response(&binding.request_details, &resolved_binding.middlewares)
+    }
 }
 
 #[async_trait]
-impl<N: Send + Sync, R: Debug + Send + Sync> GatewayBindingExecutor<N, R>
+impl<N: Display + Send + Sync, R: Debug + Send + Sync> GatewayBindingExecutor<N, R>
     for DefaultGatewayBindingExecutor<N>
 {
     async fn execute_binding(&self, binding: &ResolvedGatewayBinding<N>) -> R
@@ -168,6 +341,10 @@ impl<N: Send + Sync, R: Debug + Send + Sync> GatewayBindingExecutor<N, R>
         RibInputTypeMismatch: ToResponse<R>,
         FileServerBindingResult: ToResponse<R>,
         CorsPreflight: ToResponse<R>,
+        RateLimitError: ToResponse<R>,
+        CachedResponse: ToResponse<R>,
+        ResponseCacheError: ToResponse<R>,
+        WorkerStreamBindingResult: ToResponse<R>,
     {
         match &binding.resolved_binding {
             ResolvedBinding::Worker(resolved_binding) => {
@@ -178,6 +355,10 @@ impl<N: Send + Sync, R: Debug + Send + Sync> GatewayBindingExecutor<N, R>
                 self.handle_file_server_binding::<R>(binding, resolved_binding)
                     .await
             }
+            ResolvedBinding::Stream(resolved_binding) => {
+                self.handle_stream_binding::<R>(binding, resolved_binding)
+                    .await
+            }
             ResolvedBinding::Static(StaticBinding::HttpCorsPreflight(cors_preflight)) => {
                 cors_preflight
                     .clone()
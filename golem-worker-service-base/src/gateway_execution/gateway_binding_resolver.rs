@@ -1,8 +1,10 @@
 use crate::gateway_api_definition::http::{CompiledHttpApiDefinition, VarInfo};
-use crate::gateway_binding::{GatewayBindingCompiled, StaticBinding};
+use crate::gateway_binding::{GatewayBindingCompiled, ResponseCache, StaticBinding};
 use crate::gateway_binding::{GatewayRequestDetails, ResponseMappingCompiled};
 use crate::gateway_execution::router::RouterPattern;
 use crate::gateway_request::http_request::{router, InputHttpRequest};
+use crate::gateway_request::request_validator::RequestValidationErrors;
+use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use async_trait::async_trait;
 use golem_common::model::IdempotencyKey;
 use golem_service_base::model::VersionedComponentId;
@@ -23,17 +25,31 @@ pub trait GatewayBindingResolver<Namespace, ApiDefinition> {
 }
 
 #[derive(Debug)]
-pub struct GatewayBindingResolverError(pub String);
+pub enum GatewayBindingResolverError {
+    // The request failed the authentication middleware of the resolved route
+    Unauthorized(String),
+    // The request doesn't match the types inferred for `request` in the Rib expressions of the resolved route
+    InvalidRequest(RequestValidationErrors),
+    Other(String),
+}
 
 impl<A: AsRef<str>> From<A> for GatewayBindingResolverError {
     fn from(message: A) -> Self {
-        GatewayBindingResolverError(message.as_ref().to_string())
+        GatewayBindingResolverError::Other(message.as_ref().to_string())
     }
 }
 
 impl Display for GatewayBindingResolverError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Worker binding resolution error: {}", self.0)
+        match self {
+            GatewayBindingResolverError::Unauthorized(message) => {
+                write!(f, "Unauthorized: {}", message)
+            }
+            GatewayBindingResolverError::InvalidRequest(errors) => write!(f, "{}", errors),
+            GatewayBindingResolverError::Other(message) => {
+                write!(f, "Worker binding resolution error: {}", message)
+            }
+        }
     }
 }
 
@@ -48,6 +64,7 @@ pub enum ResolvedBinding<Namespace> {
     Static(StaticBinding),
     Worker(ResolvedWorkerBinding<Namespace>),
     FileServer(ResolvedWorkerBinding<Namespace>),
+    Stream(ResolvedWorkerBinding<Namespace>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -89,6 +106,12 @@ pub struct ResolvedWorkerBinding<Namespace> {
     pub compiled_response_mapping: ResponseMappingCompiled,
     pub namespace: Namespace,
     pub middlewares: Middlewares,
+    // API definition the matched route belongs to
+    pub api_definition: ApiDefinitionIdWithVersion,
+    // Host, method and path pattern of the matched route,
+    // identifying the route in any state kept across requests (Example: rate limits)
+    pub route: String,
+    pub response_cache: Option<ResponseCache>,
 }
 
 impl<Namespace> ResolvedGatewayBinding<Namespace> {
@@ -131,7 +154,16 @@ impl<Namespace: Clone + Send + Sync + 'static>
     ) -> Result<ResolvedGatewayBinding<Namespace>, GatewayBindingResolverError> {
         let compiled_routes = compiled_api_definitions
             .iter()
-            .flat_map(|x| x.routes.iter().map(|y| (x.namespace.clone(), y.clone())))
+            .flat_map(|x| {
+                let api_definition = ApiDefinitionIdWithVersion {
+                    id: x.id.clone(),
+                    version: x.version.clone(),
+                };
+
+                x.routes
+                    .iter()
+                    .map(move |y| (x.namespace.clone(), api_definition.clone(), y.clone()))
+            })
             .collect::<Vec<_>>();
 
         let api_request = self;
@@ -140,16 +172,48 @@ impl<Namespace: Clone + Send + Sync + 'static>
         let path: Vec<&str> = RouterPattern::split(&api_request.input_path.base_path).collect();
         let request_query_variables = self.input_path.query_components().unwrap_or_default();
         let request_body = &self.req_body;
-        let headers = &self.headers;
+
+        let route_entry = router
+            .check_path(&api_request.req_method, &path)
+            .ok_or("Failed to resolve route")?;
+
+        // A request to a route with a path rewrite is resolved once more, as if it was sent to the rewritten path.
+        // The path of the rewritten request is not rewritten again,
+        // and the request headers are transformed by both of the routes.
+        let rewritten_path = internal::rewrite_path(route_entry, &path)?;
+
+        let mut headers = self.headers.clone();
+        internal::transform_request_headers(&route_entry.binding, &mut headers);
+
+        let (path, route_entry) = match &rewritten_path {
+            Some(rewritten_path) => {
+                let path: Vec<&str> = RouterPattern::split(rewritten_path).collect();
+
+                let route_entry =
+                    router
+                        .check_path(&api_request.req_method, &path)
+                        .ok_or(format!(
+                            "Failed to resolve route of the rewritten path {}",
+                            rewritten_path
+                        ))?;
+
+                internal::transform_request_headers(&route_entry.binding, &mut headers);
+
+                (path, route_entry)
+            }
+            None => (path, route_entry),
+        };
 
         let router::RouteEntry {
             path_params,
             query_params,
             namespace,
+            api_definition,
             binding,
-        } = router
-            .check_path(&api_request.req_method, &path)
-            .ok_or("Failed to resolve route")?;
+            route,
+        } = route_entry;
+
+        let headers = &headers;
 
         let zipped_path_params: HashMap<VarInfo, &str> = {
             path_params
@@ -164,8 +228,39 @@ impl<Namespace: Clone + Send + Sync + 'static>
             query_params,
             request_body,
             headers,
+            self.client_address,
         )
-        .map_err(|err| format!("Failed to fetch input request details {}", err.join(", ")))?;
+        .map_err(|err| format!("Failed to fetch input request details {}", err.join(", ")))?
+        .with_websocket_upgrade(self.websocket_upgrade.clone());
+
+        // Routes of different API deployments are told apart by the host
+        let route = match self.get_host() {
+            Some(host) => format!("{} {}", host, route),
+            None => route,
+        };
+
+        let http_request_details = match binding {
+            GatewayBindingCompiled::Worker(worker_binding)
+            | GatewayBindingCompiled::FileServer(worker_binding) => {
+                let http_request_details =
+                    internal::authenticate(worker_binding, http_request_details, headers).await?;
+
+                internal::validate_request(worker_binding, &http_request_details, true)?;
+
+                http_request_details
+            }
+            // The response mapping of a stream binding is evaluated for each inbound message,
+            // having the message as the request body
+            GatewayBindingCompiled::Stream(worker_binding) => {
+                let http_request_details =
+                    internal::authenticate(worker_binding, http_request_details, headers).await?;
+
+                internal::validate_request(worker_binding, &http_request_details, false)?;
+
+                http_request_details
+            }
+            GatewayBindingCompiled::Static(_) => http_request_details,
+        };
 
         match binding {
             GatewayBindingCompiled::FileServer(worker_binding) => internal::get_resolved_binding(
@@ -173,6 +268,8 @@ impl<Namespace: Clone + Send + Sync + 'static>
                 &http_request_details,
                 namespace,
                 headers,
+                api_definition,
+                &route,
             )
             .await
             .map(|resolved_binding| ResolvedGatewayBinding {
@@ -184,12 +281,27 @@ impl<Namespace: Clone + Send + Sync + 'static>
                 &http_request_details,
                 namespace,
                 headers,
+                api_definition,
+                &route,
             )
             .await
             .map(|resolved_binding| ResolvedGatewayBinding {
                 request_details: http_request_details,
                 resolved_binding: ResolvedBinding::Worker(resolved_binding),
             }),
+            GatewayBindingCompiled::Stream(worker_binding) => internal::get_resolved_binding(
+                worker_binding,
+                &http_request_details,
+                namespace,
+                headers,
+                api_definition,
+                &route,
+            )
+            .await
+            .map(|resolved_binding| ResolvedGatewayBinding {
+                request_details: http_request_details,
+                resolved_binding: ResolvedBinding::Stream(resolved_binding),
+            }),
             GatewayBindingCompiled::Static(static_binding) => Ok(
                 ResolvedGatewayBinding::from_static_binding(&http_request_details, static_binding),
             ),
@@ -199,17 +311,113 @@ impl<Namespace: Clone + Send + Sync + 'static>
 
 mod internal {
     use crate::gateway_binding::{
-        GatewayBindingResolverError, GatewayRequestDetails, ResolvedWorkerBinding,
-        RibInputValueResolver, WorkerBindingCompiled, WorkerDetail,
+        GatewayBindingCompiled, GatewayBindingResolverError, GatewayRequestDetails,
+        ResolvedWorkerBinding, RibInputValueResolver, WorkerBindingCompiled, WorkerDetail,
     };
+    use crate::gateway_middleware::Transformation;
+    use crate::gateway_request::http_request::router::RouteEntry;
+    use crate::gateway_request::request_validator::RequestValidator;
+    use crate::metrics::traffic_split::record_traffic_split_target;
+    use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
     use golem_common::model::IdempotencyKey;
     use http::HeaderMap;
+    use rib::RibInputTypeInfo;
+    use std::collections::HashMap;
+
+    fn get_transformation(binding: &GatewayBindingCompiled) -> Option<Transformation> {
+        match binding {
+            GatewayBindingCompiled::Worker(worker_binding)
+            | GatewayBindingCompiled::FileServer(worker_binding)
+            | GatewayBindingCompiled::Stream(worker_binding) => worker_binding
+                .middlewares
+                .as_ref()
+                .and_then(|middlewares| middlewares.get_transformation()),
+            GatewayBindingCompiled::Static(_) => None,
+        }
+    }
+
+    // The rewritten path of the request if the matched route has a transformation middleware with a path rewrite
+    pub fn rewrite_path<Namespace>(
+        route_entry: &RouteEntry<Namespace>,
+        path: &[&str],
+    ) -> Result<Option<String>, GatewayBindingResolverError> {
+        let path_variables: HashMap<String, &str> = route_entry
+            .path_params
+            .iter()
+            .map(|(var, index)| (var.key_name.clone(), path[*index]))
+            .collect();
+
+        get_transformation(&route_entry.binding)
+            .and_then(|transformation| transformation.rewrite_path(&path_variables))
+            .transpose()
+            .map_err(GatewayBindingResolverError::Other)
+    }
+
+    // Headers of the request as seen by the binding (and Rib), after the transformation middleware of the route (if any)
+    pub fn transform_request_headers(binding: &GatewayBindingCompiled, headers: &mut HeaderMap) {
+        if let Some(transformation) = get_transformation(binding) {
+            transformation.transform_request_headers(headers);
+        }
+    }
+
+    // Validates the request against the authentication middleware of the binding (if any),
+    // making the claims of the bearer token available to Rib as `request.auth.claims`
+    pub async fn authenticate(
+        binding: &WorkerBindingCompiled,
+        http_request_details: GatewayRequestDetails,
+        headers: &HeaderMap,
+    ) -> Result<GatewayRequestDetails, GatewayBindingResolverError> {
+        let jwt_authentication = binding
+            .middlewares
+            .as_ref()
+            .and_then(|middlewares| middlewares.get_jwt_authentication());
+
+        match jwt_authentication {
+            Some(jwt_authentication) => {
+                let claims = jwt_authentication
+                    .authenticate(headers)
+                    .await
+                    .map_err(GatewayBindingResolverError::Unauthorized)?;
+
+                Ok(http_request_details.with_auth_claims(claims))
+            }
+            None => Ok(http_request_details),
+        }
+    }
+
+    // Validated against every Rib expression of the binding that makes use of the request,
+    // as the worker name is resolved before the response mapping is evaluated
+    pub fn validate_request(
+        binding: &WorkerBindingCompiled,
+        http_request_details: &GatewayRequestDetails,
+        include_response_mapping: bool,
+    ) -> Result<(), GatewayBindingResolverError> {
+        let mut rib_inputs: Vec<&RibInputTypeInfo> = vec![];
+
+        if let Some(worker_name_compiled) = &binding.worker_name_compiled {
+            rib_inputs.push(&worker_name_compiled.rib_input_type_info);
+        }
+
+        if let Some(idempotency_key_compiled) = &binding.idempotency_key_compiled {
+            rib_inputs.push(&idempotency_key_compiled.rib_input);
+        }
+
+        if include_response_mapping {
+            rib_inputs.push(&binding.response_compiled.rib_input);
+        }
+
+        http_request_details
+            .validate(&rib_inputs)
+            .map_err(GatewayBindingResolverError::InvalidRequest)
+    }
 
     pub async fn get_resolved_binding<Namespace: Clone>(
         binding: &WorkerBindingCompiled,
         http_request_details: &GatewayRequestDetails,
         namespace: &Namespace,
         headers: &HeaderMap,
+        api_definition: &ApiDefinitionIdWithVersion,
+        route: &str,
     ) -> Result<ResolvedWorkerBinding<Namespace>, GatewayBindingResolverError> {
         let worker_name_opt = if let Some(worker_name_compiled) = &binding.worker_name_compiled {
             let resolve_rib_input = http_request_details
@@ -236,7 +444,15 @@ mod internal {
             None
         };
 
-        let component_id = &binding.component_id;
+        let component_id = match &binding.traffic_split {
+            Some(traffic_split) => {
+                let component_id =
+                    traffic_split.select(&binding.component_id, worker_name_opt.as_deref());
+                record_traffic_split_target(route, &component_id);
+                component_id
+            }
+            None => binding.component_id.clone(),
+        };
 
         let idempotency_key = if let Some(idempotency_key_compiled) =
             &binding.idempotency_key_compiled
@@ -271,7 +487,7 @@ mod internal {
         };
 
         let worker_detail = WorkerDetail {
-            component_id: component_id.clone(),
+            component_id,
             worker_name: worker_name_opt,
             idempotency_key,
         };
@@ -281,6 +497,9 @@ mod internal {
             compiled_response_mapping: binding.response_compiled.clone(),
             namespace: namespace.clone(),
             middlewares: binding.middlewares.clone().unwrap_or_default(),
+            api_definition: api_definition.clone(),
+            route: route.to_string(),
+            response_cache: binding.response_cache.clone(),
         };
 
         Ok(resolved_binding)
@@ -0,0 +1,273 @@
+use crate::gateway_binding::GatewayRequestDetails;
+use crate::gateway_execution::rib_key_evaluator::RibKeyEvaluator;
+use crate::gateway_middleware::RateLimit;
+use async_trait::async_trait;
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use std::fmt::Display;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Keeps the token buckets of routes having a rate limit middleware,
+// and decides whether a request can go through to the worker.
+#[async_trait]
+pub trait GatewayRateLimiter {
+    async fn check_rate_limit(
+        &self,
+        route: &str,
+        rate_limit: &RateLimit,
+        request_details: &GatewayRequestDetails,
+    ) -> Result<(), RateLimitError>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateLimitError {
+    Exceeded { retry_after: Duration },
+    InvalidKey(String),
+}
+
+impl Display for RateLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimitError::Exceeded { retry_after } => {
+                write!(f, "Rate limit exceeded, retry after {:?}", retry_after)
+            }
+            RateLimitError::InvalidKey(error) => {
+                write!(f, "Failed to compute the rate limit key: {}", error)
+            }
+        }
+    }
+}
+
+// Buckets are kept in memory, which means the limits apply per worker service instance
+pub struct DefaultGatewayRateLimiter {
+    buckets: Cache<(String, String), (), Arc<Mutex<TokenBucket>>, ()>,
+    key_evaluator: RibKeyEvaluator,
+}
+
+impl Default for DefaultGatewayRateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DefaultGatewayRateLimiter {
+    // Once there are more buckets than this, the least recently used ones are dropped.
+    // A client whose bucket is dropped starts again with a full bucket.
+    const MAX_BUCKETS: usize = 10000;
+
+    pub fn new() -> Self {
+        DefaultGatewayRateLimiter {
+            buckets: Cache::new(
+                Some(Self::MAX_BUCKETS),
+                FullCacheEvictionMode::LeastRecentlyUsed(1),
+                BackgroundEvictionMode::None,
+                "gateway_rate_limiter",
+            ),
+            key_evaluator: RibKeyEvaluator::new(),
+        }
+    }
+
+    async fn client_key(
+        &self,
+        rate_limit: &RateLimit,
+        request_details: &GatewayRequestDetails,
+    ) -> Result<String, RateLimitError> {
+        if let Some(key_expr) = rate_limit.get_key_expr() {
+            return self
+                .key_evaluator
+                .evaluate(&key_expr, request_details)
+                .await
+                .map_err(RateLimitError::InvalidKey);
+        }
+
+        match request_details {
+            GatewayRequestDetails::Http(http_request_details) => {
+                let client_address = http_request_details
+                    .client_address
+                    .map(|address| address.to_string());
+
+                // Requests without the header are limited by their client address
+                rate_limit
+                    .get_key_header()
+                    .and_then(|key_header| http_request_details.get_header_value(&key_header))
+                    .or(client_address)
+                    .ok_or_else(|| {
+                        RateLimitError::InvalidKey(
+                            "The request has neither the key header nor a client address"
+                                .to_string(),
+                        )
+                    })
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl GatewayRateLimiter for DefaultGatewayRateLimiter {
+    async fn check_rate_limit(
+        &self,
+        route: &str,
+        rate_limit: &RateLimit,
+        request_details: &GatewayRequestDetails,
+    ) -> Result<(), RateLimitError> {
+        let client_key = self.client_key(rate_limit, request_details).await?;
+
+        let now = Instant::now();
+        let initial_bucket = TokenBucket::new(rate_limit, now);
+
+        let bucket = self
+            .buckets
+            .get_or_insert_simple(&(route.to_string(), client_key), || {
+                Box::pin(async move { Ok(Arc::new(Mutex::new(initial_bucket))) })
+            })
+            .await
+            .expect("Creating a token bucket never fails");
+
+        let mut bucket = bucket.lock().unwrap();
+        bucket
+            .try_take(rate_limit, now)
+            .map_err(|retry_after| RateLimitError::Exceeded { retry_after })
+    }
+}
+
+struct TokenBucket {
+    capacity: f64,
+    refill_per_second: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate_limit: &RateLimit, now: Instant) -> TokenBucket {
+        let capacity = rate_limit.get_requests() as f64;
+
+        TokenBucket {
+            capacity,
+            refill_per_second: capacity / rate_limit.get_period().as_secs_f64(),
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    // Takes a token out of the bucket, or returns the time until the next token is available.
+    // The configuration of the route may have changed since the bucket was created.
+    fn try_take(&mut self, rate_limit: &RateLimit, now: Instant) -> Result<(), Duration> {
+        let updated = TokenBucket::new(rate_limit, now);
+        self.capacity = updated.capacity;
+        self.refill_per_second = updated.refill_per_second;
+
+        self.refill(now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_per_second,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use crate::gateway_binding::HttpRequestDetails;
+
+    fn request_from(client_address: &str) -> GatewayRequestDetails {
+        let mut http_request_details = HttpRequestDetails::empty();
+        http_request_details.client_address = Some(client_address.parse().unwrap());
+        GatewayRequestDetails::Http(http_request_details)
+    }
+
+    #[test]
+    async fn test_rate_limit_per_client_address() {
+        let rate_limiter = DefaultGatewayRateLimiter::new();
+        let rate_limit = RateLimit::from_parameters(2, 60, None, None).unwrap();
+
+        let first_client = request_from("10.0.0.1");
+        let second_client = request_from("10.0.0.2");
+
+        for _ in 0..2 {
+            let result = rate_limiter
+                .check_rate_limit("GET /test", &rate_limit, &first_client)
+                .await;
+            assert_eq!(result, Ok(()));
+        }
+
+        let exceeded = rate_limiter
+            .check_rate_limit("GET /test", &rate_limit, &first_client)
+            .await;
+
+        let other_client = rate_limiter
+            .check_rate_limit("GET /test", &rate_limit, &second_client)
+            .await;
+
+        let other_route = rate_limiter
+            .check_rate_limit("GET /other", &rate_limit, &first_client)
+            .await;
+
+        assert!(matches!(exceeded, Err(RateLimitError::Exceeded { .. })));
+        assert_eq!(other_client, Ok(()));
+        assert_eq!(other_route, Ok(()));
+    }
+
+    #[test]
+    async fn test_rate_limit_without_key_header_uses_client_address() {
+        let rate_limiter = DefaultGatewayRateLimiter::new();
+        let rate_limit =
+            RateLimit::from_parameters(1, 60, Some("x-api-key".to_string()), None).unwrap();
+
+        let first_client = request_from("10.0.0.1");
+        let second_client = request_from("10.0.0.2");
+
+        let first = rate_limiter
+            .check_rate_limit("GET /test", &rate_limit, &first_client)
+            .await;
+        let exceeded = rate_limiter
+            .check_rate_limit("GET /test", &rate_limit, &first_client)
+            .await;
+        let other_client = rate_limiter
+            .check_rate_limit("GET /test", &rate_limit, &second_client)
+            .await;
+        let unknown_client = rate_limiter
+            .check_rate_limit(
+                "GET /test",
+                &rate_limit,
+                &GatewayRequestDetails::Http(HttpRequestDetails::empty()),
+            )
+            .await;
+
+        assert_eq!(first, Ok(()));
+        assert!(matches!(exceeded, Err(RateLimitError::Exceeded { .. })));
+        assert_eq!(other_client, Ok(()));
+        assert!(matches!(unknown_client, Err(RateLimitError::InvalidKey(_))));
+    }
+
+    #[test]
+    fn test_token_bucket_refill() {
+        let rate_limit = RateLimit::from_parameters(1, 10, None, None).unwrap();
+        let now = Instant::now();
+
+        let mut bucket = TokenBucket::new(&rate_limit, now);
+
+        assert_eq!(bucket.try_take(&rate_limit, now), Ok(()));
+        assert!(bucket
+            .try_take(&rate_limit, now + Duration::from_secs(5))
+            .is_err());
+        assert_eq!(
+            bucket.try_take(&rate_limit, now + Duration::from_secs(10)),
+            Ok(())
+        );
+    }
+}
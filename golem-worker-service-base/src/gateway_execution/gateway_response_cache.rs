@@ -0,0 +1,536 @@
+use crate::gateway_binding::{GatewayRequestDetails, ResponseCache};
+use crate::gateway_execution::rib_key_evaluator::RibKeyEvaluator;
+use crate::getter::get_status_code_or_ok;
+use crate::repo::api_definition_cache::ApiDefinitionCacheRepo;
+use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
+use async_trait::async_trait;
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use golem_common::SafeDisplay;
+use golem_wasm_rpc::json::TypeAnnotatedValueJsonExtensions;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use rib::RibResult;
+use sha2::{Digest, Sha256};
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// Keeps the responses of worker bindings having a response cache,
+// such that requests with the same cache key are served without invoking the worker.
+#[async_trait]
+pub trait GatewayResponseCache {
+    async fn cache_key(
+        &self,
+        response_cache: &ResponseCache,
+        request_details: &GatewayRequestDetails,
+    ) -> Result<String, ResponseCacheError>;
+
+    async fn get(
+        &self,
+        namespace: &str,
+        api_definition: &ApiDefinitionIdWithVersion,
+        route: &str,
+        key: &str,
+    ) -> CacheLookup;
+
+    // The generation is the one returned by the lookup that missed,
+    // such that a response computed while being invalidated is never served afterwards
+    async fn put(
+        &self,
+        namespace: &str,
+        api_definition: &ApiDefinitionIdWithVersion,
+        route: &str,
+        key: &str,
+        generation: i64,
+        result: RibResult,
+        ttl: Duration,
+    ) -> CachedResponse;
+
+    // Invalidates the cached responses of all routes of the API definition,
+    // or only the ones with the given cache key, in every worker service instance
+    async fn invalidate(
+        &self,
+        namespace: &str,
+        api_definition: &ApiDefinitionIdWithVersion,
+        key: Option<&str>,
+    ) -> Result<(), ResponseCacheError>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CacheLookup {
+    Hit(CachedResponse),
+    Miss { generation: i64 },
+    // The generation of the responses could not be retrieved,
+    // so the request is served without the cache
+    Unavailable,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseCacheError {
+    InvalidKey(String),
+    Internal(String),
+}
+
+impl Display for ResponseCacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResponseCacheError::InvalidKey(error) => {
+                write!(f, "Failed to compute the response cache key: {}", error)
+            }
+            ResponseCacheError::Internal(error) => {
+                write!(f, "Failed to access the response cache: {}", error)
+            }
+        }
+    }
+}
+
+// Result of the response mapping of a route, along with its entity tag.
+// The ETag only depends on the result, such that it is the same across repeated evaluations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedResponse {
+    pub result: Option<TypeAnnotatedValue>,
+    pub etag: String,
+}
+
+impl CachedResponse {
+    pub fn from_rib_result(result: RibResult) -> CachedResponse {
+        let result = match result {
+            RibResult::Unit => None,
+            RibResult::Val(value) => Some(value),
+        };
+
+        let json = result
+            .as_ref()
+            .map(|value| value.to_json_value().to_string())
+            .unwrap_or_default();
+
+        CachedResponse {
+            result,
+            etag: format!("\"{:x}\"", Sha256::digest(json.as_bytes())),
+        }
+    }
+
+    // Only successful responses are cached, as errors are expected to be transient
+    pub fn is_cacheable(result: &RibResult) -> bool {
+        match result {
+            RibResult::Unit => true,
+            RibResult::Val(value) => {
+                get_status_code_or_ok(value).is_ok_and(|status| status.is_success())
+            }
+        }
+    }
+
+    pub fn to_rib_result(&self) -> RibResult {
+        match &self.result {
+            Some(value) => RibResult::Val(value.clone()),
+            None => RibResult::Unit,
+        }
+    }
+
+    // Whether an `If-None-Match` header value matches the ETag of the response
+    pub fn matches(&self, if_none_match: &str) -> bool {
+        if_none_match
+            .split(',')
+            .map(|etag| etag.trim().trim_start_matches("W/"))
+            .any(|etag| etag == "*" || etag == self.etag)
+    }
+}
+
+// Cached responses are kept in memory by each worker service instance,
+// while their generation is shared through the repo
+pub struct DefaultGatewayResponseCache {
+    entries: Cache<CacheEntryKey, (), CacheEntry, ()>,
+    repo: Arc<dyn ApiDefinitionCacheRepo + Sync + Send>,
+    key_evaluator: RibKeyEvaluator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheEntryKey {
+    namespace: String,
+    api_definition: ApiDefinitionIdWithVersion,
+    route: String,
+    key: String,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    response: CachedResponse,
+    expires_at: Instant,
+    generation: i64,
+}
+
+impl DefaultGatewayResponseCache {
+    // Once there are more responses than this, the least recently used ones are dropped
+    const MAX_ENTRIES: usize = 10000;
+
+    pub fn new(repo: Arc<dyn ApiDefinitionCacheRepo + Sync + Send>) -> Self {
+        DefaultGatewayResponseCache {
+            entries: Cache::new(
+                Some(Self::MAX_ENTRIES),
+                FullCacheEvictionMode::LeastRecentlyUsed(1),
+                BackgroundEvictionMode::None,
+                "gateway_response_cache",
+            ),
+            repo,
+            key_evaluator: RibKeyEvaluator::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl GatewayResponseCache for DefaultGatewayResponseCache {
+    async fn cache_key(
+        &self,
+        response_cache: &ResponseCache,
+        request_details: &GatewayRequestDetails,
+    ) -> Result<String, ResponseCacheError> {
+        self.key_evaluator
+            .evaluate(&response_cache.get_key_expr(), request_details)
+            .await
+            .map_err(ResponseCacheError::InvalidKey)
+    }
+
+    async fn get(
+        &self,
+        namespace: &str,
+        api_definition: &ApiDefinitionIdWithVersion,
+        route: &str,
+        key: &str,
+    ) -> CacheLookup {
+        let generation = match self
+            .repo
+            .get_generation(
+                namespace,
+                &api_definition.id.0,
+                &api_definition.version.0,
+                key,
+            )
+            .await
+        {
+            Ok(generation) => generation,
+            Err(_) => return CacheLookup::Unavailable,
+        };
+
+        let entry_key = CacheEntryKey {
+            namespace: namespace.to_string(),
+            api_definition: api_definition.clone(),
+            route: route.to_string(),
+            key: key.to_string(),
+        };
+
+        match self.entries.get(&entry_key).await {
+            Some(entry) if entry.generation == generation && entry.expires_at > Instant::now() => {
+                CacheLookup::Hit(entry.response)
+            }
+            Some(_) => {
+                self.entries.remove(&entry_key);
+                CacheLookup::Miss { generation }
+            }
+            None => CacheLookup::Miss { generation },
+        }
+    }
+
+    async fn put(
+        &self,
+        namespace: &str,
+        api_definition: &ApiDefinitionIdWithVersion,
+        route: &str,
+        key: &str,
+        generation: i64,
+        result: RibResult,
+        ttl: Duration,
+    ) -> CachedResponse {
+        let response = CachedResponse::from_rib_result(result);
+
+        let entry_key = CacheEntryKey {
+            namespace: namespace.to_string(),
+            api_definition: api_definition.clone(),
+            route: route.to_string(),
+            key: key.to_string(),
+        };
+
+        let entry = CacheEntry {
+            response: response.clone(),
+            expires_at: Instant::now() + ttl,
+            generation,
+        };
+
+        self.entries.remove(&entry_key);
+        let _ = self
+            .entries
+            .get_or_insert_simple(&entry_key, || Box::pin(async move { Ok(entry) }))
+            .await;
+
+        response
+    }
+
+    async fn invalidate(
+        &self,
+        namespace: &str,
+        api_definition: &ApiDefinitionIdWithVersion,
+        key: Option<&str>,
+    ) -> Result<(), ResponseCacheError> {
+        self.repo
+            .invalidate(
+                namespace,
+                &api_definition.id.0,
+                &api_definition.version.0,
+                key,
+            )
+            .await
+            .map_err(|error| ResponseCacheError::Internal(error.to_safe_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use crate::gateway_api_definition::{ApiDefinitionId, ApiVersion};
+    use golem_service_base::repo::RepoError;
+    use golem_wasm_rpc::protobuf::{NameTypePair, NameValuePair, Type, TypedRecord};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct TestApiDefinitionCacheRepo {
+        generations: Mutex<HashMap<(String, String, String, String), i64>>,
+    }
+
+    #[async_trait]
+    impl ApiDefinitionCacheRepo for TestApiDefinitionCacheRepo {
+        async fn invalidate(
+            &self,
+            namespace: &str,
+            id: &str,
+            version: &str,
+            cache_key: Option<&str>,
+        ) -> Result<(), RepoError> {
+            let key = (
+                namespace.to_string(),
+                id.to_string(),
+                version.to_string(),
+                cache_key.unwrap_or_default().to_string(),
+            );
+            *self.generations.lock().unwrap().entry(key).or_default() += 1;
+            Ok(())
+        }
+
+        async fn get_generation(
+            &self,
+            namespace: &str,
+            id: &str,
+            version: &str,
+            cache_key: &str,
+        ) -> Result<i64, RepoError> {
+            let generations = self.generations.lock().unwrap();
+            Ok(["", cache_key]
+                .iter()
+                .filter_map(|cache_key| {
+                    generations.get(&(
+                        namespace.to_string(),
+                        id.to_string(),
+                        version.to_string(),
+                        cache_key.to_string(),
+                    ))
+                })
+                .sum())
+        }
+    }
+
+    fn api_definition(version: &str) -> ApiDefinitionIdWithVersion {
+        ApiDefinitionIdWithVersion {
+            id: ApiDefinitionId("shopping-cart".to_string()),
+            version: ApiVersion(version.to_string()),
+        }
+    }
+
+    fn result(value: &str) -> RibResult {
+        RibResult::Val(TypeAnnotatedValue::Str(value.to_string()))
+    }
+
+    async fn put(
+        cache: &DefaultGatewayResponseCache,
+        definition: &ApiDefinitionIdWithVersion,
+        route: &str,
+        key: &str,
+        value: &str,
+        ttl: Duration,
+    ) -> CachedResponse {
+        match cache.get("default", definition, route, key).await {
+            CacheLookup::Miss { generation } => {
+                cache
+                    .put(
+                        "default",
+                        definition,
+                        route,
+                        key,
+                        generation,
+                        result(value),
+                        ttl,
+                    )
+                    .await
+            }
+            lookup => panic!("Expected a cache miss, received: {lookup:?}"),
+        }
+    }
+
+    async fn is_hit(
+        cache: &DefaultGatewayResponseCache,
+        definition: &ApiDefinitionIdWithVersion,
+        route: &str,
+        key: &str,
+    ) -> bool {
+        matches!(
+            cache.get("default", definition, route, key).await,
+            CacheLookup::Hit(_)
+        )
+    }
+
+    #[test]
+    async fn test_response_cache_hit_and_expiry() {
+        let cache =
+            DefaultGatewayResponseCache::new(Arc::new(TestApiDefinitionCacheRepo::default()));
+        let definition = api_definition("0.0.1");
+        let ttl = Duration::from_secs(60);
+
+        let cached = put(&cache, &definition, "GET /cart/{user}", "jon", "cart", ttl).await;
+        let expired = put(
+            &cache,
+            &definition,
+            "GET /orders/{user}",
+            "jon",
+            "orders",
+            Duration::ZERO,
+        )
+        .await;
+
+        assert_eq!(
+            cache
+                .get("default", &definition, "GET /cart/{user}", "jon")
+                .await,
+            CacheLookup::Hit(cached.clone())
+        );
+        assert!(!is_hit(&cache, &definition, "GET /cart/{user}", "ann").await);
+        assert!(!is_hit(&cache, &api_definition("0.0.2"), "GET /cart/{user}", "jon").await);
+        assert!(!is_hit(&cache, &definition, "GET /orders/{user}", "jon").await);
+        assert!(matches!(
+            cache
+                .get("other", &definition, "GET /cart/{user}", "jon")
+                .await,
+            CacheLookup::Miss { .. }
+        ));
+        assert_ne!(cached.etag, expired.etag);
+    }
+
+    #[test]
+    async fn test_response_cache_invalidation_is_shared() {
+        let repo = Arc::new(TestApiDefinitionCacheRepo::default());
+        let cache = DefaultGatewayResponseCache::new(repo.clone());
+        let other_instance = DefaultGatewayResponseCache::new(repo);
+        let definition = api_definition("0.0.1");
+        let ttl = Duration::from_secs(60);
+
+        for instance in [&cache, &other_instance] {
+            for route in ["GET /cart/{user}", "GET /orders/{user}"] {
+                for key in ["jon", "ann"] {
+                    put(instance, &definition, route, key, key, ttl).await;
+                }
+            }
+        }
+
+        cache
+            .invalidate("default", &definition, Some("jon"))
+            .await
+            .unwrap();
+
+        for instance in [&cache, &other_instance] {
+            assert!(!is_hit(instance, &definition, "GET /cart/{user}", "jon").await);
+            assert!(is_hit(instance, &definition, "GET /cart/{user}", "ann").await);
+        }
+
+        other_instance
+            .invalidate("default", &definition, None)
+            .await
+            .unwrap();
+
+        for instance in [&cache, &other_instance] {
+            assert!(!is_hit(instance, &definition, "GET /cart/{user}", "ann").await);
+            assert!(!is_hit(instance, &definition, "GET /orders/{user}", "ann").await);
+        }
+    }
+
+    #[test]
+    async fn test_response_computed_before_invalidation_is_not_cached() {
+        let cache =
+            DefaultGatewayResponseCache::new(Arc::new(TestApiDefinitionCacheRepo::default()));
+        let definition = api_definition("0.0.1");
+
+        let generation = match cache
+            .get("default", &definition, "GET /cart/{user}", "jon")
+            .await
+        {
+            CacheLookup::Miss { generation } => generation,
+            lookup => panic!("Expected a cache miss, received: {lookup:?}"),
+        };
+
+        cache
+            .invalidate("default", &definition, Some("jon"))
+            .await
+            .unwrap();
+
+        cache
+            .put(
+                "default",
+                &definition,
+                "GET /cart/{user}",
+                "jon",
+                generation,
+                result("cart"),
+                Duration::from_secs(60),
+            )
+            .await;
+
+        assert!(!is_hit(&cache, &definition, "GET /cart/{user}", "jon").await);
+    }
+
+    #[test]
+    fn test_only_successful_responses_are_cacheable() {
+        fn with_status(status: u16) -> RibResult {
+            let status = TypeAnnotatedValue::U16(status);
+
+            RibResult::Val(TypeAnnotatedValue::Record(TypedRecord {
+                typ: vec![NameTypePair {
+                    name: "status".to_string(),
+                    typ: Some(Type::try_from(&status).unwrap()),
+                }],
+                value: vec![NameValuePair {
+                    name: "status".to_string(),
+                    value: Some(golem_wasm_rpc::protobuf::TypeAnnotatedValue {
+                        type_annotated_value: Some(status),
+                    }),
+                }],
+            }))
+        }
+
+        assert!(CachedResponse::is_cacheable(&RibResult::Unit));
+        assert!(CachedResponse::is_cacheable(&result("cart")));
+        assert!(CachedResponse::is_cacheable(&with_status(200)));
+        assert!(!CachedResponse::is_cacheable(&with_status(404)));
+        assert!(!CachedResponse::is_cacheable(&with_status(500)));
+    }
+
+    #[test]
+    fn test_etag_matching() {
+        let cached = CachedResponse::from_rib_result(result("cart"));
+
+        assert_eq!(
+            cached.etag,
+            CachedResponse::from_rib_result(result("cart")).etag
+        );
+        assert!(cached.matches(&cached.etag));
+        assert!(cached.matches(&format!("\"other\", W/{}", cached.etag)));
+        assert!(cached.matches("*"));
+        assert!(!cached.matches("\"other\""));
+    }
+}
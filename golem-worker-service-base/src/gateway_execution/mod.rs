@@ -2,14 +2,19 @@ use golem_common::model::{ComponentId, IdempotencyKey};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
 pub mod api_definition_lookup;
+pub mod api_definition_tester;
 pub mod file_server_binding_handler;
 pub mod gateway_binding_executor;
 pub mod gateway_binding_resolver;
+pub mod gateway_rate_limiter;
+pub mod gateway_response_cache;
 mod gateway_worker_request_executor;
 mod http_content_type_mapper;
 pub mod rib_input_value_resolver;
+pub mod rib_key_evaluator;
 pub mod router;
 pub mod to_response;
+pub mod worker_stream_binding_handler;
 
 pub use gateway_worker_request_executor::*;
 
@@ -0,0 +1,100 @@
+use crate::gateway_binding::{GatewayRequestDetails, RibInputValueResolver};
+use crate::gateway_rib_compiler::{DefaultRibCompiler, WorkerServiceRibCompiler};
+use rib::{RibByteCode, RibInputTypeInfo};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+// Evaluates the Rib expressions of middlewares that compute a key out of the request
+// (Example: the client key of a rate limit, or the key of a cached response).
+// These expressions can only refer to `request`, and are compiled once per expression.
+pub struct RibKeyEvaluator {
+    compiled_key_exprs: Mutex<HashMap<String, (RibByteCode, RibInputTypeInfo)>>,
+}
+
+impl Default for RibKeyEvaluator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RibKeyEvaluator {
+    pub fn new() -> Self {
+        RibKeyEvaluator {
+            compiled_key_exprs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn evaluate(
+        &self,
+        key_expr: &str,
+        request_details: &GatewayRequestDetails,
+    ) -> Result<String, String> {
+        let cached = self
+            .compiled_key_exprs
+            .lock()
+            .unwrap()
+            .get(key_expr)
+            .cloned();
+
+        let (byte_code, rib_input_type_info) = match cached {
+            Some(compiled) => compiled,
+            None => {
+                let expr = rib::from_string(key_expr).map_err(|err| err.to_string())?;
+
+                let compiled = DefaultRibCompiler::compile(&expr, &[])?;
+
+                let compiled = (compiled.byte_code, compiled.global_input_type_info);
+
+                self.compiled_key_exprs
+                    .lock()
+                    .unwrap()
+                    .insert(key_expr.to_string(), compiled.clone());
+
+                compiled
+            }
+        };
+
+        let rib_input = request_details
+            .resolve_rib_input_value(&rib_input_type_info)
+            .map_err(|err| err.to_string())?;
+
+        let key = rib::interpret_pure(&byte_code, &rib_input)
+            .await?
+            .get_literal()
+            .ok_or("Key is not a Rib expression that resolves to a literal".to_string())?
+            .as_string();
+
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use crate::gateway_binding::HttpRequestDetails;
+    use serde_json::Value;
+
+    #[test]
+    async fn test_evaluate_key_from_request_path() {
+        let mut http_request_details = HttpRequestDetails::empty();
+        http_request_details
+            .request_path_values
+            .0
+            .push("user".to_string(), Value::String("jon".to_string()));
+
+        let request_details = GatewayRequestDetails::Http(http_request_details);
+
+        let evaluator = RibKeyEvaluator::new();
+
+        let key = evaluator
+            .evaluate("let user: str = request.path.user; user", &request_details)
+            .await;
+
+        let invalid_key = evaluator.evaluate("foo(", &request_details).await;
+
+        assert_eq!(key, Ok("jon".to_string()));
+        assert!(invalid_key.is_err());
+    }
+}
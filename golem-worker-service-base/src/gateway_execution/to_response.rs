@@ -3,13 +3,23 @@ use crate::gateway_binding::{GatewayRequestDetails, RibInputTypeMismatch};
 use crate::gateway_execution::file_server_binding_handler::{
     FileServerBindingError, FileServerBindingResult,
 };
+use crate::gateway_execution::gateway_rate_limiter::RateLimitError;
+use crate::gateway_execution::gateway_response_cache::{CachedResponse, ResponseCacheError};
+use crate::gateway_execution::worker_stream_binding_handler::{
+    WorkerStreamBindingError, WorkerStreamBindingResult,
+};
 use crate::gateway_middleware::{Cors as CorsPreflight, Middlewares};
 use crate::gateway_rib_interpreter::EvaluationError;
+use crate::service::worker::proxy_worker_connection_with_messages;
+use futures::StreamExt;
+use golem_common::model::WorkerEvent;
 use http::header::*;
 use http::StatusCode;
+use poem::web::sse::{Event, SSE};
 use poem::Body;
 use poem::IntoResponse;
 use rib::RibResult;
+use std::time::Duration;
 
 pub trait ToResponse<A> {
     fn to_response(self, request_details: &GatewayRequestDetails, middlewares: &Middlewares) -> A;
@@ -18,7 +28,7 @@ pub trait ToResponse<A> {
 impl ToResponse<poem::Response> for FileServerBindingResult {
     fn to_response(
         self,
-        _request_details: &GatewayRequestDetails,
+        request_details: &GatewayRequestDetails,
         middlewares: &Middlewares,
     ) -> poem::Response {
         let mut response = match self {
@@ -37,7 +47,88 @@ impl ToResponse<poem::Response> for FileServerBindingResult {
             }
         };
 
-        middlewares.transform_http_response(&mut response);
+        middlewares.transform_http_response(request_details, &mut response);
+        response
+    }
+}
+
+const STREAM_PING_INTERVAL: Duration = Duration::from_secs(30);
+const STREAM_PING_TIMEOUT: Duration = Duration::from_secs(15);
+
+// A stream binding upgrades the request to a WebSocket if the client asked for it,
+// and otherwise falls back to server-sent events if the client accepts `text/event-stream`.
+// Server-sent events are one-directional, and hence only forward the events of the worker.
+impl ToResponse<poem::Response> for WorkerStreamBindingResult {
+    fn to_response(
+        self,
+        request_details: &GatewayRequestDetails,
+        middlewares: &Middlewares,
+    ) -> poem::Response {
+        let mut response = match self {
+            Ok(success) => {
+                let (websocket, accept) = match request_details {
+                    GatewayRequestDetails::Http(http) => (
+                        http.websocket_upgrade.as_ref().and_then(|ws| ws.take()),
+                        http.get_accept_content_type_header(),
+                    ),
+                };
+
+                match websocket {
+                    Some(websocket) => {
+                        let worker_id = success.worker_id;
+                        let events = success.events;
+                        let message_handler = success.message_handler;
+
+                        websocket
+                            .on_upgrade(move |socket| {
+                                tokio::spawn(async move {
+                                    let (sink, stream) = socket.split();
+                                    let _ = proxy_worker_connection_with_messages(
+                                        worker_id,
+                                        events,
+                                        sink,
+                                        stream,
+                                        STREAM_PING_INTERVAL,
+                                        STREAM_PING_TIMEOUT,
+                                        move |message| {
+                                            let message_handler = message_handler.clone();
+                                            Box::pin(async move {
+                                                message_handler.reply(message).await
+                                            })
+                                        },
+                                    )
+                                    .await;
+                                })
+                            })
+                            .into_response()
+                    }
+                    None if accept.is_some_and(|accept| accept.contains("text/event-stream")) => {
+                        let events = success.events.filter_map(|event| async move {
+                            let event: WorkerEvent = event.ok()?.try_into().ok()?;
+                            serde_json::to_string(&event).ok().map(Event::message)
+                        });
+
+                        SSE::new(events)
+                            .keep_alive(STREAM_PING_INTERVAL)
+                            .into_response()
+                    }
+                    None => poem::Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from_string(
+                            "Stream binding requires a WebSocket upgrade or an Accept: text/event-stream header"
+                                .to_string(),
+                        )),
+                }
+            }
+            Err(WorkerStreamBindingError::InternalError(e)) => poem::Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(Body::from_string(format!("Error {}", e).to_string())),
+            Err(WorkerStreamBindingError::WorkerServiceError(inner)) => {
+                WorkerApiBaseError::from(inner).into_response()
+            }
+        };
+
+        middlewares.transform_http_response(request_details, &mut response);
         response
     }
 }
@@ -111,14 +202,93 @@ impl ToResponse<poem::Response> for RibResult {
 impl ToResponse<poem::Response> for RibInputTypeMismatch {
     fn to_response(
         self,
-        _request_details: &GatewayRequestDetails,
+        request_details: &GatewayRequestDetails,
         middlewares: &Middlewares,
     ) -> poem::Response {
         let mut response = poem::Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from_string(format!("Error {}", self.0).to_string()));
 
-        middlewares.transform_http_response(&mut response);
+        middlewares.transform_http_response(request_details, &mut response);
+        response
+    }
+}
+
+impl ToResponse<poem::Response> for RateLimitError {
+    fn to_response(
+        self,
+        request_details: &GatewayRequestDetails,
+        middlewares: &Middlewares,
+    ) -> poem::Response {
+        let mut response = match &self {
+            RateLimitError::Exceeded { retry_after } => {
+                // Retry-After is in whole seconds, rounded up to not have clients retry too early
+                let retry_after_seconds = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+
+                poem::Response::builder()
+                    .status(StatusCode::TOO_MANY_REQUESTS)
+                    .header(RETRY_AFTER, retry_after_seconds.to_string())
+                    .body(Body::from_string(self.to_string()))
+            }
+            RateLimitError::InvalidKey(_) => poem::Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .body(Body::from_string(format!("Error {}", self))),
+        };
+
+        middlewares.transform_http_response(request_details, &mut response);
+        response
+    }
+}
+
+// A response of a worker binding with a response cache, whether it was cached already or not.
+// Requests having an `If-None-Match` header matching the ETag of the response get a 304 without a body.
+impl ToResponse<poem::Response> for CachedResponse {
+    fn to_response(
+        self,
+        request_details: &GatewayRequestDetails,
+        middlewares: &Middlewares,
+    ) -> poem::Response {
+        let if_none_match = match request_details {
+            GatewayRequestDetails::Http(http) => http.get_header_value(IF_NONE_MATCH.as_str()),
+        };
+
+        let mut response = match if_none_match {
+            Some(if_none_match) if self.matches(&if_none_match) => {
+                let mut response = poem::Response::builder()
+                    .status(StatusCode::NOT_MODIFIED)
+                    .finish();
+                middlewares.transform_http_response(request_details, &mut response);
+                response
+            }
+            _ => self
+                .to_rib_result()
+                .to_response(request_details, middlewares),
+        };
+
+        if let Ok(etag) = self.etag.parse() {
+            response.headers_mut().insert(ETAG, etag);
+        }
+
+        response
+    }
+}
+
+impl ToResponse<poem::Response> for ResponseCacheError {
+    fn to_response(
+        self,
+        request_details: &GatewayRequestDetails,
+        middlewares: &Middlewares,
+    ) -> poem::Response {
+        let status = match &self {
+            ResponseCacheError::InvalidKey(_) => StatusCode::BAD_REQUEST,
+            ResponseCacheError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        let mut response = poem::Response::builder()
+            .status(status)
+            .body(Body::from_string(format!("Error {}", self)));
+
+        middlewares.transform_http_response(request_details, &mut response);
         response
     }
 }
@@ -126,14 +296,14 @@ impl ToResponse<poem::Response> for RibInputTypeMismatch {
 impl ToResponse<poem::Response> for EvaluationError {
     fn to_response(
         self,
-        _request_details: &GatewayRequestDetails,
+        request_details: &GatewayRequestDetails,
         middlewares: &Middlewares,
     ) -> poem::Response {
         let mut response = poem::Response::builder()
             .status(StatusCode::INTERNAL_SERVER_ERROR)
             .body(Body::from_string(format!("Error {}", self).to_string()));
 
-        middlewares.transform_http_response(&mut response);
+        middlewares.transform_http_response(request_details, &mut response);
 
         response
     }
@@ -142,14 +312,14 @@ impl ToResponse<poem::Response> for EvaluationError {
 impl ToResponse<poem::Response> for String {
     fn to_response(
         self,
-        _request_details: &GatewayRequestDetails,
+        request_details: &GatewayRequestDetails,
         middlewares: &Middlewares,
     ) -> poem::Response {
         let mut response = poem::Response::builder()
             .status(StatusCode::INTERNAL_SERVER_ERROR)
             .body(Body::from_string(self.to_string()));
 
-        middlewares.transform_http_response(&mut response);
+        middlewares.transform_http_response(request_details, &mut response);
         response
     }
 }
@@ -252,7 +422,7 @@ mod internal {
                 }
             };
 
-            middleware.transform_http_response(&mut response);
+            middleware.transform_http_response(request_details, &mut response);
             response
         }
     }
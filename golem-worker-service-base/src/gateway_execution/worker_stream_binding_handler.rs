@@ -0,0 +1,176 @@
+use crate::empty_worker_metadata;
+use crate::gateway_binding::{
+    GatewayRequestDetails, ResponseMappingCompiled, RibInputValueResolver, WorkerDetail,
+};
+use crate::gateway_rib_interpreter::WorkerServiceRibInterpreter;
+use crate::service::worker::{WorkerService, WorkerServiceError, WorkerStream};
+use async_trait::async_trait;
+use golem_api_grpc::proto::golem::worker::LogEvent;
+use golem_common::model::{IdempotencyKey, WorkerId};
+use golem_service_base::auth::EmptyAuthCtx;
+use golem_service_base::model::validate_worker_name;
+use golem_wasm_rpc::json::TypeAnnotatedValueJsonExtensions;
+use rib::RibResult;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+
+// Connects a stream binding to its worker.
+// The events of the worker (stdout, stderr, logs) are forwarded to the client,
+// and the messages sent by the client (WebSocket only) are handled by the `StreamMessageHandler`.
+#[async_trait]
+pub trait WorkerStreamBindingHandler<Namespace> {
+    async fn connect_worker_stream(
+        &self,
+        namespace: &Namespace,
+        worker_detail: &WorkerDetail,
+        message_handler: StreamMessageHandler,
+    ) -> WorkerStreamBindingResult;
+}
+
+pub type WorkerStreamBindingResult = Result<WorkerStreamBindingSuccess, WorkerStreamBindingError>;
+
+pub struct WorkerStreamBindingSuccess {
+    pub worker_id: WorkerId,
+    pub events: WorkerStream<LogEvent>,
+    pub message_handler: StreamMessageHandler,
+}
+
+pub enum WorkerStreamBindingError {
+    InternalError(String),
+    WorkerServiceError(WorkerServiceError),
+}
+
+// Evaluates the response mapping of a stream binding for each message sent by the client,
+// with the message (parsed as JSON) available as `request.body`.
+// Each message is a separate invocation, and hence gets its own idempotency key.
+#[derive(Clone)]
+pub struct StreamMessageHandler {
+    evaluator: Arc<dyn WorkerServiceRibInterpreter + Sync + Send>,
+    worker_detail: WorkerDetail,
+    response_mapping: ResponseMappingCompiled,
+    request_details: GatewayRequestDetails,
+}
+
+// Reply to a message of the client, serialized the same way as the worker events
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum StreamMessageReply {
+    InvocationResult(Value),
+    InvocationError(String),
+}
+
+impl StreamMessageHandler {
+    pub fn new(
+        evaluator: Arc<dyn WorkerServiceRibInterpreter + Sync + Send>,
+        worker_detail: WorkerDetail,
+        response_mapping: ResponseMappingCompiled,
+        request_details: GatewayRequestDetails,
+    ) -> Self {
+        StreamMessageHandler {
+            evaluator,
+            worker_detail,
+            response_mapping,
+            request_details,
+        }
+    }
+
+    pub async fn handle_message(&self, message: Value) -> Result<Value, String> {
+        let request_details = self.request_details.clone().with_body(message);
+
+        let request_rib_input = request_details
+            .resolve_rib_input_value(&self.response_mapping.rib_input)
+            .map_err(|err| err.to_string())?;
+
+        let worker_rib_input = self
+            .worker_detail
+            .resolve_rib_input_value(&self.response_mapping.rib_input)
+            .map_err(|err| err.to_string())?;
+
+        let result = self
+            .evaluator
+            .evaluate(
+                self.worker_detail.worker_name.as_deref(),
+                &self.worker_detail.component_id.component_id,
+                &Some(IdempotencyKey::fresh()),
+                &self.response_mapping.response_mapping_compiled,
+                &request_rib_input.merge(worker_rib_input),
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        match result {
+            RibResult::Val(value) => Ok(value.to_json_value()),
+            RibResult::Unit => Ok(Value::Null),
+        }
+    }
+
+    // Handles a raw text message of a WebSocket, returning the serialized reply
+    pub async fn reply(&self, message: String) -> String {
+        let reply = match serde_json::from_str(&message) {
+            Ok(message) => match self.handle_message(message).await {
+                Ok(value) => StreamMessageReply::InvocationResult(value),
+                Err(error) => StreamMessageReply::InvocationError(error),
+            },
+            Err(error) => {
+                StreamMessageReply::InvocationError(format!("Invalid JSON message: {}", error))
+            }
+        };
+
+        serde_json::to_string(&reply).unwrap_or_default()
+    }
+}
+
+pub struct DefaultWorkerStreamBindingHandler {
+    worker_service: Arc<dyn WorkerService<EmptyAuthCtx> + Sync + Send>,
+}
+
+impl DefaultWorkerStreamBindingHandler {
+    pub fn new(worker_service: Arc<dyn WorkerService<EmptyAuthCtx> + Sync + Send>) -> Self {
+        DefaultWorkerStreamBindingHandler { worker_service }
+    }
+}
+
+#[async_trait]
+impl<Namespace: Send + Sync + 'static> WorkerStreamBindingHandler<Namespace>
+    for DefaultWorkerStreamBindingHandler
+{
+    async fn connect_worker_stream(
+        &self,
+        _namespace: &Namespace,
+        worker_detail: &WorkerDetail,
+        message_handler: StreamMessageHandler,
+    ) -> WorkerStreamBindingResult {
+        let worker_name =
+            worker_detail
+                .worker_name
+                .clone()
+                .ok_or(WorkerStreamBindingError::InternalError(
+                    "Stream binding requires a worker name".to_string(),
+                ))?;
+
+        validate_worker_name(&worker_name).map_err(|e| {
+            WorkerStreamBindingError::InternalError(format!("Invalid worker name: {}", e))
+        })?;
+
+        let worker_id = WorkerId {
+            component_id: worker_detail.component_id.component_id.clone(),
+            worker_name,
+        };
+
+        let events = self
+            .worker_service
+            .connect(
+                &worker_id,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await
+            .map_err(WorkerStreamBindingError::WorkerServiceError)?;
+
+        Ok(WorkerStreamBindingSuccess {
+            worker_id,
+            events,
+            message_handler,
+        })
+    }
+}
@@ -0,0 +1,449 @@
+use http::header::AUTHORIZATION;
+use http::HeaderMap;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{DecodingKey, Validation};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+// Validates bearer tokens (JWT) against a configured issuer and a JSON Web Key Set.
+// The JWKS is referred to by a URI, which can either be a local file (file://)
+// or an HTTP endpoint (http:// or https://), such as the `jwks_uri` of an OIDC provider.
+// The claims of a validated token are made available to Rib as `request.auth.claims`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct JwtAuthentication {
+    issuer: String,
+    audience: Option<String>,
+    jwks_uri: String,
+}
+
+impl JwtAuthentication {
+    pub fn get_issuer(&self) -> String {
+        self.issuer.clone()
+    }
+
+    pub fn get_audience(&self) -> Option<String> {
+        self.audience.clone()
+    }
+
+    pub fn get_jwks_uri(&self) -> String {
+        self.jwks_uri.clone()
+    }
+
+    pub fn from_parameters(
+        issuer: String,
+        audience: Option<String>,
+        jwks_uri: String,
+    ) -> Result<JwtAuthentication, String> {
+        let jwt_authentication = JwtAuthentication {
+            issuer,
+            audience,
+            jwks_uri,
+        };
+
+        jwt_authentication.validate()?;
+
+        Ok(jwt_authentication)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.issuer.is_empty() {
+            return Err("issuer cannot be empty.".to_string());
+        }
+
+        let jwks_uri = Url::parse(&self.jwks_uri)
+            .map_err(|err| format!("Invalid jwksUri {}. {}", self.jwks_uri, err))?;
+
+        match jwks_uri.scheme() {
+            "file" | "http" | "https" => Ok(()),
+            scheme => Err(format!(
+                "Unsupported jwksUri scheme {}. It must be one of file, http or https",
+                scheme
+            )),
+        }
+    }
+
+    // Validates the bearer token in the `Authorization` header and returns its claims
+    pub async fn authenticate(&self, headers: &HeaderMap) -> Result<Value, String> {
+        let token = internal::get_bearer_token(headers)?;
+
+        let header = jsonwebtoken::decode_header(token)
+            .map_err(|err| format!("Invalid bearer token. {}", err))?;
+
+        let jwk = internal::get_jwk(&self.jwks_uri, header.kid.as_deref()).await?;
+
+        let decoding_key = DecodingKey::from_jwk(&jwk)
+            .map_err(|err| format!("Invalid key in JWKS {}. {}", self.jwks_uri, err))?;
+
+        // The accepted algorithms come from the key, never from the token itself
+        let algorithms = internal::get_algorithms(&jwk);
+
+        if !algorithms.contains(&header.alg) {
+            return Err(format!(
+                "Invalid bearer token. Algorithm {:?} is not allowed for the key in JWKS {}",
+                header.alg, self.jwks_uri
+            ));
+        }
+
+        let mut validation = Validation::new(header.alg);
+        validation.algorithms = algorithms;
+        validation.set_issuer(&[self.issuer.as_str()]);
+
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience.as_str()]),
+            None => validation.validate_aud = false,
+        }
+
+        let token_data = jsonwebtoken::decode::<Value>(token, &decoding_key, &validation)
+            .map_err(|err| format!("Invalid bearer token. {}", err))?;
+
+        Ok(token_data.claims)
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::apidefinition::JwtAuthentication> for JwtAuthentication {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::apidefinition::JwtAuthentication,
+    ) -> Result<Self, Self::Error> {
+        Ok(JwtAuthentication {
+            issuer: value.issuer.ok_or("Missing issuer")?,
+            audience: value.audience,
+            jwks_uri: value.jwks_uri.ok_or("Missing jwks uri")?,
+        })
+    }
+}
+
+impl From<JwtAuthentication> for golem_api_grpc::proto::golem::apidefinition::JwtAuthentication {
+    fn from(value: JwtAuthentication) -> Self {
+        golem_api_grpc::proto::golem::apidefinition::JwtAuthentication {
+            issuer: Some(value.issuer),
+            audience: value.audience,
+            jwks_uri: Some(value.jwks_uri),
+        }
+    }
+}
+
+mod internal {
+    use super::*;
+    use jsonwebtoken::jwk::{AlgorithmParameters, EllipticCurve, Jwk, KeyAlgorithm};
+    use jsonwebtoken::Algorithm;
+    use lazy_static::lazy_static;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tokio::sync::Mutex;
+
+    // Key sets are shared by all routes referring to the same JWKS uri.
+    // A cached key set is refreshed when it expires, or when a token refers to a key id
+    // that is not part of it (key rotation). Refreshes of the same uri are serialized and
+    // happen at most once per JWKS_MIN_REFRESH_INTERVAL, so tokens with unknown key ids
+    // fail fast instead of hitting the JWKS endpoint on every request.
+    const JWKS_CACHE_TTL: Duration = Duration::from_secs(300);
+    const JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+    lazy_static! {
+        static ref JWKS_CACHE: std::sync::Mutex<HashMap<String, Arc<Mutex<CachedJwks>>>> =
+            std::sync::Mutex::new(HashMap::new());
+    }
+
+    #[derive(Default)]
+    struct CachedJwks {
+        jwks: Option<(Instant, JwkSet)>,
+        last_refresh: Option<Instant>,
+    }
+
+    impl CachedJwks {
+        fn needs_refresh(&self, kid: Option<&str>) -> bool {
+            match &self.jwks {
+                Some((fetched_at, jwks)) => {
+                    fetched_at.elapsed() >= JWKS_CACHE_TTL || find_jwk(jwks, kid).is_none()
+                }
+                None => true,
+            }
+        }
+
+        fn can_refresh(&self) -> bool {
+            self.last_refresh.map_or(true, |last_refresh| {
+                last_refresh.elapsed() >= JWKS_MIN_REFRESH_INTERVAL
+            })
+        }
+    }
+
+    pub(crate) fn get_bearer_token(headers: &HeaderMap) -> Result<&str, String> {
+        let authorization = headers
+            .get(AUTHORIZATION)
+            .ok_or("Missing Authorization header")?
+            .to_str()
+            .map_err(|_| "Invalid Authorization header")?;
+
+        match authorization.split_once(' ') {
+            Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") => {
+                let token = token.trim();
+                if token.is_empty() {
+                    Err("Missing bearer token".to_string())
+                } else {
+                    Ok(token)
+                }
+            }
+            _ => Err("Authorization header is not a bearer token".to_string()),
+        }
+    }
+
+    pub(crate) async fn get_jwk(jwks_uri: &str, kid: Option<&str>) -> Result<Jwk, String> {
+        let entry = JWKS_CACHE
+            .lock()
+            .unwrap()
+            .entry(jwks_uri.to_string())
+            .or_default()
+            .clone();
+
+        let mut cached = entry.lock().await;
+
+        if cached.needs_refresh(kid) && cached.can_refresh() {
+            cached.last_refresh = Some(Instant::now());
+            let jwks = fetch_jwks(jwks_uri).await?;
+            cached.jwks = Some((Instant::now(), jwks));
+        }
+
+        let jwks = match &cached.jwks {
+            Some((_, jwks)) => jwks,
+            None => return Err(format!("JWKS {} is not available", jwks_uri)),
+        };
+
+        find_jwk(jwks, kid).ok_or(match kid {
+            Some(kid) => format!("No key with kid {} in JWKS {}", kid, jwks_uri),
+            None => format!(
+                "Bearer token has no kid, and JWKS {} does not contain exactly one key",
+                jwks_uri
+            ),
+        })
+    }
+
+    // The algorithms a key can verify. If the key declares its algorithm, only that one is
+    // accepted, otherwise the signature algorithms matching the key type.
+    pub(crate) fn get_algorithms(jwk: &Jwk) -> Vec<Algorithm> {
+        match &jwk.common.key_algorithm {
+            Some(key_algorithm) => match key_algorithm {
+                KeyAlgorithm::HS256 => vec![Algorithm::HS256],
+                KeyAlgorithm::HS384 => vec![Algorithm::HS384],
+                KeyAlgorithm::HS512 => vec![Algorithm::HS512],
+                KeyAlgorithm::ES256 => vec![Algorithm::ES256],
+                KeyAlgorithm::ES384 => vec![Algorithm::ES384],
+                KeyAlgorithm::RS256 => vec![Algorithm::RS256],
+                KeyAlgorithm::RS384 => vec![Algorithm::RS384],
+                KeyAlgorithm::RS512 => vec![Algorithm::RS512],
+                KeyAlgorithm::PS256 => vec![Algorithm::PS256],
+                KeyAlgorithm::PS384 => vec![Algorithm::PS384],
+                KeyAlgorithm::PS512 => vec![Algorithm::PS512],
+                KeyAlgorithm::EdDSA => vec![Algorithm::EdDSA],
+                // Encryption algorithms cannot be used to verify signatures
+                _ => vec![],
+            },
+            None => match &jwk.algorithm {
+                AlgorithmParameters::OctetKey(_) => {
+                    vec![Algorithm::HS256, Algorithm::HS384, Algorithm::HS512]
+                }
+                AlgorithmParameters::RSA(_) => vec![
+                    Algorithm::RS256,
+                    Algorithm::RS384,
+                    Algorithm::RS512,
+                    Algorithm::PS256,
+                    Algorithm::PS384,
+                    Algorithm::PS512,
+                ],
+                AlgorithmParameters::EllipticCurve(params) => match params.curve {
+                    EllipticCurve::P256 => vec![Algorithm::ES256],
+                    EllipticCurve::P384 => vec![Algorithm::ES384],
+                    _ => vec![],
+                },
+                AlgorithmParameters::OctetKeyPair(params) => match params.curve {
+                    EllipticCurve::Ed25519 => vec![Algorithm::EdDSA],
+                    _ => vec![],
+                },
+            },
+        }
+    }
+
+    fn find_jwk(jwks: &JwkSet, kid: Option<&str>) -> Option<Jwk> {
+        match kid {
+            Some(kid) => jwks.find(kid).cloned(),
+            None if jwks.keys.len() == 1 => jwks.keys.first().cloned(),
+            None => None,
+        }
+    }
+
+    async fn fetch_jwks(jwks_uri: &str) -> Result<JwkSet, String> {
+        let url = Url::parse(jwks_uri).map_err(|err| format!("Invalid jwksUri. {}", err))?;
+
+        let content = match url.scheme() {
+            "file" => {
+                let path = url
+                    .to_file_path()
+                    .map_err(|_| format!("Invalid JWKS file path {}", jwks_uri))?;
+
+                tokio::fs::read_to_string(path)
+                    .await
+                    .map_err(|err| format!("Failed to read JWKS {}. {}", jwks_uri, err))?
+            }
+            _ => reqwest::get(url)
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(|err| format!("Failed to fetch JWKS {}. {}", jwks_uri, err))?
+                .text()
+                .await
+                .map_err(|err| format!("Failed to fetch JWKS {}. {}", jwks_uri, err))?,
+        };
+
+        serde_json::from_str(&content).map_err(|err| format!("Invalid JWKS {}. {}", jwks_uri, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use jsonwebtoken::{EncodingKey, Header};
+    use serde_json::json;
+
+    // base64url("secret")
+    const SECRET: &str = "c2VjcmV0";
+
+    fn jwt_authentication(audience: Option<&str>) -> JwtAuthentication {
+        let path = std::env::temp_dir().join(format!("jwks-{}.json", uuid::Uuid::new_v4()));
+        let jwks = json!({
+            "keys": [{ "kty": "oct", "kid": "test-key", "alg": "HS256", "k": SECRET }]
+        });
+        std::fs::write(&path, jwks.to_string()).unwrap();
+
+        JwtAuthentication::from_parameters(
+            "https://issuer.golem.cloud".to_string(),
+            audience.map(|a| a.to_string()),
+            Url::from_file_path(&path).unwrap().to_string(),
+        )
+        .unwrap()
+    }
+
+    fn bearer(claims: &Value) -> HeaderMap {
+        bearer_with(claims, jsonwebtoken::Algorithm::HS256, "test-key")
+    }
+
+    fn bearer_with(claims: &Value, alg: jsonwebtoken::Algorithm, kid: &str) -> HeaderMap {
+        let mut header = Header::new(alg);
+        header.kid = Some(kid.to_string());
+
+        let token =
+            jsonwebtoken::encode(&header, claims, &EncodingKey::from_secret(b"secret")).unwrap();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, format!("Bearer {}", token).parse().unwrap());
+        headers
+    }
+
+    fn claims(issuer: &str) -> Value {
+        json!({
+            "sub": "user-1",
+            "iss": issuer,
+            "aud": "shopping-cart",
+            "exp": chrono::Utc::now().timestamp() + 600
+        })
+    }
+
+    #[test]
+    async fn test_authenticate_valid_token() {
+        let jwt_authentication = jwt_authentication(Some("shopping-cart"));
+
+        let result = jwt_authentication
+            .authenticate(&bearer(&claims("https://issuer.golem.cloud")))
+            .await
+            .unwrap();
+
+        assert_eq!(result.get("sub"), Some(&json!("user-1")));
+    }
+
+    #[test]
+    async fn test_authenticate_invalid_issuer() {
+        let jwt_authentication = jwt_authentication(None);
+
+        let result = jwt_authentication
+            .authenticate(&bearer(&claims("https://other-issuer.com")))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    async fn test_authenticate_algorithm_mismatch() {
+        let jwt_authentication = jwt_authentication(None);
+
+        let result = jwt_authentication
+            .authenticate(&bearer_with(
+                &claims("https://issuer.golem.cloud"),
+                jsonwebtoken::Algorithm::HS384,
+                "test-key",
+            ))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    async fn test_authenticate_unknown_kid_does_not_refetch() {
+        let jwt_authentication = jwt_authentication(None);
+        let claims = claims("https://issuer.golem.cloud");
+
+        assert!(jwt_authentication
+            .authenticate(&bearer(&claims))
+            .await
+            .is_ok());
+
+        // A key added right after the key set was fetched is not picked up until the
+        // minimum refresh interval has passed
+        let path = Url::parse(&jwt_authentication.get_jwks_uri())
+            .unwrap()
+            .to_file_path()
+            .unwrap();
+        let jwks = json!({
+            "keys": [
+                { "kty": "oct", "kid": "test-key", "alg": "HS256", "k": SECRET },
+                { "kty": "oct", "kid": "new-key", "alg": "HS256", "k": SECRET }
+            ]
+        });
+        std::fs::write(&path, jwks.to_string()).unwrap();
+
+        let result = jwt_authentication
+            .authenticate(&bearer_with(
+                &claims,
+                jsonwebtoken::Algorithm::HS256,
+                "new-key",
+            ))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    async fn test_authenticate_missing_token() {
+        let jwt_authentication = jwt_authentication(None);
+
+        let result = jwt_authentication.authenticate(&HeaderMap::new()).await;
+
+        assert_eq!(result, Err("Missing Authorization header".to_string()));
+    }
+
+    #[test]
+    fn test_unsupported_jwks_uri() {
+        let result = JwtAuthentication::from_parameters(
+            "https://issuer.golem.cloud".to_string(),
+            None,
+            "ftp://jwks.json".to_string(),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -1,4 +1,8 @@
+use crate::gateway_binding::GatewayRequestDetails;
+use crate::gateway_middleware::http::authentication::JwtAuthentication;
 use crate::gateway_middleware::http::cors::Cors;
+use crate::gateway_middleware::http::rate_limit::RateLimit;
+use crate::gateway_middleware::http::transformation::Transformation;
 use http::header::{
     ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS,
 };
@@ -6,6 +10,9 @@ use http::header::{
 #[derive(Debug, Clone, PartialEq)]
 pub enum HttpMiddleware {
     Cors(Cors),
+    JwtAuthentication(JwtAuthentication),
+    RateLimit(RateLimit),
+    Transformation(Transformation),
 }
 
 impl HttpMiddleware {
@@ -13,12 +20,37 @@ impl HttpMiddleware {
         HttpMiddleware::Cors(cors)
     }
 
-    pub fn transform_response(&self, response: &mut poem::Response) {
+    pub fn jwt_authentication(jwt_authentication: JwtAuthentication) -> Self {
+        HttpMiddleware::JwtAuthentication(jwt_authentication)
+    }
+
+    pub fn rate_limit(rate_limit: RateLimit) -> Self {
+        HttpMiddleware::RateLimit(rate_limit)
+    }
+
+    pub fn transformation(transformation: Transformation) -> Self {
+        HttpMiddleware::Transformation(transformation)
+    }
+
+    pub fn transform_response(
+        &self,
+        request_details: &GatewayRequestDetails,
+        response: &mut poem::Response,
+    ) {
         match self {
             // if CORS is applied as a middleware, we need to return a response with specific CORS headers
             HttpMiddleware::Cors(cors) => {
                 Self::apply_cors(response, cors);
             }
+            // authentication happens before the worker binding is resolved,
+            // and has nothing to add to a successful response
+            HttpMiddleware::JwtAuthentication(_) => {}
+            // rate limits are checked before the worker is invoked
+            HttpMiddleware::RateLimit(_) => {}
+            // request headers and path are transformed before the worker binding is resolved
+            HttpMiddleware::Transformation(transformation) => {
+                transformation.transform_response(request_details, response);
+            }
         }
     }
 
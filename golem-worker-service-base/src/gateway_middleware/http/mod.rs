@@ -1,5 +1,11 @@
+pub use authentication::*;
 pub use cors::*;
 pub use http_middleware::*;
+pub use rate_limit::*;
+pub use transformation::*;
 
+mod authentication;
 mod cors;
 mod http_middleware;
+mod rate_limit;
+mod transformation;
@@ -0,0 +1,115 @@
+use crate::gateway_rib_compiler::{DefaultRibCompiler, WorkerServiceRibCompiler};
+use poem_openapi::Object;
+use rib::Expr;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Token bucket rate limit of a route.
+// Every client gets a bucket of `requests` tokens, refilled at the rate of `requests` per `period_seconds`,
+// and each request to the route takes a token out of it.
+// Clients are identified by their IP address, unless the value of a header (`key_header`)
+// or a Rib expression over the request (`key_expr`, Example: `request.auth.claims.sub`) is configured as the key.
+// Requests without the configured header are identified by their IP address.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RateLimit {
+    requests: u64,
+    period_seconds: u64,
+    key_header: Option<String>,
+    key_expr: Option<String>,
+}
+
+impl RateLimit {
+    pub fn get_requests(&self) -> u64 {
+        self.requests
+    }
+
+    pub fn get_period(&self) -> Duration {
+        Duration::from_secs(self.period_seconds)
+    }
+
+    pub fn get_key_header(&self) -> Option<String> {
+        self.key_header.clone()
+    }
+
+    pub fn get_key_expr(&self) -> Option<String> {
+        self.key_expr.clone()
+    }
+
+    pub fn from_parameters(
+        requests: u64,
+        period_seconds: u64,
+        key_header: Option<String>,
+        key_expr: Option<String>,
+    ) -> Result<RateLimit, String> {
+        let rate_limit = RateLimit {
+            requests,
+            period_seconds,
+            key_header,
+            key_expr,
+        };
+
+        rate_limit.validate()?;
+
+        Ok(rate_limit)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.requests == 0 {
+            return Err("requests of a rate limit must be greater than 0.".to_string());
+        }
+
+        if self.period_seconds == 0 {
+            return Err("periodSeconds of a rate limit must be greater than 0.".to_string());
+        }
+
+        match (&self.key_header, &self.key_expr) {
+            (Some(_), Some(_)) => Err(
+                "Only one of keyHeader and keyExpr can be specified for a rate limit.".to_string(),
+            ),
+            (_, Some(key_expr)) => {
+                let expr = rib::from_string(key_expr)
+                    .map_err(|err| format!("Invalid rate limit key expression. {}", err))?;
+
+                DefaultRibCompiler::compile(&expr, &[])
+                    .map(|_| ())
+                    .map_err(|err| format!("Rib compilation for rate limit key. {}", err))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn key_expr(&self) -> Result<Option<Expr>, String> {
+        self.key_expr
+            .as_ref()
+            .map(|key_expr| rib::from_string(key_expr).map_err(|err| err.to_string()))
+            .transpose()
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::apidefinition::RateLimit> for RateLimit {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::apidefinition::RateLimit,
+    ) -> Result<Self, Self::Error> {
+        Ok(RateLimit {
+            requests: value.requests.ok_or("Missing requests")?,
+            period_seconds: value.period_seconds.ok_or("Missing period seconds")?,
+            key_header: value.key_header,
+            key_expr: value.key_expr,
+        })
+    }
+}
+
+impl From<RateLimit> for golem_api_grpc::proto::golem::apidefinition::RateLimit {
+    fn from(value: RateLimit) -> Self {
+        golem_api_grpc::proto::golem::apidefinition::RateLimit {
+            requests: Some(value.requests),
+            period_seconds: Some(value.period_seconds),
+            key_header: value.key_header,
+            key_expr: value.key_expr,
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use crate::gateway_api_definition::http::{AllPathPatterns, PathPattern};
+use crate::gateway_binding::GatewayRequestDetails;
+use http::{HeaderMap, HeaderName, HeaderValue};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+// Declarative transformation of the requests and responses of a route,
+// such that they don't have to be encoded in the Rib expressions of the binding.
+// Request headers are added and removed before the worker binding runs (and hence are visible to Rib as `request.headers`),
+// and the request can be rewritten to a different path (Example: `/v2/users/{user-id}`), in which case
+// it is resolved once more as if it was sent to the rewritten path.
+// A correlation ID header is generated if it is missing in the request, and is returned in the response.
+// Response headers (Example: security headers) are added and removed after the response is formed.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct Transformation {
+    #[serde(default)]
+    #[oai(default)]
+    add_request_headers: BTreeMap<String, String>,
+    #[serde(default)]
+    #[oai(default)]
+    remove_request_headers: Vec<String>,
+    rewrite_path: Option<String>,
+    correlation_id_header: Option<String>,
+    #[serde(default)]
+    #[oai(default)]
+    add_response_headers: BTreeMap<String, String>,
+    #[serde(default)]
+    #[oai(default)]
+    remove_response_headers: Vec<String>,
+}
+
+impl Transformation {
+    pub fn get_add_request_headers(&self) -> BTreeMap<String, String> {
+        self.add_request_headers.clone()
+    }
+
+    pub fn get_remove_request_headers(&self) -> Vec<String> {
+        self.remove_request_headers.clone()
+    }
+
+    pub fn get_rewrite_path(&self) -> Option<AllPathPatterns> {
+        // validated while registering the middleware
+        self.rewrite_path
+            .as_ref()
+            .and_then(|rewrite_path| AllPathPatterns::parse(rewrite_path).ok())
+    }
+
+    pub fn get_correlation_id_header(&self) -> Option<String> {
+        self.correlation_id_header.clone()
+    }
+
+    pub fn get_add_response_headers(&self) -> BTreeMap<String, String> {
+        self.add_response_headers.clone()
+    }
+
+    pub fn get_remove_response_headers(&self) -> Vec<String> {
+        self.remove_response_headers.clone()
+    }
+
+    pub fn from_parameters(
+        add_request_headers: BTreeMap<String, String>,
+        remove_request_headers: Vec<String>,
+        rewrite_path: Option<String>,
+        correlation_id_header: Option<String>,
+        add_response_headers: BTreeMap<String, String>,
+        remove_response_headers: Vec<String>,
+    ) -> Result<Transformation, String> {
+        let transformation = Transformation {
+            add_request_headers,
+            remove_request_headers,
+            rewrite_path,
+            correlation_id_header,
+            add_response_headers,
+            remove_response_headers,
+        };
+
+        transformation.validate()?;
+
+        Ok(transformation)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in self
+            .add_request_headers
+            .iter()
+            .chain(self.add_response_headers.iter())
+        {
+            internal::header_name(name)?;
+            HeaderValue::from_str(value)
+                .map_err(|_| format!("Invalid value of header {} in transformation", name))?;
+        }
+
+        for name in self
+            .remove_request_headers
+            .iter()
+            .chain(self.remove_response_headers.iter())
+            .chain(self.correlation_id_header.iter())
+        {
+            internal::header_name(name)?;
+        }
+
+        if let Some(rewrite_path) = &self.rewrite_path {
+            let path = AllPathPatterns::parse(rewrite_path)
+                .map_err(|err| format!("Invalid rewrite path {}. {}", rewrite_path, err))?;
+
+            if !path.query_params.is_empty() {
+                return Err(format!(
+                    "Rewrite path {} cannot have query parameters, as the query of the request is kept",
+                    rewrite_path
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    // The path of the rewritten request, with the variables of the rewrite path
+    // taken from the path variables of the matched route
+    pub fn rewrite_path(
+        &self,
+        path_variables: &HashMap<String, &str>,
+    ) -> Option<Result<String, String>> {
+        let rewrite_path = self.get_rewrite_path()?;
+
+        let segments = rewrite_path
+            .path_patterns
+            .iter()
+            .map(|pattern| match pattern {
+                PathPattern::Literal(literal) => Ok(literal.0.clone()),
+                PathPattern::Var(var) => path_variables
+                    .get(&var.key_name)
+                    .map(|value| value.to_string())
+                    .ok_or(format!(
+                        "Path variable {} of the rewrite path is not a path variable of the route",
+                        var.key_name
+                    )),
+            })
+            .collect::<Result<Vec<_>, _>>();
+
+        Some(segments.map(|segments| format!("/{}", segments.join("/"))))
+    }
+
+    pub fn transform_request_headers(&self, headers: &mut HeaderMap) {
+        for name in &self.remove_request_headers {
+            headers.remove(name.as_str());
+        }
+
+        for (name, value) in &self.add_request_headers {
+            // hot path, and this unwrap will not fail unless we bypassed it during configuration
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+
+        if let Some(correlation_id_header) = &self.correlation_id_header {
+            if !headers.contains_key(correlation_id_header.as_str()) {
+                headers.insert(
+                    HeaderName::from_bytes(correlation_id_header.as_bytes()).unwrap(),
+                    uuid::Uuid::new_v4().to_string().parse().unwrap(),
+                );
+            }
+        }
+    }
+
+    pub fn transform_response(
+        &self,
+        request_details: &GatewayRequestDetails,
+        response: &mut poem::Response,
+    ) {
+        for name in &self.remove_response_headers {
+            response.headers_mut().remove(name.as_str());
+        }
+
+        for (name, value) in &self.add_response_headers {
+            // hot path, and this unwrap will not fail unless we bypassed it during configuration
+            response.headers_mut().insert(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+
+        if let Some(correlation_id_header) = &self.correlation_id_header {
+            let correlation_id = match request_details {
+                GatewayRequestDetails::Http(http) => http.get_header_value(correlation_id_header),
+            };
+
+            if let Some(correlation_id) = correlation_id.and_then(|id| id.parse().ok()) {
+                response.headers_mut().insert(
+                    HeaderName::from_bytes(correlation_id_header.as_bytes()).unwrap(),
+                    correlation_id,
+                );
+            }
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::apidefinition::Transformation> for Transformation {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::apidefinition::Transformation,
+    ) -> Result<Self, Self::Error> {
+        Ok(Transformation {
+            add_request_headers: value.add_request_headers.into_iter().collect(),
+            remove_request_headers: value.remove_request_headers,
+            rewrite_path: value.rewrite_path,
+            correlation_id_header: value.correlation_id_header,
+            add_response_headers: value.add_response_headers.into_iter().collect(),
+            remove_response_headers: value.remove_response_headers,
+        })
+    }
+}
+
+impl From<Transformation> for golem_api_grpc::proto::golem::apidefinition::Transformation {
+    fn from(value: Transformation) -> Self {
+        golem_api_grpc::proto::golem::apidefinition::Transformation {
+            add_request_headers: value.add_request_headers.into_iter().collect(),
+            remove_request_headers: value.remove_request_headers,
+            rewrite_path: value.rewrite_path,
+            correlation_id_header: value.correlation_id_header,
+            add_response_headers: value.add_response_headers.into_iter().collect(),
+            remove_response_headers: value.remove_response_headers,
+        }
+    }
+}
+
+mod internal {
+    use http::HeaderName;
+
+    pub(crate) fn header_name(name: &str) -> Result<HeaderName, String> {
+        HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| format!("Invalid header name {} in transformation", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use crate::gateway_binding::{HttpRequestDetails, JsonKeyValues, RequestHeaderValues};
+
+    fn transformation() -> Transformation {
+        Transformation::from_parameters(
+            BTreeMap::from([("x-api-version".to_string(), "2".to_string())]),
+            vec!["cookie".to_string()],
+            Some("/v2/users/{user-id}".to_string()),
+            Some("x-correlation-id".to_string()),
+            BTreeMap::from([("x-frame-options".to_string(), "DENY".to_string())]),
+            vec!["server".to_string()],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_invalid_transformation() {
+        let result = Transformation::from_parameters(
+            BTreeMap::from([("invalid header".to_string(), "value".to_string())]),
+            vec![],
+            None,
+            None,
+            BTreeMap::new(),
+            vec![],
+        );
+
+        assert!(result.is_err());
+
+        let result = Transformation::from_parameters(
+            BTreeMap::new(),
+            vec![],
+            Some("/users/{user-id}?{verbose}".to_string()),
+            None,
+            BTreeMap::new(),
+            vec![],
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rewrite_path() {
+        let transformation = transformation();
+
+        let path_variables = HashMap::from([("user-id".to_string(), "foo")]);
+
+        assert_eq!(
+            transformation.rewrite_path(&path_variables),
+            Some(Ok("/v2/users/foo".to_string()))
+        );
+
+        assert!(transformation
+            .rewrite_path(&HashMap::new())
+            .is_some_and(|result| result.is_err()));
+
+        assert_eq!(
+            Transformation::default().rewrite_path(&path_variables),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transform_request_headers() {
+        let transformation = transformation();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("cookie", "session=foo".parse().unwrap());
+        headers.insert("x-correlation-id", "bar".parse().unwrap());
+
+        transformation.transform_request_headers(&mut headers);
+
+        assert_eq!(headers.get("cookie"), None);
+        assert_eq!(headers.get("x-api-version").unwrap(), "2");
+        assert_eq!(headers.get("x-correlation-id").unwrap(), "bar");
+
+        let mut headers = HeaderMap::new();
+
+        transformation.transform_request_headers(&mut headers);
+
+        assert!(headers.contains_key("x-correlation-id"));
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let transformation = transformation();
+
+        let mut request_header_values = JsonKeyValues::default();
+        request_header_values.push(
+            "x-correlation-id".to_string(),
+            serde_json::Value::String("bar".to_string()),
+        );
+
+        let request_details = GatewayRequestDetails::Http(HttpRequestDetails {
+            request_header_values: RequestHeaderValues(request_header_values),
+            ..HttpRequestDetails::empty()
+        });
+
+        let mut response = poem::Response::builder().header("server", "golem").finish();
+
+        transformation.transform_response(&request_details, &mut response);
+
+        assert_eq!(response.headers().get("server"), None);
+        assert_eq!(response.headers().get("x-frame-options").unwrap(), "DENY");
+        assert_eq!(response.headers().get("x-correlation-id").unwrap(), "bar");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::gateway_binding::GatewayRequestDetails;
 pub use http::*;
 mod http;
 
@@ -25,9 +26,13 @@ impl Middlewares {
             .collect()
     }
 
-    pub fn transform_http_response(&self, response: &mut poem::Response) {
+    pub fn transform_http_response(
+        &self,
+        request_details: &GatewayRequestDetails,
+        response: &mut poem::Response,
+    ) {
         for middleware in self.http_middlewares() {
-            middleware.transform_response(response);
+            middleware.transform_response(request_details, response);
         }
     }
 
@@ -38,6 +43,18 @@ impl Middlewares {
     pub fn get_cors(&self) -> Option<Cors> {
         self.0.iter().find_map(|m| m.get_cors())
     }
+
+    pub fn get_jwt_authentication(&self) -> Option<JwtAuthentication> {
+        self.0.iter().find_map(|m| m.get_jwt_authentication())
+    }
+
+    pub fn get_rate_limit(&self) -> Option<RateLimit> {
+        self.0.iter().find_map(|m| m.get_rate_limit())
+    }
+
+    pub fn get_transformation(&self) -> Option<Transformation> {
+        self.0.iter().find_map(|m| m.get_transformation())
+    }
 }
 
 // A middleware will not add, remove or update the input to worker what-so-ever,
@@ -61,6 +78,46 @@ impl Middleware {
     pub fn get_cors(&self) -> Option<Cors> {
         match self {
             Middleware::Http(HttpMiddleware::Cors(cors)) => Some(cors.clone()),
+            Middleware::Http(_) => None,
+        }
+    }
+
+    pub fn jwt_authentication(jwt_authentication: &JwtAuthentication) -> Middleware {
+        Middleware::Http(HttpMiddleware::jwt_authentication(
+            jwt_authentication.clone(),
+        ))
+    }
+
+    pub fn get_jwt_authentication(&self) -> Option<JwtAuthentication> {
+        match self {
+            Middleware::Http(HttpMiddleware::JwtAuthentication(jwt_authentication)) => {
+                Some(jwt_authentication.clone())
+            }
+            Middleware::Http(_) => None,
+        }
+    }
+
+    pub fn rate_limit(rate_limit: &RateLimit) -> Middleware {
+        Middleware::Http(HttpMiddleware::rate_limit(rate_limit.clone()))
+    }
+
+    pub fn get_rate_limit(&self) -> Option<RateLimit> {
+        match self {
+            Middleware::Http(HttpMiddleware::RateLimit(rate_limit)) => Some(rate_limit.clone()),
+            Middleware::Http(_) => None,
+        }
+    }
+
+    pub fn transformation(transformation: &Transformation) -> Middleware {
+        Middleware::Http(HttpMiddleware::transformation(transformation.clone()))
+    }
+
+    pub fn get_transformation(&self) -> Option<Transformation> {
+        match self {
+            Middleware::Http(HttpMiddleware::Transformation(transformation)) => {
+                Some(transformation.clone())
+            }
+            Middleware::Http(_) => None,
         }
     }
 
@@ -80,6 +137,22 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::Middleware> for Middle
             let cors = Cors::try_from(cors)?;
             middlewares.push(Middleware::http(HttpMiddleware::cors(cors)));
         }
+        if let Some(jwt_authentication) = value.jwt_authentication {
+            let jwt_authentication = JwtAuthentication::try_from(jwt_authentication)?;
+            middlewares.push(Middleware::http(HttpMiddleware::jwt_authentication(
+                jwt_authentication,
+            )));
+        }
+        if let Some(rate_limit) = value.rate_limit {
+            let rate_limit = RateLimit::try_from(rate_limit)?;
+            middlewares.push(Middleware::http(HttpMiddleware::rate_limit(rate_limit)));
+        }
+        if let Some(transformation) = value.transformation {
+            let transformation = Transformation::try_from(transformation)?;
+            middlewares.push(Middleware::http(HttpMiddleware::transformation(
+                transformation,
+            )));
+        }
         Ok(Middlewares(middlewares))
     }
 }
@@ -88,6 +161,18 @@ impl From<Middlewares> for golem_api_grpc::proto::golem::apidefinition::Middlewa
     fn from(value: Middlewares) -> Self {
         golem_api_grpc::proto::golem::apidefinition::Middleware {
             cors: value.0.iter().find_map(|m| m.get_cors().map(|c| c.into())),
+            jwt_authentication: value
+                .0
+                .iter()
+                .find_map(|m| m.get_jwt_authentication().map(|j| j.into())),
+            rate_limit: value
+                .0
+                .iter()
+                .find_map(|m| m.get_rate_limit().map(|r| r.into())),
+            transformation: value
+                .0
+                .iter()
+                .find_map(|m| m.get_transformation().map(|t| t.into())),
         }
     }
 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
+use std::net::IpAddr;
 
 use crate::gateway_api_deployment::ApiSiteString;
+use crate::gateway_request::request_details::WebSocketUpgrade;
 use hyper::http::{HeaderMap, Method};
 use serde_json::Value;
 
@@ -10,6 +12,8 @@ pub struct InputHttpRequest {
     pub headers: HeaderMap,
     pub req_method: Method,
     pub req_body: Value,
+    pub client_address: Option<IpAddr>,
+    pub websocket_upgrade: Option<WebSocketUpgrade>,
 }
 
 impl InputHttpRequest {
@@ -53,6 +57,8 @@ pub mod router {
     use crate::gateway_api_definition::http::{PathPattern, QueryInfo, VarInfo};
     use crate::gateway_binding::GatewayBindingCompiled;
     use crate::gateway_execution::router::{Router, RouterPattern};
+    use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
+    use hyper::http::Method;
 
     #[derive(Debug, Clone)]
     pub struct RouteEntry<Namespace> {
@@ -60,18 +66,22 @@ pub mod router {
         pub path_params: Vec<(VarInfo, usize)>,
         pub query_params: Vec<QueryInfo>,
         pub namespace: Namespace,
+        pub api_definition: ApiDefinitionIdWithVersion,
         pub binding: GatewayBindingCompiled,
+        // method and path pattern of the route, Example: GET /users/{user-id}
+        pub route: String,
     }
 
     pub fn build<Namespace>(
-        routes: Vec<(Namespace, CompiledRoute)>,
+        routes: Vec<(Namespace, ApiDefinitionIdWithVersion, CompiledRoute)>,
     ) -> Router<RouteEntry<Namespace>> {
         let mut router = Router::new();
 
-        for (namespace, route) in routes {
-            let method = route.method.into();
+        for (namespace, api_definition, route) in routes {
+            let method: Method = route.method.into();
             let path = route.path;
             let binding = route.binding;
+            let route = format!("{} {}", method, path);
 
             let path_params = path
                 .path_patterns
@@ -87,7 +97,9 @@ pub mod router {
                 path_params,
                 query_params: path.query_params,
                 namespace,
+                api_definition,
                 binding,
+                route,
             };
 
             let path: Vec<RouterPattern> = path
@@ -1,2 +1,3 @@
 pub mod http_request;
 pub mod request_details;
+pub mod request_validator;
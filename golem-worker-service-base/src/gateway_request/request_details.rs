@@ -1,8 +1,12 @@
 use crate::gateway_api_definition::http::{QueryInfo, VarInfo};
 
 use http::HeaderMap;
+use poem::web::websocket::WebSocket;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::fmt::Debug;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
 
 #[derive(Clone, Debug)]
 pub enum GatewayRequestDetails {
@@ -15,6 +19,7 @@ impl GatewayRequestDetails {
         query_variable_names: &[QueryInfo],
         request_body: &Value,
         headers: &HeaderMap,
+        client_address: Option<IpAddr>,
     ) -> Result<Self, Vec<String>> {
         Ok(Self::Http(HttpRequestDetails::from_input_http_request(
             path_params,
@@ -22,9 +27,45 @@ impl GatewayRequestDetails {
             query_variable_names,
             request_body,
             headers,
+            client_address,
         )?))
     }
 
+    pub fn with_auth_claims(self, claims: Value) -> Self {
+        match self {
+            GatewayRequestDetails::Http(http_request_details) => {
+                GatewayRequestDetails::Http(HttpRequestDetails {
+                    request_auth_claims: Some(RequestAuthClaims(claims)),
+                    ..http_request_details
+                })
+            }
+        }
+    }
+
+    // Stream bindings evaluate the response mapping for each inbound message,
+    // with the message in place of the request body
+    pub fn with_body(self, body: Value) -> Self {
+        match self {
+            GatewayRequestDetails::Http(http_request_details) => {
+                GatewayRequestDetails::Http(HttpRequestDetails {
+                    request_body: RequestBody(body),
+                    ..http_request_details
+                })
+            }
+        }
+    }
+
+    pub fn with_websocket_upgrade(self, websocket_upgrade: Option<WebSocketUpgrade>) -> Self {
+        match self {
+            GatewayRequestDetails::Http(http_request_details) => {
+                GatewayRequestDetails::Http(HttpRequestDetails {
+                    websocket_upgrade,
+                    ..http_request_details
+                })
+            }
+        }
+    }
+
     pub fn as_json(&self) -> Value {
         match self {
             GatewayRequestDetails::Http(http_request_details) => {
@@ -51,14 +92,26 @@ impl GatewayRequestDetails {
 
                 let header_value = Value::Object(header_records);
 
-                Value::Object(serde_json::Map::from_iter(vec![
+                let mut request_details = serde_json::Map::from_iter(vec![
                     ("path".to_string(), merged_request_path_and_query),
                     (
                         "body".to_string(),
                         http_request_details.request_body.0.clone(),
                     ),
                     ("headers".to_string(), header_value),
-                ]))
+                ]);
+
+                if let Some(auth_claims) = &http_request_details.request_auth_claims {
+                    request_details.insert(
+                        "auth".to_string(),
+                        Value::Object(serde_json::Map::from_iter(vec![(
+                            "claims".to_string(),
+                            auth_claims.0.clone(),
+                        )])),
+                    );
+                }
+
+                Value::Object(request_details)
             }
         }
     }
@@ -70,6 +123,9 @@ pub struct HttpRequestDetails {
     pub request_body: RequestBody,
     pub request_query_values: RequestQueryValues,
     pub request_header_values: RequestHeaderValues,
+    pub request_auth_claims: Option<RequestAuthClaims>,
+    pub client_address: Option<IpAddr>,
+    pub websocket_upgrade: Option<WebSocketUpgrade>,
 }
 
 impl HttpRequestDetails {
@@ -79,6 +135,9 @@ impl HttpRequestDetails {
             request_body: RequestBody(Value::Null),
             request_query_values: RequestQueryValues(JsonKeyValues::default()),
             request_header_values: RequestHeaderValues(JsonKeyValues::default()),
+            request_auth_claims: None,
+            client_address: None,
+            websocket_upgrade: None,
         }
     }
 
@@ -91,12 +150,27 @@ impl HttpRequestDetails {
             .and_then(|field| field.value.as_str().map(|x| x.to_string()))
     }
 
+    // Header values are refined to JSON values (numbers, booleans) when forming the request details,
+    // and are turned back into their string representation here
+    pub fn get_header_value(&self, header_name: &str) -> Option<String> {
+        self.request_header_values
+            .0
+            .fields
+            .iter()
+            .find(|field| field.name.eq_ignore_ascii_case(header_name))
+            .map(|field| match &field.value {
+                Value::String(value) => value.clone(),
+                value => value.to_string(),
+            })
+    }
+
     fn from_input_http_request(
         path_params: &HashMap<VarInfo, &str>,
         query_variable_values: &HashMap<String, String>,
         query_variable_names: &[QueryInfo],
         request_body: &Value,
         headers: &HeaderMap,
+        client_address: Option<IpAddr>,
     ) -> Result<Self, Vec<String>> {
         let request_body = RequestBody::from(request_body)?;
         let path_params = RequestPathValues::from(path_params);
@@ -108,6 +182,9 @@ impl HttpRequestDetails {
             request_body,
             request_query_values: query_params,
             request_header_values: header_params,
+            request_auth_claims: None,
+            client_address,
+            websocket_upgrade: None,
         })
     }
 }
@@ -161,7 +238,7 @@ impl RequestQueryValues {
 }
 
 #[derive(Debug, Clone)]
-pub struct RequestHeaderValues(JsonKeyValues);
+pub struct RequestHeaderValues(pub JsonKeyValues);
 impl RequestHeaderValues {
     fn from(headers: &HeaderMap) -> Result<RequestHeaderValues, Vec<String>> {
         let mut headers_map: JsonKeyValues = JsonKeyValues::default();
@@ -179,7 +256,7 @@ impl RequestHeaderValues {
 }
 
 #[derive(Debug, Clone)]
-pub struct RequestBody(Value);
+pub struct RequestBody(pub Value);
 
 impl RequestBody {
     fn from(request_body: &Value) -> Result<RequestBody, Vec<String>> {
@@ -187,6 +264,32 @@ impl RequestBody {
     }
 }
 
+// Upgrade of a request to a WebSocket connection, which is only available
+// if the client asked for it. It can be taken once, by the binding the request is resolved to.
+#[derive(Clone)]
+pub struct WebSocketUpgrade(Arc<Mutex<Option<WebSocket>>>);
+
+impl WebSocketUpgrade {
+    pub fn new(websocket: WebSocket) -> WebSocketUpgrade {
+        WebSocketUpgrade(Arc::new(Mutex::new(Some(websocket))))
+    }
+
+    pub fn take(&self) -> Option<WebSocket> {
+        self.0.lock().unwrap().take()
+    }
+}
+
+impl Debug for WebSocketUpgrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WebSocketUpgrade")
+    }
+}
+
+// Claims of a bearer token validated by an authentication middleware,
+// available in Rib as `request.auth.claims`
+#[derive(Debug, Clone)]
+pub struct RequestAuthClaims(pub Value);
+
 #[derive(Clone, Debug, Default)]
 pub struct JsonKeyValues {
     pub fields: Vec<JsonKeyValue>,
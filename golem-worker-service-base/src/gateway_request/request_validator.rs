@@ -0,0 +1,556 @@
+use crate::gateway_request::request_details::{GatewayRequestDetails, HttpRequestDetails};
+use golem_wasm_ast::analysis::AnalysedType;
+use rib::{RibInputTypeInfo, TypeName};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Display;
+
+// Validates the body, path, query and header values of a request against the type of `request`
+// inferred when compiling the Rib expressions of a binding. This happens before any worker is involved,
+// such that a malformed request is rejected with every mismatching field, instead of
+// failing the evaluation of the first Rib expression that makes use of it.
+// Only the parts of the request used in Rib are validated, and unknown fields are ignored.
+pub trait RequestValidator {
+    fn validate(&self, rib_inputs: &[&RibInputTypeInfo]) -> Result<(), RequestValidationErrors>;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RequestValidationErrors {
+    pub errors: Vec<RequestValidationError>,
+}
+
+impl Display for RequestValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors = self
+            .errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+
+        write!(f, "Invalid request: {}", errors.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RequestValidationError {
+    pub location: RequestLocation,
+    // Path of the field including its location, Example: `body.items[0].quantity`
+    pub field: String,
+    pub expected: String,
+    pub message: String,
+}
+
+impl Display for RequestValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestLocation {
+    Body,
+    Path,
+    Query,
+    Header,
+}
+
+impl Display for RequestLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestLocation::Body => write!(f, "body"),
+            RequestLocation::Path => write!(f, "path"),
+            RequestLocation::Query => write!(f, "query"),
+            RequestLocation::Header => write!(f, "headers"),
+        }
+    }
+}
+
+impl RequestValidator for GatewayRequestDetails {
+    fn validate(&self, rib_inputs: &[&RibInputTypeInfo]) -> Result<(), RequestValidationErrors> {
+        match self {
+            GatewayRequestDetails::Http(http_request_details) => {
+                let mut errors = vec![];
+
+                for rib_input in rib_inputs {
+                    if let Some(AnalysedType::Record(request_type)) = rib_input.types.get("request")
+                    {
+                        for field in &request_type.fields {
+                            internal::validate_request_field(
+                                http_request_details,
+                                &field.name,
+                                &field.typ,
+                                &mut errors,
+                            );
+                        }
+                    }
+                }
+
+                // The same part of the request can be used by more than one Rib expression of a binding
+                let mut unique_errors: Vec<RequestValidationError> = vec![];
+
+                for error in errors {
+                    if !unique_errors.contains(&error) {
+                        unique_errors.push(error);
+                    }
+                }
+
+                if unique_errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(RequestValidationErrors {
+                        errors: unique_errors,
+                    })
+                }
+            }
+        }
+    }
+}
+
+mod internal {
+    use super::*;
+    use crate::gateway_request::request_details::JsonKeyValues;
+
+    pub(crate) fn validate_request_field(
+        http_request_details: &HttpRequestDetails,
+        name: &str,
+        analysed_type: &AnalysedType,
+        errors: &mut Vec<RequestValidationError>,
+    ) {
+        match (name, analysed_type) {
+            ("body", _) => {
+                let body = &http_request_details.request_body.0;
+
+                // A missing body is a null body
+                validate_value(
+                    Some(body),
+                    analysed_type,
+                    RequestLocation::Body,
+                    RequestLocation::Body.to_string(),
+                    errors,
+                );
+            }
+
+            // Query parameters are available in Rib under `request.path`, similar to path variables
+            ("path", AnalysedType::Record(path_type)) => {
+                for field in &path_type.fields {
+                    let path_value = get_value(
+                        &http_request_details.request_path_values.0,
+                        &field.name,
+                        false,
+                    );
+
+                    let query_value = get_value(
+                        &http_request_details.request_query_values.0,
+                        &field.name,
+                        false,
+                    );
+
+                    let (location, value) = match (path_value, query_value) {
+                        (None, Some(query_value)) => (RequestLocation::Query, Some(query_value)),
+                        (path_value, _) => (RequestLocation::Path, path_value),
+                    };
+
+                    validate_value(
+                        value,
+                        &field.typ,
+                        location,
+                        format!("{}.{}", location, field.name),
+                        errors,
+                    );
+                }
+            }
+
+            ("headers", AnalysedType::Record(headers_type)) => {
+                for field in &headers_type.fields {
+                    let value = get_value(
+                        &http_request_details.request_header_values.0,
+                        &field.name,
+                        true,
+                    );
+
+                    validate_value(
+                        value,
+                        &field.typ,
+                        RequestLocation::Header,
+                        format!("{}.{}", RequestLocation::Header, field.name),
+                        errors,
+                    );
+                }
+            }
+
+            // Anything else (Example: the claims of an authenticated request) is not sent by the client as is
+            _ => {}
+        }
+    }
+
+    fn get_value<'a>(
+        key_values: &'a JsonKeyValues,
+        name: &str,
+        ignore_case: bool,
+    ) -> Option<&'a Value> {
+        key_values
+            .fields
+            .iter()
+            .find(|field| {
+                if ignore_case {
+                    field.name.eq_ignore_ascii_case(name)
+                } else {
+                    field.name == name
+                }
+            })
+            .map(|field| &field.value)
+    }
+
+    pub(crate) fn validate_value(
+        value: Option<&Value>,
+        analysed_type: &AnalysedType,
+        location: RequestLocation,
+        field: String,
+        errors: &mut Vec<RequestValidationError>,
+    ) {
+        let value = match (value, analysed_type) {
+            (None, AnalysedType::Option(_)) | (Some(Value::Null), AnalysedType::Option(_)) => {
+                return;
+            }
+            (None, _) => {
+                errors.push(mismatch(
+                    location,
+                    field,
+                    analysed_type,
+                    "missing".to_string(),
+                ));
+                return;
+            }
+            (Some(value), _) => value,
+        };
+
+        let error_message =
+            match analysed_type {
+                AnalysedType::Option(option) => {
+                    validate_value(Some(value), &option.inner, location, field.clone(), errors);
+                    None
+                }
+                AnalysedType::Bool(_) => (!value.is_boolean())
+                    .then(|| format!("expected a boolean, found {}", kind(value))),
+                AnalysedType::S8(_) => validate_integer(value, i8::MIN as i128, i8::MAX as i128),
+                AnalysedType::U8(_) => validate_integer(value, 0, u8::MAX as i128),
+                AnalysedType::S16(_) => validate_integer(value, i16::MIN as i128, i16::MAX as i128),
+                AnalysedType::U16(_) => validate_integer(value, 0, u16::MAX as i128),
+                AnalysedType::S32(_) => validate_integer(value, i32::MIN as i128, i32::MAX as i128),
+                AnalysedType::U32(_) => validate_integer(value, 0, u32::MAX as i128),
+                AnalysedType::S64(_) => validate_integer(value, i64::MIN as i128, i64::MAX as i128),
+                AnalysedType::U64(_) => validate_integer(value, 0, u64::MAX as i128),
+                AnalysedType::F32(_) | AnalysedType::F64(_) => (!value.is_number())
+                    .then(|| format!("expected a number, found {}", kind(value))),
+                AnalysedType::Chr(_) => match value {
+                    Value::String(string) if string.chars().count() == 1 => None,
+                    Value::Number(_) => None,
+                    _ => Some(format!("expected a character, found {}", kind(value))),
+                },
+                AnalysedType::Str(_) | AnalysedType::Handle(_) => (!value.is_string())
+                    .then(|| format!("expected a string, found {}", kind(value))),
+                AnalysedType::Enum(enum_type) => match value.as_str() {
+                    Some(case) if enum_type.cases.iter().any(|c| c == case) => None,
+                    _ => Some(format!(
+                        "expected one of {}, found {}",
+                        enum_type.cases.join(", "),
+                        value
+                    )),
+                },
+                AnalysedType::Flags(flags) => match value.as_array() {
+                    Some(items) => {
+                        for (index, item) in items.iter().enumerate() {
+                            match item.as_str() {
+                                Some(flag) if flags.names.iter().any(|name| name == flag) => {}
+                                _ => errors.push(RequestValidationError {
+                                    location,
+                                    field: format!("{}[{}]", field, index),
+                                    expected: flags.names.join(" | "),
+                                    message: format!(
+                                        "expected one of {}, found {}",
+                                        flags.names.join(", "),
+                                        item
+                                    ),
+                                }),
+                            }
+                        }
+                        None
+                    }
+                    None => Some(format!("expected an array of flags, found {}", kind(value))),
+                },
+                AnalysedType::List(list) => match value.as_array() {
+                    Some(items) => {
+                        for (index, item) in items.iter().enumerate() {
+                            validate_value(
+                                Some(item),
+                                &list.inner,
+                                location,
+                                format!("{}[{}]", field, index),
+                                errors,
+                            );
+                        }
+                        None
+                    }
+                    None => Some(format!("expected an array, found {}", kind(value))),
+                },
+                AnalysedType::Tuple(tuple) => match value.as_array() {
+                    Some(items) if items.len() == tuple.items.len() => {
+                        for (index, (item, item_type)) in items.iter().zip(&tuple.items).enumerate()
+                        {
+                            validate_value(
+                                Some(item),
+                                item_type,
+                                location,
+                                format!("{}[{}]", field, index),
+                                errors,
+                            );
+                        }
+                        None
+                    }
+                    Some(items) => Some(format!(
+                        "expected an array of {} items, found {} items",
+                        tuple.items.len(),
+                        items.len()
+                    )),
+                    None => Some(format!("expected an array, found {}", kind(value))),
+                },
+                AnalysedType::Record(record) => match value.as_object() {
+                    Some(object) => {
+                        for record_field in &record.fields {
+                            validate_value(
+                                object.get(&record_field.name),
+                                &record_field.typ,
+                                location,
+                                format!("{}.{}", field, record_field.name),
+                                errors,
+                            );
+                        }
+                        None
+                    }
+                    None => Some(format!("expected an object, found {}", kind(value))),
+                },
+                AnalysedType::Variant(variant) => {
+                    let cases = variant
+                        .cases
+                        .iter()
+                        .map(|case| (case.name.as_str(), case.typ.as_ref()))
+                        .collect::<Vec<_>>();
+
+                    validate_case(value, &cases, location, field.clone(), errors);
+                    None
+                }
+                AnalysedType::Result(result) => {
+                    let cases = vec![("ok", result.ok.as_deref()), ("err", result.err.as_deref())];
+
+                    validate_case(value, &cases, location, field.clone(), errors);
+                    None
+                }
+            };
+
+        if let Some(message) = error_message {
+            errors.push(mismatch(location, field, analysed_type, message));
+        }
+    }
+
+    fn mismatch(
+        location: RequestLocation,
+        field: String,
+        analysed_type: &AnalysedType,
+        message: String,
+    ) -> RequestValidationError {
+        RequestValidationError {
+            location,
+            field,
+            expected: type_name(analysed_type),
+            message,
+        }
+    }
+
+    // Variants and results are represented as an object with a single key, which is the name of the case
+    fn validate_case(
+        value: &Value,
+        cases: &[(&str, Option<&AnalysedType>)],
+        location: RequestLocation,
+        field: String,
+        errors: &mut Vec<RequestValidationError>,
+    ) {
+        let case_names = cases.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+
+        let case = value
+            .as_object()
+            .filter(|object| object.len() == 1)
+            .and_then(|object| object.iter().next());
+
+        match case {
+            Some((name, case_value)) => match cases.iter().find(|(case, _)| case == name) {
+                Some((_, Some(case_type))) => validate_value(
+                    Some(case_value),
+                    case_type,
+                    location,
+                    format!("{}.{}", field, name),
+                    errors,
+                ),
+                Some((_, None)) => {}
+                None => errors.push(RequestValidationError {
+                    location,
+                    field,
+                    expected: case_names.join(" | "),
+                    message: format!("expected one of {}, found {}", case_names.join(", "), name),
+                }),
+            },
+            None => errors.push(RequestValidationError {
+                location,
+                field,
+                expected: case_names.join(" | "),
+                message: format!(
+                    "expected an object with one of {} as the only key, found {}",
+                    case_names.join(", "),
+                    kind(value)
+                ),
+            }),
+        }
+    }
+
+    fn validate_integer(value: &Value, min: i128, max: i128) -> Option<String> {
+        let integer = value
+            .as_i64()
+            .map(|value| value as i128)
+            .or(value.as_u64().map(|value| value as i128));
+
+        match integer {
+            Some(integer) if integer >= min && integer <= max => None,
+            Some(integer) => Some(format!(
+                "{} is out of range, expected a value between {} and {}",
+                integer, min, max
+            )),
+            None => Some(format!("expected an integer, found {}", kind(value))),
+        }
+    }
+
+    fn type_name(analysed_type: &AnalysedType) -> String {
+        match analysed_type {
+            AnalysedType::Handle(_) => "handle".to_string(),
+            _ => TypeName::try_from(analysed_type.clone())
+                .map(|type_name| type_name.to_string())
+                .unwrap_or_else(|_| format!("{:?}", analysed_type)),
+        }
+    }
+
+    fn kind(value: &Value) -> String {
+        match value {
+            Value::Null => "null".to_string(),
+            Value::Bool(_) => "a boolean".to_string(),
+            Value::Number(number) => format!("the number {}", number),
+            Value::String(_) => "a string".to_string(),
+            Value::Array(_) => "an array".to_string(),
+            Value::Object(_) => "an object".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use crate::gateway_request::request_details::{JsonKeyValues, RequestBody};
+    use golem_wasm_ast::analysis::analysed_type::{field, list, option, record, str, u32, u64};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn request_input(request_type: AnalysedType) -> RibInputTypeInfo {
+        RibInputTypeInfo {
+            types: HashMap::from([("request".to_string(), request_type)]),
+        }
+    }
+
+    fn request_details(path: Vec<(&str, Value)>, body: Value) -> GatewayRequestDetails {
+        let mut http_request_details = HttpRequestDetails::empty();
+
+        let mut path_values = JsonKeyValues::default();
+        for (name, value) in path {
+            path_values.push(name.to_string(), value);
+        }
+
+        http_request_details.request_path_values.0 = path_values;
+        http_request_details.request_body = RequestBody(body);
+
+        GatewayRequestDetails::Http(http_request_details)
+    }
+
+    #[test]
+    fn test_validate_valid_request() {
+        let rib_input = request_input(record(vec![
+            field("path", record(vec![field("user-id", u64())])),
+            field(
+                "body",
+                record(vec![field("name", str()), field("note", option(str()))]),
+            ),
+        ]));
+
+        let request = request_details(
+            vec![("user-id", json!(1))],
+            json!({ "name": "shoes", "extra": true }),
+        );
+
+        assert_eq!(request.validate(&[&rib_input]), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_lists_every_mismatch() {
+        let rib_input = request_input(record(vec![
+            field("path", record(vec![field("user-id", u64())])),
+            field(
+                "body",
+                record(vec![
+                    field("name", str()),
+                    field("items", list(record(vec![field("quantity", u32())]))),
+                ]),
+            ),
+        ]));
+
+        let request = request_details(
+            vec![("user-id", json!("foo"))],
+            json!({ "items": [{ "quantity": 1 }, { "quantity": -1 }] }),
+        );
+
+        let fields = request
+            .validate(&[&rib_input])
+            .unwrap_err()
+            .errors
+            .into_iter()
+            .map(|error| (error.location, error.field))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            fields,
+            vec![
+                (RequestLocation::Path, "path.user-id".to_string()),
+                (RequestLocation::Body, "body.name".to_string()),
+                (RequestLocation::Body, "body.items[1].quantity".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_deduplicates_errors_of_rib_inputs() {
+        let rib_input = request_input(record(vec![field("body", u64())]));
+
+        let request = request_details(vec![], json!("foo"));
+
+        let result = request.validate(&[&rib_input, &rib_input]);
+
+        assert_eq!(
+            result,
+            Err(RequestValidationErrors {
+                errors: vec![RequestValidationError {
+                    location: RequestLocation::Body,
+                    field: "body".to_string(),
+                    expected: "u64".to_string(),
+                    message: "expected an integer, found a string".to_string(),
+                }]
+            })
+        );
+    }
+}
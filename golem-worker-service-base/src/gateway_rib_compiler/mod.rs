@@ -16,5 +16,6 @@ impl WorkerServiceRibCompiler for DefaultRibCompiler {
             &export_metadata.to_vec(),
             Some(vec!["request".to_string()]),
         )
+        .map_err(|err| err.to_string())
     }
 }
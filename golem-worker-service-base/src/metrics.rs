@@ -27,3 +27,28 @@ pub fn register_all() -> Registry {
 
     default_registry().clone()
 }
+
+pub mod traffic_split {
+    use golem_service_base::model::VersionedComponentId;
+    use lazy_static::lazy_static;
+    use prometheus::*;
+
+    lazy_static! {
+        static ref TRAFFIC_SPLIT_REQUEST_TOTAL: IntCounterVec = register_int_counter_vec!(
+            "traffic_split_request_total",
+            "Number of gateway requests sent to each target of a traffic split",
+            &["route", "component_id", "component_version"]
+        )
+        .unwrap();
+    }
+
+    pub fn record_traffic_split_target(route: &str, component_id: &VersionedComponentId) {
+        TRAFFIC_SPLIT_REQUEST_TOTAL
+            .with_label_values(&[
+                route,
+                &component_id.component_id.to_string(),
+                &component_id.version.to_string(),
+            ])
+            .inc();
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use conditional_trait_gen::trait_gen;
+use golem_service_base::repo::RepoError;
+use sqlx::{Database, Pool, Row};
+use std::ops::Deref;
+use std::sync::Arc;
+use tracing::{debug, error};
+
+// Cached responses are kept by each worker service instance, while their generation is shared.
+// Invalidating the responses increments the generation, such that every instance drops
+// the responses it cached for an older generation.
+#[async_trait]
+pub trait ApiDefinitionCacheRepo {
+    // Increments the generation of the responses with the given cache key,
+    // or of all the responses of the API definition if no cache key is given
+    async fn invalidate(
+        &self,
+        namespace: &str,
+        id: &str,
+        version: &str,
+        cache_key: Option<&str>,
+    ) -> Result<(), RepoError>;
+
+    // Generation of the responses with the given cache key,
+    // including the invalidations of all the responses of the API definition
+    async fn get_generation(
+        &self,
+        namespace: &str,
+        id: &str,
+        version: &str,
+        cache_key: &str,
+    ) -> Result<i64, RepoError>;
+}
+
+// Cache key of the invalidations of all the responses of an API definition
+const ALL_CACHE_KEYS: &str = "";
+
+pub struct LoggedApiDefinitionCacheRepo<Repo: ApiDefinitionCacheRepo> {
+    repo: Repo,
+}
+
+impl<Repo: ApiDefinitionCacheRepo> LoggedApiDefinitionCacheRepo<Repo> {
+    pub fn new(repo: Repo) -> Self {
+        Self { repo }
+    }
+
+    fn logged_with_id<R>(
+        message: &'static str,
+        namespace: &str,
+        api_definition_id: &str,
+        result: Result<R, RepoError>,
+    ) -> Result<R, RepoError> {
+        match &result {
+            Ok(_) => debug!(
+                namespace = namespace,
+                api_definition_id = api_definition_id.to_string(),
+                "{}",
+                message
+            ),
+            Err(error) => error!(
+                namespace = namespace,
+                api_definition_id = api_definition_id.to_string(),
+                error = error.to_string(),
+                "{message}"
+            ),
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl<Repo: ApiDefinitionCacheRepo + Sync> ApiDefinitionCacheRepo
+    for LoggedApiDefinitionCacheRepo<Repo>
+{
+    async fn invalidate(
+        &self,
+        namespace: &str,
+        id: &str,
+        version: &str,
+        cache_key: Option<&str>,
+    ) -> Result<(), RepoError> {
+        let result = self
+            .repo
+            .invalidate(namespace, id, version, cache_key)
+            .await;
+        Self::logged_with_id("invalidate", namespace, id, result)
+    }
+
+    async fn get_generation(
+        &self,
+        namespace: &str,
+        id: &str,
+        version: &str,
+        cache_key: &str,
+    ) -> Result<i64, RepoError> {
+        let result = self
+            .repo
+            .get_generation(namespace, id, version, cache_key)
+            .await;
+        Self::logged_with_id("get_generation", namespace, id, result)
+    }
+}
+
+pub struct DbApiDefinitionCacheRepo<DB: Database> {
+    db_pool: Arc<Pool<DB>>,
+}
+
+impl<DB: Database> DbApiDefinitionCacheRepo<DB> {
+    pub fn new(db_pool: Arc<Pool<DB>>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[trait_gen(sqlx::Postgres -> sqlx::Postgres, sqlx::Sqlite)]
+#[async_trait]
+impl ApiDefinitionCacheRepo for DbApiDefinitionCacheRepo<sqlx::Postgres> {
+    async fn invalidate(
+        &self,
+        namespace: &str,
+        id: &str,
+        version: &str,
+        cache_key: Option<&str>,
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+              INSERT INTO api_definition_cache_invalidations
+                (namespace, id, version, cache_key, generation)
+              VALUES
+                ($1, $2, $3, $4, 1)
+              ON CONFLICT (namespace, id, version, cache_key) DO UPDATE
+              SET generation = api_definition_cache_invalidations.generation + 1
+               "#,
+        )
+        .bind(namespace)
+        .bind(id)
+        .bind(version)
+        .bind(cache_key.unwrap_or(ALL_CACHE_KEYS))
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_generation(
+        &self,
+        namespace: &str,
+        id: &str,
+        version: &str,
+        cache_key: &str,
+    ) -> Result<i64, RepoError> {
+        let rows = sqlx::query(
+            r#"
+              SELECT generation FROM api_definition_cache_invalidations
+              WHERE namespace = $1 AND id = $2 AND version = $3 AND cache_key IN ($4, $5)
+               "#,
+        )
+        .bind(namespace)
+        .bind(id)
+        .bind(version)
+        .bind(ALL_CACHE_KEYS)
+        .bind(cache_key)
+        .fetch_all(self.db_pool.deref())
+        .await?;
+
+        Ok(rows.iter().map(|r| r.get::<i64, _>("generation")).sum())
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::gateway_api_deployment::ApiSite;
+use crate::gateway_api_deployment::{ApiSite, ApiSiteCertificate};
 use crate::repo::api_definition::ApiDefinitionRecord;
 use crate::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use async_trait::async_trait;
@@ -54,6 +54,44 @@ impl ApiDeploymentRecord {
     }
 }
 
+// Certificate of a site, shared by all the API definitions deployed to the site.
+// The private key is stored unencrypted, so access to the database has to be restricted
+// to the worker service. It is only read when loading the certificates of the TLS listener.
+#[derive(sqlx::FromRow, Clone)]
+pub struct ApiDeploymentCertificateRecord {
+    pub namespace: String,
+    pub site: String,
+    pub certificate: String,
+    pub private_key: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ApiDeploymentCertificateRecord {
+    pub fn new<Namespace: Display>(
+        namespace: Namespace,
+        site: ApiSite,
+        certificate: ApiSiteCertificate,
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            site: site.to_string(),
+            certificate: certificate.certificate,
+            private_key: certificate.private_key,
+            created_at,
+        }
+    }
+}
+
+impl From<ApiDeploymentCertificateRecord> for ApiSiteCertificate {
+    fn from(value: ApiDeploymentCertificateRecord) -> Self {
+        ApiSiteCertificate {
+            certificate: value.certificate,
+            private_key: value.private_key,
+        }
+    }
+}
+
 #[async_trait]
 pub trait ApiDeploymentRepo {
     async fn create(&self, deployments: Vec<ApiDeploymentRecord>) -> Result<(), RepoError>;
@@ -79,6 +117,22 @@ pub trait ApiDeploymentRepo {
         &self,
         site: &str,
     ) -> Result<Vec<ApiDefinitionRecord>, RepoError>;
+
+    // Fails with a unique violation if the site has a certificate in another namespace
+    async fn create_or_update_certificate(
+        &self,
+        certificate: &ApiDeploymentCertificateRecord,
+    ) -> Result<(), RepoError>;
+
+    async fn delete_certificate(&self, namespace: &str, site: &str) -> Result<bool, RepoError>;
+
+    async fn get_certificate(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Option<ApiDeploymentCertificateRecord>, RepoError>;
+
+    async fn get_all_certificates(&self) -> Result<Vec<ApiDeploymentCertificateRecord>, RepoError>;
 }
 
 pub struct LoggedDeploymentRepo<Repo: ApiDeploymentRepo> {
@@ -168,6 +222,33 @@ impl<Repo: ApiDeploymentRepo + Sync> ApiDeploymentRepo for LoggedDeploymentRepo<
         let result = self.repo.get_definitions_by_site(site).await;
         Self::logged("get_definitions_by_site", result)
     }
+
+    async fn create_or_update_certificate(
+        &self,
+        certificate: &ApiDeploymentCertificateRecord,
+    ) -> Result<(), RepoError> {
+        let result = self.repo.create_or_update_certificate(certificate).await;
+        Self::logged("create_or_update_certificate", result)
+    }
+
+    async fn delete_certificate(&self, namespace: &str, site: &str) -> Result<bool, RepoError> {
+        let result = self.repo.delete_certificate(namespace, site).await;
+        Self::logged("delete_certificate", result)
+    }
+
+    async fn get_certificate(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Option<ApiDeploymentCertificateRecord>, RepoError> {
+        let result = self.repo.get_certificate(namespace, site).await;
+        Self::logged("get_certificate", result)
+    }
+
+    async fn get_all_certificates(&self) -> Result<Vec<ApiDeploymentCertificateRecord>, RepoError> {
+        let result = self.repo.get_all_certificates().await;
+        Self::logged("get_all_certificates", result)
+    }
 }
 
 pub struct DbApiDeploymentRepo<DB: Database> {
@@ -388,4 +469,120 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
             .await
             .map_err(|e| e.into())
     }
+
+    async fn create_or_update_certificate(
+        &self,
+        certificate: &ApiDeploymentCertificateRecord,
+    ) -> Result<(), RepoError> {
+        let result = sqlx::query(
+            r#"
+              INSERT INTO api_deployment_certificates
+                (namespace, site, certificate, private_key, created_at)
+              SELECT $1, $2, $3, $4, $5
+              WHERE NOT EXISTS (
+                SELECT 1 FROM api_deployment_certificates WHERE site = $2 AND namespace <> $1
+              )
+              ON CONFLICT (namespace, site) DO UPDATE
+              SET certificate = $3, private_key = $4, created_at = $5
+               "#,
+        )
+        .bind(certificate.namespace.clone())
+        .bind(certificate.site.clone())
+        .bind(certificate.certificate.clone())
+        .bind(certificate.private_key.clone())
+        .bind(certificate.created_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        if result.rows_affected() == 0 {
+            Err(RepoError::UniqueViolation(format!(
+                "Certificate of site {} belongs to another namespace",
+                certificate.site
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn delete_certificate(&self, namespace: &str, site: &str) -> Result<bool, RepoError> {
+        let result = sqlx::query(
+            "DELETE FROM api_deployment_certificates WHERE namespace = $1 AND site = $2",
+        )
+        .bind(namespace)
+        .bind(site)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[when(sqlx::Postgres -> get_certificate)]
+    async fn get_certificate_postgres(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Option<ApiDeploymentCertificateRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDeploymentCertificateRecord>(
+            r#"
+                SELECT namespace, site, certificate, private_key, created_at::timestamptz
+                FROM api_deployment_certificates
+                WHERE namespace = $1 AND site = $2
+                "#,
+        )
+        .bind(namespace)
+        .bind(site)
+        .fetch_optional(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_certificate)]
+    async fn get_certificate_sqlite(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<Option<ApiDeploymentCertificateRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDeploymentCertificateRecord>(
+            r#"
+                SELECT namespace, site, certificate, private_key, created_at
+                FROM api_deployment_certificates
+                WHERE namespace = $1 AND site = $2
+                "#,
+        )
+        .bind(namespace)
+        .bind(site)
+        .fetch_optional(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Postgres -> get_all_certificates)]
+    async fn get_all_certificates_postgres(
+        &self,
+    ) -> Result<Vec<ApiDeploymentCertificateRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDeploymentCertificateRecord>(
+            r#"
+                SELECT namespace, site, certificate, private_key, created_at::timestamptz
+                FROM api_deployment_certificates
+                "#,
+        )
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_all_certificates)]
+    async fn get_all_certificates_sqlite(
+        &self,
+    ) -> Result<Vec<ApiDeploymentCertificateRecord>, RepoError> {
+        sqlx::query_as::<_, ApiDeploymentCertificateRecord>(
+            r#"
+                SELECT namespace, site, certificate, private_key, created_at
+                FROM api_deployment_certificates
+                "#,
+        )
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
 }
@@ -13,4 +13,5 @@
 // limitations under the License.
 
 pub mod api_definition;
+pub mod api_definition_cache;
 pub mod api_deployment;
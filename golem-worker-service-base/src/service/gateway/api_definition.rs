@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use std::sync::Arc;
@@ -195,8 +196,10 @@ impl<AuthCtx, ValidationError> ApiDefinitionServiceDefault<AuthCtx, ValidationEr
             .iter()
             .cloned()
             .filter_map(|binding| binding.get_worker_binding())
-            .map(|binding| async move {
-                let id = &binding.component_id;
+            .flat_map(|binding| binding.get_component_ids())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|id| async move {
                 self.component_service
                     .get_by_version(&id.component_id, id.version, auth_ctx)
                     .await
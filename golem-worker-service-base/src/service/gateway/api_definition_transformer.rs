@@ -154,6 +154,8 @@ mod tests {
             idempotency_key: None,
             response_mapping: ResponseMapping(Expr::literal("")),
             middleware: None,
+            traffic_split: None,
+            response_cache: None,
         };
 
         Route {
@@ -175,6 +177,8 @@ mod tests {
             middleware: Some(Middlewares(vec![Middleware::Http(HttpMiddleware::Cors(
                 cors(),
             ))])),
+            traffic_split: None,
+            response_cache: None,
         };
 
         Route {
@@ -29,6 +29,7 @@ use crate::gateway_api_definition::http::{
 use crate::gateway_binding::GatewayBindingCompiled;
 use crate::gateway_execution::router::{Router, RouterPattern};
 use crate::repo::api_definition::ApiDefinitionRepo;
+use crate::repo::api_deployment::ApiDeploymentCertificateRecord;
 use crate::repo::api_deployment::ApiDeploymentRecord;
 use crate::repo::api_deployment::ApiDeploymentRepo;
 use crate::service::component::ComponentService;
@@ -77,6 +78,11 @@ pub trait ApiDeploymentService<AuthCtx, Namespace> {
         namespace: &Namespace,
         site: &ApiSiteString,
     ) -> Result<(), ApiDeploymentError<Namespace>>;
+
+    // Certificates of all the sites, used by the gateway to terminate TLS
+    async fn get_certificates(
+        &self,
+    ) -> Result<Vec<(ApiSiteString, ApiSiteCertificate)>, ApiDeploymentError<Namespace>>;
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -95,6 +101,8 @@ pub enum ApiDeploymentError<Namespace> {
     InternalConversionError { what: String, error: String },
     #[error("Internal error: failed to create component constraints {0}")]
     ComponentConstraintCreateError(String),
+    #[error("Invalid certificate: {0}")]
+    InvalidCertificate(String),
 }
 
 impl<T> ApiDeploymentError<T> {
@@ -122,6 +130,7 @@ impl<Namespace: Display> SafeDisplay for ApiDeploymentError<Namespace> {
             ApiDeploymentError::InternalRepoError(inner) => inner.to_safe_string(),
             ApiDeploymentError::InternalConversionError { .. } => self.to_string(),
             ApiDeploymentError::ComponentConstraintCreateError(_) => self.to_string(),
+            ApiDeploymentError::InvalidCertificate(_) => self.to_string(),
         }
     }
 }
@@ -214,6 +223,37 @@ impl<AuthCtx> ApiDeploymentServiceDefault<AuthCtx> {
         Ok(())
     }
 
+    // The certificate of a site is removed together with its last deployment
+    async fn delete_certificate_if_undeployed<Namespace>(
+        &self,
+        namespace: &str,
+        site: &str,
+    ) -> Result<(), ApiDeploymentError<Namespace>> {
+        let remaining_deployments = self.deployment_repo.get_by_site(site).await?;
+
+        if remaining_deployments.is_empty() {
+            self.deployment_repo
+                .delete_certificate(namespace, site)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // Only the certificate chain, the private key is not part of the deployment
+    async fn get_certificate<Namespace: Display>(
+        &self,
+        namespace: &Namespace,
+        site: &ApiSite,
+    ) -> Result<Option<String>, ApiDeploymentError<Namespace>> {
+        let certificate = self
+            .deployment_repo
+            .get_certificate(namespace.to_string().as_str(), site.to_string().as_str())
+            .await?;
+
+        Ok(certificate.map(|record| record.certificate))
+    }
+
     fn get_worker_functions_in_api_definitions<Namespace>(
         definitions: Vec<CompiledHttpApiDefinition<Namespace>>,
     ) -> Result<HashMap<ComponentId, FunctionConstraintCollection>, ApiDeploymentError<Namespace>>
@@ -223,13 +263,24 @@ impl<AuthCtx> ApiDeploymentServiceDefault<AuthCtx> {
         for definition in definitions {
             for route in definition.routes {
                 if let GatewayBindingCompiled::Worker(worker_binding) = route.binding {
-                    let component_id = worker_binding.component_id;
+                    // Traffic split targets are invoked with the same worker calls
+                    let mut component_ids = vec![worker_binding.component_id.component_id];
+                    if let Some(traffic_split) = worker_binding.traffic_split {
+                        for target in traffic_split.component_ids() {
+                            if !component_ids.contains(&target.component_id) {
+                                component_ids.push(target.component_id);
+                            }
+                        }
+                    }
+
                     let worker_calls = worker_binding.response_compiled.worker_calls;
                     if let Some(worker_calls) = worker_calls {
-                        worker_functions_in_rib
-                            .entry(component_id.component_id)
-                            .or_insert_with(Vec::new)
-                            .push(worker_calls)
+                        for component_id in component_ids {
+                            worker_functions_in_rib
+                                .entry(component_id)
+                                .or_insert_with(Vec::new)
+                                .push(worker_calls.clone())
+                        }
                     }
                 }
             }
@@ -276,6 +327,12 @@ where
     ) -> Result<(), ApiDeploymentError<Namespace>> {
         info!(namespace = %deployment.namespace, "Deploy API definitions");
 
+        if let Some(certificate) = &deployment.certificate {
+            certificate
+                .validate(ApiSiteString::from(&deployment.site).server_name())
+                .map_err(ApiDeploymentError::InvalidCertificate)?;
+        }
+
         let created_at = Utc::now();
 
         // Existing deployment
@@ -382,38 +439,60 @@ where
             Err(ApiDeploymentError::ApiDefinitionsConflict(
                 conflicting_definitions,
             ))
-        } else if !new_deployment_records.is_empty() {
-            for api_definition_key in set_not_draft {
-                info!(namespace = %deployment.namespace,
-                    "Set API definition as not draft - definition id: {}, definition version: {}",
-                    api_definition_key.id, api_definition_key.version
-                );
-
-                self.definition_repo
-                    .set_draft(
-                        deployment.namespace.to_string().as_str(),
-                        api_definition_key.id.0.as_str(),
-                        api_definition_key.version.0.as_str(),
-                        false,
-                    )
-                    .await?;
+        } else {
+            // The certificate is stored first, such that a site is never deployed with a
+            // rejected certificate
+            if let Some(certificate) = &deployment.certificate {
+                info!(namespace = %deployment.namespace, "Set certificate of site {}", deployment.site);
+
+                self.deployment_repo
+                    .create_or_update_certificate(&ApiDeploymentCertificateRecord::new(
+                        deployment.namespace.clone(),
+                        deployment.site.clone(),
+                        certificate.clone(),
+                        created_at,
+                    ))
+                    .await
+                    .map_err(|err| match err {
+                        RepoError::UniqueViolation(_) => ApiDeploymentError::ApiDeploymentConflict(
+                            ApiSiteString::from(&deployment.site),
+                        ),
+                        err => err.into(),
+                    })?;
             }
 
-            let constraints =
-                Self::get_worker_functions_in_api_definitions(new_definitions.clone())?;
+            if !new_deployment_records.is_empty() {
+                for api_definition_key in set_not_draft {
+                    info!(namespace = %deployment.namespace,
+                        "Set API definition as not draft - definition id: {}, definition version: {}",
+                        api_definition_key.id, api_definition_key.version
+                    );
+
+                    self.definition_repo
+                        .set_draft(
+                            deployment.namespace.to_string().as_str(),
+                            api_definition_key.id.0.as_str(),
+                            api_definition_key.version.0.as_str(),
+                            false,
+                        )
+                        .await?;
+                }
 
-            for (component_id, constraints) in constraints {
-                self.component_service
-                    .create_or_update_constraints(&component_id, constraints, auth_ctx)
-                    .await
-                    .map_err(|err| {
-                        ApiDeploymentError::ComponentConstraintCreateError(err.to_safe_string())
-                    })?;
+                let constraints =
+                    Self::get_worker_functions_in_api_definitions(new_definitions.clone())?;
+
+                for (component_id, constraints) in constraints {
+                    self.component_service
+                        .create_or_update_constraints(&component_id, constraints, auth_ctx)
+                        .await
+                        .map_err(|err| {
+                            ApiDeploymentError::ComponentConstraintCreateError(err.to_safe_string())
+                        })?;
+                }
+
+                self.deployment_repo.create(new_deployment_records).await?;
             }
 
-            self.deployment_repo.create(new_deployment_records).await?;
-            Ok(())
-        } else {
             Ok(())
         }
     }
@@ -466,6 +545,12 @@ where
 
             self.set_undeployed_as_draft(remove_deployment_records)
                 .await?;
+
+            self.delete_certificate_if_undeployed(
+                deployment.namespace.to_string().as_str(),
+                deployment.site.to_string().as_str(),
+            )
+            .await?;
         }
 
         Ok(())
@@ -510,10 +595,13 @@ where
                     val.api_definition_keys.push(api_definition_key);
                 }
                 None => {
+                    let certificate = self.get_certificate(&namespace, &site).await?;
+
                     values.push(ApiDeployment {
                         site,
                         namespace,
                         api_definition_keys: vec![api_definition_key],
+                        certificate,
                         created_at: deployment_record.created_at,
                     });
                 }
@@ -572,12 +660,17 @@ where
         }
 
         match (site, namespace, created_at) {
-            (Some(site), Some(namespace), Some(created_at)) => Ok(Some(ApiDeployment {
-                namespace,
-                site,
-                api_definition_keys,
-                created_at,
-            })),
+            (Some(site), Some(namespace), Some(created_at)) => {
+                let certificate = self.get_certificate(&namespace, &site).await?;
+
+                Ok(Some(ApiDeployment {
+                    namespace,
+                    site,
+                    api_definition_keys,
+                    certificate,
+                    created_at,
+                }))
+            }
             _ => Ok(None),
         }
     }
@@ -638,9 +731,24 @@ where
             self.set_undeployed_as_draft(existing_deployment_records)
                 .await?;
 
+            self.deployment_repo
+                .delete_certificate(namespace.to_string().as_str(), site.to_string().as_str())
+                .await?;
+
             Ok(())
         }
     }
+
+    async fn get_certificates(
+        &self,
+    ) -> Result<Vec<(ApiSiteString, ApiSiteCertificate)>, ApiDeploymentError<Namespace>> {
+        let records = self.deployment_repo.get_all_certificates().await?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| (ApiSiteString(record.site.clone()), record.into()))
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -5,7 +5,8 @@ use golem_common::SafeDisplay;
 use golem_service_base::model::{Component, VersionedComponentId};
 use serde::{Deserialize, Serialize};
 
-use crate::gateway_api_definition::http::{HttpApiDefinition, MethodPattern, Route};
+use crate::gateway_api_definition::http::{HttpApiDefinition, MethodPattern, PathPattern, Route};
+use crate::gateway_binding::GatewayBinding;
 
 use crate::gateway_execution::router::{Router, RouterPattern};
 use crate::service::gateway::api_definition_transformer::ApiDefTransformationError;
@@ -66,7 +67,14 @@ impl ApiDefinitionValidatorService<HttpApiDefinition, RouteValidationError>
         api: &HttpApiDefinition,
         _components: &[Component],
     ) -> Result<(), ValidationErrors<RouteValidationError>> {
-        let errors = unique_routes(api.routes.as_slice());
+        let errors = [
+            unique_routes(api.routes.as_slice()),
+            cacheable_routes(api.routes.as_slice()),
+            stream_routes(api.routes.as_slice()),
+            traffic_split_routes(api.routes.as_slice()),
+            rewritten_routes(api.routes.as_slice()),
+        ]
+        .concat();
 
         if errors.is_empty() {
             Ok(())
@@ -108,16 +116,141 @@ fn unique_routes(routes: &[Route]) -> Vec<RouteValidationError> {
     errors
 }
 
+// Only the responses of GET routes with a worker binding are cached,
+// as other requests are expected to change the state of the worker
+fn cacheable_routes(routes: &[Route]) -> Vec<RouteValidationError> {
+    routes
+        .iter()
+        .filter_map(|route| {
+            let (worker_binding, is_default_binding) = match &route.binding {
+                GatewayBinding::Default(worker_binding) => (worker_binding, true),
+                GatewayBinding::FileServer(worker_binding)
+                | GatewayBinding::Stream(worker_binding) => (worker_binding, false),
+                GatewayBinding::Static(_) => return None,
+            };
+
+            let has_response_cache = worker_binding.response_cache.is_some();
+
+            if has_response_cache && (route.method != MethodPattern::Get || !is_default_binding) {
+                Some(RouteValidationError {
+                    method: route.method.clone(),
+                    path: route.path.to_string(),
+                    component: Some(worker_binding.component_id.clone()),
+                    detail: "Response cache is only supported for GET routes with a default worker binding".to_string(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// A stream binding connects to a single worker, which is why it requires a worker name,
+// and WebSocket upgrades (as well as server-sent events) are GET requests
+fn stream_routes(routes: &[Route]) -> Vec<RouteValidationError> {
+    routes
+        .iter()
+        .filter_map(|route| match &route.binding {
+            GatewayBinding::Stream(worker_binding) => {
+                let detail = if route.method != MethodPattern::Get {
+                    Some("Stream binding is only supported for GET routes")
+                } else if worker_binding.worker_name.is_none() {
+                    Some("Stream binding requires a worker name")
+                } else {
+                    None
+                };
+
+                detail.map(|detail| RouteValidationError {
+                    method: route.method.clone(),
+                    path: route.path.to_string(),
+                    component: Some(worker_binding.component_id.clone()),
+                    detail: detail.to_string(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn traffic_split_routes(routes: &[Route]) -> Vec<RouteValidationError> {
+    routes
+        .iter()
+        .filter_map(|route| {
+            let worker_binding = route.binding.get_worker_binding()?;
+            let traffic_split = worker_binding.traffic_split.as_ref()?;
+
+            traffic_split
+                .validate(&worker_binding.component_id)
+                .err()
+                .map(|detail| RouteValidationError {
+                    method: route.method.clone(),
+                    path: route.path.to_string(),
+                    component: Some(worker_binding.component_id.clone()),
+                    detail,
+                })
+        })
+        .collect()
+}
+
+// The variables of a rewrite path are taken from the path variables of the route
+fn rewritten_routes(routes: &[Route]) -> Vec<RouteValidationError> {
+    routes
+        .iter()
+        .filter_map(|route| {
+            let worker_binding = route.binding.get_worker_binding()?;
+            let rewrite_path = worker_binding
+                .middleware
+                .as_ref()
+                .and_then(|middlewares| middlewares.get_transformation())?
+                .get_rewrite_path()?;
+
+            let missing_variables: Vec<String> = rewrite_path
+                .path_patterns
+                .iter()
+                .filter_map(|pattern| match pattern {
+                    PathPattern::Var(var)
+                        if !route
+                            .path
+                            .path_patterns
+                            .contains(&PathPattern::Var(var.clone())) =>
+                    {
+                        Some(var.key_name.clone())
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            if missing_variables.is_empty() {
+                None
+            } else {
+                Some(RouteValidationError {
+                    method: route.method.clone(),
+                    path: route.path.to_string(),
+                    component: Some(worker_binding.component_id.clone()),
+                    detail: format!(
+                        "Rewrite path has variables that are not path variables of the route: {}",
+                        missing_variables.join(", ")
+                    ),
+                })
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use test_r::test;
 
     use crate::gateway_api_definition::http::{MethodPattern, Route};
-    use crate::gateway_binding::{GatewayBinding, ResponseMapping};
-    use crate::service::gateway::http_api_definition_validator::unique_routes;
+    use crate::gateway_binding::{GatewayBinding, ResponseCache, ResponseMapping};
+    use crate::gateway_middleware::{Middleware, Middlewares, Transformation};
+    use crate::service::gateway::http_api_definition_validator::{
+        cacheable_routes, rewritten_routes, stream_routes, unique_routes,
+    };
     use golem_common::model::ComponentId;
     use golem_service_base::model::VersionedComponentId;
     use rib::Expr;
+    use std::collections::BTreeMap;
 
     #[test]
     fn test_unique_routes() {
@@ -134,6 +267,8 @@ mod tests {
                     idempotency_key: None,
                     response_mapping: ResponseMapping(Expr::literal("sample")),
                     middleware: None,
+                    traffic_split: None,
+                    response_cache: None,
                 }),
             }
         }
@@ -167,4 +302,113 @@ mod tests {
         assert!(errors.len() == 1);
         assert!(errors[0].detail.contains(paths[0]), "Received: {errors:?}");
     }
+
+    #[test]
+    fn test_cacheable_routes() {
+        fn make_route(method: MethodPattern) -> Route {
+            let response_cache =
+                ResponseCache::from_parameters("request.path.user".to_string(), 60).unwrap();
+
+            Route {
+                method,
+                path: crate::gateway_api_definition::http::AllPathPatterns::parse("/users/{user}")
+                    .unwrap(),
+                binding: GatewayBinding::Default(crate::gateway_binding::WorkerBinding {
+                    component_id: VersionedComponentId {
+                        component_id: ComponentId::new_v4(),
+                        version: 1,
+                    },
+                    worker_name: Some(Expr::identifier("request")),
+                    idempotency_key: None,
+                    response_mapping: ResponseMapping(Expr::literal("sample")),
+                    middleware: None,
+                    traffic_split: None,
+                    response_cache: Some(response_cache),
+                }),
+            }
+        }
+
+        let errors = cacheable_routes(&[make_route(MethodPattern::Get)]);
+        assert!(errors.is_empty());
+
+        let errors = cacheable_routes(&[make_route(MethodPattern::Post)]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].method, MethodPattern::Post);
+    }
+
+    #[test]
+    fn test_stream_routes() {
+        fn make_route(method: MethodPattern, worker_name: Option<Expr>) -> Route {
+            Route {
+                method,
+                path: crate::gateway_api_definition::http::AllPathPatterns::parse("/chat/{user}")
+                    .unwrap(),
+                binding: GatewayBinding::Stream(crate::gateway_binding::WorkerBinding {
+                    component_id: VersionedComponentId {
+                        component_id: ComponentId::new_v4(),
+                        version: 1,
+                    },
+                    worker_name,
+                    idempotency_key: None,
+                    response_mapping: ResponseMapping(Expr::literal("sample")),
+                    middleware: None,
+                    traffic_split: None,
+                    response_cache: None,
+                }),
+            }
+        }
+
+        let errors = stream_routes(&[make_route(MethodPattern::Get, Some(Expr::literal("chat")))]);
+        assert!(errors.is_empty());
+
+        let errors = stream_routes(&[
+            make_route(MethodPattern::Post, Some(Expr::literal("chat"))),
+            make_route(MethodPattern::Get, None),
+        ]);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].method, MethodPattern::Post);
+        assert_eq!(errors[1].method, MethodPattern::Get);
+    }
+
+    #[test]
+    fn test_rewritten_routes() {
+        fn make_route(rewrite_path: &str) -> Route {
+            let transformation = Transformation::from_parameters(
+                BTreeMap::new(),
+                vec![],
+                Some(rewrite_path.to_string()),
+                None,
+                BTreeMap::new(),
+                vec![],
+            )
+            .unwrap();
+
+            Route {
+                method: MethodPattern::Get,
+                path: crate::gateway_api_definition::http::AllPathPatterns::parse("/users/{user}")
+                    .unwrap(),
+                binding: GatewayBinding::Default(crate::gateway_binding::WorkerBinding {
+                    component_id: VersionedComponentId {
+                        component_id: ComponentId::new_v4(),
+                        version: 1,
+                    },
+                    worker_name: Some(Expr::identifier("request")),
+                    idempotency_key: None,
+                    response_mapping: ResponseMapping(Expr::literal("sample")),
+                    middleware: Some(Middlewares(vec![Middleware::transformation(
+                        &transformation,
+                    )])),
+                    traffic_split: None,
+                    response_cache: None,
+                }),
+            }
+        }
+
+        let errors = rewritten_routes(&[make_route("/v2/users/{user}")]);
+        assert!(errors.is_empty());
+
+        let errors = rewritten_routes(&[make_route("/v2/users/{user}/posts/{post}")]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].detail.contains("post"), "Received: {errors:?}");
+    }
 }
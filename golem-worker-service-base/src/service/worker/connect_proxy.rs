@@ -17,6 +17,8 @@ use std::{
     time::Duration,
 };
 
+use futures::future::BoxFuture;
+use futures::stream::FuturesUnordered;
 use futures::{Sink, SinkExt, Stream, StreamExt};
 use golem_api_grpc::proto::golem::worker::LogEvent;
 use golem_common::model::{WorkerEvent, WorkerId};
@@ -31,7 +33,7 @@ use tracing::{error, info};
 #[tracing::instrument(skip_all, fields(worker_id = worker_id.to_string()))]
 pub async fn proxy_worker_connection(
     worker_id: WorkerId,
-    mut worker_stream: impl Stream<Item = Result<LogEvent, Status>> + Unpin,
+    worker_stream: impl Stream<Item = Result<LogEvent, Status>> + Unpin,
     websocket_sender: impl Sink<Message, Error = IoError> + Unpin,
     websocket_receiver: impl Stream<Item = IoResult<Message>> + Unpin,
     keep_alive_interval: Duration,
@@ -39,6 +41,58 @@ pub async fn proxy_worker_connection(
 ) -> Result<(), ConnectProxyError> {
     info!("Proxying worker connection");
 
+    proxy(
+        worker_stream,
+        websocket_sender,
+        websocket_receiver,
+        keep_alive_interval,
+        max_pong_timeout,
+        |_| None,
+    )
+    .await
+}
+
+/// Proxies a worker connection like `proxy_worker_connection`, additionally handing each text message
+/// of the client to `on_message`. Replies are sent back to the client as soon as they are available,
+/// such that worker events are forwarded while a message is being handled. At most
+/// `MAX_IN_FLIGHT_MESSAGES` messages are handled concurrently, further ones wait in the socket.
+#[tracing::instrument(skip_all, fields(worker_id = worker_id.to_string()))]
+pub async fn proxy_worker_connection_with_messages(
+    worker_id: WorkerId,
+    worker_stream: impl Stream<Item = Result<LogEvent, Status>> + Unpin,
+    websocket_sender: impl Sink<Message, Error = IoError> + Unpin,
+    websocket_receiver: impl Stream<Item = IoResult<Message>> + Unpin,
+    keep_alive_interval: Duration,
+    max_pong_timeout: Duration,
+    on_message: impl FnMut(String) -> BoxFuture<'static, String>,
+) -> Result<(), ConnectProxyError> {
+    info!("Proxying worker connection with messages");
+
+    let mut on_message = on_message;
+
+    proxy(
+        worker_stream,
+        websocket_sender,
+        websocket_receiver,
+        keep_alive_interval,
+        max_pong_timeout,
+        move |message| Some(on_message(message)),
+    )
+    .await
+}
+
+/// Maximum number of client messages being handled at the same time on a connection.
+/// Once reached, no further messages are read from the client until a reply has been sent.
+const MAX_IN_FLIGHT_MESSAGES: usize = 16;
+
+async fn proxy(
+    mut worker_stream: impl Stream<Item = Result<LogEvent, Status>> + Unpin,
+    websocket_sender: impl Sink<Message, Error = IoError> + Unpin,
+    websocket_receiver: impl Stream<Item = IoResult<Message>> + Unpin,
+    keep_alive_interval: Duration,
+    max_pong_timeout: Duration,
+    mut on_message: impl FnMut(String) -> Option<BoxFuture<'static, String>>,
+) -> Result<(), ConnectProxyError> {
     let mut websocket = keep_alive::WebSocketKeepAlive::from_sink_and_stream(
         websocket_receiver,
         websocket_sender,
@@ -46,11 +100,13 @@ pub async fn proxy_worker_connection(
         max_pong_timeout,
     );
 
+    let mut replies = FuturesUnordered::new();
+
     let result = loop {
         tokio::select! {
             // WebSocket is cancellation safe.
             // https://github.com/snapview/tokio-tungstenite/issues/167
-            websocket_message = websocket.next() => {
+            websocket_message = websocket.next(), if replies.len() < MAX_IN_FLIGHT_MESSAGES => {
                 match websocket_message {
                     Some(Ok(Message::Close(payload))) => {
                         info!(
@@ -65,6 +121,11 @@ pub async fn proxy_worker_connection(
                         info!(error=error.to_string(), "Received WebSocket Error");
                         break Err(error);
                     },
+                    Some(Ok(Message::Text(message))) => {
+                        if let Some(reply) = on_message(message) {
+                            replies.push(reply);
+                        }
+                    }
                     Some(Ok(_)) => {
                     }
                     None => {
@@ -74,6 +135,14 @@ pub async fn proxy_worker_connection(
                 }
             },
 
+            Some(reply) = replies.next(), if !replies.is_empty() => {
+                if let Err(error) = websocket.send(Message::Text(reply)).await {
+                    let error: ConnectProxyError = error.into();
+                    info!(error=error.to_string(), "Error sending reply to WebSocket client");
+                    break Err(error)
+                }
+            },
+
             worker_message = worker_stream.next() => {
                 if let Some(message) = worker_message {
                     if let Err(error) = forward_worker_message(message, &mut websocket).await {
@@ -427,3 +496,56 @@ mod keep_alive {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use test_r::test;
+
+    use super::*;
+    use futures::FutureExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use tokio_util::sync::PollSender;
+
+    #[test]
+    async fn test_in_flight_messages_are_bounded() {
+        let (client_tx, client_rx) = mpsc::channel::<IoResult<Message>>(64);
+        let (server_tx, _server_rx) = mpsc::channel::<Message>(64);
+
+        let handled = Arc::new(AtomicUsize::new(0));
+
+        let proxy = tokio::spawn({
+            let handled = handled.clone();
+            proxy_worker_connection_with_messages(
+                WorkerId {
+                    component_id: golem_common::model::ComponentId::new_v4(),
+                    worker_name: "test".to_string(),
+                },
+                futures::stream::pending(),
+                PollSender::new(server_tx).sink_map_err(|_| IoError::other("closed")),
+                ReceiverStream::new(client_rx),
+                Duration::from_secs(60),
+                Duration::from_secs(60),
+                move |_| {
+                    handled.fetch_add(1, Ordering::SeqCst);
+                    // Never replies
+                    futures::future::pending().boxed()
+                },
+            )
+        });
+
+        for i in 0..MAX_IN_FLIGHT_MESSAGES + 4 {
+            client_tx
+                .send(Ok(Message::Text(i.to_string())))
+                .await
+                .unwrap();
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        proxy.abort();
+
+        assert_eq!(handled.load(Ordering::SeqCst), MAX_IN_FLIGHT_MESSAGES);
+    }
+}
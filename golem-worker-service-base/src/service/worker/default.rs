@@ -27,17 +27,21 @@ use golem_api_grpc::proto::golem::worker::{InvocationContext, InvokeResult};
 use golem_api_grpc::proto::golem::workerexecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_client::WorkerExecutorClient;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    CompletePromiseRequest, ConnectWorkerRequest, CreateWorkerRequest, InterruptWorkerRequest,
-    InvokeAndAwaitWorkerRequest, ResumeWorkerRequest, SearchOplogResponse, UpdateWorkerRequest,
+    CancelInvocationRequest, CompletePromiseRequest, ConnectWorkerRequest,
+    CreateScheduledInvocationRequest, CreateWorkerRequest, DeleteScheduledInvocationRequest,
+    ExportWorkerRequest, ForkWorkerRequest, GetScheduledInvocationsRequest, ImportWorkerRequest,
+    InterruptWorkerRequest, InvokeAndAwaitWorkerRequest, ResumeWorkerRequest, RevertWorkerRequest,
+    SearchOplogResponse, UpdateScheduledInvocationRequest, UpdateWorkerRequest,
 };
 use golem_common::client::MultiTargetGrpcClient;
 use golem_common::config::RetryConfig;
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
+use golem_common::model::scheduled_invocation::{Recurrence, ScheduledInvocation};
 use golem_common::model::{
     AccountId, ComponentFilePath, ComponentFileSystemNode, ComponentId, ComponentVersion,
-    FilterComparator, IdempotencyKey, PromiseId, ScanCursor, TargetWorkerId, WorkerFilter,
-    WorkerId, WorkerStatus,
+    FilterComparator, IdempotencyKey, PromiseId, RevertWorkerTarget, ScanCursor, TargetWorkerId,
+    Timestamp, WorkerFilter, WorkerId, WorkerStatus,
 };
 use golem_service_base::model::{Component, GolemError};
 use golem_service_base::model::{
@@ -134,7 +138,8 @@ pub trait WorkerService<AuthCtx> {
     ) -> WorkerResult<InvokeResult>;
 
     /// Validates the provided list of `TypeAnnotatedValue` parameters, and then enqueues
-    /// an invocation for the worker without awaiting its results.
+    /// an invocation for the worker without awaiting its results. When `schedule_at` is set,
+    /// the invocation is only enqueued at that time.
     async fn validate_and_invoke(
         &self,
         worker_id: &TargetWorkerId,
@@ -142,6 +147,7 @@ pub trait WorkerService<AuthCtx> {
         function_name: String,
         params: Vec<TypeAnnotatedValue>,
         invocation_context: Option<InvocationContext>,
+        schedule_at: Option<Timestamp>,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<()> {
         let params = self.validate_typed_parameters(params)?;
@@ -151,13 +157,14 @@ pub trait WorkerService<AuthCtx> {
             function_name,
             params,
             invocation_context,
+            schedule_at,
             metadata,
         )
         .await
     }
 
     /// Enqueues an invocation for the worker without awaiting its results, using raw `Val`
-    /// parameters.
+    /// parameters. When `schedule_at` is set, the invocation is only enqueued at that time.
     async fn invoke(
         &self,
         worker_id: &TargetWorkerId,
@@ -165,6 +172,7 @@ pub trait WorkerService<AuthCtx> {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        schedule_at: Option<Timestamp>,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<()>;
 
@@ -246,6 +254,87 @@ pub trait WorkerService<AuthCtx> {
         auth_ctx: &AuthCtx,
     ) -> Result<GetOplogResponse, WorkerServiceError>;
 
+    async fn fork(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn revert(
+        &self,
+        worker_id: &WorkerId,
+        target: RevertWorkerTarget,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn cancel_invocation(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn export_worker(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<u8>>;
+
+    async fn import_worker(
+        &self,
+        worker_id: &WorkerId,
+        archive: Vec<u8>,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    /// Creates a named scheduled invocation. If `start_at` is not given, the first tick is
+    /// calculated from the recurrence.
+    async fn create_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: String,
+        function_name: String,
+        params: Vec<ProtoVal>,
+        recurrence: Recurrence,
+        start_at: Option<Timestamp>,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ScheduledInvocation>;
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<ScheduledInvocation>>;
+
+    async fn update_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: String,
+        function_name: String,
+        params: Vec<ProtoVal>,
+        recurrence: Recurrence,
+        start_at: Option<Timestamp>,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ScheduledInvocation>;
+
+    async fn delete_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: String,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
     async fn list_directory(
         &self,
         worker_id: &TargetWorkerId,
@@ -581,6 +670,7 @@ where
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        schedule_at: Option<Timestamp>,
         metadata: WorkerRequestMetadata,
     ) -> WorkerResult<()> {
         let worker_id = worker_id.clone();
@@ -599,6 +689,7 @@ where
                         account_id: metadata.account_id.clone().map(|id| id.into()),
                         account_limits: metadata.limits.clone().map(|id| id.into()),
                         context: invocation_context.clone(),
+                        schedule_at: schedule_at.map(|ts| ts.into()),
                     },
                 ))
             },
@@ -1008,6 +1099,441 @@ where
             .await
     }
 
+    async fn fork(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let source_worker_id = source_worker_id.clone();
+        let target_worker_id = target_worker_id.clone();
+        self.call_worker_executor(
+            target_worker_id.clone(),
+            "fork_worker",
+            move |worker_executor_client| {
+                info!("Fork worker");
+                let source_worker_id = source_worker_id.clone();
+                let target_worker_id = target_worker_id.clone();
+                Box::pin(worker_executor_client.fork_worker(ForkWorkerRequest {
+                    source_worker_id: Some(source_worker_id.into()),
+                    target_worker_id: Some(target_worker_id.into()),
+                    account_id: metadata.account_id.clone().map(|id| id.into()),
+                    oplog_index_cutoff: oplog_index_cutoff.into(),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ForkWorkerResponse {
+                    result: Some(workerexecutor::v1::fork_worker_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::ForkWorkerResponse {
+                    result: Some(workerexecutor::v1::fork_worker_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::ForkWorkerResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn revert(
+        &self,
+        worker_id: &WorkerId,
+        target: RevertWorkerTarget,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "revert_worker",
+            move |worker_executor_client| {
+                info!("Revert worker");
+                let worker_id = worker_id.clone();
+                let target = target.clone();
+                Box::pin(worker_executor_client.revert_worker(RevertWorkerRequest {
+                    worker_id: Some(worker_id.into()),
+                    account_id: metadata.account_id.clone().map(|id| id.into()),
+                    target: Some(target.into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::RevertWorkerResponse {
+                    result: Some(workerexecutor::v1::revert_worker_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::RevertWorkerResponse {
+                    result: Some(workerexecutor::v1::revert_worker_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::RevertWorkerResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn cancel_invocation(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let idempotency_key = idempotency_key.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "cancel_invocation",
+            move |worker_executor_client| {
+                info!("Cancel invocation");
+                let worker_id = worker_id.clone();
+                let idempotency_key = idempotency_key.clone();
+                Box::pin(
+                    worker_executor_client.cancel_invocation(CancelInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        idempotency_key: Some(idempotency_key.into()),
+                    }),
+                )
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::CancelInvocationResponse {
+                    result:
+                        Some(workerexecutor::v1::cancel_invocation_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::CancelInvocationResponse {
+                    result:
+                        Some(workerexecutor::v1::cancel_invocation_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::CancelInvocationResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn export_worker(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<u8>> {
+        let worker_id = worker_id.clone();
+        let archive = self
+            .call_worker_executor(
+                worker_id.clone(),
+                "export_worker",
+                move |worker_executor_client| {
+                    info!("Export worker");
+                    let worker_id = worker_id.clone();
+                    Box::pin(worker_executor_client.export_worker(ExportWorkerRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                    }))
+                },
+                |response| match response.into_inner() {
+                    workerexecutor::v1::ExportWorkerResponse {
+                        result:
+                            Some(workerexecutor::v1::export_worker_response::Result::Success(
+                                archive,
+                            )),
+                    } => Ok(archive),
+                    workerexecutor::v1::ExportWorkerResponse {
+                        result:
+                            Some(workerexecutor::v1::export_worker_response::Result::Failure(err)),
+                    } => Err(err.into()),
+                    workerexecutor::v1::ExportWorkerResponse { .. } => {
+                        Err("Empty response".into())
+                    }
+                },
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+        Ok(archive)
+    }
+
+    async fn import_worker(
+        &self,
+        worker_id: &WorkerId,
+        archive: Vec<u8>,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "import_worker",
+            move |worker_executor_client| {
+                info!("Import worker");
+                let worker_id = worker_id.clone();
+                let archive = archive.clone();
+                Box::pin(worker_executor_client.import_worker(ImportWorkerRequest {
+                    worker_id: Some(worker_id.into()),
+                    account_id: metadata.account_id.clone().map(|id| id.into()),
+                    archive,
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ImportWorkerResponse {
+                    result: Some(workerexecutor::v1::import_worker_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::ImportWorkerResponse {
+                    result: Some(workerexecutor::v1::import_worker_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::ImportWorkerResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn create_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: String,
+        function_name: String,
+        params: Vec<ProtoVal>,
+        recurrence: Recurrence,
+        start_at: Option<Timestamp>,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ScheduledInvocation> {
+        let worker_id = worker_id.clone();
+        let invocation = golem_api_grpc::proto::golem::worker::ScheduledInvocation {
+            name,
+            function_name,
+            input: params,
+            recurrence: Some(recurrence.into()),
+            next_fire_at: start_at.map(|ts| ts.into()),
+            idempotency_key: None,
+            failed_attempts: 0,
+            last_error: None,
+        };
+        let invocation = self
+            .call_worker_executor(
+                worker_id.clone(),
+                "create_scheduled_invocation",
+                move |worker_executor_client| {
+                    info!("Create scheduled invocation");
+                    let worker_id = worker_id.clone();
+                    let invocation = invocation.clone();
+                    Box::pin(worker_executor_client.create_scheduled_invocation(
+                        CreateScheduledInvocationRequest {
+                            worker_id: Some(worker_id.into()),
+                            account_id: metadata.account_id.clone().map(|id| id.into()),
+                            invocation: Some(invocation),
+                        },
+                    ))
+                },
+                |response| match response.into_inner() {
+                    workerexecutor::v1::CreateScheduledInvocationResponse {
+                        result:
+                            Some(
+                                workerexecutor::v1::create_scheduled_invocation_response::Result::Success(
+                                    invocation,
+                                ),
+                            ),
+                    } => Ok(invocation.try_into().map_err(|err| {
+                        GolemError::Unknown(GolemErrorUnknown {
+                            details: format!("Unexpected scheduled invocation: {err}"),
+                        })
+                    })?),
+                    workerexecutor::v1::CreateScheduledInvocationResponse {
+                        result:
+                            Some(
+                                workerexecutor::v1::create_scheduled_invocation_response::Result::Failure(
+                                    err,
+                                ),
+                            ),
+                    } => Err(err.into()),
+                    workerexecutor::v1::CreateScheduledInvocationResponse { .. } => {
+                        Err("Empty response".into())
+                    }
+                },
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+        Ok(invocation)
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<ScheduledInvocation>> {
+        let worker_id = worker_id.clone();
+        let invocations = self
+            .call_worker_executor(
+                worker_id.clone(),
+                "get_scheduled_invocations",
+                move |worker_executor_client| {
+                    info!("Get scheduled invocations");
+                    let worker_id = worker_id.clone();
+                    Box::pin(worker_executor_client.get_scheduled_invocations(
+                        GetScheduledInvocationsRequest {
+                            worker_id: Some(worker_id.into()),
+                            account_id: metadata.account_id.clone().map(|id| id.into()),
+                        },
+                    ))
+                },
+                |response| match response.into_inner() {
+                    workerexecutor::v1::GetScheduledInvocationsResponse {
+                        result:
+                            Some(
+                                workerexecutor::v1::get_scheduled_invocations_response::Result::Success(
+                                    success,
+                                ),
+                            ),
+                    } => Ok(success
+                        .invocations
+                        .into_iter()
+                        .map(|invocation| invocation.try_into())
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|err| {
+                            GolemError::Unknown(GolemErrorUnknown {
+                                details: format!("Unexpected scheduled invocation: {err}"),
+                            })
+                        })?),
+                    workerexecutor::v1::GetScheduledInvocationsResponse {
+                        result:
+                            Some(
+                                workerexecutor::v1::get_scheduled_invocations_response::Result::Failure(
+                                    err,
+                                ),
+                            ),
+                    } => Err(err.into()),
+                    workerexecutor::v1::GetScheduledInvocationsResponse { .. } => {
+                        Err("Empty response".into())
+                    }
+                },
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+        Ok(invocations)
+    }
+
+    async fn update_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: String,
+        function_name: String,
+        params: Vec<ProtoVal>,
+        recurrence: Recurrence,
+        start_at: Option<Timestamp>,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ScheduledInvocation> {
+        let worker_id = worker_id.clone();
+        let invocation = golem_api_grpc::proto::golem::worker::ScheduledInvocation {
+            name,
+            function_name,
+            input: params,
+            recurrence: Some(recurrence.into()),
+            next_fire_at: start_at.map(|ts| ts.into()),
+            idempotency_key: None,
+            failed_attempts: 0,
+            last_error: None,
+        };
+        let invocation = self
+            .call_worker_executor(
+                worker_id.clone(),
+                "update_scheduled_invocation",
+                move |worker_executor_client| {
+                    info!("Update scheduled invocation");
+                    let worker_id = worker_id.clone();
+                    let invocation = invocation.clone();
+                    Box::pin(worker_executor_client.update_scheduled_invocation(
+                        UpdateScheduledInvocationRequest {
+                            worker_id: Some(worker_id.into()),
+                            account_id: metadata.account_id.clone().map(|id| id.into()),
+                            invocation: Some(invocation),
+                        },
+                    ))
+                },
+                |response| match response.into_inner() {
+                    workerexecutor::v1::UpdateScheduledInvocationResponse {
+                        result:
+                            Some(
+                                workerexecutor::v1::update_scheduled_invocation_response::Result::Success(
+                                    invocation,
+                                ),
+                            ),
+                    } => Ok(invocation.try_into().map_err(|err| {
+                        GolemError::Unknown(GolemErrorUnknown {
+                            details: format!("Unexpected scheduled invocation: {err}"),
+                        })
+                    })?),
+                    workerexecutor::v1::UpdateScheduledInvocationResponse {
+                        result:
+                            Some(
+                                workerexecutor::v1::update_scheduled_invocation_response::Result::Failure(
+                                    err,
+                                ),
+                            ),
+                    } => Err(err.into()),
+                    workerexecutor::v1::UpdateScheduledInvocationResponse { .. } => {
+                        Err("Empty response".into())
+                    }
+                },
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+        Ok(invocation)
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        name: String,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "delete_scheduled_invocation",
+            move |worker_executor_client| {
+                info!("Delete scheduled invocation");
+                let worker_id = worker_id.clone();
+                let name = name.clone();
+                Box::pin(worker_executor_client.delete_scheduled_invocation(
+                    DeleteScheduledInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        name,
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::DeleteScheduledInvocationResponse {
+                    result:
+                        Some(workerexecutor::v1::delete_scheduled_invocation_response::Result::Success(
+                            _,
+                        )),
+                } => Ok(()),
+                workerexecutor::v1::DeleteScheduledInvocationResponse {
+                    result:
+                        Some(workerexecutor::v1::delete_scheduled_invocation_response::Result::Failure(
+                            err,
+                        )),
+                } => Err(err.into()),
+                workerexecutor::v1::DeleteScheduledInvocationResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn list_directory(
         &self,
         worker_id: &TargetWorkerId,
@@ -12,13 +12,18 @@ use golem_common::model::IdempotencyKey;
 use golem_worker_service_base::gateway_execution::gateway_binding_executor::{
     DefaultGatewayBindingExecutor, GatewayBindingExecutor,
 };
-use golem_worker_service_base::gateway_execution::gateway_binding_resolver::GatewayBindingResolver;
+use golem_worker_service_base::gateway_execution::gateway_binding_resolver::{
+    GatewayBindingResolver, GatewayBindingResolverError,
+};
+use golem_worker_service_base::gateway_execution::gateway_rate_limiter::DefaultGatewayRateLimiter;
+use golem_worker_service_base::gateway_execution::gateway_response_cache::DefaultGatewayResponseCache;
 use golem_worker_service_base::gateway_middleware::Cors;
 use golem_worker_service_base::gateway_request::http_request::{ApiInputPath, InputHttpRequest};
 use golem_worker_service_base::service::gateway::api_definition_transformer::ApiDefinitionTransformer;
 use golem_worker_service_base::{api, gateway_api_definition};
 use http::{HeaderMap, HeaderValue, Method};
 use serde_json::Value;
+use std::sync::Arc;
 
 // The tests that focus on end to end workflow of API Gateway, without involving any real workers,
 // and stays independent of other modules.
@@ -50,6 +55,11 @@ async fn execute(
     let test_executor = DefaultGatewayBindingExecutor::new(
         internal::get_test_rib_interpreter(),
         internal::get_test_file_server_binding_handler(),
+        Arc::new(DefaultGatewayRateLimiter::new()),
+        Arc::new(DefaultGatewayResponseCache::new(
+            internal::get_test_api_definition_cache_repo(),
+        )),
+        internal::get_test_worker_stream_binding_handler(),
     );
 
     let poem_response: poem::Response = test_executor.execute_binding(&resolved_route).await;
@@ -92,6 +102,48 @@ async fn test_end_to_end_api_gateway_simple_worker() {
     assert_eq!(result, expected);
 }
 
+#[test]
+async fn test_end_to_end_api_gateway_traffic_split() {
+    let empty_headers = HeaderMap::new();
+    let api_request = get_api_request("foo/1", None, &empty_headers, serde_json::Value::Null);
+
+    let worker_name = r#"
+      let id: u64 = request.path.user-id;
+      "shopping-cart-${id}"
+    "#;
+
+    let response_mapping = r#"
+      let response = golem:it/api.{get-cart-contents}("a", "b");
+      response
+    "#;
+
+    let api_specification: HttpApiDefinition =
+        get_api_spec_worker_binding("foo/{user-id}", worker_name, response_mapping);
+    let split_api_specification: HttpApiDefinition = get_api_spec_worker_binding_with_traffic_split(
+        "foo/{user-id}",
+        worker_name,
+        response_mapping,
+        internal::CANARY_COMPONENT_ID,
+        100,
+    );
+
+    let test_response = execute(&api_request, &api_specification).await;
+    let split_test_response = execute(&api_request, &split_api_specification).await;
+
+    assert_eq!(
+        test_response.get_component_id(),
+        Some("0b6d9cd8-f373-4e29-8a5a-548e61b868a5".to_string())
+    );
+    assert_eq!(
+        split_test_response.get_component_id(),
+        Some(internal::CANARY_COMPONENT_ID.to_string())
+    );
+    assert_eq!(
+        split_test_response.get_worker_name(),
+        Some("shopping-cart-1".to_string())
+    );
+}
+
 #[test]
 async fn test_end_to_end_api_gateway_cors_preflight() {
     let empty_headers = HeaderMap::new();
@@ -540,6 +592,56 @@ async fn test_api_gateway_rib_input_from_request_details() {
     test_paths("/getcartcontent/{cart-id}", "/getcartcontent/1", true).await;
 }
 
+#[test]
+async fn test_api_gateway_request_validation() {
+    let empty_headers = HeaderMap::new();
+    let api_request = get_api_request(
+        "/getcartcontent/foo",
+        None,
+        &empty_headers,
+        serde_json::json!({"quantity": "ten"}),
+    );
+
+    let worker_name = r#"
+        let x: u64 = request.path.cart-id;
+        "shopping-cart-${x}"
+    "#;
+
+    let response_mapping = r#"
+        let quantity: u32 = request.body.quantity;
+        let response = golem:it/api.{get-cart-contents}("foo", "bar");
+        response
+        "#;
+
+    let api_specification: HttpApiDefinition =
+        get_api_spec_worker_binding("/getcartcontent/{cart-id}", worker_name, response_mapping);
+
+    let compiled_api_spec = CompiledHttpApiDefinition::from_http_api_definition(
+        &api_specification,
+        &internal::get_component_metadata(),
+        &DefaultNamespace::default(),
+    )
+    .unwrap();
+
+    let result = api_request
+        .resolve_worker_binding(vec![compiled_api_spec])
+        .await;
+
+    let fields = match result {
+        Err(GatewayBindingResolverError::InvalidRequest(errors)) => errors
+            .errors
+            .into_iter()
+            .map(|error| error.field)
+            .collect::<Vec<_>>(),
+        other => panic!("Expected an invalid request, found {:?}", other.map(|_| ())),
+    };
+
+    assert_eq!(
+        fields,
+        vec!["path.cart-id".to_string(), "body.quantity".to_string()]
+    );
+}
+
 #[test]
 async fn test_api_gateway_idempotency_key_resolution() {
     async fn test_key(header_map: &HeaderMap, idempotency_key: Option<IdempotencyKey>) {
@@ -583,6 +685,67 @@ async fn test_api_gateway_idempotency_key_resolution() {
     test_key(&headers, Some(IdempotencyKey::new("bar".to_string()))).await;
 }
 
+#[test]
+async fn test_api_gateway_path_rewrite_and_request_headers_transformation() {
+    let api_request = get_api_request("/legacy/1", None, &HeaderMap::new(), Value::Null);
+
+    let yaml_string = r#"
+          id: users-api
+          version: 0.0.1
+          createdAt: 2024-08-21T07:42:15.696Z
+          routes:
+          - method: Get
+            path: legacy/{user-id}
+            binding:
+              type: wit-worker
+              componentId:
+                componentId: 0b6d9cd8-f373-4e29-8a5a-548e61b868a5
+                version: 0
+              workerName: '"legacy"'
+              response: '${let response = golem:it/api.{get-cart-contents}("a", "b"); response}'
+              middleware:
+                transformation:
+                  rewritePath: /carts/{user-id}
+                  addRequestHeaders:
+                    x-api-version: "2"
+          - method: Get
+            path: carts/{cart-id}
+            binding:
+              type: wit-worker
+              componentId:
+                componentId: 0b6d9cd8-f373-4e29-8a5a-548e61b868a5
+                version: 0
+              workerName: '${let id: u64 = request.path.cart-id; let version: u64 = request.headers.x-api-version; "shopping-cart-${id}-v${version}"}'
+              response: '${let response = golem:it/api.{get-cart-contents}("a", "b"); response}'
+        "#;
+
+    let http_api_definition_request: api::HttpApiDefinitionRequest =
+        serde_yaml::from_str(yaml_string).unwrap();
+
+    let core_request: gateway_api_definition::http::HttpApiDefinitionRequest =
+        http_api_definition_request.try_into().unwrap();
+
+    let create_at: DateTime<Utc> = "2024-08-21T07:42:15.696Z".parse().unwrap();
+    let api_specification = HttpApiDefinition::new(core_request, create_at);
+
+    let compiled_api_spec = CompiledHttpApiDefinition::from_http_api_definition(
+        &api_specification,
+        &internal::get_component_metadata(),
+        &DefaultNamespace::default(),
+    )
+    .unwrap();
+
+    let resolved_route = api_request
+        .resolve_worker_binding(vec![compiled_api_spec])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        resolved_route.get_worker_detail().unwrap().worker_name,
+        Some("shopping-cart-1-v2".to_string())
+    );
+}
+
 fn get_api_request(
     base_path: &str,
     query_path: Option<&str>,
@@ -597,6 +760,8 @@ fn get_api_request(
         headers: headers.clone(),
         req_method: Method::GET,
         req_body,
+        client_address: None,
+        websocket_upgrade: None,
     }
 }
 
@@ -614,6 +779,8 @@ fn get_preflight_api_request(
         headers: headers.clone(),
         req_method: Method::OPTIONS,
         req_body,
+        client_address: None,
+        websocket_upgrade: None,
     }
 }
 
@@ -653,6 +820,50 @@ fn get_api_spec_worker_binding(
     HttpApiDefinition::new(core_request, create_at)
 }
 
+fn get_api_spec_worker_binding_with_traffic_split(
+    path_pattern: &str,
+    worker_name: &str,
+    rib_expression: &str,
+    target_component_id: &str,
+    target_weight: u32,
+) -> HttpApiDefinition {
+    let yaml_string = format!(
+        r#"
+          id: users-api
+          version: 0.0.1
+          createdAt: 2024-08-21T07:42:15.696Z
+          routes:
+          - method: Get
+            path: {}
+            binding:
+              type: wit-worker
+              componentId:
+                componentId: 0b6d9cd8-f373-4e29-8a5a-548e61b868a5
+                version: 0
+              workerName: '{}'
+              response: '${{{}}}'
+              trafficSplit:
+                targets:
+                - componentId:
+                    componentId: {}
+                    version: 0
+                  weight: {}
+
+        "#,
+        path_pattern, worker_name, rib_expression, target_component_id, target_weight
+    );
+
+    // Serde is available only for user facing HttpApiDefinition
+    let http_api_definition_request: api::HttpApiDefinitionRequest =
+        serde_yaml::from_str(yaml_string.as_str()).unwrap();
+
+    let core_request: gateway_api_definition::http::HttpApiDefinitionRequest =
+        http_api_definition_request.try_into().unwrap();
+
+    let create_at: DateTime<Utc> = "2024-08-21T07:42:15.696Z".parse().unwrap();
+    HttpApiDefinition::new(core_request, create_at)
+}
+
 fn get_api_spec_cors_preflight_binding_default_response(path_pattern: &str) -> HttpApiDefinition {
     let yaml_string = format!(
         r#"
@@ -823,6 +1034,7 @@ mod internal {
     use async_trait::async_trait;
     use golem_common::model::ComponentId;
     use golem_service_base::model::VersionedComponentId;
+    use golem_service_base::repo::RepoError;
     use golem_wasm_ast::analysis::analysed_type::{field, record, str, tuple};
     use golem_wasm_ast::analysis::{
         AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
@@ -835,6 +1047,9 @@ mod internal {
         FileServerBindingHandler, FileServerBindingResult,
     };
     use golem_worker_service_base::gateway_execution::gateway_binding_resolver::WorkerDetail;
+    use golem_worker_service_base::gateway_execution::worker_stream_binding_handler::{
+        StreamMessageHandler, WorkerStreamBindingHandler, WorkerStreamBindingResult,
+    };
     use golem_worker_service_base::gateway_execution::{
         GatewayResolvedWorkerRequest, GatewayWorkerRequestExecutor, WorkerRequestExecutorError,
         WorkerResponse,
@@ -843,6 +1058,7 @@ mod internal {
     use golem_worker_service_base::gateway_rib_interpreter::{
         DefaultRibInterpreter, EvaluationError, WorkerServiceRibInterpreter,
     };
+    use golem_worker_service_base::repo::api_definition_cache::ApiDefinitionCacheRepo;
     use http::header::{
         ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_HEADERS,
         ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS,
@@ -882,9 +1098,23 @@ mod internal {
         }
     }
 
+    struct TestWorkerStreamBindingHandler {}
+    #[async_trait]
+    impl<Namespace> WorkerStreamBindingHandler<Namespace> for TestWorkerStreamBindingHandler {
+        async fn connect_worker_stream(
+            &self,
+            _namespace: &Namespace,
+            _worker_detail: &WorkerDetail,
+            _message_handler: StreamMessageHandler,
+        ) -> WorkerStreamBindingResult {
+            unimplemented!()
+        }
+    }
+
     #[derive(Debug, Clone)]
     pub(crate) struct TestResponse {
         // test function execution simply propagates these details in response body
+        component_id: Option<String>,
         worker_name: Option<String>,
         function_name: Option<String>,
         function_params: Option<Value>,
@@ -931,6 +1161,11 @@ mod internal {
             if let Some(bytes) = bytes {
                 let body_json: Value = serde_json::from_slice(&bytes).unwrap_or_default();
 
+                let component_id = body_json
+                    .get("component_id")
+                    .and_then(|v| v.as_str())
+                    .map(String::from);
+
                 let worker_name = body_json
                     .get("worker_name")
                     .and_then(|v| v.as_str())
@@ -944,6 +1179,7 @@ mod internal {
                 let function_params = body_json.get("function_params").cloned();
 
                 TestResponse {
+                    component_id,
                     worker_name,
                     function_name,
                     function_params,
@@ -956,6 +1192,7 @@ mod internal {
                 }
             } else {
                 TestResponse {
+                    component_id: None,
                     worker_name: None,
                     function_name: None,
                     function_params: None,
@@ -993,6 +1230,10 @@ mod internal {
             self.cors_header_expose_headers.clone()
         }
 
+        pub fn get_component_id(&self) -> Option<String> {
+            self.component_id.clone()
+        }
+
         pub fn get_worker_name(&self) -> Option<String> {
             self.worker_name.clone()
         }
@@ -1090,6 +1331,8 @@ mod internal {
         create_record(record_elems).unwrap()
     }
 
+    pub(crate) const CANARY_COMPONENT_ID: &str = "3d1c4ad2-4a65-4d5e-9c0e-2f8b2b8c7a11";
+
     pub(crate) fn get_component_metadata() -> ComponentMetadataDictionary {
         let versioned_component_id = VersionedComponentId {
             component_id: ComponentId::try_from("0b6d9cd8-f373-4e29-8a5a-548e61b868a5").unwrap(),
@@ -1126,6 +1369,13 @@ mod internal {
 
         let metadata = vec![analysed_export];
 
+        // The target of traffic splits, exporting the same functions
+        let canary_component_id = VersionedComponentId {
+            component_id: ComponentId::try_from(CANARY_COMPONENT_ID).unwrap(),
+            version: 0,
+        };
+
+        metadata_dict.insert(canary_component_id, metadata.clone());
         metadata_dict.insert(versioned_component_id, metadata);
 
         ComponentMetadataDictionary {
@@ -1143,4 +1393,40 @@ mod internal {
     ) -> Arc<dyn FileServerBindingHandler<Namespace> + Sync + Send> {
         Arc::new(TestFileServerBindingHandler {})
     }
+
+    pub(crate) fn get_test_worker_stream_binding_handler<Namespace>(
+    ) -> Arc<dyn WorkerStreamBindingHandler<Namespace> + Sync + Send> {
+        Arc::new(TestWorkerStreamBindingHandler {})
+    }
+
+    // None of the cached responses are ever invalidated in these tests
+    struct TestApiDefinitionCacheRepo;
+
+    #[async_trait]
+    impl ApiDefinitionCacheRepo for TestApiDefinitionCacheRepo {
+        async fn invalidate(
+            &self,
+            _namespace: &str,
+            _id: &str,
+            _version: &str,
+            _cache_key: Option<&str>,
+        ) -> Result<(), RepoError> {
+            Ok(())
+        }
+
+        async fn get_generation(
+            &self,
+            _namespace: &str,
+            _id: &str,
+            _version: &str,
+            _cache_key: &str,
+        ) -> Result<i64, RepoError> {
+            Ok(0)
+        }
+    }
+
+    pub(crate) fn get_test_api_definition_cache_repo(
+    ) -> Arc<dyn ApiDefinitionCacheRepo + Sync + Send> {
+        Arc::new(TestApiDefinitionCacheRepo)
+    }
 }
@@ -0,0 +1,10 @@
+-- Private keys are stored unencrypted, access to this table must be restricted to the worker service
+CREATE TABLE api_deployment_certificates
+(
+    namespace   text      NOT NULL,
+    site        text      NOT NULL,
+    certificate text      NOT NULL,
+    private_key text      NOT NULL,
+    created_at  timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (namespace, site)
+);
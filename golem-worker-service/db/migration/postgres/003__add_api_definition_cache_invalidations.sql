@@ -0,0 +1,11 @@
+-- Generation of the cached responses of an API definition, shared by all worker service instances.
+-- An empty cache key stands for all the responses of the API definition.
+CREATE TABLE api_definition_cache_invalidations
+(
+    namespace  text   NOT NULL,
+    id         text   NOT NULL,
+    version    text   NOT NULL,
+    cache_key  text   NOT NULL,
+    generation bigint NOT NULL,
+    PRIMARY KEY (namespace, id, version, cache_key)
+);
@@ -5,10 +5,15 @@ use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
 use golem_worker_service_base::api::ApiEndpointError;
 use golem_worker_service_base::api::HttpApiDefinitionRequest;
 use golem_worker_service_base::api::HttpApiDefinitionWithTypeInfo;
+use golem_worker_service_base::api::{ApiDefinitionTestRequest, ApiDefinitionTestResult};
 use golem_worker_service_base::gateway_api_definition::http::CompiledHttpApiDefinition;
 use golem_worker_service_base::gateway_api_definition::http::HttpApiDefinitionRequest as CoreHttpApiDefinitionRequest;
 use golem_worker_service_base::gateway_api_definition::http::OpenApiDefinitionRequest;
+use golem_worker_service_base::gateway_api_definition::http::OpenApiHttpApiDefinitionResponse;
 use golem_worker_service_base::gateway_api_definition::{ApiDefinitionId, ApiVersion};
+use golem_worker_service_base::gateway_execution::api_definition_tester::ApiDefinitionTester;
+use golem_worker_service_base::gateway_execution::gateway_response_cache::GatewayResponseCache;
+use golem_worker_service_base::service::gateway::api_definition::ApiDefinitionIdWithVersion;
 use golem_worker_service_base::service::gateway::api_definition::ApiDefinitionService;
 use golem_worker_service_base::service::gateway::http_api_definition_validator::RouteValidationError;
 use poem_openapi::param::{Path, Query};
@@ -24,6 +29,8 @@ pub struct RegisterApiDefinitionApi {
             + Sync
             + Send,
     >,
+    response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
+    api_definition_tester: Arc<dyn ApiDefinitionTester<DefaultNamespace> + Sync + Send>,
 }
 
 #[OpenApi(prefix_path = "/v1/api/definitions", tag = ApiTags::ApiDefinition)]
@@ -34,8 +41,14 @@ impl RegisterApiDefinitionApi {
                 + Sync
                 + Send,
         >,
+        response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
+        api_definition_tester: Arc<dyn ApiDefinitionTester<DefaultNamespace> + Sync + Send>,
     ) -> Self {
-        Self { definition_service }
+        Self {
+            definition_service,
+            response_cache,
+            api_definition_tester,
+        }
     }
 
     /// Upload an OpenAPI definition
@@ -198,6 +211,116 @@ impl RegisterApiDefinitionApi {
         record.result(response)
     }
 
+    /// Export an API definition in OpenAPI format
+    ///
+    /// Exports an API definition, selected by its API definition ID and version, as an OpenAPI 3 spec.
+    /// The schemas of parameters, request body and response are inferred from the Rib expressions of the bindings.
+    #[oai(
+        path = "/:id/:version/export",
+        method = "get",
+        operation_id = "export_definition"
+    )]
+    async fn export(
+        &self,
+        id: Path<ApiDefinitionId>,
+        version: Path<ApiVersion>,
+    ) -> Result<Json<OpenApiHttpApiDefinitionResponse>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "export_definition",
+            api_definition_id = id.0.to_string(),
+            version = version.0.to_string()
+        );
+
+        let response = {
+            let api_definition_id = id.0;
+
+            let api_version = version.0;
+
+            let data = self
+                .definition_service
+                .get(
+                    &api_definition_id,
+                    &api_version,
+                    &DefaultNamespace::default(),
+                    &EmptyAuthCtx::default(),
+                )
+                .instrument(record.span.clone())
+                .await?;
+
+            let definition = data.ok_or(ApiEndpointError::not_found(safe(format!(
+                "Can't find api definition with id {api_definition_id}, and version {api_version}"
+            ))))?;
+
+            let result =
+                OpenApiHttpApiDefinitionResponse::from_compiled_http_api_definition(&definition)
+                    .map_err(|err| ApiEndpointError::internal(safe(err)))?;
+
+            Ok(Json(result))
+        };
+
+        record.result(response)
+    }
+
+    /// Test an API definition with a sample request
+    ///
+    /// Resolves a sample request against the routes of an API definition, selected by its API definition ID and version,
+    /// without the API definition being deployed. Returns the matched route, the evaluated worker name and idempotency key,
+    /// and the result of the response mapping. Worker calls are made against the actual worker, unless a mock worker result is given.
+    #[oai(
+        path = "/:id/:version/test",
+        method = "post",
+        operation_id = "test_definition"
+    )]
+    async fn test(
+        &self,
+        id: Path<ApiDefinitionId>,
+        version: Path<ApiVersion>,
+        payload: Json<ApiDefinitionTestRequest>,
+    ) -> Result<Json<ApiDefinitionTestResult>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "test_definition",
+            api_definition_id = id.0.to_string(),
+            version = version.0.to_string()
+        );
+
+        let response = {
+            let api_definition_id = id.0;
+
+            let api_version = version.0;
+
+            let data = self
+                .definition_service
+                .get(
+                    &api_definition_id,
+                    &api_version,
+                    &DefaultNamespace::default(),
+                    &EmptyAuthCtx::default(),
+                )
+                .instrument(record.span.clone())
+                .await?;
+
+            let definition = data.ok_or(ApiEndpointError::not_found(safe(format!(
+                "Can't find api definition with id {api_definition_id}, and version {api_version}"
+            ))))?;
+
+            let request = payload
+                .0
+                .to_input_http_request()
+                .map_err(|err| ApiEndpointError::bad_request(safe(err)))?;
+
+            let result = self
+                .api_definition_tester
+                .test(definition, request, payload.0.mock_worker_result)
+                .instrument(record.span.clone())
+                .await
+                .map_err(|err| ApiEndpointError::bad_request(safe(err.to_string())))?;
+
+            Ok(Json(result.into()))
+        };
+
+        record.result(response)
+    }
+
     /// Delete an API definition
     ///
     /// Deletes an API definition by its API definition ID and version.
@@ -236,6 +359,49 @@ impl RegisterApiDefinitionApi {
         record.result(response)
     }
 
+    /// Invalidate cached responses of an API definition
+    ///
+    /// Invalidates the responses cached by the worker bindings of an API definition having a response cache,
+    /// in every worker service instance.
+    /// If `key` is specified, only the responses with the given cache key are invalidated.
+    #[oai(
+        path = "/:id/:version/cache",
+        method = "delete",
+        operation_id = "invalidate_definition_cache"
+    )]
+    async fn invalidate_cache(
+        &self,
+        id: Path<ApiDefinitionId>,
+        version: Path<ApiVersion>,
+        key: Query<Option<String>>,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "invalidate_definition_cache",
+            api_definition_id = id.0.to_string(),
+            version = version.0.to_string()
+        );
+
+        let response = {
+            let api_definition = ApiDefinitionIdWithVersion {
+                id: id.0,
+                version: version.0,
+            };
+
+            self.response_cache
+                .invalidate(
+                    &DefaultNamespace::default().to_string(),
+                    &api_definition,
+                    key.0.as_deref(),
+                )
+                .instrument(record.span.clone())
+                .await
+                .map_err(|err| ApiEndpointError::internal(safe(err.to_string())))?;
+
+            Ok(Json("API definition cache invalidated".to_string()))
+        };
+        record.result(response)
+    }
+
     /// Get or list API definitions
     ///
     /// If `api_definition_id` is specified, returns a single API definition.
@@ -311,9 +477,18 @@ mod test {
     use golem_common::model::ComponentId;
     use golem_service_base::db;
     use golem_service_base::model::Component;
+    use golem_worker_service_base::gateway_execution::api_definition_tester::DefaultApiDefinitionTester;
+    use golem_worker_service_base::gateway_execution::gateway_response_cache::DefaultGatewayResponseCache;
+    use golem_worker_service_base::gateway_execution::{
+        GatewayResolvedWorkerRequest, GatewayWorkerRequestExecutor, WorkerRequestExecutorError,
+        WorkerResponse,
+    };
     use golem_worker_service_base::repo::api_definition::{
         ApiDefinitionRepo, DbApiDefinitionRepo, LoggedApiDefinitionRepo,
     };
+    use golem_worker_service_base::repo::api_definition_cache::{
+        DbApiDefinitionCacheRepo, LoggedApiDefinitionCacheRepo,
+    };
     use golem_worker_service_base::repo::api_deployment;
     use golem_worker_service_base::service::component::ComponentResult;
     use golem_worker_service_base::service::gateway::api_definition::ApiDefinitionServiceDefault;
@@ -375,6 +550,18 @@ mod test {
         }
     }
 
+    struct TestWorkerRequestExecutor;
+
+    #[async_trait]
+    impl GatewayWorkerRequestExecutor for TestWorkerRequestExecutor {
+        async fn execute(
+            &self,
+            _resolved_worker_request: GatewayResolvedWorkerRequest,
+        ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
+            unimplemented!()
+        }
+    }
+
     async fn make_route<'c>() -> (poem::Route, SqliteDb<'c>) {
         let db = SqliteDb::default();
         let db_config = DbSqliteConfig {
@@ -404,7 +591,17 @@ mod test {
             Arc::new(HttpApiDefinitionValidator {}),
         );
 
-        let endpoint = RegisterApiDefinitionApi::new(Arc::new(definition_service));
+        let endpoint = RegisterApiDefinitionApi::new(
+            Arc::new(definition_service),
+            Arc::new(DefaultGatewayResponseCache::new(Arc::new(
+                LoggedApiDefinitionCacheRepo::new(DbApiDefinitionCacheRepo::new(
+                    db_pool.clone().into(),
+                )),
+            ))),
+            Arc::new(DefaultApiDefinitionTester::new(Arc::new(
+                TestWorkerRequestExecutor,
+            ))),
+        );
 
         (
             poem::Route::new().nest("", OpenApiService::new(endpoint, "test", "1.0")),
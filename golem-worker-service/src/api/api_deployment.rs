@@ -52,6 +52,7 @@ impl ApiDeploymentApi {
                 namespace: DefaultNamespace::default(),
                 api_definition_keys: api_definition_infos,
                 site: payload.site.clone(),
+                certificate: payload.certificate.clone(),
             };
 
             self.deployment_service
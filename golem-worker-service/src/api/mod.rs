@@ -5,14 +5,19 @@ pub mod worker_connect;
 
 use crate::api::worker::WorkerApi;
 use crate::service::Services;
+use futures::Stream;
+use golem_service_base::auth::DefaultNamespace;
 use golem_worker_service_base::api::CustomHttpRequestApi;
 use golem_worker_service_base::api::HealthcheckApi;
 use poem::endpoint::PrometheusExporter;
+use poem::listener::{RustlsCertificate, RustlsConfig};
 use poem::{get, EndpointExt, Route};
 use poem_openapi::OpenApiService;
 use prometheus::Registry;
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, warn};
 
 type ApiServices = (
     WorkerApi,
@@ -41,14 +46,95 @@ pub fn combined_routes(prometheus_registry: Arc<Registry>, services: &Services)
         )
 }
 
-pub fn custom_request_route(services: Services) -> Route {
-    let custom_request_executor = CustomHttpRequestApi::new(
-        services.worker_to_http_service,
-        services.http_definition_lookup_service,
-        services.fileserver_binding_handler,
-    );
+// The custom request API is shared by the HTTP and TLS listeners,
+// such that rate limits and cached responses apply across both of them
+pub fn custom_request_api(services: &Services) -> CustomHttpRequestApi<DefaultNamespace> {
+    CustomHttpRequestApi::new(
+        services.worker_to_http_service.clone(),
+        services.http_definition_lookup_service.clone(),
+        services.fileserver_binding_handler.clone(),
+        services.rate_limiter.clone(),
+        services.response_cache.clone(),
+        services.worker_stream_binding_handler.clone(),
+    )
+}
+
+pub fn custom_request_route(custom_request_api: CustomHttpRequestApi<DefaultNamespace>) -> Route {
+    Route::new().nest("/", custom_request_api)
+}
+
+// TLS configuration of the custom request API, with a certificate per deployed site.
+// Certificates are reloaded periodically, and a new configuration is emitted only when they change.
+// Certificates failing validation are skipped, without affecting the other sites.
+// No configuration is emitted while there is no valid certificate at all.
+pub fn custom_request_tls_config(
+    services: &Services,
+    refresh_interval: Duration,
+) -> impl Stream<Item = RustlsConfig> + Send + Unpin + 'static {
+    let deployment_service = services.deployment_service.clone();
+
+    Box::pin(futures::stream::unfold(
+        (tokio::time::interval(refresh_interval), None),
+        move |(mut interval, mut previous)| {
+            let deployment_service = deployment_service.clone();
+
+            async move {
+                loop {
+                    interval.tick().await;
+
+                    match deployment_service.get_certificates().await {
+                        Ok(certificates) if previous.as_ref() != Some(&certificates) => {
+                            // An invalid certificate would make the whole configuration unusable,
+                            // so it is left out and only its own site is not served over TLS
+                            let valid_certificates = certificates
+                                .iter()
+                                .filter(|(site, certificate)| {
+                                    match certificate.validate(site.server_name()) {
+                                        Ok(()) => true,
+                                        Err(error) => {
+                                            error!(
+                                                site = %site,
+                                                error = %error,
+                                                "Skipping invalid site certificate"
+                                            );
+                                            false
+                                        }
+                                    }
+                                })
+                                .collect::<Vec<_>>();
+
+                            if valid_certificates.is_empty() {
+                                warn!("No valid site certificates, not updating the TLS configuration");
+                                previous = Some(certificates);
+                                continue;
+                            }
+
+                            let config = valid_certificates.into_iter().fold(
+                                RustlsConfig::new(),
+                                |config, (site, certificate)| {
+                                    config.certificate(
+                                        site.server_name(),
+                                        RustlsCertificate::new()
+                                            .cert(certificate.certificate.clone())
+                                            .key(certificate.private_key.clone()),
+                                    )
+                                },
+                            );
 
-    Route::new().nest("/", custom_request_executor)
+                            return Some((config, (interval, Some(certificates))));
+                        }
+                        Ok(_) => {}
+                        Err(error) => {
+                            error!(
+                                error = error.to_string(),
+                                "Failed to load site certificates"
+                            )
+                        }
+                    }
+                }
+            }
+        },
+    ))
 }
 
 pub fn make_open_api_service(services: &Services) -> OpenApiService<ApiServices, ()> {
@@ -58,7 +144,11 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<ApiServices,
                 component_service: services.component_service.clone(),
                 worker_service: services.worker_service.clone(),
             },
-            api_definition::RegisterApiDefinitionApi::new(services.definition_service.clone()),
+            api_definition::RegisterApiDefinitionApi::new(
+                services.definition_service.clone(),
+                services.response_cache.clone(),
+                services.api_definition_tester.clone(),
+            ),
             api_deployment::ApiDeploymentApi::new(services.deployment_service.clone()),
             HealthcheckApi,
         ),
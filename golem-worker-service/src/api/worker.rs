@@ -3,8 +3,8 @@ use futures_util::TryStreamExt;
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::public_oplog::OplogCursor;
 use golem_common::model::{
-    ComponentFilePath, ComponentId, IdempotencyKey, ScanCursor, TargetWorkerId, WorkerFilter,
-    WorkerId,
+    ComponentFilePath, ComponentId, IdempotencyKey, RevertWorkerTarget, ScanCursor, TargetWorkerId,
+    Timestamp, WorkerFilter, WorkerId,
 };
 use golem_common::recorded_http_api_request;
 use golem_service_base::api_tags::ApiTags;
@@ -215,6 +215,8 @@ impl WorkerApi {
     ///
     /// Ideal for invoking ephemeral components, but works with durable ones as well.
     /// Triggers the execution of a function and immediately returns.
+    /// If `schedule-at` is set, the invocation is only enqueued at that time. Until then it can be
+    /// cancelled with its idempotency key.
     #[oai(
         path = "/:component_id/invoke",
         method = "post",
@@ -225,6 +227,7 @@ impl WorkerApi {
         component_id: Path<ComponentId>,
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
         function: Query<String>,
+        #[oai(name = "schedule-at")] schedule_at: Query<Option<Timestamp>>,
         params: Json<InvokeParameters>,
     ) -> Result<Json<InvokeResponse>> {
         let worker_id = make_target_worker_id(component_id.0, None)?;
@@ -244,6 +247,7 @@ impl WorkerApi {
                 function.0,
                 params.0.params,
                 None,
+                schedule_at.0,
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -257,6 +261,8 @@ impl WorkerApi {
     /// Invoke a function
     ///
     /// Triggers the execution of a function and immediately returns.
+    /// If `schedule-at` is set, the invocation is only enqueued at that time. Until then it can be
+    /// cancelled with its idempotency key.
     #[oai(
         path = "/:component_id/workers/:worker_name/invoke",
         method = "post",
@@ -268,6 +274,7 @@ impl WorkerApi {
         worker_name: Path<String>,
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
         function: Query<String>,
+        #[oai(name = "schedule-at")] schedule_at: Query<Option<Timestamp>>,
         params: Json<InvokeParameters>,
     ) -> Result<Json<InvokeResponse>> {
         let worker_id = make_target_worker_id(component_id.0, Some(worker_name.0))?;
@@ -287,6 +294,7 @@ impl WorkerApi {
                 function.0,
                 params.0.params,
                 None,
+                schedule_at.0,
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -613,6 +621,374 @@ impl WorkerApi {
         record.result(response)
     }
 
+    /// Fork a worker
+    ///
+    /// Creates a new worker of the same component by copying the source worker's oplog up to and
+    /// including the given oplog index. The new worker then continues from that point independently.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/fork",
+        method = "post",
+        operation_id = "fork_worker"
+    )]
+    async fn fork_worker(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        params: Json<ForkWorkerRequest>,
+    ) -> Result<Json<ForkWorkerResponse>> {
+        let source_worker_id = make_worker_id(component_id.0.clone(), worker_name.0)?;
+        let target_worker_id = make_worker_id(component_id.0, params.target_worker_name.clone())?;
+
+        let record = recorded_http_api_request!(
+            "fork_worker",
+            source_worker_id = source_worker_id.to_string(),
+            target_worker_id = target_worker_id.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .fork(
+                &source_worker_id,
+                &target_worker_id,
+                params.oplog_index_cutoff,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(ForkWorkerResponse {}));
+
+        record.result(response)
+    }
+
+    /// Revert a worker
+    ///
+    /// Reverts a worker by undoing either the last few invocations or the last few recorded oplog
+    /// entries. The undone entries are skipped from then on, both when replaying and when the worker continues.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/revert",
+        method = "post",
+        operation_id = "revert_worker"
+    )]
+    async fn revert_worker(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        target: Json<RevertWorkerTarget>,
+    ) -> Result<Json<RevertWorkerResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!("revert_worker", worker_id = worker_id.to_string());
+
+        let response = self
+            .worker_service
+            .revert(
+                &worker_id,
+                target.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(RevertWorkerResponse {}));
+
+        record.result(response)
+    }
+
+    /// Cancel a pending invocation
+    ///
+    /// Removes an enqueued invocation identified by its idempotency key, if it has not started yet.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocations/:idempotency_key",
+        method = "delete",
+        operation_id = "cancel_invocation"
+    )]
+    async fn cancel_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        idempotency_key: Path<IdempotencyKey>,
+    ) -> Result<Json<CancelInvocationResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "cancel_invocation",
+            worker_id = worker_id.to_string(),
+            idempotency_key = idempotency_key.0.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .cancel_invocation(
+                &worker_id,
+                &idempotency_key.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(CancelInvocationResponse {}));
+
+        record.result(response)
+    }
+
+    /// Export a worker
+    ///
+    /// Returns a versioned archive containing the worker's full oplog, its large oplog payloads
+    /// and its promises. The archive can be imported into another Golem installation. Key-value
+    /// buckets are shared by the account's workers and are not part of the archive.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/export",
+        method = "get",
+        operation_id = "export_worker"
+    )]
+    async fn export_worker(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+    ) -> Result<Binary<Vec<u8>>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!("export_worker", worker_id = worker_id.to_string());
+
+        let response = self
+            .worker_service
+            .export_worker(
+                &worker_id,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Binary);
+
+        record.result(response)
+    }
+
+    /// Import a worker
+    ///
+    /// Creates a new worker from an archive previously produced by the export endpoint. The
+    /// component must already exist and have the component version the archived worker was
+    /// created with.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/import",
+        method = "post",
+        operation_id = "import_worker"
+    )]
+    async fn import_worker(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        archive: Binary<Vec<u8>>,
+    ) -> Result<Json<ImportWorkerResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!("import_worker", worker_id = worker_id.to_string());
+
+        let response = self
+            .worker_service
+            .import_worker(
+                &worker_id,
+                archive.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(ImportWorkerResponse {}));
+
+        record.result(response)
+    }
+
+    /// Schedule a recurring invocation
+    ///
+    /// Creates a named scheduled invocation of one of the worker's functions. It can fire once,
+    /// at a fixed interval or following a five-field cron expression. Each tick is invoked with
+    /// its own idempotency key, so a tick is never performed twice.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules",
+        method = "post",
+        operation_id = "create_scheduled_invocation"
+    )]
+    async fn create_scheduled_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        params: Json<ScheduleInvocationParameters>,
+    ) -> Result<Json<ScheduledInvocationInfo>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "create_scheduled_invocation",
+            worker_id = worker_id.to_string(),
+            name = params.0.name.clone(),
+            function = params.0.function_name.clone()
+        );
+
+        let response = async {
+            let input = self
+                .worker_service
+                .validate_typed_parameters(params.0.params)?;
+            self.worker_service
+                .create_scheduled_invocation(
+                    &worker_id,
+                    params.0.name,
+                    params.0.function_name,
+                    input,
+                    params.0.recurrence,
+                    params.0.start_at,
+                    empty_worker_metadata(),
+                    &EmptyAuthCtx::default(),
+                )
+                .await
+        }
+        .instrument(record.span.clone())
+        .await
+        .map_err(|e| e.into())
+        .map(|invocation| Json(invocation.into()));
+
+        record.result(response)
+    }
+
+    /// List the scheduled invocations of a worker
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules",
+        method = "get",
+        operation_id = "get_scheduled_invocations"
+    )]
+    async fn get_scheduled_invocations(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+    ) -> Result<Json<ScheduledInvocationsResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "get_scheduled_invocations",
+            worker_id = worker_id.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .get_scheduled_invocations(
+                &worker_id,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|invocations| {
+                Json(ScheduledInvocationsResponse {
+                    invocations: invocations.into_iter().map(|i| i.into()).collect(),
+                })
+            });
+
+        record.result(response)
+    }
+
+    /// Update a scheduled invocation
+    ///
+    /// Replaces the function, parameters and recurrence of an existing scheduled invocation.
+    /// The name in the request body must match the one in the path.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules/:name",
+        method = "put",
+        operation_id = "update_scheduled_invocation"
+    )]
+    async fn update_scheduled_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        name: Path<String>,
+        params: Json<ScheduleInvocationParameters>,
+    ) -> Result<Json<ScheduledInvocationInfo>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "update_scheduled_invocation",
+            worker_id = worker_id.to_string(),
+            name = name.0.clone(),
+            function = params.0.function_name.clone()
+        );
+
+        if params.0.name != name.0 {
+            return record.result(Err(WorkerApiBaseError::BadRequest(Json(ErrorsBody {
+                errors: vec![format!(
+                    "Scheduled invocation name {} does not match {}",
+                    params.0.name, name.0
+                )],
+            }))));
+        }
+
+        let response = async {
+            let input = self
+                .worker_service
+                .validate_typed_parameters(params.0.params)?;
+            self.worker_service
+                .update_scheduled_invocation(
+                    &worker_id,
+                    name.0,
+                    params.0.function_name,
+                    input,
+                    params.0.recurrence,
+                    params.0.start_at,
+                    empty_worker_metadata(),
+                    &EmptyAuthCtx::default(),
+                )
+                .await
+        }
+        .instrument(record.span.clone())
+        .await
+        .map_err(|e| e.into())
+        .map(|invocation| Json(invocation.into()));
+
+        record.result(response)
+    }
+
+    /// Delete a scheduled invocation
+    ///
+    /// Removes the scheduled invocation together with its pending tick.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules/:name",
+        method = "delete",
+        operation_id = "delete_scheduled_invocation"
+    )]
+    async fn delete_scheduled_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        name: Path<String>,
+    ) -> Result<Json<DeleteScheduledInvocationResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "delete_scheduled_invocation",
+            worker_id = worker_id.to_string(),
+            name = name.0.clone()
+        );
+
+        let response = self
+            .worker_service
+            .delete_scheduled_invocation(
+                &worker_id,
+                name.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(DeleteScheduledInvocationResponse {}));
+
+        record.result(response)
+    }
+
     /// Get or search the oplog of a worker
     #[oai(
         path = "/:component_id/workers/:worker_name/oplog",
@@ -19,25 +19,36 @@ use futures::StreamExt;
 use golem_api_grpc::proto::golem::common::{Empty, ErrorBody, ErrorsBody};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_server::WorkerService as GrpcWorkerService;
 use golem_api_grpc::proto::golem::worker::v1::{
-    complete_promise_response, delete_worker_response, get_oplog_response,
-    get_worker_metadata_response, get_workers_metadata_response, interrupt_worker_response,
-    invoke_and_await_json_response, invoke_and_await_response, invoke_and_await_typed_response,
-    invoke_response, launch_new_worker_response, resume_worker_response, search_oplog_response,
-    update_worker_response, worker_error, worker_execution_error, CompletePromiseRequest,
-    CompletePromiseResponse, ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse,
-    GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse, GetWorkerMetadataRequest,
-    GetWorkerMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
-    GetWorkersMetadataSuccessResponse, InterruptWorkerRequest, InterruptWorkerResponse,
+    cancel_invocation_response, complete_promise_response, create_scheduled_invocation_response,
+    delete_scheduled_invocation_response, delete_worker_response, export_worker_response,
+    fork_worker_response, get_oplog_response, get_scheduled_invocations_response,
+    get_worker_metadata_response, get_workers_metadata_response, import_worker_response,
+    interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response,
+    invoke_and_await_typed_response, invoke_response, launch_new_worker_response,
+    resume_worker_response, revert_worker_response, search_oplog_response,
+    update_scheduled_invocation_response, update_worker_response, worker_error,
+    worker_execution_error, CancelInvocationRequest, CancelInvocationResponse,
+    CompletePromiseRequest, CompletePromiseResponse, ConnectWorkerRequest,
+    CreateScheduledInvocationRequest, CreateScheduledInvocationResponse,
+    DeleteScheduledInvocationRequest, DeleteScheduledInvocationResponse, DeleteWorkerRequest,
+    DeleteWorkerResponse, ExportWorkerRequest, ExportWorkerResponse, ForkWorkerRequest,
+    ForkWorkerResponse, GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse,
+    GetScheduledInvocationsRequest, GetScheduledInvocationsResponse,
+    GetScheduledInvocationsSuccessResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse,
+    GetWorkersMetadataRequest, GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse,
+    ImportWorkerRequest, ImportWorkerResponse, InterruptWorkerRequest, InterruptWorkerResponse,
     InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest,
     InvokeAndAwaitResponse, InvokeAndAwaitTypedResponse, InvokeJsonRequest, InvokeRequest,
     InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
-    LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, SearchOplogRequest,
-    SearchOplogResponse, SearchOplogSuccessResponse, UnknownError, UpdateWorkerRequest,
-    UpdateWorkerResponse, WorkerError as GrpcWorkerError, WorkerExecutionError,
+    LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest,
+    RevertWorkerResponse, SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse,
+    UnknownError, UpdateScheduledInvocationRequest, UpdateScheduledInvocationResponse,
+    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError as GrpcWorkerError,
+    WorkerExecutionError,
 };
 use golem_api_grpc::proto::golem::worker::v1::{list_directory_response, GetFileContentsResponse};
 use golem_api_grpc::proto::golem::worker::{
-    InvokeResult, InvokeResultTyped, LogEvent, WorkerMetadata,
+    InvokeResult, InvokeResultTyped, LogEvent, ScheduledInvocation, WorkerMetadata,
 };
 use golem_common::grpc::{
     proto_component_id_string, proto_idempotency_key_string,
@@ -45,8 +56,10 @@ use golem_common::grpc::{
     proto_worker_id_string,
 };
 use golem_common::model::oplog::OplogIndex;
+use golem_common::model::scheduled_invocation::Recurrence;
 use golem_common::model::{
-    ComponentFilePath, ComponentVersion, ScanCursor, TargetWorkerId, WorkerFilter, WorkerId,
+    ComponentFilePath, ComponentVersion, IdempotencyKey, RevertWorkerTarget, ScanCursor,
+    TargetWorkerId, WorkerFilter, WorkerId,
 };
 use golem_common::recorded_grpc_api_request;
 use golem_service_base::auth::EmptyAuthCtx;
@@ -531,6 +544,259 @@ impl GrpcWorkerService for WorkerGrpcApi {
         }))
     }
 
+    async fn fork_worker(
+        &self,
+        request: Request<ForkWorkerRequest>,
+    ) -> Result<Response<ForkWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "fork_worker",
+            source_worker_id = proto_worker_id_string(&request.source_worker_id),
+            target_worker_id = proto_worker_id_string(&request.target_worker_id),
+        );
+
+        let response = match self
+            .fork_worker(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(fork_worker_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                fork_worker_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(ForkWorkerResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn revert_worker(
+        &self,
+        request: Request<RevertWorkerRequest>,
+    ) -> Result<Response<RevertWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "revert_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .revert_worker(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(revert_worker_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                revert_worker_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(RevertWorkerResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn cancel_invocation(
+        &self,
+        request: Request<CancelInvocationRequest>,
+    ) -> Result<Response<CancelInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "cancel_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        let response = match self
+            .cancel_invocation(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(cancel_invocation_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                cancel_invocation_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(CancelInvocationResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn export_worker(
+        &self,
+        request: Request<ExportWorkerRequest>,
+    ) -> Result<Response<ExportWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "export_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .export_worker(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(archive) => record.succeed(export_worker_response::Result::Success(archive)),
+            Err(error) => record.fail(
+                export_worker_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(ExportWorkerResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn import_worker(
+        &self,
+        request: Request<ImportWorkerRequest>,
+    ) -> Result<Response<ImportWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "import_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .import_worker(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(import_worker_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                import_worker_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(ImportWorkerResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn create_scheduled_invocation(
+        &self,
+        request: Request<CreateScheduledInvocationRequest>,
+    ) -> Result<Response<CreateScheduledInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "create_scheduled_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .create_scheduled_invocation(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(invocation) => record.succeed(
+                create_scheduled_invocation_response::Result::Success(invocation),
+            ),
+            Err(error) => record.fail(
+                create_scheduled_invocation_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(CreateScheduledInvocationResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        request: Request<GetScheduledInvocationsRequest>,
+    ) -> Result<Response<GetScheduledInvocationsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_scheduled_invocations",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .get_scheduled_invocations(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(invocations) => record.succeed(get_scheduled_invocations_response::Result::Success(
+                GetScheduledInvocationsSuccessResponse { invocations },
+            )),
+            Err(error) => record.fail(
+                get_scheduled_invocations_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(GetScheduledInvocationsResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn update_scheduled_invocation(
+        &self,
+        request: Request<UpdateScheduledInvocationRequest>,
+    ) -> Result<Response<UpdateScheduledInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "update_scheduled_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .update_scheduled_invocation(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(invocation) => record.succeed(
+                update_scheduled_invocation_response::Result::Success(invocation),
+            ),
+            Err(error) => record.fail(
+                update_scheduled_invocation_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(UpdateScheduledInvocationResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        request: Request<DeleteScheduledInvocationRequest>,
+    ) -> Result<Response<DeleteScheduledInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "delete_scheduled_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .delete_scheduled_invocation(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(delete_scheduled_invocation_response::Result::Success(
+                Empty {},
+            )),
+            Err(error) => record.fail(
+                delete_scheduled_invocation_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(DeleteScheduledInvocationResponse {
+            result: Some(response),
+        }))
+    }
+
     async fn list_directory(
         &self,
         request: Request<golem_api_grpc::proto::golem::worker::v1::ListDirectoryRequest>,
@@ -760,6 +1026,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params.params,
                 request.context,
+                request.schedule_at.map(|ts| ts.into()),
                 empty_worker_metadata(),
             )
             .await?;
@@ -784,6 +1051,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params,
                 request.context,
+                None,
                 empty_worker_metadata(),
             )
             .await?;
@@ -1016,6 +1284,191 @@ impl WorkerGrpcApi {
         })
     }
 
+    async fn fork_worker(&self, request: ForkWorkerRequest) -> Result<(), GrpcWorkerError> {
+        let source_worker_id = validate_protobuf_worker_id(request.source_worker_id)?;
+        let target_worker_id = validate_protobuf_worker_id(request.target_worker_id)?;
+
+        self.worker_service
+            .fork(
+                &source_worker_id,
+                &target_worker_id,
+                OplogIndex::from_u64(request.oplog_index_cutoff),
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revert_worker(&self, request: RevertWorkerRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+        let target: RevertWorkerTarget = request
+            .target
+            .ok_or_else(|| bad_request_error("Missing revert target"))?
+            .try_into()
+            .map_err(|error| bad_request_error(format!("Invalid revert target: {error}")))?;
+
+        self.worker_service
+            .revert(
+                &worker_id,
+                target,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn cancel_invocation(
+        &self,
+        request: CancelInvocationRequest,
+    ) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+        let idempotency_key: IdempotencyKey = request
+            .idempotency_key
+            .ok_or_else(|| bad_request_error("Missing idempotency key"))?
+            .into();
+
+        self.worker_service
+            .cancel_invocation(
+                &worker_id,
+                &idempotency_key,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn export_worker(
+        &self,
+        request: ExportWorkerRequest,
+    ) -> Result<Vec<u8>, GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        let archive = self
+            .worker_service
+            .export_worker(
+                &worker_id,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(archive)
+    }
+
+    async fn import_worker(&self, request: ImportWorkerRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        self.worker_service
+            .import_worker(
+                &worker_id,
+                request.archive,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_scheduled_invocation(
+        &self,
+        request: CreateScheduledInvocationRequest,
+    ) -> Result<ScheduledInvocation, GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+        let invocation = request
+            .invocation
+            .ok_or_else(|| bad_request_error("Missing scheduled invocation"))?;
+        let recurrence = validate_protobuf_recurrence(invocation.recurrence)?;
+
+        let result = self
+            .worker_service
+            .create_scheduled_invocation(
+                &worker_id,
+                invocation.name,
+                invocation.function_name,
+                invocation.input,
+                recurrence,
+                invocation.next_fire_at.map(|ts| ts.into()),
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(result.into())
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        request: GetScheduledInvocationsRequest,
+    ) -> Result<Vec<ScheduledInvocation>, GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        let result = self
+            .worker_service
+            .get_scheduled_invocations(
+                &worker_id,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(result
+            .into_iter()
+            .map(|invocation| invocation.into())
+            .collect())
+    }
+
+    async fn update_scheduled_invocation(
+        &self,
+        request: UpdateScheduledInvocationRequest,
+    ) -> Result<ScheduledInvocation, GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+        let invocation = request
+            .invocation
+            .ok_or_else(|| bad_request_error("Missing scheduled invocation"))?;
+        let recurrence = validate_protobuf_recurrence(invocation.recurrence)?;
+
+        let result = self
+            .worker_service
+            .update_scheduled_invocation(
+                &worker_id,
+                invocation.name,
+                invocation.function_name,
+                invocation.input,
+                recurrence,
+                invocation.next_fire_at.map(|ts| ts.into()),
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(result.into())
+    }
+
+    async fn delete_scheduled_invocation(
+        &self,
+        request: DeleteScheduledInvocationRequest,
+    ) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        self.worker_service
+            .delete_scheduled_invocation(
+                &worker_id,
+                request.name,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn list_directory(
         &self,
         request: golem_api_grpc::proto::golem::worker::v1::ListDirectoryRequest,
@@ -1127,6 +1580,19 @@ fn validate_component_file_path(file_path: String) -> Result<ComponentFilePath,
     ComponentFilePath::from_abs_str(&file_path).map_err(|_| bad_request_error("Invalid file path"))
 }
 
+fn validate_protobuf_recurrence(
+    recurrence: Option<golem_api_grpc::proto::golem::worker::Recurrence>,
+) -> Result<Recurrence, GrpcWorkerError> {
+    let recurrence: Recurrence = recurrence
+        .ok_or_else(|| bad_request_error("Missing recurrence"))?
+        .try_into()
+        .map_err(|e| bad_request_error(format!("Invalid recurrence: {e}")))?;
+    recurrence
+        .validate()
+        .map_err(|e| bad_request_error(format!("Invalid recurrence: {e}")))?;
+    Ok(recurrence)
+}
+
 fn bad_request_error<T>(error: T) -> GrpcWorkerError
 where
     T: Into<String>,
@@ -1242,6 +1708,9 @@ fn error_to_status(error: GrpcWorkerError) -> Status {
                 worker_execution_error::Error::FileSystemError(_) => {
                     "Failed accessing worker filesystem".to_string()
                 }
+                worker_execution_error::Error::ResourceLimitExceeded(_) => {
+                    "Resource limit exceeded".to_string()
+                }
             };
             Status::internal(message)
         }
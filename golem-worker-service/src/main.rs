@@ -1,6 +1,7 @@
 use std::net::{Ipv4Addr, SocketAddrV4};
 use std::sync::Arc;
 
+use futures::StreamExt;
 use opentelemetry::global;
 use opentelemetry_sdk::metrics::MeterProviderBuilder;
 use poem::listener::TcpListener;
@@ -76,12 +77,16 @@ pub async fn app(
         .await
         .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
 
-    let http_service1 = services.clone();
     let http_service2 = services.clone();
     let grpc_services = services.clone();
+    let tls_services = services.clone();
+    let tls_config = config.custom_request_tls.clone();
+
+    let custom_request_api = api::custom_request_api(&services);
+    let tls_custom_request_api = custom_request_api.clone();
 
     let custom_request_server = tokio::spawn(async move {
-        let route = api::custom_request_route(http_service1)
+        let route = api::custom_request_route(custom_request_api)
             .with(OpenTelemetryMetrics::new())
             .with(Tracing);
 
@@ -92,6 +97,38 @@ pub async fn app(
             .expect("Custom Request server failed")
     });
 
+    let custom_request_tls_server = tokio::spawn(async move {
+        if tls_config.enabled {
+            let mut certificates = api::custom_request_tls_config(
+                &tls_services,
+                tls_config.certificate_refresh_interval,
+            );
+
+            // The TLS listener is only started once there is a valid certificate to serve
+            match certificates.next().await {
+                Some(initial_certificates) => {
+                    let route = api::custom_request_route(tls_custom_request_api)
+                        .with(OpenTelemetryMetrics::new())
+                        .with(Tracing);
+
+                    let certificates =
+                        futures::stream::iter([initial_certificates]).chain(certificates);
+
+                    poem::Server::new(
+                        TcpListener::bind(("0.0.0.0", tls_config.port)).rustls(certificates),
+                    )
+                    .name("gateway-tls")
+                    .run(route)
+                    .await
+                    .expect("Custom Request TLS server failed")
+                }
+                None => std::future::pending::<()>().await,
+            }
+        } else {
+            std::future::pending::<()>().await
+        }
+    });
+
     let worker_server = tokio::spawn(async move {
         let prometheus_registry = Arc::new(prometheus_registry);
         let app = api::combined_routes(prometheus_registry, &http_service2)
@@ -116,6 +153,7 @@ pub async fn app(
     select! {
         _ = worker_server => {},
         _ = custom_request_server => {},
+        _ = custom_request_tls_server => {},
         _ = grpc_server => {},
     }
     Ok(())
@@ -6,8 +6,20 @@ use golem_service_base::config::BlobStorageConfig;
 use golem_service_base::service::initial_component_files::InitialComponentFilesService;
 use golem_service_base::storage::blob::BlobStorage;
 use golem_service_base::storage::sqlite::SqlitePool;
+use golem_worker_service_base::gateway_execution::api_definition_tester::{
+    ApiDefinitionTester, DefaultApiDefinitionTester,
+};
 use golem_worker_service_base::gateway_execution::file_server_binding_handler::DefaultFileServerBindingHandler;
 use golem_worker_service_base::gateway_execution::file_server_binding_handler::FileServerBindingHandler;
+use golem_worker_service_base::gateway_execution::gateway_rate_limiter::{
+    DefaultGatewayRateLimiter, GatewayRateLimiter,
+};
+use golem_worker_service_base::gateway_execution::gateway_response_cache::{
+    DefaultGatewayResponseCache, GatewayResponseCache,
+};
+use golem_worker_service_base::gateway_execution::worker_stream_binding_handler::{
+    DefaultWorkerStreamBindingHandler, WorkerStreamBindingHandler,
+};
 use worker_request_executor::UnauthorisedWorkerRequestExecutor;
 
 use golem_worker_service_base::gateway_api_definition::http::{
@@ -22,6 +34,7 @@ use golem_worker_service_base::gateway_execution::api_definition_lookup::{
 };
 use golem_worker_service_base::gateway_execution::GatewayWorkerRequestExecutor;
 use golem_worker_service_base::repo::api_definition;
+use golem_worker_service_base::repo::api_definition_cache;
 use golem_worker_service_base::repo::api_deployment;
 use golem_worker_service_base::service::component::RemoteComponentService;
 use golem_worker_service_base::service::gateway::api_definition::{
@@ -69,6 +82,11 @@ pub struct Services {
     >,
     pub fileserver_binding_handler:
         Arc<dyn FileServerBindingHandler<DefaultNamespace> + Sync + Send>,
+    pub rate_limiter: Arc<dyn GatewayRateLimiter + Sync + Send>,
+    pub response_cache: Arc<dyn GatewayResponseCache + Sync + Send>,
+    pub worker_stream_binding_handler:
+        Arc<dyn WorkerStreamBindingHandler<DefaultNamespace> + Sync + Send>,
+    pub api_definition_tester: Arc<dyn ApiDefinitionTester<DefaultNamespace> + Sync + Send>,
 }
 
 impl Services {
@@ -119,7 +137,10 @@ impl Services {
             UnauthorisedWorkerRequestExecutor::new(worker_service.clone()),
         );
 
-        let (api_definition_repo, api_deployment_repo) = match config.db.clone() {
+        let (api_definition_repo, api_deployment_repo, api_definition_cache_repo) = match config
+            .db
+            .clone()
+        {
             DbConfig::Postgres(c) => {
                 let db_pool = db::create_postgres_pool(&c)
                     .await
@@ -132,7 +153,16 @@ impl Services {
                     Arc::new(api_deployment::LoggedDeploymentRepo::new(
                         api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
                     ));
-                (api_definition_repo, api_deployment_repo)
+                let api_definition_cache_repo: Arc<
+                    dyn api_definition_cache::ApiDefinitionCacheRepo + Sync + Send,
+                > = Arc::new(api_definition_cache::LoggedApiDefinitionCacheRepo::new(
+                    api_definition_cache::DbApiDefinitionCacheRepo::new(db_pool.clone().into()),
+                ));
+                (
+                    api_definition_repo,
+                    api_deployment_repo,
+                    api_definition_cache_repo,
+                )
             }
             DbConfig::Sqlite(c) => {
                 let db_pool = db::create_sqlite_pool(&c)
@@ -146,7 +176,16 @@ impl Services {
                     Arc::new(api_deployment::LoggedDeploymentRepo::new(
                         api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
                     ));
-                (api_definition_repo, api_deployment_repo)
+                let api_definition_cache_repo: Arc<
+                    dyn api_definition_cache::ApiDefinitionCacheRepo + Sync + Send,
+                > = Arc::new(api_definition_cache::LoggedApiDefinitionCacheRepo::new(
+                    api_definition_cache::DbApiDefinitionCacheRepo::new(db_pool.clone().into()),
+                ));
+                (
+                    api_definition_repo,
+                    api_deployment_repo,
+                    api_definition_cache_repo,
+                )
             }
         };
 
@@ -186,6 +225,12 @@ impl Services {
             worker_service.clone(),
         ));
 
+        let worker_stream_binding_handler: Arc<
+            dyn WorkerStreamBindingHandler<DefaultNamespace> + Sync + Send,
+        > = Arc::new(DefaultWorkerStreamBindingHandler::new(
+            worker_service.clone(),
+        ));
+
         let api_definition_validator_service = Arc::new(HttpApiDefinitionValidator {});
 
         let definition_service: Arc<
@@ -210,6 +255,17 @@ impl Services {
         let http_definition_lookup_service =
             Arc::new(HttpApiDefinitionLookup::new(deployment_service.clone()));
 
+        let rate_limiter: Arc<dyn GatewayRateLimiter + Sync + Send> =
+            Arc::new(DefaultGatewayRateLimiter::new());
+
+        let response_cache: Arc<dyn GatewayResponseCache + Sync + Send> =
+            Arc::new(DefaultGatewayResponseCache::new(api_definition_cache_repo));
+
+        let api_definition_tester: Arc<dyn ApiDefinitionTester<DefaultNamespace> + Sync + Send> =
+            Arc::new(DefaultApiDefinitionTester::new(
+                worker_to_http_service.clone(),
+            ));
+
         Ok(Services {
             worker_service,
             definition_service,
@@ -219,6 +275,10 @@ impl Services {
             component_service,
             api_definition_validator_service,
             fileserver_binding_handler,
+            rate_limiter,
+            response_cache,
+            worker_stream_binding_handler,
+            api_definition_tester,
         })
     }
 }